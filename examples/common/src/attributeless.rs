@@ -39,12 +39,7 @@ impl Example for LocalExample {
 
     // yet, we still need to tell luminance to render a certain number of vertices (even if we send no
     // attributes / data); in our case, we’ll just render a triangle, which has three vertices
-    let tess = context
-      .new_tess()
-      .set_render_vertex_nb(3)
-      .set_mode(Mode::Triangle)
-      .build()
-      .unwrap();
+    let tess = Tess::attributeless(context, Mode::Triangle, 3).unwrap();
 
     Self { program, tess }
   }
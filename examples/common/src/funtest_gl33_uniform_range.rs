@@ -0,0 +1,112 @@
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::{shader::types::Arr, UniformInterface};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::{Program, Uniform, UniformWarning},
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2( 1.,  1.),
+  vec2(-1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec3 frag;
+
+uniform float weights[4];
+
+void main() {
+  frag = vec3(weights[0] + weights[1], weights[2], weights[3]);
+}";
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  weights: Uniform<Arr<f32, 4>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  tess: Tess<()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .unwrap()
+      .ignore_warnings();
+
+    let tess = context
+      .new_tess()
+      .set_mode(Mode::TriangleFan)
+      .set_render_vertex_nb(4)
+      .build()
+      .unwrap();
+
+    LocalExample { program, tess }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let tess = &self.tess;
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.weights, &[0.; 4]);
+
+            // the range fits exactly at the end of the array
+            assert_eq!(iface.set_range(&uni.weights, 2, &[0.5, 0.25]), Ok(()));
+
+            // the range is rejected whether it overflows the array or the offset computation
+            assert_eq!(
+              iface.set_range(&uni.weights, 3, &[1., 1.]),
+              Err(UniformWarning::range_out_of_bounds(3, 2, 4))
+            );
+            assert_eq!(
+              iface.set_range(&uni.weights, usize::MAX, &[1.]),
+              Err(UniformWarning::range_out_of_bounds(usize::MAX, 1, 4))
+            );
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(tess)
+            })
+          })
+        },
+      )
+      .assume();
+
+    if render.is_err() {
+      log::error!("cannot render");
+    }
+
+    LoopFeedback::Exit
+  }
+}
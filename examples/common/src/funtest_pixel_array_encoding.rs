@@ -3,7 +3,7 @@ use luminance_front::{
   context::GraphicsContext,
   framebuffer::Framebuffer,
   pixel::RGB8UI,
-  texture::{Dim2, Sampler, TexelUpload, Texture},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, TexelUpload, Texture},
   Backend,
 };
 
@@ -14,8 +14,14 @@ impl Example for LocalExample {
     _: &mut impl PlatformServices,
     context: &mut impl GraphicsContext<Backend = Backend>,
   ) -> Self {
+    // integral textures cannot be filtered linearly
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
     let _texture: Texture<Dim2, RGB8UI> = context
-      .new_texture([100, 100], Sampler::default(), TexelUpload::reserve(0))
+      .new_texture([100, 100], sampler, TexelUpload::reserve(0))
       .unwrap();
 
     LocalExample
@@ -60,6 +60,8 @@ pub mod funtest_483_indices_mut_corruption;
 pub mod funtest_flatten_slice;
 #[cfg(all(feature = "funtest", feature = "funtest-gl33-f64-uniform"))]
 pub mod funtest_gl33_f64_uniform;
+#[cfg(all(feature = "funtest", feature = "funtest-gl33-uniform-range"))]
+pub mod funtest_gl33_uniform_range;
 #[cfg(feature = "funtest")]
 pub mod funtest_pixel_array_encoding;
 #[cfg(feature = "funtest")]
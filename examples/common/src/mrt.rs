@@ -122,10 +122,10 @@ impl Example for LocalExample {
         InputAction::Quit => return LoopFeedback::Exit,
 
         InputAction::Resized { width, height } => {
-          // simply ask another offscreen framebuffer at the right dimension (no allocation / reallocation)
-          self.offscreen_buffer = context
-            .new_framebuffer([width, height], 0, Sampler::default())
-            .expect("framebuffer recreation");
+          self
+            .offscreen_buffer
+            .resize([width, height])
+            .expect("framebuffer resize");
         }
 
         _ => (),
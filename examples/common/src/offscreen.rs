@@ -123,9 +123,10 @@ impl Example for LocalExample {
       match action {
         InputAction::Quit => return LoopFeedback::Exit,
         InputAction::Resized { width, height } => {
-          self.offscreen_buffer = context
-            .new_framebuffer([width, height], 0, Sampler::default())
-            .expect("framebuffer recreation");
+          self
+            .offscreen_buffer
+            .resize([width, height])
+            .expect("framebuffer resize");
         }
         _ => (),
       }
@@ -238,6 +238,7 @@ examples! {
   funtests:
   "funtest-tess-no-data", funtest_tess_no_data,
   "funtest-gl33-f64-uniform" if "funtest-gl33-f64-uniform", funtest_gl33_f64_uniform,
+  "funtest-gl33-uniform-range" if "funtest-gl33-uniform-range", funtest_gl33_uniform_range,
   "funtest-scissor-test", funtest_scissor_test,
   "funtest-360-manually-drop-framebuffer", funtest_360_manually_drop_framebuffer,
   "funtest-flatten-slice", funtest_flatten_slice,
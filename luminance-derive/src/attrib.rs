@@ -1,7 +1,7 @@
 use std::error;
 use std::fmt;
 use syn::parse::Parse;
-use syn::{Attribute, Ident, Lit, Meta, NestedMeta};
+use syn::{Attribute, Ident, Lit, LitStr, Meta, NestedMeta};
 
 #[derive(Debug)]
 pub(crate) enum AttrError {
@@ -82,6 +82,26 @@ pub(crate) fn get_field_attr_once<'a, A, T>(
 where
   A: IntoIterator<Item = &'a Attribute>,
   T: Parse,
+{
+  let strlit = get_field_lit_str_once(field_ident, attrs, key, sub_key, known_subkeys)?;
+  strlit
+    .parse()
+    .map_err(|_| AttrError::cannot_parse_attribute(field_ident.clone(), key, sub_key))
+}
+
+/// Get an attribute on a field or a variant that must appear only once with the following syntax,
+/// without parsing its content:
+///
+///   #[key(sub_key = "lit")]
+pub(crate) fn get_field_lit_str_once<'a, A>(
+  field_ident: &Ident,
+  attrs: A,
+  key: &str,
+  sub_key: &str,
+  known_subkeys: &[&str],
+) -> Result<LitStr, AttrError>
+where
+  A: IntoIterator<Item = &'a Attribute>,
 {
   let mut lit = None;
 
@@ -98,9 +118,7 @@ where
               }
 
               if let Lit::Str(ref strlit) = mnv.lit {
-                lit = Some(strlit.parse().map_err(|_| {
-                  AttrError::cannot_parse_attribute(field_ident.clone(), key, sub_key)
-                })?);
+                lit = Some(strlit.clone());
               }
             } else {
               let ident_str = mnv
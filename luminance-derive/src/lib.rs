@@ -21,7 +21,18 @@
 //!
 //! [See the full documentation here](https://docs.rs/luminance/latest/luminance/#uniform-interface)
 //!
+//! # `Std140`
+//!
+//! This macro allows to derive the `Std140` trait from [luminance-std140] for a custom `struct` type, so that it can be
+//! used in uniform blocks. A `struct` named after the type, suffixed with `Std140`, is generated to hold the `std140`
+//! representation. The `luminance-std140` crate must be a dependency of the crate using the macro.
+//!
+//! Fields are placed at their `std140` offsets, with explicit padding between them; for instance, a scalar following
+//! a three-component vector is packed right after it. Backends supporting it check the layout of that representation
+//! against the uniform blocks declared in shaders.
+//!
 //! [luminance]: https://crates.io/crates/luminance
+//! [luminance-std140]: https://crates.io/crates/luminance-std140
 //! [`Vertex`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Vertex.html
 //! [`Semantics`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Semantics.html
 
@@ -29,10 +40,12 @@ extern crate proc_macro;
 
 mod attrib;
 mod semantics;
+mod std140;
 mod uniform_interface;
 mod vertex;
 
 use crate::semantics::generate_enum_semantics_impl;
+use crate::std140::generate_std140_impl;
 use crate::uniform_interface::generate_uniform_interface_impl;
 use crate::vertex::generate_vertex_impl;
 use proc_macro::TokenStream;
@@ -82,3 +95,18 @@ pub fn derive_uniform_interface(input: TokenStream) -> TokenStream {
     _ => panic!("only structs are currently supported for deriving UniformInterface"),
   }
 }
+
+#[proc_macro_derive(Std140)]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+  let di: DeriveInput = parse_macro_input!(input);
+
+  match di.data {
+    // for now, we only handle structs
+    Data::Struct(struct_) => match generate_std140_impl(di.ident, di.vis, di.generics, struct_) {
+      Ok(impl_) => impl_,
+      Err(e) => panic!("{}", e),
+    },
+
+    _ => panic!("only structs are currently supported for deriving Std140"),
+  }
+}
@@ -0,0 +1,155 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use std::error;
+use std::fmt;
+use syn::{DataStruct, Fields, Generics, Ident, Visibility};
+
+#[non_exhaustive]
+#[derive(Debug)]
+pub(crate) enum DeriveStd140Error {
+  UnnamedFields,
+  UnitStruct,
+  GenericStruct,
+}
+
+impl DeriveStd140Error {
+  pub(crate) fn unsupported_unnamed() -> Self {
+    DeriveStd140Error::UnnamedFields
+  }
+
+  pub(crate) fn unsupported_unit() -> Self {
+    DeriveStd140Error::UnitStruct
+  }
+
+  pub(crate) fn unsupported_generics() -> Self {
+    DeriveStd140Error::GenericStruct
+  }
+}
+
+impl fmt::Display for DeriveStd140Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      DeriveStd140Error::UnnamedFields => f.write_str("unsupported unnamed fields"),
+      DeriveStd140Error::UnitStruct => f.write_str("unsupported unit struct"),
+      DeriveStd140Error::GenericStruct => f.write_str("unsupported generic struct"),
+    }
+  }
+}
+
+impl error::Error for DeriveStd140Error {}
+
+pub(crate) fn generate_std140_impl(
+  ident: Ident,
+  vis: Visibility,
+  generics: Generics,
+  struct_: DataStruct,
+) -> Result<TokenStream, DeriveStd140Error> {
+  if !generics.params.is_empty() {
+    return Err(DeriveStd140Error::unsupported_generics());
+  }
+
+  match struct_.fields {
+    Fields::Named(named_fields) => {
+      // the encoded type is a struct with the same fields, each one being encoded and placed at its std140 offset; the
+      // offsets are computed from the base alignment of each field, and padding is explicitly inserted before each field
+      // and at the end of the struct, whose size is a multiple of its base alignment
+      let encoded_ident = format_ident!("{}Std140", ident);
+      let encoded_doc = format!("`std140` representation of [`{}`].", ident);
+
+      let mut encoded_fields = Vec::new();
+      let mut layout_consts = Vec::new();
+      let mut field_idents = Vec::new();
+      let mut field_names = Vec::new();
+      let mut pad_idents = Vec::new();
+      let mut pad_consts = Vec::new();
+      let mut end = quote! { 0 };
+      let mut align = quote! { 16 };
+
+      for (i, field) in named_fields.named.into_iter().enumerate() {
+        let field_ident = field.ident.unwrap();
+        let field_vis = field.vis;
+        let field_ty = field.ty;
+        let offset_const = format_ident!("STD140_OFFSET_{}", i);
+        let pad_const = format_ident!("STD140_PAD_{}", i);
+        let pad_ident = format_ident!("_pad_{}", field_ident);
+
+        layout_consts.push(quote! {
+          const #offset_const: usize =
+            luminance_std140::round_up(#end, <#field_ty as luminance_std140::Std140>::ALIGN);
+          const #pad_const: usize = Self::#offset_const - (#end);
+        });
+        encoded_fields.push(quote! {
+          #pad_ident: [u8; #encoded_ident::#pad_const],
+          #field_vis #field_ident: <#field_ty as luminance_std140::Std140>::Encoded
+        });
+
+        end = quote! {
+          Self::#offset_const + std::mem::size_of::<<#field_ty as luminance_std140::Std140>::Encoded>()
+        };
+        align = quote! {
+          luminance_std140::max(#align, <#field_ty as luminance_std140::Std140>::ALIGN)
+        };
+
+        field_names.push(field_ident.to_string());
+        field_idents.push(field_ident);
+        pad_idents.push(pad_ident);
+        pad_consts.push(pad_const);
+      }
+
+      let output = quote! {
+        #[doc = #encoded_doc]
+        // structures are aligned on vec4 in std140
+        #[repr(C, align(16))]
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)]
+        #vis struct #encoded_ident {
+          #(#encoded_fields,)*
+          _pad_end: [u8; #encoded_ident::STD140_PAD_END],
+        }
+
+        impl #encoded_ident {
+          #(#layout_consts)*
+          const STD140_ALIGN: usize = #align;
+          const STD140_PAD_END: usize = luminance_std140::round_up(#end, Self::STD140_ALIGN) - (#end);
+        }
+
+        impl luminance_std140::Std140 for #ident {
+          type Encoded = #encoded_ident;
+
+          const ALIGN: usize = #encoded_ident::STD140_ALIGN;
+
+          fn std140_encode(self) -> Self::Encoded {
+            #encoded_ident {
+              #(
+                #pad_idents: [0; #encoded_ident::#pad_consts],
+                #field_idents: luminance_std140::Std140::std140_encode(self.#field_idents),
+              )*
+              _pad_end: [0; #encoded_ident::STD140_PAD_END],
+            }
+          }
+
+          fn std140_decode(encoded: Self::Encoded) -> Self {
+            #ident {
+              #(#field_idents: luminance_std140::Std140::std140_decode(encoded.#field_idents)),*
+            }
+          }
+
+          fn std140_fields() -> Vec<(&'static str, usize)> {
+            let encoded = std::mem::MaybeUninit::<#encoded_ident>::uninit();
+            let base = encoded.as_ptr();
+
+            // only the addresses of the fields are computed; the uninitialized memory is never read
+            vec![
+              #((#field_names, unsafe { std::ptr::addr_of!((*base).#field_idents) } as usize - base as usize)),*
+            ]
+          }
+        }
+      };
+
+      Ok(output.into())
+    }
+
+    Fields::Unnamed(_) => Err(DeriveStd140Error::unsupported_unnamed()),
+    Fields::Unit => Err(DeriveStd140Error::unsupported_unit()),
+  }
+}
@@ -1,4 +1,4 @@
-use crate::attrib::{get_field_attr_once, get_field_flag_once, AttrError};
+use crate::attrib::{get_field_flag_once, get_field_lit_str_once, AttrError};
 use proc_macro::TokenStream;
 use quote::quote;
 use std::error;
@@ -90,9 +90,10 @@ pub(crate) fn generate_uniform_interface_impl(
           KNOWN_SUBKEYS,
         )
         .map_err(DeriveUniformInterfaceError::unbound_error)?;
+        // keep the name verbatim so that it can refer to array items or struct members (lights[0].color)
         let name =
-          get_field_attr_once(&ident, field.attrs.iter(), "uniform", "name", KNOWN_SUBKEYS)
-            .map(|ident: Ident| ident.to_string())
+          get_field_lit_str_once(&ident, field.attrs.iter(), "uniform", "name", KNOWN_SUBKEYS)
+            .map(|strlit| strlit.value())
             .or_else(|e| match e {
               AttrError::CannotFindAttribute(..) => Ok(field_ident.to_string()),
 
@@ -3,10 +3,10 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use std::error;
 use std::fmt;
-use syn::{Attribute, DataStruct, Field, Fields, Ident, Index, LitBool, Type};
+use syn::{Attribute, DataStruct, Field, Fields, Ident, Index, LitBool, LitInt, Type};
 
 // accepted sub keys for the "vertex" key
-const KNOWN_SUBKEYS: &[&str] = &["sem", "instanced", "normalized"];
+const KNOWN_SUBKEYS: &[&str] = &["sem", "instanced", "divisor", "normalized"];
 
 #[derive(Debug)]
 pub(crate) enum StructImplError {
@@ -70,7 +70,8 @@ where
   let sem_type: Type = get_field_attr_once(&ident, attrs.clone(), "vertex", "sem", KNOWN_SUBKEYS)
     .map_err(StructImplError::semantics_error)?;
 
-  let instancing = get_instancing(&ident, attrs.clone())?;
+  let instancing = get_instancing(&ident, attrs.clone())?
+    .unwrap_or_else(|| quote! { luminance::vertex::VertexInstancing::Off });
 
   match struct_.fields {
     Fields::Unnamed(unnamed_fields) => {
@@ -148,6 +149,9 @@ where
     })
     .map_err(StructImplError::field_error)?;
 
+  // fields can override the instancing of the struct
+  let instancing = get_instancing(&ident, &field.attrs)?.unwrap_or_else(|| instancing.clone());
+
   let field_ty = &field.ty;
   let names = fields_names.into();
 
@@ -256,24 +260,39 @@ fn process_struct(
   }
 }
 
+/// Get the instancing set with the `divisor` or `instanced` arguments, if any.
 fn get_instancing<'a, A>(
   ident: &Ident,
   attrs: A,
-) -> Result<proc_macro2::TokenStream, StructImplError>
+) -> Result<Option<proc_macro2::TokenStream>, StructImplError>
 where
-  A: IntoIterator<Item = &'a Attribute>,
+  A: IntoIterator<Item = &'a Attribute> + Clone,
 {
-  // search for the instancing argument; if not there, we don’t use vertex instancing
+  // a divisor implies vertex instancing
+  match get_field_attr_once(ident, attrs.clone(), "vertex", "divisor", KNOWN_SUBKEYS) {
+    Ok(divisor) => {
+      let divisor: LitInt = divisor;
+      return Ok(Some(
+        quote! { luminance::vertex::VertexInstancing::Divisor(#divisor) },
+      ));
+    }
+
+    Err(AttrError::CannotFindAttribute(..)) => (),
+
+    Err(e) => return Err(StructImplError::field_error(e)),
+  }
+
+  // search for the instancing argument
   get_field_attr_once(&ident, attrs, "vertex", "instanced", KNOWN_SUBKEYS)
     .map(|b: LitBool| {
       if b.value {
-        quote! { luminance::vertex::VertexInstancing::On }
+        Some(quote! { luminance::vertex::VertexInstancing::On })
       } else {
-        quote! { luminance::vertex::VertexInstancing::Off }
+        Some(quote! { luminance::vertex::VertexInstancing::Off })
       }
     })
     .or_else(|e| match e {
-      AttrError::CannotFindAttribute(..) => Ok(quote! { luminance::vertex::VertexInstancing::Off }),
+      AttrError::CannotFindAttribute(..) => Ok(None),
 
       _ => Err(e),
     })
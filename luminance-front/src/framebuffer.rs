@@ -1,4 +1,10 @@
 use crate::Backend;
 
 pub type Framebuffer<D, CS, DS> = luminance::framebuffer::Framebuffer<Backend, D, CS, DS>;
-pub use luminance::framebuffer::{FramebufferError, IncompleteReason};
+pub type MultisampleFramebuffer<CS, DS> =
+  luminance::framebuffer::MultisampleFramebuffer<Backend, CS, DS>;
+pub type BackBuffer = luminance::framebuffer::BackBuffer<Backend>;
+pub type PixelReadback<T> = luminance::framebuffer::PixelReadback<Backend, T>;
+pub use luminance::framebuffer::{
+  Attachment, BlitMask, FramebufferError, IncompleteReason, Region, Renderbuffer,
+};
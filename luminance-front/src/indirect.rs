@@ -0,0 +1,7 @@
+use crate::Backend;
+
+pub use luminance::indirect::{
+  DrawArraysIndirectCommand, DrawElementsIndirectCommand, IndirectBufferError, IndirectCommand,
+};
+
+pub type IndirectBuffer<C> = luminance::indirect::IndirectBuffer<Backend, C>;
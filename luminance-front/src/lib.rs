@@ -54,14 +54,60 @@
 //! - _Default_: `["gl33", "webgl2"]`.
 //! - **OpenGL**:
 //!   - `"gl33"`: OpenGL 3.3 implementation.
+//!   - `"gl33-GL_ARB_base_instance"`: rendering with a base instance for the OpenGL 3.3 implementation. Requires the
+//!     `GL_ARB_base_instance` extension, which is core since OpenGL 4.2.
+//!   - `"gl33-GL_ARB_bindless_texture"`: bindless textures for the OpenGL 3.3 implementation. Requires the
+//!     `GL_ARB_bindless_texture` extension.
+//!   - `"gl33-GL_ARB_compute_shader"`: compute shaders for the OpenGL 3.3 implementation. Requires the
+//!     `GL_ARB_compute_shader` extension, which is core since OpenGL 4.3.
+//!   - `"gl33-GL_ARB_draw_indirect"`: indirect buffers and renders of a single draw command stored in them for the
+//!     OpenGL 3.3 implementation. Requires the `GL_ARB_draw_indirect` extension, which is core since OpenGL 4.0.
+//!   - `"gl33-GL_ARB_ES3_compatibility"`: native fixed-index primitive restart for the OpenGL 3.3 implementation, which
+//!     then doesn’t have to set the primitive restart index on every render. Requires the `GL_ARB_ES3_compatibility`
+//!     extension, which is core since OpenGL 4.3.
+//!   - `"gl33-GL_ARB_explicit_uniform_location"`: explicit uniform locations (`layout(location = N) uniform`) for the
+//!     OpenGL 3.3 implementation. Requires the `GL_ARB_explicit_uniform_location` extension, which is core since OpenGL
+//!     4.3.
+//!   - `"gl33-GL_ARB_gpu_shader_fp64"`: 64-bit floating-point uniforms (`double`, `dvec*`, `dmat*`) for the OpenGL 3.3
+//!     implementation. Requires the `GL_ARB_gpu_shader_fp64` extension, which is core since OpenGL 4.0.
+//!   - `"gl33-GL_ARB_multi_draw_indirect"`: renders of all the draw commands stored in indirect buffers at once for the
+//!     OpenGL 3.3 implementation; implies `"gl33-GL_ARB_draw_indirect"`. Requires the `GL_ARB_multi_draw_indirect`
+//!     extension, which is core since OpenGL 4.3.
+//!   - `"gl33-GL_ARB_separate_shader_objects"`: separable programs and program pipelines for the OpenGL 3.3
+//!     implementation. Requires the `GL_ARB_separate_shader_objects` extension, which is core since OpenGL 4.1.
+//!   - `"gl33-GL_ARB_shader_atomic_counters"`: atomic counters (`atomic_uint`) for the OpenGL 3.3 implementation.
+//!     Requires the `GL_ARB_shader_atomic_counters` extension, which is core since OpenGL 4.2.
+//!   - `"gl33-GL_ARB_shader_image_load_store"`: image uniforms (`image2D`, etc.) that shader stages can load from and
+//!     store to, for the OpenGL 3.3 implementation. Requires the `GL_ARB_shader_image_load_store` extension, which is
+//!     core since OpenGL 4.2.
+//!   - `"gl33-GL_ARB_shader_storage_buffer_object"`: shader storage (buffer blocks) for the OpenGL 3.3 implementation.
+//!     Requires the `GL_ARB_shader_storage_buffer_object` extension, which is core since OpenGL 4.3.
+//!   - `"gl33-GL_ARB_shader_subroutine"`: shader subroutines for the OpenGL 3.3 implementation. Requires the
+//!     `GL_ARB_shader_subroutine` extension, which is core since OpenGL 4.0.
+//!   - `"gl33-GL_ARB_transform_feedback2"`: transform feedback objects and capture for the OpenGL 3.3 implementation.
+//!     Requires the `GL_ARB_transform_feedback2` extension, which is core since OpenGL 4.0.
+//!   - `"gl33-GL_KHR_parallel_shader_compile"`: deferred programs, compiled and linked in the background, for the
+//!     OpenGL 3.3 implementation. Requires the `GL_KHR_parallel_shader_compile` extension.
+//!   - `"gl33-GL_NV_mesh_shader"`: task and mesh shaders for the OpenGL 3.3 implementation. Requires the
+//!     `GL_NV_mesh_shader` extension, and `luminance_gl::gl33::load_with` to be called. Task and mesh stages must
+//!     declare `#version 450` and enable the extension themselves.
+//!   - `"gl33-cgmath"`, `"gl33-glam"`, `"gl33-mint"`, `"gl33-nalgebra"`: vector and matrix types of the corresponding
+//!     math crates can be used as uniforms with the OpenGL 3.3 implementation.
 //! - **WebGL 2**:
 //!   - `"webgl2"`: WebGL 2 implementation.
+//! - **Textures**:
+//!   - `"container"`: parsing and loading of KTX, KTX2 and DDS texture containers.
+//! - **Shaders**:
+//!   - `"naga"`: translation of shader stages written in other shading languages, such as WGSL, with [naga].
 //!
 //! [luminance]: https://crates.io/crates/luminance
+//! [naga]: https://crates.io/crates/naga
 
 pub mod context;
 pub mod framebuffer;
+pub mod indirect;
 pub mod pipeline;
+pub mod pixel_buffer;
 pub mod query;
 pub mod render_gate;
 pub mod shader;
@@ -69,9 +115,14 @@ pub mod shading_gate;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
+#[cfg(feature = "container")]
+pub mod texture_container;
+pub mod texture_streamer;
+pub mod transform_feedback;
 
 // re-export
 pub use luminance::blending;
+pub use luminance::color_mask;
 pub use luminance::depth_stencil;
 pub use luminance::face_culling;
 pub use luminance::pixel;
@@ -1,11 +1,15 @@
 use crate::Backend;
 
 pub use luminance::pipeline::{
-  PipelineError, PipelineState, ShaderDataBinding, TextureBinding, Viewport,
+  ClearColor, ImageAccess, ImageBinding, InvalidateAttachments, PipelineError, PipelineState,
+  ShaderDataBinding, ShaderStorageBinding, ShadowBinding, TextureBinding, Viewport,
 };
 
 pub type Pipeline<'a> = luminance::pipeline::Pipeline<'a, Backend>;
 pub type PipelineGate<'a> = luminance::pipeline::PipelineGate<'a, Backend>;
 pub type BoundTexture<'a, D, P> = luminance::pipeline::BoundTexture<'a, Backend, D, P>;
+pub type BoundImage<'a, D, P> = luminance::pipeline::BoundImage<'a, Backend, D, P>;
 pub type BoundShaderData<'a, T> = luminance::pipeline::BoundShaderData<'a, Backend, T>;
+pub type BoundShaderStorage<'a, T> = luminance::pipeline::BoundShaderStorage<'a, Backend, T>;
+pub type BoundAtomicCounters<'a> = luminance::pipeline::BoundAtomicCounters<'a, Backend>;
 pub type Render<E> = luminance::pipeline::Render<E>;
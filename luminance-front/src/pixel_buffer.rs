@@ -0,0 +1,7 @@
+use crate::Backend;
+
+pub use luminance::pixel_buffer::PixelBufferError;
+
+pub type PixelBuffer<T> = luminance::pixel_buffer::PixelBuffer<Backend, T>;
+pub type PixelBufferSliceMut<'a, T> = luminance::pixel_buffer::PixelBufferSliceMut<'a, Backend, T>;
+pub type UploadFence = luminance::pixel_buffer::UploadFence<Backend>;
@@ -1,10 +1,18 @@
 use crate::Backend;
 
+pub mod cache;
+pub mod diagnostic;
+pub mod include;
+#[cfg(feature = "naga")]
+pub mod ir;
 pub mod types;
 
 pub use luminance::shader::{
-  ProgramError, ProgramWarning, ShaderDataError, StageError, StageType, TessellationStages,
-  Uniform, UniformInterface, UniformType, UniformWarning, VertexAttribWarning,
+  ActiveAtomicCounter, ActiveUniform, ActiveVertexAttrib, GeometryConfig, GeometryInput,
+  GeometryOutput, GlslVersion, ProgramError, ProgramValidation, ProgramWarning, ShaderDataError,
+  StageError, StageType, Subroutine, SubroutineUniform, TessellationStages, Uniform,
+  UniformInterface, UniformName, UniformType, UniformWarning, VertexAttribWarning,
+  VertexFormatError,
 };
 
 pub type Stage = luminance::shader::Stage<Backend>;
@@ -14,4 +22,10 @@ pub type AdaptationFailure<Sem, Out, Uni> =
   luminance::shader::AdaptationFailure<Backend, Sem, Out, Uni>;
 pub type ProgramInterface<'a> = luminance::shader::ProgramInterface<'a, Backend>;
 pub type Program<Sem, Out, Uni> = luminance::shader::Program<Backend, Sem, Out, Uni>;
+pub type DeferredProgram<Sem, Out, Uni> =
+  luminance::shader::DeferredProgram<Backend, Sem, Out, Uni>;
+pub type BuiltComputeProgram<Uni> = luminance::shader::BuiltComputeProgram<Backend, Uni>;
+pub type ComputeProgram<Uni> = luminance::shader::ComputeProgram<Backend, Uni>;
 pub type ShaderData<T> = luminance::shader::ShaderData<Backend, T>;
+pub type AtomicCounters = luminance::shader::AtomicCounters<Backend>;
+pub type ProgramPipeline = luminance::shader::ProgramPipeline<Backend>;
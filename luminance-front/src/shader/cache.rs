@@ -0,0 +1,6 @@
+use crate::Backend;
+
+pub type ProgramCache<Sem, Out, Uni> =
+  luminance::shader::cache::ProgramCache<Backend, Sem, Out, Uni>;
+pub type SharedProgram<Sem, Out, Uni> =
+  luminance::shader::cache::SharedProgram<Backend, Sem, Out, Uni>;
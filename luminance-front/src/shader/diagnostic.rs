@@ -0,0 +1 @@
+pub use luminance::shader::diagnostic::{Diagnostic, Severity, ShaderLog};
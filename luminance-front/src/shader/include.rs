@@ -0,0 +1,3 @@
+pub use luminance::shader::include::{
+  expand_includes, ExpandedSource, IncludeError, IncludeResolver,
+};
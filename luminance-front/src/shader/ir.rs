@@ -0,0 +1 @@
+pub use luminance::shader::ir::ShaderIr;
@@ -1 +1,3 @@
-pub use luminance::shader::types::{Mat22, Mat33, Mat44, Vec2, Vec3, Vec4};
+pub use luminance::shader::types::{
+  Mat22, Mat23, Mat24, Mat32, Mat33, Mat34, Mat42, Mat43, Mat44, Transposed, Vec2, Vec3, Vec4,
+};
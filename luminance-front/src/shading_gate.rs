@@ -1,3 +1,4 @@
 use crate::Backend;
 
 pub type ShadingGate<'a> = luminance::shading_gate::ShadingGate<'a, Backend>;
+pub type ComputeGate<'a> = luminance::shading_gate::ComputeGate<'a, Backend>;
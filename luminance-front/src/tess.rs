@@ -1,8 +1,8 @@
 use crate::Backend;
 
 pub use luminance::tess::{
-  Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndexType, TessMapError,
-  TessViewError, View,
+  Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndexType, TessLevels,
+  TessMapError, TessViewError, View,
 };
 
 pub type TessBuilder<'a, V, I = (), W = (), S = Interleaved> =
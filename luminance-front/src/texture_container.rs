@@ -0,0 +1 @@
+pub use luminance::texture_container::{ContainerDimensionable, ContainerError, TextureContainer};
@@ -0,0 +1,5 @@
+use crate::Backend;
+
+pub use luminance::texture_streamer::{TextureStreamerError, TextureStreamerStats};
+
+pub type TextureStreamer<K, D, P> = luminance::texture_streamer::TextureStreamer<Backend, K, D, P>;
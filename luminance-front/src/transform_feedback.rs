@@ -0,0 +1,5 @@
+use crate::Backend;
+
+pub use luminance::transform_feedback::TransformFeedbackError;
+
+pub type TransformFeedback = luminance::transform_feedback::TransformFeedback<Backend>;
@@ -2,23 +2,49 @@
 //!
 //! This module implements an OpenGL 3.3 backend for luminance. The backend type is [`GL33`].
 
+#[cfg(feature = "GL_ARB_bindless_texture")]
+mod bindless;
 mod buffer;
 mod depth_stencil;
 mod framebuffer;
+#[cfg(feature = "GL_ARB_draw_indirect")]
+mod indirect;
+#[cfg(feature = "GL_NV_mesh_shader")]
+mod mesh_shader;
 mod pipeline;
 mod pixel;
+mod pixel_buffer;
 mod query;
 mod shader;
 mod state;
 mod tess;
 mod texture;
+#[cfg(feature = "GL_ARB_transform_feedback2")]
+mod transform_feedback;
 mod vertex_restart;
 
 pub use self::state::GLState;
 pub use self::state::StateQueryError;
 use std::cell::RefCell;
+use std::os::raw::c_void;
 use std::rc::Rc;
 
+/// Load the OpenGL functions used by the backend that are not provided by the [`gl`] crate, such as extension
+/// functions.
+///
+/// This function must be called along with [`gl::load_with`], with the same loader.
+#[allow(unused_variables, unused_mut)]
+pub fn load_with<F>(mut loader: F)
+where
+  F: FnMut(&'static str) -> *const c_void,
+{
+  #[cfg(feature = "GL_ARB_bindless_texture")]
+  bindless::load_with(&mut loader);
+
+  #[cfg(feature = "GL_NV_mesh_shader")]
+  mesh_shader::load_with(&mut loader);
+}
+
 /// An OpenGL 3.3 backend.
 ///
 /// This type is to be used as a luminance backend type. It implements the whole public API.
@@ -35,6 +61,15 @@ impl GL33 {
     })
   }
 
+  /// Enable or disable the uniform cache.
+  ///
+  /// When enabled (the default), shader programs remember the last value of their numeric uniforms (scalars, vectors and
+  /// matrices) and skip updates with the same value. Disabling it can be useful to profile the actual cost of uniform
+  /// updates.
+  pub fn enable_uniform_cache(&mut self, enabled: bool) {
+    self.state.borrow_mut().enable_uniform_cache(enabled);
+  }
+
   /// Internal access to the backend state.
   ///
   /// # Unsafety
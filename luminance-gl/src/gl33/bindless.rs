@@ -0,0 +1,75 @@
+//! `GL_ARB_bindless_texture` functions.
+//!
+//! Those functions are not part of the [`gl`] crate, so they are loaded by [`load_with`](super::load_with).
+
+#![allow(non_snake_case)]
+
+use gl::types::{GLint, GLuint, GLuint64};
+use std::{
+  mem,
+  os::raw::c_void,
+  ptr::null_mut,
+  sync::atomic::{AtomicPtr, Ordering},
+};
+
+static GET_TEXTURE_HANDLE: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+static MAKE_TEXTURE_HANDLE_RESIDENT: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+static MAKE_TEXTURE_HANDLE_NON_RESIDENT: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+static UNIFORM_HANDLE_UI64: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+
+pub(crate) fn load_with(loader: &mut dyn FnMut(&'static str) -> *const c_void) {
+  GET_TEXTURE_HANDLE.store(
+    loader("glGetTextureHandleARB") as *mut c_void,
+    Ordering::Relaxed,
+  );
+  MAKE_TEXTURE_HANDLE_RESIDENT.store(
+    loader("glMakeTextureHandleResidentARB") as *mut c_void,
+    Ordering::Relaxed,
+  );
+  MAKE_TEXTURE_HANDLE_NON_RESIDENT.store(
+    loader("glMakeTextureHandleNonResidentARB") as *mut c_void,
+    Ordering::Relaxed,
+  );
+  UNIFORM_HANDLE_UI64.store(
+    loader("glUniformHandleui64ARB") as *mut c_void,
+    Ordering::Relaxed,
+  );
+}
+
+/// Whether all the functions were loaded.
+pub(crate) fn is_loaded() -> bool {
+  [
+    &GET_TEXTURE_HANDLE,
+    &MAKE_TEXTURE_HANDLE_RESIDENT,
+    &MAKE_TEXTURE_HANDLE_NON_RESIDENT,
+    &UNIFORM_HANDLE_UI64,
+  ]
+  .iter()
+  .all(|f| !f.load(Ordering::Relaxed).is_null())
+}
+
+// the functions below must only be called once is_loaded() returned true
+
+pub(crate) unsafe fn GetTextureHandleARB(texture: GLuint) -> GLuint64 {
+  let f: extern "system" fn(GLuint) -> GLuint64 =
+    mem::transmute(GET_TEXTURE_HANDLE.load(Ordering::Relaxed));
+  f(texture)
+}
+
+pub(crate) unsafe fn MakeTextureHandleResidentARB(handle: GLuint64) {
+  let f: extern "system" fn(GLuint64) =
+    mem::transmute(MAKE_TEXTURE_HANDLE_RESIDENT.load(Ordering::Relaxed));
+  f(handle)
+}
+
+pub(crate) unsafe fn MakeTextureHandleNonResidentARB(handle: GLuint64) {
+  let f: extern "system" fn(GLuint64) =
+    mem::transmute(MAKE_TEXTURE_HANDLE_NON_RESIDENT.load(Ordering::Relaxed));
+  f(handle)
+}
+
+pub(crate) unsafe fn UniformHandleui64ARB(location: GLint, value: GLuint64) {
+  let f: extern "system" fn(GLint, GLuint64) =
+    mem::transmute(UNIFORM_HANDLE_UI64.load(Ordering::Relaxed));
+  f(location, value)
+}
@@ -6,11 +6,12 @@ use crate::gl33::{
 };
 use gl;
 use gl::types::*;
-use luminance::tess::TessMapError;
+use luminance::tess::{TessError, TessMapError};
 use std::{
   cell::RefCell,
   error, fmt, mem,
   ops::{Deref, DerefMut},
+  ptr,
   rc::Rc,
   slice,
 };
@@ -97,6 +98,47 @@ impl<T> Buffer<T> {
     self.buf.len()
   }
 
+  /// Write `values` to the buffer, starting at the value at index `offset`.
+  ///
+  /// `U` can be another type than `T`, such as for buffers of raw bytes; `offset` is then in `U` values.
+  pub(crate) unsafe fn write<U>(&mut self, offset: usize, values: &[U]) -> Result<(), TessError>
+  where
+    U: Copy,
+  {
+    let capacity = mem::size_of_val(self.buf.as_slice()) / mem::size_of::<U>().max(1);
+
+    if offset
+      .checked_add(values.len())
+      .map_or(true, |end| end > capacity)
+    {
+      return Err(TessError::out_of_bounds(offset, values.len(), capacity));
+    }
+
+    let byte_offset = offset * mem::size_of::<U>();
+    let bytes = mem::size_of_val(values);
+
+    // keep the cached version up to date
+    ptr::copy_nonoverlapping(
+      values.as_ptr() as *const u8,
+      (self.buf.as_mut_ptr() as *mut u8).add(byte_offset),
+      bytes,
+    );
+
+    self
+      .gl_buf
+      .state
+      .borrow_mut()
+      .bind_array_buffer(self.handle(), Bind::Cached);
+    gl::BufferSubData(
+      gl::ARRAY_BUFFER,
+      byte_offset as GLintptr,
+      bytes as GLsizeiptr,
+      values.as_ptr() as _,
+    );
+
+    Ok(())
+  }
+
   pub(crate) fn slice_buffer(&self) -> Result<BufferSlice<T>, SliceBufferError> {
     unsafe {
       self
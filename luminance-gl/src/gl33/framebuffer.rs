@@ -1,5 +1,8 @@
 use crate::gl33::{
-  state::{Bind, GLState},
+  pixel::opengl_pixel_format,
+  pixel_buffer::UploadFence,
+  state::{Bind, GLState, ScissorState},
+  texture::set_pack_alignment,
   GL33,
 };
 use gl::{self, types::*};
@@ -7,31 +10,170 @@ use luminance::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      Framebuffer as FramebufferBackend, FramebufferBackBuffer, FramebufferBlit, FramebufferLayer,
+      FramebufferLevel, FramebufferMultisample, FramebufferRead, FramebufferReadback,
+      FramebufferRenderbuffer, FramebufferResize,
+    },
+    pixel_buffer::UploadFence as UploadFenceBackend,
   },
-  framebuffer::{FramebufferError, IncompleteReason},
-  texture::{Dim2, Dimensionable, Sampler},
+  framebuffer::{Attachment, BlitMask, FramebufferError, IncompleteReason, Region},
+  pixel::{Format, Pixel, PixelFormat, Size},
+  texture::{Dim, Dim2, Dimensionable, MagFilter, Sampler},
 };
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, mem, os::raw::c_void, ptr, rc::Rc, time::Duration};
 
 pub struct Framebuffer<D>
 where
   D: Dimensionable,
 {
   pub(crate) handle: GLuint,
-  renderbuffer: Option<GLuint>,
+  // renderbuffers owned by the framebuffer: default depth buffer or renderbuffer slots
+  renderbuffers: Vec<GLuint>,
   pub(crate) size: D::Size,
+  // attached textures and renderbuffers, as (attachment point, handle, texture target or RENDERBUFFER)
+  attachments: Vec<(GLenum, GLuint, GLenum)>,
+  // attached layer of layered textures; None if all the layers are attached
+  layer: Option<u32>,
+  // attached mipmap level of the textures of the slots
+  level: usize,
+  // attachment point of the depth texture; depth/stencil textures are attached to both depth and stencil
+  depth_attachment: GLenum,
+  // textures owned by the framebuffer: multisample storage or default layered depth buffer
+  owned_textures: Vec<GLuint>,
+  // whether the storage of the framebuffer is multisampled
+  multisampled: bool,
   state: Rc<RefCell<GLState>>,
 }
 
+impl<D> Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Attach the textures of the framebuffer with the given layer.
+  unsafe fn attach_layer(&self, layer: Option<u32>) -> Result<(), FramebufferError> {
+    for &(attachment, handle, target) in &self.attachments {
+      attach_texture(
+        attachment,
+        handle,
+        target,
+        layer,
+        self.attachment_level(handle),
+      )?;
+    }
+
+    Ok(())
+  }
+
+  /// Size of the attached mipmap level.
+  pub(crate) fn level_size(&self) -> D::Size {
+    D::level_size(self.size, self.level)
+  }
+
+  /// Mipmap level to attach a texture with; textures owned by the framebuffer have a single level.
+  fn attachment_level(&self, handle: GLuint) -> usize {
+    if self.owned_textures.contains(&handle) {
+      0
+    } else {
+      self.level
+    }
+  }
+
+  /// Number of attached color textures and renderbuffers.
+  pub(crate) fn color_attachments_len(&self) -> usize {
+    self
+      .attachments
+      .iter()
+      .filter(|&&(attachment, _, _)| attachment != self.depth_attachment)
+      .count()
+  }
+
+  /// Check whether the framebuffer, bound as draw framebuffer with its textures attached with `layer`, is complete.
+  ///
+  /// The offending attachment of incomplete attachments and draw buffers is looked for.
+  unsafe fn status(&self, layer: Option<u32>) -> Result<(), IncompleteReason> {
+    match get_framebuffer_status() {
+      Err(IncompleteReason::IncompleteAttachment(None)) => Err(
+        IncompleteReason::IncompleteAttachment(self.find_incomplete_attachment(layer)),
+      ),
+      Err(IncompleteReason::IncompleteDrawBuffer(None)) => Err(
+        IncompleteReason::IncompleteDrawBuffer(self.find_missing_draw_buffer()),
+      ),
+      status => status,
+    }
+  }
+
+  /// Find the incomplete attachment by detaching the attachments one at a time, until the framebuffer no longer has
+  /// an incomplete attachment.
+  unsafe fn find_incomplete_attachment(&self, layer: Option<u32>) -> Option<Attachment> {
+    for &(attachment, handle, target) in &self.attachments {
+      gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, attachment, gl::RENDERBUFFER, 0);
+      let status = get_framebuffer_status();
+
+      // the attachment was attached with the same arguments, so this cannot fail
+      let _ = attach_texture(
+        attachment,
+        handle,
+        target,
+        layer,
+        self.attachment_level(handle),
+      );
+
+      if !matches!(status, Err(IncompleteReason::IncompleteAttachment(_))) {
+        return Some(from_gl_attachment(attachment));
+      }
+    }
+
+    None
+  }
+
+  /// Find the first draw buffer with nothing attached.
+  unsafe fn find_missing_draw_buffer(&self) -> Option<Attachment> {
+    let max = self.state.borrow_mut().get_max_color_attachments();
+
+    for i in 0..max {
+      let mut draw_buffer = 0;
+      gl::GetIntegerv(gl::DRAW_BUFFER0 + i as GLenum, &mut draw_buffer);
+
+      if draw_buffer as GLenum == gl::NONE {
+        continue;
+      }
+
+      let mut object_type = 0;
+      gl::GetFramebufferAttachmentParameteriv(
+        gl::FRAMEBUFFER,
+        draw_buffer as GLenum,
+        gl::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE,
+        &mut object_type,
+      );
+
+      if object_type as GLenum == gl::NONE {
+        return Some(from_gl_attachment(draw_buffer as GLenum));
+      }
+    }
+
+    None
+  }
+}
+
 impl<D> Drop for Framebuffer<D>
 where
   D: Dimensionable,
 {
   fn drop(&mut self) {
     unsafe {
-      if let Some(renderbuffer) = self.renderbuffer {
-        gl::DeleteRenderbuffers(1, &renderbuffer);
+      if !self.owned_textures.is_empty() {
+        gl::DeleteTextures(
+          self.owned_textures.len() as GLsizei,
+          self.owned_textures.as_ptr(),
+        );
+      }
+
+      if !self.renderbuffers.is_empty() {
+        gl::DeleteRenderbuffers(
+          self.renderbuffers.len() as GLsizei,
+          self.renderbuffers.as_ptr(),
+        );
         gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
       }
 
@@ -62,7 +204,9 @@ where
     let mut handle: GLuint = 0;
     let color_formats = CS::color_formats();
     let depth_format = DS::depth_format();
-    let mut depth_renderbuffer: Option<GLuint> = None;
+    let mut renderbuffers = Vec::new();
+
+    check_color_attachments(&mut self.state.borrow_mut(), color_formats.len())?;
 
     gl::GenFramebuffers(1, &mut handle);
 
@@ -88,8 +232,19 @@ where
       gl::DrawBuffers(color_buf_nb, color_buffers.as_ptr());
     }
 
-    // depth texture
-    if depth_format.is_none() {
+    // default depth buffer; layered framebuffers get a layered depth texture, so that all their layers can be rendered
+    // to at once
+    let mut attachments = Vec::new();
+    let mut owned_textures = Vec::new();
+
+    if let (None, Some((height, layers))) = (depth_format, default_depth_layers::<D>(size)) {
+      let texture =
+        new_layered_depth_texture(&mut self.state.borrow_mut(), D::width(size), height, layers);
+      gl::FramebufferTexture(gl::FRAMEBUFFER, gl::DEPTH_ATTACHMENT, texture, 0);
+
+      attachments.push((gl::DEPTH_ATTACHMENT, texture, gl::TEXTURE_2D_ARRAY));
+      owned_textures.push(texture);
+    } else if depth_format.is_none() {
       let mut renderbuffer: GLuint = 0;
 
       gl::GenRenderbuffers(1, &mut renderbuffer);
@@ -109,13 +264,19 @@ where
         renderbuffer,
       );
 
-      depth_renderbuffer = Some(renderbuffer);
+      renderbuffers.push(renderbuffer);
     }
 
     let framebuffer = Framebuffer {
       handle,
-      renderbuffer: depth_renderbuffer,
+      renderbuffers,
       size,
+      attachments,
+      layer: None,
+      level: 0,
+      depth_attachment: depth_attachment(depth_format),
+      owned_textures,
+      multisampled: false,
       state: self.state.clone(),
     };
 
@@ -123,25 +284,40 @@ where
   }
 
   unsafe fn attach_color_texture(
-    _: &mut Self::FramebufferRepr,
+    framebuffer: &mut Self::FramebufferRepr,
     texture: &Self::TextureRepr,
     attachment_index: usize,
   ) -> Result<(), FramebufferError> {
-    gl::FramebufferTexture(
-      gl::FRAMEBUFFER,
-      gl::COLOR_ATTACHMENT0 + attachment_index as GLenum,
+    let attachment = gl::COLOR_ATTACHMENT0 + attachment_index as GLenum;
+    attach_texture(
+      attachment,
       texture.handle,
-      0,
-    );
+      texture.target,
+      framebuffer.layer,
+      framebuffer.level,
+    )?;
+    framebuffer
+      .attachments
+      .push((attachment, texture.handle, texture.target));
 
     Ok(())
   }
 
   unsafe fn attach_depth_texture(
-    _: &mut Self::FramebufferRepr,
+    framebuffer: &mut Self::FramebufferRepr,
     texture: &Self::TextureRepr,
   ) -> Result<(), FramebufferError> {
-    gl::FramebufferTexture(gl::FRAMEBUFFER, gl::DEPTH_ATTACHMENT, texture.handle, 0);
+    let attachment = framebuffer.depth_attachment;
+    attach_texture(
+      attachment,
+      texture.handle,
+      texture.target,
+      framebuffer.layer,
+      framebuffer.level,
+    )?;
+    framebuffer
+      .attachments
+      .push((attachment, texture.handle, texture.target));
 
     Ok(())
   }
@@ -149,33 +325,308 @@ where
   unsafe fn validate_framebuffer(
     framebuffer: Self::FramebufferRepr,
   ) -> Result<Self::FramebufferRepr, FramebufferError> {
-    get_framebuffer_status()
+    framebuffer
+      .status(framebuffer.layer)
       .map(move |_| framebuffer)
       .map_err(FramebufferError::from)
   }
 
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size {
-    framebuffer.size
+    framebuffer.level_size()
   }
 }
 
+/// Attach the `level` mipmap level of a texture to the currently bound framebuffer, with either a single layer or all
+/// of them.
+///
+/// Renderbuffers, which have a single layer and level, are attached as well.
+unsafe fn attach_texture(
+  attachment: GLenum,
+  handle: GLuint,
+  target: GLenum,
+  layer: Option<u32>,
+  level: usize,
+) -> Result<(), FramebufferError> {
+  let level = level as GLint;
+
+  match (target, layer) {
+    (gl::RENDERBUFFER, _) => {
+      gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, attachment, gl::RENDERBUFFER, handle)
+    }
+
+    (_, None) => gl::FramebufferTexture(gl::FRAMEBUFFER, attachment, handle, level),
+
+    (gl::TEXTURE_3D | gl::TEXTURE_1D_ARRAY | gl::TEXTURE_2D_ARRAY, Some(layer)) => {
+      gl::FramebufferTextureLayer(gl::FRAMEBUFFER, attachment, handle, level, layer as GLint)
+    }
+
+    // faces of cubemaps are attached as 2D textures
+    (gl::TEXTURE_CUBE_MAP, Some(face)) => gl::FramebufferTexture2D(
+      gl::FRAMEBUFFER,
+      attachment,
+      gl::TEXTURE_CUBE_MAP_POSITIVE_X + face,
+      handle,
+      level,
+    ),
+
+    _ => return Err(FramebufferError::unsupported_attachment()),
+  }
+
+  Ok(())
+}
+
 fn get_framebuffer_status() -> Result<(), IncompleteReason> {
   let status = unsafe { gl::CheckFramebufferStatus(gl::FRAMEBUFFER) };
 
   match status {
     gl::FRAMEBUFFER_COMPLETE => Ok(()),
     gl::FRAMEBUFFER_UNDEFINED => Err(IncompleteReason::Undefined),
-    gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => Err(IncompleteReason::IncompleteAttachment),
+    gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => Err(IncompleteReason::IncompleteAttachment(None)),
     gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => Err(IncompleteReason::MissingAttachment),
-    gl::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => Err(IncompleteReason::IncompleteDrawBuffer),
+    gl::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => Err(IncompleteReason::IncompleteDrawBuffer(None)),
     gl::FRAMEBUFFER_INCOMPLETE_READ_BUFFER => Err(IncompleteReason::IncompleteReadBuffer),
     gl::FRAMEBUFFER_UNSUPPORTED => Err(IncompleteReason::Unsupported),
     gl::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => Err(IncompleteReason::IncompleteMultisample),
     gl::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => Err(IncompleteReason::IncompleteLayerTargets),
-    _ => panic!(
-      "unknown OpenGL framebuffer incomplete status! status={}",
-      status
-    ),
+    _ => Err(IncompleteReason::Unknown(status)),
+  }
+}
+
+/// Attachment of an OpenGL attachment point.
+fn from_gl_attachment(attachment: GLenum) -> Attachment {
+  match attachment {
+    gl::DEPTH_ATTACHMENT => Attachment::Depth,
+    gl::STENCIL_ATTACHMENT => Attachment::Stencil,
+    gl::DEPTH_STENCIL_ATTACHMENT => Attachment::DepthStencil,
+    _ => Attachment::Color((attachment - gl::COLOR_ATTACHMENT0) as usize),
+  }
+}
+
+unsafe impl<D> FramebufferLayer<D> for GL33
+where
+  D: Dimensionable,
+{
+  unsafe fn select_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    layer: Option<u32>,
+  ) -> Result<(), FramebufferError> {
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
+    let result = framebuffer
+      .attach_layer(layer)
+      .and_then(|_| framebuffer.status(layer).map_err(FramebufferError::from));
+
+    match result {
+      Ok(_) => framebuffer.layer = layer,
+      Err(_) => {
+        // the previous layer was attached successfully, so this cannot fail
+        let _ = framebuffer.attach_layer(framebuffer.layer);
+      }
+    }
+
+    result
+  }
+}
+
+unsafe impl<D> FramebufferLevel<D> for GL33
+where
+  D: Dimensionable,
+{
+  unsafe fn select_level(
+    framebuffer: &mut Self::FramebufferRepr,
+    level: usize,
+  ) -> Result<(), FramebufferError> {
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
+    let previous_level = mem::replace(&mut framebuffer.level, level);
+
+    // 3D textures have fewer layers in higher mipmap levels; all the layers are attached if the selected one is gone
+    let layer = match (
+      framebuffer.layer,
+      default_depth_layers::<D>(framebuffer.level_size()),
+    ) {
+      (Some(layer), Some((_, layers))) if layer >= layers => None,
+      (layer, _) => layer,
+    };
+
+    let result = framebuffer
+      .attach_layer(layer)
+      .and_then(|_| framebuffer.status(layer).map_err(FramebufferError::from));
+
+    match result {
+      Ok(_) => framebuffer.layer = layer,
+      Err(_) => {
+        // the previous level was attached successfully, so this cannot fail
+        framebuffer.level = previous_level;
+        let _ = framebuffer.attach_layer(framebuffer.layer);
+      }
+    }
+
+    result
+  }
+}
+
+unsafe impl<D> FramebufferResize<D> for GL33
+where
+  D: Dimensionable,
+{
+  unsafe fn resize_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    size: D::Size,
+  ) -> Result<(), FramebufferError> {
+    framebuffer.size = size;
+
+    // the back buffer is resized with the window
+    if framebuffer.handle == 0 {
+      return Ok(());
+    }
+
+    let width = D::width(size) as GLsizei;
+    let height = D::height(size) as GLsizei;
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle);
+
+    // renderbuffers keep their format and number of samples
+    for &renderbuffer in &framebuffer.renderbuffers {
+      let mut iformat: GLint = 0;
+      let mut samples: GLint = 0;
+
+      gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+      gl::GetRenderbufferParameteriv(
+        gl::RENDERBUFFER,
+        gl::RENDERBUFFER_INTERNAL_FORMAT,
+        &mut iformat,
+      );
+      gl::GetRenderbufferParameteriv(gl::RENDERBUFFER, gl::RENDERBUFFER_SAMPLES, &mut samples);
+      gl::RenderbufferStorageMultisample(
+        gl::RENDERBUFFER,
+        samples,
+        iformat as GLenum,
+        width,
+        height,
+      );
+    }
+
+    gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+
+    // owned textures are either multisample storage or the default layered depth buffer
+    let layers = default_depth_layers::<D>(size);
+
+    for &(_, handle, target) in &framebuffer.attachments {
+      if !framebuffer.owned_textures.contains(&handle) {
+        continue;
+      }
+
+      state.bind_texture(target, handle);
+
+      match (target, layers) {
+        (gl::TEXTURE_2D_MULTISAMPLE, _) => {
+          let mut iformat: GLint = 0;
+          let mut samples: GLint = 0;
+
+          gl::GetTexLevelParameteriv(target, 0, gl::TEXTURE_INTERNAL_FORMAT, &mut iformat);
+          gl::GetTexLevelParameteriv(target, 0, gl::TEXTURE_SAMPLES, &mut samples);
+          gl::TexImage2DMultisample(target, samples, iformat as GLenum, width, height, gl::TRUE);
+        }
+
+        (_, Some((layer_height, layers))) => gl::TexImage3D(
+          target,
+          0,
+          gl::DEPTH_COMPONENT32F as GLint,
+          width,
+          layer_height as GLsizei,
+          layers as GLsizei,
+          0,
+          gl::DEPTH_COMPONENT,
+          gl::FLOAT,
+          ptr::null(),
+        ),
+
+        _ => return Err(FramebufferError::unsupported_attachment()),
+      }
+
+      state.bind_texture(target, 0);
+    }
+
+    drop(state);
+
+    // textures of the slots are attached again once resized
+    let owned_textures = &framebuffer.owned_textures;
+    framebuffer.attachments.retain(|&(_, handle, target)| {
+      target == gl::RENDERBUFFER || owned_textures.contains(&handle)
+    });
+
+    // 3D textures have fewer layers in higher mipmap levels
+    let level_layers = default_depth_layers::<D>(framebuffer.level_size());
+
+    if let (Some(layer), Some((_, layers))) = (framebuffer.layer, level_layers) {
+      if layer >= layers {
+        framebuffer.layer = None;
+        framebuffer.attach_layer(None)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  unsafe fn validate_resized_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    if framebuffer.handle == 0 {
+      return Ok(());
+    }
+
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle);
+
+    framebuffer
+      .status(framebuffer.layer)
+      .map_err(FramebufferError::from)
+  }
+}
+
+unsafe impl FramebufferRenderbuffer for GL33 {
+  unsafe fn attach_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    pixel_format: PixelFormat,
+    attachment_index: Option<usize>,
+  ) -> Result<(), FramebufferError> {
+    let (_, iformat, _) =
+      opengl_pixel_format(pixel_format).ok_or(FramebufferError::UnsupportedAttachment)?;
+    let attachment = match attachment_index {
+      Some(index) => gl::COLOR_ATTACHMENT0 + index as GLenum,
+      None => framebuffer.depth_attachment,
+    };
+    let [width, height] = framebuffer.size;
+    let mut renderbuffer: GLuint = 0;
+
+    gl::GenRenderbuffers(1, &mut renderbuffer);
+    gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+    gl::RenderbufferStorage(
+      gl::RENDERBUFFER,
+      iformat,
+      width as GLsizei,
+      height as GLsizei,
+    );
+    gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+
+    // the renderbuffer is owned by the framebuffer as soon as it is created, so that it is deleted on error
+    framebuffer.renderbuffers.push(renderbuffer);
+
+    attach_texture(attachment, renderbuffer, gl::RENDERBUFFER, None, 0)?;
+    framebuffer
+      .attachments
+      .push((attachment, renderbuffer, gl::RENDERBUFFER));
+
+    Ok(())
   }
 }
 
@@ -186,9 +637,531 @@ unsafe impl FramebufferBackBuffer for GL33 {
   ) -> Result<Self::FramebufferRepr, FramebufferError> {
     Ok(Framebuffer {
       handle: 0,
-      renderbuffer: None,
+      renderbuffers: Vec::new(),
+      size,
+      attachments: Vec::new(),
+      layer: None,
+      level: 0,
+      depth_attachment: gl::DEPTH_ATTACHMENT,
+      owned_textures: Vec::new(),
+      multisampled: false,
+      state: self.state.clone(),
+    })
+  }
+}
+
+unsafe impl FramebufferMultisample for GL33 {
+  unsafe fn new_multisample_framebuffer<CS, DS>(
+    &mut self,
+    size: <Dim2 as Dimensionable>::Size,
+    samples: usize,
+  ) -> Result<Self::FramebufferRepr, FramebufferError>
+  where
+    CS: ColorSlot<Self, Dim2>,
+    DS: DepthStencilSlot<Self, Dim2>,
+  {
+    let mut max_samples: GLint = 0;
+    gl::GetIntegerv(gl::MAX_SAMPLES, &mut max_samples);
+
+    if samples == 0 || samples > max_samples as usize {
+      return Err(FramebufferError::unsupported_sample_count(samples));
+    }
+
+    let color_formats = CS::color_formats();
+    let depth_format = DS::depth_format();
+    let [width, height] = size;
+    let mut handle: GLuint = 0;
+
+    let mut state = self.state.borrow_mut();
+    check_color_attachments(&mut state, color_formats.len())?;
+
+    gl::GenFramebuffers(1, &mut handle);
+
+    state.bind_draw_framebuffer(handle);
+
+    // color textures
+    let mut attachments = Vec::with_capacity(color_formats.len());
+    let mut multisample_textures = Vec::with_capacity(color_formats.len());
+
+    for (i, &pf) in color_formats.iter().enumerate() {
+      let (_, iformat, _) =
+        opengl_pixel_format(pf).ok_or(FramebufferError::UnsupportedAttachment)?;
+      let attachment = gl::COLOR_ATTACHMENT0 + i as GLenum;
+      let texture = state.create_texture();
+
+      state.bind_texture(gl::TEXTURE_2D_MULTISAMPLE, texture);
+      gl::TexImage2DMultisample(
+        gl::TEXTURE_2D_MULTISAMPLE,
+        samples as GLsizei,
+        iformat,
+        width as GLsizei,
+        height as GLsizei,
+        gl::TRUE,
+      );
+      state.bind_texture(gl::TEXTURE_2D_MULTISAMPLE, 0);
+
+      gl::FramebufferTexture2D(
+        gl::FRAMEBUFFER,
+        attachment,
+        gl::TEXTURE_2D_MULTISAMPLE,
+        texture,
+        0,
+      );
+
+      attachments.push((attachment, texture, gl::TEXTURE_2D_MULTISAMPLE));
+      multisample_textures.push(texture);
+    }
+
+    if attachments.is_empty() {
+      gl::DrawBuffer(gl::NONE);
+    } else {
+      let color_buffers: Vec<_> = attachments.iter().map(|&(a, _, _)| a).collect();
+      gl::DrawBuffers(color_buffers.len() as GLsizei, color_buffers.as_ptr());
+    }
+
+    // depth renderbuffer; a default depth buffer is allocated if the depth slot is ()
+    let depth_iformat = match depth_format {
+      Some(pf) => {
+        opengl_pixel_format(pf)
+          .ok_or(FramebufferError::UnsupportedAttachment)?
+          .1
+      }
+      None => gl::DEPTH_COMPONENT32F,
+    };
+    let depth_attachment = depth_attachment(depth_format);
+    let mut renderbuffer: GLuint = 0;
+
+    gl::GenRenderbuffers(1, &mut renderbuffer);
+    gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+    gl::RenderbufferStorageMultisample(
+      gl::RENDERBUFFER,
+      samples as GLsizei,
+      depth_iformat,
+      width as GLsizei,
+      height as GLsizei,
+    );
+    gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+
+    gl::FramebufferRenderbuffer(
+      gl::FRAMEBUFFER,
+      depth_attachment,
+      gl::RENDERBUFFER,
+      renderbuffer,
+    );
+
+    drop(state);
+
+    let framebuffer = Framebuffer {
+      handle,
+      renderbuffers: vec![renderbuffer],
       size,
+      attachments,
+      layer: None,
+      level: 0,
+      depth_attachment,
+      owned_textures: multisample_textures,
+      multisampled: true,
       state: self.state.clone(),
+    };
+
+    <Self as FramebufferBackend<Dim2>>::validate_framebuffer(framebuffer)
+  }
+
+  unsafe fn resolve(
+    framebuffer: &Self::FramebufferRepr,
+    target: &mut Self::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    let region = Region::whole(framebuffer.size);
+    blit_framebuffer(
+      framebuffer,
+      target,
+      region,
+      region,
+      BlitMask::COLOR,
+      MagFilter::Nearest,
+    )
+  }
+}
+
+unsafe impl FramebufferBlit for GL33 {
+  unsafe fn blit(
+    framebuffer: &Self::FramebufferRepr,
+    target: &mut Self::FramebufferRepr,
+    src: Region,
+    dst: Region,
+    mask: BlitMask,
+    filter: MagFilter,
+  ) -> Result<(), FramebufferError> {
+    blit_framebuffer(framebuffer, target, src, dst, mask, filter)
+  }
+}
+
+unsafe impl FramebufferRead for GL33 {
+  unsafe fn read_pixels<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment: Option<usize>,
+    region: Region,
+  ) -> Result<Vec<P::Encoding>, FramebufferError>
+  where
+    P: Pixel,
+    P::Encoding: Copy + Default,
+  {
+    let mut pixels = vec![P::Encoding::default(); region.width as usize * region.height as usize];
+    read_pixels::<P>(
+      framebuffer,
+      attachment,
+      region,
+      pixels.as_mut_ptr() as *mut c_void,
+    )?;
+
+    Ok(pixels)
+  }
+}
+
+pub struct PixelReadback {
+  handle: GLuint,
+  bytes: usize,
+  fence: UploadFence,
+}
+
+impl Drop for PixelReadback {
+  fn drop(&mut self) {
+    unsafe { gl::DeleteBuffers(1, &self.handle) };
+  }
+}
+
+unsafe impl FramebufferReadback for GL33 {
+  type PixelReadbackRepr = PixelReadback;
+
+  unsafe fn read_pixels_async<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment: Option<usize>,
+    region: Region,
+  ) -> Result<Self::PixelReadbackRepr, FramebufferError>
+  where
+    P: Pixel,
+  {
+    let bytes = region.width as usize * region.height as usize * mem::size_of::<P::Encoding>();
+    let mut handle: GLuint = 0;
+
+    gl::GenBuffers(1, &mut handle);
+
+    if handle == 0 {
+      return Err(FramebufferError::cannot_read("cannot generate buffer name"));
+    }
+
+    // the pixel pack buffer binding is not cached, and must be left unbound for regular reads
+    gl::BindBuffer(gl::PIXEL_PACK_BUFFER, handle);
+    gl::BufferData(
+      gl::PIXEL_PACK_BUFFER,
+      bytes as GLsizeiptr,
+      ptr::null(),
+      gl::STREAM_READ,
+    );
+
+    // with a pixel pack buffer bound, pixels are written at an offset in the buffer instead of in memory
+    let read = read_pixels::<P>(framebuffer, attachment, region, ptr::null_mut());
+    gl::BindBuffer(gl::PIXEL_PACK_BUFFER, 0);
+
+    if let Err(e) = read {
+      gl::DeleteBuffers(1, &handle);
+      return Err(e);
+    }
+
+    let sync = gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+    Ok(PixelReadback {
+      handle,
+      bytes,
+      fence: UploadFence { sync },
     })
   }
+
+  unsafe fn is_readback_ready(readback: &Self::PixelReadbackRepr) -> bool {
+    <Self as UploadFenceBackend>::is_upload_complete(&readback.fence)
+  }
+
+  unsafe fn wait_readback(readback: &Self::PixelReadbackRepr, timeout: Duration) -> bool {
+    <Self as UploadFenceBackend>::wait_upload(&readback.fence, timeout)
+  }
+
+  unsafe fn readback_pixels<T>(
+    readback: Self::PixelReadbackRepr,
+  ) -> Result<Vec<T>, FramebufferError>
+  where
+    T: Copy + Default,
+  {
+    let mut pixels = vec![T::default(); readback.bytes / mem::size_of::<T>()];
+
+    // reading the buffer waits for the copy to be complete
+    gl::BindBuffer(gl::PIXEL_PACK_BUFFER, readback.handle);
+    gl::GetBufferSubData(
+      gl::PIXEL_PACK_BUFFER,
+      0,
+      readback.bytes as GLsizeiptr,
+      pixels.as_mut_ptr() as *mut c_void,
+    );
+    gl::BindBuffer(gl::PIXEL_PACK_BUFFER, 0);
+
+    Ok(pixels)
+  }
+}
+
+/// Read the pixels of the `region` of an attachment of `framebuffer` to `pixels`.
+///
+/// `pixels` is an offset in the bound pixel pack buffer, if any.
+unsafe fn read_pixels<P>(
+  framebuffer: &Framebuffer<Dim2>,
+  attachment: Option<usize>,
+  region: Region,
+  pixels: *mut c_void,
+) -> Result<(), FramebufferError>
+where
+  P: Pixel,
+{
+  if framebuffer.multisampled {
+    return Err(FramebufferError::cannot_read(
+      "multisampled framebuffers must be resolved before being read",
+    ));
+  }
+
+  let pf = P::pixel_format();
+  let (format, ty) = match pf.format {
+    // only the depth of 32-bit floating depth/stencil pixels is read
+    Format::DepthStencil(Size::ThirtyTwo, _) => (gl::DEPTH_COMPONENT, gl::FLOAT),
+    _ => {
+      let (format, _, ty) = opengl_pixel_format(pf).ok_or_else(|| {
+        FramebufferError::cannot_read(format!("unsupported pixel format {:?}", pf))
+      })?;
+      (format, ty)
+    }
+  };
+
+  let read_buffer = match attachment {
+    Some(_) if framebuffer.handle == 0 => gl::BACK,
+    Some(index) if index < framebuffer.color_attachments_len() => {
+      gl::COLOR_ATTACHMENT0 + index as GLenum
+    }
+    Some(index) => {
+      return Err(FramebufferError::cannot_read(format!(
+        "no color attachment at index {}",
+        index
+      )))
+    }
+    None => gl::NONE,
+  };
+
+  if region.width == 0 || region.height == 0 {
+    return Ok(());
+  }
+
+  gl::BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffer.handle);
+
+  // depth is read regardless of the read buffer
+  if read_buffer != gl::NONE {
+    gl::ReadBuffer(read_buffer);
+  }
+
+  // set the packing alignment based on the number of bytes to skip, so that rows are tightly packed
+  let skip_bytes = (region.width as usize * mem::size_of::<P::Encoding>()) % 8;
+  set_pack_alignment(skip_bytes);
+
+  gl::ReadPixels(
+    region.x as GLint,
+    region.y as GLint,
+    region.width as GLsizei,
+    region.height as GLsizei,
+    format,
+    ty,
+    pixels,
+  );
+
+  gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+
+  Ok(())
+}
+
+/// Blit the `src` region of `framebuffer` into the `dst` region of `target`.
+unsafe fn blit_framebuffer(
+  framebuffer: &Framebuffer<Dim2>,
+  target: &mut Framebuffer<Dim2>,
+  src: Region,
+  dst: Region,
+  mask: BlitMask,
+  filter: MagFilter,
+) -> Result<(), FramebufferError> {
+  if framebuffer.multisampled && (src.width, src.height) != (dst.width, dst.height) {
+    return Err(FramebufferError::cannot_blit(
+      "multisampled framebuffers can only be blitted without scaling",
+    ));
+  }
+
+  let [src_x0, src_y0, src_x1, src_y1] =
+    [src.x, src.y, src.x + src.width, src.y + src.height].map(|c| c as GLint);
+  let [dst_x0, dst_y0, dst_x1, dst_y1] =
+    [dst.x, dst.y, dst.x + dst.width, dst.y + dst.height].map(|c| c as GLint);
+  let filter = match filter {
+    MagFilter::Nearest => gl::NEAREST,
+    MagFilter::Linear => gl::LINEAR,
+  };
+
+  // the back buffer has a single color buffer
+  let color_buffers_len = |framebuffer: &Framebuffer<Dim2>| {
+    if framebuffer.handle == 0 {
+      1
+    } else {
+      framebuffer.color_attachments_len()
+    }
+  };
+  let target_colors = color_buffers_len(target);
+  let colors = if mask.color {
+    color_buffers_len(framebuffer).min(target_colors)
+  } else {
+    0
+  };
+
+  let mut state = target.state.borrow_mut();
+
+  // blits are affected by the scissor test
+  state.set_scissor_state(ScissorState::Off);
+  state.bind_draw_framebuffer(target.handle);
+  gl::BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffer.handle);
+
+  for i in 0..colors {
+    let attachment = gl::COLOR_ATTACHMENT0 + i as GLenum;
+
+    // color attachments are not valid buffers of the back buffer
+    if framebuffer.handle == 0 {
+      gl::ReadBuffer(gl::BACK);
+    } else {
+      gl::ReadBuffer(attachment);
+    }
+
+    if target.handle == 0 {
+      gl::DrawBuffer(gl::BACK);
+    } else {
+      // draw only to the attachment of the same index
+      let draw_buffers: Vec<_> = (0..=i)
+        .map(|j| if j == i { attachment } else { gl::NONE })
+        .collect();
+      gl::DrawBuffers(draw_buffers.len() as GLsizei, draw_buffers.as_ptr());
+    }
+
+    gl::BlitFramebuffer(
+      src_x0,
+      src_y0,
+      src_x1,
+      src_y1,
+      dst_x0,
+      dst_y0,
+      dst_x1,
+      dst_y1,
+      gl::COLOR_BUFFER_BIT,
+      filter,
+    );
+  }
+
+  // restore the draw buffers of the target
+  if colors > 0 && target.handle != 0 {
+    let color_buffers: Vec<_> =
+      (gl::COLOR_ATTACHMENT0..gl::COLOR_ATTACHMENT0 + target_colors as GLenum).collect();
+    gl::DrawBuffers(color_buffers.len() as GLsizei, color_buffers.as_ptr());
+  }
+
+  // depth and stencil are blitted at once
+  let mut depth_stencil_bits = 0;
+
+  if mask.depth {
+    depth_stencil_bits |= gl::DEPTH_BUFFER_BIT;
+  }
+
+  if mask.stencil {
+    depth_stencil_bits |= gl::STENCIL_BUFFER_BIT;
+  }
+
+  if depth_stencil_bits != 0 {
+    gl::BlitFramebuffer(
+      src_x0,
+      src_y0,
+      src_x1,
+      src_y1,
+      dst_x0,
+      dst_y0,
+      dst_x1,
+      dst_y1,
+      depth_stencil_bits,
+      gl::NEAREST,
+    );
+  }
+
+  gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+
+  Ok(())
+}
+
+/// Height and number of layers of the default depth buffer of layered framebuffers; `None` if not layered.
+fn default_depth_layers<D>(size: D::Size) -> Option<(u32, u32)>
+where
+  D: Dimensionable,
+{
+  match D::dim() {
+    Dim::Dim3 | Dim::Dim2Array | Dim::Cubemap => Some((D::height(size), D::depth(size))),
+    Dim::Dim1Array => Some((1, D::height(size))),
+    Dim::Dim1 | Dim::Dim2 => None,
+  }
+}
+
+/// Create a 2D array depth texture, used as the default depth buffer of layered framebuffers.
+///
+/// Depth textures cannot be 3D and only color attachments must share the same target, so a 2D array texture is used
+/// whatever the dimension of the framebuffer.
+unsafe fn new_layered_depth_texture(
+  state: &mut GLState,
+  width: u32,
+  height: u32,
+  layers: u32,
+) -> GLuint {
+  let texture = state.create_texture();
+
+  state.bind_texture(gl::TEXTURE_2D_ARRAY, texture);
+  gl::TexImage3D(
+    gl::TEXTURE_2D_ARRAY,
+    0,
+    gl::DEPTH_COMPONENT32F as GLint,
+    width as GLsizei,
+    height as GLsizei,
+    layers as GLsizei,
+    0,
+    gl::DEPTH_COMPONENT,
+    gl::FLOAT,
+    std::ptr::null(),
+  );
+  gl::TexParameteri(gl::TEXTURE_2D_ARRAY, gl::TEXTURE_MAX_LEVEL, 0);
+  state.bind_texture(gl::TEXTURE_2D_ARRAY, 0);
+
+  texture
+}
+
+/// Check that `count` color attachments can be drawn to.
+fn check_color_attachments(state: &mut GLState, count: usize) -> Result<(), FramebufferError> {
+  let max = state.get_max_color_attachments();
+
+  if count > max {
+    Err(FramebufferError::too_many_color_attachments(count, max))
+  } else {
+    Ok(())
+  }
+}
+
+fn depth_attachment(depth_format: Option<PixelFormat>) -> GLenum {
+  match depth_format {
+    Some(PixelFormat {
+      format: Format::DepthStencil(..),
+      ..
+    }) => gl::DEPTH_STENCIL_ATTACHMENT,
+    Some(PixelFormat {
+      format: Format::Stencil(_),
+      ..
+    }) => gl::STENCIL_ATTACHMENT,
+    _ => gl::DEPTH_ATTACHMENT,
+  }
 }
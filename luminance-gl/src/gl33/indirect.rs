@@ -0,0 +1,157 @@
+//! Indirect buffers and indirect renders.
+
+use crate::gl33::{state::GLState, GL33};
+use gl::{self, types::*};
+#[cfg(feature = "GL_ARB_multi_draw_indirect")]
+use luminance::backend::tess_gate::TessGateIndirect;
+use luminance::{
+  backend::{indirect::IndirectBuffer as IndirectBufferBackend, tess_gate::TessGateIndirectAt},
+  indirect::{IndirectBufferError, IndirectCommand},
+  tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData},
+};
+use std::{cell::RefCell, mem, rc::Rc};
+
+pub struct IndirectBuffer {
+  handle: GLuint,
+  state: Rc<RefCell<GLState>>,
+}
+
+/// Run `f` with `handle` bound as the draw indirect buffer, and unbind it afterwards.
+///
+/// The draw indirect buffer binding is not cached, and only used by indirect renders.
+pub(crate) unsafe fn with_draw_indirect_buffer<R>(handle: GLuint, f: impl FnOnce() -> R) -> R {
+  gl::BindBuffer(gl::DRAW_INDIRECT_BUFFER, handle);
+  let r = f();
+  gl::BindBuffer(gl::DRAW_INDIRECT_BUFFER, 0);
+  r
+}
+
+impl Drop for IndirectBuffer {
+  fn drop(&mut self) {
+    unsafe {
+      self.state.borrow_mut().unbind_buffer(self.handle);
+      gl::DeleteBuffers(1, &self.handle);
+    }
+  }
+}
+
+unsafe impl<C> IndirectBufferBackend<C> for GL33
+where
+  C: IndirectCommand,
+{
+  type IndirectBufferRepr = IndirectBuffer;
+
+  unsafe fn new_indirect_buffer(
+    &mut self,
+    commands: &[C],
+  ) -> Result<Self::IndirectBufferRepr, IndirectBufferError> {
+    let mut handle: GLuint = 0;
+
+    gl::GenBuffers(1, &mut handle);
+
+    if handle == 0 {
+      return Err(IndirectBufferError::cannot_create(
+        "cannot generate buffer name",
+      ));
+    }
+
+    with_draw_indirect_buffer(handle, || {
+      gl::BufferData(
+        gl::DRAW_INDIRECT_BUFFER,
+        mem::size_of_val(commands) as GLsizeiptr,
+        commands.as_ptr() as _,
+        gl::DYNAMIC_DRAW,
+      )
+    });
+
+    Ok(IndirectBuffer {
+      handle,
+      state: self.state.clone(),
+    })
+  }
+
+  unsafe fn upload_indirect_commands(
+    buffer: &mut Self::IndirectBufferRepr,
+    offset: usize,
+    commands: &[C],
+  ) -> Result<(), IndirectBufferError> {
+    with_draw_indirect_buffer(buffer.handle, || {
+      gl::BufferSubData(
+        gl::DRAW_INDIRECT_BUFFER,
+        (offset * mem::size_of::<C>()) as GLintptr,
+        mem::size_of_val(commands) as GLsizeiptr,
+        commands.as_ptr() as _,
+      )
+    });
+
+    Ok(())
+  }
+}
+
+#[cfg(feature = "GL_ARB_multi_draw_indirect")]
+unsafe impl<V, I, W> TessGateIndirect<V, I, W, Interleaved> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn render_indirect(
+    &mut self,
+    tess: &Self::TessRepr,
+    commands: &Self::IndirectBufferRepr,
+    len: usize,
+  ) {
+    tess.render_indirect(commands.handle, len);
+  }
+}
+
+#[cfg(feature = "GL_ARB_multi_draw_indirect")]
+unsafe impl<V, I, W> TessGateIndirect<V, I, W, Deinterleaved> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn render_indirect(
+    &mut self,
+    tess: &Self::TessRepr,
+    commands: &Self::IndirectBufferRepr,
+    len: usize,
+  ) {
+    tess.render_indirect(commands.handle, len);
+  }
+}
+
+unsafe impl<V, I, W, C> TessGateIndirectAt<V, I, W, Interleaved, C> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+  C: IndirectCommand,
+{
+  unsafe fn render_indirect_at(
+    &mut self,
+    tess: &Self::TessRepr,
+    commands: &Self::IndirectBufferRepr,
+    offset: usize,
+  ) {
+    tess.render_indirect_at(commands.handle, offset, C::INDEXED);
+  }
+}
+
+unsafe impl<V, I, W, C> TessGateIndirectAt<V, I, W, Deinterleaved, C> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  C: IndirectCommand,
+{
+  unsafe fn render_indirect_at(
+    &mut self,
+    tess: &Self::TessRepr,
+    commands: &Self::IndirectBufferRepr,
+    offset: usize,
+  ) {
+    tess.render_indirect_at(commands.handle, offset, C::INDEXED);
+  }
+}
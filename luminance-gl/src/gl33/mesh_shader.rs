@@ -0,0 +1,42 @@
+//! `GL_NV_mesh_shader` functions and constants.
+//!
+//! Those are not part of the [`gl`] crate, so the functions are loaded by [`load_with`](super::load_with).
+
+#![allow(non_snake_case)]
+
+use gl::types::{GLbitfield, GLenum, GLuint};
+use std::{
+  mem,
+  os::raw::c_void,
+  ptr::null_mut,
+  sync::atomic::{AtomicPtr, Ordering},
+};
+
+pub(crate) const MESH_SHADER_NV: GLenum = 0x9559;
+pub(crate) const TASK_SHADER_NV: GLenum = 0x955A;
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+pub(crate) const MESH_SHADER_BIT_NV: GLbitfield = 0x40;
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+pub(crate) const TASK_SHADER_BIT_NV: GLbitfield = 0x80;
+
+static DRAW_MESH_TASKS: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+
+pub(crate) fn load_with(loader: &mut dyn FnMut(&'static str) -> *const c_void) {
+  DRAW_MESH_TASKS.store(
+    loader("glDrawMeshTasksNV") as *mut c_void,
+    Ordering::Relaxed,
+  );
+}
+
+/// Whether all the functions were loaded.
+pub(crate) fn is_loaded() -> bool {
+  !DRAW_MESH_TASKS.load(Ordering::Relaxed).is_null()
+}
+
+// the functions below must only be called once is_loaded() returned true
+
+pub(crate) unsafe fn DrawMeshTasksNV(first: GLuint, count: GLuint) {
+  let f: extern "system" fn(GLuint, GLuint) =
+    mem::transmute(DRAW_MESH_TASKS.load(Ordering::Relaxed));
+  f(first, count)
+}
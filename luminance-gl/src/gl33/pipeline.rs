@@ -1,9 +1,28 @@
 use super::buffer::Buffer;
+#[cfg(feature = "GL_NV_mesh_shader")]
+use crate::gl33::mesh_shader;
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+use crate::gl33::pixel::opengl_pixel_format;
 use crate::gl33::{
+  shader::{active_vertex_attribs, geometry_config, has_tessellation_stages},
   state::{BlendingState, DepthTest, FaceCullingState, GLState, ScissorState},
   GL33,
 };
 use gl::types::*;
+#[cfg(feature = "GL_ARB_shader_atomic_counters")]
+use luminance::backend::pipeline::PipelineAtomicCounters;
+#[cfg(feature = "GL_NV_mesh_shader")]
+use luminance::backend::render_gate::RenderGateMesh;
+#[cfg(feature = "GL_ARB_compute_shader")]
+use luminance::backend::shading_gate::ShadingGateCompute;
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+use luminance::backend::shading_gate::ShadingGateSeparable;
+#[cfg(feature = "GL_ARB_base_instance")]
+use luminance::backend::tess_gate::TessGateBaseInstance;
+#[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+use luminance::backend::{pipeline::PipelineShaderStorage, shader::ShaderStorage};
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+use luminance::{backend::pipeline::PipelineImage, pipeline::ImageAccess, texture::Dim};
 use luminance::{
   backend::{
     pipeline::{Pipeline as PipelineBackend, PipelineBase, PipelineShaderData, PipelineTexture},
@@ -11,12 +30,14 @@ use luminance::{
     shader::ShaderData,
     shading_gate::ShadingGate,
     tess::Tess,
-    tess_gate::TessGate,
+    tess_gate::{TessGate, TessGateBaseVertex, TessGateValidation},
   },
   blending::BlendingMode,
-  pipeline::{PipelineError, PipelineState, Viewport},
+  color_mask::ColorMask,
+  pipeline::{ClearColor, PipelineError, PipelineState, Viewport},
   pixel::Pixel,
   render_state::RenderState,
+  shader::{ActiveVertexAttrib, GeometryConfig},
   tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData},
   texture::Dimensionable,
 };
@@ -49,6 +70,43 @@ where
   }
 }
 
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+pub struct BoundImage<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  pub(crate) unit: u32,
+  access: ImageAccess,
+  state: Rc<RefCell<GLState>>,
+  _phantom: PhantomData<*const (D, P)>,
+}
+
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+impl<D, P> Drop for BoundImage<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  fn drop(&mut self) {
+    // make the stores visible to whatever comes next (sampling, uploads, rendering, other image accesses)
+    if self.access != ImageAccess::ReadOnly {
+      unsafe {
+        gl::MemoryBarrier(
+          gl::SHADER_IMAGE_ACCESS_BARRIER_BIT
+            | gl::TEXTURE_FETCH_BARRIER_BIT
+            | gl::TEXTURE_UPDATE_BARRIER_BIT
+            | gl::FRAMEBUFFER_BARRIER_BIT,
+        );
+      }
+    }
+
+    // place the binding into the free list
+    let mut state = self.state.borrow_mut();
+    state.binding_stack_mut().free_image_units.push(self.unit);
+  }
+}
+
 pub struct BoundShaderData<T> {
   pub(crate) binding: u32,
   state: Rc<RefCell<GLState>>,
@@ -67,6 +125,31 @@ impl<T> Drop for BoundShaderData<T> {
   }
 }
 
+#[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+pub struct BoundShaderStorage<T> {
+  pub(crate) binding: u32,
+  state: Rc<RefCell<GLState>>,
+  _phantom: PhantomData<*const T>,
+}
+
+#[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+impl<T> Drop for BoundShaderStorage<T> {
+  fn drop(&mut self) {
+    // place the binding into the free list
+    self
+      .state
+      .borrow_mut()
+      .binding_stack_mut()
+      .free_shader_storage
+      .push(self.binding);
+  }
+}
+
+#[cfg(feature = "GL_ARB_shader_atomic_counters")]
+pub struct BoundAtomicCounters {
+  pub(crate) binding: u32,
+}
+
 unsafe impl PipelineBase for GL33 {
   type PipelineRepr = Pipeline;
 
@@ -92,7 +175,7 @@ where
 
     state.bind_draw_framebuffer(framebuffer.handle);
 
-    let size = framebuffer.size;
+    let size = framebuffer.level_size();
 
     match pipeline_state.viewport {
       Viewport::Whole => {
@@ -109,6 +192,11 @@ where
       }
     }
 
+    // color masks left by a previous render state also apply to clears
+    if pipeline_state.clear_color.is_some() || !pipeline_state.clear_color_attachments.is_empty() {
+      state.set_color_masks(ColorMask::ALL, &[]);
+    }
+
     let mut clear_buffer_bits = 0;
     if let Some(clear_color) = pipeline_state.clear_color {
       state.set_clear_color([
@@ -145,7 +233,79 @@ where
       gl::Clear(clear_buffer_bits);
     }
 
+    // the back buffer has a single color buffer
+    let colors = if framebuffer.handle == 0 {
+      1
+    } else {
+      framebuffer.color_attachments_len()
+    };
+
+    for &(index, color) in &pipeline_state.clear_color_attachments {
+      if index >= colors {
+        continue;
+      }
+
+      let draw_buffer = index as GLint;
+
+      match color {
+        ClearColor::Float(color) => gl::ClearBufferfv(gl::COLOR, draw_buffer, color.as_ptr()),
+        ClearColor::Int(color) => gl::ClearBufferiv(gl::COLOR, draw_buffer, color.as_ptr()),
+        ClearColor::UInt(color) => gl::ClearBufferuiv(gl::COLOR, draw_buffer, color.as_ptr()),
+      }
+    }
+
     state.enable_srgb_framebuffer(pipeline_state.srgb_enabled);
+    state.enable_seamless_cubemap(pipeline_state.seamless_cubemap_enabled);
+  }
+
+  unsafe fn end_pipeline(
+    &mut self,
+    framebuffer: &Self::FramebufferRepr,
+    pipeline_state: &PipelineState,
+  ) {
+    let invalidate = &pipeline_state.invalidate_attachments;
+    let mut state = self.state.borrow_mut();
+
+    if invalidate.is_empty() || !state.supports_invalidate_subdata() {
+      return;
+    }
+
+    // the back buffer has a single color buffer and different attachment names
+    let (colors, color_attachment, depth_attachment, stencil_attachment) =
+      if framebuffer.handle == 0 {
+        (1, gl::COLOR, gl::DEPTH, gl::STENCIL)
+      } else {
+        (
+          framebuffer.color_attachments_len(),
+          gl::COLOR_ATTACHMENT0,
+          gl::DEPTH_ATTACHMENT,
+          gl::STENCIL_ATTACHMENT,
+        )
+      };
+
+    let mut attachments: Vec<_> = invalidate
+      .colors
+      .iter()
+      .filter(|&&index| index < colors)
+      .map(|&index| color_attachment + index as GLenum)
+      .collect();
+
+    if invalidate.depth {
+      attachments.push(depth_attachment);
+    }
+
+    if invalidate.stencil {
+      attachments.push(stencil_attachment);
+    }
+
+    if !attachments.is_empty() {
+      state.bind_draw_framebuffer(framebuffer.handle);
+      gl::InvalidateFramebuffer(
+        gl::DRAW_FRAMEBUFFER,
+        attachments.len() as GLsizei,
+        attachments.as_ptr(),
+      );
+    }
   }
 }
 
@@ -188,6 +348,73 @@ where
   }
 }
 
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+unsafe impl<D, P> PipelineImage<D, P> for GL33
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  type BoundImageRepr = BoundImage<D, P>;
+
+  unsafe fn bind_image(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::TextureRepr,
+    level: usize,
+    layer: Option<usize>,
+    access: ImageAccess,
+  ) -> Result<Self::BoundImageRepr, PipelineError> {
+    let pf = P::pixel_format();
+    let iformat = opengl_pixel_format(pf)
+      .map(|(_, iformat, _)| iformat)
+      .filter(|&iformat| is_image_format(iformat))
+      .ok_or_else(|| PipelineError::unsupported_image_format(pf))?;
+
+    let mut state = pipeline.state.borrow_mut();
+    let bstack = state.binding_stack_mut();
+
+    let unit = bstack.free_image_units.pop().unwrap_or_else(|| {
+      // no more free units; reserve one
+      let unit = bstack.next_image_unit;
+      bstack.next_image_unit += 1;
+      unit
+    });
+
+    // layered textures are bound with all their layers, unless a single one is selected
+    let (layered, layer) = match (D::dim(), layer) {
+      (Dim::Dim1 | Dim::Dim2, _) => (gl::FALSE, 0),
+      (_, Some(layer)) => (gl::FALSE, layer as GLint),
+      (_, None) => (gl::TRUE, 0),
+    };
+
+    let gl_access = match access {
+      ImageAccess::ReadOnly => gl::READ_ONLY,
+      ImageAccess::WriteOnly => gl::WRITE_ONLY,
+      ImageAccess::ReadWrite => gl::READ_WRITE,
+    };
+
+    gl::BindImageTexture(
+      unit,
+      texture.handle,
+      level as GLint,
+      layered,
+      layer,
+      gl_access,
+      iformat,
+    );
+
+    Ok(BoundImage {
+      unit,
+      access,
+      state: pipeline.state.clone(),
+      _phantom: PhantomData,
+    })
+  }
+
+  unsafe fn image_binding(bound: &Self::BoundImageRepr) -> u32 {
+    bound.unit
+  }
+}
+
 unsafe impl<T> PipelineShaderData<T> for GL33
 where
   Self: ShaderData<T, ShaderDataRepr = Buffer<<ArrElem<T> as Std140>::Encoded>>,
@@ -223,6 +450,112 @@ where
   }
 }
 
+/// Whether an internal format can be used with image load / store.
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+fn is_image_format(iformat: GLenum) -> bool {
+  matches!(
+    iformat,
+    gl::RGBA32F
+      | gl::RGBA16F
+      | gl::RG32F
+      | gl::RG16F
+      | gl::R11F_G11F_B10F
+      | gl::R32F
+      | gl::R16F
+      | gl::RGBA32UI
+      | gl::RGBA16UI
+      | gl::RGB10_A2UI
+      | gl::RGBA8UI
+      | gl::RG32UI
+      | gl::RG16UI
+      | gl::RG8UI
+      | gl::R32UI
+      | gl::R16UI
+      | gl::R8UI
+      | gl::RGBA32I
+      | gl::RGBA16I
+      | gl::RGBA8I
+      | gl::RG32I
+      | gl::RG16I
+      | gl::RG8I
+      | gl::R32I
+      | gl::R16I
+      | gl::R8I
+      | gl::RGBA16
+      | gl::RGB10_A2
+      | gl::RGBA8
+      | gl::RG16
+      | gl::RG8
+      | gl::R16
+      | gl::R8
+      | gl::RGBA16_SNORM
+      | gl::RGBA8_SNORM
+      | gl::RG16_SNORM
+      | gl::RG8_SNORM
+      | gl::R16_SNORM
+      | gl::R8_SNORM
+  )
+}
+
+#[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+unsafe impl<T> PipelineShaderStorage<T> for GL33
+where
+  Self: ShaderStorage<T, ShaderDataRepr = Buffer<<ArrElem<T> as Std140>::Encoded>>,
+  T: Std140,
+{
+  type BoundShaderStorageRepr = BoundShaderStorage<T>;
+
+  unsafe fn bind_shader_storage(
+    pipeline: &Self::PipelineRepr,
+    shader_data: &Self::ShaderDataRepr,
+  ) -> Result<Self::BoundShaderStorageRepr, PipelineError> {
+    let mut state = pipeline.state.borrow_mut();
+    let bstack = state.binding_stack_mut();
+
+    let binding = bstack.free_shader_storage.pop().unwrap_or_else(|| {
+      // no more free bindings; reserve one
+      let binding = bstack.next_shader_storage;
+      bstack.next_shader_storage += 1;
+      binding
+    });
+
+    state.bind_shader_storage_buffer(shader_data.handle(), binding);
+
+    Ok(BoundShaderStorage {
+      binding,
+      state: pipeline.state.clone(),
+      _phantom: PhantomData,
+    })
+  }
+
+  unsafe fn shader_storage_binding(bound: &Self::BoundShaderStorageRepr) -> u32 {
+    bound.binding
+  }
+}
+
+#[cfg(feature = "GL_ARB_shader_atomic_counters")]
+unsafe impl PipelineAtomicCounters for GL33 {
+  type BoundAtomicCountersRepr = BoundAtomicCounters;
+
+  unsafe fn bind_atomic_counters(
+    pipeline: &Self::PipelineRepr,
+    counters: &Self::AtomicCountersRepr,
+    binding: u32,
+  ) -> Result<Self::BoundAtomicCountersRepr, PipelineError> {
+    // the binding point is declared by the shader stages, so there is no binding stack to allocate from
+    pipeline
+      .state
+      .borrow_mut()
+      .bind_atomic_counter_buffer(counters.handle(), binding);
+
+    Ok(BoundAtomicCounters { binding })
+  }
+
+  unsafe fn atomic_counters_binding(bound: &Self::BoundAtomicCountersRepr) -> u32 {
+    bound.binding
+  }
+}
+
 unsafe impl<V, I, W> TessGate<V, I, W, Interleaved> for GL33
 where
   V: TessVertexData<Interleaved, Data = Vec<V>>,
@@ -240,6 +573,44 @@ where
   }
 }
 
+unsafe impl<V, I, W> TessGateBaseVertex<V, I, W, Interleaved> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn render_base_vertex(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+  ) {
+    let _ = tess.render_base(start_index, vert_nb, inst_nb, base_vertex, 0);
+  }
+}
+
+#[cfg(feature = "GL_ARB_base_instance")]
+unsafe impl<V, I, W> TessGateBaseInstance<V, I, W, Interleaved> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn render_base_instance(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+    base_instance: usize,
+  ) {
+    let _ = tess.render_base(start_index, vert_nb, inst_nb, base_vertex, base_instance);
+  }
+}
+
 unsafe impl<V, I, W> TessGate<V, I, W, Deinterleaved> for GL33
 where
   V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
@@ -257,6 +628,67 @@ where
   }
 }
 
+unsafe impl<V, I, W> TessGateBaseVertex<V, I, W, Deinterleaved> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn render_base_vertex(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+  ) {
+    let _ = tess.render_base(start_index, vert_nb, inst_nb, base_vertex, 0);
+  }
+}
+
+#[cfg(feature = "GL_ARB_base_instance")]
+unsafe impl<V, I, W> TessGateBaseInstance<V, I, W, Deinterleaved> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn render_base_instance(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+    base_instance: usize,
+  ) {
+    let _ = tess.render_base(start_index, vert_nb, inst_nb, base_vertex, base_instance);
+  }
+}
+
+unsafe impl TessGateValidation for GL33 {
+  unsafe fn current_vertex_attribs(&mut self) -> Vec<ActiveVertexAttrib> {
+    match self.state.borrow().current_program() {
+      0 => Vec::new(),
+      handle => active_vertex_attribs(handle),
+    }
+  }
+
+  unsafe fn current_geometry_config(&mut self) -> Option<GeometryConfig> {
+    match self.state.borrow().current_program() {
+      0 => None,
+      handle => geometry_config(handle),
+    }
+  }
+
+  unsafe fn current_has_tessellation_stages(&mut self) -> Option<bool> {
+    match self.state.borrow().current_program() {
+      0 => None,
+      handle => Some(has_tessellation_stages(handle)),
+    }
+  }
+}
+
 unsafe impl RenderGate for GL33 {
   unsafe fn enter_render_state(&mut self, rdr_st: &RenderState) {
     let mut gfx_state = self.state.borrow_mut();
@@ -290,6 +722,7 @@ unsafe impl RenderGate for GL33 {
     }
 
     gfx_state.set_depth_write(rdr_st.depth_write());
+    gfx_state.set_color_masks(rdr_st.color_mask(), rdr_st.color_attachment_masks());
 
     // stencil-related state
     if let Some(stencil_test) = rdr_st.stencil_test() {
@@ -331,5 +764,37 @@ unsafe impl RenderGate for GL33 {
 unsafe impl ShadingGate for GL33 {
   unsafe fn apply_shader_program(&mut self, shader_program: &Self::ProgramRepr) {
     self.state.borrow_mut().use_program(shader_program.handle);
+
+    #[cfg(feature = "GL_ARB_shader_subroutine")]
+    shader_program.apply_subroutines();
+  }
+}
+
+#[cfg(feature = "GL_ARB_compute_shader")]
+unsafe impl ShadingGateCompute for GL33 {
+  unsafe fn dispatch(&mut self, x: u32, y: u32, z: u32) {
+    gl::DispatchCompute(x as GLuint, y as GLuint, z as GLuint);
+
+    // the writes might be consumed in any way (vertex attributes, uniforms, textures, mapping, etc.)
+    gl::MemoryBarrier(gl::ALL_BARRIER_BITS);
+  }
+}
+
+#[cfg(feature = "GL_NV_mesh_shader")]
+unsafe impl RenderGateMesh for GL33 {
+  unsafe fn draw_mesh_tasks(&mut self, count: u32) {
+    // no mesh program can be in-use if the functions are not loaded
+    if mesh_shader::is_loaded() {
+      mesh_shader::DrawMeshTasksNV(0, count as GLuint);
+    }
+  }
+}
+
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+unsafe impl ShadingGateSeparable for GL33 {
+  unsafe fn apply_program_pipeline(&mut self, pipeline: &Self::ProgramPipelineRepr) {
+    // a program pipeline is ignored as long as a program is in use
+    self.state.borrow_mut().use_program(0);
+    gl::BindProgramPipeline(pipeline.handle);
   }
 }
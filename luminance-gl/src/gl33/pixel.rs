@@ -1,6 +1,16 @@
 use gl::types::*;
 
-use luminance::pixel::{Format, PixelFormat, Size, Type};
+use luminance::pixel::{CompressedFormat, Format, PixelFormat, Size, Type};
+
+// S3TC and ASTC formats are not part of the core profile, so their constants are missing from the gl crate
+const COMPRESSED_RGBA_S3TC_DXT1_EXT: GLenum = 0x83F1;
+const COMPRESSED_RGBA_S3TC_DXT3_EXT: GLenum = 0x83F2;
+const COMPRESSED_RGBA_S3TC_DXT5_EXT: GLenum = 0x83F3;
+const COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT: GLenum = 0x8C4D;
+const COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT: GLenum = 0x8C4E;
+const COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT: GLenum = 0x8C4F;
+const COMPRESSED_RGBA_ASTC_4X4_KHR: GLenum = 0x93B0;
+const COMPRESSED_SRGB8_ALPHA8_ASTC_4X4_KHR: GLenum = 0x93D0;
 
 // OpenGL format, internal sized-format and type.
 pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GLenum)> {
@@ -32,6 +42,7 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
     (Format::R(Size::ThirtyTwo), Type::Unsigned) => {
       Some((gl::RED_INTEGER, gl::R32UI, gl::UNSIGNED_INT))
     }
+    (Format::R(Size::Sixteen), Type::Floating) => Some((gl::RED, gl::R16F, gl::FLOAT)),
     (Format::R(Size::ThirtyTwo), Type::Floating) => Some((gl::RED, gl::R32F, gl::FLOAT)),
 
     // red, blue channels
@@ -73,6 +84,9 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
     (Format::RG(Size::ThirtyTwo, Size::ThirtyTwo), Type::Unsigned) => {
       Some((gl::RG_INTEGER, gl::RG32UI, gl::UNSIGNED_INT))
     }
+    (Format::RG(Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((gl::RG, gl::RG16F, gl::FLOAT))
+    }
     (Format::RG(Size::ThirtyTwo, Size::ThirtyTwo), Type::Floating) => {
       Some((gl::RG, gl::RG32F, gl::FLOAT))
     }
@@ -104,6 +118,10 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
       Some((gl::RGB_INTEGER, gl::RGB16UI, gl::UNSIGNED_SHORT))
     }
 
+    (Format::RGB(Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((gl::RGB, gl::RGB16F, gl::FLOAT))
+    }
+
     (Format::RGB(Size::Eleven, Size::Eleven, Size::Ten), Type::Floating) => {
       Some((gl::RGB, gl::R11F_G11F_B10F, gl::FLOAT))
     }
@@ -152,6 +170,9 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
     (Format::RGBA(Size::Sixteen, Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Unsigned) => {
       Some((gl::RGBA_INTEGER, gl::RGBA16UI, gl::UNSIGNED_SHORT))
     }
+    (Format::RGBA(Size::Sixteen, Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((gl::RGBA, gl::RGBA16F, gl::FLOAT))
+    }
 
     (
       Format::RGBA(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo),
@@ -188,10 +209,27 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
       Some((gl::RGBA, gl::SRGB8_ALPHA8, gl::BYTE))
     }
 
+    (Format::Depth(Size::Sixteen), Type::NormUnsigned) => Some((
+      gl::DEPTH_COMPONENT,
+      gl::DEPTH_COMPONENT16,
+      gl::UNSIGNED_SHORT,
+    )),
+    (Format::Depth(Size::TwentyFour), Type::NormUnsigned) => {
+      Some((gl::DEPTH_COMPONENT, gl::DEPTH_COMPONENT24, gl::UNSIGNED_INT))
+    }
     (Format::Depth(Size::ThirtyTwo), Type::Floating) => {
       Some((gl::DEPTH_COMPONENT, gl::DEPTH_COMPONENT32F, gl::FLOAT))
     }
 
+    (Format::Stencil(Size::Eight), Type::Unsigned) => {
+      Some((gl::STENCIL_INDEX, gl::STENCIL_INDEX8, gl::UNSIGNED_BYTE))
+    }
+
+    (Format::DepthStencil(Size::TwentyFour, Size::Eight), Type::NormUnsigned) => Some((
+      gl::DEPTH_STENCIL,
+      gl::DEPTH24_STENCIL8,
+      gl::UNSIGNED_INT_24_8,
+    )),
     (Format::DepthStencil(Size::ThirtyTwo, Size::Eight), Type::Floating) => Some((
       gl::DEPTH_STENCIL,
       gl::DEPTH32F_STENCIL8,
@@ -201,3 +239,36 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
     _ => None,
   }
 }
+
+// OpenGL internal format of compressed pixel formats.
+pub(crate) fn opengl_compressed_format(cf: CompressedFormat) -> GLenum {
+  match cf {
+    CompressedFormat::BC1RGBA => COMPRESSED_RGBA_S3TC_DXT1_EXT,
+    CompressedFormat::BC1SRGBA => COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+    CompressedFormat::BC2RGBA => COMPRESSED_RGBA_S3TC_DXT3_EXT,
+    CompressedFormat::BC2SRGBA => COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+    CompressedFormat::BC3RGBA => COMPRESSED_RGBA_S3TC_DXT5_EXT,
+    CompressedFormat::BC3SRGBA => COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
+    CompressedFormat::BC4R => gl::COMPRESSED_RED_RGTC1,
+    CompressedFormat::BC4RSigned => gl::COMPRESSED_SIGNED_RED_RGTC1,
+    CompressedFormat::BC5RG => gl::COMPRESSED_RG_RGTC2,
+    CompressedFormat::BC5RGSigned => gl::COMPRESSED_SIGNED_RG_RGTC2,
+    CompressedFormat::BC6HRGBUnsigned => gl::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT,
+    CompressedFormat::BC6HRGBSigned => gl::COMPRESSED_RGB_BPTC_SIGNED_FLOAT,
+    CompressedFormat::BC7RGBA => gl::COMPRESSED_RGBA_BPTC_UNORM,
+    CompressedFormat::BC7SRGBA => gl::COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+    CompressedFormat::ETC2RGB => gl::COMPRESSED_RGB8_ETC2,
+    CompressedFormat::ETC2SRGB => gl::COMPRESSED_SRGB8_ETC2,
+    CompressedFormat::ETC2RGBA1 => gl::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+    CompressedFormat::ETC2SRGBA1 => gl::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+    CompressedFormat::ETC2RGBA => gl::COMPRESSED_RGBA8_ETC2_EAC,
+    CompressedFormat::ETC2SRGBA => gl::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,
+    CompressedFormat::EACR => gl::COMPRESSED_R11_EAC,
+    CompressedFormat::EACRSigned => gl::COMPRESSED_SIGNED_R11_EAC,
+    CompressedFormat::EACRG => gl::COMPRESSED_RG11_EAC,
+    CompressedFormat::EACRGSigned => gl::COMPRESSED_SIGNED_RG11_EAC,
+    // ASTC formats are enumerated in the same order as the block sizes
+    CompressedFormat::ASTCRGBA(block) => COMPRESSED_RGBA_ASTC_4X4_KHR + block as GLenum,
+    CompressedFormat::ASTCSRGBA(block) => COMPRESSED_SRGB8_ALPHA8_ASTC_4X4_KHR + block as GLenum,
+  }
+}
@@ -0,0 +1,194 @@
+//! Pixel buffer objects and upload fences.
+
+use crate::gl33::{
+  state::GLState,
+  texture::{upload_level_texels_from_buffer, Texture},
+  GL33,
+};
+use gl::{self, types::*};
+use luminance::{
+  backend::pixel_buffer::{
+    PixelBuffer as PixelBufferBackend, PixelBufferSliceMut as PixelBufferSliceMutBackend,
+    TextureStreaming, UploadFence as UploadFenceBackend,
+  },
+  pixel::Pixel,
+  pixel_buffer::PixelBufferError,
+  texture::{Dimensionable, TextureError},
+};
+use std::{
+  cell::RefCell,
+  marker::PhantomData,
+  mem,
+  ops::{Deref, DerefMut},
+  ptr,
+  rc::Rc,
+  slice,
+  time::Duration,
+};
+
+pub struct PixelBuffer {
+  handle: GLuint,
+  len: usize,
+  bytes: usize,
+  state: Rc<RefCell<GLState>>,
+}
+
+impl Drop for PixelBuffer {
+  fn drop(&mut self) {
+    unsafe {
+      self.state.borrow_mut().unbind_buffer(self.handle);
+      gl::DeleteBuffers(1, &self.handle);
+    }
+  }
+}
+
+unsafe impl<T> PixelBufferBackend<T> for GL33 {
+  type PixelBufferRepr = PixelBuffer;
+
+  unsafe fn new_pixel_buffer(
+    &mut self,
+    len: usize,
+  ) -> Result<Self::PixelBufferRepr, PixelBufferError> {
+    let bytes = len * mem::size_of::<T>();
+    let mut handle: GLuint = 0;
+
+    gl::GenBuffers(1, &mut handle);
+
+    if handle == 0 {
+      return Err(PixelBufferError::cannot_create(
+        "cannot generate buffer name",
+      ));
+    }
+
+    // the pixel unpack buffer binding is not cached, and must be left unbound for regular uploads
+    gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, handle);
+    gl::BufferData(
+      gl::PIXEL_UNPACK_BUFFER,
+      bytes as GLsizeiptr,
+      ptr::null(),
+      gl::STREAM_DRAW,
+    );
+    gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, 0);
+
+    Ok(PixelBuffer {
+      handle,
+      len,
+      bytes,
+      state: self.state.clone(),
+    })
+  }
+}
+
+pub struct PixelBufferSliceMut<'a, T> {
+  handle: GLuint,
+  ptr: *mut T,
+  len: usize,
+  _phantom: PhantomData<&'a mut T>,
+}
+
+impl<T> Drop for PixelBufferSliceMut<'_, T> {
+  fn drop(&mut self) {
+    unsafe {
+      gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, self.handle);
+      gl::UnmapBuffer(gl::PIXEL_UNPACK_BUFFER);
+      gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, 0);
+    }
+  }
+}
+
+impl<T> Deref for PixelBufferSliceMut<'_, T> {
+  type Target = [T];
+
+  fn deref(&self) -> &Self::Target {
+    unsafe { slice::from_raw_parts(self.ptr, self.len) }
+  }
+}
+
+impl<T> DerefMut for PixelBufferSliceMut<'_, T> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+  }
+}
+
+unsafe impl<'a, T> PixelBufferSliceMutBackend<'a, T> for GL33
+where
+  T: 'a,
+{
+  type PixelBufferSliceMutRepr = PixelBufferSliceMut<'a, T>;
+
+  unsafe fn slice_mut(
+    buffer: &'a mut Self::PixelBufferRepr,
+  ) -> Result<Self::PixelBufferSliceMutRepr, PixelBufferError> {
+    gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, buffer.handle);
+
+    // invalidating the buffer orphans its previous storage, which pending uploads keep using
+    let ptr = gl::MapBufferRange(
+      gl::PIXEL_UNPACK_BUFFER,
+      0,
+      buffer.bytes as GLsizeiptr,
+      gl::MAP_WRITE_BIT | gl::MAP_INVALIDATE_BUFFER_BIT,
+    ) as *mut T;
+
+    gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, 0);
+
+    if ptr.is_null() {
+      return Err(PixelBufferError::cannot_map("glMapBufferRange failed"));
+    }
+
+    Ok(PixelBufferSliceMut {
+      handle: buffer.handle,
+      ptr,
+      len: buffer.len,
+      _phantom: PhantomData,
+    })
+  }
+}
+
+pub struct UploadFence {
+  pub(crate) sync: GLsync,
+}
+
+impl Drop for UploadFence {
+  fn drop(&mut self) {
+    unsafe { gl::DeleteSync(self.sync) };
+  }
+}
+
+unsafe impl UploadFenceBackend for GL33 {
+  type UploadFenceRepr = UploadFence;
+
+  unsafe fn is_upload_complete(fence: &Self::UploadFenceRepr) -> bool {
+    let mut status = 0;
+    gl::GetSynciv(fence.sync, gl::SYNC_STATUS, 1, ptr::null_mut(), &mut status);
+
+    status == gl::SIGNALED as GLint
+  }
+
+  unsafe fn wait_upload(fence: &Self::UploadFenceRepr, timeout: Duration) -> bool {
+    let timeout = timeout.as_nanos().min(u128::from(u64::MAX)) as GLuint64;
+
+    matches!(
+      gl::ClientWaitSync(fence.sync, gl::SYNC_FLUSH_COMMANDS_BIT, timeout),
+      gl::ALREADY_SIGNALED | gl::CONDITION_SATISFIED
+    )
+  }
+}
+
+unsafe impl<D, P> TextureStreaming<D, P> for GL33
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  unsafe fn upload_part_level_from_buffer(
+    texture: &mut Texture,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+    buffer: &PixelBuffer,
+  ) -> Result<Self::UploadFenceRepr, TextureError> {
+    upload_level_texels_from_buffer::<D, P>(texture, level, offset, size, buffer.handle)?;
+
+    let sync = gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+    Ok(UploadFence { sync })
+  }
+}
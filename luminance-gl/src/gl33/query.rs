@@ -1,7 +1,10 @@
 //! Query API implementation for OpenGL 3.3.
 
 use crate::GL33;
-use luminance::backend::query::{Query as QueryBackend, QueryError};
+use luminance::{
+  backend::query::{Query as QueryBackend, QueryError},
+  pixel::CompressionFamily,
+};
 
 unsafe impl QueryBackend for GL33 {
   fn backend_author(&self) -> Result<String, QueryError> {
@@ -28,4 +31,30 @@ unsafe impl QueryBackend for GL33 {
     let max = self.state.borrow_mut().get_max_texture_array_elements();
     Ok(max)
   }
+
+  fn supports_compression(&self, family: CompressionFamily) -> Result<bool, QueryError> {
+    let extension = match family {
+      CompressionFamily::S3TC => "GL_EXT_texture_compression_s3tc",
+      // RGTC is core since OpenGL 3.0
+      CompressionFamily::RGTC => return Ok(true),
+      CompressionFamily::BPTC => "GL_ARB_texture_compression_bptc",
+      CompressionFamily::ETC2 => "GL_ARB_ES3_compatibility",
+      CompressionFamily::ASTC => "GL_KHR_texture_compression_astc_ldr",
+    };
+
+    Ok(self.state.borrow_mut().has_extension(extension))
+  }
+
+  fn max_anisotropy(&self) -> Result<Option<f32>, QueryError> {
+    Ok(self.state.borrow_mut().get_max_anisotropy())
+  }
+
+  fn supports_float_blending(&self) -> Result<bool, QueryError> {
+    // blending floating-point color attachments is core since OpenGL 3.0
+    Ok(true)
+  }
+
+  fn is_back_buffer_srgb(&self) -> Result<bool, QueryError> {
+    Ok(self.state.borrow_mut().is_back_buffer_srgb())
+  }
 }
@@ -1,23 +1,60 @@
 use super::buffer::Buffer;
-use crate::gl33::GL33;
+#[cfg(feature = "GL_ARB_bindless_texture")]
+use crate::gl33::bindless;
+#[cfg(feature = "GL_NV_mesh_shader")]
+use crate::gl33::mesh_shader;
+use crate::gl33::{GLState, GL33};
 use gl::{self, types::*};
+#[cfg(feature = "GL_ARB_compute_shader")]
+use luminance::backend::shader::ShaderCompute;
+#[cfg(feature = "GL_KHR_parallel_shader_compile")]
+use luminance::backend::shader::ShaderDeferred;
+#[cfg(feature = "GL_NV_mesh_shader")]
+use luminance::backend::shader::ShaderMesh;
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+use luminance::backend::shader::ShaderSeparable;
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+use luminance::pipeline::ImageBinding;
+#[cfg(feature = "GL_ARB_bindless_texture")]
+use luminance::texture::TextureHandle;
+#[cfg(feature = "GL_ARB_shader_atomic_counters")]
+use luminance::{backend::shader::AtomicCounters, shader::ActiveAtomicCounter};
+#[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+use luminance::{backend::shader::ShaderStorage, pipeline::ShaderStorageBinding};
+#[cfg(feature = "GL_ARB_shader_subroutine")]
 use luminance::{
-  backend::shader::{Shader, ShaderData, Uniformable},
-  pipeline::{ShaderDataBinding, TextureBinding},
+  backend::shader::ShaderSubroutine,
+  shader::{Subroutine, SubroutineUniform},
+};
+use luminance::{
+  backend::shader::{
+    Shader, ShaderData, ShaderReload, ShaderValidation, Uniformable, UniformableRange,
+  },
+  pipeline::{ShaderDataBinding, ShadowBinding, TextureBinding},
   pixel::{SamplerType, Type as PixelType},
   shader::{
-    types::{Arr, Mat22, Mat33, Mat44, Vec2, Vec3, Vec4},
-    ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform, UniformType,
-    UniformWarning, VertexAttribWarning,
+    split_version_directive,
+    types::{
+      Arr, Mat22, Mat23, Mat24, Mat32, Mat33, Mat34, Mat42, Mat43, Mat44, Transposed, Vec2, Vec3,
+      Vec4,
+    },
+    ActiveUniform, ActiveVertexAttrib, GeometryConfig, GeometryInput, GeometryOutput, GlslVersion,
+    ProgramError, ProgramValidation, ProgramWarning, ShaderDataError, StageError, StageType,
+    TessellationStages, Uniform, UniformName, UniformType, UniformWarning, VertexAttribWarning,
   },
   texture::{Dim, Dimensionable},
   vertex::Semantics,
 };
 use luminance_std140::{ArrElem, Std140};
 use std::{
-  ffi::CString,
+  cell::RefCell,
+  collections::HashMap,
+  ffi::{CStr, CString},
+  hash::{Hash, Hasher},
   mem,
   ptr::{null, null_mut},
+  rc::Rc,
+  slice,
 };
 
 #[derive(Debug)]
@@ -26,247 +63,1467 @@ pub struct Stage {
   ty: StageType,
 }
 
-impl Drop for Stage {
-  fn drop(&mut self) {
-    unsafe {
-      gl::DeleteShader(self.handle);
+impl Drop for Stage {
+  fn drop(&mut self) {
+    unsafe {
+      gl::DeleteShader(self.handle);
+    }
+  }
+}
+
+impl Stage {
+  /// Start compiling a stage; whether it succeeds is checked with [`Stage::check_compilation`].
+  unsafe fn compile(ty: StageType, src: &str) -> Result<Self, StageError> {
+    let shader_type = opengl_shader_type(ty).ok_or_else(|| StageError::unsupported_type(ty))?;
+    let handle = gl::CreateShader(shader_type);
+
+    if handle == 0 {
+      return Err(StageError::compilation_failed(
+        ty,
+        "unable to create shader stage",
+      ));
+    }
+
+    let c_src = CString::new(glsl_pragma_src(src).as_bytes()).unwrap();
+    gl::ShaderSource(handle, 1, [c_src.as_ptr()].as_ptr(), null());
+    gl::CompileShader(handle);
+
+    Ok(Stage { handle, ty })
+  }
+
+  unsafe fn check_compilation(&self) -> Result<(), StageError> {
+    let mut compiled: GLint = gl::FALSE.into();
+    gl::GetShaderiv(self.handle, gl::COMPILE_STATUS, &mut compiled);
+
+    if compiled == gl::TRUE.into() {
+      Ok(())
+    } else {
+      Err(StageError::compilation_failed(self.ty, self.info_log()))
+    }
+  }
+
+  /// Info log of the last compilation.
+  unsafe fn info_log(&self) -> String {
+    let mut log_len: GLint = 0;
+    gl::GetShaderiv(self.handle, gl::INFO_LOG_LENGTH, &mut log_len);
+
+    let mut log: Vec<u8> = Vec::with_capacity(log_len as usize);
+    let mut written: GLsizei = 0;
+    gl::GetShaderInfoLog(
+      self.handle,
+      log_len,
+      &mut written,
+      log.as_mut_ptr() as *mut GLchar,
+    );
+
+    log.set_len(written as usize);
+
+    String::from_utf8_lossy(&log).into_owned()
+  }
+}
+
+/// Location, type and size of a uniform, as reflected from a program.
+#[derive(Clone, Copy, Debug)]
+struct ReflectedUniform {
+  location: GLint,
+  ty: GLenum,
+  size: GLint,
+}
+
+impl ReflectedUniform {
+  /// Reflect the uniform named `name`, if active.
+  unsafe fn reflect(program: GLuint, name: &CStr) -> Option<Self> {
+    let location = gl::GetUniformLocation(program, name.as_ptr() as *const GLchar);
+
+    if location < 0 {
+      return None;
+    }
+
+    let mut index = 0;
+    gl::GetUniformIndices(
+      program,
+      1,
+      [name.as_ptr() as *const GLchar].as_ptr(),
+      &mut index,
+    );
+
+    if index == gl::INVALID_INDEX {
+      return None;
+    }
+
+    // we don’t need the name back, so no buffer is passed
+    let mut size = 0;
+    let mut ty = 0;
+    gl::GetActiveUniform(
+      program,
+      index,
+      0,
+      null_mut(),
+      &mut size,
+      &mut ty,
+      null_mut(),
+    );
+
+    Some(ReflectedUniform { location, ty, size })
+  }
+}
+
+/// How a uniform was looked up, so that it can be looked up again when its program is reloaded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum UniformLookup {
+  Name {
+    name: UniformName,
+    ty: UniformType,
+    size: usize,
+  },
+  Location {
+    location: GLint,
+    ty: UniformType,
+    size: usize,
+  },
+  Block(String),
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  StorageBlock(String),
+}
+
+impl Hash for UniformLookup {
+  fn hash<H>(&self, state: &mut H)
+  where
+    H: Hasher,
+  {
+    // types and sizes are left out, as UniformType doesn’t implement Hash; equal lookups still hash the same
+    mem::discriminant(self).hash(state);
+
+    match *self {
+      UniformLookup::Name { ref name, .. } => name.hash(state),
+      UniformLookup::Location { location, .. } => location.hash(state),
+      UniformLookup::Block(ref name) => name.hash(state),
+      #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+      UniformLookup::StorageBlock(ref name) => name.hash(state),
+    }
+  }
+}
+
+/// A uniform handed out by a uniform builder.
+///
+/// [`Uniform`] handles are indices of mapped uniforms rather than locations, so that they remain valid when their
+/// program is reloaded and locations change.
+#[derive(Debug)]
+struct MappedUniform {
+  lookup: UniformLookup,
+  // location, or index for blocks; -1 if inactive
+  location: GLint,
+  // locations of the items of arrays, per item index; looked up on demand
+  item_locations: HashMap<usize, GLint>,
+}
+
+/// Uniforms handed out by the uniform builders of a program.
+#[derive(Debug, Default)]
+struct MappedUniforms {
+  // indexed by Uniform::index
+  uniforms: Vec<MappedUniform>,
+  // index of the uniform handed out for each lookup
+  indices: HashMap<UniformLookup, usize>,
+}
+
+#[derive(Debug)]
+pub struct Program {
+  pub(crate) handle: GLuint,
+  // raw bytes of the last value of each uniform, per location
+  uniform_cache: HashMap<GLint, Vec<u8>>,
+  // reflected uniforms looked up by interned names; None if inactive
+  named_uniforms: Rc<RefCell<HashMap<UniformName, Option<ReflectedUniform>>>>,
+  // uniforms handed out by uniform builders
+  mapped_uniforms: Rc<RefCell<MappedUniforms>>,
+  state: Rc<RefCell<GLState>>,
+  // varyings captured with transform feedback; kept around to be declared again when reloading
+  varyings: Vec<CString>,
+  // fragment outputs bound to color attachment indices; kept around for the same reason
+  fragment_outputs: Vec<(CString, GLuint)>,
+  // selected subroutine indices, per stage; they are lost every time the program is used, so we keep them around
+  #[cfg(feature = "GL_ARB_shader_subroutine")]
+  subroutines: Vec<(GLenum, Vec<GLuint>)>,
+  // stages provided by the program when used in a program pipeline; only set for separable programs
+  #[cfg(feature = "GL_ARB_separate_shader_objects")]
+  stage_bits: GLbitfield,
+}
+
+impl Drop for Program {
+  fn drop(&mut self) {
+    unsafe {
+      gl::DeleteProgram(self.handle);
+    }
+  }
+}
+
+impl Program {
+  fn new(handle: GLuint, state: Rc<RefCell<GLState>>) -> Self {
+    Program {
+      handle,
+      uniform_cache: HashMap::new(),
+      named_uniforms: Rc::new(RefCell::new(HashMap::new())),
+      mapped_uniforms: Rc::new(RefCell::new(MappedUniforms::default())),
+      state,
+      varyings: Vec::new(),
+      fragment_outputs: Vec::new(),
+      #[cfg(feature = "GL_ARB_shader_subroutine")]
+      subroutines: Vec::new(),
+      #[cfg(feature = "GL_ARB_separate_shader_objects")]
+      stage_bits: 0,
+    }
+  }
+
+  /// Create a program and attach stages to it, without linking it.
+  unsafe fn with_stages(
+    state: Rc<RefCell<GLState>>,
+    vertex: &Stage,
+    tess: Option<TessellationStages<Stage>>,
+    geometry: Option<&Stage>,
+    fragment: &Stage,
+  ) -> Self {
+    let handle = gl::CreateProgram();
+
+    if let Some(TessellationStages {
+      control,
+      evaluation,
+    }) = tess
+    {
+      gl::AttachShader(handle, control.handle);
+      gl::AttachShader(handle, evaluation.handle);
+    }
+
+    gl::AttachShader(handle, vertex.handle);
+
+    if let Some(geometry) = geometry {
+      gl::AttachShader(handle, geometry.handle);
+    }
+
+    gl::AttachShader(handle, fragment.handle);
+
+    Program::new(handle, state)
+  }
+
+  fn link(&self) -> Result<(), ProgramError> {
+    unsafe { gl::LinkProgram(self.handle) };
+    self.check_link()
+  }
+
+  fn check_link(&self) -> Result<(), ProgramError> {
+    let handle = self.handle;
+
+    unsafe {
+      let mut linked: GLint = gl::FALSE.into();
+      gl::GetProgramiv(handle, gl::LINK_STATUS, &mut linked);
+
+      if linked == gl::TRUE.into() {
+        Ok(())
+      } else {
+        Err(ProgramError::link_failed(self.info_log()))
+      }
+    }
+  }
+
+  /// Info log of the last link.
+  fn info_log(&self) -> String {
+    unsafe {
+      let mut log_len: GLint = 0;
+      gl::GetProgramiv(self.handle, gl::INFO_LOG_LENGTH, &mut log_len);
+
+      let mut log: Vec<u8> = Vec::with_capacity(log_len as usize);
+      let mut written: GLsizei = 0;
+      gl::GetProgramInfoLog(
+        self.handle,
+        log_len,
+        &mut written,
+        log.as_mut_ptr() as *mut GLchar,
+      );
+
+      log.set_len(written as usize);
+
+      String::from_utf8_lossy(&log).into_owned()
+    }
+  }
+
+  /// Declare the varyings to capture with transform feedback; taken into account at the next link.
+  fn declare_varyings(&self) {
+    let names: Vec<_> = self.varyings.iter().map(|name| name.as_ptr()).collect();
+
+    unsafe {
+      gl::TransformFeedbackVaryings(
+        self.handle,
+        names.len() as GLsizei,
+        names.as_ptr(),
+        gl::INTERLEAVED_ATTRIBS,
+      );
+    }
+  }
+
+  /// Bind the fragment outputs to their color attachment indices; taken into account at the next link.
+  fn bind_fragment_outputs(&self) {
+    for (name, index) in &self.fragment_outputs {
+      unsafe { gl::BindFragDataLocation(self.handle, *index, name.as_ptr()) };
+    }
+  }
+
+  /// Replace the program with a reloaded one, declaring the same varyings and fragment outputs and remapping the
+  /// uniforms handed out so far.
+  ///
+  /// Separable programs stay separable. Subroutine selections are not kept, as the indices of subroutines can change.
+  unsafe fn replace_with<Sem>(
+    &mut self,
+    mut reloaded: Program,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    Sem: Semantics,
+  {
+    if !self.varyings.is_empty() {
+      reloaded.varyings = self.varyings.clone();
+      reloaded.declare_varyings();
+    }
+
+    reloaded.fragment_outputs = self.fragment_outputs.clone();
+    reloaded.bind_fragment_outputs();
+
+    // must be set before linking
+    #[cfg(feature = "GL_ARB_separate_shader_objects")]
+    if self.stage_bits != 0 {
+      gl::ProgramParameteri(reloaded.handle, gl::PROGRAM_SEPARABLE, gl::TRUE as GLint);
+      reloaded.stage_bits = attached_stage_bits(reloaded.handle);
+    }
+
+    let mut warnings: Vec<_> = GL33::apply_semantics::<Sem>(&mut reloaded)?
+      .into_iter()
+      .map(ProgramWarning::VertexAttrib)
+      .collect();
+
+    // look the mapped uniforms up again, so that their handles remain valid
+    let uniform_builder = UniformBuilder::new(&reloaded);
+    for mapped in self.mapped_uniforms.borrow_mut().uniforms.iter_mut() {
+      mapped.item_locations.clear();
+      mapped.location = uniform_builder
+        .find(&mapped.lookup)
+        .unwrap_or_else(|warning| {
+          warnings.push(ProgramWarning::Uniform(warning));
+          -1
+        });
+    }
+
+    reloaded.mapped_uniforms = self.mapped_uniforms.clone();
+    *self = reloaded;
+
+    Ok(warnings)
+  }
+}
+
+impl Program {
+  /// Location of a uniform, or index for blocks; -1 if inactive.
+  fn location<T>(&self, uniform: &Uniform<T>) -> GLint
+  where
+    T: ?Sized,
+  {
+    mapped_location(&self.mapped_uniforms, uniform)
+  }
+
+  /// Location of the item `offset` of an array uniform; -1 if inactive.
+  ///
+  /// Only explicit locations of array items are guaranteed to be consecutive; other items are looked up by name.
+  fn item_location<T>(&self, uniform: &Uniform<T>, offset: usize) -> GLint
+  where
+    T: ?Sized,
+  {
+    let mut mapped_uniforms = self.mapped_uniforms.borrow_mut();
+    let mapped = match usize::try_from(uniform.index())
+      .ok()
+      .and_then(|index| mapped_uniforms.uniforms.get_mut(index))
+    {
+      Some(mapped) if mapped.location >= 0 => mapped,
+      _ => return -1,
+    };
+
+    if offset == 0 {
+      return mapped.location;
+    }
+
+    let name = match mapped.lookup {
+      UniformLookup::Name { ref name, .. } => name.as_str(),
+      _ => return mapped.location + offset as GLint,
+    };
+    let handle = self.handle;
+
+    *mapped.item_locations.entry(offset).or_insert_with(|| {
+      // arrays are reflected with the name of their first item
+      let name = name.strip_suffix("[0]").unwrap_or(name);
+      let item_name = CString::new(format!("{}[{}]", name, offset)).unwrap();
+      unsafe { gl::GetUniformLocation(handle, item_name.as_ptr()) }
+    })
+  }
+
+  fn is_uniform_cache_enabled(&mut self) -> bool {
+    let enabled = self.state.borrow().is_uniform_cache_enabled();
+
+    // values updated while disabled are not tracked, so forget about them
+    if !enabled && !self.uniform_cache.is_empty() {
+      self.uniform_cache.clear();
+    }
+
+    enabled
+  }
+
+  /// Cache the value of the uniform at `location`, returning whether it needs to be updated.
+  ///
+  /// Always returns `true` if the uniform cache is disabled.
+  fn cache_uniform<T>(&mut self, location: GLint, value: &T) -> bool
+  where
+    T: ?Sized,
+  {
+    if !self.is_uniform_cache_enabled() {
+      return true;
+    }
+
+    // uniform values are plain scalars, vectors and matrices, without padding
+    let bytes =
+      unsafe { slice::from_raw_parts(value as *const T as *const u8, mem::size_of_val(value)) };
+
+    match self.uniform_cache.get_mut(&location) {
+      Some(cached) if cached == bytes => false,
+
+      Some(cached) => {
+        cached.clear();
+        cached.extend_from_slice(bytes);
+        true
+      }
+
+      None => {
+        self.uniform_cache.insert(location, bytes.to_owned());
+        true
+      }
+    }
+  }
+
+  /// Cache a range of items of the array uniform at `location`, returning whether they need to be updated.
+  ///
+  /// `values` starts at item `offset` of the array. Always returns `true` if the array is not cached.
+  fn cache_uniform_range<T>(&mut self, location: GLint, offset: usize, values: &[T]) -> bool {
+    if !self.is_uniform_cache_enabled() {
+      return true;
+    }
+
+    let bytes =
+      unsafe { slice::from_raw_parts(values.as_ptr() as *const u8, mem::size_of_val(values)) };
+    let start = offset * mem::size_of::<T>();
+
+    match self
+      .uniform_cache
+      .get_mut(&location)
+      .and_then(|cached| cached.get_mut(start..start + bytes.len()))
+    {
+      Some(cached) if cached == bytes => false,
+
+      Some(cached) => {
+        cached.copy_from_slice(bytes);
+        true
+      }
+
+      None => true,
+    }
+  }
+}
+
+#[cfg(feature = "GL_ARB_shader_subroutine")]
+impl Program {
+  /// Upload the selected subroutines again.
+  pub(crate) unsafe fn apply_subroutines(&self) {
+    for (stage, indices) in &self.subroutines {
+      gl::UniformSubroutinesuiv(*stage, indices.len() as GLsizei, indices.as_ptr());
+    }
+  }
+}
+
+fn mapped_location<T>(mapped_uniforms: &RefCell<MappedUniforms>, uniform: &Uniform<T>) -> GLint
+where
+  T: ?Sized,
+{
+  // unbound uniforms have a negative index
+  usize::try_from(uniform.index())
+    .ok()
+    .and_then(|index| {
+      mapped_uniforms
+        .borrow()
+        .uniforms
+        .get(index)
+        .map(|m| m.location)
+    })
+    .unwrap_or(-1)
+}
+
+pub struct UniformBuilder {
+  handle: GLuint,
+  named_uniforms: Rc<RefCell<HashMap<UniformName, Option<ReflectedUniform>>>>,
+  mapped_uniforms: Rc<RefCell<MappedUniforms>>,
+}
+
+impl UniformBuilder {
+  fn new(program: &Program) -> Self {
+    UniformBuilder {
+      handle: program.handle,
+      named_uniforms: program.named_uniforms.clone(),
+      mapped_uniforms: program.mapped_uniforms.clone(),
+    }
+  }
+
+  /// Location of a uniform handed out by this builder, or index for blocks.
+  fn location<T>(&self, uniform: &Uniform<T>) -> GLint
+  where
+    T: ?Sized,
+  {
+    mapped_location(&self.mapped_uniforms, uniform)
+  }
+
+  /// Hand out the uniform looked up with `lookup`, found at `location`.
+  fn map<T>(&self, lookup: UniformLookup, location: GLint) -> Uniform<T> {
+    let mut mapped_uniforms = self.mapped_uniforms.borrow_mut();
+    let MappedUniforms { uniforms, indices } = &mut *mapped_uniforms;
+
+    let index = *indices.entry(lookup).or_insert_with_key(|lookup| {
+      uniforms.push(MappedUniform {
+        lookup: lookup.clone(),
+        location,
+        item_locations: HashMap::new(),
+      });
+      uniforms.len() - 1
+    });
+
+    unsafe { Uniform::new(index as GLint) }
+  }
+
+  /// Look a uniform up again, returning its location, or index for blocks.
+  fn find(&self, lookup: &UniformLookup) -> Result<GLint, UniformWarning> {
+    match *lookup {
+      UniformLookup::Name { ref name, ty, size } => self.find_uniform(name.as_str(), ty, size),
+      UniformLookup::Location { location, ty, size } => {
+        self.find_uniform_at_location(location, ty, size)
+      }
+      UniformLookup::Block(ref name) => self.find_uniform_block(name),
+      #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+      UniformLookup::StorageBlock(ref name) => self.find_shader_storage_block(name),
+    }
+  }
+
+  fn ask_named_uniform<T>(
+    &self,
+    name: &UniformName,
+    ty: UniformType,
+    size: usize,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    GL33: for<'u> Uniformable<'u, T>,
+  {
+    let cached = self.named_uniforms.borrow().get(name).copied();
+    let reflected = match cached {
+      Some(reflected) => reflected,
+
+      None => {
+        let reflected = unsafe { ReflectedUniform::reflect(self.handle, name.as_c_str()) };
+        self
+          .named_uniforms
+          .borrow_mut()
+          .insert(name.clone(), reflected);
+        reflected
+      }
+    };
+
+    let reflected = reflected.ok_or_else(|| UniformWarning::inactive(name.as_str()))?;
+
+    check_uniform_size_match(name.as_str(), size, reflected.size as usize)?;
+    check_uniform_type_match(name.as_str(), ty, reflected.ty)?;
+
+    let lookup = UniformLookup::Name {
+      name: name.clone(),
+      ty,
+      size,
+    };
+    Ok(self.map(lookup, reflected.location))
+  }
+
+  fn ask_uniform<T>(
+    &self,
+    name: &str,
+    ty: UniformType,
+    size: usize,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    GL33: for<'u> Uniformable<'u, T>,
+  {
+    let location = self.find_uniform(name, ty, size)?;
+    let lookup = UniformLookup::Name {
+      name: UniformName::new(name)?,
+      ty,
+      size,
+    };
+    Ok(self.map(lookup, location))
+  }
+
+  fn find_uniform(
+    &self,
+    name: &str,
+    ty: UniformType,
+    size: usize,
+  ) -> Result<GLint, UniformWarning> {
+    let location = {
+      let c_name = uniform_c_name(name)?;
+      unsafe { gl::GetUniformLocation(self.handle, c_name.as_ptr() as *const GLchar) }
+    };
+
+    // ensure the location smells good
+    if location < 0 {
+      return Err(UniformWarning::inactive(name));
+    }
+
+    // ensure the type is correct regarding what we have in the type-system
+    uniform_type_match(self.handle, name, ty, size)?;
+
+    Ok(location)
+  }
+
+  fn ask_uniform_at_location<T>(
+    &self,
+    location: GLint,
+    ty: UniformType,
+    size: usize,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    GL33: for<'u> Uniformable<'u, T>,
+  {
+    let location = self.find_uniform_at_location(location, ty, size)?;
+    let lookup = UniformLookup::Location { location, ty, size };
+    Ok(self.map(lookup, location))
+  }
+
+  fn find_uniform_at_location(
+    &self,
+    location: GLint,
+    ty: UniformType,
+    size: usize,
+  ) -> Result<GLint, UniformWarning> {
+    let name = format!("location {}", location);
+
+    if location < 0 {
+      return Err(UniformWarning::inactive(name));
+    }
+
+    // ensure the type is correct regarding what we have in the type-system
+    uniform_type_match_at_location(self.handle, &name, location, ty, size)?;
+
+    Ok(location)
+  }
+
+  fn ask_uniform_block<T>(&self, name: &str) -> Result<Uniform<T>, UniformWarning>
+  where
+    GL33: for<'u> Uniformable<'u, T>,
+  {
+    let index = self.find_uniform_block(name)?;
+    Ok(self.map(UniformLookup::Block(name.to_owned()), index))
+  }
+
+  fn find_uniform_block(&self, name: &str) -> Result<GLint, UniformWarning> {
+    let location = {
+      let c_name = uniform_c_name(name)?;
+      unsafe { gl::GetUniformBlockIndex(self.handle, c_name.as_ptr() as *const GLchar) }
+    };
+
+    // ensure the location smells good
+    if location == gl::INVALID_INDEX {
+      return Err(UniformWarning::inactive(name));
+    }
+
+    Ok(location as GLint)
+  }
+
+  /// Check the offsets of the members of a uniform block against the `std140` representation of its content, which is
+  /// an array of values with the given stride.
+  ///
+  /// Members that are fields of the values are checked against `fields`. If the values have no fields, the stride of
+  /// array members is checked instead.
+  fn check_uniform_block_layout(
+    &self,
+    block_index: GLuint,
+    fields: &[(&str, usize)],
+    stride: usize,
+  ) -> Result<(), UniformWarning> {
+    let mut count = 0;
+    unsafe {
+      gl::GetActiveUniformBlockiv(
+        self.handle,
+        block_index,
+        gl::UNIFORM_BLOCK_ACTIVE_UNIFORMS,
+        &mut count,
+      )
+    };
+
+    let mut indices = vec![0 as GLint; count as usize];
+    unsafe {
+      gl::GetActiveUniformBlockiv(
+        self.handle,
+        block_index,
+        gl::UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES,
+        indices.as_mut_ptr(),
+      )
+    };
+    let indices: Vec<_> = indices.into_iter().map(|index| index as GLuint).collect();
+
+    let mut offsets = vec![0 as GLint; count as usize];
+    let mut array_strides = vec![0 as GLint; count as usize];
+    unsafe {
+      gl::GetActiveUniformsiv(
+        self.handle,
+        count,
+        indices.as_ptr(),
+        gl::UNIFORM_OFFSET,
+        offsets.as_mut_ptr(),
+      );
+      gl::GetActiveUniformsiv(
+        self.handle,
+        count,
+        indices.as_ptr(),
+        gl::UNIFORM_ARRAY_STRIDE,
+        array_strides.as_mut_ptr(),
+      );
+    }
+
+    let mut max_len = 0;
+    unsafe { gl::GetProgramiv(self.handle, gl::ACTIVE_UNIFORM_MAX_LENGTH, &mut max_len) };
+    let mut name = vec![0u8; max_len as usize];
+
+    for ((&index, &found_offset), &array_stride) in indices.iter().zip(&offsets).zip(&array_strides)
+    {
+      let mut len = 0;
+      unsafe {
+        gl::GetActiveUniformName(
+          self.handle,
+          index,
+          max_len,
+          &mut len,
+          name.as_mut_ptr() as *mut GLchar,
+        )
+      };
+      let name = String::from_utf8_lossy(&name[..len as usize]);
+      let found_offset = found_offset as usize;
+
+      if fields.is_empty() {
+        let array_stride = array_stride as usize;
+
+        // compare the offset of the second item, as the first one is the beginning of the array
+        if array_stride != 0 && array_stride != stride {
+          return Err(UniformWarning::offset_mismatch(
+            name.trim_end_matches("[0]").to_owned() + "[1]",
+            found_offset + stride,
+            found_offset + array_stride,
+          ));
+        }
+      } else if let Some((item, field)) = block_member_field(&name) {
+        if let Some((_, field_offset)) = fields.iter().find(|(field_name, _)| *field_name == field)
+        {
+          let offset = item * stride + field_offset;
+
+          if offset != found_offset {
+            return Err(UniformWarning::offset_mismatch(name, offset, found_offset));
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  fn ask_shader_storage_block<T>(&self, name: &str) -> Result<Uniform<T>, UniformWarning>
+  where
+    GL33: for<'u> Uniformable<'u, T>,
+  {
+    let index = self.find_shader_storage_block(name)?;
+    Ok(self.map(UniformLookup::StorageBlock(name.to_owned()), index))
+  }
+
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  fn find_shader_storage_block(&self, name: &str) -> Result<GLint, UniformWarning> {
+    let location = {
+      let c_name = uniform_c_name(name)?;
+      unsafe {
+        gl::GetProgramResourceIndex(
+          self.handle,
+          gl::SHADER_STORAGE_BLOCK,
+          c_name.as_ptr() as *const GLchar,
+        )
+      }
+    };
+
+    // ensure the location smells good
+    if location == gl::INVALID_INDEX {
+      return Err(UniformWarning::inactive(name));
+    }
+
+    Ok(location as GLint)
+  }
+}
+
+unsafe impl Shader for GL33 {
+  type StageRepr = Stage;
+
+  type ProgramRepr = Program;
+
+  type UniformBuilderRepr = UniformBuilder;
+
+  unsafe fn glsl_version(&self) -> GlslVersion {
+    GlslVersion::new(330)
+  }
+
+  unsafe fn new_stage(&mut self, ty: StageType, src: &str) -> Result<Self::StageRepr, StageError> {
+    let stage = Stage::compile(ty, src)?;
+    stage.check_compilation()?;
+    Ok(stage)
+  }
+
+  unsafe fn new_program(
+    &mut self,
+    vertex: &Self::StageRepr,
+    tess: Option<TessellationStages<Self::StageRepr>>,
+    geometry: Option<&Self::StageRepr>,
+    fragment: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    let program = Program::with_stages(self.state.clone(), vertex, tess, geometry, fragment);
+    program.link().map(move |_| program)
+  }
+
+  unsafe fn apply_semantics<Sem>(
+    program: &mut Self::ProgramRepr,
+  ) -> Result<Vec<VertexAttribWarning>, ProgramError>
+  where
+    Sem: Semantics,
+  {
+    let warnings = bind_vertex_attribs_locations::<Sem>(program);
+
+    program.link()?;
+
+    Ok(warnings)
+  }
+
+  unsafe fn set_transform_feedback_varyings(
+    program: &mut Self::ProgramRepr,
+    varyings: &[String],
+  ) -> Result<(), ProgramError> {
+    program.varyings = varyings
+      .iter()
+      .map(|varying| {
+        CString::new(varying.as_bytes())
+          .map_err(|_| ProgramError::creation_failed(format!("invalid varying name: {}", varying)))
+      })
+      .collect::<Result<_, _>>()?;
+    program.declare_varyings();
+
+    Ok(())
+  }
+
+  unsafe fn set_fragment_output_locations(
+    program: &mut Self::ProgramRepr,
+    outputs: &[(String, usize)],
+  ) -> Result<(), ProgramError> {
+    program.fragment_outputs = outputs
+      .iter()
+      .map(|(name, index)| {
+        CString::new(name.as_bytes())
+          .map(|c_name| (c_name, *index as GLuint))
+          .map_err(|_| {
+            ProgramError::creation_failed(format!("invalid fragment output name: {}", name))
+          })
+      })
+      .collect::<Result<_, _>>()?;
+    program.bind_fragment_outputs();
+
+    Ok(())
+  }
+
+  unsafe fn fragment_output_location(program: &Self::ProgramRepr, name: &str) -> Option<usize> {
+    let c_name = CString::new(name.as_bytes()).ok()?;
+    let location = gl::GetFragDataLocation(program.handle, c_name.as_ptr());
+
+    if location < 0 {
+      None
+    } else {
+      Some(location as usize)
+    }
+  }
+
+  unsafe fn new_uniform_builder(
+    program: &mut Self::ProgramRepr,
+  ) -> Result<Self::UniformBuilderRepr, ProgramError> {
+    Ok(UniformBuilder::new(&program))
+  }
+
+  unsafe fn ask_uniform<T>(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    name: &str,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    Self: for<'u> Uniformable<'u, T>,
+  {
+    let uniform = match Self::ty() {
+      UniformType::ShaderDataBinding => {
+        let uniform = uniform_builder.ask_uniform_block(name)?;
+        <Self as Uniformable<T>>::check(uniform_builder, name, &uniform)?;
+        uniform
+      }
+      #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+      UniformType::ShaderStorageBinding => uniform_builder.ask_shader_storage_block(name)?,
+      _ => uniform_builder.ask_uniform(name, Self::ty(), Self::SIZE)?,
+    };
+
+    Ok(uniform)
+  }
+
+  unsafe fn ask_uniform_named<T>(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    name: &UniformName,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    Self: for<'u> Uniformable<'u, T>,
+  {
+    match Self::ty() {
+      // blocks are not cached
+      UniformType::ShaderDataBinding | UniformType::ShaderStorageBinding => {
+        Self::ask_uniform(uniform_builder, name.as_str())
+      }
+      ty => uniform_builder.ask_named_uniform(name, ty, Self::SIZE),
+    }
+  }
+
+  unsafe fn ask_uniform_at_location<T>(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    location: i32,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    Self: for<'u> Uniformable<'u, T>,
+  {
+    match Self::ty() {
+      // blocks don’t have locations
+      ty @ (UniformType::ShaderDataBinding | UniformType::ShaderStorageBinding) => Err(
+        UniformWarning::unsupported_type(format!("location {}", location), ty),
+      ),
+      ty => uniform_builder.ask_uniform_at_location(location, ty, Self::SIZE),
+    }
+  }
+
+  unsafe fn active_uniforms(program: &Self::ProgramRepr) -> Vec<ActiveUniform> {
+    let mut count = 0;
+    gl::GetProgramiv(program.handle, gl::ACTIVE_UNIFORMS, &mut count);
+
+    let mut max_len = 0;
+    gl::GetProgramiv(program.handle, gl::ACTIVE_UNIFORM_MAX_LENGTH, &mut max_len);
+
+    let mut name = vec![0u8; max_len as usize];
+
+    (0..count as GLuint)
+      .filter_map(|index| {
+        // uniforms living in uniform blocks cannot be mapped on their own
+        let mut block_index = 0;
+        gl::GetActiveUniformsiv(
+          program.handle,
+          1,
+          &index,
+          gl::UNIFORM_BLOCK_INDEX,
+          &mut block_index,
+        );
+
+        if block_index != -1 {
+          return None;
+        }
+
+        let mut len = 0;
+        let mut size = 0;
+        let mut glty = 0;
+        gl::GetActiveUniform(
+          program.handle,
+          index,
+          max_len,
+          &mut len,
+          &mut size,
+          &mut glty,
+          name.as_mut_ptr() as *mut GLchar,
+        );
+
+        let name = &name[..len as usize];
+        let c_name = CString::new(name).unwrap();
+        let location = gl::GetUniformLocation(program.handle, c_name.as_ptr());
+
+        Some(ActiveUniform {
+          name: String::from_utf8_lossy(name).into_owned(),
+          location: if location < 0 { None } else { Some(location) },
+          ty: glenum_to_uniform_type(glty),
+          size: size as usize,
+        })
+      })
+      .collect()
+  }
+
+  unsafe fn active_vertex_attribs(program: &Self::ProgramRepr) -> Vec<ActiveVertexAttrib> {
+    active_vertex_attribs(program.handle)
+  }
+
+  unsafe fn geometry_config(program: &Self::ProgramRepr) -> Option<GeometryConfig> {
+    geometry_config(program.handle)
+  }
+
+  unsafe fn stage_log(stage: &Self::StageRepr) -> String {
+    stage.info_log()
+  }
+
+  unsafe fn program_log(program: &Self::ProgramRepr) -> String {
+    program.info_log()
+  }
+
+  unsafe fn unbound<T>(_: &mut Self::UniformBuilderRepr) -> Uniform<T>
+  where
+    Self: for<'u> Uniformable<'u, T>,
+  {
+    Uniform::new(-1)
+  }
+}
+
+unsafe impl ShaderReload for GL33 {
+  unsafe fn reload_program<Sem>(
+    &mut self,
+    program: &mut Self::ProgramRepr,
+    vertex: &Self::StageRepr,
+    tess: Option<TessellationStages<Self::StageRepr>>,
+    geometry: Option<&Self::StageRepr>,
+    fragment: &Self::StageRepr,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    Sem: Semantics,
+  {
+    // link a whole new program, so that the current one is left untouched on failure
+    let reloaded = self.new_program(vertex, tess, geometry, fragment)?;
+    program.replace_with::<Sem>(reloaded)
+  }
+
+  unsafe fn replace_stage<Sem>(
+    &mut self,
+    program: &mut Self::ProgramRepr,
+    stage: &Self::StageRepr,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    Sem: Semantics,
+  {
+    let attached = attached_shaders(program.handle);
+
+    // as with reload_program, a whole new program is linked; the stages it keeps are still alive, as they are attached
+    // to the current program
+    let handle = gl::CreateProgram();
+    let replaced_type = opengl_shader_type(stage.ty);
+
+    for shader in attached {
+      let mut ty = 0;
+      gl::GetShaderiv(shader, gl::SHADER_TYPE, &mut ty);
+
+      if Some(ty as GLenum) != replaced_type {
+        gl::AttachShader(handle, shader);
+      }
+    }
+
+    gl::AttachShader(handle, stage.handle);
+
+    // linked when applying the semantics
+    let reloaded = Program::new(handle, self.state.clone());
+    program.replace_with::<Sem>(reloaded)
+  }
+}
+
+#[cfg(feature = "GL_KHR_parallel_shader_compile")]
+unsafe impl ShaderDeferred for GL33 {
+  unsafe fn new_deferred_stage(
+    &mut self,
+    ty: StageType,
+    src: &str,
+  ) -> Result<Self::StageRepr, StageError> {
+    Stage::compile(ty, src)
+  }
+
+  unsafe fn new_deferred_program(
+    &mut self,
+    vertex: &Self::StageRepr,
+    tess: Option<TessellationStages<Self::StageRepr>>,
+    geometry: Option<&Self::StageRepr>,
+    fragment: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    Ok(Program::with_stages(
+      self.state.clone(),
+      vertex,
+      tess,
+      geometry,
+      fragment,
+    ))
+  }
+
+  unsafe fn start_deferred_link<Sem>(program: &mut Self::ProgramRepr)
+  where
+    Sem: Semantics,
+  {
+    // attributes are bound before linking, as we cannot know which ones are active without waiting for the link and
+    // linking again afterwards would block
+    for desc in Sem::semantics_set() {
+      let c_name = CString::new(desc.name.as_bytes()).unwrap();
+      gl::BindAttribLocation(
+        program.handle,
+        desc.index as GLuint,
+        c_name.as_ptr() as *const GLchar,
+      );
+    }
+
+    gl::LinkProgram(program.handle);
+  }
+
+  unsafe fn is_deferred_link_complete(program: &Self::ProgramRepr) -> bool {
+    let mut complete: GLint = gl::FALSE.into();
+    gl::GetProgramiv(program.handle, COMPLETION_STATUS_KHR, &mut complete);
+    complete == gl::TRUE.into()
+  }
+
+  unsafe fn finish_deferred_link<Sem>(
+    program: &mut Self::ProgramRepr,
+    stages: &[&Self::StageRepr],
+  ) -> Result<Vec<VertexAttribWarning>, ProgramError>
+  where
+    Sem: Semantics,
+  {
+    if let Err(e) = program.check_link() {
+      // stages failing to compile make the link fail; report them rather than the link error
+      for stage in stages {
+        stage.check_compilation()?;
+      }
+
+      return Err(e);
+    }
+
+    let warnings = Sem::semantics_set()
+      .iter()
+      .filter_map(|desc| get_vertex_attrib_location(program, &desc.name).err())
+      .collect();
+
+    Ok(warnings)
+  }
+}
+
+unsafe impl ShaderValidation for GL33 {
+  unsafe fn validate_program(program: &Self::ProgramRepr) -> ProgramValidation {
+    let handle = program.handle;
+    gl::ValidateProgram(handle);
+
+    let mut valid: GLint = gl::FALSE.into();
+    gl::GetProgramiv(handle, gl::VALIDATE_STATUS, &mut valid);
+
+    let mut log_len: GLint = 0;
+    gl::GetProgramiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
+
+    let mut log = vec![0u8; log_len as usize];
+    let mut written: GLsizei = 0;
+    if log_len > 0 {
+      gl::GetProgramInfoLog(
+        handle,
+        log_len,
+        &mut written,
+        log.as_mut_ptr() as *mut GLchar,
+      );
+    }
+    log.truncate(written as usize);
+
+    ProgramValidation {
+      valid: valid == gl::TRUE.into(),
+      log: String::from_utf8_lossy(&log).into_owned(),
+    }
+  }
+}
+
+#[cfg(feature = "GL_ARB_compute_shader")]
+unsafe impl ShaderCompute for GL33 {
+  unsafe fn new_compute_program(
+    &mut self,
+    compute: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    let handle = gl::CreateProgram();
+
+    gl::AttachShader(handle, compute.handle);
+
+    let program = Program::new(handle, self.state.clone());
+    program.link().map(move |_| program)
+  }
+}
+
+#[cfg(feature = "GL_NV_mesh_shader")]
+unsafe impl ShaderMesh for GL33 {
+  unsafe fn new_mesh_program(
+    &mut self,
+    task: Option<&Self::StageRepr>,
+    mesh: &Self::StageRepr,
+    fragment: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    if !mesh_shader::is_loaded() {
+      return Err(ProgramError::creation_failed(
+        "GL_NV_mesh_shader functions are not loaded",
+      ));
+    }
+
+    let handle = gl::CreateProgram();
+
+    if let Some(task) = task {
+      gl::AttachShader(handle, task.handle);
     }
+
+    gl::AttachShader(handle, mesh.handle);
+    gl::AttachShader(handle, fragment.handle);
+
+    let program = Program::new(handle, self.state.clone());
+    program.link().map(move |_| program)
   }
 }
 
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
 #[derive(Debug)]
-pub struct Program {
+pub struct ProgramPipeline {
   pub(crate) handle: GLuint,
 }
 
-impl Drop for Program {
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+impl Drop for ProgramPipeline {
   fn drop(&mut self) {
     unsafe {
-      gl::DeleteProgram(self.handle);
+      gl::DeleteProgramPipelines(1, &self.handle);
     }
   }
 }
 
-impl Program {
-  fn link(&self) -> Result<(), ProgramError> {
-    let handle = self.handle;
-
-    unsafe {
-      gl::LinkProgram(handle);
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+unsafe impl ShaderSeparable for GL33 {
+  type ProgramPipelineRepr = ProgramPipeline;
 
-      let mut linked: GLint = gl::FALSE.into();
-      gl::GetProgramiv(handle, gl::LINK_STATUS, &mut linked);
+  unsafe fn new_separable_program(
+    &mut self,
+    stage: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    let handle = gl::CreateProgram();
 
-      if linked == gl::TRUE.into() {
-        Ok(())
-      } else {
-        let mut log_len: GLint = 0;
-        gl::GetProgramiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
+    // must be set before linking
+    gl::ProgramParameteri(handle, gl::PROGRAM_SEPARABLE, gl::TRUE as GLint);
+    gl::AttachShader(handle, stage.handle);
 
-        let mut log: Vec<u8> = Vec::with_capacity(log_len as usize);
-        gl::GetProgramInfoLog(handle, log_len, null_mut(), log.as_mut_ptr() as *mut GLchar);
+    let mut program = Program::new(handle, self.state.clone());
+    program.stage_bits = opengl_shader_stage_bit(stage.ty);
+    program.link().map(move |_| program)
+  }
 
-        log.set_len(log_len as usize);
+  unsafe fn new_program_pipeline(&mut self) -> Result<Self::ProgramPipelineRepr, ProgramError> {
+    let mut handle: GLuint = 0;
+    gl::GenProgramPipelines(1, &mut handle);
 
-        Err(ProgramError::link_failed(String::from_utf8(log).unwrap()))
-      }
-    }
+    Ok(ProgramPipeline { handle })
   }
-}
-
-pub struct UniformBuilder {
-  handle: GLuint,
-}
 
-impl UniformBuilder {
-  fn new(program: &Program) -> Self {
-    UniformBuilder {
-      handle: program.handle,
+  unsafe fn use_program_stage(
+    pipeline: &mut Self::ProgramPipelineRepr,
+    program: &Self::ProgramRepr,
+  ) {
+    // non-separable programs don’t provide any stage
+    if program.stage_bits != 0 {
+      gl::UseProgramStages(pipeline.handle, program.stage_bits, program.handle);
     }
   }
+}
 
-  fn ask_uniform<T>(
-    &self,
+#[cfg(feature = "GL_ARB_shader_subroutine")]
+unsafe impl ShaderSubroutine for GL33 {
+  unsafe fn ask_subroutine_uniform(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    stage: StageType,
     name: &str,
-    ty: UniformType,
-    size: usize,
-  ) -> Result<Uniform<T>, UniformWarning>
-  where
-    GL33: for<'u> Uniformable<'u, T>,
-  {
-    let location = {
-      let c_name = CString::new(name.as_bytes()).unwrap();
-      unsafe { gl::GetUniformLocation(self.handle, c_name.as_ptr() as *const GLchar) }
-    };
+  ) -> Result<SubroutineUniform, UniformWarning> {
+    let c_name = uniform_c_name(name)?;
+    let shader_type = opengl_shader_type(stage).ok_or_else(|| UniformWarning::inactive(name))?;
+    let location = gl::GetSubroutineUniformLocation(
+      uniform_builder.handle,
+      shader_type,
+      c_name.as_ptr() as *const GLchar,
+    );
 
-    // ensure the location smells good
     if location < 0 {
       return Err(UniformWarning::inactive(name));
     }
 
-    // ensure the type is correct regarding what we have in the type-system
-    uniform_type_match(self.handle, name, ty, size)?;
-
-    Ok(unsafe { Uniform::new(location) })
+    Ok(SubroutineUniform::new(stage, location))
   }
 
-  fn ask_uniform_block<T>(&self, name: &str) -> Result<Uniform<T>, UniformWarning>
-  where
-    GL33: for<'u> Uniformable<'u, T>,
-  {
-    let location = {
-      let c_name = CString::new(name.as_bytes()).unwrap();
-      unsafe { gl::GetUniformBlockIndex(self.handle, c_name.as_ptr() as *const GLchar) }
-    };
+  unsafe fn ask_subroutine(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    stage: StageType,
+    name: &str,
+  ) -> Result<Subroutine, UniformWarning> {
+    let c_name = uniform_c_name(name)?;
+    let shader_type = opengl_shader_type(stage).ok_or_else(|| UniformWarning::inactive(name))?;
+    let index = gl::GetSubroutineIndex(
+      uniform_builder.handle,
+      shader_type,
+      c_name.as_ptr() as *const GLchar,
+    );
 
-    // ensure the location smells good
-    if location == gl::INVALID_INDEX {
+    if index == gl::INVALID_INDEX {
       return Err(UniformWarning::inactive(name));
     }
 
-    Ok(unsafe { Uniform::new(location as _) })
+    Ok(Subroutine::new(stage, index))
   }
-}
 
-unsafe impl Shader for GL33 {
-  type StageRepr = Stage;
+  unsafe fn set_subroutine(
+    program: &mut Self::ProgramRepr,
+    uniform: &SubroutineUniform,
+    subroutine: &Subroutine,
+  ) {
+    if uniform.stage() != subroutine.stage() || uniform.index() < 0 {
+      return;
+    }
 
-  type ProgramRepr = Program;
+    let stage = match opengl_shader_type(uniform.stage()) {
+      Some(stage) => stage,
+      None => return,
+    };
+    let handle = program.handle;
+
+    let indices = match program.subroutines.iter().position(|(s, _)| *s == stage) {
+      Some(i) => &mut program.subroutines[i].1,
+
+      None => {
+        // all the subroutine uniforms of a stage are set at once, so start from their current values
+        let mut count = 0;
+        gl::GetProgramStageiv(
+          handle,
+          stage,
+          gl::ACTIVE_SUBROUTINE_UNIFORM_LOCATIONS,
+          &mut count,
+        );
 
-  type UniformBuilderRepr = UniformBuilder;
+        let indices = (0..count)
+          .map(|location| {
+            let mut index = 0;
+            gl::GetUniformSubroutineuiv(stage, location, &mut index);
+            index
+          })
+          .collect();
 
-  unsafe fn new_stage(&mut self, ty: StageType, src: &str) -> Result<Self::StageRepr, StageError> {
-    let handle = gl::CreateShader(opengl_shader_type(ty));
+        program.subroutines.push((stage, indices));
+        &mut program.subroutines.last_mut().unwrap().1
+      }
+    };
 
-    if handle == 0 {
-      return Err(StageError::compilation_failed(
-        ty,
-        "unable to create shader stage",
-      ));
+    if let Some(index) = indices.get_mut(uniform.index() as usize) {
+      *index = subroutine.index();
+      gl::UniformSubroutinesuiv(stage, indices.len() as GLsizei, indices.as_ptr());
     }
+  }
+}
 
-    let c_src = CString::new(glsl_pragma_src(src).as_bytes()).unwrap();
-    gl::ShaderSource(handle, 1, [c_src.as_ptr()].as_ptr(), null());
-    gl::CompileShader(handle);
+fn opengl_shader_type(t: StageType) -> Option<GLenum> {
+  match t {
+    StageType::TessellationControlShader => Some(gl::TESS_CONTROL_SHADER),
+    StageType::TessellationEvaluationShader => Some(gl::TESS_EVALUATION_SHADER),
+    StageType::VertexShader => Some(gl::VERTEX_SHADER),
+    StageType::GeometryShader => Some(gl::GEOMETRY_SHADER),
+    StageType::FragmentShader => Some(gl::FRAGMENT_SHADER),
+    #[cfg(feature = "GL_ARB_compute_shader")]
+    StageType::ComputeShader => Some(gl::COMPUTE_SHADER),
+    #[cfg(not(feature = "GL_ARB_compute_shader"))]
+    StageType::ComputeShader => None,
+    #[cfg(feature = "GL_NV_mesh_shader")]
+    StageType::TaskShader => Some(mesh_shader::TASK_SHADER_NV),
+    #[cfg(feature = "GL_NV_mesh_shader")]
+    StageType::MeshShader => Some(mesh_shader::MESH_SHADER_NV),
+    #[cfg(not(feature = "GL_NV_mesh_shader"))]
+    StageType::TaskShader | StageType::MeshShader => None,
+  }
+}
 
-    let mut compiled: GLint = gl::FALSE.into();
-    gl::GetShaderiv(handle, gl::COMPILE_STATUS, &mut compiled);
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+fn opengl_shader_stage_bit(t: StageType) -> GLbitfield {
+  match t {
+    StageType::TessellationControlShader => gl::TESS_CONTROL_SHADER_BIT,
+    StageType::TessellationEvaluationShader => gl::TESS_EVALUATION_SHADER_BIT,
+    StageType::VertexShader => gl::VERTEX_SHADER_BIT,
+    StageType::GeometryShader => gl::GEOMETRY_SHADER_BIT,
+    StageType::FragmentShader => gl::FRAGMENT_SHADER_BIT,
+    StageType::ComputeShader => gl::COMPUTE_SHADER_BIT,
+    #[cfg(feature = "GL_NV_mesh_shader")]
+    StageType::TaskShader => mesh_shader::TASK_SHADER_BIT_NV,
+    #[cfg(feature = "GL_NV_mesh_shader")]
+    StageType::MeshShader => mesh_shader::MESH_SHADER_BIT_NV,
+    // such stages cannot be created
+    #[cfg(not(feature = "GL_NV_mesh_shader"))]
+    StageType::TaskShader | StageType::MeshShader => 0,
+  }
+}
 
-    if compiled == gl::TRUE.into() {
-      Ok(Stage { handle, ty })
-    } else {
-      let mut log_len: GLint = 0;
-      gl::GetShaderiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
+// not part of the generated bindings
+#[cfg(feature = "GL_KHR_parallel_shader_compile")]
+const COMPLETION_STATUS_KHR: GLenum = 0x91B1;
 
-      let mut log: Vec<u8> = Vec::with_capacity(log_len as usize);
-      gl::GetShaderInfoLog(handle, log_len, null_mut(), log.as_mut_ptr() as *mut GLchar);
+const GLSL_VERSION: &str = "#version 330 core\n";
 
-      gl::DeleteShader(handle);
+const GLSL_PRAGMA: &str = "#extension GL_ARB_separate_shader_objects : require\n";
 
-      log.set_len(log_len as usize);
+fn glsl_pragma_src(src: &str) -> String {
+  // sources declaring their own version keep it
+  let (version, body) = split_version_directive(src);
+  let mut pragma = String::from(if version.is_empty() {
+    GLSL_VERSION
+  } else {
+    version
+  });
 
-      Err(StageError::compilation_failed(
-        ty,
-        String::from_utf8(log).unwrap(),
-      ))
-    }
+  if !pragma.ends_with('\n') {
+    pragma.push('\n');
   }
 
-  unsafe fn new_program(
-    &mut self,
-    vertex: &Self::StageRepr,
-    tess: Option<TessellationStages<Self::StageRepr>>,
-    geometry: Option<&Self::StageRepr>,
-    fragment: &Self::StageRepr,
-  ) -> Result<Self::ProgramRepr, ProgramError> {
-    let handle = gl::CreateProgram();
-
-    if let Some(TessellationStages {
-      control,
-      evaluation,
-    }) = tess
-    {
-      gl::AttachShader(handle, control.handle);
-      gl::AttachShader(handle, evaluation.handle);
-    }
-
-    gl::AttachShader(handle, vertex.handle);
-
-    if let Some(geometry) = geometry {
-      gl::AttachShader(handle, geometry.handle);
-    }
+  pragma.push_str(GLSL_PRAGMA);
 
-    gl::AttachShader(handle, fragment.handle);
+  #[cfg(feature = "GL_ARB_bindless_texture")]
+  pragma.push_str("#extension GL_ARB_bindless_texture : require\n");
 
-    let program = Program { handle };
-    program.link().map(move |_| program)
-  }
+  #[cfg(feature = "GL_ARB_compute_shader")]
+  pragma.push_str("#extension GL_ARB_compute_shader : require\n");
 
-  unsafe fn apply_semantics<Sem>(
-    program: &mut Self::ProgramRepr,
-  ) -> Result<Vec<VertexAttribWarning>, ProgramError>
-  where
-    Sem: Semantics,
-  {
-    let warnings = bind_vertex_attribs_locations::<Sem>(program);
+  #[cfg(feature = "GL_ARB_explicit_uniform_location")]
+  pragma.push_str("#extension GL_ARB_explicit_uniform_location : require\n");
 
-    program.link()?;
+  #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+  pragma.push_str("#extension GL_ARB_gpu_shader_fp64 : require\n");
 
-    Ok(warnings)
-  }
+  #[cfg(feature = "GL_ARB_shader_atomic_counters")]
+  pragma.push_str("#extension GL_ARB_shader_atomic_counters : require\n");
 
-  unsafe fn new_uniform_builder(
-    program: &mut Self::ProgramRepr,
-  ) -> Result<Self::UniformBuilderRepr, ProgramError> {
-    Ok(UniformBuilder::new(&program))
-  }
+  #[cfg(feature = "GL_ARB_shader_image_load_store")]
+  pragma.push_str("#extension GL_ARB_shader_image_load_store : require\n");
 
-  unsafe fn ask_uniform<T>(
-    uniform_builder: &mut Self::UniformBuilderRepr,
-    name: &str,
-  ) -> Result<Uniform<T>, UniformWarning>
-  where
-    Self: for<'u> Uniformable<'u, T>,
-  {
-    let uniform = match Self::ty() {
-      UniformType::ShaderDataBinding => uniform_builder.ask_uniform_block(name)?,
-      _ => uniform_builder.ask_uniform(name, Self::ty(), Self::SIZE)?,
-    };
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  pragma.push_str("#extension GL_ARB_shader_storage_buffer_object : require\n");
 
-    Ok(uniform)
-  }
+  #[cfg(feature = "GL_ARB_shader_subroutine")]
+  pragma.push_str("#extension GL_ARB_shader_subroutine : require\n");
 
-  unsafe fn unbound<T>(_: &mut Self::UniformBuilderRepr) -> Uniform<T>
-  where
-    Self: for<'u> Uniformable<'u, T>,
-  {
-    Uniform::new(-1)
-  }
-}
+  // extensions must be enabled before setting the default layouts
+  pragma.push_str("layout(std140) uniform;\n");
 
-fn opengl_shader_type(t: StageType) -> GLenum {
-  match t {
-    StageType::TessellationControlShader => gl::TESS_CONTROL_SHADER,
-    StageType::TessellationEvaluationShader => gl::TESS_EVALUATION_SHADER,
-    StageType::VertexShader => gl::VERTEX_SHADER,
-    StageType::GeometryShader => gl::GEOMETRY_SHADER,
-    StageType::FragmentShader => gl::FRAGMENT_SHADER,
-  }
-}
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  pragma.push_str("layout(std140) buffer;\n");
 
-#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-const GLSL_PRAGMA: &str = "#version 330 core\n\
-                           #extension GL_ARB_separate_shader_objects : require\n
-                           #extension GL_ARB_gpu_shader_fp64 : require\n\
-                           layout(std140) uniform;\n";
-#[cfg(not(feature = "GL_ARB_gpu_shader_fp64"))]
-const GLSL_PRAGMA: &str = "#version 330 core\n\
-                           #extension GL_ARB_separate_shader_objects : require\n\
-                           layout(std140) uniform;\n";
+  // number the lines as if there was no preamble, so that diagnostics refer to the lines of the source
+  let body_line = src[..src.len() - body.len()].matches('\n').count() + 1;
+  pragma.push_str(&format!("#line {}\n", body_line));
 
-fn glsl_pragma_src(src: &str) -> String {
-  let mut pragma = String::from(GLSL_PRAGMA);
-  pragma.push_str(src);
+  pragma.push_str(body);
   pragma
 }
 
@@ -287,7 +1544,7 @@ fn uniform_type_match(
     // get the index of the uniform
     let mut index = 0;
 
-    let c_name = CString::new(name.as_bytes()).unwrap();
+    let c_name = uniform_c_name(name)?;
     gl::GetUniformIndices(
       program,
       1,
@@ -295,6 +1552,12 @@ fn uniform_type_match(
       &mut index,
     );
 
+    // the uniform might be active (it has a location) but not be reflected, which happens when the name refers to a
+    // part of an aggregate that the driver doesn’t list
+    if index == gl::INVALID_INDEX {
+      return Err(UniformWarning::inactive(name));
+    }
+
     // get its size and type
     let mut name_ = Vec::<GLchar>::with_capacity(max_len as usize);
     gl::GetActiveUniform(
@@ -308,12 +1571,173 @@ fn uniform_type_match(
     );
   }
 
-  let found_size = found_size as usize;
-  if size > 0 && found_size != size {
-    return Err(UniformWarning::size_mismatch(name, size, found_size));
+  check_uniform_size_match(name, size, found_size as usize)?;
+  check_uniform_type_match(name, ty, glty)
+}
+
+/// Convert a uniform name to a C string, failing if it contains a NUL byte.
+fn uniform_c_name(name: &str) -> Result<CString, UniformWarning> {
+  CString::new(name.as_bytes()).map_err(|_| UniformWarning::invalid_name(name))
+}
+
+/// Find the uniform living at `location` and check its type and size.
+///
+/// Arrays span several consecutive locations, so the location can refer to any item of an array; only the items from
+/// that location to the end of the array are then available.
+fn uniform_type_match_at_location(
+  program: GLuint,
+  name: &str,
+  location: GLint,
+  ty: UniformType,
+  size: usize,
+) -> Result<(), UniformWarning> {
+  unsafe {
+    let mut count = 0;
+    gl::GetProgramiv(program, gl::ACTIVE_UNIFORMS, &mut count);
+
+    let mut max_len = 0;
+    gl::GetProgramiv(program, gl::ACTIVE_UNIFORM_MAX_LENGTH, &mut max_len);
+
+    let mut uniform_name = vec![0u8; max_len as usize];
+
+    for index in 0..count as GLuint {
+      let mut found_size = 0;
+      let mut glty = 0;
+      gl::GetActiveUniform(
+        program,
+        index,
+        max_len,
+        null_mut(),
+        &mut found_size,
+        &mut glty,
+        uniform_name.as_mut_ptr() as *mut GLchar,
+      );
+
+      // the name is NUL-terminated, so it can be passed back directly
+      let found_location = gl::GetUniformLocation(program, uniform_name.as_ptr() as *const GLchar);
+
+      if found_location >= 0 && (found_location..found_location + found_size).contains(&location) {
+        check_uniform_size_match(
+          name,
+          size,
+          (found_location + found_size - location) as usize,
+        )?;
+        return check_uniform_type_match(name, ty, glty);
+      }
+    }
+  }
+
+  Err(UniformWarning::inactive(name))
+}
+
+/// Check that the requested size of a uniform fits in the declared size of the uniform in the shader.
+///
+/// A size of `0` is used by unsized uniforms (textures, shader data, etc.) and always matches. Arrays smaller than the
+/// declared array are accepted, as only the first items will be updated.
+fn check_uniform_size_match(
+  name: &str,
+  size: usize,
+  found_size: usize,
+) -> Result<(), UniformWarning> {
+  if size > found_size {
+    Err(UniformWarning::size_mismatch(name, size, found_size))
+  } else {
+    Ok(())
   }
+}
 
-  check_uniform_type_match(name, ty, glty)
+/// Call a macro with the list of supported uniform types, associated with their GLSL type constant.
+macro_rules! uniform_types {
+  ($m:ident) => {
+    $m!(
+      // scalars
+      (Int, INT),
+      (UInt, UNSIGNED_INT),
+      (Float, FLOAT),
+      (Double, DOUBLE),
+      (Bool, BOOL),
+      // vectors
+      (IVec2, INT_VEC2),
+      (IVec3, INT_VEC3),
+      (IVec4, INT_VEC4),
+      (UIVec2, UNSIGNED_INT_VEC2),
+      (UIVec3, UNSIGNED_INT_VEC3),
+      (UIVec4, UNSIGNED_INT_VEC4),
+      (Vec2, FLOAT_VEC2),
+      (Vec3, FLOAT_VEC3),
+      (Vec4, FLOAT_VEC4),
+      (DVec2, DOUBLE_VEC2),
+      (DVec3, DOUBLE_VEC3),
+      (DVec4, DOUBLE_VEC4),
+      (BVec2, BOOL_VEC2),
+      (BVec3, BOOL_VEC3),
+      (BVec4, BOOL_VEC4),
+      // matrices
+      (M22, FLOAT_MAT2),
+      (M33, FLOAT_MAT3),
+      (M44, FLOAT_MAT4),
+      (M23, FLOAT_MAT2x3),
+      (M32, FLOAT_MAT3x2),
+      (M24, FLOAT_MAT2x4),
+      (M42, FLOAT_MAT4x2),
+      (M34, FLOAT_MAT3x4),
+      (M43, FLOAT_MAT4x3),
+      (DM22, DOUBLE_MAT2),
+      (DM33, DOUBLE_MAT3),
+      (DM44, DOUBLE_MAT4),
+      (DM23, DOUBLE_MAT2x3),
+      (DM32, DOUBLE_MAT3x2),
+      (DM24, DOUBLE_MAT2x4),
+      (DM42, DOUBLE_MAT4x2),
+      (DM34, DOUBLE_MAT3x4),
+      (DM43, DOUBLE_MAT4x3),
+      // textures
+      (ISampler1D, INT_SAMPLER_1D),
+      (ISampler2D, INT_SAMPLER_2D),
+      (ISampler3D, INT_SAMPLER_3D),
+      (ISampler1DArray, INT_SAMPLER_1D_ARRAY),
+      (ISampler2DArray, INT_SAMPLER_2D_ARRAY),
+      (UISampler1D, UNSIGNED_INT_SAMPLER_1D),
+      (UISampler2D, UNSIGNED_INT_SAMPLER_2D),
+      (UISampler3D, UNSIGNED_INT_SAMPLER_3D),
+      (UISampler1DArray, UNSIGNED_INT_SAMPLER_1D_ARRAY),
+      (UISampler2DArray, UNSIGNED_INT_SAMPLER_2D_ARRAY),
+      (Sampler1D, SAMPLER_1D),
+      (Sampler2D, SAMPLER_2D),
+      (Sampler3D, SAMPLER_3D),
+      (Sampler1DArray, SAMPLER_1D_ARRAY),
+      (Sampler2DArray, SAMPLER_2D_ARRAY),
+      (ICubemap, INT_SAMPLER_CUBE),
+      (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
+      (Cubemap, SAMPLER_CUBE),
+      (Sampler1DShadow, SAMPLER_1D_SHADOW),
+      (Sampler2DShadow, SAMPLER_2D_SHADOW),
+      (Sampler1DArrayShadow, SAMPLER_1D_ARRAY_SHADOW),
+      (Sampler2DArrayShadow, SAMPLER_2D_ARRAY_SHADOW),
+      (CubemapShadow, SAMPLER_CUBE_SHADOW),
+      // images
+      (IImage1D, INT_IMAGE_1D),
+      (IImage2D, INT_IMAGE_2D),
+      (IImage3D, INT_IMAGE_3D),
+      (IImage1DArray, INT_IMAGE_1D_ARRAY),
+      (IImage2DArray, INT_IMAGE_2D_ARRAY),
+      (UIImage1D, UNSIGNED_INT_IMAGE_1D),
+      (UIImage2D, UNSIGNED_INT_IMAGE_2D),
+      (UIImage3D, UNSIGNED_INT_IMAGE_3D),
+      (UIImage1DArray, UNSIGNED_INT_IMAGE_1D_ARRAY),
+      (UIImage2DArray, UNSIGNED_INT_IMAGE_2D_ARRAY),
+      (Image1D, IMAGE_1D),
+      (Image2D, IMAGE_2D),
+      (Image3D, IMAGE_3D),
+      (Image1DArray, IMAGE_1D_ARRAY),
+      (Image2DArray, IMAGE_2D_ARRAY),
+      (IImageCubemap, INT_IMAGE_CUBE),
+      (UIImageCubemap, UNSIGNED_INT_IMAGE_CUBE),
+      (ImageCubemap, IMAGE_CUBE),
+      // atomic counters
+      (AtomicCounter, UNSIGNED_INT_ATOMIC_COUNTER),
+    )
+  };
 }
 
 #[allow(clippy::cognitive_complexity)]
@@ -324,14 +1748,14 @@ fn check_uniform_type_match(
 ) -> Result<(), UniformWarning> {
   // helper macro to check type mismatch for each variant
   macro_rules! milkcheck {
-    ($ty:expr, $( ( $v:tt, $t:tt ) ),* $(,)?) => {
-      match $ty {
+    ($( ( $v:tt, $t:tt ) ),* $(,)?) => {
+      match ty {
         $(
           UniformType::$v => {
             if glty == gl::$t {
               Ok(())
             } else {
-              Err(UniformWarning::type_mismatch(name, ty))
+              Err(UniformWarning::type_mismatch(name, ty, glenum_to_uniform_type(glty)))
             }
           }
         )*
@@ -341,57 +1765,176 @@ fn check_uniform_type_match(
     }
   }
 
-  milkcheck!(
-    ty,
-    // scalars
-    (Int, INT),
-    (UInt, UNSIGNED_INT),
-    (Float, FLOAT),
-    (Double, DOUBLE),
-    (Bool, BOOL),
-    // vectors
-    (IVec2, INT_VEC2),
-    (IVec3, INT_VEC3),
-    (IVec4, INT_VEC4),
-    (UIVec2, UNSIGNED_INT_VEC2),
-    (UIVec3, UNSIGNED_INT_VEC3),
-    (UIVec4, UNSIGNED_INT_VEC4),
-    (Vec2, FLOAT_VEC2),
-    (Vec3, FLOAT_VEC3),
-    (Vec4, FLOAT_VEC4),
-    (DVec2, DOUBLE_VEC2),
-    (DVec3, DOUBLE_VEC3),
-    (DVec4, DOUBLE_VEC4),
-    (BVec2, BOOL_VEC2),
-    (BVec3, BOOL_VEC3),
-    (BVec4, BOOL_VEC4),
-    // matrices
-    (M22, FLOAT_MAT2),
-    (M33, FLOAT_MAT3),
-    (M44, FLOAT_MAT4),
-    (DM22, DOUBLE_MAT2),
-    (DM33, DOUBLE_MAT3),
-    (DM44, DOUBLE_MAT4),
-    // textures
-    (ISampler1D, INT_SAMPLER_1D),
-    (ISampler2D, INT_SAMPLER_2D),
-    (ISampler3D, INT_SAMPLER_3D),
-    (ISampler1DArray, INT_SAMPLER_1D_ARRAY),
-    (ISampler2DArray, INT_SAMPLER_2D_ARRAY),
-    (UISampler1D, UNSIGNED_INT_SAMPLER_1D),
-    (UISampler2D, UNSIGNED_INT_SAMPLER_2D),
-    (UISampler3D, UNSIGNED_INT_SAMPLER_3D),
-    (UISampler1DArray, UNSIGNED_INT_SAMPLER_1D_ARRAY),
-    (UISampler2DArray, UNSIGNED_INT_SAMPLER_2D_ARRAY),
-    (Sampler1D, SAMPLER_1D),
-    (Sampler2D, SAMPLER_2D),
-    (Sampler3D, SAMPLER_3D),
-    (Sampler1DArray, SAMPLER_1D_ARRAY),
-    (Sampler2DArray, SAMPLER_2D_ARRAY),
-    (ICubemap, INT_SAMPLER_CUBE),
-    (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
-    (Cubemap, SAMPLER_CUBE),
-  )
+  uniform_types!(milkcheck)
+}
+
+/// Get the item index and field name of a uniform block member name, such as `items[3].color` or `Block.color`.
+///
+/// Names of members of nested structures are ignored, as they are not fields of the items.
+fn block_member_field(name: &str) -> Option<(usize, &str)> {
+  let (prefix, field) = name.rsplit_once('.')?;
+
+  if let Some(prefix) = prefix.strip_suffix(']') {
+    let (_, item) = prefix.rsplit_once('[')?;
+    Some((item.parse().ok()?, field))
+  } else if !prefix.contains(|c| c == '.' || c == '[') {
+    Some((0, field))
+  } else {
+    None
+  }
+}
+
+/// List the shader stages attached to a program.
+unsafe fn attached_shaders(handle: GLuint) -> Vec<GLuint> {
+  let mut count = 0;
+  gl::GetProgramiv(handle, gl::ATTACHED_SHADERS, &mut count);
+
+  let mut attached = vec![0; count as usize];
+  gl::GetAttachedShaders(handle, count, null_mut(), attached.as_mut_ptr());
+
+  attached
+}
+
+/// Stages provided by a separable program when used in a program pipeline.
+#[cfg(feature = "GL_ARB_separate_shader_objects")]
+unsafe fn attached_stage_bits(handle: GLuint) -> GLbitfield {
+  attached_shaders(handle)
+    .into_iter()
+    .map(|shader| {
+      let mut ty = 0;
+      gl::GetShaderiv(shader, gl::SHADER_TYPE, &mut ty);
+
+      match ty as GLenum {
+        gl::TESS_CONTROL_SHADER => gl::TESS_CONTROL_SHADER_BIT,
+        gl::TESS_EVALUATION_SHADER => gl::TESS_EVALUATION_SHADER_BIT,
+        gl::VERTEX_SHADER => gl::VERTEX_SHADER_BIT,
+        gl::GEOMETRY_SHADER => gl::GEOMETRY_SHADER_BIT,
+        gl::FRAGMENT_SHADER => gl::FRAGMENT_SHADER_BIT,
+        gl::COMPUTE_SHADER => gl::COMPUTE_SHADER_BIT,
+        #[cfg(feature = "GL_NV_mesh_shader")]
+        mesh_shader::TASK_SHADER_NV => mesh_shader::TASK_SHADER_BIT_NV,
+        #[cfg(feature = "GL_NV_mesh_shader")]
+        mesh_shader::MESH_SHADER_NV => mesh_shader::MESH_SHADER_BIT_NV,
+        _ => 0,
+      }
+    })
+    .fold(0, |bits, bit| bits | bit)
+}
+
+/// Whether a program has tessellation stages.
+pub(crate) unsafe fn has_tessellation_stages(handle: GLuint) -> bool {
+  // the tessellation evaluation stage is the one required to consume patches
+  attached_shaders(handle).into_iter().any(|shader| {
+    let mut ty = 0;
+    gl::GetShaderiv(shader, gl::SHADER_TYPE, &mut ty);
+    ty as GLenum == gl::TESS_EVALUATION_SHADER
+  })
+}
+
+/// Get the configuration of the geometry stage of a program, if it has one.
+pub(crate) unsafe fn geometry_config(handle: GLuint) -> Option<GeometryConfig> {
+  // querying the geometry configuration of a program without geometry stage is an error
+  let has_geometry_stage = attached_shaders(handle).into_iter().any(|shader| {
+    let mut ty = 0;
+    gl::GetShaderiv(shader, gl::SHADER_TYPE, &mut ty);
+    ty as GLenum == gl::GEOMETRY_SHADER
+  });
+
+  if !has_geometry_stage {
+    return None;
+  }
+
+  let mut input = 0;
+  gl::GetProgramiv(handle, gl::GEOMETRY_INPUT_TYPE, &mut input);
+
+  let mut output = 0;
+  gl::GetProgramiv(handle, gl::GEOMETRY_OUTPUT_TYPE, &mut output);
+
+  let mut max_output_vertices = 0;
+  gl::GetProgramiv(handle, gl::GEOMETRY_VERTICES_OUT, &mut max_output_vertices);
+
+  let input = match input as GLenum {
+    gl::POINTS => GeometryInput::Points,
+    gl::LINES => GeometryInput::Lines,
+    gl::LINES_ADJACENCY => GeometryInput::LinesAdjacency,
+    gl::TRIANGLES => GeometryInput::Triangles,
+    gl::TRIANGLES_ADJACENCY => GeometryInput::TrianglesAdjacency,
+    _ => return None,
+  };
+
+  let output = match output as GLenum {
+    gl::POINTS => GeometryOutput::Points,
+    gl::LINE_STRIP => GeometryOutput::LineStrip,
+    gl::TRIANGLE_STRIP => GeometryOutput::TriangleStrip,
+    _ => return None,
+  };
+
+  Some(GeometryConfig {
+    input,
+    output,
+    max_output_vertices: max_output_vertices as usize,
+  })
+}
+
+/// List the active vertex attributes of a program, built-in ones excluded.
+pub(crate) unsafe fn active_vertex_attribs(handle: GLuint) -> Vec<ActiveVertexAttrib> {
+  let mut count = 0;
+  gl::GetProgramiv(handle, gl::ACTIVE_ATTRIBUTES, &mut count);
+
+  let mut max_len = 0;
+  gl::GetProgramiv(handle, gl::ACTIVE_ATTRIBUTE_MAX_LENGTH, &mut max_len);
+
+  // the name is passed back to get the location, so keep room for the nul byte
+  let mut name = vec![0u8; max_len as usize + 1];
+
+  (0..count as GLuint)
+    .filter_map(|index| {
+      let mut len = 0;
+      let mut size = 0;
+      let mut glty = 0;
+      gl::GetActiveAttrib(
+        handle,
+        index,
+        max_len,
+        &mut len,
+        &mut size,
+        &mut glty,
+        name.as_mut_ptr() as *mut GLchar,
+      );
+
+      // built-in attributes have no location
+      name[len as usize] = 0;
+      let location = gl::GetAttribLocation(handle, name.as_ptr() as *const GLchar);
+
+      if location < 0 {
+        return None;
+      }
+
+      Some(ActiveVertexAttrib {
+        name: String::from_utf8_lossy(&name[..len as usize]).into_owned(),
+        location: location as usize,
+        ty: glenum_to_uniform_type(glty),
+        size: size as usize,
+      })
+    })
+    .collect()
+}
+
+/// Get the [`UniformType`] of a GLSL type constant, if supported.
+fn glenum_to_uniform_type(glty: GLuint) -> Option<UniformType> {
+  macro_rules! reverse {
+    ($( ( $v:tt, $t:tt ) ),* $(,)?) => {
+      match glty {
+        $(
+          gl::$t => Some(UniformType::$v),
+        )*
+
+        _ => None,
+      }
+    }
+  }
+
+  uniform_types!(reverse)
 }
 
 fn bind_vertex_attribs_locations<Sem>(program: &Program) -> Vec<VertexAttribWarning>
@@ -434,8 +1977,26 @@ fn get_vertex_attrib_location(
   }
 }
 
+/// Update a uniform of a program with a `glUniform*` function.
+///
+/// With separate shader objects, the corresponding `glProgramUniform*` function is called instead, so that the program
+/// doesn’t have to be in use — which is never the case of the stage-only programs of a program pipeline.
+macro_rules! program_uniform {
+  ($program:expr, $f:tt, $pf:tt, $($arg:expr),* $(,)?) => {{
+    #[cfg(feature = "GL_ARB_separate_shader_objects")]
+    gl::$pf($program.handle, $($arg),*);
+
+    // the program is expected to be in use
+    #[cfg(not(feature = "GL_ARB_separate_shader_objects"))]
+    {
+      let _: &Program = $program;
+      gl::$f($($arg),*);
+    }
+  }};
+}
+
 macro_rules! impl_Uniformable {
-  (Arr<$t:ty>, $uty:tt, $f:tt) => {
+  (Arr<$t:ty>, $uty:tt, $f:tt, $pf:tt) => {
     unsafe impl<'a, const N: usize> Uniformable<'a, Arr<$t, N>> for GL33 {
       type Target = &'a [$t; N];
 
@@ -445,13 +2006,34 @@ macro_rules! impl_Uniformable {
         UniformType::$uty
       }
 
-      unsafe fn update(_: &mut Program, uniform: &'a Uniform<Arr<$t, N>>, value: Self::Target) {
-        gl::$f(uniform.index(), N as GLsizei, value.as_ptr() as _);
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<Arr<$t, N>>, value: Self::Target) {
+        let location = program.location(uniform);
+
+        if program.cache_uniform(location, value) {
+          program_uniform!(program, $f, $pf, location, N as GLsizei, value.as_ptr() as _);
+        }
+      }
+    }
+
+    unsafe impl<'a, const N: usize> UniformableRange<'a, $t, N> for GL33 {
+      unsafe fn update_range(
+        program: &mut Program,
+        uniform: &'a Uniform<Arr<$t, N>>,
+        offset: usize,
+        values: &'a [$t],
+      ) {
+        let location = program.location(uniform);
+
+        // items of inactive uniforms don’t have locations
+        if location >= 0 && program.cache_uniform_range(location, offset, values) {
+          let location = program.item_location(uniform, offset);
+          program_uniform!(program, $f, $pf, location, values.len() as GLsizei, values.as_ptr() as _);
+        }
       }
     }
   };
 
-  (vec $t:ty, $uty:tt, $f:tt) => {
+  (vec $t:ty, $uty:tt, $f:tt, $pf:tt) => {
     unsafe impl<'a> Uniformable<'a, $t> for GL33 {
       type Target = $t;
 
@@ -461,13 +2043,17 @@ macro_rules! impl_Uniformable {
         UniformType::$uty
       }
 
-      unsafe fn update(_: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
-        gl::$f(uniform.index(), 1, value.as_ptr());
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
+        let location = program.location(uniform);
+
+        if program.cache_uniform(location, &value) {
+          program_uniform!(program, $f, $pf, location, 1, value.as_ptr());
+        }
       }
     }
   };
 
-  ($t:ty, $uty:tt, $f:tt) => {
+  ($t:ty, $uty:tt, $f:tt, $pf:tt) => {
     unsafe impl<'a> Uniformable<'a, $t> for GL33 {
       type Target = $t;
 
@@ -477,14 +2063,22 @@ macro_rules! impl_Uniformable {
         UniformType::$uty
       }
 
-      unsafe fn update(_: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
-        gl::$f(uniform.index(), value);
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
+        let location = program.location(uniform);
+
+        if program.cache_uniform(location, &value) {
+          program_uniform!(program, $f, $pf, location, value);
+        }
       }
     }
   };
 
   // matrix notation
-  (mat Arr<$t:ty>, $uty:tt, $f:tt) => {
+  (mat Arr<$t:ty>, $uty:tt, $f:tt, $pf:tt) => {
+    impl_Uniformable!(mat Arr<$t>, $uty, $f, $pf, FALSE);
+  };
+
+  (mat Arr<$t:ty>, $uty:tt, $f:tt, $pf:tt, $transpose:ident) => {
     unsafe impl<'a, const N: usize> Uniformable<'a, Arr<$t, N>> for GL33 {
       type Target = &'a [$t; N];
 
@@ -494,18 +2088,54 @@ macro_rules! impl_Uniformable {
         UniformType::$uty
       }
 
-      unsafe fn update(_: &mut Program, uniform: &'a Uniform<Arr<$t, N>>, value: Self::Target) {
-        gl::$f(
-          uniform.index(),
-          N as GLsizei,
-          gl::FALSE,
-          value.as_ptr() as _,
-        );
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<Arr<$t, N>>, value: Self::Target) {
+        let location = program.location(uniform);
+
+        if program.cache_uniform(location, value) {
+          program_uniform!(
+            program,
+            $f,
+            $pf,
+            location,
+            N as GLsizei,
+            gl::$transpose,
+            value.as_ptr() as _,
+          );
+        }
+      }
+    }
+
+    unsafe impl<'a, const N: usize> UniformableRange<'a, $t, N> for GL33 {
+      unsafe fn update_range(
+        program: &mut Program,
+        uniform: &'a Uniform<Arr<$t, N>>,
+        offset: usize,
+        values: &'a [$t],
+      ) {
+        let location = program.location(uniform);
+
+        // items of inactive uniforms don’t have locations
+        if location >= 0 && program.cache_uniform_range(location, offset, values) {
+          let location = program.item_location(uniform, offset);
+          program_uniform!(
+            program,
+            $f,
+            $pf,
+            location,
+            values.len() as GLsizei,
+            gl::$transpose,
+            values.as_ptr() as _,
+          );
+        }
       }
     }
   };
 
-  (mat $t:ty, $uty:tt, $f:tt) => {
+  (mat $t:ty, $uty:tt, $f:tt, $pf:tt) => {
+    impl_Uniformable!(mat $t, $uty, $f, $pf, FALSE);
+  };
+
+  (mat $t:ty, $uty:tt, $f:tt, $pf:tt, $transpose:ident) => {
     unsafe impl<'a> Uniformable<'a, $t> for GL33 {
       type Target = $t;
 
@@ -515,81 +2145,206 @@ macro_rules! impl_Uniformable {
         UniformType::$uty
       }
 
-      unsafe fn update(_: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
-        gl::$f(uniform.index(), 1, gl::FALSE, value.as_ptr() as _);
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
+        let location = program.location(uniform);
+
+        if program.cache_uniform(location, &value) {
+          program_uniform!(program, $f, $pf, location, 1, gl::$transpose, value.as_ptr() as _);
+        }
       }
     }
   };
 }
 
-impl_Uniformable!(i32, Int, Uniform1i);
-impl_Uniformable!(vec Vec2<i32>, IVec2, Uniform2iv);
-impl_Uniformable!(vec Vec3<i32>, IVec3, Uniform3iv);
-impl_Uniformable!(vec Vec4<i32>, IVec4, Uniform4iv);
+impl_Uniformable!(i32, Int, Uniform1i, ProgramUniform1i);
+impl_Uniformable!(vec Vec2<i32>, IVec2, Uniform2iv, ProgramUniform2iv);
+impl_Uniformable!(vec Vec3<i32>, IVec3, Uniform3iv, ProgramUniform3iv);
+impl_Uniformable!(vec Vec4<i32>, IVec4, Uniform4iv, ProgramUniform4iv);
+
+impl_Uniformable!(Arr<i32>, Int, Uniform1iv, ProgramUniform1iv);
+impl_Uniformable!(Arr<Vec2<i32>>, IVec2, Uniform2iv, ProgramUniform2iv);
+impl_Uniformable!(Arr<Vec3<i32>>, IVec3, Uniform3iv, ProgramUniform3iv);
+impl_Uniformable!(Arr<Vec4<i32>>, IVec4, Uniform4iv, ProgramUniform4iv);
+
+impl_Uniformable!(u32, UInt, Uniform1ui, ProgramUniform1ui);
+impl_Uniformable!(vec Vec2<u32>, UIVec2, Uniform2uiv, ProgramUniform2uiv);
+impl_Uniformable!(vec Vec3<u32>, UIVec3, Uniform3uiv, ProgramUniform3uiv);
+impl_Uniformable!(vec Vec4<u32>, UIVec4, Uniform4uiv, ProgramUniform4uiv);
+impl_Uniformable!(Arr<u32>, UInt, Uniform1uiv, ProgramUniform1uiv);
+impl_Uniformable!(Arr<Vec2<u32>>, UIVec2, Uniform2uiv, ProgramUniform2uiv);
+impl_Uniformable!(Arr<Vec3<u32>>, UIVec3, Uniform3uiv, ProgramUniform3uiv);
+impl_Uniformable!(Arr<Vec4<u32>>, UIVec4, Uniform4uiv, ProgramUniform4uiv);
+
+impl_Uniformable!(f32, Float, Uniform1f, ProgramUniform1f);
+impl_Uniformable!(vec Vec2<f32>, Vec2, Uniform2fv, ProgramUniform2fv);
+impl_Uniformable!(vec Vec3<f32>, Vec3, Uniform3fv, ProgramUniform3fv);
+impl_Uniformable!(vec Vec4<f32>, Vec4, Uniform4fv, ProgramUniform4fv);
+impl_Uniformable!(Arr<f32>, Float, Uniform1fv, ProgramUniform1fv);
+impl_Uniformable!(Arr<Vec2<f32>>, Vec2, Uniform2fv, ProgramUniform2fv);
+impl_Uniformable!(Arr<Vec3<f32>>, Vec3, Uniform3fv, ProgramUniform3fv);
+impl_Uniformable!(Arr<Vec4<f32>>, Vec4, Uniform4fv, ProgramUniform4fv);
+
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(f64, Double, Uniform1d, ProgramUniform1d);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(vec Vec2<f64>, DVec2, Uniform2dv, ProgramUniform2dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(vec Vec3<f64>, DVec3, Uniform3dv, ProgramUniform3dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(vec Vec4<f64>, DVec4, Uniform4dv, ProgramUniform4dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(Arr<f64>, Double, Uniform1dv, ProgramUniform1dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(Arr<Vec2<f64>>, DVec2, Uniform2dv, ProgramUniform2dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(Arr<Vec3<f64>>, DVec3, Uniform3dv, ProgramUniform3dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(Arr<Vec4<f64>>, DVec4, Uniform4dv, ProgramUniform4dv);
+
+impl_Uniformable!(mat Mat22<f32>, M22, UniformMatrix2fv, ProgramUniformMatrix2fv);
+impl_Uniformable!(mat Arr<Mat22<f32>>, M22, UniformMatrix2fv, ProgramUniformMatrix2fv);
+
+impl_Uniformable!(mat Mat33<f32>, M33, UniformMatrix3fv, ProgramUniformMatrix3fv);
+impl_Uniformable!(mat Arr<Mat33<f32>>, M33, UniformMatrix3fv, ProgramUniformMatrix3fv);
+
+impl_Uniformable!(mat Mat44<f32>, M44, UniformMatrix4fv, ProgramUniformMatrix4fv);
+impl_Uniformable!(mat Arr<Mat44<f32>>, M44, UniformMatrix4fv, ProgramUniformMatrix4fv);
+
+impl_Uniformable!(mat Mat23<f32>, M23, UniformMatrix2x3fv, ProgramUniformMatrix2x3fv);
+impl_Uniformable!(mat Arr<Mat23<f32>>, M23, UniformMatrix2x3fv, ProgramUniformMatrix2x3fv);
+
+impl_Uniformable!(mat Mat32<f32>, M32, UniformMatrix3x2fv, ProgramUniformMatrix3x2fv);
+impl_Uniformable!(mat Arr<Mat32<f32>>, M32, UniformMatrix3x2fv, ProgramUniformMatrix3x2fv);
+
+impl_Uniformable!(mat Mat24<f32>, M24, UniformMatrix2x4fv, ProgramUniformMatrix2x4fv);
+impl_Uniformable!(mat Arr<Mat24<f32>>, M24, UniformMatrix2x4fv, ProgramUniformMatrix2x4fv);
+
+impl_Uniformable!(mat Mat42<f32>, M42, UniformMatrix4x2fv, ProgramUniformMatrix4x2fv);
+impl_Uniformable!(mat Arr<Mat42<f32>>, M42, UniformMatrix4x2fv, ProgramUniformMatrix4x2fv);
+
+impl_Uniformable!(mat Mat34<f32>, M34, UniformMatrix3x4fv, ProgramUniformMatrix3x4fv);
+impl_Uniformable!(mat Arr<Mat34<f32>>, M34, UniformMatrix3x4fv, ProgramUniformMatrix3x4fv);
+
+impl_Uniformable!(mat Mat43<f32>, M43, UniformMatrix4x3fv, ProgramUniformMatrix4x3fv);
+impl_Uniformable!(mat Arr<Mat43<f32>>, M43, UniformMatrix4x3fv, ProgramUniformMatrix4x3fv);
+
+// transposed matrices are uploaded as the transpose of the wrapped matrix
+impl_Uniformable!(mat Transposed<Mat22<f32>>, M22, UniformMatrix2fv, ProgramUniformMatrix2fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat22<f32>>>, M22, UniformMatrix2fv, ProgramUniformMatrix2fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat33<f32>>, M33, UniformMatrix3fv, ProgramUniformMatrix3fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat33<f32>>>, M33, UniformMatrix3fv, ProgramUniformMatrix3fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat44<f32>>, M44, UniformMatrix4fv, ProgramUniformMatrix4fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat44<f32>>>, M44, UniformMatrix4fv, ProgramUniformMatrix4fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat32<f32>>, M23, UniformMatrix2x3fv, ProgramUniformMatrix2x3fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat32<f32>>>, M23, UniformMatrix2x3fv, ProgramUniformMatrix2x3fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat23<f32>>, M32, UniformMatrix3x2fv, ProgramUniformMatrix3x2fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat23<f32>>>, M32, UniformMatrix3x2fv, ProgramUniformMatrix3x2fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat42<f32>>, M24, UniformMatrix2x4fv, ProgramUniformMatrix2x4fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat42<f32>>>, M24, UniformMatrix2x4fv, ProgramUniformMatrix2x4fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat24<f32>>, M42, UniformMatrix4x2fv, ProgramUniformMatrix4x2fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat24<f32>>>, M42, UniformMatrix4x2fv, ProgramUniformMatrix4x2fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat43<f32>>, M34, UniformMatrix3x4fv, ProgramUniformMatrix3x4fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat43<f32>>>, M34, UniformMatrix3x4fv, ProgramUniformMatrix3x4fv, TRUE);
+
+impl_Uniformable!(mat Transposed<Mat34<f32>>, M43, UniformMatrix4x3fv, ProgramUniformMatrix4x3fv, TRUE);
+impl_Uniformable!(mat Arr<Transposed<Mat34<f32>>>, M43, UniformMatrix4x3fv, ProgramUniformMatrix4x3fv, TRUE);
 
-impl_Uniformable!(Arr<i32>, Int, Uniform1iv);
-impl_Uniformable!(Arr<Vec2<i32>>, IVec2, Uniform2iv);
-impl_Uniformable!(Arr<Vec3<i32>>, IVec3, Uniform3iv);
-impl_Uniformable!(Arr<Vec4<i32>>, IVec4, Uniform4iv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat22<f64>, DM22, UniformMatrix2dv, ProgramUniformMatrix2dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat22<f64>>, DM22, UniformMatrix2dv, ProgramUniformMatrix2dv);
+
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat33<f64>, DM33, UniformMatrix3dv, ProgramUniformMatrix3dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat33<f64>>, DM33, UniformMatrix3dv, ProgramUniformMatrix3dv);
+
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat44<f64>, DM44, UniformMatrix4dv, ProgramUniformMatrix4dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat44<f64>>, DM44, UniformMatrix4dv, ProgramUniformMatrix4dv);
+
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat23<f64>, DM23, UniformMatrix2x3dv, ProgramUniformMatrix2x3dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat23<f64>>, DM23, UniformMatrix2x3dv, ProgramUniformMatrix2x3dv);
+
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat32<f64>, DM32, UniformMatrix3x2dv, ProgramUniformMatrix3x2dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat32<f64>>, DM32, UniformMatrix3x2dv, ProgramUniformMatrix3x2dv);
+
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat24<f64>, DM24, UniformMatrix2x4dv, ProgramUniformMatrix2x4dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat24<f64>>, DM24, UniformMatrix2x4dv, ProgramUniformMatrix2x4dv);
 
-impl_Uniformable!(u32, UInt, Uniform1ui);
-impl_Uniformable!(vec Vec2<u32>, UIVec2, Uniform2uiv);
-impl_Uniformable!(vec Vec3<u32>, UIVec3, Uniform3uiv);
-impl_Uniformable!(vec Vec4<u32>, UIVec4, Uniform4uiv);
-impl_Uniformable!(Arr<u32>, UInt, Uniform1uiv);
-impl_Uniformable!(Arr<Vec2<u32>>, UIVec2, Uniform2uiv);
-impl_Uniformable!(Arr<Vec3<u32>>, UIVec3, Uniform3uiv);
-impl_Uniformable!(Arr<Vec4<u32>>, UIVec4, Uniform4uiv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat42<f64>, DM42, UniformMatrix4x2dv, ProgramUniformMatrix4x2dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat42<f64>>, DM42, UniformMatrix4x2dv, ProgramUniformMatrix4x2dv);
 
-impl_Uniformable!(f32, Float, Uniform1f);
-impl_Uniformable!(vec Vec2<f32>, Vec2, Uniform2fv);
-impl_Uniformable!(vec Vec3<f32>, Vec3, Uniform3fv);
-impl_Uniformable!(vec Vec4<f32>, Vec4, Uniform4fv);
-impl_Uniformable!(Arr<f32>, Float, Uniform1fv);
-impl_Uniformable!(Arr<Vec2<f32>>, Vec2, Uniform2fv);
-impl_Uniformable!(Arr<Vec3<f32>>, Vec3, Uniform3fv);
-impl_Uniformable!(Arr<Vec4<f32>>, Vec4, Uniform4fv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Mat34<f64>, DM34, UniformMatrix3x4dv, ProgramUniformMatrix3x4dv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Mat34<f64>>, DM34, UniformMatrix3x4dv, ProgramUniformMatrix3x4dv);
 
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(f64, Double, Uniform1d);
+impl_Uniformable!(mat Mat43<f64>, DM43, UniformMatrix4x3dv, ProgramUniformMatrix4x3dv);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(vec Vec2<f64>, DVec2, Uniform2dv);
+impl_Uniformable!(mat Arr<Mat43<f64>>, DM43, UniformMatrix4x3dv, ProgramUniformMatrix4x3dv);
+
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(vec Vec3<f64>, DVec3, Uniform3dv);
+impl_Uniformable!(mat Transposed<Mat22<f64>>, DM22, UniformMatrix2dv, ProgramUniformMatrix2dv, TRUE);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(vec Vec4<f64>, DVec4, Uniform4dv);
+impl_Uniformable!(mat Arr<Transposed<Mat22<f64>>>, DM22, UniformMatrix2dv, ProgramUniformMatrix2dv, TRUE);
+
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(Arr<f64>, Double, Uniform1dv);
+impl_Uniformable!(mat Transposed<Mat33<f64>>, DM33, UniformMatrix3dv, ProgramUniformMatrix3dv, TRUE);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(Arr<Vec2<f64>>, DVec2, Uniform2dv);
+impl_Uniformable!(mat Arr<Transposed<Mat33<f64>>>, DM33, UniformMatrix3dv, ProgramUniformMatrix3dv, TRUE);
+
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(Arr<Vec3<f64>>, DVec3, Uniform3dv);
+impl_Uniformable!(mat Transposed<Mat44<f64>>, DM44, UniformMatrix4dv, ProgramUniformMatrix4dv, TRUE);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(Arr<Vec4<f64>>, DVec4, Uniform4dv);
+impl_Uniformable!(mat Arr<Transposed<Mat44<f64>>>, DM44, UniformMatrix4dv, ProgramUniformMatrix4dv, TRUE);
 
-impl_Uniformable!(mat Mat22<f32>, M22, UniformMatrix2fv);
-impl_Uniformable!(mat Arr<Mat22<f32>>, M22, UniformMatrix2fv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Transposed<Mat32<f64>>, DM23, UniformMatrix2x3dv, ProgramUniformMatrix2x3dv, TRUE);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Transposed<Mat32<f64>>>, DM23, UniformMatrix2x3dv, ProgramUniformMatrix2x3dv, TRUE);
 
-impl_Uniformable!(mat Mat33<f32>, M33, UniformMatrix3fv);
-impl_Uniformable!(mat Arr<Mat33<f32>>, M33, UniformMatrix3fv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Transposed<Mat23<f64>>, DM32, UniformMatrix3x2dv, ProgramUniformMatrix3x2dv, TRUE);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Transposed<Mat23<f64>>>, DM32, UniformMatrix3x2dv, ProgramUniformMatrix3x2dv, TRUE);
 
-impl_Uniformable!(mat Mat44<f32>, M44, UniformMatrix4fv);
-impl_Uniformable!(mat Arr<Mat44<f32>>, M44, UniformMatrix4fv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Transposed<Mat42<f64>>, DM24, UniformMatrix2x4dv, ProgramUniformMatrix2x4dv, TRUE);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_Uniformable!(mat Arr<Transposed<Mat42<f64>>>, DM24, UniformMatrix2x4dv, ProgramUniformMatrix2x4dv, TRUE);
 
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(mat Mat22<f64>, DM22, UniformMatrix2dv);
+impl_Uniformable!(mat Transposed<Mat24<f64>>, DM42, UniformMatrix4x2dv, ProgramUniformMatrix4x2dv, TRUE);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(mat Arr<Mat22<f64>>, DM22, UniformMatrix2dv);
+impl_Uniformable!(mat Arr<Transposed<Mat24<f64>>>, DM42, UniformMatrix4x2dv, ProgramUniformMatrix4x2dv, TRUE);
 
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(mat Mat33<f64>, DM33, UniformMatrix3dv);
+impl_Uniformable!(mat Transposed<Mat43<f64>>, DM34, UniformMatrix3x4dv, ProgramUniformMatrix3x4dv, TRUE);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(mat Arr<Mat33<f64>>, DM33, UniformMatrix3dv);
+impl_Uniformable!(mat Arr<Transposed<Mat43<f64>>>, DM34, UniformMatrix3x4dv, ProgramUniformMatrix3x4dv, TRUE);
 
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(mat Mat44<f64>, DM44, UniformMatrix4dv);
+impl_Uniformable!(mat Transposed<Mat34<f64>>, DM43, UniformMatrix4x3dv, ProgramUniformMatrix4x3dv, TRUE);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(mat Arr<Mat44<f64>>, DM44, UniformMatrix4dv);
+impl_Uniformable!(mat Arr<Transposed<Mat34<f64>>>, DM43, UniformMatrix4x3dv, ProgramUniformMatrix4x3dv, TRUE);
 
 unsafe impl<'a> Uniformable<'a, bool> for GL33 {
   type Target = bool;
@@ -600,8 +2355,14 @@ unsafe impl<'a> Uniformable<'a, bool> for GL33 {
     UniformType::Bool
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<bool>, value: Self::Target) {
-    gl::Uniform1ui(uniform.index(), value as u32);
+  unsafe fn update(program: &mut Program, uniform: &'a Uniform<bool>, value: Self::Target) {
+    program_uniform!(
+      program,
+      Uniform1ui,
+      ProgramUniform1ui,
+      program.location(uniform),
+      value as u32
+    );
   }
 }
 
@@ -614,9 +2375,16 @@ unsafe impl<'a> Uniformable<'a, Vec2<bool>> for GL33 {
     UniformType::BVec2
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<Vec2<bool>>, value: Self::Target) {
+  unsafe fn update(program: &mut Program, uniform: &'a Uniform<Vec2<bool>>, value: Self::Target) {
     let v = [value[0] as u32, value[1] as u32];
-    gl::Uniform2uiv(uniform.index(), 1, v.as_ptr() as _);
+    program_uniform!(
+      program,
+      Uniform2uiv,
+      ProgramUniform2uiv,
+      program.location(uniform),
+      1,
+      v.as_ptr() as _
+    );
   }
 }
 
@@ -629,9 +2397,16 @@ unsafe impl<'a> Uniformable<'a, Vec3<bool>> for GL33 {
     UniformType::BVec3
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<Vec3<bool>>, value: Self::Target) {
+  unsafe fn update(program: &mut Program, uniform: &'a Uniform<Vec3<bool>>, value: Self::Target) {
     let v = [value[0] as u32, value[1] as u32, value[2] as u32];
-    gl::Uniform3uiv(uniform.index(), 1, v.as_ptr() as _);
+    program_uniform!(
+      program,
+      Uniform3uiv,
+      ProgramUniform3uiv,
+      program.location(uniform),
+      1,
+      v.as_ptr() as _
+    );
   }
 }
 
@@ -644,14 +2419,21 @@ unsafe impl<'a> Uniformable<'a, Vec4<bool>> for GL33 {
     UniformType::BVec4
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<Vec4<bool>>, value: Self::Target) {
+  unsafe fn update(program: &mut Program, uniform: &'a Uniform<Vec4<bool>>, value: Self::Target) {
     let v = [
       value[0] as u32,
       value[1] as u32,
       value[2] as u32,
       value[3] as u32,
     ];
-    gl::Uniform4uiv(uniform.index(), 1, v.as_ptr() as _);
+    program_uniform!(
+      program,
+      Uniform4uiv,
+      ProgramUniform4uiv,
+      program.location(uniform),
+      1,
+      v.as_ptr() as _
+    );
   }
 }
 
@@ -667,11 +2449,18 @@ unsafe impl<'a, const N: usize> Uniformable<'a, Arr<bool, N>> for GL33 {
     UniformType::Bool
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<Arr<bool, N>>, value: Self::Target) {
+  unsafe fn update(program: &mut Program, uniform: &'a Uniform<Arr<bool, N>>, value: Self::Target) {
     BOOL_CACHE.clear();
     BOOL_CACHE.extend(value.iter().map(|x| *x as u32));
 
-    gl::Uniform1uiv(uniform.index(), N as GLsizei, BOOL_CACHE.as_ptr() as _);
+    program_uniform!(
+      program,
+      Uniform1uiv,
+      ProgramUniform1uiv,
+      program.location(uniform),
+      N as GLsizei,
+      BOOL_CACHE.as_ptr() as _
+    );
   }
 }
 
@@ -684,11 +2473,22 @@ unsafe impl<'a, const N: usize> Uniformable<'a, Arr<Vec2<bool>, N>> for GL33 {
     UniformType::BVec2
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<Arr<Vec2<bool>, N>>, value: Self::Target) {
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<Arr<Vec2<bool>, N>>,
+    value: Self::Target,
+  ) {
     BOOL_CACHE.clear();
     BOOL_CACHE.extend(value.iter().flat_map(|x| [x[0] as u32, x[1] as u32]));
 
-    gl::Uniform2uiv(uniform.index(), N as GLsizei, BOOL_CACHE.as_ptr() as _);
+    program_uniform!(
+      program,
+      Uniform2uiv,
+      ProgramUniform2uiv,
+      program.location(uniform),
+      N as GLsizei,
+      BOOL_CACHE.as_ptr() as _
+    );
   }
 }
 
@@ -701,7 +2501,11 @@ unsafe impl<'a, const N: usize> Uniformable<'a, Arr<Vec3<bool>, N>> for GL33 {
     UniformType::BVec3
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<Arr<Vec3<bool>, N>>, value: Self::Target) {
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<Arr<Vec3<bool>, N>>,
+    value: Self::Target,
+  ) {
     BOOL_CACHE.clear();
     BOOL_CACHE.extend(
       value
@@ -709,7 +2513,14 @@ unsafe impl<'a, const N: usize> Uniformable<'a, Arr<Vec3<bool>, N>> for GL33 {
         .flat_map(|x| [x[0] as u32, x[1] as u32, x[2] as u32]),
     );
 
-    gl::Uniform3uiv(uniform.index(), N as GLsizei, BOOL_CACHE.as_ptr() as _);
+    program_uniform!(
+      program,
+      Uniform3uiv,
+      ProgramUniform3uiv,
+      program.location(uniform),
+      N as GLsizei,
+      BOOL_CACHE.as_ptr() as _
+    );
   }
 }
 
@@ -722,7 +2533,11 @@ unsafe impl<'a, const N: usize> Uniformable<'a, Arr<Vec4<bool>, N>> for GL33 {
     UniformType::BVec4
   }
 
-  unsafe fn update(_: &mut Program, uniform: &'a Uniform<Arr<Vec4<bool>, N>>, value: Self::Target) {
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<Arr<Vec4<bool>, N>>,
+    value: Self::Target,
+  ) {
     BOOL_CACHE.clear();
     BOOL_CACHE.extend(
       value
@@ -730,13 +2545,131 @@ unsafe impl<'a, const N: usize> Uniformable<'a, Arr<Vec4<bool>, N>> for GL33 {
         .flat_map(|x| [x[0] as u32, x[1] as u32, x[2] as u32, x[3] as u32]),
     );
 
-    gl::Uniform4uiv(uniform.index(), N as GLsizei, BOOL_CACHE.as_ptr() as _);
+    program_uniform!(
+      program,
+      Uniform4uiv,
+      ProgramUniform4uiv,
+      program.location(uniform),
+      N as GLsizei,
+      BOOL_CACHE.as_ptr() as _
+    );
   }
 }
 
+/// Implement [`Uniformable`] for a type from a math crate by converting it into the luminance type `$via`.
+#[allow(unused_macros)]
+macro_rules! impl_Uniformable_via {
+  // generic types convertible into arrays
+  (<$s:ident> $t:ty => $arr:ty => $via:ty) => {
+    unsafe impl<'a, $s> Uniformable<'a, $t> for GL33
+    where
+      $s: 'a,
+      $t: Into<$arr>,
+      $via: From<$arr>,
+      GL33: for<'u> Uniformable<'u, $via, Target = $via>,
+    {
+      type Target = $t;
+
+      const SIZE: usize = 1;
+
+      unsafe fn ty() -> UniformType {
+        <Self as Uniformable<$via>>::ty()
+      }
+
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
+        let uniform = Uniform::<$via>::new(uniform.index());
+        let value = <$via>::from(Into::<$arr>::into(value));
+        <Self as Uniformable<$via>>::update(program, &uniform, value);
+      }
+    }
+  };
+
+  // concrete types, with an explicit conversion into an array
+  ($t:ty => $via:ty, |$v:ident| $conv:expr) => {
+    unsafe impl<'a> Uniformable<'a, $t> for GL33 {
+      type Target = $t;
+
+      const SIZE: usize = 1;
+
+      unsafe fn ty() -> UniformType {
+        <Self as Uniformable<$via>>::ty()
+      }
+
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<$t>, $v: Self::Target) {
+        let uniform = Uniform::<$via>::new(uniform.index());
+        <Self as Uniformable<$via>>::update(program, &uniform, <$via>::from($conv));
+      }
+    }
+  };
+}
+
+#[cfg(feature = "cgmath")]
+impl_Uniformable_via!(<S> cgmath::Vector2<S> => [S; 2] => Vec2<S>);
+#[cfg(feature = "cgmath")]
+impl_Uniformable_via!(<S> cgmath::Vector3<S> => [S; 3] => Vec3<S>);
+#[cfg(feature = "cgmath")]
+impl_Uniformable_via!(<S> cgmath::Vector4<S> => [S; 4] => Vec4<S>);
+#[cfg(feature = "cgmath")]
+impl_Uniformable_via!(<S> cgmath::Matrix2<S> => [[S; 2]; 2] => Mat22<S>);
+#[cfg(feature = "cgmath")]
+impl_Uniformable_via!(<S> cgmath::Matrix3<S> => [[S; 3]; 3] => Mat33<S>);
+#[cfg(feature = "cgmath")]
+impl_Uniformable_via!(<S> cgmath::Matrix4<S> => [[S; 4]; 4] => Mat44<S>);
+
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::Vec2 => Vec2<f32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::Vec3 => Vec3<f32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::Vec4 => Vec4<f32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::IVec2 => Vec2<i32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::IVec3 => Vec3<i32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::IVec4 => Vec4<i32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::UVec2 => Vec2<u32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::UVec3 => Vec3<u32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::UVec4 => Vec4<u32>, |v| v.to_array());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::Mat2 => Mat22<f32>, |m| m.to_cols_array_2d());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::Mat3 => Mat33<f32>, |m| m.to_cols_array_2d());
+#[cfg(feature = "glam")]
+impl_Uniformable_via!(glam::Mat4 => Mat44<f32>, |m| m.to_cols_array_2d());
+
+#[cfg(feature = "mint")]
+impl_Uniformable_via!(<S> mint::Vector2<S> => [S; 2] => Vec2<S>);
+#[cfg(feature = "mint")]
+impl_Uniformable_via!(<S> mint::Vector3<S> => [S; 3] => Vec3<S>);
+#[cfg(feature = "mint")]
+impl_Uniformable_via!(<S> mint::Vector4<S> => [S; 4] => Vec4<S>);
+#[cfg(feature = "mint")]
+impl_Uniformable_via!(<S> mint::ColumnMatrix2<S> => [[S; 2]; 2] => Mat22<S>);
+#[cfg(feature = "mint")]
+impl_Uniformable_via!(<S> mint::ColumnMatrix3<S> => [[S; 3]; 3] => Mat33<S>);
+#[cfg(feature = "mint")]
+impl_Uniformable_via!(<S> mint::ColumnMatrix4<S> => [[S; 4]; 4] => Mat44<S>);
+
+#[cfg(feature = "nalgebra")]
+impl_Uniformable_via!(<S> nalgebra::Vector2<S> => [S; 2] => Vec2<S>);
+#[cfg(feature = "nalgebra")]
+impl_Uniformable_via!(<S> nalgebra::Vector3<S> => [S; 3] => Vec3<S>);
+#[cfg(feature = "nalgebra")]
+impl_Uniformable_via!(<S> nalgebra::Vector4<S> => [S; 4] => Vec4<S>);
+#[cfg(feature = "nalgebra")]
+impl_Uniformable_via!(<S> nalgebra::Matrix2<S> => [[S; 2]; 2] => Mat22<S>);
+#[cfg(feature = "nalgebra")]
+impl_Uniformable_via!(<S> nalgebra::Matrix3<S> => [[S; 3]; 3] => Mat33<S>);
+#[cfg(feature = "nalgebra")]
+impl_Uniformable_via!(<S> nalgebra::Matrix4<S> => [[S; 4]; 4] => Mat44<S>);
+
 unsafe impl<'a, T> Uniformable<'a, ShaderDataBinding<T>> for GL33
 where
-  T: 'a,
+  T: 'a + Std140,
 {
   type Target = ShaderDataBinding<T>;
 
@@ -751,14 +2684,143 @@ where
     uniform: &'a Uniform<ShaderDataBinding<T>>,
     value: Self::Target,
   ) {
-    gl::UniformBlockBinding(
-      program.handle,
-      uniform.index() as GLuint,
-      value.binding() as GLuint,
+    let index = program.location(uniform);
+
+    if index >= 0 {
+      gl::UniformBlockBinding(program.handle, index as GLuint, value.binding() as GLuint);
+    }
+  }
+
+  unsafe fn check(
+    uniform_builder: &mut UniformBuilder,
+    _: &str,
+    uniform: &Uniform<ShaderDataBinding<T>>,
+  ) -> Result<(), UniformWarning> {
+    uniform_builder.check_uniform_block_layout(
+      uniform_builder.location(uniform) as GLuint,
+      &T::std140_fields(),
+      mem::size_of::<<ArrElem<T> as Std140>::Encoded>(),
     )
   }
 }
 
+#[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+unsafe impl<'a, T> Uniformable<'a, ShaderStorageBinding<T>> for GL33
+where
+  T: 'a,
+{
+  type Target = ShaderStorageBinding<T>;
+
+  const SIZE: usize = 0;
+
+  unsafe fn ty() -> UniformType {
+    UniformType::ShaderStorageBinding
+  }
+
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<ShaderStorageBinding<T>>,
+    value: Self::Target,
+  ) {
+    let index = program.location(uniform);
+
+    if index >= 0 {
+      gl::ShaderStorageBlockBinding(program.handle, index as GLuint, value.binding() as GLuint);
+    }
+  }
+}
+
+/// Uniform type of a texture used as an image in shaders, depending on its dimension and sampler type.
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+fn image_uniform_type<D, S>() -> UniformType
+where
+  D: Dimensionable,
+  S: SamplerType,
+{
+  match (S::sample_type(), D::dim()) {
+    (PixelType::NormIntegral, Dim::Dim1) => UniformType::Image1D,
+    (PixelType::NormUnsigned, Dim::Dim1) => UniformType::Image1D,
+    (PixelType::Integral, Dim::Dim1) => UniformType::IImage1D,
+    (PixelType::Unsigned, Dim::Dim1) => UniformType::UIImage1D,
+    (PixelType::Floating, Dim::Dim1) => UniformType::Image1D,
+
+    (PixelType::NormIntegral, Dim::Dim2) => UniformType::Image2D,
+    (PixelType::NormUnsigned, Dim::Dim2) => UniformType::Image2D,
+    (PixelType::Integral, Dim::Dim2) => UniformType::IImage2D,
+    (PixelType::Unsigned, Dim::Dim2) => UniformType::UIImage2D,
+    (PixelType::Floating, Dim::Dim2) => UniformType::Image2D,
+
+    (PixelType::NormIntegral, Dim::Dim3) => UniformType::Image3D,
+    (PixelType::NormUnsigned, Dim::Dim3) => UniformType::Image3D,
+    (PixelType::Integral, Dim::Dim3) => UniformType::IImage3D,
+    (PixelType::Unsigned, Dim::Dim3) => UniformType::UIImage3D,
+    (PixelType::Floating, Dim::Dim3) => UniformType::Image3D,
+
+    (PixelType::NormIntegral, Dim::Cubemap) => UniformType::ImageCubemap,
+    (PixelType::NormUnsigned, Dim::Cubemap) => UniformType::ImageCubemap,
+    (PixelType::Integral, Dim::Cubemap) => UniformType::IImageCubemap,
+    (PixelType::Unsigned, Dim::Cubemap) => UniformType::UIImageCubemap,
+    (PixelType::Floating, Dim::Cubemap) => UniformType::ImageCubemap,
+
+    (PixelType::NormIntegral, Dim::Dim1Array) => UniformType::Image1DArray,
+    (PixelType::NormUnsigned, Dim::Dim1Array) => UniformType::Image1DArray,
+    (PixelType::Integral, Dim::Dim1Array) => UniformType::IImage1DArray,
+    (PixelType::Unsigned, Dim::Dim1Array) => UniformType::UIImage1DArray,
+    (PixelType::Floating, Dim::Dim1Array) => UniformType::Image1DArray,
+
+    (PixelType::NormIntegral, Dim::Dim2Array) => UniformType::Image2DArray,
+    (PixelType::NormUnsigned, Dim::Dim2Array) => UniformType::Image2DArray,
+    (PixelType::Integral, Dim::Dim2Array) => UniformType::IImage2DArray,
+    (PixelType::Unsigned, Dim::Dim2Array) => UniformType::UIImage2DArray,
+    (PixelType::Floating, Dim::Dim2Array) => UniformType::Image2DArray,
+  }
+}
+
+/// Uniform type of a texture used in shaders, depending on its dimension and sampler type.
+fn sampler_uniform_type<D, S>() -> UniformType
+where
+  D: Dimensionable,
+  S: SamplerType,
+{
+  match (S::sample_type(), D::dim()) {
+    (PixelType::NormIntegral, Dim::Dim1) => UniformType::Sampler1D,
+    (PixelType::NormUnsigned, Dim::Dim1) => UniformType::Sampler1D,
+    (PixelType::Integral, Dim::Dim1) => UniformType::ISampler1D,
+    (PixelType::Unsigned, Dim::Dim1) => UniformType::UISampler1D,
+    (PixelType::Floating, Dim::Dim1) => UniformType::Sampler1D,
+
+    (PixelType::NormIntegral, Dim::Dim2) => UniformType::Sampler2D,
+    (PixelType::NormUnsigned, Dim::Dim2) => UniformType::Sampler2D,
+    (PixelType::Integral, Dim::Dim2) => UniformType::ISampler2D,
+    (PixelType::Unsigned, Dim::Dim2) => UniformType::UISampler2D,
+    (PixelType::Floating, Dim::Dim2) => UniformType::Sampler2D,
+
+    (PixelType::NormIntegral, Dim::Dim3) => UniformType::Sampler3D,
+    (PixelType::NormUnsigned, Dim::Dim3) => UniformType::Sampler3D,
+    (PixelType::Integral, Dim::Dim3) => UniformType::ISampler3D,
+    (PixelType::Unsigned, Dim::Dim3) => UniformType::UISampler3D,
+    (PixelType::Floating, Dim::Dim3) => UniformType::Sampler3D,
+
+    (PixelType::NormIntegral, Dim::Cubemap) => UniformType::Cubemap,
+    (PixelType::NormUnsigned, Dim::Cubemap) => UniformType::Cubemap,
+    (PixelType::Integral, Dim::Cubemap) => UniformType::ICubemap,
+    (PixelType::Unsigned, Dim::Cubemap) => UniformType::UICubemap,
+    (PixelType::Floating, Dim::Cubemap) => UniformType::Cubemap,
+
+    (PixelType::NormIntegral, Dim::Dim1Array) => UniformType::Sampler1DArray,
+    (PixelType::NormUnsigned, Dim::Dim1Array) => UniformType::Sampler1DArray,
+    (PixelType::Integral, Dim::Dim1Array) => UniformType::ISampler1DArray,
+    (PixelType::Unsigned, Dim::Dim1Array) => UniformType::UISampler1DArray,
+    (PixelType::Floating, Dim::Dim1Array) => UniformType::Sampler1DArray,
+
+    (PixelType::NormIntegral, Dim::Dim2Array) => UniformType::Sampler2DArray,
+    (PixelType::NormUnsigned, Dim::Dim2Array) => UniformType::Sampler2DArray,
+    (PixelType::Integral, Dim::Dim2Array) => UniformType::ISampler2DArray,
+    (PixelType::Unsigned, Dim::Dim2Array) => UniformType::UISampler2DArray,
+    (PixelType::Floating, Dim::Dim2Array) => UniformType::Sampler2DArray,
+  }
+}
+
 unsafe impl<'a, D, S> Uniformable<'a, TextureBinding<D, S>> for GL33
 where
   D: 'a + Dimensionable,
@@ -769,51 +2831,118 @@ where
   const SIZE: usize = 0;
 
   unsafe fn ty() -> UniformType {
-    match (S::sample_type(), D::dim()) {
-      (PixelType::NormIntegral, Dim::Dim1) => UniformType::Sampler1D,
-      (PixelType::NormUnsigned, Dim::Dim1) => UniformType::Sampler1D,
-      (PixelType::Integral, Dim::Dim1) => UniformType::ISampler1D,
-      (PixelType::Unsigned, Dim::Dim1) => UniformType::UISampler1D,
-      (PixelType::Floating, Dim::Dim1) => UniformType::Sampler1D,
-
-      (PixelType::NormIntegral, Dim::Dim2) => UniformType::Sampler2D,
-      (PixelType::NormUnsigned, Dim::Dim2) => UniformType::Sampler2D,
-      (PixelType::Integral, Dim::Dim2) => UniformType::ISampler2D,
-      (PixelType::Unsigned, Dim::Dim2) => UniformType::UISampler2D,
-      (PixelType::Floating, Dim::Dim2) => UniformType::Sampler2D,
-
-      (PixelType::NormIntegral, Dim::Dim3) => UniformType::Sampler3D,
-      (PixelType::NormUnsigned, Dim::Dim3) => UniformType::Sampler3D,
-      (PixelType::Integral, Dim::Dim3) => UniformType::ISampler3D,
-      (PixelType::Unsigned, Dim::Dim3) => UniformType::UISampler3D,
-      (PixelType::Floating, Dim::Dim3) => UniformType::Sampler3D,
-
-      (PixelType::NormIntegral, Dim::Cubemap) => UniformType::Cubemap,
-      (PixelType::NormUnsigned, Dim::Cubemap) => UniformType::Cubemap,
-      (PixelType::Integral, Dim::Cubemap) => UniformType::ICubemap,
-      (PixelType::Unsigned, Dim::Cubemap) => UniformType::UICubemap,
-      (PixelType::Floating, Dim::Cubemap) => UniformType::Cubemap,
-
-      (PixelType::NormIntegral, Dim::Dim1Array) => UniformType::Sampler1DArray,
-      (PixelType::NormUnsigned, Dim::Dim1Array) => UniformType::Sampler1DArray,
-      (PixelType::Integral, Dim::Dim1Array) => UniformType::ISampler1DArray,
-      (PixelType::Unsigned, Dim::Dim1Array) => UniformType::UISampler1DArray,
-      (PixelType::Floating, Dim::Dim1Array) => UniformType::Sampler1DArray,
-
-      (PixelType::NormIntegral, Dim::Dim2Array) => UniformType::Sampler2DArray,
-      (PixelType::NormUnsigned, Dim::Dim2Array) => UniformType::Sampler2DArray,
-      (PixelType::Integral, Dim::Dim2Array) => UniformType::ISampler2DArray,
-      (PixelType::Unsigned, Dim::Dim2Array) => UniformType::UISampler2DArray,
-      (PixelType::Floating, Dim::Dim2Array) => UniformType::Sampler2DArray,
-    }
+    sampler_uniform_type::<D, S>()
   }
 
   unsafe fn update(
-    _: &mut Program,
+    program: &mut Program,
     uniform: &'a Uniform<TextureBinding<D, S>>,
     value: Self::Target,
   ) {
-    gl::Uniform1i(uniform.index(), value.binding() as GLint)
+    program_uniform!(
+      program,
+      Uniform1i,
+      ProgramUniform1i,
+      program.location(uniform),
+      value.binding() as GLint
+    )
+  }
+}
+
+/// Uniform type of a depth texture used with a shadow sampler, depending on its dimension.
+///
+/// There is no shadow sampler for 3D textures, so [`UniformType::Sampler3D`] is used, which will make
+/// the type check fail against any shadow sampler.
+fn shadow_uniform_type<D>() -> UniformType
+where
+  D: Dimensionable,
+{
+  match D::dim() {
+    Dim::Dim1 => UniformType::Sampler1DShadow,
+    Dim::Dim2 => UniformType::Sampler2DShadow,
+    Dim::Dim3 => UniformType::Sampler3D,
+    Dim::Cubemap => UniformType::CubemapShadow,
+    Dim::Dim1Array => UniformType::Sampler1DArrayShadow,
+    Dim::Dim2Array => UniformType::Sampler2DArrayShadow,
+  }
+}
+
+unsafe impl<'a, D> Uniformable<'a, ShadowBinding<D>> for GL33
+where
+  D: 'a + Dimensionable,
+{
+  type Target = ShadowBinding<D>;
+
+  const SIZE: usize = 0;
+
+  unsafe fn ty() -> UniformType {
+    shadow_uniform_type::<D>()
+  }
+
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<ShadowBinding<D>>,
+    value: Self::Target,
+  ) {
+    program_uniform!(
+      program,
+      Uniform1i,
+      ProgramUniform1i,
+      program.location(uniform),
+      value.binding() as GLint
+    )
+  }
+}
+
+#[cfg(feature = "GL_ARB_shader_image_load_store")]
+unsafe impl<'a, D, S> Uniformable<'a, ImageBinding<D, S>> for GL33
+where
+  D: 'a + Dimensionable,
+  S: 'a + SamplerType,
+{
+  type Target = ImageBinding<D, S>;
+
+  const SIZE: usize = 0;
+
+  unsafe fn ty() -> UniformType {
+    image_uniform_type::<D, S>()
+  }
+
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<ImageBinding<D, S>>,
+    value: Self::Target,
+  ) {
+    program_uniform!(
+      program,
+      Uniform1i,
+      ProgramUniform1i,
+      program.location(uniform),
+      value.binding() as GLint
+    )
+  }
+}
+
+#[cfg(feature = "GL_ARB_bindless_texture")]
+unsafe impl<'a, D, S> Uniformable<'a, TextureHandle<D, S>> for GL33
+where
+  D: 'a + Dimensionable,
+  S: 'a + SamplerType,
+{
+  type Target = TextureHandle<D, S>;
+
+  const SIZE: usize = 0;
+
+  unsafe fn ty() -> UniformType {
+    sampler_uniform_type::<D, S>()
+  }
+
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<TextureHandle<D, S>>,
+    value: Self::Target,
+  ) {
+    bindless::UniformHandleui64ARB(program.location(uniform), value.handle())
   }
 }
 
@@ -877,3 +3006,131 @@ where
     Ok(())
   }
 }
+
+#[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+unsafe impl<T> ShaderStorage<T> for GL33
+where
+  T: Std140,
+{
+  unsafe fn read_shader_storage(
+    shader_data: &Self::ShaderDataRepr,
+  ) -> Result<Vec<T>, ShaderDataError> {
+    // make shader writes visible to the mapping below
+    gl::MemoryBarrier(gl::BUFFER_UPDATE_BARRIER_BIT);
+
+    let slice = shader_data
+      .slice_buffer()
+      .map_err(|_| ShaderDataError::CannotReadData)?;
+
+    Ok(
+      slice
+        .iter()
+        .map(|&x| <ArrElem<T> as Std140>::std140_decode(x).0)
+        .collect(),
+    )
+  }
+}
+
+#[cfg(feature = "GL_ARB_shader_atomic_counters")]
+unsafe impl AtomicCounters for GL33 {
+  type AtomicCountersRepr = Buffer<u32>;
+
+  unsafe fn new_atomic_counters(
+    &mut self,
+    values: impl Iterator<Item = u32>,
+  ) -> Result<Self::AtomicCountersRepr, ShaderDataError> {
+    Ok(Buffer::from_vec(self, values.collect()))
+  }
+
+  unsafe fn read_atomic_counters(
+    counters: &Self::AtomicCountersRepr,
+  ) -> Result<Vec<u32>, ShaderDataError> {
+    // make shader atomic operations visible to the mapping below
+    gl::MemoryBarrier(gl::ATOMIC_COUNTER_BARRIER_BIT | gl::BUFFER_UPDATE_BARRIER_BIT);
+
+    let slice = counters
+      .slice_buffer()
+      .map_err(|_| ShaderDataError::CannotReadData)?;
+
+    Ok(slice.to_vec())
+  }
+
+  unsafe fn set_atomic_counters(
+    counters: &mut Self::AtomicCountersRepr,
+    values: impl Iterator<Item = u32>,
+  ) -> Result<(), ShaderDataError> {
+    // check the number of values before touching any counter
+    let values: Vec<_> = values.collect();
+    if values.len() != counters.len() {
+      return Err(ShaderDataError::CannotReplaceData);
+    }
+
+    // do not overwrite counters still being operated on by shader stages
+    gl::MemoryBarrier(gl::BUFFER_UPDATE_BARRIER_BIT);
+
+    let mut slice = counters
+      .slice_buffer_mut()
+      .map_err(|_| ShaderDataError::CannotReplaceData)?;
+    slice.copy_from_slice(&values);
+
+    Ok(())
+  }
+
+  unsafe fn active_atomic_counters(program: &Self::ProgramRepr) -> Vec<ActiveAtomicCounter> {
+    let mut count = 0;
+    gl::GetProgramiv(program.handle, gl::ACTIVE_UNIFORMS, &mut count);
+
+    let mut max_len = 0;
+    gl::GetProgramiv(program.handle, gl::ACTIVE_UNIFORM_MAX_LENGTH, &mut max_len);
+
+    let mut name = vec![0u8; max_len as usize];
+
+    (0..count as GLuint)
+      .filter_map(|index| {
+        let mut len = 0;
+        let mut size = 0;
+        let mut glty = 0;
+        gl::GetActiveUniform(
+          program.handle,
+          index,
+          max_len,
+          &mut len,
+          &mut size,
+          &mut glty,
+          name.as_mut_ptr() as *mut GLchar,
+        );
+
+        if glty != gl::UNSIGNED_INT_ATOMIC_COUNTER {
+          return None;
+        }
+
+        let mut buffer_index = 0;
+        gl::GetActiveUniformsiv(
+          program.handle,
+          1,
+          &index,
+          gl::UNIFORM_ATOMIC_COUNTER_BUFFER_INDEX,
+          &mut buffer_index,
+        );
+
+        let mut binding = 0;
+        gl::GetActiveAtomicCounterBufferiv(
+          program.handle,
+          buffer_index as GLuint,
+          gl::ATOMIC_COUNTER_BUFFER_BINDING,
+          &mut binding,
+        );
+
+        let mut offset = 0;
+        gl::GetActiveUniformsiv(program.handle, 1, &index, gl::UNIFORM_OFFSET, &mut offset);
+
+        Some(ActiveAtomicCounter {
+          name: String::from_utf8_lossy(&name[..len as usize]).into_owned(),
+          binding: binding as u32,
+          offset: offset as usize,
+          size: size as usize,
+        })
+      })
+      .collect()
+  }
+}
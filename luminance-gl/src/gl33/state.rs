@@ -9,12 +9,17 @@ use crate::gl33::{
 use gl::types::*;
 use luminance::{
   blending::{Equation, Factor},
+  color_mask::ColorMask,
   depth_stencil::{Comparison, StencilOperations, StencilTest, Write},
   face_culling::{FaceCullingMode, FaceCullingOrder},
   scissor::ScissorRegion,
+  tess::TessLevels,
 };
 use std::{cell::RefCell, error, ffi::CStr, fmt, marker::PhantomData, os::raw::c_char};
 
+// anisotropic filtering is core only since OpenGL 4.6, so its constant is missing from the gl crate
+const MAX_TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FF;
+
 // TLS synchronization barrier for `GLState`.
 //
 // Note: disable on no_std.
@@ -26,6 +31,14 @@ pub(crate) struct BindingStack {
   pub(crate) free_texture_units: Vec<u32>,
   pub(crate) next_shader_data: u32,
   pub(crate) free_shader_data: Vec<u32>,
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  pub(crate) next_shader_storage: u32,
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  pub(crate) free_shader_storage: Vec<u32>,
+  #[cfg(feature = "GL_ARB_shader_image_load_store")]
+  pub(crate) next_image_unit: u32,
+  #[cfg(feature = "GL_ARB_shader_image_load_store")]
+  pub(crate) free_image_units: Vec<u32>,
 }
 
 impl BindingStack {
@@ -36,6 +49,14 @@ impl BindingStack {
       free_texture_units: Vec::new(),
       next_shader_data: 0,
       free_shader_data: Vec::new(),
+      #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+      next_shader_storage: 0,
+      #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+      free_shader_storage: Vec::new(),
+      #[cfg(feature = "GL_ARB_shader_image_load_store")]
+      next_image_unit: 0,
+      #[cfg(feature = "GL_ARB_shader_image_load_store")]
+      free_image_units: Vec::new(),
     }
   }
 }
@@ -124,6 +145,9 @@ pub struct GLState {
   // depth write
   depth_write: Cached<Write>,
 
+  // color masks
+  color_masks: Cached<(ColorMask, Vec<(usize, ColorMask)>)>,
+
   // stencil test
   stencil_test_enabled: Cached<bool>,
   stencil_test: Cached<StencilTest>,
@@ -144,6 +168,9 @@ pub struct GLState {
   // patch primitive vertex number
   patch_vertex_nb: Cached<usize>,
 
+  // patch default tessellation levels
+  patch_default_levels: Cached<TessLevels>,
+
   // texture
   current_texture_unit: Cached<GLenum>,
   bound_textures: Vec<(GLenum, GLuint)>,
@@ -159,6 +186,14 @@ pub struct GLState {
   // uniform buffer
   bound_uniform_buffers: Vec<GLuint>,
 
+  // shader storage buffer
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  bound_shader_storage_buffers: Vec<GLuint>,
+
+  // atomic counter buffer
+  #[cfg(feature = "GL_ARB_shader_atomic_counters")]
+  bound_atomic_counter_buffers: Vec<GLuint>,
+
   // array buffer
   bound_array_buffer: GLuint,
 
@@ -174,9 +209,15 @@ pub struct GLState {
   // shader program
   current_program: GLuint,
 
+  // whether shader programs skip uniform updates with unchanged values
+  uniform_cache_enabled: bool,
+
   // framebuffer sRGB
   srgb_framebuffer_enabled: Cached<bool>,
 
+  // seamless cubemap filtering
+  seamless_cubemap_enabled: Cached<bool>,
+
   // vendor name; cached when asked the first time and then re-used
   vendor_name: Option<String>,
 
@@ -186,11 +227,20 @@ pub struct GLState {
   // OpenGL version; cached when asked the first time and then re-used
   gl_version: Option<String>,
 
+  // OpenGL major and minor version numbers; cached when asked the first time and then re-used
+  gl_version_numbers: Option<(GLint, GLint)>,
+
   // GLSL version; cached when asked the first time and then re-used
   glsl_version: Option<String>,
 
   /// Maximum number of elements a texture array can hold.
   max_texture_array_elements: Option<usize>,
+
+  // maximum anisotropy; cached when asked the first time anisotropic filtering is supported and then re-used
+  max_anisotropy: Option<f32>,
+
+  // supported extensions; cached when asked the first time and then re-used
+  extensions: Option<Vec<String>>,
 }
 
 impl GLState {
@@ -228,6 +278,7 @@ impl GLState {
       let depth_test = Cached::new(get_ctx_depth_test()?);
       let depth_test_comparison = Cached::new(Comparison::Less);
       let depth_write = Cached::new(get_ctx_depth_write()?);
+      let color_masks = Cached::new((get_ctx_color_mask(), Vec::new()));
       let stencil_test_enabled = Cached::new(get_ctx_stencil_test_enabled()?);
       let stencil_test = Cached::new(get_ctx_stencil_test()?);
       let stencil_operations = Cached::new(get_ctx_stencil_operations()?);
@@ -236,23 +287,32 @@ impl GLState {
       let face_culling_mode = Cached::new(get_ctx_face_culling_mode()?);
       let vertex_restart = Cached::new(get_ctx_vertex_restart()?);
       let patch_vertex_nb = Cached::new(0);
+      let patch_default_levels = Cached::new(TessLevels::default());
       let current_texture_unit = Cached::new(get_ctx_current_texture_unit()?);
       let bound_textures = vec![(gl::TEXTURE_2D, 0); 48]; // 48 is the platform minimal requirement
       let texture_swimming_pool = Vec::new();
       let bound_uniform_buffers = vec![0; 36]; // 36 is the platform minimal requirement
+      #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+      let bound_shader_storage_buffers = vec![0; 8]; // 8 is the platform minimal requirement
+      #[cfg(feature = "GL_ARB_shader_atomic_counters")]
+      let bound_atomic_counter_buffers = vec![0; 1]; // 1 is the platform minimal requirement
       let bound_array_buffer = 0;
       let bound_element_array_buffer = 0;
       let bound_draw_framebuffer = Cached::new(get_ctx_bound_draw_framebuffer()?);
       let bound_vertex_array = get_ctx_bound_vertex_array()?;
       let current_program = get_ctx_current_program()?;
       let srgb_framebuffer_enabled = Cached::new(get_ctx_srgb_framebuffer_enabled()?);
+      let seamless_cubemap_enabled = Cached::new(get_ctx_seamless_cubemap_enabled()?);
       let scissor_state = Cached::new(get_ctx_scissor_state()?);
       let scissor_region = Cached::new(get_ctx_scissor_region()?);
       let vendor_name = None;
       let renderer_name = None;
       let gl_version = None;
+      let gl_version_numbers = None;
       let glsl_version = None;
       let max_texture_array_elements = None;
+      let max_anisotropy = None;
+      let extensions = None;
 
       Ok(GLState {
         _a: PhantomData,
@@ -267,6 +327,7 @@ impl GLState {
         depth_test,
         depth_test_comparison,
         depth_write,
+        color_masks,
         stencil_test_enabled,
         stencil_test,
         stencil_operations,
@@ -275,23 +336,33 @@ impl GLState {
         face_culling_mode,
         vertex_restart,
         patch_vertex_nb,
+        patch_default_levels,
         current_texture_unit,
         bound_textures,
         texture_swimming_pool,
         bound_uniform_buffers,
+        #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+        bound_shader_storage_buffers,
+        #[cfg(feature = "GL_ARB_shader_atomic_counters")]
+        bound_atomic_counter_buffers,
         bound_array_buffer,
         bound_element_array_buffer,
         bound_draw_framebuffer,
         bound_vertex_array,
         current_program,
+        uniform_cache_enabled: true,
         srgb_framebuffer_enabled,
+        seamless_cubemap_enabled,
         scissor_state,
         scissor_region,
         vendor_name,
         renderer_name,
         gl_version,
+        gl_version_numbers,
         glsl_version,
         max_texture_array_elements,
+        max_anisotropy,
+        extensions,
       })
     }
   }
@@ -340,6 +411,22 @@ impl GLState {
     }
   }
 
+  /// Invalidate the shader storage buffer bindings.
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  pub fn invalidate_bound_shader_storage_buffers(&mut self) {
+    for b in &mut self.bound_shader_storage_buffers {
+      *b = 0;
+    }
+  }
+
+  /// Invalidate the atomic counter buffer bindings.
+  #[cfg(feature = "GL_ARB_shader_atomic_counters")]
+  pub fn invalidate_bound_atomic_counter_buffers(&mut self) {
+    for b in &mut self.bound_atomic_counter_buffers {
+      *b = 0;
+    }
+  }
+
   /// Invalidate the currently in-use viewport.
   pub fn invalidate_viewport(&mut self) {
     self.viewport.invalidate()
@@ -380,6 +467,11 @@ impl GLState {
     self.depth_write.invalidate()
   }
 
+  /// Invalidate the currently in-use color masks.
+  pub fn invalidate_color_masks(&mut self) {
+    self.color_masks.invalidate()
+  }
+
   /// Invalidate the currently in-use face culling state.
   pub fn invalidate_face_culling_state(&mut self) {
     self.face_culling_state.invalidate()
@@ -405,11 +497,21 @@ impl GLState {
     self.patch_vertex_nb.invalidate()
   }
 
+  /// Invalidate the currently in-use patch default tessellation levels.
+  pub fn invalidate_patch_default_levels(&mut self) {
+    self.patch_default_levels.invalidate()
+  }
+
   /// Invalidate the currently in-use sRGB framebuffer state.
   pub fn invalidate_srgb_framebuffer_enabled(&mut self) {
     self.srgb_framebuffer_enabled.invalidate()
   }
 
+  /// Invalidate the currently in-use seamless cubemap filtering state.
+  pub fn invalidate_seamless_cubemap_enabled(&mut self) {
+    self.seamless_cubemap_enabled.invalidate()
+  }
+
   /// Marshal a string represented as `*const c_uchar`, represented by the input argument, into a `&str`.
   ///
   /// The string is returned in a lossy way, which means that non-unicode characters go wheeeeeeeeeeee.
@@ -454,6 +556,40 @@ impl GLState {
     })
   }
 
+  /// Check whether the OpenGL version is at least `major.minor`.
+  ///
+  /// Cache the version numbers on the first call and then re-use them for later calls.
+  pub fn has_gl_version(&mut self, major: GLint, minor: GLint) -> bool {
+    let version = *self.gl_version_numbers.get_or_insert_with(|| unsafe {
+      let (mut major, mut minor) = (0, 0);
+      gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
+      gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
+      (major, minor)
+    });
+
+    version >= (major, minor)
+  }
+
+  /// Check whether copies between images are supported.
+  pub fn supports_copy_image(&mut self) -> bool {
+    self.has_gl_version(4, 3) || self.has_extension("GL_ARB_copy_image")
+  }
+
+  /// Check whether immutable texture storage is supported.
+  pub fn supports_texture_storage(&mut self) -> bool {
+    self.has_gl_version(4, 2) || self.has_extension("GL_ARB_texture_storage")
+  }
+
+  /// Check whether framebuffer invalidation is supported.
+  pub fn supports_invalidate_subdata(&mut self) -> bool {
+    self.has_gl_version(4, 3) || self.has_extension("GL_ARB_invalidate_subdata")
+  }
+
+  /// Check whether texture views are supported.
+  pub fn supports_texture_views(&mut self) -> bool {
+    self.has_gl_version(4, 3) || self.has_extension("GL_ARB_texture_view")
+  }
+
   /// Get the GLSL version.
   ///
   /// Cache the version on the first call and then re-use it for later calls.
@@ -478,6 +614,75 @@ impl GLState {
     })
   }
 
+  /// Get the maximum number of color attachments a framebuffer can draw to.
+  pub fn get_max_color_attachments(&mut self) -> usize {
+    let mut max_attachments = 0;
+    let mut max_draw_buffers = 0;
+
+    unsafe {
+      gl::GetIntegerv(gl::MAX_COLOR_ATTACHMENTS, &mut max_attachments);
+      gl::GetIntegerv(gl::MAX_DRAW_BUFFERS, &mut max_draw_buffers);
+    }
+
+    max_attachments.min(max_draw_buffers) as usize
+  }
+
+  /// Get the maximum anisotropy of anisotropic filtering, if supported.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls.
+  pub fn get_max_anisotropy(&mut self) -> Option<f32> {
+    if self.max_anisotropy.is_none()
+      && (self.has_extension("GL_EXT_texture_filter_anisotropic")
+        || self.has_extension("GL_ARB_texture_filter_anisotropic"))
+    {
+      let mut max = 0.;
+      unsafe { gl::GetFloatv(MAX_TEXTURE_MAX_ANISOTROPY, &mut max) };
+      self.max_anisotropy = Some(max);
+    }
+
+    self.max_anisotropy
+  }
+
+  /// Check whether the color buffer of the default framebuffer has an sRGB encoding.
+  pub fn is_back_buffer_srgb(&mut self) -> bool {
+    let mut encoding = 0;
+
+    unsafe {
+      self.bind_draw_framebuffer(0);
+      gl::GetFramebufferAttachmentParameteriv(
+        gl::DRAW_FRAMEBUFFER,
+        gl::BACK_LEFT,
+        gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+        &mut encoding,
+      );
+    }
+
+    encoding as GLenum == gl::SRGB
+  }
+
+  /// Check whether an extension is supported.
+  ///
+  /// Cache the list of supported extensions on the first call and then re-use it for later calls.
+  pub fn has_extension(&mut self, name: &str) -> bool {
+    self
+      .extensions
+      .get_or_insert_with(|| unsafe {
+        let mut count = 0;
+        gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count);
+
+        (0..count as GLuint)
+          .map(|i| {
+            let name_ptr = gl::GetStringi(gl::EXTENSIONS, i);
+            CStr::from_ptr(name_ptr as *const c_char)
+              .to_string_lossy()
+              .into_owned()
+          })
+          .collect()
+      })
+      .iter()
+      .any(|ext| ext == name)
+  }
+
   pub(crate) fn binding_stack_mut(&mut self) -> &mut BindingStack {
     &mut self.binding_stack
   }
@@ -682,6 +887,36 @@ impl GLState {
     }
   }
 
+  /// Set the color mask of all color attachments, then the ones of specific color attachments.
+  pub(crate) unsafe fn set_color_masks(
+    &mut self,
+    color_mask: ColorMask,
+    attachment_masks: &[(usize, ColorMask)],
+  ) {
+    let color_masks = (color_mask, attachment_masks.to_vec());
+
+    if self.color_masks.is_invalid(&color_masks) {
+      gl::ColorMask(
+        color_mask.red as GLboolean,
+        color_mask.green as GLboolean,
+        color_mask.blue as GLboolean,
+        color_mask.alpha as GLboolean,
+      );
+
+      for &(index, mask) in attachment_masks {
+        gl::ColorMaski(
+          index as GLuint,
+          mask.red as GLboolean,
+          mask.green as GLboolean,
+          mask.blue as GLboolean,
+          mask.alpha as GLboolean,
+        );
+      }
+
+      self.color_masks.set(color_masks);
+    }
+  }
+
   pub(crate) unsafe fn enable_stencil_test(&mut self, enable: bool) {
     if self.stencil_test_enabled.is_invalid(&enable) {
       if enable {
@@ -756,8 +991,23 @@ impl GLState {
   pub(crate) unsafe fn set_vertex_restart(&mut self, state: VertexRestart) {
     if self.vertex_restart.is_invalid(&state) {
       match state {
-        VertexRestart::On => gl::Enable(gl::PRIMITIVE_RESTART),
-        VertexRestart::Off => gl::Disable(gl::PRIMITIVE_RESTART),
+        VertexRestart::On => {
+          gl::Enable(gl::PRIMITIVE_RESTART);
+          #[cfg(feature = "GL_ARB_ES3_compatibility")]
+          gl::Disable(gl::PRIMITIVE_RESTART_FIXED_INDEX);
+        }
+
+        VertexRestart::Off => {
+          gl::Disable(gl::PRIMITIVE_RESTART);
+          #[cfg(feature = "GL_ARB_ES3_compatibility")]
+          gl::Disable(gl::PRIMITIVE_RESTART_FIXED_INDEX);
+        }
+
+        #[cfg(feature = "GL_ARB_ES3_compatibility")]
+        VertexRestart::FixedIndex => {
+          gl::Disable(gl::PRIMITIVE_RESTART);
+          gl::Enable(gl::PRIMITIVE_RESTART_FIXED_INDEX);
+        }
       }
 
       self.vertex_restart.set(state);
@@ -771,6 +1021,14 @@ impl GLState {
     }
   }
 
+  pub(crate) unsafe fn set_patch_default_levels(&mut self, levels: TessLevels) {
+    if self.patch_default_levels.is_invalid(&levels) {
+      gl::PatchParameterfv(gl::PATCH_DEFAULT_OUTER_LEVEL, levels.outer.as_ptr());
+      gl::PatchParameterfv(gl::PATCH_DEFAULT_INNER_LEVEL, levels.inner.as_ptr());
+      self.patch_default_levels.set(levels);
+    }
+  }
+
   pub(crate) unsafe fn set_texture_unit(&mut self, unit: u32) {
     let unit = unit as GLenum;
 
@@ -848,6 +1106,50 @@ impl GLState {
     }
   }
 
+  #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+  pub(crate) unsafe fn bind_shader_storage_buffer(&mut self, handle: GLuint, binding: u32) {
+    let binding_ = binding as usize;
+
+    match self.bound_shader_storage_buffers.get(binding_) {
+      Some(&handle_) if handle != handle_ => {
+        gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, binding as GLuint, handle);
+        self.bound_shader_storage_buffers[binding_] = handle;
+      }
+
+      None => {
+        gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, binding as GLuint, handle);
+
+        // not enough registered buffer bindings; let’s grow a bit more
+        self.bound_shader_storage_buffers.resize(binding_ + 1, 0);
+        self.bound_shader_storage_buffers[binding_] = handle;
+      }
+
+      _ => (), // cached
+    }
+  }
+
+  #[cfg(feature = "GL_ARB_shader_atomic_counters")]
+  pub(crate) unsafe fn bind_atomic_counter_buffer(&mut self, handle: GLuint, binding: u32) {
+    let binding_ = binding as usize;
+
+    match self.bound_atomic_counter_buffers.get(binding_) {
+      Some(&handle_) if handle != handle_ => {
+        gl::BindBufferBase(gl::ATOMIC_COUNTER_BUFFER, binding as GLuint, handle);
+        self.bound_atomic_counter_buffers[binding_] = handle;
+      }
+
+      None => {
+        gl::BindBufferBase(gl::ATOMIC_COUNTER_BUFFER, binding as GLuint, handle);
+
+        // not enough registered buffer bindings; let’s grow a bit more
+        self.bound_atomic_counter_buffers.resize(binding_ + 1, 0);
+        self.bound_atomic_counter_buffers[binding_] = handle;
+      }
+
+      _ => (), // cached
+    }
+  }
+
   pub(crate) unsafe fn unbind_buffer(&mut self, handle: GLuint) {
     if self.bound_array_buffer == handle {
       self.bind_array_buffer(0, Bind::Cached);
@@ -860,6 +1162,21 @@ impl GLState {
     {
       *handle_ = 0;
     }
+
+    // a buffer can be bound both as uniform and shader storage buffer
+    #[cfg(feature = "GL_ARB_shader_storage_buffer_object")]
+    for handle_ in &mut self.bound_shader_storage_buffers {
+      if *handle_ == handle {
+        *handle_ = 0;
+      }
+    }
+
+    #[cfg(feature = "GL_ARB_shader_atomic_counters")]
+    for handle_ in &mut self.bound_atomic_counter_buffers {
+      if *handle_ == handle {
+        *handle_ = 0;
+      }
+    }
   }
 
   pub(crate) unsafe fn bind_draw_framebuffer(&mut self, handle: GLuint) {
@@ -880,6 +1197,10 @@ impl GLState {
     self.bind_vertex_array(0, Bind::Cached)
   }
 
+  pub(crate) fn current_program(&self) -> GLuint {
+    self.current_program
+  }
+
   pub(crate) unsafe fn use_program(&mut self, handle: GLuint) {
     if self.current_program != handle {
       gl::UseProgram(handle);
@@ -887,6 +1208,14 @@ impl GLState {
     }
   }
 
+  pub(crate) fn enable_uniform_cache(&mut self, enabled: bool) {
+    self.uniform_cache_enabled = enabled;
+  }
+
+  pub(crate) fn is_uniform_cache_enabled(&self) -> bool {
+    self.uniform_cache_enabled
+  }
+
   pub(crate) unsafe fn enable_srgb_framebuffer(&mut self, srgb_framebuffer_enabled: bool) {
     if self
       .srgb_framebuffer_enabled
@@ -901,6 +1230,21 @@ impl GLState {
       self.srgb_framebuffer_enabled.set(srgb_framebuffer_enabled);
     }
   }
+
+  pub(crate) unsafe fn enable_seamless_cubemap(&mut self, seamless_cubemap_enabled: bool) {
+    if self
+      .seamless_cubemap_enabled
+      .is_invalid(&seamless_cubemap_enabled)
+    {
+      if seamless_cubemap_enabled {
+        gl::Enable(gl::TEXTURE_CUBE_MAP_SEAMLESS);
+      } else {
+        gl::Disable(gl::TEXTURE_CUBE_MAP_SEAMLESS);
+      }
+
+      self.seamless_cubemap_enabled.set(seamless_cubemap_enabled);
+    }
+  }
 }
 
 /// Should the binding be cached or forced to the provided value?
@@ -975,6 +1319,8 @@ pub enum StateQueryError {
   UnknownVertexRestartState(GLboolean),
   /// Corrupted sRGB framebuffer state.
   UnknownSRGBFramebufferState(GLboolean),
+  /// Corrupted seamless cubemap filtering state.
+  UnknownSeamlessCubemapState(GLboolean),
   /// Corrupted scissor state.
   UnknownScissorState(GLboolean),
 }
@@ -1021,6 +1367,9 @@ impl fmt::Display for StateQueryError {
       StateQueryError::UnknownSRGBFramebufferState(ref s) => {
         write!(f, "unknown sRGB framebuffer state: {}", s)
       }
+      StateQueryError::UnknownSeamlessCubemapState(ref s) => {
+        write!(f, "unknown seamless cubemap state: {}", s)
+      }
       StateQueryError::UnknownScissorState(ref s) => write!(f, "unknown scissor state: {}", s),
     }
   }
@@ -1177,6 +1526,15 @@ unsafe fn get_ctx_depth_write() -> Result<Write, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_color_mask() -> ColorMask {
+  let mut data = [gl::FALSE; 4];
+
+  gl::GetBooleanv(gl::COLOR_WRITEMASK, data.as_mut_ptr());
+
+  let [red, green, blue, alpha] = data.map(|channel| channel != gl::FALSE);
+  ColorMask::new(red, green, blue, alpha)
+}
+
 unsafe fn get_ctx_stencil_test_enabled() -> Result<bool, StateQueryError> {
   let state = gl::IsEnabled(gl::STENCIL_TEST);
 
@@ -1264,6 +1622,11 @@ unsafe fn get_ctx_face_culling_mode() -> Result<FaceCullingMode, StateQueryError
 }
 
 unsafe fn get_ctx_vertex_restart() -> Result<VertexRestart, StateQueryError> {
+  #[cfg(feature = "GL_ARB_ES3_compatibility")]
+  if gl::IsEnabled(gl::PRIMITIVE_RESTART_FIXED_INDEX) == gl::TRUE {
+    return Ok(VertexRestart::FixedIndex);
+  }
+
   let state = gl::IsEnabled(gl::PRIMITIVE_RESTART);
 
   match state {
@@ -1307,6 +1670,16 @@ unsafe fn get_ctx_srgb_framebuffer_enabled() -> Result<bool, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_seamless_cubemap_enabled() -> Result<bool, StateQueryError> {
+  let state = gl::IsEnabled(gl::TEXTURE_CUBE_MAP_SEAMLESS);
+
+  match state {
+    gl::TRUE => Ok(true),
+    gl::FALSE => Ok(false),
+    _ => Err(StateQueryError::UnknownSeamlessCubemapState(state)),
+  }
+}
+
 /// Whether or not enable blending.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub(crate) enum BlendingState {
@@ -1,3 +1,5 @@
+#[cfg(feature = "GL_ARB_draw_indirect")]
+use crate::gl33::indirect::with_draw_indirect_buffer;
 use crate::gl33::{
   buffer::{Buffer, BufferSlice, BufferSliceMut},
   state::{Bind, GLState},
@@ -7,15 +9,16 @@ use crate::gl33::{
 use gl::{self, types::*};
 use luminance::backend::tess::{
   IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-  VertexSlice as VertexSliceBackend,
+  TessPatchLevels as TessPatchLevelsBackend, VertexSlice as VertexSliceBackend,
+  VertexUpload as VertexUploadBackend,
 };
 use luminance::tess::{
   Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
-  TessMapError, TessVertexData,
+  TessLevels, TessMapError, TessVertexData,
 };
 use luminance::vertex::{
   Deinterleave, Normalized, Vertex, VertexAttribDesc, VertexAttribDim, VertexAttribType,
-  VertexBufferDesc, VertexInstancing,
+  VertexBufferDesc,
 };
 use std::{cell::RefCell, marker::PhantomData, os::raw::c_void, ptr, rc::Rc};
 
@@ -29,6 +32,32 @@ where
   restart_index: Option<I>,
 }
 
+impl<I> IndexedDrawState<I>
+where
+  I: TessIndex,
+{
+  /// Enable vertex restart with the restart index, if any; disable it otherwise.
+  unsafe fn set_vertex_restart(&self, gfx_st: &mut GLState) {
+    match self.restart_index {
+      #[cfg(feature = "GL_ARB_ES3_compatibility")]
+      Some(restart_index)
+        if restart_index.try_into_u32() == I::FIXED_RESTART_INDEX.try_into_u32() =>
+      {
+        gfx_st.set_vertex_restart(VertexRestart::FixedIndex);
+      }
+
+      Some(restart_index) => {
+        gfx_st.set_vertex_restart(VertexRestart::On);
+        gl::PrimitiveRestartIndex(restart_index.try_into_u32().unwrap_or(0));
+      }
+
+      None => {
+        gfx_st.set_vertex_restart(VertexRestart::Off);
+      }
+    }
+  }
+}
+
 #[derive(Debug)]
 struct TessRaw<I>
 where
@@ -37,6 +66,7 @@ where
   vao: GLenum,
   mode: GLenum,
   patch_vert_nb: usize,
+  tess_levels: TessLevels,
   index_state: Option<IndexedDrawState<I>>,
   state: Rc<RefCell<GLState>>,
 }
@@ -45,20 +75,26 @@ impl<I> TessRaw<I>
 where
   I: TessIndex,
 {
+  /// Render the tessellation; `base_instance` must be `0` if `GL_ARB_base_instance` is not supported.
   unsafe fn render(
     &self,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_vertex: isize,
+    base_instance: usize,
   ) -> Result<(), TessError> {
     let vert_nb = vert_nb as GLsizei;
     let inst_nb = inst_nb as GLsizei;
+    let base_vertex = base_vertex as GLint;
+    let base_instance = base_instance as GLuint;
 
     let mut gfx_st = self.state.borrow_mut();
     gfx_st.bind_vertex_array(self.vao, Bind::Cached);
 
     if self.mode == gl::PATCHES {
       gfx_st.set_patch_vertex_nb(self.patch_vert_nb);
+      gfx_st.set_patch_default_levels(self.tess_levels);
     }
 
     match (I::INDEX_TYPE, self.index_state.as_ref()) {
@@ -66,31 +102,53 @@ where
         // indexed render
         let first = (index_ty.bytes() * start_index) as *const c_void;
 
-        if let Some(restart_index) = index_state.restart_index {
-          gfx_st.set_vertex_restart(VertexRestart::On);
-          gl::PrimitiveRestartIndex(restart_index.try_into_u32().unwrap_or(0));
-        } else {
-          gfx_st.set_vertex_restart(VertexRestart::Off);
-        }
+        index_state.set_vertex_restart(&mut gfx_st);
 
-        if inst_nb <= 1 {
-          gl::DrawElements(self.mode, vert_nb, index_type_to_glenum(index_ty), first);
-        } else {
-          gl::DrawElementsInstanced(
+        let index_ty = index_type_to_glenum(index_ty);
+
+        if base_instance != 0 {
+          gl::DrawElementsInstancedBaseVertexBaseInstance(
             self.mode,
             vert_nb,
-            index_type_to_glenum(index_ty),
+            index_ty,
             first,
-            inst_nb,
+            inst_nb.max(1),
+            base_vertex,
+            base_instance,
           );
+        } else if base_vertex != 0 {
+          if inst_nb <= 1 {
+            gl::DrawElementsBaseVertex(self.mode, vert_nb, index_ty, first, base_vertex);
+          } else {
+            gl::DrawElementsInstancedBaseVertex(
+              self.mode,
+              vert_nb,
+              index_ty,
+              first,
+              inst_nb,
+              base_vertex,
+            );
+          }
+        } else if inst_nb <= 1 {
+          gl::DrawElements(self.mode, vert_nb, index_ty, first);
+        } else {
+          gl::DrawElementsInstanced(self.mode, vert_nb, index_ty, first, inst_nb);
         }
       }
 
       _ => {
-        // direct render
-        let first = start_index as GLint;
+        // direct render; the base vertex is the same as starting further
+        let first = start_index as GLint + base_vertex;
 
-        if inst_nb <= 1 {
+        if base_instance != 0 {
+          gl::DrawArraysInstancedBaseInstance(
+            self.mode,
+            first,
+            vert_nb,
+            inst_nb.max(1),
+            base_instance,
+          );
+        } else if inst_nb <= 1 {
           gl::DrawArrays(self.mode, first, vert_nb);
         } else {
           gl::DrawArraysInstanced(self.mode, first, vert_nb, inst_nb);
@@ -100,6 +158,74 @@ where
 
     Ok(())
   }
+
+  /// Render the tessellation once per draw command of the `len` first ones of the indirect buffer `commands`.
+  ///
+  /// Nothing is rendered if the tessellation is not indexed.
+  #[cfg(feature = "GL_ARB_multi_draw_indirect")]
+  unsafe fn render_indirect(&self, commands: GLuint, len: usize) {
+    let index_ty = match (I::INDEX_TYPE, self.index_state.as_ref()) {
+      (Some(index_ty), Some(index_state)) => {
+        let mut gfx_st = self.state.borrow_mut();
+        gfx_st.bind_vertex_array(self.vao, Bind::Cached);
+
+        if self.mode == gl::PATCHES {
+          gfx_st.set_patch_vertex_nb(self.patch_vert_nb);
+          gfx_st.set_patch_default_levels(self.tess_levels);
+        }
+
+        index_state.set_vertex_restart(&mut gfx_st);
+        index_ty
+      }
+
+      _ => return,
+    };
+
+    with_draw_indirect_buffer(commands, || {
+      gl::MultiDrawElementsIndirect(
+        self.mode,
+        index_type_to_glenum(index_ty),
+        ptr::null(),
+        len as GLsizei,
+        0,
+      )
+    });
+  }
+
+  /// Render the tessellation as described by the draw command at the byte offset `offset` in the indirect buffer
+  /// `commands`.
+  ///
+  /// `indexed` tells whether the draw command picks indices; nothing is rendered if the tessellation is not indexed then.
+  #[cfg(feature = "GL_ARB_draw_indirect")]
+  unsafe fn render_indirect_at(&self, commands: GLuint, offset: usize, indexed: bool) {
+    let mut gfx_st = self.state.borrow_mut();
+
+    let index_ty = match (I::INDEX_TYPE, self.index_state.as_ref()) {
+      (Some(index_ty), Some(index_state)) if indexed => {
+        index_state.set_vertex_restart(&mut gfx_st);
+        Some(index_ty)
+      }
+
+      _ if indexed => return,
+      _ => None,
+    };
+
+    gfx_st.bind_vertex_array(self.vao, Bind::Cached);
+
+    if self.mode == gl::PATCHES {
+      gfx_st.set_patch_vertex_nb(self.patch_vert_nb);
+      gfx_st.set_patch_default_levels(self.tess_levels);
+    }
+
+    with_draw_indirect_buffer(commands, || match index_ty {
+      Some(index_ty) => gl::DrawElementsIndirect(
+        self.mode,
+        index_type_to_glenum(index_ty),
+        offset as *const c_void,
+      ),
+      None => gl::DrawArraysIndirect(self.mode, offset as *const c_void),
+    });
+  }
 }
 
 impl<I> Drop for TessRaw<I>
@@ -126,6 +252,56 @@ where
   instance_buffer: Option<Buffer<W>>,
 }
 
+#[cfg(feature = "GL_ARB_transform_feedback2")]
+impl<V, I, W> InterleavedTess<V, I, W>
+where
+  V: Vertex,
+  I: TessIndex,
+  W: Vertex,
+{
+  /// Primitive mode.
+  pub(crate) fn mode(&self) -> GLenum {
+    self.raw.mode
+  }
+
+  pub(crate) fn vertex_buffer(&self) -> Option<&Buffer<V>> {
+    self.vertex_buffer.as_ref()
+  }
+}
+
+impl<V, I, W> InterleavedTess<V, I, W>
+where
+  V: Vertex,
+  I: TessIndex,
+  W: Vertex,
+{
+  /// Render with a base vertex and a base instance; see [`TessRaw::render`].
+  pub(crate) unsafe fn render_base(
+    &self,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+    base_instance: usize,
+  ) -> Result<(), TessError> {
+    self
+      .raw
+      .render(start_index, vert_nb, inst_nb, base_vertex, base_instance)
+  }
+
+  /// Render from an indirect buffer; see [`TessRaw::render_indirect`].
+  #[cfg(feature = "GL_ARB_multi_draw_indirect")]
+  pub(crate) unsafe fn render_indirect(&self, commands: GLuint, len: usize) {
+    self.raw.render_indirect(commands, len)
+  }
+
+  /// Render from a single draw command of an indirect buffer; see [`TessRaw::render_indirect_at`].
+  #[cfg(feature = "GL_ARB_draw_indirect")]
+  pub(crate) unsafe fn render_indirect_at(&self, commands: GLuint, offset: usize, indexed: bool) {
+    self.raw.render_indirect_at(commands, offset, indexed)
+  }
+}
+
 unsafe impl<V, I, W> TessBackend<V, I, W, Interleaved> for GL33
 where
   V: TessVertexData<Interleaved, Data = Vec<V>>,
@@ -168,6 +344,7 @@ where
       vao,
       mode,
       patch_vert_nb,
+      tess_levels: TessLevels::default(),
       index_state,
       state,
     };
@@ -211,7 +388,22 @@ where
     vert_nb: usize,
     inst_nb: usize,
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(start_index, vert_nb, inst_nb, 0, 0)
+  }
+}
+
+unsafe impl<V, I, W> TessPatchLevelsBackend<V, I, W, Interleaved> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn default_tess_levels(tess: &Self::TessRepr) -> TessLevels {
+    tess.raw.tess_levels
+  }
+
+  unsafe fn set_default_tess_levels(tess: &mut Self::TessRepr, levels: TessLevels) {
+    tess.raw.tess_levels = levels;
   }
 }
 
@@ -241,6 +433,26 @@ where
   }
 }
 
+unsafe impl<V, I, W> VertexUploadBackend<V, I, W, Interleaved, V> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn upload_vertices(
+    tess: &mut Self::TessRepr,
+    offset: usize,
+    vertices: &[V],
+  ) -> Result<(), TessError> {
+    match tess.vertex_buffer {
+      Some(ref mut vb) => vb.write(offset, vertices),
+      None => Err(TessError::attributeless_error(
+        "cannot upload vertices to an attributeless tessellation",
+      )),
+    }
+  }
+}
+
 unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Interleaved> for GL33
 where
   V: TessVertexData<Interleaved, Data = Vec<V>>,
@@ -308,6 +520,39 @@ where
   _phantom: PhantomData<*const (V, W)>,
 }
 
+impl<V, I, W> DeinterleavedTess<V, I, W>
+where
+  V: Vertex,
+  I: TessIndex,
+  W: Vertex,
+{
+  /// Render with a base vertex and a base instance; see [`TessRaw::render`].
+  pub(crate) unsafe fn render_base(
+    &self,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+    base_instance: usize,
+  ) -> Result<(), TessError> {
+    self
+      .raw
+      .render(start_index, vert_nb, inst_nb, base_vertex, base_instance)
+  }
+
+  /// Render from an indirect buffer; see [`TessRaw::render_indirect`].
+  #[cfg(feature = "GL_ARB_multi_draw_indirect")]
+  pub(crate) unsafe fn render_indirect(&self, commands: GLuint, len: usize) {
+    self.raw.render_indirect(commands, len)
+  }
+
+  /// Render from a single draw command of an indirect buffer; see [`TessRaw::render_indirect_at`].
+  #[cfg(feature = "GL_ARB_draw_indirect")]
+  pub(crate) unsafe fn render_indirect_at(&self, commands: GLuint, offset: usize, indexed: bool) {
+    self.raw.render_indirect_at(commands, offset, indexed)
+  }
+}
+
 unsafe impl<V, I, W> TessBackend<V, I, W, Deinterleaved> for GL33
 where
   V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
@@ -350,6 +595,7 @@ where
       vao,
       mode,
       patch_vert_nb,
+      tess_levels: TessLevels::default(),
       index_state,
       state,
     };
@@ -394,7 +640,22 @@ where
     vert_nb: usize,
     inst_nb: usize,
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(start_index, vert_nb, inst_nb, 0, 0)
+  }
+}
+
+unsafe impl<V, I, W> TessPatchLevelsBackend<V, I, W, Deinterleaved> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn default_tess_levels(tess: &Self::TessRepr) -> TessLevels {
+    tess.raw.tess_levels
+  }
+
+  unsafe fn set_default_tess_levels(tess: &mut Self::TessRepr, levels: TessLevels) {
+    tess.raw.tess_levels = levels;
   }
 }
 
@@ -431,6 +692,27 @@ where
   }
 }
 
+unsafe impl<V, I, W, T> VertexUploadBackend<V, I, W, Deinterleaved, T> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>> + Deinterleave<T>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  T: Copy,
+{
+  unsafe fn upload_vertices(
+    tess: &mut Self::TessRepr,
+    offset: usize,
+    vertices: &[T],
+  ) -> Result<(), TessError> {
+    match tess.vertex_buffers.get_mut(V::RANK) {
+      Some(vb) => vb.write(offset, vertices),
+      None => Err(TessError::attributeless_error(
+        "cannot upload vertices to an attributeless tessellation",
+      )),
+    }
+  }
+}
+
 unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Deinterleaved> for GL33
 where
   V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
@@ -704,11 +986,7 @@ fn set_component_format(stride: GLsizei, off: usize, desc: &VertexBufferDesc) {
     }
 
     // set vertex attribute divisor based on the vertex instancing configuration
-    let divisor = match desc.instancing {
-      VertexInstancing::On => 1,
-      VertexInstancing::Off => 0,
-    };
-    gl::VertexAttribDivisor(index, divisor);
+    gl::VertexAttribDivisor(index, desc.instancing.divisor());
 
     gl::EnableVertexAttribArray(index);
   }
@@ -1,24 +1,47 @@
+#[cfg(feature = "GL_ARB_bindless_texture")]
+use crate::gl33::bindless;
 use crate::gl33::{
-  depth_stencil::comparison_to_glenum, pixel::opengl_pixel_format, state::GLState, GL33,
+  depth_stencil::comparison_to_glenum,
+  pixel::{opengl_compressed_format, opengl_pixel_format},
+  state::{GLState, ScissorState},
+  GL33,
 };
 use gl::{self, types::*};
+#[cfg(feature = "GL_ARB_bindless_texture")]
+use luminance::backend::texture::TextureBindless;
 use luminance::{
-  backend::texture::{Texture as TextureBackend, TextureBase},
-  pixel::{Pixel, PixelFormat},
-  texture::{Dim, Dimensionable, MagFilter, MinFilter, Sampler, TexelUpload, TextureError, Wrap},
+  backend::texture::{
+    Texture as TextureBackend, TextureBase, TextureCompressed, TextureCopy, TextureView,
+  },
+  pixel::{CompressedPixel, Format, Pixel, PixelFormat},
+  texture::{
+    Dim, Dimensionable, MagFilter, MinFilter, Sampler, Swizzle, TexelUpload, TextureError, Wrap,
+  },
 };
-use std::{cell::RefCell, mem, os::raw::c_void, ptr, rc::Rc};
+use std::{cell::RefCell, mem, ops::Range, os::raw::c_void, ptr, rc::Rc};
+
+// anisotropic filtering is core only since OpenGL 4.6, so its constant is missing from the gl crate
+const TEXTURE_MAX_ANISOTROPY: GLenum = 0x84FE;
 
 pub struct Texture {
   pub(crate) handle: GLuint, // handle to the GPU texture object
   pub(crate) target: GLenum, // “type” of the texture; used for bindings
   mipmaps: usize,
+  sampler: Sampler,
+  immutable: bool, // whether the storage was allocated with glTexStorage*; such storage cannot be reallocated
   state: Rc<RefCell<GLState>>,
+  #[cfg(feature = "GL_ARB_bindless_texture")]
+  resident_handle: Option<GLuint64>, // bindless handle, if resident
 }
 
 impl Drop for Texture {
   fn drop(&mut self) {
     unsafe {
+      #[cfg(feature = "GL_ARB_bindless_texture")]
+      if let Some(handle) = self.resident_handle.take() {
+        bindless::MakeTextureHandleNonResidentARB(handle);
+      }
+
       gl::DeleteTextures(1, &self.handle);
     }
   }
@@ -105,44 +128,75 @@ where
     <Self as TextureBackend<D, P>>::upload_part_raw(texture, D::ZERO_OFFSET, size, texels)
   }
 
-  unsafe fn get_raw_texels(
-    texture: &Self::TextureRepr,
-    _: D::Size,
-  ) -> Result<Vec<P::RawEncoding>, TextureError>
-  where
-    P::RawEncoding: Copy + Default,
-  {
-    let pf = P::pixel_format();
-    let (format, _, ty) = opengl_pixel_format(pf).unwrap();
+  unsafe fn upload_part_level(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+    texels: &[P::Encoding],
+  ) -> Result<(), TextureError> {
+    let mut gfx_state = texture.state.borrow_mut();
+
+    gfx_state.bind_texture(texture.target, texture.handle);
 
-    let mut w = 0;
-    let mut h = 0;
+    upload_level_texels::<D, P, P::Encoding>(texture.target, level, offset, size, texels)?;
+
+    gfx_state.bind_texture(texture.target, 0);
+
+    Ok(())
+  }
 
+  unsafe fn upload_part_level_raw(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+    texels: &[P::RawEncoding],
+  ) -> Result<(), TextureError> {
     let mut gfx_state = texture.state.borrow_mut();
+
     gfx_state.bind_texture(texture.target, texture.handle);
 
-    // retrieve the size of the texture (w and h)
-    gl::GetTexLevelParameteriv(texture.target, 0, gl::TEXTURE_WIDTH, &mut w);
-    gl::GetTexLevelParameteriv(texture.target, 0, gl::TEXTURE_HEIGHT, &mut h);
+    upload_level_texels::<D, P, P::RawEncoding>(texture.target, level, offset, size, texels)?;
 
-    // set the packing alignment based on the number of bytes to skip
-    let skip_bytes = (pf.format.bytes_len() * w as usize) % 8;
-    set_pack_alignment(skip_bytes);
+    gfx_state.bind_texture(texture.target, 0);
 
-    // resize the vec to allocate enough space to host the returned texels
-    let mut texels = vec![Default::default(); (w * h) as usize * pf.channels_len()];
+    Ok(())
+  }
 
-    gl::GetTexImage(
-      texture.target,
-      0,
-      format,
-      ty,
-      texels.as_mut_ptr() as *mut c_void,
-    );
+  unsafe fn generate_mipmaps(texture: &mut Self::TextureRepr) -> Result<(), TextureError> {
+    if texture.mipmaps == 0 {
+      return Ok(());
+    }
 
+    let mut gfx_state = texture.state.borrow_mut();
+
+    gfx_state.bind_texture(texture.target, texture.handle);
+    gl::GenerateMipmap(texture.target);
     gfx_state.bind_texture(texture.target, 0);
 
-    Ok(texels)
+    Ok(())
+  }
+
+  unsafe fn get_texels(
+    texture: &Self::TextureRepr,
+    size: D::Size,
+  ) -> Result<Vec<P::Encoding>, TextureError>
+  where
+    P::Encoding: Copy + Default,
+  {
+    read_texels::<D, _>(texture, size, P::pixel_format(), 1)
+  }
+
+  unsafe fn get_raw_texels(
+    texture: &Self::TextureRepr,
+    size: D::Size,
+  ) -> Result<Vec<P::RawEncoding>, TextureError>
+  where
+    P::RawEncoding: Copy + Default,
+  {
+    let pf = P::pixel_format();
+    read_texels::<D, _>(texture, size, pf, pf.channels_len())
   }
 
   unsafe fn resize(
@@ -150,11 +204,7 @@ where
     size: D::Size,
     texels: TexelUpload<[P::Encoding]>,
   ) -> Result<(), TextureError> {
-    let mipmaps = texels.mipmaps();
-    let mut state = texture.state.borrow_mut();
-
-    state.bind_texture(texture.target, texture.handle);
-    create_texture_storage::<D>(size, 1 + mipmaps, P::pixel_format())?;
+    reallocate_texture::<D>(texture, size, texels.mipmaps(), P::pixel_format())?;
     upload_texels::<D, P, P::Encoding>(texture.target, D::ZERO_OFFSET, size, texels)
   }
 
@@ -163,12 +213,383 @@ where
     size: <D as Dimensionable>::Size,
     texels: TexelUpload<'_, [<P as Pixel>::RawEncoding]>,
   ) -> Result<(), luminance::texture::TextureError> {
-    let mipmaps = texels.mipmaps();
+    reallocate_texture::<D>(texture, size, texels.mipmaps(), P::pixel_format())?;
+    upload_texels::<D, P, P::RawEncoding>(texture.target, D::ZERO_OFFSET, size, texels)
+  }
+}
+
+#[cfg(feature = "GL_ARB_bindless_texture")]
+unsafe impl<D, P> TextureBindless<D, P> for GL33
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  unsafe fn make_resident(texture: &mut Self::TextureRepr) -> Result<u64, TextureError> {
+    if let Some(handle) = texture.resident_handle {
+      return Ok(handle);
+    }
+
+    if !bindless::is_loaded() {
+      return Err(TextureError::cannot_make_resident(
+        "GL_ARB_bindless_texture functions are not loaded",
+      ));
+    }
+
+    let handle = bindless::GetTextureHandleARB(texture.handle);
+
+    if handle == 0 {
+      return Err(TextureError::cannot_make_resident(
+        "cannot get the texture handle",
+      ));
+    }
+
+    bindless::MakeTextureHandleResidentARB(handle);
+    texture.resident_handle = Some(handle);
+
+    Ok(handle)
+  }
+
+  unsafe fn make_non_resident(texture: &mut Self::TextureRepr) {
+    if let Some(handle) = texture.resident_handle.take() {
+      bindless::MakeTextureHandleNonResidentARB(handle);
+    }
+  }
+}
+
+unsafe impl<D, P> TextureView<D, P> for GL33
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  unsafe fn new_texture_view(
+    texture: &Self::TextureRepr,
+    levels: Range<usize>,
+    layers: Range<u32>,
+  ) -> Result<Self::TextureRepr, TextureError> {
     let mut state = texture.state.borrow_mut();
 
-    state.bind_texture(texture.target, texture.handle);
-    create_texture_storage::<D>(size, 1 + mipmaps, P::pixel_format())?;
-    upload_texels::<D, P, P::RawEncoding>(texture.target, D::ZERO_OFFSET, size, texels)
+    if !state.supports_texture_views() {
+      return Err(TextureError::cannot_create_view(
+        "texture views are not supported",
+      ));
+    }
+
+    if !texture.immutable {
+      return Err(TextureError::cannot_create_view(
+        "the texture doesn’t have immutable storage",
+      ));
+    }
+
+    let pf = P::pixel_format();
+    let (_, iformat, _) =
+      opengl_pixel_format(pf).ok_or(TextureError::UnsupportedPixelFormat(pf))?;
+
+    // the name of a view must never have been bound
+    let handle = state.create_texture();
+    let target = opengl_target(D::dim());
+
+    gl::TextureView(
+      handle,
+      target,
+      texture.handle,
+      iformat,
+      levels.start as GLuint,
+      levels.len() as GLuint,
+      layers.start,
+      layers.end - layers.start,
+    );
+
+    Ok(Texture {
+      handle,
+      target,
+      mipmaps: levels.len() - 1,
+      sampler: texture.sampler,
+      immutable: true,
+      state: texture.state.clone(),
+      #[cfg(feature = "GL_ARB_bindless_texture")]
+      resident_handle: None,
+    })
+  }
+}
+
+unsafe impl<D, P> TextureCopy<D, P> for GL33
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  unsafe fn copy_texture(
+    src: &Self::TextureRepr,
+    dst: &mut Self::TextureRepr,
+    src_offset: D::Offset,
+    size: D::Size,
+    dst_offset: D::Offset,
+  ) -> Result<(), TextureError> {
+    let mut state = src.state.borrow_mut();
+    let (src_origin, extent) = copy_region::<D>(src_offset, size);
+    let (dst_origin, _) = copy_region::<D>(dst_offset, size);
+
+    if !state.supports_copy_image() {
+      return blit_texture::<D>(
+        &mut state,
+        P::pixel_format(),
+        src,
+        dst,
+        src_origin,
+        dst_origin,
+        extent,
+      );
+    }
+
+    gl::CopyImageSubData(
+      src.handle,
+      src.target,
+      0,
+      src_origin[0],
+      src_origin[1],
+      src_origin[2],
+      dst.handle,
+      dst.target,
+      0,
+      dst_origin[0],
+      dst_origin[1],
+      dst_origin[2],
+      extent[0],
+      extent[1],
+      extent[2],
+    );
+
+    Ok(())
+  }
+}
+
+/// Origin and extent of a region, as expected by glCopyImageSubData: layers of 1D arrays are along the y axis, and
+/// layers of 2D arrays and cubemap faces along the z axis.
+fn copy_region<D>(offset: D::Offset, size: D::Size) -> ([GLint; 3], [GLsizei; 3])
+where
+  D: Dimensionable,
+{
+  let x = D::x_offset(offset) as GLint;
+  let width = D::width(size) as GLsizei;
+
+  match D::dim() {
+    Dim::Dim1 => ([x, 0, 0], [width, 1, 1]),
+
+    Dim::Dim2 | Dim::Dim1Array => (
+      [x, D::y_offset(offset) as GLint, 0],
+      [width, D::height(size) as GLsizei, 1],
+    ),
+
+    Dim::Cubemap => (
+      [
+        x,
+        D::y_offset(offset) as GLint,
+        D::z_offset(offset) as GLint,
+      ],
+      [width, width, 1],
+    ),
+
+    Dim::Dim3 | Dim::Dim2Array => (
+      [
+        x,
+        D::y_offset(offset) as GLint,
+        D::z_offset(offset) as GLint,
+      ],
+      [width, D::height(size) as GLsizei, D::depth(size) as GLsizei],
+    ),
+  }
+}
+
+/// Copy a region between textures by blitting framebuffers, layer by layer, when glCopyImageSubData is not available.
+unsafe fn blit_texture<D>(
+  state: &mut GLState,
+  pf: PixelFormat,
+  src: &Texture,
+  dst: &Texture,
+  src_origin: [GLint; 3],
+  dst_origin: [GLint; 3],
+  extent: [GLsizei; 3],
+) -> Result<(), TextureError>
+where
+  D: Dimensionable,
+{
+  let (attachment, mask) = match pf.format {
+    Format::Depth(_) => (gl::DEPTH_ATTACHMENT, gl::DEPTH_BUFFER_BIT),
+    Format::DepthStencil(..) => (
+      gl::DEPTH_STENCIL_ATTACHMENT,
+      gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT,
+    ),
+    Format::Stencil(_) => (gl::STENCIL_ATTACHMENT, gl::STENCIL_BUFFER_BIT),
+    _ => (gl::COLOR_ATTACHMENT0, gl::COLOR_BUFFER_BIT),
+  };
+
+  let mut framebuffers = [0; 2];
+  gl::GenFramebuffers(2, framebuffers.as_mut_ptr());
+
+  // blits are affected by the scissor test and sRGB conversions
+  state.set_scissor_state(ScissorState::Off);
+  state.enable_srgb_framebuffer(false);
+  gl::BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffers[0]);
+  state.bind_draw_framebuffer(framebuffers[1]);
+
+  if attachment != gl::COLOR_ATTACHMENT0 {
+    gl::ReadBuffer(gl::NONE);
+    gl::DrawBuffer(gl::NONE);
+  }
+
+  // layers of 1D arrays are rows of the region
+  let (layer_axis, rows) = if D::dim() == Dim::Dim1Array {
+    (1, 1)
+  } else {
+    (2, extent[1])
+  };
+  let src_y = if layer_axis == 1 { 0 } else { src_origin[1] };
+  let dst_y = if layer_axis == 1 { 0 } else { dst_origin[1] };
+  let mut result = Ok(());
+
+  for i in 0..extent[layer_axis] {
+    attach_texture_layer::<D>(
+      gl::READ_FRAMEBUFFER,
+      attachment,
+      src,
+      src_origin[layer_axis] + i,
+    );
+    attach_texture_layer::<D>(
+      gl::DRAW_FRAMEBUFFER,
+      attachment,
+      dst,
+      dst_origin[layer_axis] + i,
+    );
+
+    if gl::CheckFramebufferStatus(gl::READ_FRAMEBUFFER) != gl::FRAMEBUFFER_COMPLETE
+      || gl::CheckFramebufferStatus(gl::DRAW_FRAMEBUFFER) != gl::FRAMEBUFFER_COMPLETE
+    {
+      result = Err(TextureError::cannot_copy_texels(format!(
+        "{:?} textures cannot be attached to framebuffers",
+        pf
+      )));
+      break;
+    }
+
+    gl::BlitFramebuffer(
+      src_origin[0],
+      src_y,
+      src_origin[0] + extent[0],
+      src_y + rows,
+      dst_origin[0],
+      dst_y,
+      dst_origin[0] + extent[0],
+      dst_y + rows,
+      mask,
+      gl::NEAREST,
+    );
+  }
+
+  gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+  state.bind_draw_framebuffer(0);
+  gl::DeleteFramebuffers(2, framebuffers.as_ptr());
+
+  result
+}
+
+/// Attach a layer of the base level of a texture to the framebuffer bound to `target`; the layer is ignored by 1D and
+/// 2D textures.
+unsafe fn attach_texture_layer<D>(
+  target: GLenum,
+  attachment: GLenum,
+  texture: &Texture,
+  layer: GLint,
+) where
+  D: Dimensionable,
+{
+  match D::dim() {
+    Dim::Dim1 => gl::FramebufferTexture1D(target, attachment, gl::TEXTURE_1D, texture.handle, 0),
+    Dim::Dim2 => gl::FramebufferTexture2D(target, attachment, gl::TEXTURE_2D, texture.handle, 0),
+    Dim::Cubemap => gl::FramebufferTexture2D(
+      target,
+      attachment,
+      gl::TEXTURE_CUBE_MAP_POSITIVE_X + layer as GLenum,
+      texture.handle,
+      0,
+    ),
+    Dim::Dim3 | Dim::Dim1Array | Dim::Dim2Array => {
+      gl::FramebufferTextureLayer(target, attachment, texture.handle, 0, layer)
+    }
+  }
+}
+
+unsafe impl<P> TextureCompressed<P> for GL33
+where
+  P: CompressedPixel,
+{
+  unsafe fn new_compressed_texture(
+    &mut self,
+    size: [u32; 2],
+    sampler: Sampler,
+    levels: &[&[u8]],
+  ) -> Result<Self::TextureRepr, TextureError> {
+    let mut state = self.state.borrow_mut();
+    let mipmaps = levels.len() - 1;
+    let target = gl::TEXTURE_2D;
+    let iformat = opengl_compressed_format(P::compressed_format());
+
+    let handle = state.create_texture();
+    state.bind_texture(target, handle);
+
+    set_texture_levels(target, mipmaps);
+    apply_sampler_to_texture(&mut state, target, sampler);
+
+    for (level, data) in levels.iter().enumerate() {
+      gl::CompressedTexImage2D(
+        target,
+        level as GLint,
+        iformat,
+        (size[0] >> level).max(1) as GLsizei,
+        (size[1] >> level).max(1) as GLsizei,
+        0,
+        data.len() as GLsizei,
+        data.as_ptr() as *const c_void,
+      );
+    }
+
+    let texture = Texture {
+      handle,
+      target,
+      mipmaps,
+      sampler,
+      immutable: false,
+      state: self.state.clone(),
+      #[cfg(feature = "GL_ARB_bindless_texture")]
+      resident_handle: None,
+    };
+
+    Ok(texture)
+  }
+
+  unsafe fn upload_compressed_part(
+    texture: &mut Self::TextureRepr,
+    offset: [u32; 2],
+    size: [u32; 2],
+    data: &[u8],
+  ) -> Result<(), TextureError> {
+    let mut gfx_state = texture.state.borrow_mut();
+
+    gfx_state.bind_texture(texture.target, texture.handle);
+
+    gl::CompressedTexSubImage2D(
+      texture.target,
+      0,
+      offset[0] as GLint,
+      offset[1] as GLint,
+      size[0] as GLsizei,
+      size[1] as GLsizei,
+      opengl_compressed_format(P::compressed_format()),
+      data.len() as GLsizei,
+      data.as_ptr() as *const c_void,
+    );
+
+    gfx_state.bind_texture(texture.target, 0);
+
+    Ok(())
   }
 }
 
@@ -184,18 +605,58 @@ pub(crate) fn opengl_target(d: Dim) -> GLenum {
 }
 
 pub(crate) unsafe fn create_texture<D>(
+  state: &mut GLState,
   target: GLenum,
   size: D::Size,
   mipmaps: usize,
   pf: PixelFormat,
   sampler: Sampler,
-) -> Result<(), TextureError>
+) -> Result<bool, TextureError>
 where
   D: Dimensionable,
 {
   set_texture_levels(target, mipmaps);
-  apply_sampler_to_texture(target, sampler);
-  create_texture_storage::<D>(size, 1 + mipmaps, pf)
+  apply_sampler_to_texture(state, target, sampler);
+  create_texture_storage::<D>(state, target, size, 1 + mipmaps, pf)
+}
+
+// Reallocate the storage of a texture for a new size and number of mipmaps.
+unsafe fn reallocate_texture<D>(
+  texture: &mut Texture,
+  size: D::Size,
+  mipmaps: usize,
+  pf: PixelFormat,
+) -> Result<(), TextureError>
+where
+  D: Dimensionable,
+{
+  let mut state = texture.state.borrow_mut();
+
+  // immutable storage cannot be reallocated, so the texture gets a brand new name
+  if texture.immutable {
+    let handle = state.create_texture();
+
+    #[cfg(feature = "GL_ARB_bindless_texture")]
+    if let Some(resident_handle) = texture.resident_handle.take() {
+      bindless::MakeTextureHandleNonResidentARB(resident_handle);
+    }
+
+    gl::DeleteTextures(1, &texture.handle);
+    texture.handle = handle;
+  }
+
+  state.bind_texture(texture.target, texture.handle);
+  texture.immutable = create_texture::<D>(
+    &mut state,
+    texture.target,
+    size,
+    mipmaps,
+    pf,
+    texture.sampler,
+  )?;
+  texture.mipmaps = mipmaps;
+
+  Ok(())
 }
 
 fn set_texture_levels(target: GLenum, mipmaps: usize) {
@@ -205,7 +666,7 @@ fn set_texture_levels(target: GLenum, mipmaps: usize) {
   }
 }
 
-fn apply_sampler_to_texture(target: GLenum, sampler: Sampler) {
+fn apply_sampler_to_texture(state: &mut GLState, target: GLenum, sampler: Sampler) {
   unsafe {
     gl::TexParameteri(
       target,
@@ -250,6 +711,33 @@ fn apply_sampler_to_texture(target: GLenum, sampler: Sampler) {
         gl::TexParameteri(target, gl::TEXTURE_COMPARE_MODE, gl::NONE as GLint);
       }
     }
+
+    // anisotropic filtering is ignored if not supported
+    if let (Some(anisotropy), Some(max)) = (sampler.max_anisotropy, state.get_max_anisotropy()) {
+      gl::TexParameterf(target, TEXTURE_MAX_ANISOTROPY, anisotropy.clamp(1., max));
+    }
+
+    gl::TexParameterfv(
+      target,
+      gl::TEXTURE_BORDER_COLOR,
+      sampler.border_color.as_ptr(),
+    );
+
+    let swizzle = sampler
+      .swizzle
+      .map(|swizzle| opengl_swizzle(swizzle) as GLint);
+    gl::TexParameteriv(target, gl::TEXTURE_SWIZZLE_RGBA, swizzle.as_ptr());
+  }
+}
+
+fn opengl_swizzle(swizzle: Swizzle) -> GLenum {
+  match swizzle {
+    Swizzle::Red => gl::RED,
+    Swizzle::Green => gl::GREEN,
+    Swizzle::Blue => gl::BLUE,
+    Swizzle::Alpha => gl::ALPHA,
+    Swizzle::Zero => gl::ZERO,
+    Swizzle::One => gl::ONE,
   }
 }
 
@@ -258,6 +746,7 @@ fn opengl_wrap(wrap: Wrap) -> GLenum {
     Wrap::ClampToEdge => gl::CLAMP_TO_EDGE,
     Wrap::Repeat => gl::REPEAT,
     Wrap::MirroredRepeat => gl::MIRRORED_REPEAT,
+    Wrap::ClampToBorder => gl::CLAMP_TO_BORDER,
   }
 }
 
@@ -296,225 +785,113 @@ where
   let handle = state.create_texture();
   state.bind_texture(target, handle);
 
-  create_texture::<D>(target, size, mipmaps, P::pixel_format(), sampler)?;
+  let immutable = create_texture::<D>(
+    &mut state,
+    target,
+    size,
+    mipmaps,
+    P::pixel_format(),
+    sampler,
+  )?;
   upload_texels::<D, P, Px>(target, D::ZERO_OFFSET, size, texels)?;
 
   let texture = Texture {
     handle,
     target,
     mipmaps,
+    sampler,
+    immutable,
     state: gl33.state.clone(),
+    #[cfg(feature = "GL_ARB_bindless_texture")]
+    resident_handle: None,
   };
 
   Ok(texture)
 }
 
-fn create_texture_storage<D>(
+// Allocate the storage of the bound texture, and return whether it is immutable.
+//
+// Immutable storage is allocated at once for all the levels with glTexStorage* when supported (OpenGL 4.2 or
+// GL_ARB_texture_storage); otherwise, each level is allocated with glTexImage*.
+unsafe fn create_texture_storage<D>(
+  state: &mut GLState,
+  target: GLenum,
   size: D::Size,
   levels: usize,
   pf: PixelFormat,
-) -> Result<(), TextureError>
+) -> Result<bool, TextureError>
 where
   D: Dimensionable,
 {
-  match opengl_pixel_format(pf) {
-    Some(glf) => {
-      let (format, iformat, encoding) = glf;
-
-      match D::dim() {
-        // 1D texture
-        Dim::Dim1 => {
-          create_texture_1d_storage(format, iformat, encoding, D::width(size), levels);
-          Ok(())
-        }
-
-        // 2D texture
-        Dim::Dim2 => {
-          create_texture_2d_storage(
-            gl::TEXTURE_2D,
-            format,
-            iformat,
-            encoding,
-            D::width(size),
-            D::height(size),
-            levels,
-          );
-          Ok(())
-        }
+  let (format, iformat, encoding) = opengl_pixel_format(pf).ok_or_else(|| {
+    TextureError::texture_storage_creation_failed(format!(
+      "unsupported texture pixel format: {:?}",
+      pf
+    ))
+  })?;
+
+  // immutable storage requires a sized internal format and cannot be empty
+  let sized = !matches!(iformat, gl::RED | gl::RG | gl::RGB | gl::RGBA);
+
+  if sized && D::count(size) != 0 && state.supports_texture_storage() {
+    // levels past the 1×1 one cannot be allocated
+    let levels = levels.min(max_levels::<D>(size)) as GLsizei;
+    let w = D::width(size) as GLsizei;
+    let h = D::height(size) as GLsizei;
+    let d = D::depth(size) as GLsizei;
+
+    match D::dim() {
+      Dim::Dim1 => gl::TexStorage1D(target, levels, iformat, w),
+      Dim::Dim2 | Dim::Dim1Array | Dim::Cubemap => gl::TexStorage2D(target, levels, iformat, w, h),
+      Dim::Dim3 | Dim::Dim2Array => gl::TexStorage3D(target, levels, iformat, w, h, d),
+    }
 
-        // 3D texture
-        Dim::Dim3 => {
-          create_texture_3d_storage(
-            gl::TEXTURE_3D,
-            format,
-            iformat,
-            encoding,
-            D::width(size),
-            D::height(size),
-            D::depth(size),
-            levels,
-          );
-          Ok(())
-        }
+    return Ok(true);
+  }
 
-        // cubemap
-        Dim::Cubemap => {
-          create_cubemap_storage(format, iformat, encoding, D::width(size), levels);
-          Ok(())
-        }
+  for level in 0..levels {
+    let level_size = D::level_size(size, level);
+    let w = D::width(level_size) as GLsizei;
+    let h = D::height(level_size) as GLsizei;
+    let d = D::depth(level_size) as GLsizei;
+    let (level, iformat) = (level as GLint, iformat as GLint);
+    let texels = ptr::null();
+
+    match D::dim() {
+      Dim::Dim1 => gl::TexImage1D(target, level, iformat, w, 0, format, encoding, texels),
+
+      Dim::Dim2 | Dim::Dim1Array => {
+        gl::TexImage2D(target, level, iformat, w, h, 0, format, encoding, texels)
+      }
 
-        // 1D array texture
-        Dim::Dim1Array => {
-          create_texture_2d_storage(
-            gl::TEXTURE_1D_ARRAY,
-            format,
-            iformat,
-            encoding,
-            D::width(size),
-            D::height(size),
-            levels,
-          );
-          Ok(())
+      Dim::Cubemap => {
+        for face in 0..6 {
+          let target = gl::TEXTURE_CUBE_MAP_POSITIVE_X + face;
+          gl::TexImage2D(target, level, iformat, w, h, 0, format, encoding, texels);
         }
+      }
 
-        // 2D array texture
-        Dim::Dim2Array => {
-          create_texture_3d_storage(
-            gl::TEXTURE_2D_ARRAY,
-            format,
-            iformat,
-            encoding,
-            D::width(size),
-            D::height(size),
-            D::depth(size),
-            levels,
-          );
-          Ok(())
-        }
+      Dim::Dim3 | Dim::Dim2Array => {
+        gl::TexImage3D(target, level, iformat, w, h, d, 0, format, encoding, texels)
       }
     }
-
-    None => Err(TextureError::texture_storage_creation_failed(format!(
-      "unsupported texture pixel format: {:?}",
-      pf
-    ))),
-  }
-}
-
-fn create_texture_1d_storage(
-  format: GLenum,
-  iformat: GLenum,
-  encoding: GLenum,
-  w: u32,
-  levels: usize,
-) {
-  for level in 0..levels {
-    let w = w / (1 << level as u32);
-
-    unsafe {
-      gl::TexImage1D(
-        gl::TEXTURE_1D,
-        level as GLint,
-        iformat as GLint,
-        w as GLsizei,
-        0,
-        format,
-        encoding,
-        ptr::null(),
-      )
-    };
   }
-}
 
-fn create_texture_2d_storage(
-  target: GLenum,
-  format: GLenum,
-  iformat: GLenum,
-  encoding: GLenum,
-  w: u32,
-  h: u32,
-  levels: usize,
-) {
-  for level in 0..levels {
-    let div = 1 << level as u32;
-    let w = w / div;
-    let h = h / div;
-
-    unsafe {
-      gl::TexImage2D(
-        target,
-        level as GLint,
-        iformat as GLint,
-        w as GLsizei,
-        h as GLsizei,
-        0,
-        format,
-        encoding,
-        ptr::null(),
-      )
-    };
-  }
+  Ok(false)
 }
 
-fn create_texture_3d_storage(
-  target: GLenum,
-  format: GLenum,
-  iformat: GLenum,
-  encoding: GLenum,
-  w: u32,
-  h: u32,
-  d: u32,
-  levels: usize,
-) {
-  for level in 0..levels {
-    let div = 1 << level as u32;
-    let w = w / div;
-    let h = h / div;
-    let d = d / div;
-
-    unsafe {
-      gl::TexImage3D(
-        target,
-        level as GLint,
-        iformat as GLint,
-        w as GLsizei,
-        h as GLsizei,
-        d as GLsizei,
-        0,
-        format,
-        encoding,
-        ptr::null(),
-      )
-    };
-  }
-}
+// Number of levels of a complete mipmap chain, down to the 1×1 level.
+fn max_levels<D>(size: D::Size) -> usize
+where
+  D: Dimensionable,
+{
+  let extent = match D::dim() {
+    Dim::Dim1 | Dim::Dim1Array => D::width(size),
+    Dim::Dim2 | Dim::Cubemap | Dim::Dim2Array => D::width(size).max(D::height(size)),
+    Dim::Dim3 => D::width(size).max(D::height(size)).max(D::depth(size)),
+  };
 
-fn create_cubemap_storage(
-  format: GLenum,
-  iformat: GLenum,
-  encoding: GLenum,
-  s: u32,
-  levels: usize,
-) {
-  for level in 0..levels {
-    let s = s / (1 << level as u32);
-
-    for face in 0..6 {
-      unsafe {
-        gl::TexImage2D(
-          gl::TEXTURE_CUBE_MAP_POSITIVE_X + face,
-          level as GLint,
-          iformat as GLint,
-          s as GLsizei,
-          s as GLsizei,
-          0,
-          format,
-          encoding,
-          ptr::null(),
-        )
-      };
-    }
-  }
+  (u32::BITS - extent.leading_zeros()) as usize
 }
 
 // set the unpack alignment for uploading aligned texels
@@ -529,8 +906,65 @@ fn set_unpack_alignment(skip_bytes: usize) {
   unsafe { gl::PixelStorei(gl::UNPACK_ALIGNMENT, unpack_alignment) };
 }
 
+// Read the texels of the base level of a texture, with `per_pixel` values of type `T` per pixel.
+unsafe fn read_texels<D, T>(
+  texture: &Texture,
+  size: D::Size,
+  pf: PixelFormat,
+  per_pixel: usize,
+) -> Result<Vec<T>, TextureError>
+where
+  D: Dimensionable,
+  T: Copy + Default,
+{
+  let (format, _, ty) = opengl_pixel_format(pf).ok_or(TextureError::UnsupportedPixelFormat(pf))?;
+
+  let row_len = D::width(size) as usize * per_pixel;
+  let layer_len = row_len * D::height(size) as usize;
+  let layers = D::depth(size) as usize;
+
+  if layer_len == 0 {
+    return Ok(Vec::new());
+  }
+
+  let mut gfx_state = texture.state.borrow_mut();
+  gfx_state.bind_texture(texture.target, texture.handle);
+
+  // set the packing alignment based on the number of bytes to skip, so that rows are tightly packed
+  let skip_bytes = (row_len * mem::size_of::<T>()) % 8;
+  set_pack_alignment(skip_bytes);
+
+  // resize the vec to allocate enough space to host the returned texels
+  let mut texels = vec![T::default(); layer_len * layers];
+
+  if texture.target == gl::TEXTURE_CUBE_MAP {
+    // faces of cubemaps must be read one by one
+    for (face, face_texels) in texels.chunks_exact_mut(layer_len).enumerate() {
+      gl::GetTexImage(
+        gl::TEXTURE_CUBE_MAP_POSITIVE_X + face as GLenum,
+        0,
+        format,
+        ty,
+        face_texels.as_mut_ptr() as *mut c_void,
+      );
+    }
+  } else {
+    gl::GetTexImage(
+      texture.target,
+      0,
+      format,
+      ty,
+      texels.as_mut_ptr() as *mut c_void,
+    );
+  }
+
+  gfx_state.bind_texture(texture.target, 0);
+
+  Ok(texels)
+}
+
 // set the pack alignment for downloading aligned texels
-fn set_pack_alignment(skip_bytes: usize) {
+pub(crate) fn set_pack_alignment(skip_bytes: usize) {
   let pack_alignment = match skip_bytes {
     0 => 8,
     2 => 2,
@@ -598,6 +1032,65 @@ where
   Ok(())
 }
 
+// Upload texels to a single mipmap level of the bound texture.
+fn upload_level_texels<D, P, T>(
+  target: GLenum,
+  level: usize,
+  off: D::Offset,
+  size: D::Size,
+  texels: &[T],
+) -> Result<(), TextureError>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  let pf = P::pixel_format();
+  let pf_size = pf.format.bytes_len();
+  let expected_bytes = D::count(size) * pf_size;
+  let input_bytes = mem::size_of_val(texels);
+
+  if input_bytes < expected_bytes {
+    // potential segfault / overflow; abort
+    return Err(TextureError::not_enough_pixels(expected_bytes, input_bytes));
+  }
+
+  let skip_bytes = (D::width(size) as usize * pf_size) % 8;
+  set_unpack_alignment(skip_bytes);
+
+  set_texels::<D, _>(target, pf, level as GLint, size, off, texels)
+}
+
+// Upload the texels of a pixel unpack buffer to a single mipmap level of a texture.
+//
+// The buffer must hold enough texels for the region made with `off` and `size`.
+pub(crate) unsafe fn upload_level_texels_from_buffer<D, P>(
+  texture: &Texture,
+  level: usize,
+  off: D::Offset,
+  size: D::Size,
+  buffer: GLuint,
+) -> Result<(), TextureError>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  let pf = P::pixel_format();
+  let skip_bytes = (D::width(size) as usize * pf.format.bytes_len()) % 8;
+  let mut gfx_state = texture.state.borrow_mut();
+
+  gfx_state.bind_texture(texture.target, texture.handle);
+  gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, buffer);
+  set_unpack_alignment(skip_bytes);
+
+  // with a pixel unpack buffer bound, the texels pointer is an offset in the buffer
+  let uploaded = set_texels_ptr::<D>(texture.target, pf, level as GLint, size, off, ptr::null());
+
+  gl::BindBuffer(gl::PIXEL_UNPACK_BUFFER, 0);
+  gfx_state.bind_texture(texture.target, 0);
+
+  uploaded
+}
+
 // Set texels for a texture.
 fn set_texels<D, T>(
   target: GLenum,
@@ -607,6 +1100,28 @@ fn set_texels<D, T>(
   off: D::Offset,
   texels: &[T],
 ) -> Result<(), TextureError>
+where
+  D: Dimensionable,
+{
+  set_texels_ptr::<D>(
+    target,
+    pf,
+    level,
+    size,
+    off,
+    texels.as_ptr() as *const c_void,
+  )
+}
+
+// Set texels for a texture from a pointer to them.
+fn set_texels_ptr<D>(
+  target: GLenum,
+  pf: PixelFormat,
+  level: GLint,
+  size: D::Size,
+  off: D::Offset,
+  texels: *const c_void,
+) -> Result<(), TextureError>
 where
   D: Dimensionable,
 {
@@ -620,7 +1135,7 @@ where
           D::width(size) as GLsizei,
           format,
           encoding,
-          texels.as_ptr() as *const c_void,
+          texels,
         );
       },
 
@@ -634,7 +1149,7 @@ where
           D::height(size) as GLsizei,
           format,
           encoding,
-          texels.as_ptr() as *const c_void,
+          texels,
         );
       },
 
@@ -650,7 +1165,7 @@ where
           D::depth(size) as GLsizei,
           format,
           encoding,
-          texels.as_ptr() as *const c_void,
+          texels,
         );
       },
 
@@ -664,7 +1179,7 @@ where
           D::width(size) as GLsizei,
           format,
           encoding,
-          texels.as_ptr() as *const c_void,
+          texels,
         );
       },
 
@@ -678,7 +1193,7 @@ where
           D::height(size) as GLsizei,
           format,
           encoding,
-          texels.as_ptr() as *const c_void,
+          texels,
         );
       },
 
@@ -694,7 +1209,7 @@ where
           D::depth(size) as GLsizei,
           format,
           encoding,
-          texels.as_ptr() as *const c_void,
+          texels,
         );
       },
     },
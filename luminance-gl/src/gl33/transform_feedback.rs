@@ -0,0 +1,83 @@
+//! Transform feedback implementation for OpenGL 3.3.
+
+use crate::gl33::GL33;
+use gl::types::*;
+use luminance::{
+  backend::transform_feedback::{
+    TransformFeedback as TransformFeedbackBackend, TransformFeedbackCapture,
+  },
+  tess::{Interleaved, TessIndex, TessVertexData},
+  transform_feedback::TransformFeedbackError,
+  vertex::Vertex,
+};
+
+#[derive(Debug)]
+pub struct TransformFeedback {
+  handle: GLuint,
+}
+
+impl Drop for TransformFeedback {
+  fn drop(&mut self) {
+    unsafe {
+      gl::DeleteTransformFeedbacks(1, &self.handle);
+    }
+  }
+}
+
+unsafe impl TransformFeedbackBackend for GL33 {
+  type TransformFeedbackRepr = TransformFeedback;
+
+  unsafe fn new_transform_feedback(
+    &mut self,
+  ) -> Result<Self::TransformFeedbackRepr, TransformFeedbackError> {
+    let mut handle: GLuint = 0;
+    gl::GenTransformFeedbacks(1, &mut handle);
+
+    if handle == 0 {
+      return Err(TransformFeedbackError::cannot_create(
+        "cannot generate transform feedback object",
+      ));
+    }
+
+    Ok(TransformFeedback { handle })
+  }
+}
+
+unsafe impl<V, I, W> TransformFeedbackCapture<V, I, W> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>> + Vertex,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>> + Vertex,
+{
+  unsafe fn begin_capture(
+    &mut self,
+    feedback: &mut Self::TransformFeedbackRepr,
+    output: &mut Self::TessRepr,
+  ) -> Result<(), TransformFeedbackError> {
+    // only independent primitives can be captured
+    let primitive = match output.mode() {
+      gl::POINTS => gl::POINTS,
+      gl::LINES => gl::LINES,
+      gl::TRIANGLES => gl::TRIANGLES,
+      _ => return Err(TransformFeedbackError::unsupported_primitive_mode()),
+    };
+
+    let buffer = output
+      .vertex_buffer()
+      .ok_or_else(TransformFeedbackError::no_vertex_buffer)?
+      .handle();
+
+    gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, feedback.handle);
+    gl::BindBufferBase(gl::TRANSFORM_FEEDBACK_BUFFER, 0, buffer);
+    gl::Enable(gl::RASTERIZER_DISCARD);
+    gl::BeginTransformFeedback(primitive);
+
+    Ok(())
+  }
+
+  unsafe fn end_capture(&mut self, _: &mut Self::TransformFeedbackRepr, _: &mut Self::TessRepr) {
+    gl::EndTransformFeedback();
+    gl::Disable(gl::RASTERIZER_DISCARD);
+    gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, 0);
+  }
+}
@@ -7,6 +7,9 @@
 pub enum VertexRestart {
   /// Vertex restart is enabled.
   On,
+  /// Vertex restart is enabled, with the maximum value of the index type as restart index.
+  #[cfg(feature = "GL_ARB_ES3_compatibility")]
+  FixedIndex,
   /// Vertex restart is disabled.
   Off,
 }
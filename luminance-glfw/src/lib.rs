@@ -106,6 +106,7 @@ impl GlfwSurface {
 
     // init OpenGL
     gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+    luminance_gl::gl33::load_with(|s| window.get_proc_address(s) as *const c_void);
 
     let gl = GL33::new().map_err(GlfwSurfaceError::GraphicsStateError)?;
     let context = GL33Context { window, gl };
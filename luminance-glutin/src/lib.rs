@@ -123,6 +123,7 @@ impl GlutinSurface {
 
     // init OpenGL
     gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
+    luminance_gl::gl33::load_with(|s| ctx.get_proc_address(s) as *const c_void);
 
     ctx.window().set_visible(true);
 
@@ -150,6 +151,7 @@ impl GlutinSurface {
 
     // init OpenGL
     gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
+    luminance_gl::gl33::load_with(|s| ctx.get_proc_address(s) as *const c_void);
 
     ctx.window().set_visible(true);
 
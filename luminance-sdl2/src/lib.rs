@@ -115,6 +115,7 @@ impl GL33Surface {
       .map_err(Sdl2SurfaceError::GlContextInitFailed)?;
 
     gl::load_with(|s| video_system.gl_get_proc_address(s) as *const c_void);
+    luminance_gl::gl33::load_with(|s| video_system.gl_get_proc_address(s) as *const c_void);
 
     let gl = GL33::new().map_err(Sdl2SurfaceError::GraphicsStateError)?;
     let surface = GL33Surface {
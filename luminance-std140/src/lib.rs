@@ -1,8 +1,17 @@
 //! Types and traits implementing the [std140] OpenGL rule.
 //!
+//! Custom `struct` types can implement [`Std140`] with `#[derive(Std140)]` from [luminance-derive].
+//!
 //! [std140]: https://www.khronos.org/registry/OpenGL/specs/gl/glspec45.core.pdf#page=159
+//! [luminance-derive]: https://crates.io/crates/luminance-derive
 
-use luminance::shader::types::{Mat22, Mat33, Mat44, Vec2, Vec3, Vec4};
+use luminance::{
+  shader::types::{
+    Mat22, Mat23, Mat24, Mat32, Mat33, Mat34, Mat42, Mat43, Mat44, Vec2, Vec3, Vec4,
+  },
+  texture::TextureHandle,
+};
+use std::mem;
 
 /// Types that have a `std140` representation.
 ///
@@ -11,11 +20,25 @@ use luminance::shader::types::{Mat22, Mat33, Mat44, Vec2, Vec3, Vec4};
 pub trait Std140: Copy {
   type Encoded: Copy;
 
+  /// Base alignment of the `std140` representation.
+  ///
+  /// It is the alignment of [`Std140::Encoded`], except for three-component vectors, which are encoded without their
+  /// trailing padding, so that a scalar can be packed right after them.
+  const ALIGN: usize = mem::align_of::<Self::Encoded>();
+
   /// Encode the value into its `std140` representation.
   fn std140_encode(self) -> Self::Encoded;
 
   /// Decode a value from its `std140` representation.
   fn std140_decode(encoded: Self::Encoded) -> Self;
+
+  /// Names and byte offsets of the fields of the `std140` representation.
+  ///
+  /// Backends use this to check the representation against the layout of uniform blocks declared in shaders. Types
+  /// without named fields, such as scalars, vectors and matrices, have no fields.
+  fn std140_fields() -> Vec<(&'static str, usize)> {
+    Vec::new()
+  }
 }
 
 /// 4-bytes aligned wrapper.
@@ -114,6 +137,25 @@ macro_rules! impl_Std140_Aligned16 {
   };
 }
 
+/// Implement [`Std140`] for a three-component vector type as an identity, with a base alignment of `$align`.
+macro_rules! impl_Std140_vec3 {
+  ($t:ty, $align:literal) => {
+    impl Std140 for $t {
+      type Encoded = $t;
+
+      const ALIGN: usize = $align;
+
+      fn std140_encode(self) -> Self::Encoded {
+        self
+      }
+
+      fn std140_decode(encoded: Self::Encoded) -> Self {
+        encoded
+      }
+    }
+  };
+}
+
 /// Implement [`Std140`] for a type by wrapping it in [`Aligned32`].
 macro_rules! impl_Std140_Aligned32 {
   ($t:ty) => {
@@ -133,22 +175,22 @@ macro_rules! impl_Std140_Aligned32 {
 
 impl_Std140_id!(f32);
 impl_Std140_Aligned8!(Vec2<f32>);
-impl_Std140_Aligned16!(Vec3<f32>);
+impl_Std140_vec3!(Vec3<f32>, 16);
 impl_Std140_Aligned16!(Vec4<f32>);
 
 impl_Std140_id!(f64);
 impl_Std140_Aligned16!(Vec2<f64>);
-impl_Std140_Aligned32!(Vec3<f64>);
+impl_Std140_vec3!(Vec3<f64>, 32);
 impl_Std140_Aligned32!(Vec4<f64>);
 
 impl_Std140_id!(i32);
 impl_Std140_Aligned8!(Vec2<i32>);
-impl_Std140_Aligned16!(Vec3<i32>);
+impl_Std140_vec3!(Vec3<i32>, 16);
 impl_Std140_Aligned16!(Vec4<i32>);
 
 impl_Std140_id!(u32);
 impl_Std140_Aligned8!(Vec2<u32>);
-impl_Std140_Aligned16!(Vec3<u32>);
+impl_Std140_vec3!(Vec3<u32>, 16);
 impl_Std140_Aligned16!(Vec4<u32>);
 
 impl_Std140_Aligned4!(bool);
@@ -167,9 +209,37 @@ impl Std140 for Vec2<bool> {
   }
 }
 
-impl_Std140_Aligned16!(Vec3<bool>);
+impl Std140 for Vec3<bool> {
+  type Encoded = Vec3<Aligned4<bool>>;
+
+  const ALIGN: usize = 16;
+
+  fn std140_encode(self) -> Self::Encoded {
+    let Vec3([x, y, z]) = self;
+    Vec3::new(Aligned4(x), Aligned4(y), Aligned4(z))
+  }
+
+  fn std140_decode(encoded: Self::Encoded) -> Self {
+    let Vec3([Aligned4(x), Aligned4(y), Aligned4(z)]) = encoded;
+    Vec3::new(x, y, z)
+  }
+}
+
 impl_Std140_Aligned16!(Vec4<bool>);
 
+// bindless texture handles are 64-bit values
+impl<D, S> Std140 for TextureHandle<D, S> {
+  type Encoded = Aligned8<Self>;
+
+  fn std140_encode(self) -> Self::Encoded {
+    Aligned8(self)
+  }
+
+  fn std140_decode(encoded: Self::Encoded) -> Self {
+    encoded.0
+  }
+}
+
 impl Std140 for Mat22<f32> {
   type Encoded = Aligned16<[Aligned16<[f32; 2]>; 2]>;
 
@@ -184,16 +254,18 @@ impl Std140 for Mat22<f32> {
   }
 }
 
+// dvec2 columns are 16 bytes, which is already a multiple of the vec4 alignment, so the columns must not be padded any
+// further
 impl Std140 for Mat22<f64> {
-  type Encoded = Aligned32<[Aligned32<[f64; 2]>; 2]>;
+  type Encoded = Aligned16<[Aligned16<[f64; 2]>; 2]>;
 
   fn std140_encode(self) -> Self::Encoded {
     let [a, b]: [[f64; 2]; 2] = self.into();
-    Aligned32([Aligned32(a), Aligned32(b)])
+    Aligned16([Aligned16(a), Aligned16(b)])
   }
 
   fn std140_decode(encoded: Self::Encoded) -> Self {
-    let Aligned32([Aligned32(a), Aligned32(b)]) = encoded;
+    let Aligned16([Aligned16(a), Aligned16(b)]) = encoded;
     [a, b].into()
   }
 }
@@ -254,6 +326,39 @@ impl Std140 for Mat44<f64> {
   }
 }
 
+/// Implement [`Std140`] for a non-square matrix `$t<$f>` of `$c` columns of `$r` rows, by wrapping each column and the
+/// whole matrix in `$align`.
+macro_rules! impl_Std140_mat {
+  ($t:ident<$f:ty>, $c:literal, $r:literal, $align:ident) => {
+    impl Std140 for $t<$f> {
+      type Encoded = $align<[$align<[$f; $r]>; $c]>;
+
+      fn std140_encode(self) -> Self::Encoded {
+        $align(self.0.map($align))
+      }
+
+      fn std140_decode(encoded: Self::Encoded) -> Self {
+        $t(encoded.0.map(|$align(column)| column))
+      }
+    }
+  };
+}
+
+impl_Std140_mat!(Mat23<f32>, 2, 3, Aligned16);
+impl_Std140_mat!(Mat32<f32>, 3, 2, Aligned16);
+impl_Std140_mat!(Mat24<f32>, 2, 4, Aligned16);
+impl_Std140_mat!(Mat42<f32>, 4, 2, Aligned16);
+impl_Std140_mat!(Mat34<f32>, 3, 4, Aligned16);
+impl_Std140_mat!(Mat43<f32>, 4, 3, Aligned16);
+
+// as with dmat2, dvec2 columns are already 16 bytes; dvec3 and dvec4 columns must be aligned on 32 bytes
+impl_Std140_mat!(Mat23<f64>, 2, 3, Aligned32);
+impl_Std140_mat!(Mat32<f64>, 3, 2, Aligned16);
+impl_Std140_mat!(Mat24<f64>, 2, 4, Aligned32);
+impl_Std140_mat!(Mat42<f64>, 4, 2, Aligned16);
+impl_Std140_mat!(Mat34<f64>, 3, 4, Aligned32);
+impl_Std140_mat!(Mat43<f64>, 4, 3, Aligned32);
+
 /// Type wrapper for values inside arrays.
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -265,6 +370,9 @@ where
 {
   type Encoded = Aligned16<<T as Std140>::Encoded>;
 
+  // array items are aligned on vec4
+  const ALIGN: usize = max(<T as Std140>::ALIGN, 16);
+
   fn std140_encode(self) -> Self::Encoded {
     Aligned16(self.0.std140_encode())
   }
@@ -272,6 +380,30 @@ where
   fn std140_decode(encoded: Self::Encoded) -> Self {
     ArrElem(<T as Std140>::std140_decode(encoded.0))
   }
+
+  fn std140_fields() -> Vec<(&'static str, usize)> {
+    <T as Std140>::std140_fields()
+  }
+}
+
+/// Round `offset` up to the next multiple of `align`.
+///
+/// This is used by the code generated by `#[derive(Std140)]`.
+#[doc(hidden)]
+pub const fn round_up(offset: usize, align: usize) -> usize {
+  (offset + align - 1) / align * align
+}
+
+/// Greatest of two alignments.
+///
+/// This is used by the code generated by `#[derive(Std140)]`.
+#[doc(hidden)]
+pub const fn max(a: usize, b: usize) -> usize {
+  if a > b {
+    a
+  } else {
+    b
+  }
 }
 
 #[cfg(test)]
@@ -285,7 +417,7 @@ mod tests {
     T: Std140,
   {
     assert_eq!(mem::size_of::<<T as Std140>::Encoded>(), size);
-    assert_eq!(mem::align_of::<<T as Std140>::Encoded>(), align);
+    assert_eq!(<T as Std140>::ALIGN, align);
   }
 
   #[test]
@@ -307,8 +439,8 @@ mod tests {
 
   #[test]
   fn vec3() {
-    assert_size_align::<Vec3<f32>>(16, 16);
-    assert_size_align::<Vec3<f64>>(32, 32);
+    assert_size_align::<Vec3<f32>>(12, 16);
+    assert_size_align::<Vec3<f64>>(24, 32);
   }
 
   #[test]
@@ -329,7 +461,7 @@ mod tests {
 
   #[test]
   fn ivec3() {
-    assert_size_align::<Vec3<i32>>(16, 16);
+    assert_size_align::<Vec3<i32>>(12, 16);
   }
 
   #[test]
@@ -349,7 +481,7 @@ mod tests {
 
   #[test]
   fn uvec3() {
-    assert_size_align::<Vec3<i32>>(16, 16);
+    assert_size_align::<Vec3<u32>>(12, 16);
   }
 
   #[test]
@@ -369,7 +501,7 @@ mod tests {
 
   #[test]
   fn bvec3() {
-    assert_size_align::<Vec3<bool>>(16, 16);
+    assert_size_align::<Vec3<bool>>(12, 16);
   }
 
   #[test]
@@ -380,16 +512,36 @@ mod tests {
   #[test]
   fn mat22() {
     assert_size_align::<Mat22<f32>>(32, 16);
+    assert_size_align::<Mat22<f64>>(32, 16);
   }
 
   #[test]
   fn mat33() {
     assert_size_align::<Mat33<f32>>(48, 16);
+    assert_size_align::<Mat33<f64>>(96, 32);
   }
 
   #[test]
   fn mat44() {
     assert_size_align::<Mat44<f32>>(64, 16);
+    assert_size_align::<Mat44<f64>>(128, 32);
+  }
+
+  #[test]
+  fn mat_non_square() {
+    assert_size_align::<Mat23<f32>>(32, 16);
+    assert_size_align::<Mat32<f32>>(48, 16);
+    assert_size_align::<Mat24<f32>>(32, 16);
+    assert_size_align::<Mat42<f32>>(64, 16);
+    assert_size_align::<Mat34<f32>>(48, 16);
+    assert_size_align::<Mat43<f32>>(64, 16);
+
+    assert_size_align::<Mat23<f64>>(64, 32);
+    assert_size_align::<Mat32<f64>>(48, 16);
+    assert_size_align::<Mat24<f64>>(64, 32);
+    assert_size_align::<Mat42<f64>>(64, 16);
+    assert_size_align::<Mat34<f64>>(96, 32);
+    assert_size_align::<Mat43<f64>>(128, 32);
   }
 
   #[test]
@@ -413,7 +565,7 @@ mod tests {
   #[test]
   fn mat22_array() {
     assert_size_align::<ArrElem<Mat22<f32>>>(32, 16);
-    assert_size_align::<ArrElem<Mat22<f64>>>(64, 32);
+    assert_size_align::<ArrElem<Mat22<f64>>>(32, 16);
   }
 
   #[test]
@@ -427,4 +579,12 @@ mod tests {
     assert_size_align::<ArrElem<Mat44<f32>>>(64, 16);
     assert_size_align::<ArrElem<Mat44<f64>>>(128, 32);
   }
+
+  #[test]
+  fn texture_handle() {
+    use luminance::{pixel::NormUnsigned, texture::Dim2};
+
+    assert_size_align::<TextureHandle<Dim2, NormUnsigned>>(8, 8);
+    assert_size_align::<ArrElem<TextureHandle<Dim2, NormUnsigned>>>(16, 16);
+  }
 }
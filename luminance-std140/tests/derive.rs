@@ -0,0 +1,99 @@
+use luminance::{
+  shader::types::{Mat44, Vec2, Vec3},
+  Std140,
+};
+use luminance_std140::{ArrElem, Std140};
+use std::mem;
+
+#[derive(Clone, Copy, Debug, PartialEq, Std140)]
+struct Light {
+  pos: Vec3<f32>,
+  dir: Vec2<f32>,
+  intensity: f32,
+  transform: Mat44<f32>,
+}
+
+#[test]
+fn derive_std140_layout() {
+  assert_eq!(
+    Light::std140_fields(),
+    vec![
+      ("pos", 0),
+      ("dir", 16),
+      ("intensity", 24),
+      ("transform", 32)
+    ]
+  );
+  assert_eq!(mem::size_of::<<Light as Std140>::Encoded>(), 96);
+  assert_eq!(mem::align_of::<<Light as Std140>::Encoded>(), 16);
+  assert_eq!(mem::size_of::<<ArrElem<Light> as Std140>::Encoded>(), 96);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Std140)]
+struct Packed {
+  pos: Vec3<f32>,
+  radius: f32,
+  normal: Vec3<f32>,
+  uv: Vec2<f32>,
+  flags: Vec3<u32>,
+  light: Light,
+  weight: f32,
+  item: ArrElem<f32>,
+  precise: Vec3<f64>,
+  last: f32,
+}
+
+#[test]
+fn derive_std140_offsets() {
+  // a scalar is packed right after a vec3
+  assert_eq!(mem::offset_of!(PackedStd140, pos), 0);
+  assert_eq!(mem::offset_of!(PackedStd140, radius), 12);
+  // vec3 are aligned on 16 bytes, vec2 on 8 bytes
+  assert_eq!(mem::offset_of!(PackedStd140, normal), 16);
+  assert_eq!(mem::offset_of!(PackedStd140, uv), 32);
+  assert_eq!(mem::offset_of!(PackedStd140, flags), 48);
+  // structures are aligned on 16 bytes and their size is rounded up to 16 bytes
+  assert_eq!(mem::offset_of!(PackedStd140, light), 64);
+  assert_eq!(mem::offset_of!(PackedStd140, weight), 160);
+  // array items are aligned on 16 bytes, and dvec3 on 32 bytes
+  assert_eq!(mem::offset_of!(PackedStd140, item), 176);
+  assert_eq!(mem::offset_of!(PackedStd140, precise), 192);
+  assert_eq!(mem::offset_of!(PackedStd140, last), 216);
+
+  // the structure is aligned on its most aligned field
+  assert_eq!(<Packed as Std140>::ALIGN, 32);
+  assert_eq!(mem::size_of::<PackedStd140>(), 224);
+
+  assert_eq!(
+    Packed::std140_fields(),
+    vec![
+      ("pos", 0),
+      ("radius", 12),
+      ("normal", 16),
+      ("uv", 32),
+      ("flags", 48),
+      ("light", 64),
+      ("weight", 160),
+      ("item", 176),
+      ("precise", 192),
+      ("last", 216),
+    ]
+  );
+}
+
+#[test]
+fn derive_std140_roundtrip() {
+  let light = Light {
+    pos: Vec3::new(1., 2., 3.),
+    dir: Vec2::new(0., 1.),
+    intensity: 0.5,
+    transform: Mat44::from([
+      [1., 0., 0., 0.],
+      [0., 1., 0., 0.],
+      [0., 0., 1., 0.],
+      [0., 0., 0., 1.],
+    ]),
+  };
+
+  assert_eq!(Light::std140_decode(light.std140_encode()), light);
+}
@@ -12,6 +12,7 @@ use std::{
   marker::PhantomData,
   mem,
   ops::{Deref, DerefMut},
+  ptr,
   rc::Rc,
   slice,
 };
@@ -98,6 +99,46 @@ where
     &self.gl_buf.handle
   }
 
+  /// Write `values` to the buffer, starting at the value at index `offset`.
+  ///
+  /// `U` can be another type than `T`, such as for buffers of raw bytes; `offset` is then in `U` values.
+  pub(crate) fn write<U>(&mut self, offset: usize, values: &[U]) -> Result<(), TessError>
+  where
+    U: Copy,
+  {
+    let capacity = mem::size_of_val(self.buf.as_slice()) / mem::size_of::<U>().max(1);
+
+    if offset
+      .checked_add(values.len())
+      .map_or(true, |end| end > capacity)
+    {
+      return Err(TessError::out_of_bounds(offset, values.len(), capacity));
+    }
+
+    let byte_offset = offset * mem::size_of::<U>();
+    let bytes = mem::size_of_val(values);
+
+    // keep the cached version up to date
+    unsafe {
+      ptr::copy_nonoverlapping(
+        values.as_ptr() as *const u8,
+        (self.buf.as_mut_ptr() as *mut u8).add(byte_offset),
+        bytes,
+      );
+    }
+
+    let mut state = self.gl_buf.state.borrow_mut();
+    update_webgl_buffer::<TARGET>(
+      &mut state,
+      &self.gl_buf.handle,
+      values.as_ptr() as *const u8,
+      bytes,
+      byte_offset,
+    )?;
+
+    Ok(())
+  }
+
   pub(crate) fn slice_buffer(&self) -> BufferSlice<T> {
     BufferSlice {
       handle: &self.gl_buf.handle,
@@ -1,18 +1,28 @@
 //! Framebuffer support for WebGL2.
 
-use crate::webgl2::{state::WebGL2State, WebGL2};
+use crate::webgl2::{
+  pixel::webgl_pixel_format,
+  state::{ScissorState, WebGL2State},
+  texture::set_pack_alignment,
+  WebGL2,
+};
 use js_sys::Uint32Array;
 use luminance::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      Framebuffer as FramebufferBackend, FramebufferBackBuffer, FramebufferBlit, FramebufferLayer,
+      FramebufferLevel, FramebufferMultisample, FramebufferRead, FramebufferRenderbuffer,
+      FramebufferResize,
+    },
   },
-  framebuffer::{FramebufferError, IncompleteReason},
-  texture::{Dim2, Dimensionable, Sampler},
+  framebuffer::{Attachment, BlitMask, FramebufferError, IncompleteReason, Region},
+  pixel::{Format, Pixel, PixelFormat},
+  texture::{Dim, Dim2, Dimensionable, MagFilter, Sampler},
 };
-use std::{cell::RefCell, rc::Rc};
-use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlRenderbuffer};
+use std::{cell::RefCell, mem, rc::Rc, slice};
+use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlRenderbuffer, WebGlTexture};
 
 pub struct Framebuffer<D>
 where
@@ -20,11 +30,101 @@ where
 {
   // None is the default framebuffer…
   pub(crate) handle: Option<WebGlFramebuffer>,
+  // depth renderbuffer owned by the framebuffer: default depth buffer or renderbuffer slot
   renderbuffer: Option<WebGlRenderbuffer>,
   pub(crate) size: D::Size,
+  // attached textures, as (attachment point, texture, texture target)
+  attachments: Vec<(u32, WebGlTexture, u32)>,
+  // attached layer of layered textures; WebGL2 has no layered rendering, so a single layer is always attached
+  layer: u32,
+  // attached mipmap level of the textures
+  level: usize,
+  // attachment point of the depth texture; depth/stencil textures are attached to both depth and stencil
+  depth_attachment: u32,
+  // color renderbuffers owned by the framebuffer: multisample storage or renderbuffer slots
+  color_renderbuffers: Vec<WebGlRenderbuffer>,
+  // whether the storage of the framebuffer is multisampled
+  multisampled: bool,
   state: Rc<RefCell<WebGL2State>>,
 }
 
+impl<D> Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Attach the textures of the framebuffer with the given layer.
+  fn attach_layer(&self, layer: u32) -> Result<(), FramebufferError> {
+    let state = self.state.borrow();
+
+    for (attachment, texture, target) in &self.attachments {
+      attach_texture(&state, *attachment, texture, *target, layer, self.level)?;
+    }
+
+    Ok(())
+  }
+
+  /// Number of attached color buffers.
+  pub(crate) fn color_attachments_len(&self) -> usize {
+    let textures = self
+      .attachments
+      .iter()
+      .filter(|(attachment, _, _)| *attachment != self.depth_attachment)
+      .count();
+
+    textures + self.color_renderbuffers.len()
+  }
+
+  /// Size of the attached mipmap level.
+  pub(crate) fn level_size(&self) -> D::Size {
+    D::level_size(self.size, self.level)
+  }
+
+  /// Number of layers of the attached mipmap level; 3D textures have fewer layers in higher levels.
+  fn level_layers(&self) -> u32 {
+    match D::dim() {
+      Dim::Dim3 | Dim::Dim2Array => D::depth(self.level_size()),
+      Dim::Cubemap => 6,
+      _ => 1,
+    }
+  }
+
+  /// Check whether the framebuffer, bound as draw framebuffer with `layer` attached, is complete.
+  ///
+  /// The offending texture of incomplete attachments is looked for.
+  fn status(&self, state: &mut WebGL2State, layer: u32) -> Result<(), IncompleteReason> {
+    match get_framebuffer_status(state) {
+      Err(IncompleteReason::IncompleteAttachment(None)) => Err(
+        IncompleteReason::IncompleteAttachment(self.find_incomplete_attachment(state, layer)),
+      ),
+      status => status,
+    }
+  }
+
+  /// Find the incomplete attachment by detaching the textures one at a time, until the framebuffer no longer has an
+  /// incomplete attachment.
+  fn find_incomplete_attachment(&self, state: &mut WebGL2State, layer: u32) -> Option<Attachment> {
+    for (attachment, texture, target) in &self.attachments {
+      state.ctx.framebuffer_texture_2d(
+        WebGl2RenderingContext::FRAMEBUFFER,
+        *attachment,
+        WebGl2RenderingContext::TEXTURE_2D,
+        None,
+        0,
+      );
+      let status = get_framebuffer_status(state);
+
+      // the texture was attached with the same arguments, so this cannot fail
+      let _ = attach_texture(state, *attachment, texture, *target, layer, self.level);
+
+      if !matches!(status, Err(IncompleteReason::IncompleteAttachment(_))) {
+        return Some(from_webgl_attachment(*attachment));
+      }
+    }
+
+    None
+  }
+}
+
 impl<D> Drop for Framebuffer<D>
 where
   D: Dimensionable,
@@ -32,6 +132,10 @@ where
   fn drop(&mut self) {
     let state = self.state.borrow();
 
+    for renderbuffer in &self.color_renderbuffers {
+      state.ctx.delete_renderbuffer(Some(renderbuffer));
+    }
+
     state.ctx.delete_renderbuffer(self.renderbuffer.as_ref());
     state.ctx.delete_framebuffer(self.handle.as_ref());
   }
@@ -58,6 +162,7 @@ where
     let mut depth_renderbuffer = None;
 
     let mut state = self.state.borrow_mut();
+    check_color_attachments(&mut state, color_formats.len())?;
 
     let handle = state
       .create_framebuffer()
@@ -116,6 +221,12 @@ where
       handle: Some(handle),
       renderbuffer: depth_renderbuffer,
       size,
+      attachments: Vec::new(),
+      layer: 0,
+      level: 0,
+      depth_attachment: depth_attachment(depth_format),
+      color_renderbuffers: Vec::new(),
+      multisampled: false,
       state: self.state.clone(),
     };
 
@@ -127,56 +238,97 @@ where
     texture: &Self::TextureRepr,
     attachment_index: usize,
   ) -> Result<(), FramebufferError> {
-    match texture.target {
-      WebGl2RenderingContext::TEXTURE_2D => {
-        let state = framebuffer.state.borrow();
-        state.ctx.framebuffer_texture_2d(
-          WebGl2RenderingContext::FRAMEBUFFER,
-          WebGl2RenderingContext::COLOR_ATTACHMENT0 + attachment_index as u32,
-          texture.target,
-          Some(&texture.handle),
-          0,
-        );
-
-        Ok(())
-      }
+    let attachment = WebGl2RenderingContext::COLOR_ATTACHMENT0 + attachment_index as u32;
+    attach_texture(
+      &framebuffer.state.borrow(),
+      attachment,
+      &texture.handle,
+      texture.target,
+      framebuffer.layer,
+      framebuffer.level,
+    )?;
+    framebuffer
+      .attachments
+      .push((attachment, texture.handle.clone(), texture.target));
 
-      _ => Err(FramebufferError::unsupported_attachment()),
-    }
+    Ok(())
   }
 
   unsafe fn attach_depth_texture(
     framebuffer: &mut Self::FramebufferRepr,
     texture: &Self::TextureRepr,
   ) -> Result<(), FramebufferError> {
-    match texture.target {
-      WebGl2RenderingContext::TEXTURE_2D => {
-        let state = framebuffer.state.borrow();
-        state.ctx.framebuffer_texture_2d(
-          WebGl2RenderingContext::FRAMEBUFFER,
-          WebGl2RenderingContext::DEPTH_ATTACHMENT,
-          texture.target,
-          Some(&texture.handle),
-          0,
-        );
-
-        Ok(())
-      }
+    let attachment = framebuffer.depth_attachment;
+    attach_texture(
+      &framebuffer.state.borrow(),
+      attachment,
+      &texture.handle,
+      texture.target,
+      framebuffer.layer,
+      framebuffer.level,
+    )?;
+    framebuffer
+      .attachments
+      .push((attachment, texture.handle.clone(), texture.target));
 
-      _ => Err(FramebufferError::unsupported_attachment()),
-    }
+    Ok(())
   }
 
   unsafe fn validate_framebuffer(
     framebuffer: Self::FramebufferRepr,
   ) -> Result<Self::FramebufferRepr, FramebufferError> {
-    get_framebuffer_status(&mut framebuffer.state.borrow_mut())?;
+    framebuffer.status(&mut framebuffer.state.borrow_mut(), framebuffer.layer)?;
     Ok(framebuffer)
   }
 
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size {
-    framebuffer.size
+    framebuffer.level_size()
+  }
+}
+
+/// Attach the `level` mipmap level of a texture to the currently bound framebuffer; only the given layer of layered
+/// textures is attached.
+fn attach_texture(
+  state: &WebGL2State,
+  attachment: u32,
+  texture: &WebGlTexture,
+  target: u32,
+  layer: u32,
+  level: usize,
+) -> Result<(), FramebufferError> {
+  let level = level as i32;
+
+  match target {
+    WebGl2RenderingContext::TEXTURE_2D => state.ctx.framebuffer_texture_2d(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      attachment,
+      target,
+      Some(texture),
+      level,
+    ),
+
+    WebGl2RenderingContext::TEXTURE_CUBE_MAP => state.ctx.framebuffer_texture_2d(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      attachment,
+      WebGl2RenderingContext::TEXTURE_CUBE_MAP_POSITIVE_X + layer,
+      Some(texture),
+      level,
+    ),
+
+    WebGl2RenderingContext::TEXTURE_3D | WebGl2RenderingContext::TEXTURE_2D_ARRAY => {
+      state.ctx.framebuffer_texture_layer(
+        WebGl2RenderingContext::FRAMEBUFFER,
+        attachment,
+        Some(texture),
+        level,
+        layer as i32,
+      )
+    }
+
+    _ => return Err(FramebufferError::unsupported_attachment()),
   }
+
+  Ok(())
 }
 
 fn get_framebuffer_status(state: &mut WebGL2State) -> Result<(), IncompleteReason> {
@@ -187,7 +339,7 @@ fn get_framebuffer_status(state: &mut WebGL2State) -> Result<(), IncompleteReaso
   match status {
     WebGl2RenderingContext::FRAMEBUFFER_COMPLETE => Ok(()),
     WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => {
-      Err(IncompleteReason::IncompleteAttachment)
+      Err(IncompleteReason::IncompleteAttachment(None))
     }
     WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => {
       Err(IncompleteReason::MissingAttachment)
@@ -196,10 +348,95 @@ fn get_framebuffer_status(state: &mut WebGL2State) -> Result<(), IncompleteReaso
     WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => {
       Err(IncompleteReason::IncompleteMultisample)
     }
-    _ => panic!(
-      "unknown WebGL2 framebuffer incomplete status! status={}",
-      status
-    ),
+    WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_DIMENSIONS => {
+      Err(IncompleteReason::IncompleteDimensions)
+    }
+    _ => Err(IncompleteReason::Unknown(status)),
+  }
+}
+
+/// Attachment of a WebGL2 attachment point.
+fn from_webgl_attachment(attachment: u32) -> Attachment {
+  match attachment {
+    WebGl2RenderingContext::DEPTH_ATTACHMENT => Attachment::Depth,
+    WebGl2RenderingContext::STENCIL_ATTACHMENT => Attachment::Stencil,
+    WebGl2RenderingContext::DEPTH_STENCIL_ATTACHMENT => Attachment::DepthStencil,
+    _ => Attachment::Color((attachment - WebGl2RenderingContext::COLOR_ATTACHMENT0) as usize),
+  }
+}
+
+unsafe impl<D> FramebufferLayer<D> for WebGL2
+where
+  D: Dimensionable,
+{
+  unsafe fn select_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    layer: Option<u32>,
+  ) -> Result<(), FramebufferError> {
+    // layered rendering is not supported
+    let layer = layer.ok_or_else(FramebufferError::unsupported_attachment)?;
+
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    let result = framebuffer.attach_layer(layer).and_then(|_| {
+      framebuffer
+        .status(&mut framebuffer.state.borrow_mut(), layer)
+        .map_err(FramebufferError::from)
+    });
+
+    match result {
+      Ok(_) => framebuffer.layer = layer,
+      Err(_) => {
+        // the previous layer was attached successfully, so this cannot fail
+        let _ = framebuffer.attach_layer(framebuffer.layer);
+      }
+    }
+
+    result
+  }
+}
+
+unsafe impl<D> FramebufferLevel<D> for WebGL2
+where
+  D: Dimensionable,
+{
+  unsafe fn select_level(
+    framebuffer: &mut Self::FramebufferRepr,
+    level: usize,
+  ) -> Result<(), FramebufferError> {
+    framebuffer
+      .state
+      .borrow_mut()
+      .bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    let previous_level = mem::replace(&mut framebuffer.level, level);
+
+    // 3D textures have fewer layers in higher mipmap levels; the first layer is attached if the selected one is gone
+    let layer = if framebuffer.layer < framebuffer.level_layers() {
+      framebuffer.layer
+    } else {
+      0
+    };
+
+    let result = framebuffer.attach_layer(layer).and_then(|_| {
+      framebuffer
+        .status(&mut framebuffer.state.borrow_mut(), layer)
+        .map_err(FramebufferError::from)
+    });
+
+    match result {
+      Ok(_) => framebuffer.layer = layer,
+      Err(_) => {
+        // the previous level was attached successfully, so this cannot fail
+        framebuffer.level = previous_level;
+        let _ = framebuffer.attach_layer(framebuffer.layer);
+      }
+    }
+
+    result
   }
 }
 
@@ -212,7 +449,529 @@ unsafe impl FramebufferBackBuffer for WebGL2 {
       handle: None, // None is the default framebuffer in WebGL
       renderbuffer: None,
       size,
+      attachments: Vec::new(),
+      layer: 0,
+      level: 0,
+      depth_attachment: WebGl2RenderingContext::DEPTH_ATTACHMENT,
+      color_renderbuffers: Vec::new(),
+      multisampled: false,
       state: self.state.clone(),
     })
   }
 }
+
+unsafe impl FramebufferMultisample for WebGL2 {
+  unsafe fn new_multisample_framebuffer<CS, DS>(
+    &mut self,
+    size: <Dim2 as Dimensionable>::Size,
+    samples: usize,
+  ) -> Result<Self::FramebufferRepr, FramebufferError>
+  where
+    CS: ColorSlot<Self, Dim2>,
+    DS: DepthStencilSlot<Self, Dim2>,
+  {
+    let mut state = self.state.borrow_mut();
+    let max_samples = state.get_max_samples().unwrap_or(0);
+
+    if samples == 0 || samples > max_samples {
+      return Err(FramebufferError::unsupported_sample_count(samples));
+    }
+
+    let color_formats = CS::color_formats();
+    let depth_format = DS::depth_format();
+    check_color_attachments(&mut state, color_formats.len())?;
+
+    let handle = state
+      .create_framebuffer()
+      .ok_or_else(FramebufferError::cannot_create)?;
+    state.bind_draw_framebuffer(Some(&handle));
+
+    // color renderbuffers; WebGL2 has no multisample textures
+    let mut color_renderbuffers = Vec::with_capacity(color_formats.len());
+
+    for (i, &pf) in color_formats.iter().enumerate() {
+      let (_, iformat, _) =
+        webgl_pixel_format(pf).ok_or(FramebufferError::UnsupportedAttachment)?;
+      let renderbuffer = new_multisample_renderbuffer(&state, samples, iformat, size)?;
+
+      state.ctx.framebuffer_renderbuffer(
+        WebGl2RenderingContext::FRAMEBUFFER,
+        WebGl2RenderingContext::COLOR_ATTACHMENT0 + i as u32,
+        WebGl2RenderingContext::RENDERBUFFER,
+        Some(&renderbuffer),
+      );
+
+      color_renderbuffers.push(renderbuffer);
+    }
+
+    if color_formats.is_empty() {
+      state.ctx.draw_buffers(&WebGl2RenderingContext::NONE.into());
+    } else {
+      let color_buf_nb = color_formats.len() as u32;
+      let color_buffers: Vec<_> = (WebGl2RenderingContext::COLOR_ATTACHMENT0
+        ..WebGl2RenderingContext::COLOR_ATTACHMENT0 + color_buf_nb)
+        .collect();
+
+      let buffers = Uint32Array::view(&color_buffers);
+
+      state.ctx.draw_buffers(buffers.as_ref());
+    }
+
+    // depth renderbuffer; a default depth buffer is allocated if the depth slot is ()
+    let depth_iformat = match depth_format {
+      Some(pf) => {
+        webgl_pixel_format(pf)
+          .ok_or(FramebufferError::UnsupportedAttachment)?
+          .1
+      }
+      None => WebGl2RenderingContext::DEPTH_COMPONENT32F,
+    };
+    let depth_attachment = depth_attachment(depth_format);
+    let renderbuffer = new_multisample_renderbuffer(&state, samples, depth_iformat, size)?;
+
+    state.ctx.framebuffer_renderbuffer(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      depth_attachment,
+      WebGl2RenderingContext::RENDERBUFFER,
+      Some(&renderbuffer),
+    );
+
+    drop(state);
+
+    let framebuffer = Framebuffer {
+      handle: Some(handle),
+      renderbuffer: Some(renderbuffer),
+      size,
+      attachments: Vec::new(),
+      layer: 0,
+      level: 0,
+      depth_attachment,
+      color_renderbuffers,
+      multisampled: true,
+      state: self.state.clone(),
+    };
+
+    <Self as FramebufferBackend<Dim2>>::validate_framebuffer(framebuffer)
+  }
+
+  unsafe fn resolve(
+    framebuffer: &Self::FramebufferRepr,
+    target: &mut Self::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    let region = Region::whole(framebuffer.size);
+    blit_framebuffer(
+      framebuffer,
+      target,
+      region,
+      region,
+      BlitMask::COLOR,
+      MagFilter::Nearest,
+    )
+  }
+}
+
+unsafe impl FramebufferBlit for WebGL2 {
+  unsafe fn blit(
+    framebuffer: &Self::FramebufferRepr,
+    target: &mut Self::FramebufferRepr,
+    src: Region,
+    dst: Region,
+    mask: BlitMask,
+    filter: MagFilter,
+  ) -> Result<(), FramebufferError> {
+    blit_framebuffer(framebuffer, target, src, dst, mask, filter)
+  }
+}
+
+unsafe impl FramebufferRead for WebGL2 {
+  unsafe fn read_pixels<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment: Option<usize>,
+    region: Region,
+  ) -> Result<Vec<P::Encoding>, FramebufferError>
+  where
+    P: Pixel,
+    P::Encoding: Copy + Default,
+  {
+    if framebuffer.multisampled {
+      return Err(FramebufferError::cannot_read(
+        "multisampled framebuffers must be resolved before being read",
+      ));
+    }
+
+    let read_buffer = match attachment {
+      Some(_) if framebuffer.handle.is_none() => WebGl2RenderingContext::BACK,
+      Some(index) if index < framebuffer.color_attachments_len() => {
+        WebGl2RenderingContext::COLOR_ATTACHMENT0 + index as u32
+      }
+      Some(index) => {
+        return Err(FramebufferError::cannot_read(format!(
+          "no color attachment at index {}",
+          index
+        )))
+      }
+      None => {
+        return Err(FramebufferError::cannot_read(
+          "WebGL2 cannot read depth pixels",
+        ))
+      }
+    };
+
+    let pf = P::pixel_format();
+    let (format, _, ty) = webgl_pixel_format(pf)
+      .ok_or_else(|| FramebufferError::cannot_read(format!("unsupported pixel format {:?}", pf)))?;
+
+    let len = region.width as usize * region.height as usize;
+    let mut pixels = vec![P::Encoding::default(); len];
+
+    if len == 0 {
+      return Ok(pixels);
+    }
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_read_framebuffer(framebuffer.handle.as_ref());
+    state.ctx.read_buffer(read_buffer);
+
+    // set the packing alignment based on the number of bytes to skip, so that rows are tightly packed
+    let skip_bytes = (region.width as usize * mem::size_of::<P::Encoding>()) % 8;
+    set_pack_alignment(&mut state, skip_bytes);
+
+    state
+      .ctx
+      .read_pixels_with_u8_array_and_dst_offset(
+        region.x as i32,
+        region.y as i32,
+        region.width as i32,
+        region.height as i32,
+        format,
+        ty,
+        slice::from_raw_parts_mut(
+          pixels.as_mut_ptr() as *mut u8,
+          mem::size_of_val(pixels.as_slice()),
+        ),
+        0,
+      )
+      .map_err(|e| FramebufferError::cannot_read(format!("{:?}", e)))?;
+
+    Ok(pixels)
+  }
+}
+
+/// Blit the `src` region of `framebuffer` into the `dst` region of `target`.
+unsafe fn blit_framebuffer(
+  framebuffer: &Framebuffer<Dim2>,
+  target: &mut Framebuffer<Dim2>,
+  src: Region,
+  dst: Region,
+  mask: BlitMask,
+  filter: MagFilter,
+) -> Result<(), FramebufferError> {
+  if framebuffer.multisampled && (src.width, src.height) != (dst.width, dst.height) {
+    return Err(FramebufferError::cannot_blit(
+      "multisampled framebuffers can only be blitted without scaling",
+    ));
+  }
+
+  let [src_x0, src_y0, src_x1, src_y1] =
+    [src.x, src.y, src.x + src.width, src.y + src.height].map(|c| c as i32);
+  let [dst_x0, dst_y0, dst_x1, dst_y1] =
+    [dst.x, dst.y, dst.x + dst.width, dst.y + dst.height].map(|c| c as i32);
+  let filter = match filter {
+    MagFilter::Nearest => WebGl2RenderingContext::NEAREST,
+    MagFilter::Linear => WebGl2RenderingContext::LINEAR,
+  };
+
+  // the back buffer has a single color buffer
+  let color_buffers_len = |framebuffer: &Framebuffer<Dim2>| {
+    if framebuffer.handle.is_none() {
+      1
+    } else {
+      framebuffer.color_attachments_len()
+    }
+  };
+  let target_colors = color_buffers_len(target);
+  let colors = if mask.color {
+    color_buffers_len(framebuffer).min(target_colors)
+  } else {
+    0
+  };
+
+  let mut state = target.state.borrow_mut();
+
+  // blits are affected by the scissor test
+  state.set_scissor_state(ScissorState::Off);
+  state.bind_draw_framebuffer(target.handle.as_ref());
+  state.bind_read_framebuffer(framebuffer.handle.as_ref());
+
+  for i in 0..colors {
+    let attachment = WebGl2RenderingContext::COLOR_ATTACHMENT0 + i as u32;
+
+    // color attachments are not valid buffers of the back buffer
+    if framebuffer.handle.is_none() {
+      state.ctx.read_buffer(WebGl2RenderingContext::BACK);
+    } else {
+      state.ctx.read_buffer(attachment);
+    }
+
+    if target.handle.is_none() {
+      let draw_buffers = [WebGl2RenderingContext::BACK];
+      state
+        .ctx
+        .draw_buffers(Uint32Array::view(&draw_buffers).as_ref());
+    } else {
+      // draw only to the attachment of the same index
+      let draw_buffers: Vec<_> = (0..=i as u32)
+        .map(|j| {
+          if j == i as u32 {
+            attachment
+          } else {
+            WebGl2RenderingContext::NONE
+          }
+        })
+        .collect();
+      state
+        .ctx
+        .draw_buffers(Uint32Array::view(&draw_buffers).as_ref());
+    }
+
+    state.ctx.blit_framebuffer(
+      src_x0,
+      src_y0,
+      src_x1,
+      src_y1,
+      dst_x0,
+      dst_y0,
+      dst_x1,
+      dst_y1,
+      WebGl2RenderingContext::COLOR_BUFFER_BIT,
+      filter,
+    );
+  }
+
+  // restore the draw buffers of the target
+  if colors > 0 && target.handle.is_some() {
+    let color_buffers: Vec<_> = (WebGl2RenderingContext::COLOR_ATTACHMENT0
+      ..WebGl2RenderingContext::COLOR_ATTACHMENT0 + target_colors as u32)
+      .collect();
+    state
+      .ctx
+      .draw_buffers(Uint32Array::view(&color_buffers).as_ref());
+  }
+
+  // depth and stencil are blitted at once
+  let mut depth_stencil_bits = 0;
+
+  if mask.depth {
+    depth_stencil_bits |= WebGl2RenderingContext::DEPTH_BUFFER_BIT;
+  }
+
+  if mask.stencil {
+    depth_stencil_bits |= WebGl2RenderingContext::STENCIL_BUFFER_BIT;
+  }
+
+  if depth_stencil_bits != 0 {
+    state.ctx.blit_framebuffer(
+      src_x0,
+      src_y0,
+      src_x1,
+      src_y1,
+      dst_x0,
+      dst_y0,
+      dst_x1,
+      dst_y1,
+      depth_stencil_bits,
+      WebGl2RenderingContext::NEAREST,
+    );
+  }
+
+  state.bind_read_framebuffer(None);
+
+  Ok(())
+}
+
+unsafe impl<D> FramebufferResize<D> for WebGL2
+where
+  D: Dimensionable,
+{
+  unsafe fn resize_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    size: D::Size,
+  ) -> Result<(), FramebufferError> {
+    framebuffer.size = size;
+
+    // the back buffer is resized with the canvas
+    if framebuffer.handle.is_none() {
+      return Ok(());
+    }
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    // renderbuffers keep their format and number of samples
+    for renderbuffer in framebuffer
+      .renderbuffer
+      .iter()
+      .chain(&framebuffer.color_renderbuffers)
+    {
+      state
+        .ctx
+        .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, Some(renderbuffer));
+
+      let [iformat, samples] = [
+        WebGl2RenderingContext::RENDERBUFFER_INTERNAL_FORMAT,
+        WebGl2RenderingContext::RENDERBUFFER_SAMPLES,
+      ]
+      .map(|pname| {
+        state
+          .ctx
+          .get_renderbuffer_parameter(WebGl2RenderingContext::RENDERBUFFER, pname)
+          .as_f64()
+          .unwrap_or(0.)
+      });
+
+      state.ctx.renderbuffer_storage_multisample(
+        WebGl2RenderingContext::RENDERBUFFER,
+        samples as i32,
+        iformat as u32,
+        D::width(size) as i32,
+        D::height(size) as i32,
+      );
+    }
+
+    state
+      .ctx
+      .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, None);
+
+    // textures of the slots are attached again once resized
+    framebuffer.attachments.clear();
+
+    if framebuffer.layer >= framebuffer.level_layers() {
+      framebuffer.layer = 0;
+    }
+
+    Ok(())
+  }
+
+  unsafe fn validate_resized_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    if framebuffer.handle.is_none() {
+      return Ok(());
+    }
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    framebuffer
+      .status(&mut state, framebuffer.layer)
+      .map_err(FramebufferError::from)
+  }
+}
+
+unsafe impl FramebufferRenderbuffer for WebGL2 {
+  unsafe fn attach_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    pixel_format: PixelFormat,
+    attachment_index: Option<usize>,
+  ) -> Result<(), FramebufferError> {
+    let (_, iformat, _) =
+      webgl_pixel_format(pixel_format).ok_or(FramebufferError::UnsupportedAttachment)?;
+    let attachment = match attachment_index {
+      Some(index) => WebGl2RenderingContext::COLOR_ATTACHMENT0 + index as u32,
+      None => framebuffer.depth_attachment,
+    };
+    let [width, height] = framebuffer.size;
+
+    let state = framebuffer.state.borrow();
+    let renderbuffer = state
+      .ctx
+      .create_renderbuffer()
+      .ok_or_else(FramebufferError::cannot_create)?;
+
+    state
+      .ctx
+      .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, Some(&renderbuffer));
+    state.ctx.renderbuffer_storage(
+      WebGl2RenderingContext::RENDERBUFFER,
+      iformat,
+      width as i32,
+      height as i32,
+    );
+    state
+      .ctx
+      .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, None);
+
+    state.ctx.framebuffer_renderbuffer(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      attachment,
+      WebGl2RenderingContext::RENDERBUFFER,
+      Some(&renderbuffer),
+    );
+
+    drop(state);
+
+    if attachment_index.is_some() {
+      framebuffer.color_renderbuffers.push(renderbuffer);
+    } else {
+      framebuffer.renderbuffer = Some(renderbuffer);
+    }
+
+    Ok(())
+  }
+}
+
+/// Create a multisampled renderbuffer.
+fn new_multisample_renderbuffer(
+  state: &WebGL2State,
+  samples: usize,
+  iformat: u32,
+  [width, height]: [u32; 2],
+) -> Result<WebGlRenderbuffer, FramebufferError> {
+  let renderbuffer = state
+    .ctx
+    .create_renderbuffer()
+    .ok_or_else(FramebufferError::cannot_create)?;
+
+  state
+    .ctx
+    .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, Some(&renderbuffer));
+  state.ctx.renderbuffer_storage_multisample(
+    WebGl2RenderingContext::RENDERBUFFER,
+    samples as i32,
+    iformat,
+    width as i32,
+    height as i32,
+  );
+  state
+    .ctx
+    .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, None);
+
+  Ok(renderbuffer)
+}
+
+/// Check that `count` color attachments can be drawn to.
+///
+/// WebGL2 guarantees at least 4 color attachments, which is assumed if the limit cannot be queried.
+fn check_color_attachments(state: &mut WebGL2State, count: usize) -> Result<(), FramebufferError> {
+  let max = state.get_max_color_attachments().unwrap_or(4);
+
+  if count > max {
+    Err(FramebufferError::too_many_color_attachments(count, max))
+  } else {
+    Ok(())
+  }
+}
+
+fn depth_attachment(depth_format: Option<PixelFormat>) -> u32 {
+  match depth_format {
+    Some(PixelFormat {
+      format: Format::DepthStencil(..),
+      ..
+    }) => WebGl2RenderingContext::DEPTH_STENCIL_ATTACHMENT,
+    Some(PixelFormat {
+      format: Format::Stencil(_),
+      ..
+    }) => WebGl2RenderingContext::STENCIL_ATTACHMENT,
+    _ => WebGl2RenderingContext::DEPTH_ATTACHMENT,
+  }
+}
@@ -1,5 +1,6 @@
 //! Pipeline support for WebGL2.
 
+use js_sys::Array;
 use luminance::{
   backend::{
     pipeline::{Pipeline as PipelineBackend, PipelineBase, PipelineShaderData, PipelineTexture},
@@ -7,21 +8,25 @@ use luminance::{
     shader::ShaderData,
     shading_gate::ShadingGate,
     tess::Tess,
-    tess_gate::TessGate,
+    tess_gate::{TessGate, TessGateValidation},
   },
   blending::BlendingMode,
-  pipeline::{PipelineError, PipelineState, Viewport},
+  color_mask::ColorMask,
+  pipeline::{ClearColor, PipelineError, PipelineState, Viewport},
   pixel::Pixel,
   render_state::RenderState,
+  shader::{ActiveVertexAttrib, GeometryConfig},
   tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData},
   texture::Dimensionable,
 };
 use luminance_std140::{ArrElem, Std140};
 use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use wasm_bindgen::JsValue;
 use web_sys::WebGl2RenderingContext;
 
 use crate::webgl2::{
   array_buffer::IntoArrayBuffer,
+  shader::active_vertex_attribs,
   state::{BlendingState, FaceCullingState, ScissorState, WebGL2State},
   WebGL2,
 };
@@ -101,7 +106,7 @@ where
 
     state.bind_draw_framebuffer(framebuffer.handle.as_ref());
 
-    let size = framebuffer.size;
+    let size = framebuffer.level_size();
 
     let (x, y, w, h) = match pipeline_state.viewport {
       Viewport::Whole => (0, 0, D::width(size), D::height(size)),
@@ -115,6 +120,11 @@ where
 
     state.set_viewport([x as _, y as _, w as _, h as _]);
 
+    // color masks left by a previous render state also apply to clears
+    if pipeline_state.clear_color.is_some() || !pipeline_state.clear_color_attachments.is_empty() {
+      state.set_color_masks(ColorMask::ALL, &[]);
+    }
+
     let mut clear_buffer_bits = 0;
 
     if let Some(clear_color) = pipeline_state.clear_color {
@@ -148,6 +158,97 @@ where
     if clear_buffer_bits != 0 {
       state.ctx.clear(clear_buffer_bits);
     }
+
+    // the back buffer has a single color buffer
+    let colors = if framebuffer.handle.is_none() {
+      1
+    } else {
+      framebuffer.color_attachments_len()
+    };
+
+    for &(index, color) in &pipeline_state.clear_color_attachments {
+      if index >= colors {
+        continue;
+      }
+
+      let buffer = WebGl2RenderingContext::COLOR;
+      let draw_buffer = index as i32;
+
+      match color {
+        ClearColor::Float(color) => {
+          state
+            .ctx
+            .clear_bufferfv_with_f32_array(buffer, draw_buffer, &color)
+        }
+        ClearColor::Int(color) => {
+          state
+            .ctx
+            .clear_bufferiv_with_i32_array(buffer, draw_buffer, &color)
+        }
+        ClearColor::UInt(color) => {
+          state
+            .ctx
+            .clear_bufferuiv_with_u32_array(buffer, draw_buffer, &color)
+        }
+      }
+    }
+  }
+
+  unsafe fn end_pipeline(
+    &mut self,
+    framebuffer: &Self::FramebufferRepr,
+    pipeline_state: &PipelineState,
+  ) {
+    let invalidate = &pipeline_state.invalidate_attachments;
+
+    if invalidate.is_empty() {
+      return;
+    }
+
+    // the back buffer has a single color buffer and different attachment names
+    let (colors, color_attachment, depth_attachment, stencil_attachment) =
+      if framebuffer.handle.is_none() {
+        (
+          1,
+          WebGl2RenderingContext::COLOR,
+          WebGl2RenderingContext::DEPTH,
+          WebGl2RenderingContext::STENCIL,
+        )
+      } else {
+        (
+          framebuffer.color_attachments_len(),
+          WebGl2RenderingContext::COLOR_ATTACHMENT0,
+          WebGl2RenderingContext::DEPTH_ATTACHMENT,
+          WebGl2RenderingContext::STENCIL_ATTACHMENT,
+        )
+      };
+
+    let mut attachments: Vec<_> = invalidate
+      .colors
+      .iter()
+      .filter(|&&index| index < colors)
+      .map(|&index| color_attachment + index as u32)
+      .collect();
+
+    if invalidate.depth {
+      attachments.push(depth_attachment);
+    }
+
+    if invalidate.stencil {
+      attachments.push(stencil_attachment);
+    }
+
+    if !attachments.is_empty() {
+      let mut state = self.state.borrow_mut();
+      state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+      let attachments: Array = attachments.into_iter().map(JsValue::from).collect();
+
+      // invalidation is only a hint, so failures are ignored
+      let _ = state
+        .ctx
+        .invalidate_framebuffer(WebGl2RenderingContext::DRAW_FRAMEBUFFER, &attachments);
+    }
   }
 }
 
@@ -268,6 +369,27 @@ where
   }
 }
 
+unsafe impl TessGateValidation for WebGL2 {
+  unsafe fn current_vertex_attribs(&mut self) -> Vec<ActiveVertexAttrib> {
+    let state = self.state.borrow();
+
+    match state.current_program() {
+      Some(program) => active_vertex_attribs(&state.ctx, program),
+      None => Vec::new(),
+    }
+  }
+
+  unsafe fn current_geometry_config(&mut self) -> Option<GeometryConfig> {
+    // WebGL 2 has no geometry stage
+    None
+  }
+
+  unsafe fn current_has_tessellation_stages(&mut self) -> Option<bool> {
+    // WebGL 2 has no tessellation stages
+    self.state.borrow().current_program().map(|_| false)
+  }
+}
+
 unsafe impl RenderGate for WebGL2 {
   unsafe fn enter_render_state(&mut self, rdr_st: &RenderState) {
     let mut state = self.state.borrow_mut();
@@ -302,6 +424,7 @@ unsafe impl RenderGate for WebGL2 {
     }
 
     state.set_depth_write(rdr_st.depth_write());
+    state.set_color_masks(rdr_st.color_mask(), rdr_st.color_attachment_masks());
 
     // stencil-related state
     if let Some(stencil_test) = rdr_st.stencil_test() {
@@ -1,4 +1,5 @@
-use luminance::pixel::{Format, PixelFormat, Size, Type};
+use crate::webgl2::query::compression_extension;
+use luminance::pixel::{CompressedFormat, Format, PixelFormat, Size, Type};
 use web_sys::WebGl2RenderingContext;
 
 // WebGL format, internal sized-format and type.
@@ -36,6 +37,11 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::R16UI,
       WebGl2RenderingContext::UNSIGNED_SHORT,
     )),
+    (Format::R(Size::Sixteen), Type::Floating) => Some((
+      WebGl2RenderingContext::RED,
+      WebGl2RenderingContext::R16F,
+      WebGl2RenderingContext::FLOAT,
+    )),
 
     (Format::R(Size::ThirtyTwo), Type::NormUnsigned) => Some((
       WebGl2RenderingContext::RED_INTEGER,
@@ -95,6 +101,11 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::RG16UI,
       WebGl2RenderingContext::UNSIGNED_SHORT,
     )),
+    (Format::RG(Size::Sixteen, Size::Sixteen), Type::Floating) => Some((
+      WebGl2RenderingContext::RG,
+      WebGl2RenderingContext::RG16F,
+      WebGl2RenderingContext::FLOAT,
+    )),
 
     (Format::RG(Size::ThirtyTwo, Size::ThirtyTwo), Type::NormUnsigned) => Some((
       WebGl2RenderingContext::RG,
@@ -154,6 +165,11 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::RGB16UI,
       WebGl2RenderingContext::UNSIGNED_SHORT,
     )),
+    (Format::RGB(Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => Some((
+      WebGl2RenderingContext::RGB,
+      WebGl2RenderingContext::RGB16F,
+      WebGl2RenderingContext::FLOAT,
+    )),
 
     (Format::RGB(Size::Eleven, Size::Eleven, Size::Ten), Type::Floating) => Some((
       WebGl2RenderingContext::RGB,
@@ -227,6 +243,13 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
         WebGl2RenderingContext::UNSIGNED_SHORT,
       ))
     }
+    (Format::RGBA(Size::Sixteen, Size::Sixteen, Size::Sixteen, Size::Sixteen), Type::Floating) => {
+      Some((
+        WebGl2RenderingContext::RGBA,
+        WebGl2RenderingContext::RGBA16F,
+        WebGl2RenderingContext::FLOAT,
+      ))
+    }
 
     (
       Format::RGBA(Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo, Size::ThirtyTwo),
@@ -295,12 +318,34 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       ))
     }
 
+    (Format::Depth(Size::Sixteen), Type::NormUnsigned) => Some((
+      WebGl2RenderingContext::DEPTH_COMPONENT,
+      WebGl2RenderingContext::DEPTH_COMPONENT16,
+      WebGl2RenderingContext::UNSIGNED_SHORT,
+    )),
+    (Format::Depth(Size::TwentyFour), Type::NormUnsigned) => Some((
+      WebGl2RenderingContext::DEPTH_COMPONENT,
+      WebGl2RenderingContext::DEPTH_COMPONENT24,
+      WebGl2RenderingContext::UNSIGNED_INT,
+    )),
     (Format::Depth(Size::ThirtyTwo), Type::Floating) => Some((
       WebGl2RenderingContext::DEPTH_COMPONENT,
       WebGl2RenderingContext::DEPTH_COMPONENT32F,
       WebGl2RenderingContext::FLOAT,
     )),
 
+    // WebGL2 has no stencil texture, so stencil formats are only used by renderbuffers
+    (Format::Stencil(Size::Eight), Type::Unsigned) => Some((
+      WebGl2RenderingContext::STENCIL_INDEX8,
+      WebGl2RenderingContext::STENCIL_INDEX8,
+      WebGl2RenderingContext::UNSIGNED_BYTE,
+    )),
+
+    (Format::DepthStencil(Size::TwentyFour, Size::Eight), Type::NormUnsigned) => Some((
+      WebGl2RenderingContext::DEPTH_STENCIL,
+      WebGl2RenderingContext::DEPTH24_STENCIL8,
+      WebGl2RenderingContext::UNSIGNED_INT_24_8,
+    )),
     (Format::DepthStencil(Size::ThirtyTwo, Size::Eight), Type::Floating) => Some((
       WebGl2RenderingContext::DEPTH_STENCIL,
       WebGl2RenderingContext::DEPTH32F_STENCIL8,
@@ -310,3 +355,41 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
     _ => None,
   }
 }
+
+// WebGL internal format of compressed pixel formats, along with the extension providing it.
+//
+// Compressed formats are only exposed by extensions, so their constants are missing from WebGl2RenderingContext.
+pub(crate) fn webgl_compressed_format(cf: CompressedFormat) -> (u32, &'static str) {
+  let iformat = match cf {
+    CompressedFormat::BC1RGBA => 0x83F1,
+    CompressedFormat::BC2RGBA => 0x83F2,
+    CompressedFormat::BC3RGBA => 0x83F3,
+    // sRGB S3TC formats are provided by their own extension
+    CompressedFormat::BC1SRGBA => return (0x8C4D, "WEBGL_compressed_texture_s3tc_srgb"),
+    CompressedFormat::BC2SRGBA => return (0x8C4E, "WEBGL_compressed_texture_s3tc_srgb"),
+    CompressedFormat::BC3SRGBA => return (0x8C4F, "WEBGL_compressed_texture_s3tc_srgb"),
+    CompressedFormat::BC4R => 0x8DBB,
+    CompressedFormat::BC4RSigned => 0x8DBC,
+    CompressedFormat::BC5RG => 0x8DBD,
+    CompressedFormat::BC5RGSigned => 0x8DBE,
+    CompressedFormat::BC6HRGBUnsigned => 0x8E8F,
+    CompressedFormat::BC6HRGBSigned => 0x8E8E,
+    CompressedFormat::BC7RGBA => 0x8E8C,
+    CompressedFormat::BC7SRGBA => 0x8E8D,
+    CompressedFormat::ETC2RGB => 0x9274,
+    CompressedFormat::ETC2SRGB => 0x9275,
+    CompressedFormat::ETC2RGBA1 => 0x9276,
+    CompressedFormat::ETC2SRGBA1 => 0x9277,
+    CompressedFormat::ETC2RGBA => 0x9278,
+    CompressedFormat::ETC2SRGBA => 0x9279,
+    CompressedFormat::EACR => 0x9270,
+    CompressedFormat::EACRSigned => 0x9271,
+    CompressedFormat::EACRG => 0x9272,
+    CompressedFormat::EACRGSigned => 0x9273,
+    // ASTC formats are enumerated in the same order as the block sizes
+    CompressedFormat::ASTCRGBA(block) => 0x93B0 + block as u32,
+    CompressedFormat::ASTCSRGBA(block) => 0x93D0 + block as u32,
+  };
+
+  (iformat, compression_extension(cf.family()))
+}
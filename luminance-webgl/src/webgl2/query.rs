@@ -1,7 +1,10 @@
 //! Query API implementation.
 
 use crate::WebGL2;
-use luminance::backend::query::{Query as QueryBackend, QueryError};
+use luminance::{
+  backend::query::{Query as QueryBackend, QueryError},
+  pixel::CompressionFamily,
+};
 
 unsafe impl QueryBackend for WebGL2 {
   fn backend_author(&self) -> Result<String, QueryError> {
@@ -43,4 +46,33 @@ unsafe impl QueryBackend for WebGL2 {
       .get_max_texture_array_elements()
       .ok_or_else(|| QueryError::NoMaxTextureArrayElements)
   }
+
+  fn supports_compression(&self, family: CompressionFamily) -> Result<bool, QueryError> {
+    let extension = compression_extension(family);
+    Ok(self.state.borrow_mut().has_extension(extension))
+  }
+
+  fn max_anisotropy(&self) -> Result<Option<f32>, QueryError> {
+    Ok(self.state.borrow_mut().get_max_anisotropy())
+  }
+
+  fn supports_float_blending(&self) -> Result<bool, QueryError> {
+    Ok(self.state.borrow_mut().has_extension("EXT_float_blend"))
+  }
+
+  fn is_back_buffer_srgb(&self) -> Result<bool, QueryError> {
+    // the drawing buffer of WebGL2 canvases is always linear RGB(A)
+    Ok(false)
+  }
+}
+
+/// WebGL extension providing a family of compressed pixel formats.
+pub(crate) fn compression_extension(family: CompressionFamily) -> &'static str {
+  match family {
+    CompressionFamily::S3TC => "WEBGL_compressed_texture_s3tc",
+    CompressionFamily::RGTC => "EXT_texture_compression_rgtc",
+    CompressionFamily::BPTC => "EXT_texture_compression_bptc",
+    CompressionFamily::ETC2 => "WEBGL_compressed_texture_etc",
+    CompressionFamily::ASTC => "WEBGL_compressed_texture_astc",
+  }
 }
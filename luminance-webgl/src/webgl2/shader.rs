@@ -3,13 +3,18 @@
 use super::buffer::{Buffer, BufferError};
 use crate::webgl2::{state::WebGL2State, WebGL2};
 use luminance::{
-  backend::shader::{Shader, ShaderData, Uniformable},
-  pipeline::{ShaderDataBinding, TextureBinding},
+  backend::shader::{Shader, ShaderData, ShaderValidation, Uniformable},
+  pipeline::{ShaderDataBinding, ShadowBinding, TextureBinding},
   pixel::{SamplerType, Type as PixelType},
   shader::{
-    types::{Arr, Mat22, Mat33, Mat44, Vec2, Vec3, Vec4},
-    ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform, UniformType,
-    UniformWarning, VertexAttribWarning,
+    split_version_directive,
+    types::{
+      Arr, Mat22, Mat23, Mat24, Mat32, Mat33, Mat34, Mat42, Mat43, Mat44, Transposed, Vec2, Vec3,
+      Vec4,
+    },
+    ActiveUniform, ActiveVertexAttrib, GeometryConfig, GlslVersion, ProgramError,
+    ProgramValidation, ShaderDataError, StageError, StageType, TessellationStages, Uniform,
+    UniformType, UniformWarning, VertexAttribWarning,
   },
   texture::{Dim, Dimensionable},
   vertex::Semantics,
@@ -36,11 +41,12 @@ impl Stage {
     let state = webgl2.state.borrow();
 
     let shader_ty = webgl_shader_type(ty)
-      .ok_or_else(|| StageError::CompilationFailed(ty, "unsupported shader type".to_owned()))?;
+      .ok_or_else(|| StageError::compilation_failed(ty, "unsupported shader type"))?;
 
-    let handle = state.ctx.create_shader(shader_ty).ok_or_else(|| {
-      StageError::CompilationFailed(ty, "unable to create shader stage".to_owned())
-    })?;
+    let handle = state
+      .ctx
+      .create_shader(shader_ty)
+      .ok_or_else(|| StageError::compilation_failed(ty, "unable to create shader stage"))?;
 
     state.ctx.shader_source(&handle, &patch_shader_src(src));
     state.ctx.compile_shader(&handle);
@@ -49,9 +55,7 @@ impl Stage {
       .ctx
       .get_shader_parameter(&handle, WebGl2RenderingContext::COMPILE_STATUS)
       .as_bool()
-      .ok_or_else(|| {
-        StageError::CompilationFailed(ty, "cannot determine compilation status".to_owned())
-      })?;
+      .ok_or_else(|| StageError::compilation_failed(ty, "cannot determine compilation status"))?;
 
     if compiled {
       Ok(Stage {
@@ -63,7 +67,7 @@ impl Stage {
       let log = state
         .ctx
         .get_shader_info_log(&handle)
-        .ok_or_else(|| StageError::CompilationFailed(ty, "no compilation error".to_owned()))?;
+        .ok_or_else(|| StageError::compilation_failed(ty, "no compilation error"))?;
 
       state.ctx.delete_shader(Some(&handle));
 
@@ -147,7 +151,7 @@ impl Program {
       .ctx
       .get_program_parameter(handle, WebGl2RenderingContext::LINK_STATUS)
       .as_bool()
-      .ok_or_else(|| ProgramError::LinkFailed("unknown link status".to_owned()))?;
+      .ok_or_else(|| ProgramError::link_failed("unknown link status"))?;
 
     if linked {
       Ok(())
@@ -245,6 +249,10 @@ unsafe impl Shader for WebGL2 {
 
   type UniformBuilderRepr = UniformBuilder;
 
+  unsafe fn glsl_version(&self) -> GlslVersion {
+    GlslVersion::new_es(300)
+  }
+
   unsafe fn new_stage(&mut self, ty: StageType, src: &str) -> Result<Self::StageRepr, StageError> {
     Stage::new(self, ty, src)
   }
@@ -275,6 +283,47 @@ unsafe impl Shader for WebGL2 {
     Ok(warnings)
   }
 
+  unsafe fn set_transform_feedback_varyings(
+    program: &mut Self::ProgramRepr,
+    varyings: &[String],
+  ) -> Result<(), ProgramError> {
+    let names = js_sys::Array::new();
+    for name in varyings {
+      names.push(&name.as_str().into());
+    }
+
+    program.state.borrow().ctx.transform_feedback_varyings(
+      &program.handle,
+      names.as_ref(),
+      WebGl2RenderingContext::INTERLEAVED_ATTRIBS,
+    );
+
+    Ok(())
+  }
+
+  unsafe fn set_fragment_output_locations(
+    _: &mut Self::ProgramRepr,
+    _: &[(String, usize)],
+  ) -> Result<(), ProgramError> {
+    Err(ProgramError::creation_failed(
+      "fragment outputs must be bound with layout(location = N) in WebGL 2",
+    ))
+  }
+
+  unsafe fn fragment_output_location(program: &Self::ProgramRepr, name: &str) -> Option<usize> {
+    let location = program
+      .state
+      .borrow()
+      .ctx
+      .get_frag_data_location(&program.handle, name);
+
+    if location < 0 {
+      None
+    } else {
+      Some(location as usize)
+    }
+  }
+
   unsafe fn new_uniform_builder(
     program: &mut Self::ProgramRepr,
   ) -> Result<Self::UniformBuilderRepr, ProgramError> {
@@ -297,6 +346,79 @@ unsafe impl Shader for WebGL2 {
     Ok(uniform)
   }
 
+  unsafe fn ask_uniform_at_location<T>(
+    _: &mut Self::UniformBuilderRepr,
+    location: i32,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    Self: for<'a> Uniformable<'a, T>,
+  {
+    // WebGL2 doesn’t support explicit uniform locations, and its locations are opaque objects that cannot be obtained
+    // without a name
+    Err(UniformWarning::inactive(format!("location {}", location)))
+  }
+
+  unsafe fn active_uniforms(program: &Self::ProgramRepr) -> Vec<ActiveUniform> {
+    let state = program.state.borrow();
+    let count = state
+      .ctx
+      .get_program_parameter(&program.handle, WebGl2RenderingContext::ACTIVE_UNIFORMS)
+      .as_f64()
+      .unwrap_or(0.) as u32;
+
+    (0..count)
+      .filter_map(|index| {
+        // uniforms living in uniform blocks cannot be mapped on their own
+        let block_index = state.ctx.get_active_uniforms(
+          &program.handle,
+          js_sys::Array::of1(&index.into()).as_ref(),
+          WebGl2RenderingContext::UNIFORM_BLOCK_INDEX,
+        );
+
+        if js_sys::Array::from(&block_index).get(0).as_f64() != Some(-1.) {
+          return None;
+        }
+
+        let info = state.ctx.get_active_uniform(&program.handle, index)?;
+
+        Some(ActiveUniform {
+          name: info.name(),
+          // WebGL2 uniform locations are opaque objects
+          location: None,
+          ty: glenum_to_uniform_type(info.type_()),
+          size: info.size() as usize,
+        })
+      })
+      .collect()
+  }
+
+  unsafe fn active_vertex_attribs(program: &Self::ProgramRepr) -> Vec<ActiveVertexAttrib> {
+    active_vertex_attribs(&program.state.borrow().ctx, &program.handle)
+  }
+
+  unsafe fn geometry_config(_: &Self::ProgramRepr) -> Option<GeometryConfig> {
+    // WebGL 2 has no geometry stage
+    None
+  }
+
+  unsafe fn stage_log(stage: &Self::StageRepr) -> String {
+    stage
+      .state
+      .borrow()
+      .ctx
+      .get_shader_info_log(&stage.handle)
+      .unwrap_or_default()
+  }
+
+  unsafe fn program_log(program: &Self::ProgramRepr) -> String {
+    program
+      .state
+      .borrow()
+      .ctx
+      .get_program_info_log(&program.handle)
+      .unwrap_or_default()
+  }
+
   unsafe fn unbound<T>(_: &mut Self::UniformBuilderRepr) -> Uniform<T>
   where
     Self: for<'a> Uniformable<'a, T>,
@@ -305,6 +427,25 @@ unsafe impl Shader for WebGL2 {
   }
 }
 
+unsafe impl ShaderValidation for WebGL2 {
+  unsafe fn validate_program(program: &Self::ProgramRepr) -> ProgramValidation {
+    let state = program.state.borrow();
+    state.ctx.validate_program(&program.handle);
+
+    let valid = state
+      .ctx
+      .get_program_parameter(&program.handle, WebGl2RenderingContext::VALIDATE_STATUS)
+      .as_bool()
+      .unwrap_or(false);
+    let log = state
+      .ctx
+      .get_program_info_log(&program.handle)
+      .unwrap_or_default();
+
+    ProgramValidation { valid, log }
+  }
+}
+
 fn webgl_shader_type(ty: StageType) -> Option<u32> {
   match ty {
     StageType::VertexShader => Some(WebGl2RenderingContext::VERTEX_SHADER),
@@ -313,14 +454,32 @@ fn webgl_shader_type(ty: StageType) -> Option<u32> {
   }
 }
 
-const GLSL_PRAGMA: &str = "#version 300 es\n\
-                           precision highp float;\n\
+const GLSL_VERSION: &str = "#version 300 es\n";
+
+const GLSL_PRAGMA: &str = "precision highp float;\n\
                            precision highp int;
                            layout(std140) uniform;\n";
 
 fn patch_shader_src(src: &str) -> String {
-  let mut pragma = String::from(GLSL_PRAGMA);
-  pragma.push_str(src);
+  // sources declaring their own version keep it
+  let (version, body) = split_version_directive(src);
+  let mut pragma = String::from(if version.is_empty() {
+    GLSL_VERSION
+  } else {
+    version
+  });
+
+  if !pragma.ends_with('\n') {
+    pragma.push('\n');
+  }
+
+  pragma.push_str(GLSL_PRAGMA);
+
+  // number the lines as if there was no preamble, so that diagnostics refer to the lines of the source
+  let body_line = src[..src.len() - body.len()].matches('\n').count() + 1;
+  pragma.push_str(&format!("#line {}\n", body_line));
+
+  pragma.push_str(body);
   pragma
 }
 
@@ -346,41 +505,103 @@ fn uniform_type_match(
     state
       .ctx
       .get_uniform_indices(program, name_array.as_ref())
-      .ok_or_else(|| UniformWarning::TypeMismatch("cannot retrieve uniform index".to_owned(), ty))?
+      .ok_or_else(|| UniformWarning::type_mismatch(name, ty, None))?
       .get(0)
       .as_f64()
       .map(|x| x as u32)
-      .ok_or_else(|| {
-        UniformWarning::TypeMismatch("wrong type when retrieving uniform".to_owned(), ty)
-      })?
+      .ok_or_else(|| UniformWarning::type_mismatch(name, ty, None))?
   };
 
+  if index == WebGl2RenderingContext::INVALID_INDEX {
+    return Err(UniformWarning::inactive(name));
+  }
+
   // get its size and type
   let info = state
     .ctx
     .get_active_uniform(program, index)
-    .ok_or_else(|| UniformWarning::TypeMismatch("cannot retrieve active uniform".to_owned(), ty))?;
+    .ok_or_else(|| UniformWarning::type_mismatch(name, ty, None))?;
+
+  check_size_match(name, size, info.size() as usize)?;
+  check_types_match(name, ty, info.type_())
+}
 
-  let found_size = info.size() as usize;
-  if size > 0 && found_size != size {
-    return Err(UniformWarning::size_mismatch(name, size, found_size));
+/// Check that the requested size of a uniform fits in the declared size of the uniform in the shader.
+///
+/// A size of `0` is used by unsized uniforms (textures, shader data, etc.) and always matches. Arrays smaller than the
+/// declared array are accepted, as only the first items will be updated.
+fn check_size_match(name: &str, size: usize, found_size: usize) -> Result<(), UniformWarning> {
+  if size > found_size {
+    Err(UniformWarning::size_mismatch(name, size, found_size))
+  } else {
+    Ok(())
   }
+}
 
-  check_types_match(name, ty, info.type_())
+/// Call a macro with the list of supported uniform types, associated with their GLSL type constant.
+macro_rules! uniform_types {
+  ($m:ident) => {
+    $m!(
+      // scalars
+      (Int, INT),
+      (UInt, UNSIGNED_INT),
+      (Float, FLOAT),
+      (Bool, BOOL),
+      // vectors
+      (IVec2, INT_VEC2),
+      (IVec3, INT_VEC3),
+      (IVec4, INT_VEC4),
+      (UIVec2, UNSIGNED_INT_VEC2),
+      (UIVec3, UNSIGNED_INT_VEC3),
+      (UIVec4, UNSIGNED_INT_VEC4),
+      (Vec2, FLOAT_VEC2),
+      (Vec3, FLOAT_VEC3),
+      (Vec4, FLOAT_VEC4),
+      (BVec2, BOOL_VEC2),
+      (BVec3, BOOL_VEC3),
+      (BVec4, BOOL_VEC4),
+      // matrices
+      (M22, FLOAT_MAT2),
+      (M33, FLOAT_MAT3),
+      (M44, FLOAT_MAT4),
+      (M23, FLOAT_MAT2X3),
+      (M32, FLOAT_MAT3X2),
+      (M24, FLOAT_MAT2X4),
+      (M42, FLOAT_MAT4X2),
+      (M34, FLOAT_MAT3X4),
+      (M43, FLOAT_MAT4X3),
+      // textures
+      (ISampler2D, INT_SAMPLER_2D),
+      (ISampler3D, INT_SAMPLER_3D),
+      (ISampler2DArray, INT_SAMPLER_2D_ARRAY),
+      (UISampler2D, UNSIGNED_INT_SAMPLER_2D),
+      (UISampler3D, UNSIGNED_INT_SAMPLER_3D),
+      (UISampler2DArray, UNSIGNED_INT_SAMPLER_2D_ARRAY),
+      (Sampler2D, SAMPLER_2D),
+      (Sampler3D, SAMPLER_3D),
+      (Sampler2DArray, SAMPLER_2D_ARRAY),
+      (ICubemap, INT_SAMPLER_CUBE),
+      (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
+      (Cubemap, SAMPLER_CUBE),
+      (Sampler2DShadow, SAMPLER_2D_SHADOW),
+      (Sampler2DArrayShadow, SAMPLER_2D_ARRAY_SHADOW),
+      (CubemapShadow, SAMPLER_CUBE_SHADOW),
+    )
+  };
 }
 
 #[allow(clippy::cognitive_complexity)]
 fn check_types_match(name: &str, ty: UniformType, glty: u32) -> Result<(), UniformWarning> {
   // helper macro to check type mismatch for each variant
   macro_rules! milkcheck {
-    ($ty:expr, $( ( $v:tt, $t:tt ) ),* $(,)?) => {
-      match $ty {
+    ($( ( $v:tt, $t:tt ) ),* $(,)?) => {
+      match ty {
         $(
           UniformType::$v => {
             if glty == WebGl2RenderingContext::$t {
               Ok(())
             } else {
-              Err(UniformWarning::type_mismatch(name, ty))
+              Err(UniformWarning::type_mismatch(name, ty, glenum_to_uniform_type(glty)))
             }
           }
         )*
@@ -390,44 +611,56 @@ fn check_types_match(name: &str, ty: UniformType, glty: u32) -> Result<(), Unifo
     }
   }
 
-  milkcheck!(
-    ty,
-    // scalars
-    (Int, INT),
-    (UInt, UNSIGNED_INT),
-    (Float, FLOAT),
-    (Bool, BOOL),
-    // vectors
-    (IVec2, INT_VEC2),
-    (IVec3, INT_VEC3),
-    (IVec4, INT_VEC4),
-    (UIVec2, UNSIGNED_INT_VEC2),
-    (UIVec3, UNSIGNED_INT_VEC3),
-    (UIVec4, UNSIGNED_INT_VEC4),
-    (Vec2, FLOAT_VEC2),
-    (Vec3, FLOAT_VEC3),
-    (Vec4, FLOAT_VEC4),
-    (BVec2, BOOL_VEC2),
-    (BVec3, BOOL_VEC3),
-    (BVec4, BOOL_VEC4),
-    // matrices
-    (M22, FLOAT_MAT2),
-    (M33, FLOAT_MAT3),
-    (M44, FLOAT_MAT4),
-    // textures
-    (ISampler2D, INT_SAMPLER_2D),
-    (ISampler3D, INT_SAMPLER_3D),
-    (ISampler2DArray, INT_SAMPLER_2D_ARRAY),
-    (UISampler2D, UNSIGNED_INT_SAMPLER_2D),
-    (UISampler3D, UNSIGNED_INT_SAMPLER_3D),
-    (UISampler2DArray, UNSIGNED_INT_SAMPLER_2D_ARRAY),
-    (Sampler2D, SAMPLER_2D),
-    (Sampler3D, SAMPLER_3D),
-    (Sampler2DArray, SAMPLER_2D_ARRAY),
-    (ICubemap, INT_SAMPLER_CUBE),
-    (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
-    (Cubemap, SAMPLER_CUBE),
-  )
+  uniform_types!(milkcheck)
+}
+
+/// List the active vertex attributes of a program, built-in ones excluded.
+pub(crate) fn active_vertex_attribs(
+  ctx: &WebGl2RenderingContext,
+  program: &WebGlProgram,
+) -> Vec<ActiveVertexAttrib> {
+  let count = ctx
+    .get_program_parameter(program, WebGl2RenderingContext::ACTIVE_ATTRIBUTES)
+    .as_f64()
+    .unwrap_or(0.) as u32;
+
+  (0..count)
+    .filter_map(|index| {
+      let info = ctx.get_active_attrib(program, index)?;
+      let name = info.name();
+
+      // built-in attributes have no location
+      let location = ctx.get_attrib_location(program, &name);
+
+      if location < 0 {
+        return None;
+      }
+
+      Some(ActiveVertexAttrib {
+        name,
+        location: location as usize,
+        ty: glenum_to_uniform_type(info.type_()),
+        size: info.size() as usize,
+      })
+    })
+    .collect()
+}
+
+/// Get the [`UniformType`] of a GLSL type constant, if supported.
+fn glenum_to_uniform_type(glty: u32) -> Option<UniformType> {
+  macro_rules! reverse {
+    ($( ( $v:tt, $t:tt ) ),* $(,)?) => {
+      match glty {
+        $(
+          WebGl2RenderingContext::$t => Some(UniformType::$v),
+        )*
+
+        _ => None,
+      }
+    }
+  }
+
+  uniform_types!(reverse)
 }
 
 fn bind_vertex_attribs_locations<Sem>(
@@ -567,8 +800,12 @@ macro_rules! impl_Uniformable {
 
   // matrix notation
   (mat arr $q:ident $t:ty, $size:expr, $uty:tt, $f:tt) => {
-    unsafe impl<'a, const N: usize> Uniformable<'a, Arr<$q<$t>, N>> for WebGL2 {
-      type Target = &'a [$q<$t>; N];
+    impl_Uniformable!(mat arr [$q<$t>] $t, $size, $uty, $f, false);
+  };
+
+  (mat arr [$m:ty] $t:ty, $size:expr, $uty:tt, $f:tt, $transpose:expr) => {
+    unsafe impl<'a, const N: usize> Uniformable<'a, Arr<$m, N>> for WebGL2 {
+      type Target = &'a [$m; N];
 
       const SIZE: usize = N;
 
@@ -576,16 +813,12 @@ macro_rules! impl_Uniformable {
         UniformType::$uty
       }
 
-      unsafe fn update(
-        program: &mut Program,
-        uniform: &'a Uniform<Arr<$q<$t>, N>>,
-        value: Self::Target,
-      ) {
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<Arr<$m, N>>, value: Self::Target) {
         let data = flatten_slice!(value: $t, len = $size * N);
 
         program.state.borrow().ctx.$f(
           program.location_map.borrow().get(&uniform.index()),
-          false,
+          $transpose,
           data,
           0,
           value.len() as u32,
@@ -595,8 +828,12 @@ macro_rules! impl_Uniformable {
   };
 
   (mat $q:ident $t:ty, $size:expr, $uty:tt, $f:tt) => {
-    unsafe impl<'a> Uniformable<'a, $q<$t>> for WebGL2 {
-      type Target = $q<$t>;
+    impl_Uniformable!(mat [$q<$t>] $t, $size, $uty, $f, false);
+  };
+
+  (mat [$m:ty] $t:ty, $size:expr, $uty:tt, $f:tt, $transpose:expr) => {
+    unsafe impl<'a> Uniformable<'a, $m> for WebGL2 {
+      type Target = $m;
 
       const SIZE: usize = 1;
 
@@ -604,12 +841,12 @@ macro_rules! impl_Uniformable {
         UniformType::$uty
       }
 
-      unsafe fn update(program: &mut Program, uniform: &'a Uniform<$q<$t>>, value: Self::Target) {
+      unsafe fn update(program: &mut Program, uniform: &'a Uniform<$m>, value: Self::Target) {
         let data = flatten_slice!(value: $t, len = $size);
 
         program.state.borrow().ctx.$f(
           program.location_map.borrow().get(&uniform.index()),
-          false,
+          $transpose,
           data,
         );
       }
@@ -700,6 +937,52 @@ impl_Uniformable!(mat arr Mat33 f32, 9, M33, uniform_matrix3fv_with_f32_array_an
 impl_Uniformable!(mat Mat44 f32, 16, M44, uniform_matrix4fv_with_f32_array);
 impl_Uniformable!(mat arr Mat44 f32, 16, M44, uniform_matrix4fv_with_f32_array_and_src_offset_and_src_length);
 
+impl_Uniformable!(mat Mat23 f32, 6, M23, uniform_matrix2x3fv_with_f32_array);
+impl_Uniformable!(mat arr Mat23 f32, 6, M23, uniform_matrix2x3fv_with_f32_array_and_src_offset_and_src_length);
+
+impl_Uniformable!(mat Mat32 f32, 6, M32, uniform_matrix3x2fv_with_f32_array);
+impl_Uniformable!(mat arr Mat32 f32, 6, M32, uniform_matrix3x2fv_with_f32_array_and_src_offset_and_src_length);
+
+impl_Uniformable!(mat Mat24 f32, 8, M24, uniform_matrix2x4fv_with_f32_array);
+impl_Uniformable!(mat arr Mat24 f32, 8, M24, uniform_matrix2x4fv_with_f32_array_and_src_offset_and_src_length);
+
+impl_Uniformable!(mat Mat42 f32, 8, M42, uniform_matrix4x2fv_with_f32_array);
+impl_Uniformable!(mat arr Mat42 f32, 8, M42, uniform_matrix4x2fv_with_f32_array_and_src_offset_and_src_length);
+
+impl_Uniformable!(mat Mat34 f32, 12, M34, uniform_matrix3x4fv_with_f32_array);
+impl_Uniformable!(mat arr Mat34 f32, 12, M34, uniform_matrix3x4fv_with_f32_array_and_src_offset_and_src_length);
+
+impl_Uniformable!(mat Mat43 f32, 12, M43, uniform_matrix4x3fv_with_f32_array);
+impl_Uniformable!(mat arr Mat43 f32, 12, M43, uniform_matrix4x3fv_with_f32_array_and_src_offset_and_src_length);
+
+// transposed matrices are uploaded as the transpose of the wrapped matrix
+impl_Uniformable!(mat [Transposed<Mat22<f32>>] f32, 4, M22, uniform_matrix2fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat22<f32>>] f32, 4, M22, uniform_matrix2fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat33<f32>>] f32, 9, M33, uniform_matrix3fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat33<f32>>] f32, 9, M33, uniform_matrix3fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat44<f32>>] f32, 16, M44, uniform_matrix4fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat44<f32>>] f32, 16, M44, uniform_matrix4fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat32<f32>>] f32, 6, M23, uniform_matrix2x3fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat32<f32>>] f32, 6, M23, uniform_matrix2x3fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat23<f32>>] f32, 6, M32, uniform_matrix3x2fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat23<f32>>] f32, 6, M32, uniform_matrix3x2fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat42<f32>>] f32, 8, M24, uniform_matrix2x4fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat42<f32>>] f32, 8, M24, uniform_matrix2x4fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat24<f32>>] f32, 8, M42, uniform_matrix4x2fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat24<f32>>] f32, 8, M42, uniform_matrix4x2fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat43<f32>>] f32, 12, M34, uniform_matrix3x4fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat43<f32>>] f32, 12, M34, uniform_matrix3x4fv_with_f32_array_and_src_offset_and_src_length, true);
+
+impl_Uniformable!(mat [Transposed<Mat34<f32>>] f32, 12, M43, uniform_matrix4x3fv_with_f32_array, true);
+impl_Uniformable!(mat arr [Transposed<Mat34<f32>>] f32, 12, M43, uniform_matrix4x3fv_with_f32_array_and_src_offset_and_src_length, true);
+
 // Special exception for booleans: because we cannot simply send the bool Rust type down to the
 // GPU, we have to convert them to 32-bit integer (unsigned), which is a total fuck up and waste of
 // memory bandwidth, but well, WebGL / OpenGL, whatcha wanna do. Also, for slice versions… we have
@@ -977,6 +1260,38 @@ where
   }
 }
 
+unsafe impl<'a, D> Uniformable<'a, ShadowBinding<D>> for WebGL2
+where
+  D: 'a + Dimensionable,
+{
+  type Target = ShadowBinding<D>;
+
+  const SIZE: usize = 0;
+
+  unsafe fn ty() -> UniformType {
+    // there is no shadow sampler for 3D textures, so this will fail the type check
+    match D::dim() {
+      Dim::Dim1 => UniformType::Sampler1DShadow,
+      Dim::Dim2 => UniformType::Sampler2DShadow,
+      Dim::Dim3 => UniformType::Sampler3D,
+      Dim::Cubemap => UniformType::CubemapShadow,
+      Dim::Dim1Array => UniformType::Sampler1DArrayShadow,
+      Dim::Dim2Array => UniformType::Sampler2DArrayShadow,
+    }
+  }
+
+  unsafe fn update(
+    program: &mut Program,
+    uniform: &'a Uniform<ShadowBinding<D>>,
+    value: Self::Target,
+  ) {
+    program.state.borrow().ctx.uniform1i(
+      program.location_map.borrow().get(&uniform.index()),
+      value.binding() as i32,
+    );
+  }
+}
+
 unsafe impl<T> ShaderData<T> for WebGL2
 where
   T: Std140,
@@ -1,13 +1,15 @@
 //! Graphics state.
 
-use js_sys::{Float32Array, Int32Array, Uint32Array};
+use js_sys::{Array, Float32Array, Function, Int32Array, Reflect, Uint32Array};
 use luminance::{
   blending::{Equation, Factor},
+  color_mask::ColorMask,
   depth_stencil::{Comparison, StencilOp, StencilOperations, StencilTest, Write},
   face_culling::{FaceCullingMode, FaceCullingOrder},
   scissor::ScissorRegion,
 };
 use std::{fmt, marker::PhantomData};
+use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{
   WebGl2RenderingContext, WebGlBuffer, WebGlFramebuffer, WebGlProgram, WebGlTexture,
   WebGlVertexArrayObject,
@@ -74,6 +76,9 @@ pub struct WebGL2State {
   // depth write
   depth_write: Write,
 
+  // color masks
+  color_masks: (ColorMask, Vec<(usize, ColorMask)>),
+
   // face culling
   face_culling_state: FaceCullingState,
   face_culling_order: FaceCullingOrder,
@@ -134,6 +139,9 @@ pub struct WebGL2State {
 
   /// Maximum number of elements a texture array can hold.
   max_texture_array_elements: Option<usize>,
+
+  // maximum anisotropy; cached when asked the first time anisotropic filtering is supported and then re-used
+  max_anisotropy: Option<f32>,
 }
 
 impl WebGL2State {
@@ -161,6 +169,7 @@ impl WebGL2State {
     let depth_test_enabled = get_ctx_depth_test_enabled(&mut ctx);
     let depth_test_comparison = Comparison::Less;
     let depth_write = get_ctx_depth_write(&mut ctx)?;
+    let color_masks = (ColorMask::ALL, Vec::new());
     let stencil_test_enabled = get_ctx_stencil_test_enabled(&mut ctx);
     let stencil_test = get_ctx_stencil_test(&mut ctx)?;
     let stencil_operations = get_ctx_stencil_operations(&mut ctx)?;
@@ -188,6 +197,7 @@ impl WebGL2State {
     let gl_version = None;
     let glsl_version = None;
     let max_texture_array_elements = None;
+    let max_anisotropy = None;
 
     Ok(WebGL2State {
       _phantom: PhantomData,
@@ -203,6 +213,7 @@ impl WebGL2State {
       depth_test_enabled,
       depth_test_comparison,
       depth_write,
+      color_masks,
       stencil_test_enabled,
       stencil_test,
       stencil_operations,
@@ -228,6 +239,7 @@ impl WebGL2State {
       webgl_version: gl_version,
       glsl_version,
       max_texture_array_elements,
+      max_anisotropy,
     })
   }
 
@@ -411,6 +423,10 @@ impl WebGL2State {
     }
   }
 
+  pub(crate) fn current_program(&self) -> Option<&WebGlProgram> {
+    self.current_program.as_ref()
+  }
+
   pub(crate) fn use_program(&mut self, handle: Option<&WebGlProgram>) {
     if self.current_program.as_ref() != handle {
       self.ctx.use_program(handle);
@@ -575,6 +591,58 @@ impl WebGL2State {
     }
   }
 
+  /// Set the color mask of all color attachments, then the ones of specific color attachments.
+  ///
+  /// The color masks of specific color attachments require the `OES_draw_buffers_indexed` extension and are ignored if
+  /// it is not supported.
+  pub(crate) fn set_color_masks(
+    &mut self,
+    color_mask: ColorMask,
+    attachment_masks: &[(usize, ColorMask)],
+  ) {
+    if self.color_masks.0 == color_mask && self.color_masks.1 == attachment_masks {
+      return;
+    }
+
+    self.ctx.color_mask(
+      color_mask.red,
+      color_mask.green,
+      color_mask.blue,
+      color_mask.alpha,
+    );
+
+    if !attachment_masks.is_empty() {
+      // web-sys has no binding for OES_draw_buffers_indexed, so its colorMaskiOES function is called dynamically
+      let extension = self
+        .ctx
+        .get_extension("OES_draw_buffers_indexed")
+        .ok()
+        .flatten();
+      let color_mask_i = extension.as_ref().and_then(|extension| {
+        Reflect::get(extension, &JsValue::from_str("colorMaskiOES"))
+          .ok()?
+          .dyn_into::<Function>()
+          .ok()
+      });
+
+      if let (Some(extension), Some(color_mask_i)) = (extension, color_mask_i) {
+        for &(index, mask) in attachment_masks {
+          let args = Array::of5(
+            &JsValue::from(index as u32),
+            &JsValue::from(mask.red),
+            &JsValue::from(mask.green),
+            &JsValue::from(mask.blue),
+            &JsValue::from(mask.alpha),
+          );
+
+          let _ = color_mask_i.apply(&extension, &args);
+        }
+      }
+    }
+
+    self.color_masks = (color_mask, attachment_masks.to_vec());
+  }
+
   pub(crate) fn enable_stencil_test(&mut self, enabled: bool) {
     if self.stencil_test_enabled != enabled {
       if enabled {
@@ -715,6 +783,42 @@ impl WebGL2State {
       max
     })
   }
+
+  /// Get the maximum anisotropy of anisotropic filtering, if supported.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls.
+  pub(crate) fn get_max_anisotropy(&mut self) -> Option<f32> {
+    if self.max_anisotropy.is_none() && self.has_extension("EXT_texture_filter_anisotropic") {
+      // MAX_TEXTURE_MAX_ANISOTROPY_EXT
+      self.max_anisotropy = self.ctx.get_webgl_param(0x84FF);
+    }
+
+    self.max_anisotropy
+  }
+
+  /// Get the maximum number of samples of multisampled storage.
+  pub(crate) fn get_max_samples(&mut self) -> Option<usize> {
+    self
+      .ctx
+      .get_webgl_param(WebGl2RenderingContext::MAX_SAMPLES)
+  }
+
+  /// Get the maximum number of color attachments a framebuffer can draw to.
+  pub(crate) fn get_max_color_attachments(&mut self) -> Option<usize> {
+    let max_attachments: Option<usize> = self
+      .ctx
+      .get_webgl_param(WebGl2RenderingContext::MAX_COLOR_ATTACHMENTS);
+    let max_draw_buffers: Option<usize> = self
+      .ctx
+      .get_webgl_param(WebGl2RenderingContext::MAX_DRAW_BUFFERS);
+
+    max_attachments.zip(max_draw_buffers).map(|(a, b)| a.min(b))
+  }
+
+  /// Check whether an extension is supported, enabling it if so.
+  pub(crate) fn has_extension(&mut self, name: &str) -> bool {
+    matches!(self.ctx.get_extension(name), Ok(Some(_)))
+  }
 }
 
 impl Drop for WebGL2State {
@@ -2,7 +2,7 @@
 
 use luminance::backend::tess::{
   IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-  VertexSlice as VertexSliceBackend,
+  VertexSlice as VertexSliceBackend, VertexUpload as VertexUploadBackend,
 };
 use luminance::tess::{
   Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
@@ -10,7 +10,7 @@ use luminance::tess::{
 };
 use luminance::vertex::{
   Deinterleave, Normalized, Vertex, VertexAttribDesc, VertexAttribDim, VertexAttribType,
-  VertexBufferDesc, VertexInstancing,
+  VertexBufferDesc,
 };
 use std::cell::RefCell;
 use std::marker::PhantomData;
@@ -29,7 +29,7 @@ where
   vao: WebGlVertexArrayObject,
   mode: u32,
   // A small note: WebGL2 doesn’t support custom primitive restart index; it assumes the maximum
-  // value of I as being that restart index.
+  // value of I as being that restart index (I::FIXED_RESTART_INDEX).
   index_buffer: Option<Buffer<I, { WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER }>>,
   state: Rc<RefCell<WebGL2State>>,
 }
@@ -224,6 +224,26 @@ where
   }
 }
 
+unsafe impl<V, I, W> VertexUploadBackend<V, I, W, Interleaved, V> for WebGL2
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn upload_vertices(
+    tess: &mut Self::TessRepr,
+    offset: usize,
+    vertices: &[V],
+  ) -> Result<(), TessError> {
+    match tess.vertex_buffer {
+      Some(ref mut vb) => vb.write(offset, vertices),
+      None => Err(TessError::attributeless_error(
+        "cannot upload vertices to an attributeless tessellation",
+      )),
+    }
+  }
+}
+
 unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Interleaved> for WebGL2
 where
   V: TessVertexData<Interleaved, Data = Vec<V>>,
@@ -409,6 +429,27 @@ where
   }
 }
 
+unsafe impl<V, I, W, T> VertexUploadBackend<V, I, W, Deinterleaved, T> for WebGL2
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>> + Deinterleave<T>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  T: Copy,
+{
+  unsafe fn upload_vertices(
+    tess: &mut Self::TessRepr,
+    offset: usize,
+    vertices: &[T],
+  ) -> Result<(), TessError> {
+    match tess.vertex_buffers.get_mut(V::RANK) {
+      Some(vb) => vb.write(offset, vertices),
+      None => Err(TessError::attributeless_error(
+        "cannot upload vertices to an attributeless tessellation",
+      )),
+    }
+  }
+}
+
 unsafe impl<'a, V, I, W> IndexSliceBackend<'a, V, I, W, Deinterleaved> for WebGL2
 where
   V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
@@ -676,11 +717,7 @@ fn set_component_format(
   }
 
   // set vertex attribute divisor based on the vertex instancing configuration
-  let divisor = match desc.instancing {
-    VertexInstancing::On => 1,
-    VertexInstancing::Off => 0,
-  };
-  ctx.vertex_attrib_divisor(index, divisor);
+  ctx.vertex_attrib_divisor(index, desc.instancing.divisor());
 
   ctx.enable_vertex_attrib_array(index);
 }
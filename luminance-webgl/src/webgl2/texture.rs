@@ -1,12 +1,12 @@
 use crate::webgl2::{
   array_buffer::IntoArrayBuffer,
-  pixel::webgl_pixel_format,
+  pixel::{webgl_compressed_format, webgl_pixel_format},
   state::{comparison_to_glenum, WebGL2State},
   WebGL2,
 };
 use luminance::{
-  backend::texture::{Texture as TextureBackend, TextureBase},
-  pixel::{Pixel, PixelFormat},
+  backend::texture::{Texture as TextureBackend, TextureBase, TextureCompressed},
+  pixel::{CompressedPixel, Pixel, PixelFormat},
   texture::{Dim, Dimensionable, MagFilter, MinFilter, Sampler, TexelUpload, TextureError, Wrap},
 };
 use std::{cell::RefCell, mem, rc::Rc, slice};
@@ -114,83 +114,80 @@ where
     <Self as TextureBackend<D, P>>::upload_part_raw(texture, D::ZERO_OFFSET, size, texels)
   }
 
-  unsafe fn get_raw_texels(
-    texture: &Self::TextureRepr,
+  unsafe fn upload_part_level(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    offset: D::Offset,
     size: D::Size,
-  ) -> Result<Vec<P::RawEncoding>, TextureError>
-  where
-    P::RawEncoding: Copy + Default,
-  {
-    let pf = P::pixel_format();
-    let (format, _, ty) = webgl_pixel_format(pf).ok_or(TextureError::UnsupportedPixelFormat(pf))?;
-
+    texels: &[P::Encoding],
+  ) -> Result<(), TextureError> {
     let mut gfx_state = texture.state.borrow_mut();
+
     gfx_state.bind_texture(texture.target, Some(&texture.handle));
 
-    // Retrieve the size of the texture (w and h); WebGL2 doesn’t support the
-    // glGetTexLevelParameteriv function (I know it’s fucking surprising), so we have to implement
-    // a workaround and store that value on the CPU side.
-    let w = D::width(size);
-    let h = D::height(size);
-
-    // set the packing alignment based on the number of bytes to skip
-    let skip_bytes = (pf.format.bytes_len() * w as usize) % 8;
-    set_pack_alignment(&mut gfx_state, skip_bytes);
-
-    // We need a workaround to get the texel data, because WebGL2 doesn’t support the glGetTexImage
-    // function. The idea is that we are using a special read framebuffer that is always around and
-    // on which we can attach the texture we want to read the texels from.
-    match gfx_state.create_or_get_readback_framebuffer() {
-      Some(ref readback_fb) => {
-        // Resize the vec to allocate enough space to host the returned texels.
-        let texels_nb = (w * h) as usize * pf.channels_len();
-        let mut texels = vec![Default::default(); texels_nb];
-
-        // Attach the texture so that we can read from the framebuffer; careful here, since we are
-        // reading from a 2D texture while the attached texture might not be compatible.
-        gfx_state.bind_read_framebuffer(Some(readback_fb));
-        gfx_state.ctx.framebuffer_texture_2d(
-          WebGl2RenderingContext::READ_FRAMEBUFFER,
-          WebGl2RenderingContext::COLOR_ATTACHMENT0,
-          texture.target,
-          Some(&texture.handle),
-          0,
-        );
+    upload_level_texels::<D, P, P::Encoding>(
+      &mut gfx_state,
+      texture.target,
+      level,
+      offset,
+      size,
+      texels,
+    )
+  }
 
-        // Read from the framebuffer.
-        gfx_state
-          .ctx
-          .read_pixels_with_u8_array_and_dst_offset(
-            0,
-            0,
-            w as i32,
-            h as i32,
-            format,
-            ty,
-            slice::from_raw_parts_mut(
-              texels.as_mut_ptr() as *mut u8,
-              texels_nb * mem::size_of::<P::RawEncoding>(),
-            ),
-            0,
-          )
-          .map_err(|e| TextureError::CannotRetrieveTexels(format!("{:?}", e)))?;
+  unsafe fn upload_part_level_raw(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+    texels: &[P::RawEncoding],
+  ) -> Result<(), TextureError> {
+    let mut gfx_state = texture.state.borrow_mut();
 
-        // Detach the texture from the framebuffer.
-        gfx_state.ctx.framebuffer_texture_2d(
-          WebGl2RenderingContext::READ_FRAMEBUFFER,
-          WebGl2RenderingContext::COLOR_ATTACHMENT0,
-          texture.target,
-          None,
-          0,
-        );
+    gfx_state.bind_texture(texture.target, Some(&texture.handle));
 
-        Ok(texels)
-      }
+    upload_level_texels::<D, P, P::RawEncoding>(
+      &mut gfx_state,
+      texture.target,
+      level,
+      offset,
+      size,
+      texels,
+    )
+  }
 
-      None => Err(TextureError::cannot_retrieve_texels(
-        "unavailable readback framebuffer",
-      )),
+  unsafe fn generate_mipmaps(texture: &mut Self::TextureRepr) -> Result<(), TextureError> {
+    if texture.mipmaps == 0 {
+      return Ok(());
     }
+
+    let mut gfx_state = texture.state.borrow_mut();
+
+    gfx_state.bind_texture(texture.target, Some(&texture.handle));
+    gfx_state.ctx.generate_mipmap(texture.target);
+
+    Ok(())
+  }
+
+  unsafe fn get_texels(
+    texture: &Self::TextureRepr,
+    size: D::Size,
+  ) -> Result<Vec<P::Encoding>, TextureError>
+  where
+    P::Encoding: Copy + Default,
+  {
+    read_texels::<D, _>(texture, size, P::pixel_format(), 1)
+  }
+
+  unsafe fn get_raw_texels(
+    texture: &Self::TextureRepr,
+    size: D::Size,
+  ) -> Result<Vec<P::RawEncoding>, TextureError>
+  where
+    P::RawEncoding: Copy + Default,
+  {
+    let pf = P::pixel_format();
+    read_texels::<D, _>(texture, size, pf, pf.channels_len())
   }
 
   unsafe fn resize(
@@ -220,6 +217,84 @@ where
   }
 }
 
+unsafe impl<P> TextureCompressed<P> for WebGL2
+where
+  P: CompressedPixel,
+{
+  unsafe fn new_compressed_texture(
+    &mut self,
+    size: [u32; 2],
+    sampler: Sampler,
+    levels: &[&[u8]],
+  ) -> Result<Self::TextureRepr, TextureError> {
+    let mut state = self.state.borrow_mut();
+    let (iformat, extension) = webgl_compressed_format(P::compressed_format());
+
+    // compressed formats are only usable once their extension is enabled
+    if !state.has_extension(extension) {
+      return Err(TextureError::unsupported_pixel_format(P::pixel_format()));
+    }
+
+    let mipmaps = levels.len() - 1;
+    let target = WebGl2RenderingContext::TEXTURE_2D;
+
+    let handle = state.create_texture().ok_or_else(|| {
+      TextureError::TextureStorageCreationFailed("cannot create texture".to_owned())
+    })?;
+    state.bind_texture(target, Some(&handle));
+
+    set_texture_levels(&mut state, target, mipmaps);
+    apply_sampler_to_texture(&mut state, target, sampler);
+
+    for (level, data) in levels.iter().enumerate() {
+      state.ctx.compressed_tex_image_2d_with_u8_array(
+        target,
+        level as i32,
+        iformat,
+        (size[0] >> level).max(1) as i32,
+        (size[1] >> level).max(1) as i32,
+        0,
+        data,
+      );
+    }
+
+    let texture = Texture {
+      handle,
+      target,
+      mipmaps,
+      state: self.state.clone(),
+    };
+
+    Ok(texture)
+  }
+
+  unsafe fn upload_compressed_part(
+    texture: &mut Self::TextureRepr,
+    offset: [u32; 2],
+    size: [u32; 2],
+    data: &[u8],
+  ) -> Result<(), TextureError> {
+    let mut gfx_state = texture.state.borrow_mut();
+    let (iformat, _) = webgl_compressed_format(P::compressed_format());
+
+    gfx_state.bind_texture(texture.target, Some(&texture.handle));
+
+    // the binding takes the data mutably, even though it only reads it
+    gfx_state.ctx.compressed_tex_sub_image_2d_with_u8_array(
+      texture.target,
+      0,
+      offset[0] as i32,
+      offset[1] as i32,
+      size[0] as i32,
+      size[1] as i32,
+      iformat,
+      &mut data.to_vec(),
+    );
+
+    Ok(())
+  }
+}
+
 pub(crate) fn opengl_target(d: Dim) -> Option<u32> {
   match d {
     Dim::Dim2 => Some(WebGl2RenderingContext::TEXTURE_2D),
@@ -354,6 +429,14 @@ fn apply_sampler_to_texture(state: &mut WebGL2State, target: u32, sampler: Sampl
       );
     }
   }
+
+  // anisotropic filtering is ignored if not supported
+  if let (Some(anisotropy), Some(max)) = (sampler.max_anisotropy, state.get_max_anisotropy()) {
+    // TEXTURE_MAX_ANISOTROPY_EXT
+    state
+      .ctx
+      .tex_parameterf(target, 0x84FE, anisotropy.clamp(1., max));
+  }
 }
 
 fn webgl_wrap(wrap: Wrap) -> u32 {
@@ -361,6 +444,8 @@ fn webgl_wrap(wrap: Wrap) -> u32 {
     Wrap::ClampToEdge => WebGl2RenderingContext::CLAMP_TO_EDGE,
     Wrap::Repeat => WebGl2RenderingContext::REPEAT,
     Wrap::MirroredRepeat => WebGl2RenderingContext::MIRRORED_REPEAT,
+    // WebGL has no border color
+    Wrap::ClampToBorder => WebGl2RenderingContext::CLAMP_TO_EDGE,
   }
 }
 
@@ -514,7 +599,7 @@ fn set_unpack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
 }
 
 // set the pack alignment for downloading aligned texels
-fn set_pack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
+pub(crate) fn set_pack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
   let pack_alignment = match skip_bytes {
     0 => 8,
     2 => 2,
@@ -584,6 +669,144 @@ where
   Ok(())
 }
 
+// Read the texels of the base level of a texture, with `per_pixel` values of type `T` per pixel.
+unsafe fn read_texels<D, T>(
+  texture: &Texture,
+  size: D::Size,
+  pf: PixelFormat,
+  per_pixel: usize,
+) -> Result<Vec<T>, TextureError>
+where
+  D: Dimensionable,
+  T: Copy + Default,
+{
+  let (format, _, ty) = webgl_pixel_format(pf).ok_or(TextureError::UnsupportedPixelFormat(pf))?;
+
+  // Retrieve the size of the texture (w and h); WebGL2 doesn’t support the
+  // glGetTexLevelParameteriv function (I know it’s fucking surprising), so we have to implement
+  // a workaround and store that value on the CPU side.
+  let w = D::width(size);
+  let h = D::height(size);
+  let layer_len = (w * h) as usize * per_pixel;
+  let layers = D::depth(size) as usize;
+
+  if layer_len == 0 {
+    return Ok(Vec::new());
+  }
+
+  let mut gfx_state = texture.state.borrow_mut();
+
+  // set the packing alignment based on the number of bytes to skip, so that rows are tightly packed
+  let skip_bytes = (w as usize * per_pixel * mem::size_of::<T>()) % 8;
+  set_pack_alignment(&mut gfx_state, skip_bytes);
+
+  // We need a workaround to get the texel data, because WebGL2 doesn’t support the glGetTexImage
+  // function. The idea is that we are using a special read framebuffer that is always around and
+  // on which we can attach the texture we want to read the texels from, layer by layer.
+  match gfx_state.create_or_get_readback_framebuffer() {
+    Some(ref readback_fb) => {
+      // Resize the vec to allocate enough space to host the returned texels.
+      let mut texels = vec![T::default(); layer_len * layers];
+
+      gfx_state.bind_read_framebuffer(Some(readback_fb));
+
+      for (layer, layer_texels) in texels.chunks_exact_mut(layer_len).enumerate() {
+        // Attach the layer so that we can read from the framebuffer.
+        match texture.target {
+          WebGl2RenderingContext::TEXTURE_CUBE_MAP => gfx_state.ctx.framebuffer_texture_2d(
+            WebGl2RenderingContext::READ_FRAMEBUFFER,
+            WebGl2RenderingContext::COLOR_ATTACHMENT0,
+            WebGl2RenderingContext::TEXTURE_CUBE_MAP_POSITIVE_X + layer as u32,
+            Some(&texture.handle),
+            0,
+          ),
+
+          WebGl2RenderingContext::TEXTURE_3D | WebGl2RenderingContext::TEXTURE_2D_ARRAY => {
+            gfx_state.ctx.framebuffer_texture_layer(
+              WebGl2RenderingContext::READ_FRAMEBUFFER,
+              WebGl2RenderingContext::COLOR_ATTACHMENT0,
+              Some(&texture.handle),
+              0,
+              layer as i32,
+            )
+          }
+
+          target => gfx_state.ctx.framebuffer_texture_2d(
+            WebGl2RenderingContext::READ_FRAMEBUFFER,
+            WebGl2RenderingContext::COLOR_ATTACHMENT0,
+            target,
+            Some(&texture.handle),
+            0,
+          ),
+        }
+
+        // Read from the framebuffer.
+        gfx_state
+          .ctx
+          .read_pixels_with_u8_array_and_dst_offset(
+            0,
+            0,
+            w as i32,
+            h as i32,
+            format,
+            ty,
+            slice::from_raw_parts_mut(
+              layer_texels.as_mut_ptr() as *mut u8,
+              mem::size_of_val(layer_texels),
+            ),
+            0,
+          )
+          .map_err(|e| TextureError::CannotRetrieveTexels(format!("{:?}", e)))?;
+      }
+
+      // Detach the texture from the framebuffer.
+      gfx_state.ctx.framebuffer_texture_2d(
+        WebGl2RenderingContext::READ_FRAMEBUFFER,
+        WebGl2RenderingContext::COLOR_ATTACHMENT0,
+        WebGl2RenderingContext::TEXTURE_2D,
+        None,
+        0,
+      );
+
+      Ok(texels)
+    }
+
+    None => Err(TextureError::cannot_retrieve_texels(
+      "unavailable readback framebuffer",
+    )),
+  }
+}
+
+// Upload texels to a single mipmap level of the bound texture.
+fn upload_level_texels<D, P, T>(
+  state: &mut WebGL2State,
+  target: u32,
+  level: usize,
+  off: D::Offset,
+  size: D::Size,
+  texels: &[T],
+) -> Result<(), TextureError>
+where
+  D: Dimensionable,
+  P: Pixel,
+  T: IntoArrayBuffer,
+{
+  let pf = P::pixel_format();
+  let pf_size = pf.format.bytes_len();
+  let expected_bytes = D::count(size) * pf_size;
+  let input_bytes = mem::size_of_val(texels);
+
+  if input_bytes < expected_bytes {
+    // potential segfault / overflow; abort
+    return Err(TextureError::not_enough_pixels(expected_bytes, input_bytes));
+  }
+
+  let skip_bytes = (D::width(size) as usize * pf_size) % 8;
+  set_unpack_alignment(state, skip_bytes);
+
+  set_texels::<D, _>(state, target, pf, level as i32, size, off, texels)
+}
+
 // Set texels for a texture.
 fn set_texels<D, T>(
   state: &mut WebGL2State,
@@ -88,7 +88,9 @@
 pub mod color_slot;
 pub mod depth_stencil_slot;
 pub mod framebuffer;
+pub mod indirect;
 pub mod pipeline;
+pub mod pixel_buffer;
 pub mod query;
 pub mod render_gate;
 pub mod shader;
@@ -96,3 +98,4 @@ pub mod shading_gate;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
+pub mod transform_feedback;
@@ -8,10 +8,13 @@
 //! types from this module to use as constraint.
 
 use crate::{
-  backend::{framebuffer::Framebuffer, texture::Texture as TextureBackend},
+  backend::{
+    framebuffer::{Framebuffer, FramebufferRenderbuffer},
+    texture::Texture as TextureBackend,
+  },
   context::GraphicsContext,
-  framebuffer::FramebufferError,
-  texture::{TexelUpload, Texture},
+  framebuffer::{FramebufferError, Renderbuffer},
+  texture::{Dim2, TexelUpload, Texture},
 };
 use crate::{
   pixel::{ColorPixel, PixelFormat, RenderablePixel},
@@ -24,12 +27,19 @@ use crate::{
 /// compile-time to ensure type safety. Even though this trait lives on the backend side of luminance, no backend is
 /// supposed to implement it, but instead use it.
 ///
-/// Three types of color slots exist:
+/// Five types of color slots exist:
 ///
 /// - None, represented by the `()` implementor.
 /// - A single color [`Texture`]. This type of color slot is often suitable for renderable framebuffer.
 /// - A tuple of different color [`Texture`]. This situation is mostly used for _multi render target_, allowing to
 ///   render (via a fragment shader) into different part of the color slot.
+/// - An array of color [`Texture`] of the same pixel format, such as `[RGBA32F; 4]`. This is the same as a tuple, but
+///   is more convenient when all the textures have the same pixel format, as they can be iterated over.
+/// - A [`Renderbuffer`], which cannot be sampled and gives no texture, if the backend implements
+///   [`FramebufferRenderbuffer`]. It can be part of a tuple.
+///
+/// Each pixel format of a tuple or array is attached to the color attachment of the same index, and the fragment shader
+/// output at the same location renders into it.
 ///
 /// For color slots that have color textures, the pixel type must be a [`RenderablePixel`] as well as a [`ColorPixel`].
 ///
@@ -65,6 +75,16 @@ where
   ) -> Result<Self::ColorTextures, FramebufferError>
   where
     C: GraphicsContext<Backend = B>;
+
+  /// Resize the textures of the color slot and attach them again.
+  ///
+  /// Renderbuffers are resized by the backend, so only textures must be resized.
+  fn resize_color_textures(
+    textures: &mut Self::ColorTextures,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError>;
 }
 
 impl<B, D> ColorSlot<B, D> for ()
@@ -92,6 +112,15 @@ where
   {
     Ok(())
   }
+
+  fn resize_color_textures(
+    _: &mut Self::ColorTextures,
+    _: D::Size,
+    _: &mut B::FramebufferRepr,
+    _: usize,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
 }
 
 impl<B, D, P> ColorSlot<B, D> for P
@@ -124,62 +153,151 @@ where
 
     Ok(texture)
   }
+
+  fn resize_color_textures(
+    texture: &mut Self::ColorTextures,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    let mipmaps = texture.mipmaps();
+    texture.resize(size, TexelUpload::reserve(mipmaps))?;
+
+    unsafe { B::attach_color_texture(framebuffer, &texture.repr, attachment_index) }
+  }
+}
+
+impl<B, D, P, const N: usize> ColorSlot<B, D> for [P; N]
+where
+  B: ?Sized + Framebuffer<D> + TextureBackend<D, P>,
+  D: Dimensionable,
+  D::Size: Copy,
+  P: ColorPixel + RenderablePixel,
+{
+  type ColorTextures = [Texture<B, D, P>; N];
+
+  fn color_formats() -> Vec<PixelFormat> {
+    vec![P::pixel_format(); N]
+  }
+
+  fn reify_color_textures<C>(
+    ctx: &mut C,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: &Sampler,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<Self::ColorTextures, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let textures = (0..N)
+      .map(|i| {
+        <P as ColorSlot<B, D>>::reify_color_textures(
+          ctx,
+          size,
+          mipmaps,
+          sampler,
+          framebuffer,
+          attachment_index + i,
+        )
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    match textures.try_into() {
+      Ok(textures) => Ok(textures),
+      Err(_) => unreachable!("exactly {} color textures were created", N),
+    }
+  }
+
+  fn resize_color_textures(
+    textures: &mut Self::ColorTextures,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    for (i, texture) in textures.iter_mut().enumerate() {
+      <P as ColorSlot<B, D>>::resize_color_textures(
+        texture,
+        size,
+        framebuffer,
+        attachment_index + i,
+      )?;
+    }
+
+    Ok(())
+  }
+}
+
+impl<B, P> ColorSlot<B, Dim2> for Renderbuffer<P>
+where
+  B: ?Sized + FramebufferRenderbuffer,
+  P: ColorPixel + RenderablePixel,
+{
+  type ColorTextures = ();
+
+  fn color_formats() -> Vec<PixelFormat> {
+    vec![P::pixel_format()]
+  }
+
+  fn reify_color_textures<C>(
+    _: &mut C,
+    _: <Dim2 as Dimensionable>::Size,
+    _: usize,
+    _: &Sampler,
+    framebuffer: &mut B::FramebufferRepr,
+    attachment_index: usize,
+  ) -> Result<Self::ColorTextures, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    unsafe { B::attach_renderbuffer(framebuffer, P::pixel_format(), Some(attachment_index)) }
+  }
+
+  fn resize_color_textures(
+    _: &mut Self::ColorTextures,
+    _: <Dim2 as Dimensionable>::Size,
+    _: &mut B::FramebufferRepr,
+    _: usize,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
 }
 
 macro_rules! impl_color_slot_tuple {
-  ($($pf:ident),*) => {
-    impl<B, D, $($pf),*> ColorSlot<B, D> for ($($pf),*)
+  ($($cs:ident),*) => {
+    impl<B, D, $($cs),*> ColorSlot<B, D> for ($($cs),*)
     where
-      B: ?Sized + Framebuffer<D> + $(TextureBackend<D, $pf> +)*,
+      B: ?Sized + Framebuffer<D>,
       D: Dimensionable,
       D::Size: Copy,
       $(
-        $pf: ColorPixel + RenderablePixel
+        $cs: ColorSlot<B, D>
       ),*
     {
-      type ColorTextures = ($(Texture<B, D, $pf>),*);
+      type ColorTextures = ($($cs::ColorTextures),*);
 
       fn color_formats() -> Vec<PixelFormat> {
-        vec![$($pf::pixel_format()),*]
-
+        let mut formats = Vec::new();
+        $(formats.extend($cs::color_formats());)*
+        formats
       }
 
-      impl_reify_color_textures!{ $($pf),* }
-    }
-  }
-}
-
-// A small helper macro to implement reify_color_textures in impl_color_slot_tuple!.
-//
-// We need this macro so that we can implement the increment logic without having to do weird
-// arithmetic at runtime or have dead code.
-macro_rules! impl_reify_color_textures {
-  ($pf:ident , $($pfr:ident),*) => {
-    fn reify_color_textures<C>(
-      ctx: &mut C,
-      size: D::Size,
-      mipmaps: usize,
-      sampler: &Sampler,
-      framebuffer: &mut B::FramebufferRepr,
-      mut attachment_index: usize,
-    ) -> Result<Self::ColorTextures, FramebufferError>
-    where
-      C: GraphicsContext<Backend = B>,
-    {
-      let textures = (
-        // first element of the tuple
-        <$pf as ColorSlot<B, D>>::reify_color_textures(
-          ctx,
-          size,
-          mipmaps,
-          sampler,
-          framebuffer,
-          attachment_index,
-        )?,
-        // rest of the tuple
-        $({
-          attachment_index += 1;
-          let texture = <$pfr as ColorSlot<B, D>>::reify_color_textures(
+      // the attachment index is incremented after the last slot too, hence the allow
+      #[allow(unused_assignments)]
+      fn reify_color_textures<C>(
+        ctx: &mut C,
+        size: D::Size,
+        mipmaps: usize,
+        sampler: &Sampler,
+        framebuffer: &mut B::FramebufferRepr,
+        mut attachment_index: usize,
+      ) -> Result<Self::ColorTextures, FramebufferError>
+      where
+        C: GraphicsContext<Backend = B>,
+      {
+        let textures = ($({
+          let textures = $cs::reify_color_textures(
             ctx,
             size,
             mipmaps,
@@ -188,11 +306,28 @@ macro_rules! impl_reify_color_textures {
             attachment_index,
           )?;
 
-          texture
-        }),*
-      );
+          // each slot of the tuple takes as many attachments as it has pixel formats
+          attachment_index += $cs::color_formats().len();
+          textures
+        }),*);
+
+        Ok(textures)
+      }
+
+      #[allow(non_snake_case, unused_assignments)]
+      fn resize_color_textures(
+        ($($cs),*): &mut Self::ColorTextures,
+        size: D::Size,
+        framebuffer: &mut B::FramebufferRepr,
+        mut attachment_index: usize,
+      ) -> Result<(), FramebufferError> {
+        $(
+          <$cs as ColorSlot<B, D>>::resize_color_textures($cs, size, framebuffer, attachment_index)?;
+          attachment_index += <$cs as ColorSlot<B, D>>::color_formats().len();
+        )*
 
-      Ok(textures)
+        Ok(())
+      }
     }
   }
 }
@@ -10,11 +10,17 @@
 //! types from this module to use as constraint.
 
 use crate::{
-  backend::{framebuffer::Framebuffer, texture::Texture as TextureBackend},
+  backend::{
+    framebuffer::{Framebuffer, FramebufferRenderbuffer},
+    texture::Texture as TextureBackend,
+  },
   context::GraphicsContext,
-  framebuffer::FramebufferError,
-  pixel::{Depth32F, Depth32FStencil8, Pixel as _, PixelFormat},
-  texture::{Dimensionable, Sampler, TexelUpload, Texture},
+  framebuffer::{FramebufferError, Renderbuffer},
+  pixel::{
+    Depth16, Depth24, Depth24Stencil8, Depth32F, Depth32FStencil8, DepthPixel, Pixel as _,
+    PixelFormat,
+  },
+  texture::{Dim2, Dimensionable, Sampler, TexelUpload, Texture},
 };
 
 /// A depth/stencil slot.
@@ -29,7 +35,14 @@ use crate::{
 ///   for the framebuffer.
 /// - A single depth [`Texture`]. This type of depth/stenccil slot is often suitable for renderable framebuffer. The
 ///   pixel format must implement [`DepthPixel`].
-/// - A combined depth/stencil [`Texture`], allowing to use a depth buffer along with a stencil buffer.
+/// - A combined depth/stencil [`Texture`], allowing to use a depth buffer along with a stencil buffer. The backend
+///   attaches it as both the depth and stencil attachments.
+/// - A depth, depth/stencil or stencil [`Renderbuffer`], which cannot be sampled and gives no texture, if the backend
+///   implements [`FramebufferRenderbuffer`]. Stencil-only slots, such as `Renderbuffer<Stencil8>`, are only available
+///   as renderbuffers; the framebuffer then has no depth buffer.
+///
+/// Depth textures can be sampled once rendered to, for instance for shadow mapping. Setting
+/// [`Sampler::depth_comparison`] allows to sample them with shadow samplers (e.g. `sampler2DShadow`).
 ///
 /// Feel free to have a look at the list of implementors of this trait to know which types you can use as depth and
 /// stencil slots.
@@ -55,6 +68,15 @@ where
   ) -> Result<Self::DepthStencilTexture, FramebufferError>
   where
     C: GraphicsContext<Backend = B>;
+
+  /// Resize the texture of the depth/stencil slot and attach it again.
+  ///
+  /// Renderbuffers are resized by the backend, so only textures must be resized.
+  fn resize_depth_texture(
+    texture: &mut Self::DepthStencilTexture,
+    size: D::Size,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError>;
 }
 
 impl<B, D> DepthStencilSlot<B, D> for ()
@@ -81,62 +103,99 @@ where
   {
     Ok(())
   }
+
+  fn resize_depth_texture(
+    _: &mut Self::DepthStencilTexture,
+    _: D::Size,
+    _: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
+  }
 }
 
-impl<B, D> DepthStencilSlot<B, D> for Depth32F
-where
-  B: ?Sized + Framebuffer<D> + TextureBackend<D, Depth32F>,
-  D: Dimensionable,
-  D::Size: Copy,
-{
-  type DepthStencilTexture = Texture<B, D, Depth32F>;
+macro_rules! impl_DepthStencilSlot {
+  ($($t:ty),*) => {
+    $(
+      impl<B, D> DepthStencilSlot<B, D> for $t
+      where
+        B: ?Sized + Framebuffer<D> + TextureBackend<D, $t>,
+        D: Dimensionable,
+        D::Size: Copy,
+      {
+        type DepthStencilTexture = Texture<B, D, $t>;
 
-  fn depth_format() -> Option<PixelFormat> {
-    Some(Depth32F::pixel_format())
-  }
+        fn depth_format() -> Option<PixelFormat> {
+          Some(<$t>::pixel_format())
+        }
 
-  fn reify_depth_texture<C>(
-    ctx: &mut C,
-    size: D::Size,
-    mipmaps: usize,
-    sampler: &Sampler,
-    framebuffer: &mut B::FramebufferRepr,
-  ) -> Result<Self::DepthStencilTexture, FramebufferError>
-  where
-    C: GraphicsContext<Backend = B>,
-  {
-    let texture = Texture::new(ctx, size, sampler.clone(), TexelUpload::reserve(mipmaps))?;
-    unsafe { B::attach_depth_texture(framebuffer, &texture.repr)? };
+        fn reify_depth_texture<C>(
+          ctx: &mut C,
+          size: D::Size,
+          mipmaps: usize,
+          sampler: &Sampler,
+          framebuffer: &mut B::FramebufferRepr,
+        ) -> Result<Self::DepthStencilTexture, FramebufferError>
+        where
+          C: GraphicsContext<Backend = B>,
+        {
+          let texture = Texture::new(ctx, size, sampler.clone(), TexelUpload::reserve(mipmaps))?;
+          unsafe { B::attach_depth_texture(framebuffer, &texture.repr)? };
 
-    Ok(texture)
-  }
+          Ok(texture)
+        }
+
+        fn resize_depth_texture(
+          texture: &mut Self::DepthStencilTexture,
+          size: D::Size,
+          framebuffer: &mut B::FramebufferRepr,
+        ) -> Result<(), FramebufferError> {
+          let mipmaps = texture.mipmaps();
+          texture.resize(size, TexelUpload::reserve(mipmaps))?;
+
+          unsafe { B::attach_depth_texture(framebuffer, &texture.repr) }
+        }
+      }
+    )*
+  };
 }
 
-impl<B, D> DepthStencilSlot<B, D> for Depth32FStencil8
+impl_DepthStencilSlot!(
+  Depth16,
+  Depth24,
+  Depth32F,
+  Depth24Stencil8,
+  Depth32FStencil8
+);
+
+impl<B, P> DepthStencilSlot<B, Dim2> for Renderbuffer<P>
 where
-  B: ?Sized + Framebuffer<D> + TextureBackend<D, Depth32FStencil8>,
-  D: Dimensionable,
-  D::Size: Copy,
+  B: ?Sized + FramebufferRenderbuffer,
+  P: DepthPixel,
 {
-  type DepthStencilTexture = Texture<B, D, Depth32FStencil8>;
+  type DepthStencilTexture = ();
 
   fn depth_format() -> Option<PixelFormat> {
-    Some(Depth32F::pixel_format())
+    Some(P::pixel_format())
   }
 
   fn reify_depth_texture<C>(
-    ctx: &mut C,
-    size: D::Size,
-    mipmaps: usize,
-    sampler: &Sampler,
+    _: &mut C,
+    _: <Dim2 as Dimensionable>::Size,
+    _: usize,
+    _: &Sampler,
     framebuffer: &mut B::FramebufferRepr,
   ) -> Result<Self::DepthStencilTexture, FramebufferError>
   where
     C: GraphicsContext<Backend = B>,
   {
-    let texture = Texture::new(ctx, size, sampler.clone(), TexelUpload::reserve(mipmaps))?;
-    unsafe { B::attach_depth_texture(framebuffer, &texture.repr)? };
+    unsafe { B::attach_renderbuffer(framebuffer, P::pixel_format(), None) }
+  }
 
-    Ok(texture)
+  fn resize_depth_texture(
+    _: &mut Self::DepthStencilTexture,
+    _: <Dim2 as Dimensionable>::Size,
+    _: &mut B::FramebufferRepr,
+  ) -> Result<(), FramebufferError> {
+    Ok(())
   }
 }
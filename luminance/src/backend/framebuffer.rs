@@ -4,9 +4,11 @@
 
 use crate::{
   backend::{color_slot::ColorSlot, depth_stencil_slot::DepthStencilSlot, texture::TextureBase},
-  framebuffer::FramebufferError,
-  texture::{Dim2, Dimensionable, Sampler},
+  framebuffer::{BlitMask, FramebufferError, Region},
+  pixel::{Pixel, PixelFormat},
+  texture::{Dim2, Dimensionable, MagFilter, Sampler},
 };
+use std::time::Duration;
 
 /// Framebuffer backend.
 ///
@@ -82,6 +84,277 @@ where
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size;
 }
 
+/// Framebuffer resizing.
+///
+/// Framebuffers are resized in three steps: [`FramebufferResize::resize_framebuffer`] reallocates the storage owned by
+/// the framebuffer, then the textures of the color and depth/stencil slots are resized and attached again with
+/// [`Framebuffer::attach_color_texture`] and [`Framebuffer::attach_depth_texture`], and finally
+/// [`FramebufferResize::validate_resized_framebuffer`] checks that the framebuffer is complete.
+///
+/// # Safety
+///
+/// Implementors must leave the framebuffer usable once the three steps are done, and must not release the textures of
+/// the slots, which are owned by the caller.
+pub unsafe trait FramebufferResize<D>: Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Prepare a framebuffer to be resized to `size`.
+  ///
+  /// The storage owned by the framebuffer — such as the depth buffer allocated for `()` depth slots and renderbuffers
+  /// — must be reallocated for `size`, and the textures of the slots detached, as they are attached again once resized.
+  /// If the selected layer is out of the bounds of `size`, all the layers — or the first one, for backends not
+  /// supporting layered rendering — must be selected instead.
+  ///
+  /// # Safety
+  ///
+  /// `framebuffer` must have been created by this backend, and the textures of its slots must be resized and attached
+  /// again before [`FramebufferResize::validate_resized_framebuffer`] is called.
+  unsafe fn resize_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    size: D::Size,
+  ) -> Result<(), FramebufferError>;
+
+  /// Check that a resized framebuffer, whose slots are attached again, is complete.
+  ///
+  /// # Safety
+  ///
+  /// Must only be called once the textures of the slots of `framebuffer`, prepared with
+  /// [`FramebufferResize::resize_framebuffer`], are attached again.
+  unsafe fn validate_resized_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Layer selection of layered framebuffers.
+///
+/// Layered framebuffers are 3D, array or cubemap framebuffers; the layers of a cubemap are its faces, in
+/// [`CubeFace::index`] order. Backends implementing this trait can attach either a single layer or all the layers of
+/// their textures. Backends supporting layered rendering attach all the layers when creating framebuffers — including
+/// the layers of the depth buffer allocated when the depth slot is `()` — while others attach the first one.
+///
+/// # Safety
+///
+/// Implementors must keep [`Framebuffer::framebuffer_size`] consistent with the attached layers.
+///
+/// [`CubeFace::index`]: crate::texture::CubeFace::index
+pub unsafe trait FramebufferLayer<D>: Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Attach the `layer` layer of the textures of the framebuffer, or all of their layers if `None`.
+  ///
+  /// If the framebuffer would be incomplete, the previously attached layers must be restored and the error returned.
+  ///
+  /// # Safety
+  ///
+  /// `layer`, if any, must be in the bounds of the textures of `framebuffer`.
+  unsafe fn select_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    layer: Option<u32>,
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Mipmap level selection of framebuffers.
+///
+/// Backends implementing this trait can attach any mipmap level of the textures of the color and depth slots. Storage
+/// allocated by the backend — such as the depth buffer allocated when the depth slot is `()` — has a single level and
+/// stays attached.
+///
+/// # Safety
+///
+/// Implementors must keep [`Framebuffer::framebuffer_size`] consistent with the attached level.
+pub unsafe trait FramebufferLevel<D>: Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Attach the `level` mipmap level of the textures of the framebuffer.
+  ///
+  /// [`Framebuffer::framebuffer_size`] must then return the size of that level. If a single layer is attached and the
+  /// level has fewer layers, all the layers — or the first one, if the backend cannot attach all of them — must be
+  /// attached instead. If the framebuffer would be incomplete, the previously attached level must be restored and the
+  /// error returned.
+  ///
+  /// # Safety
+  ///
+  /// `level` must be in the bounds of the textures of `framebuffer`.
+  unsafe fn select_level(
+    framebuffer: &mut Self::FramebufferRepr,
+    level: usize,
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Multisampled framebuffers.
+///
+/// Multisampled framebuffers store several samples per pixel, which makes them suitable for anti-aliased (MSAA)
+/// rendering. Their attachments cannot be sampled directly: they are _resolved_ into a regular framebuffer instead.
+///
+/// # Safety
+///
+/// Implementors must never expose the multisampled storage as sampleable textures.
+pub unsafe trait FramebufferMultisample: Framebuffer<Dim2> {
+  /// Create a new multisampled framebuffer with `samples` samples per pixel.
+  ///
+  /// The backend allocates the multisampled storage of the color formats of `CS` and of the depth format of `DS` — or
+  /// a default depth storage if `DS` has none —, attaches it and validates the framebuffer. [`FramebufferError::UnsupportedSampleCount`]
+  /// must be returned if the number of samples is not supported.
+  unsafe fn new_multisample_framebuffer<CS, DS>(
+    &mut self,
+    size: <Dim2 as Dimensionable>::Size,
+    samples: usize,
+  ) -> Result<Self::FramebufferRepr, FramebufferError>
+  where
+    CS: ColorSlot<Self, Dim2>,
+    DS: DepthStencilSlot<Self, Dim2>;
+
+  /// Resolve the color attachments of a multisampled framebuffer into the ones of `target`, by pairs of attachments
+  /// of the same index.
+  ///
+  /// # Safety
+  ///
+  /// Both framebuffers must have been created by this backend and have the same size.
+  unsafe fn resolve(
+    framebuffer: &Self::FramebufferRepr,
+    target: &mut Self::FramebufferRepr,
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Renderbuffer attachments.
+///
+/// Renderbuffers are storage that can be rendered to but not sampled, such as depth buffers which are never read back.
+/// They are attached by the [`Renderbuffer`] color and depth/stencil slots.
+///
+/// # Safety
+///
+/// Implementors must release the renderbuffers when the framebuffer is dropped.
+///
+/// [`Renderbuffer`]: crate::framebuffer::Renderbuffer
+pub unsafe trait FramebufferRenderbuffer: Framebuffer<Dim2> {
+  /// Allocate a renderbuffer of the size of the framebuffer and attach it.
+  ///
+  /// `attachment_index` is the rank of the color attachment, or `None` for the depth/stencil attachment. The
+  /// renderbuffer is owned by the framebuffer.
+  ///
+  /// # Safety
+  ///
+  /// `attachment_index` must be in the bounds of the color slots of `framebuffer`, and the pixel format must be a depth
+  /// or depth/stencil one when it is `None`.
+  unsafe fn attach_renderbuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    pixel_format: PixelFormat,
+    attachment_index: Option<usize>,
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Blits between framebuffers.
+///
+/// # Safety
+///
+/// Implementors must not leave the read and draw framebuffer bindings in a state that the rest of the backend doesn’t
+/// expect.
+pub unsafe trait FramebufferBlit: Framebuffer<Dim2> {
+  /// Copy the `src` region of a framebuffer into the `dst` region of `target`, scaled with `filter` if their sizes
+  /// differ.
+  ///
+  /// Color attachments are copied by pairs of the same index, and only the first one is copied if either framebuffer is
+  /// the back buffer. `framebuffer` can be multisampled, in which case its samples are resolved.
+  ///
+  /// # Safety
+  ///
+  /// The regions must be in the bounds of their framebuffers, `mask` must select at least one buffer and `filter` must
+  /// be [`MagFilter::Nearest`] if depth or stencil are copied.
+  unsafe fn blit(
+    framebuffer: &Self::FramebufferRepr,
+    target: &mut Self::FramebufferRepr,
+    src: Region,
+    dst: Region,
+    mask: BlitMask,
+    filter: MagFilter,
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Pixel reads from framebuffers.
+///
+/// # Safety
+///
+/// Implementors must return exactly as many pixels as the region holds.
+pub unsafe trait FramebufferRead: Framebuffer<Dim2> {
+  /// Read the pixels of the `region` of the color attachment at index `attachment` of a framebuffer, or of its depth
+  /// attachment if `attachment` is `None`.
+  ///
+  /// The color buffer of the back buffer — at index `0` — has the [`NormRGBA8UI`] pixel format, and depth buffers
+  /// allocated by the backend have the [`Depth32F`] one. Pixels are returned row by row, starting with the bottom row.
+  ///
+  /// # Safety
+  ///
+  /// `region` must be in the bounds of the framebuffer and `P` must be the pixel format of the attachment.
+  ///
+  /// [`NormRGBA8UI`]: crate::pixel::NormRGBA8UI
+  /// [`Depth32F`]: crate::pixel::Depth32F
+  unsafe fn read_pixels<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment: Option<usize>,
+    region: Region,
+  ) -> Result<Vec<P::Encoding>, FramebufferError>
+  where
+    P: Pixel,
+    P::Encoding: Copy + Default;
+}
+
+/// Asynchronous pixel reads from framebuffers.
+///
+/// This trait requires [`FramebufferRead`] as super trait.
+///
+/// # Safety
+///
+/// Implementors must keep the pixels of a pending read valid until it is dropped, even if the framebuffer is rendered
+/// to or dropped.
+pub unsafe trait FramebufferReadback: FramebufferRead {
+  /// Backend representation of a pending pixel read.
+  type PixelReadbackRepr;
+
+  /// Start reading the pixels of the `region` of an attachment of a framebuffer.
+  ///
+  /// The arguments are the same as in [`FramebufferRead::read_pixels`]. This method must not wait for the GPU to be done
+  /// rendering to the framebuffer, but copy the pixels to a GPU buffer once it is.
+  ///
+  /// # Safety
+  ///
+  /// Same as [`FramebufferRead::read_pixels`].
+  unsafe fn read_pixels_async<P>(
+    framebuffer: &Self::FramebufferRepr,
+    attachment: Option<usize>,
+    region: Region,
+  ) -> Result<Self::PixelReadbackRepr, FramebufferError>
+  where
+    P: Pixel;
+
+  /// Whether the pixels of a pending read are ready, without blocking.
+  ///
+  /// # Safety
+  ///
+  /// `readback` must have been created by [`FramebufferReadback::read_pixels_async`] of this backend.
+  unsafe fn is_readback_ready(readback: &Self::PixelReadbackRepr) -> bool;
+
+  /// Block until the pixels of a pending read are ready, for at most `timeout`; return whether they are ready.
+  ///
+  /// # Safety
+  ///
+  /// `readback` must have been created by [`FramebufferReadback::read_pixels_async`] of this backend.
+  unsafe fn wait_readback(readback: &Self::PixelReadbackRepr, timeout: Duration) -> bool;
+
+  /// Get the pixels of a pending read, blocking until they are ready.
+  ///
+  /// # Safety
+  ///
+  /// `T` must be the encoding of the pixel format the read was started with.
+  unsafe fn readback_pixels<T>(
+    readback: Self::PixelReadbackRepr,
+  ) -> Result<Vec<T>, FramebufferError>
+  where
+    T: Copy + Default;
+}
+
 /// Back buffer.
 ///
 /// A back buffer is a special kind of [`Framebuffer`]. It’s a 2D (c.f. [`Dim2`]) framebuffer that is provided
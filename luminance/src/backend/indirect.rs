@@ -0,0 +1,43 @@
+//! Indirect buffer backend interface.
+//!
+//! This interface defines the low-level API indirect buffers must implement to be usable. Rendering from them is done
+//! with [`TessGateIndirect`] and [`TessGateIndirectAt`].
+//!
+//! [`TessGateIndirect`]: crate::backend::tess_gate::TessGateIndirect
+//! [`TessGateIndirectAt`]: crate::backend::tess_gate::TessGateIndirectAt
+
+use crate::indirect::{IndirectBufferError, IndirectCommand};
+
+/// Buffers of draw commands of type `C`.
+///
+/// # Safety
+///
+/// Implementors must store the commands with the layout of `C`, as the GPU reads them.
+pub unsafe trait IndirectBuffer<C>
+where
+  C: IndirectCommand,
+{
+  /// Backend representation of an indirect buffer.
+  type IndirectBufferRepr;
+
+  /// Create a new indirect buffer holding `commands`.
+  ///
+  /// # Safety
+  ///
+  /// `commands` must not be empty.
+  unsafe fn new_indirect_buffer(
+    &mut self,
+    commands: &[C],
+  ) -> Result<Self::IndirectBufferRepr, IndirectBufferError>;
+
+  /// Upload `commands` to an indirect buffer, starting at the draw command at index `offset`.
+  ///
+  /// # Safety
+  ///
+  /// `commands` must not be empty and must fit in the buffer from `offset`.
+  unsafe fn upload_indirect_commands(
+    buffer: &mut Self::IndirectBufferRepr,
+    offset: usize,
+    commands: &[C],
+  ) -> Result<(), IndirectBufferError>;
+}
@@ -21,11 +21,11 @@
 use crate::{
   backend::{
     framebuffer::Framebuffer as FramebufferBackend,
-    shader::ShaderData,
+    shader::{AtomicCounters, ShaderData, ShaderStorage},
     shading_gate::ShadingGate as ShadingGateBackend,
     texture::{Texture, TextureBase},
   },
-  pipeline::{PipelineError, PipelineState},
+  pipeline::{ImageAccess, PipelineError, PipelineState},
   pixel::Pixel,
   texture::Dimensionable,
 };
@@ -67,6 +67,20 @@ where
     framebuffer: &Self::FramebufferRepr,
     pipeline_state: &PipelineState,
   );
+
+  /// End a pipeline started with [`Pipeline::start_pipeline`] with the same framebuffer and state.
+  ///
+  /// The content of the attachments listed in [`PipelineState::invalidate_attachments`] can be discarded. This is only a
+  /// hint, which backends not supporting it ignore.
+  ///
+  /// # Safety
+  ///
+  /// `framebuffer` and `pipeline_state` must be the ones the pipeline was started with.
+  unsafe fn end_pipeline(
+    &mut self,
+    framebuffer: &Self::FramebufferRepr,
+    pipeline_state: &PipelineState,
+  );
 }
 
 /// Operations that can be run on pipelines and textures.
@@ -97,6 +111,47 @@ where
   unsafe fn texture_binding(bound: &Self::BoundTextureRepr) -> u32;
 }
 
+/// Operations that can be run on pipelines and textures used as images.
+///
+/// This trait requires [`PipelineBase`] and [`Texture`].
+///
+/// # Safety
+///
+/// Implementors must make stores to bound images visible to the operations following the unbinding.
+pub unsafe trait PipelineImage<D, P>: PipelineBase + Texture<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Representation of a [`Texture`] bound as an image on the backend.
+  type BoundImageRepr;
+
+  /// Bind a level of a [`Texture`] as an image to the current [`Pipeline`].
+  ///
+  /// Images can be loaded from and stored to by shader stages, depending on `access`. Binding points of images are not
+  /// shared with the ones of [`PipelineTexture::bind_texture`]. `layer` selects a single layer of layered textures, or
+  /// all of them if [`None`]. The bound representation will most of the time implement [`Drop`] to recycle the binding
+  /// point, and ensure that the stores are visible to the next operations.
+  ///
+  /// # Safety
+  ///
+  /// Must be called while a pipeline is running, and `level` and `layer` must be in the bounds of `texture`.
+  unsafe fn bind_image(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::TextureRepr,
+    level: usize,
+    layer: Option<usize>,
+    access: ImageAccess,
+  ) -> Result<Self::BoundImageRepr, PipelineError>;
+
+  /// Get the `u32` representation of the bound image, also known as binding.
+  ///
+  /// # Safety
+  ///
+  /// `bound` must have been obtained with [`PipelineImage::bind_image`] of this backend.
+  unsafe fn image_binding(bound: &Self::BoundImageRepr) -> u32;
+}
+
 /// Operations that can be run on pipelines and shader data.
 ///
 /// This trait requires [`PipelineBase`] and [`ShaderData`].
@@ -117,3 +172,69 @@ pub unsafe trait PipelineShaderData<T>: PipelineBase + ShaderData<T> {
   /// Get the `u32` representation of the bound shader data, also known as binding.
   unsafe fn shader_data_binding(bound: &Self::BoundShaderDataRepr) -> u32;
 }
+
+/// Operations that can be run on pipelines and shader storage.
+///
+/// This trait requires [`PipelineBase`] and [`ShaderStorage`].
+///
+/// # Safety
+///
+/// Implementors must recycle the binding points once the bound shader storage is dropped.
+pub unsafe trait PipelineShaderStorage<T>: PipelineBase + ShaderStorage<T> {
+  /// Representation of a _bound_ [`ShaderData`] as shader storage on the backend.
+  type BoundShaderStorageRepr;
+
+  /// Bind a [`ShaderData`] as shader storage to the current [`Pipeline`].
+  ///
+  /// Binding points of shader storage are not shared with the ones of [`PipelineShaderData::bind_shader_data`]. As with
+  /// shader data, the bound representation will most of the time implement [`Drop`] to recycle the binding point.
+  ///
+  /// # Safety
+  ///
+  /// Must be called while a pipeline is running.
+  unsafe fn bind_shader_storage(
+    pipeline: &Self::PipelineRepr,
+    shader_data: &Self::ShaderDataRepr,
+  ) -> Result<Self::BoundShaderStorageRepr, PipelineError>;
+
+  /// Get the `u32` representation of the bound shader storage, also known as binding.
+  ///
+  /// # Safety
+  ///
+  /// `bound` must have been obtained with [`PipelineShaderStorage::bind_shader_storage`] of this backend.
+  unsafe fn shader_storage_binding(bound: &Self::BoundShaderStorageRepr) -> u32;
+}
+
+/// Operations that can be run on pipelines and atomic counters.
+///
+/// This trait requires [`PipelineBase`] and [`AtomicCounters`].
+///
+/// # Safety
+///
+/// Implementors must bind the counters at the exact binding point requested.
+pub unsafe trait PipelineAtomicCounters: PipelineBase + AtomicCounters {
+  /// Representation of bound [`AtomicCounters`] on the backend.
+  type BoundAtomicCountersRepr;
+
+  /// Bind [`AtomicCounters`] to the current [`Pipeline`] at the given `binding` point.
+  ///
+  /// Contrary to the other resources, the binding point is not picked by the backend, as shader stages declare it
+  /// statically (i.e. `layout(binding = 0) uniform atomic_uint counter;`).
+  ///
+  /// # Safety
+  ///
+  /// Must be called while a pipeline is running, and `binding` must be lower than the number of atomic counter binding
+  /// points of the backend.
+  unsafe fn bind_atomic_counters(
+    pipeline: &Self::PipelineRepr,
+    counters: &Self::AtomicCountersRepr,
+    binding: u32,
+  ) -> Result<Self::BoundAtomicCountersRepr, PipelineError>;
+
+  /// Get the `u32` representation of the bound atomic counters, also known as binding.
+  ///
+  /// # Safety
+  ///
+  /// `bound` must have been obtained with [`PipelineAtomicCounters::bind_atomic_counters`] of this backend.
+  unsafe fn atomic_counters_binding(bound: &Self::BoundAtomicCountersRepr) -> u32;
+}
@@ -0,0 +1,111 @@
+//! Pixel buffer backend interface.
+//!
+//! This interface defines the low-level API pixel buffers must implement to be usable. Backends supporting pixel
+//! buffers implement [`PixelBuffer`] and [`PixelBufferSliceMut`] to create and write to them, and
+//! [`TextureStreaming`] to upload their content to textures, which is tracked with an [`UploadFence`].
+
+use crate::{
+  backend::texture::Texture,
+  pixel::Pixel,
+  pixel_buffer::PixelBufferError,
+  texture::{Dimensionable, TextureError},
+};
+use std::{ops::DerefMut, time::Duration};
+
+/// Pixel buffers of texels of type `T`.
+///
+/// # Safety
+///
+/// Implementors must allocate room for exactly `len` texels.
+pub unsafe trait PixelBuffer<T> {
+  /// Backend representation of a pixel buffer.
+  type PixelBufferRepr;
+
+  /// Create a new pixel buffer holding `len` texels.
+  ///
+  /// # Safety
+  ///
+  /// `len` must not be zero.
+  unsafe fn new_pixel_buffer(
+    &mut self,
+    len: usize,
+  ) -> Result<Self::PixelBufferRepr, PixelBufferError>;
+}
+
+/// Mapping of pixel buffers.
+///
+/// This trait requires [`PixelBuffer`] as super trait.
+///
+/// # Safety
+///
+/// Implementors must not let writes through the slice race with uploads in flight.
+pub unsafe trait PixelBufferSliceMut<'a, T>: PixelBuffer<T> {
+  /// Backend representation of a mutable slice of the texels of a pixel buffer.
+  ///
+  /// The texels written to the slice must be visible to the backend once the slice is dropped.
+  type PixelBufferSliceMutRepr: 'a + DerefMut<Target = [T]>;
+
+  /// Obtain a mutable slice of the texels of a pixel buffer.
+  ///
+  /// The previous content of the buffer is lost; uploads still using it must not be affected.
+  ///
+  /// # Safety
+  ///
+  /// `buffer` must have been created by this backend.
+  unsafe fn slice_mut(
+    buffer: &'a mut Self::PixelBufferRepr,
+  ) -> Result<Self::PixelBufferSliceMutRepr, PixelBufferError>;
+}
+
+/// Fences signaled once an upload is complete.
+///
+/// # Safety
+///
+/// Implementors must only report complete uploads once the texels can be sampled.
+pub unsafe trait UploadFence {
+  /// Backend representation of an upload fence.
+  type UploadFenceRepr;
+
+  /// Whether the upload is complete, without blocking.
+  ///
+  /// # Safety
+  ///
+  /// `fence` must have been returned by [`TextureStreaming::upload_part_level_from_buffer`] of this backend.
+  unsafe fn is_upload_complete(fence: &Self::UploadFenceRepr) -> bool;
+
+  /// Block until the upload is complete, for at most `timeout`; return whether it is complete.
+  ///
+  /// # Safety
+  ///
+  /// `fence` must have been returned by [`TextureStreaming::upload_part_level_from_buffer`] of this backend.
+  unsafe fn wait_upload(fence: &Self::UploadFenceRepr, timeout: Duration) -> bool;
+}
+
+/// Uploads of the content of pixel buffers to textures.
+///
+/// This trait requires [`Texture`], [`PixelBuffer`] and [`UploadFence`] as super traits.
+///
+/// # Safety
+///
+/// Implementors must keep the buffer content alive until the upload is complete.
+pub unsafe trait TextureStreaming<D, P>:
+  Texture<D, P> + PixelBuffer<P::Encoding> + UploadFence
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Schedule the upload of the first texels of `buffer` to a region of the `level` mipmap level of a texture.
+  ///
+  /// This method must not wait for the upload to be complete, but return a fence signaled once it is.
+  ///
+  /// # Safety
+  ///
+  /// The region must be in the bounds of the level, and `buffer` must hold at least as many texels as the region.
+  unsafe fn upload_part_level_from_buffer(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+    buffer: &Self::PixelBufferRepr,
+  ) -> Result<Self::UploadFenceRepr, TextureError>;
+}
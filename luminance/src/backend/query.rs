@@ -3,6 +3,7 @@
 //! This interface provides various means to query some metrics and data from the backend, such as the maximum number of
 //! active texture units, memory sizes, etc.
 
+use crate::pixel::CompressionFamily;
 use std::fmt;
 
 /// Query error.
@@ -20,6 +21,9 @@ pub enum QueryError {
   /// No backend shading language version information available.
   NoBackendShadingLanguageVersion,
 
+  /// The backend shading language version cannot be understood.
+  UnknownShadingLanguageVersion(String),
+
   /// No maximum number of elements for texture arrays information available.
   NoMaxTextureArrayElements,
 }
@@ -33,6 +37,9 @@ impl fmt::Display for QueryError {
       QueryError::NoBackendShadingLanguageVersion => {
         f.write_str("no backend shading language version available")
       }
+      QueryError::UnknownShadingLanguageVersion(version) => {
+        write!(f, "unknown backend shading language version: {}", version)
+      }
       QueryError::NoMaxTextureArrayElements => {
         f.write_str("no maximum number of elements for texture arrays available")
       }
@@ -60,4 +67,16 @@ pub unsafe trait Query {
 
   /// The maximum number of elements a texture array can hold.
   fn max_texture_array_elements(&self) -> Result<usize, QueryError>;
+
+  /// Whether a family of compressed pixel formats is supported.
+  fn supports_compression(&self, family: CompressionFamily) -> Result<bool, QueryError>;
+
+  /// The maximum anisotropy of anisotropic filtering, or `None` if anisotropic filtering is not supported.
+  fn max_anisotropy(&self) -> Result<Option<f32>, QueryError>;
+
+  /// Whether 32-bit floating-point color attachments can be blended.
+  fn supports_float_blending(&self) -> Result<bool, QueryError>;
+
+  /// Whether the color buffer of the back buffer has an sRGB encoding.
+  fn is_back_buffer_srgb(&self) -> Result<bool, QueryError>;
 }
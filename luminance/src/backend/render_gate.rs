@@ -5,10 +5,28 @@
 //! A render gate is a special kind of pipeline node that allows to group renders behind a shared [`RenderState`]. All
 //! subsequent nodes in the pipeline will be using that render state.
 
-use crate::render_state::RenderState;
+use crate::{backend::shader::ShaderMesh, render_state::RenderState};
 
 /// Render gate and associated [`RenderState`].
 pub unsafe trait RenderGate {
   /// Enter the [`RenderGate`] and share the [`RenderState`] for all subsequent nodes in the pipeline.
   unsafe fn enter_render_state(&mut self, rdr_st: &RenderState);
 }
+
+/// Mesh shader support for render gates.
+///
+/// This trait requires [`RenderGate`] and [`ShaderMesh`] as super traits.
+///
+/// # Safety
+///
+/// Implementors must only launch work groups with a mesh program in use.
+pub unsafe trait RenderGateMesh: RenderGate + ShaderMesh {
+  /// Launch `count` work groups of the mesh program currently in-use.
+  ///
+  /// The work groups are task shader work groups if the program has a task stage, and mesh shader work groups otherwise.
+  ///
+  /// # Safety
+  ///
+  /// A mesh program must be in use, and `count` must not exceed the maximum number of work groups of the backend.
+  unsafe fn draw_mesh_tasks(&mut self, count: u32);
+}
@@ -15,6 +15,7 @@
 //! - Tessellation shaders, run to tessellate the vertex stream.
 //! - Compute shaders, special kind of shaders used to compute non-image related data on the backend using the shader
 //!   pipeline.
+//! - Task and mesh shaders, generating primitives in work groups instead of processing a vertex stream.
 //!
 //! Then, the concept of a « shader program », which agregates shader stages into a single entity after a process of «
 //! linking » the various shader stages. A shader program is a pipeline resource, so it will be used inside a graphics
@@ -27,8 +28,10 @@
 
 use crate::{
   shader::{
-    ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform, UniformType,
-    UniformWarning, VertexAttribWarning,
+    types::Arr, ActiveAtomicCounter, ActiveUniform, ActiveVertexAttrib, GeometryConfig,
+    GlslVersion, ProgramError, ProgramValidation, ProgramWarning, ShaderDataError, StageError,
+    StageType, Subroutine, SubroutineUniform, TessellationStages, Uniform, UniformName,
+    UniformType, UniformWarning, VertexAttribWarning,
   },
   vertex::Semantics,
 };
@@ -55,6 +58,9 @@ pub unsafe trait Uniformable<'a, T>: Shader {
   ///
   /// For regular uniform variables, this should be `1`. For arrays, it should be the length of the array.
   /// For anything that is not sized, such as texture bindings, shader data, etc., set it to `0`.
+  ///
+  /// Backends must check this size against the declared size of the uniform in the shader, and emit a
+  /// [`UniformWarning::SizeMismatch`] if it exceeds it.
   const SIZE: usize;
 
   /// Reify the type of the uniform as a [`UniformType`].
@@ -62,6 +68,45 @@ pub unsafe trait Uniformable<'a, T>: Shader {
 
   /// Update the associated value of the [`Uniform`] in the given shader program.
   unsafe fn update(program: &mut Self::ProgramRepr, uniform: &'a Uniform<T>, value: Self::Target);
+
+  /// Perform additional checks on a [`Uniform`] once it has been looked up.
+  ///
+  /// This is used for checks that cannot be expressed with [`Uniformable::ty`], such as the layout of the content of
+  /// uniform blocks. The default implementation performs no check.
+  ///
+  /// # Safety
+  ///
+  /// `uniform` must have been looked up with `uniform_builder`.
+  unsafe fn check(
+    _uniform_builder: &mut Self::UniformBuilderRepr,
+    _name: &str,
+    _uniform: &Uniform<T>,
+  ) -> Result<(), UniformWarning> {
+    Ok(())
+  }
+}
+
+/// Backend support for updating a range of the items of array uniforms.
+///
+/// This allows to update only some items of an array [`Uniform`] instead of uploading the whole array every time.
+///
+/// # Safety
+///
+/// Implementors must only write the items of the range, leaving the other items of the array untouched.
+pub unsafe trait UniformableRange<'a, T, const N: usize>:
+  Uniformable<'a, Arr<T, N>>
+{
+  /// Update the items of the array [`Uniform`] starting at item `offset` with `values`.
+  ///
+  /// # Safety
+  ///
+  /// `offset + values.len()` must not exceed `N`, and `uniform` must belong to the program currently in use.
+  unsafe fn update_range(
+    program: &mut Self::ProgramRepr,
+    uniform: &'a Uniform<Arr<T, N>>,
+    offset: usize,
+    values: &'a [T],
+  );
 }
 
 /// Shader support.
@@ -85,6 +130,13 @@ pub unsafe trait Shader {
   /// Backend representation of a uniform builder.
   type UniformBuilderRepr;
 
+  /// GLSL version the stages are compiled with, unless their source declares its own.
+  ///
+  /// # Safety
+  ///
+  /// Must be called with the backend context current.
+  unsafe fn glsl_version(&self) -> GlslVersion;
+
   /// Create a new shader stage of type [`StageType`].
   unsafe fn new_stage(&mut self, ty: StageType, src: &str) -> Result<Self::StageRepr, StageError>;
 
@@ -100,6 +152,39 @@ pub unsafe trait Shader {
     fragment: &Self::StageRepr,
   ) -> Result<Self::ProgramRepr, ProgramError>;
 
+  /// Declare the varyings to capture with transform feedback.
+  ///
+  /// The varyings are captured interleaved, in order. This must only be taken into account when the program gets
+  /// linked, which happens in [`Shader::apply_semantics`].
+  ///
+  /// # Safety
+  ///
+  /// `program` must have been created by this backend and not be linked yet.
+  unsafe fn set_transform_feedback_varyings(
+    program: &mut Self::ProgramRepr,
+    varyings: &[String],
+  ) -> Result<(), ProgramError>;
+
+  /// Bind fragment outputs to color attachment indices.
+  ///
+  /// Each output is given with its name and the index of the color attachment it must be written to. This must only be
+  /// taken into account when the program gets linked, which happens in [`Shader::apply_semantics`].
+  ///
+  /// # Safety
+  ///
+  /// `program` must have been created by this backend and not be linked yet.
+  unsafe fn set_fragment_output_locations(
+    program: &mut Self::ProgramRepr,
+    outputs: &[(String, usize)],
+  ) -> Result<(), ProgramError>;
+
+  /// Get the color attachment index a fragment output is written to, if the output is active.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn fragment_output_location(program: &Self::ProgramRepr, name: &str) -> Option<usize>;
+
   /// Apply semantics.
   ///
   /// This is a very specific operations that happen right after the shader program got successfully created by the
@@ -128,6 +213,82 @@ pub unsafe trait Shader {
   where
     Self: for<'u> Uniformable<'u, T>;
 
+  /// Lookup a [`Uniform`] by its interned [`UniformName`].
+  ///
+  /// The default implementation looks the name up with [`Shader::ask_uniform`]. Backends can override it to cache the
+  /// lookup per program.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn ask_uniform_named<T>(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    name: &UniformName,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    Self: for<'u> Uniformable<'u, T>,
+  {
+    Self::ask_uniform(uniform_builder, name.as_str())
+  }
+
+  /// Lookup a [`Uniform`] by its location.
+  ///
+  /// This method must map the [`Uniform`] living at `location` without looking its name up. If the backend can
+  /// reflect the uniforms of the program, it should check that the type of the uniform matches, and return the
+  /// appropriate error otherwise.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend. Backends which cannot reflect uniforms cannot check
+  /// `location`, so a wrong location maps another uniform.
+  unsafe fn ask_uniform_at_location<T>(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    location: i32,
+  ) -> Result<Uniform<T>, UniformWarning>
+  where
+    Self: for<'u> Uniformable<'u, T>;
+
+  /// List the active uniforms of a program.
+  ///
+  /// Uniforms declared in uniform blocks must not be listed, as they cannot be mapped on their own.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn active_uniforms(program: &Self::ProgramRepr) -> Vec<ActiveUniform>;
+
+  /// List the active vertex attributes of a program, built-in ones excluded.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn active_vertex_attribs(program: &Self::ProgramRepr) -> Vec<ActiveVertexAttrib>;
+
+  /// Get the configuration of the geometry stage of a program, if it has one.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn geometry_config(program: &Self::ProgramRepr) -> Option<GeometryConfig>;
+
+  /// Info log of a stage, as reported by the compiler.
+  ///
+  /// The log can contain warnings even if the compilation succeeded. It is empty if the compiler had nothing to report.
+  ///
+  /// # Safety
+  ///
+  /// `stage` must have been created by this backend.
+  unsafe fn stage_log(stage: &Self::StageRepr) -> String;
+
+  /// Info log of a program, as reported by the linker.
+  ///
+  /// The log can contain warnings even if the link succeeded. It is empty if the linker had nothing to report.
+  ///
+  /// # Safety
+  ///
+  /// `program` must have been created by this backend.
+  unsafe fn program_log(program: &Self::ProgramRepr) -> String;
+
   /// Backend representation of an _unbound_ [`Uniform`] (i.e. that is inactive in the shader program).
   ///
   /// This is a method taking a uniform builder so that the builder can accumulate a state.
@@ -136,6 +297,263 @@ pub unsafe trait Shader {
     Self: for<'u> Uniformable<'u, T>;
 }
 
+/// Shader subroutine backend.
+///
+/// Subroutines allow to select, per shader stage, which function a subroutine uniform calls without changing the shader
+/// program.
+///
+/// # Safety
+///
+/// Implementors must make the selections persist across uses of the program, even if the underlying state is lost when
+/// another program is used.
+pub unsafe trait ShaderSubroutine: Shader {
+  /// Lookup a [`SubroutineUniform`] declared in the given shader stage.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn ask_subroutine_uniform(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    stage: StageType,
+    name: &str,
+  ) -> Result<SubroutineUniform, UniformWarning>;
+
+  /// Lookup a [`Subroutine`] defined in the given shader stage.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn ask_subroutine(
+    uniform_builder: &mut Self::UniformBuilderRepr,
+    stage: StageType,
+    name: &str,
+  ) -> Result<Subroutine, UniformWarning>;
+
+  /// Select the [`Subroutine`] called by a [`SubroutineUniform`] in the given shader program.
+  ///
+  /// The selection must persist across uses of the shader program. If the [`Subroutine`] and the [`SubroutineUniform`]
+  /// don’t belong to the same shader stage, nothing must happen.
+  ///
+  /// # Safety
+  ///
+  /// The [`SubroutineUniform`] and the [`Subroutine`] must have been looked up in `program`.
+  unsafe fn set_subroutine(
+    program: &mut Self::ProgramRepr,
+    uniform: &SubroutineUniform,
+    subroutine: &Subroutine,
+  );
+}
+
+/// Mesh shader backend.
+///
+/// Mesh programs are made of an optional [`StageType::TaskShader`] stage, a [`StageType::MeshShader`] stage and a
+/// [`StageType::FragmentShader`] stage. They replace the vertex processing stages of regular programs.
+///
+/// # Safety
+///
+/// Implementors must reject mesh programs on hardware not supporting them instead of creating broken programs.
+pub unsafe trait ShaderMesh: Shader {
+  /// Create a new mesh program from its stages.
+  ///
+  /// Backends must check that mesh shaders are supported, and return an error otherwise.
+  ///
+  /// # Safety
+  ///
+  /// The stages must have been created by this backend with the stage types they are passed as.
+  unsafe fn new_mesh_program(
+    &mut self,
+    task: Option<&Self::StageRepr>,
+    mesh: &Self::StageRepr,
+    fragment: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError>;
+}
+
+/// Compute shader backend.
+///
+/// Compute programs are made of a single [`StageType::ComputeShader`] stage.
+///
+/// # Safety
+///
+/// Implementors must only create programs made of a single compute stage.
+pub unsafe trait ShaderCompute: Shader {
+  /// Create a new compute program from a compute stage.
+  ///
+  /// # Safety
+  ///
+  /// `stage` must be a compute stage created by this backend.
+  unsafe fn new_compute_program(
+    &mut self,
+    compute: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError>;
+}
+
+/// Separate shader objects backend.
+///
+/// Separable programs are made of a single stage and are combined at render time by a program pipeline, which selects
+/// a separable program for each of its stages.
+///
+/// # Safety
+///
+/// Implementors must only combine stages of separable programs in program pipelines.
+pub unsafe trait ShaderSeparable: Shader {
+  /// Representation of a program pipeline by the backend.
+  type ProgramPipelineRepr;
+
+  /// Create a new separable program from a single stage.
+  ///
+  /// # Safety
+  ///
+  /// `stage` must have been created by this backend.
+  unsafe fn new_separable_program(
+    &mut self,
+    stage: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError>;
+
+  /// Create a new, empty program pipeline.
+  ///
+  /// # Safety
+  ///
+  /// Must be called with the backend context current.
+  unsafe fn new_program_pipeline(&mut self) -> Result<Self::ProgramPipelineRepr, ProgramError>;
+
+  /// Use the stage of a separable program in a program pipeline, replacing the previous program used for that stage.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a separable program of this backend, and `pipeline` must have been created by this backend.
+  unsafe fn use_program_stage(
+    pipeline: &mut Self::ProgramPipelineRepr,
+    program: &Self::ProgramRepr,
+  );
+}
+
+/// Shader program reloading backend.
+///
+/// # Safety
+///
+/// Implementors must leave the program untouched when reloading fails.
+pub unsafe trait ShaderReload: Shader {
+  /// Reload a program in place by linking new stages, applying the semantics `Sem`.
+  ///
+  /// The [`Uniform`]s previously obtained for the program must remain valid: they must refer to the uniforms with the
+  /// same names (or locations) in the reloaded program. Uniforms that cannot be found anymore — because they became
+  /// inactive or changed type — must be reported as warnings, and updating them must be a no-op. Separable programs
+  /// must stay separable, while subroutine selections are reset. If the reloaded program fails to link, the program
+  /// must be left untouched.
+  ///
+  /// # Safety
+  ///
+  /// `program` must have been created by this backend, and must not be in use by a running shading gate.
+  unsafe fn reload_program<Sem>(
+    &mut self,
+    program: &mut Self::ProgramRepr,
+    vertex: &Self::StageRepr,
+    tess: Option<TessellationStages<Self::StageRepr>>,
+    geometry: Option<&Self::StageRepr>,
+    fragment: &Self::StageRepr,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    Sem: Semantics;
+
+  /// Reload a program in place by replacing the stage of the same type as `stage` and linking it again, applying the
+  /// semantics `Sem`.
+  ///
+  /// The other stages of the program must be kept; if the program has no stage of that type, `stage` is added. The
+  /// same rules as with [`ShaderReload::reload_program`] apply.
+  ///
+  /// # Safety
+  ///
+  /// Same as [`ShaderReload::reload_program`].
+  unsafe fn replace_stage<Sem>(
+    &mut self,
+    program: &mut Self::ProgramRepr,
+    stage: &Self::StageRepr,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    Sem: Semantics;
+}
+
+/// Shader program validation backend.
+///
+/// # Safety
+///
+/// Implementors must not modify the program or the current state while validating.
+pub unsafe trait ShaderValidation: Shader {
+  /// Validate a program against the current state, as if it was used for a draw command.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn validate_program(program: &Self::ProgramRepr) -> ProgramValidation;
+}
+
+/// Deferred shader program backend.
+///
+/// Programs created this way are compiled and linked in the background (e.g. on driver threads): the status of the
+/// stages and of the link is only checked once the backend reports that the whole work is complete, so that the caller
+/// doesn’t have to wait for it.
+///
+/// # Safety
+///
+/// Implementors must not report a deferred program as complete before its stages are compiled and linked.
+pub unsafe trait ShaderDeferred: Shader {
+  /// Start compiling a new shader stage of type [`StageType`], without checking whether it succeeds.
+  ///
+  /// Compilation errors must be reported by [`ShaderDeferred::finish_deferred_link`] instead.
+  ///
+  /// # Safety
+  ///
+  /// Must be called with the backend context current.
+  unsafe fn new_deferred_stage(
+    &mut self,
+    ty: StageType,
+    src: &str,
+  ) -> Result<Self::StageRepr, StageError>;
+
+  /// Create a new shader program from several shader stages, without linking it.
+  ///
+  /// # Safety
+  ///
+  /// The stages must have been created by [`ShaderDeferred::new_deferred_stage`] of this backend.
+  unsafe fn new_deferred_program(
+    &mut self,
+    vertex: &Self::StageRepr,
+    tess: Option<TessellationStages<Self::StageRepr>>,
+    geometry: Option<&Self::StageRepr>,
+    fragment: &Self::StageRepr,
+  ) -> Result<Self::ProgramRepr, ProgramError>;
+
+  /// Apply the semantics `Sem` and start linking a program created with [`ShaderDeferred::new_deferred_program`].
+  ///
+  /// # Safety
+  ///
+  /// `program` must have been created by [`ShaderDeferred::new_deferred_program`] and not be linked yet.
+  unsafe fn start_deferred_link<Sem>(program: &mut Self::ProgramRepr)
+  where
+    Sem: Semantics;
+
+  /// Check whether compiling the stages and linking a program is complete. This must not block.
+  ///
+  /// # Safety
+  ///
+  /// The link of `program` must have been started with [`ShaderDeferred::start_deferred_link`].
+  unsafe fn is_deferred_link_complete(program: &Self::ProgramRepr) -> bool;
+
+  /// Check the result of compiling the stages and linking a program, waiting for it if not complete yet.
+  ///
+  /// If linking failed because a stage failed to compile, the compilation error must be reported.
+  ///
+  /// # Safety
+  ///
+  /// The link of `program` must have been started with [`ShaderDeferred::start_deferred_link`].
+  unsafe fn finish_deferred_link<Sem>(
+    program: &mut Self::ProgramRepr,
+    stages: &[&Self::StageRepr],
+  ) -> Result<Vec<VertexAttribWarning>, ProgramError>
+  where
+    Sem: Semantics;
+}
+
 /// Shader data backend.
 pub unsafe trait ShaderData<T> {
   /// Representation of the data by the backend.
@@ -168,3 +586,74 @@ pub unsafe trait ShaderData<T> {
     values: impl Iterator<Item = T>,
   ) -> Result<(), ShaderDataError>;
 }
+
+/// Shader storage backend.
+///
+/// Shader storage reuses the representation of [`ShaderData`] but can be written to by shader stages, so the backend
+/// must be able to read the data back.
+///
+/// # Safety
+///
+/// Implementors must make the writes of shader stages visible before reading the data back.
+pub unsafe trait ShaderStorage<T>: ShaderData<T> {
+  /// Read all the items back, as seen by the GPU.
+  ///
+  /// # Safety
+  ///
+  /// `shader_data` must have been created by this backend.
+  unsafe fn read_shader_storage(
+    shader_data: &Self::ShaderDataRepr,
+  ) -> Result<Vec<T>, ShaderDataError>;
+}
+
+/// Atomic counters backend.
+///
+/// Atomic counters are tightly packed `u32` values living in a buffer, that shader stages can atomically increment and
+/// decrement (`atomic_uint`).
+///
+/// # Safety
+///
+/// Implementors must store the counters as tightly packed `u32` values, as shader stages access them.
+pub unsafe trait AtomicCounters: Shader {
+  /// Representation of the atomic counters by the backend.
+  type AtomicCountersRepr;
+
+  /// Build new atomic counters from some initial values represented via an iterator.
+  ///
+  /// # Safety
+  ///
+  /// Must be called with the backend context current.
+  unsafe fn new_atomic_counters(
+    &mut self,
+    values: impl Iterator<Item = u32>,
+  ) -> Result<Self::AtomicCountersRepr, ShaderDataError>;
+
+  /// Read all the counters back, as seen by the GPU.
+  ///
+  /// # Safety
+  ///
+  /// `counters` must have been created by this backend.
+  unsafe fn read_atomic_counters(
+    counters: &Self::AtomicCountersRepr,
+  ) -> Result<Vec<u32>, ShaderDataError>;
+
+  /// Set the counters by providing an iterator.
+  ///
+  /// The iterator must provide exactly one value per counter; otherwise, no counter is set and
+  /// [`ShaderDataError::CannotReplaceData`] is returned.
+  ///
+  /// # Safety
+  ///
+  /// `counters` must have been created by this backend.
+  unsafe fn set_atomic_counters(
+    counters: &mut Self::AtomicCountersRepr,
+    values: impl Iterator<Item = u32>,
+  ) -> Result<(), ShaderDataError>;
+
+  /// List the active atomic counters of a program.
+  ///
+  /// # Safety
+  ///
+  /// `program` must be a linked program of this backend.
+  unsafe fn active_atomic_counters(program: &Self::ProgramRepr) -> Vec<ActiveAtomicCounter>;
+}
@@ -4,7 +4,7 @@
 //!
 //! Shading gates allow to shade a scene with a shader program.
 
-use crate::backend::shader::Shader as ShaderBackend;
+use crate::backend::shader::{Shader as ShaderBackend, ShaderCompute, ShaderSeparable};
 
 /// Shading gate backend.
 ///
@@ -13,5 +13,46 @@ use crate::backend::shader::Shader as ShaderBackend;
 /// [`Shader`]: crate::backend::shader::Shader
 pub unsafe trait ShadingGate: ShaderBackend {
   /// Apply the shader program and make it currently in-use for subsequent pipeline nodes.
+  ///
+  /// Uniforms are only updated while the shader program is in-use, so implementations are not expected to unbind it
+  /// afterwards. Binding a shader program that is already in-use should be a no-op.
   unsafe fn apply_shader_program(&mut self, shader_program: &Self::ProgramRepr);
 }
+
+/// Compute support for shading gates.
+///
+/// This trait requires [`ShadingGate`] and [`ShaderCompute`] as super traits.
+///
+/// # Safety
+///
+/// Implementors must issue the memory barriers required by the writes of compute programs.
+pub unsafe trait ShadingGateCompute: ShadingGate + ShaderCompute {
+  /// Dispatch the compute program currently in-use over a grid of `x * y * z` work groups.
+  ///
+  /// Writes performed by the compute program must be visible to the subsequent operations — such as reading shader
+  /// storage back or rendering with its content — so implementations must issue the required memory barriers.
+  ///
+  /// # Safety
+  ///
+  /// A compute program must be in use.
+  unsafe fn dispatch(&mut self, x: u32, y: u32, z: u32);
+}
+
+/// Separate shader objects support for shading gates.
+///
+/// This trait requires [`ShadingGate`] and [`ShaderSeparable`] as super traits.
+///
+/// # Safety
+///
+/// Implementors must unbind the current shader program when applying a program pipeline.
+pub unsafe trait ShadingGateSeparable: ShadingGate + ShaderSeparable {
+  /// Apply the program pipeline and make it currently in-use for subsequent pipeline nodes.
+  ///
+  /// A program pipeline is only used when no shader program is in-use, so implementations must unbind the current
+  /// shader program, if any.
+  ///
+  /// # Safety
+  ///
+  /// `pipeline` must have been created by this backend, with a stage for every stage the subsequent renders need.
+  unsafe fn apply_program_pipeline(&mut self, pipeline: &Self::ProgramPipelineRepr);
+}
@@ -18,7 +18,7 @@
 
 use std::ops::{Deref, DerefMut};
 
-use crate::tess::{Mode, TessError, TessIndex, TessMapError, TessVertexData};
+use crate::tess::{Mode, TessError, TessIndex, TessLevels, TessMapError, TessVertexData};
 
 /// Tessellation support on the backend.
 ///
@@ -95,6 +95,72 @@ where
   ) -> Result<(), TessError>;
 }
 
+/// Default tessellation levels of patches.
+///
+/// This trait requires [`Tess`] as super trait. Backends supporting tessellation shaders should implement it.
+///
+/// # Safety
+///
+/// Implementors must apply the default levels when rendering patches without tessellation control stage.
+pub unsafe trait TessPatchLevels<V, I, W, S>: Tess<V, I, W, S>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Default tessellation levels of the patches of the tessellation.
+  ///
+  /// # Safety
+  ///
+  /// `tess` must have been created by this backend.
+  unsafe fn default_tess_levels(tess: &Self::TessRepr) -> TessLevels;
+
+  /// Set the default tessellation levels of the patches of the tessellation.
+  ///
+  /// Those levels must be used when rendering patches without tessellation control stage.
+  ///
+  /// # Safety
+  ///
+  /// `tess` must have been created by this backend.
+  unsafe fn set_default_tess_levels(tess: &mut Self::TessRepr, levels: TessLevels);
+}
+
+/// Upload vertex data to tessellations.
+///
+/// This trait requires [`Tess`] as super trait. Unlike [`VertexSlice`], only the uploaded vertices are sent to the
+/// vertex storage, which is typically done with a single buffer update.
+///
+/// # Safety
+///
+/// Implementors must check the bounds of the upload against the vertex storage.
+pub unsafe trait VertexUpload<V, I, W, S, T>: Tess<V, I, W, S>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+  T: Copy,
+{
+  /// Upload `vertices` to the vertex storage of the tessellation, starting at the vertex at index `offset`.
+  ///
+  /// `T` is the vertex type for [`Interleaved`] memory, and the type of the uploaded attribute for [`Deinterleaved`]
+  /// memory. Fail with [`TessError::OutOfBounds`] if the vertices don’t fit in the vertex storage, and
+  /// [`TessError::AttributelessError`] if the tessellation has no vertex storage.
+  ///
+  /// # Safety
+  ///
+  /// `vertices` must not be empty, and `T` must match the vertex storage of `tess`.
+  ///
+  /// [`Interleaved`]: crate::tess::Interleaved
+  /// [`Deinterleaved`]: crate::tess::Deinterleaved
+  unsafe fn upload_vertices(
+    tess: &mut Self::TessRepr,
+    offset: usize,
+    vertices: &[T],
+  ) -> Result<(), TessError>;
+}
+
 /// Slice vertex data on CPU.
 ///
 /// This trait must be implemented by the backend so that it’s possible to _slice_ the vertex data. The idea is that the
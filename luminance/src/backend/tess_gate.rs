@@ -4,7 +4,9 @@
 //!
 //! A tessellation gate allows to render [`Tess`] objects.
 
-use crate::backend::tess::Tess;
+use crate::backend::{indirect::IndirectBuffer, tess::Tess};
+use crate::indirect::{DrawElementsIndirectCommand, IndirectCommand};
+use crate::shader::{ActiveVertexAttrib, GeometryConfig};
 use crate::tess::{TessIndex, TessVertexData};
 
 /// Trait to implement to be able to render [`Tess`] objects.
@@ -27,3 +29,162 @@ where
     inst_nb: usize,
   );
 }
+
+/// Rendering [`Tess`] objects with a base vertex.
+///
+/// This trait requires [`TessGate`] as super trait.
+///
+/// # Safety
+///
+/// Implementors must not fetch vertices outside of the vertex storage of the [`Tess`].
+pub unsafe trait TessGateBaseVertex<V, I, W, S>: TessGate<V, I, W, S>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Render the [`Tess`] like [`TessGate::render`], adding `base_vertex` to every index before fetching vertices.
+  ///
+  /// For non-indexed [`Tess`], `base_vertex` is added to `start_index` instead.
+  ///
+  /// # Safety
+  ///
+  /// Same as [`TessGate::render`]; moreover, the indices offset by `base_vertex` must be in the bounds of the vertex
+  /// storage.
+  unsafe fn render_base_vertex(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+  );
+}
+
+/// Rendering [`Tess`] objects with a base vertex and a base instance.
+///
+/// This trait requires [`TessGateBaseVertex`] as super trait.
+///
+/// # Safety
+///
+/// Implementors must not fetch instance data outside of the instance storage of the [`Tess`].
+pub unsafe trait TessGateBaseInstance<V, I, W, S>: TessGateBaseVertex<V, I, W, S>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Render the [`Tess`] like [`TessGateBaseVertex::render_base_vertex`], fetching instance data starting at the
+  /// instance `base_instance`.
+  ///
+  /// `base_instance` doesn’t change the instance index seen by shader stages.
+  ///
+  /// # Safety
+  ///
+  /// Same as [`TessGateBaseVertex::render_base_vertex`]; moreover, `base_instance + inst_nb` must not exceed the number
+  /// of instances of the instance storage.
+  unsafe fn render_base_instance(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_vertex: isize,
+    base_instance: usize,
+  );
+}
+
+/// Rendering [`Tess`] objects from draw commands stored in indirect buffers.
+///
+/// This trait requires [`TessGate`] and [`IndirectBuffer`] as super traits.
+///
+/// # Safety
+///
+/// Implementors must bind `commands` as the source of draw commands only for the duration of the render.
+pub unsafe trait TessGateIndirect<V, I, W, S>:
+  TessGate<V, I, W, S> + IndirectBuffer<DrawElementsIndirectCommand>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Render the [`Tess`] once per draw command of `commands`, picking indices as described by each command.
+  ///
+  /// # Safety
+  ///
+  /// The [`Tess`] must be indexed, `commands` must not be empty and `len` must not exceed the number of commands it
+  /// holds.
+  unsafe fn render_indirect(
+    &mut self,
+    tess: &Self::TessRepr,
+    commands: &Self::IndirectBufferRepr,
+    len: usize,
+  );
+}
+
+/// Rendering [`Tess`] objects from a single draw command stored in an indirect buffer.
+///
+/// This trait requires [`TessGate`] and [`IndirectBuffer`] as super traits.
+///
+/// # Safety
+///
+/// Implementors must bind `commands` as the source of draw commands only for the duration of the render.
+pub unsafe trait TessGateIndirectAt<V, I, W, S, C>:
+  TessGate<V, I, W, S> + IndirectBuffer<C>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+  C: IndirectCommand,
+{
+  /// Render the [`Tess`] as described by the draw command at the byte offset `offset` in `commands`.
+  ///
+  /// # Safety
+  ///
+  /// The [`Tess`] must be indexed if [`IndirectCommand::INDEXED`] is `true`, and `offset` must be aligned on 4 bytes
+  /// and leave room for a draw command in the buffer.
+  unsafe fn render_indirect_at(
+    &mut self,
+    tess: &Self::TessRepr,
+    commands: &Self::IndirectBufferRepr,
+    offset: usize,
+  );
+}
+
+/// Vertex format validation.
+///
+/// This trait allows to check the vertex format of [`Tess`] objects against the program in use before rendering them.
+///
+/// # Safety
+///
+/// Implementors must reflect the program actually in use, not the last one created.
+pub unsafe trait TessGateValidation {
+  /// List the active vertex attributes of the program currently in use, built-in ones excluded.
+  ///
+  /// If no program is in use, the list is empty.
+  ///
+  /// # Safety
+  ///
+  /// Must be called from within a shading gate.
+  unsafe fn current_vertex_attribs(&mut self) -> Vec<ActiveVertexAttrib>;
+
+  /// Get the configuration of the geometry stage of the program currently in use, if any.
+  ///
+  /// # Safety
+  ///
+  /// Must be called from within a shading gate.
+  unsafe fn current_geometry_config(&mut self) -> Option<GeometryConfig>;
+
+  /// Whether the program currently in use has tessellation stages.
+  ///
+  /// `None` is returned if no program is in use, or if it cannot be known.
+  ///
+  /// # Safety
+  ///
+  /// Must be called from within a shading gate.
+  unsafe fn current_has_tessellation_stages(&mut self) -> Option<bool>;
+}
@@ -9,11 +9,16 @@
 //! - The rest of the abstraction, bigger, is [`Texture`].
 //!
 //! You will have to implement both traits to be able to use textures.
+//!
+//! Backends supporting bindless textures can also implement [`TextureBindless`], backends supporting compressed
+//! pixel formats can implement [`TextureCompressed`], and backends supporting texture views can implement
+//! [`TextureView`].
 
 use crate::{
-  pixel::Pixel,
-  texture::{Dimensionable, Sampler, TexelUpload, TextureError},
+  pixel::{CompressedPixel, Pixel},
+  texture::{Dim2, Dimensionable, Sampler, TexelUpload, TextureError},
 };
+use std::ops::Range;
 
 /// Type family giving the backend representation type.
 ///
@@ -107,6 +112,64 @@ where
     texels: TexelUpload<[P::RawEncoding]>,
   ) -> Result<(), TextureError>;
 
+  /// Upload texels to a part of a single mipmap level of a texture.
+  ///
+  /// `level` is `0` for the base level. The backend must check that enough texels are provided. Mipmaps are not
+  /// regenerated.
+  ///
+  /// # Safety
+  ///
+  /// `level` must be in the bounds of `texture`, and the part formed with `offset` and `size` in the bounds of that
+  /// level.
+  unsafe fn upload_part_level(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+    texels: &[P::Encoding],
+  ) -> Result<(), TextureError>;
+
+  /// Upload raw texels to a part of a single mipmap level of a texture.
+  ///
+  /// This is the same as [`Texture::upload_part_level`], but with the _raw encoding_ of the texels.
+  ///
+  /// # Safety
+  ///
+  /// Same as [`Texture::upload_part_level`].
+  unsafe fn upload_part_level_raw(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+    texels: &[P::RawEncoding],
+  ) -> Result<(), TextureError>;
+
+  /// Generate the mipmap levels of a texture out of its base level.
+  ///
+  /// Textures without mipmaps must be left untouched.
+  ///
+  /// # Safety
+  ///
+  /// `texture` must have been created by this backend.
+  unsafe fn generate_mipmaps(texture: &mut Self::TextureRepr) -> Result<(), TextureError>;
+
+  /// Get a copy of the texels stored in the base level of the texture.
+  ///
+  /// The texels are tightly packed, row after row, layer after layer — faces, for cubemaps, in [`CubeFace::index`]
+  /// order.
+  ///
+  /// # Safety
+  ///
+  /// `size` must be the size of the base level of `texture`.
+  ///
+  /// [`CubeFace::index`]: crate::texture::CubeFace::index
+  unsafe fn get_texels(
+    texture: &Self::TextureRepr,
+    size: D::Size,
+  ) -> Result<Vec<P::Encoding>, TextureError>
+  where
+    P::Encoding: Copy + Default;
+
   /// Get a copy of the raw texels stored in the texture.
   ///
   /// `size` will match the actual size of the texture, you do not need to cache it.
@@ -137,3 +200,129 @@ where
     texel: TexelUpload<[P::RawEncoding]>,
   ) -> Result<(), TextureError>;
 }
+
+/// Bindless texture interface.
+///
+/// Bindless textures are accessed in shaders via a handle instead of being bound to a texture unit.
+///
+/// # Safety
+///
+/// Implementors must keep handles valid while the texture is resident.
+pub unsafe trait TextureBindless<D, P>: Texture<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Make the texture resident and return its handle.
+  ///
+  /// Calling this method on an already resident texture must return the same handle.
+  ///
+  /// # Safety
+  ///
+  /// `texture` must outlive the use of the returned handle in shader stages.
+  unsafe fn make_resident(texture: &mut Self::TextureRepr) -> Result<u64, TextureError>;
+
+  /// Make the texture non-resident.
+  ///
+  /// Calling this method on a non-resident texture must do nothing.
+  ///
+  /// # Safety
+  ///
+  /// The handle of `texture` must not be used by shader stages anymore.
+  unsafe fn make_non_resident(texture: &mut Self::TextureRepr);
+}
+
+/// Compressed texture interface.
+///
+/// Compressed textures are created and uploaded to with already compressed data, whose size has already been
+/// validated against the [`CompressedFormat`] of the pixel type.
+///
+/// # Safety
+///
+/// Implementors must upload the data as-is, with the [`CompressedFormat`] of `P`.
+///
+/// [`CompressedFormat`]: crate::pixel::CompressedFormat
+pub unsafe trait TextureCompressed<P>: Texture<Dim2, P>
+where
+  P: CompressedPixel,
+{
+  /// Create a new compressed texture with the data of each of its mipmap levels, starting with the base level.
+  ///
+  /// # Safety
+  ///
+  /// `levels` must not be empty, and each level must hold the data of its whole size in the compressed format
+  /// of `P`.
+  unsafe fn new_compressed_texture(
+    &mut self,
+    size: [u32; 2],
+    sampler: Sampler,
+    levels: &[&[u8]],
+  ) -> Result<Self::TextureRepr, TextureError>;
+
+  /// Upload compressed data to a region of the base level of the texture.
+  ///
+  /// # Safety
+  ///
+  /// The region must be in the bounds of the base level, and `data` must hold the data of the whole region in the
+  /// compressed format of `P`.
+  unsafe fn upload_compressed_part(
+    texture: &mut Self::TextureRepr,
+    offset: [u32; 2],
+    size: [u32; 2],
+    data: &[u8],
+  ) -> Result<(), TextureError>;
+}
+
+/// Texture view interface.
+///
+/// A view shares the storage of another texture, reinterpreted with the dimension `D` and the pixel format `P`.
+///
+/// # Safety
+///
+/// Implementors must keep the storage alive while any view of it is alive.
+pub unsafe trait TextureView<D, P>: Texture<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Create a view of the `levels` mipmap levels and `layers` layers of `texture`.
+  ///
+  /// Cubemap faces are selected as layers.
+  ///
+  /// # Safety
+  ///
+  /// The ranges must not be empty and must be in the bounds of `texture`, and the pixel format of `texture` must be
+  /// compatible with `P`.
+  unsafe fn new_texture_view(
+    texture: &Self::TextureRepr,
+    levels: Range<usize>,
+    layers: Range<u32>,
+  ) -> Result<Self::TextureRepr, TextureError>;
+}
+
+/// Texture copy interface.
+///
+/// Texels are copied between textures on the GPU, without going through the CPU.
+///
+/// # Safety
+///
+/// Implementors must copy the texels without converting them.
+pub unsafe trait TextureCopy<D, P>: Texture<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Copy the region of the base level of `src` made with `src_offset` and `size` to the base level of `dst`, at
+  /// `dst_offset`.
+  ///
+  /// # Safety
+  ///
+  /// The region must be in the bounds of both textures.
+  unsafe fn copy_texture(
+    src: &Self::TextureRepr,
+    dst: &mut Self::TextureRepr,
+    src_offset: D::Offset,
+    size: D::Size,
+    dst_offset: D::Offset,
+  ) -> Result<(), TextureError>;
+}
@@ -0,0 +1,68 @@
+//! Transform feedback backend interface.
+//!
+//! This interface defines the low-level API transform feedback objects must implement to be usable.
+
+use crate::{
+  backend::tess::Tess,
+  tess::{Interleaved, TessIndex, TessVertexData},
+  transform_feedback::TransformFeedbackError,
+};
+
+/// Transform feedback objects.
+///
+/// # Safety
+///
+/// Implementors must release the transform feedback object when its representation is dropped.
+pub unsafe trait TransformFeedback {
+  /// Backend representation of a transform feedback object.
+  type TransformFeedbackRepr;
+
+  /// Create a new transform feedback object.
+  ///
+  /// # Safety
+  ///
+  /// Must be called from the thread owning the backend context.
+  unsafe fn new_transform_feedback(
+    &mut self,
+  ) -> Result<Self::TransformFeedbackRepr, TransformFeedbackError>;
+}
+
+/// Capture of transform feedback into a [`Tess`].
+///
+/// This trait requires [`TransformFeedback`] and [`Tess`] as super traits.
+///
+/// # Safety
+///
+/// Implementors must restore rasterization once a capture ends.
+pub unsafe trait TransformFeedbackCapture<V, I, W>:
+  TransformFeedback + Tess<V, I, W, Interleaved>
+where
+  V: TessVertexData<Interleaved>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved>,
+{
+  /// Start capturing the vertices output by the subsequent renders into the vertex buffer of `output`.
+  ///
+  /// The primitives are captured as independent primitives of the primitive mode of `output`. Rasterization must be disabled
+  /// until [`TransformFeedbackCapture::end_capture`] is called.
+  ///
+  /// # Safety
+  ///
+  /// No capture must be running, and the primitive mode of `output` must be points, lines or triangles.
+  unsafe fn begin_capture(
+    &mut self,
+    feedback: &mut Self::TransformFeedbackRepr,
+    output: &mut Self::TessRepr,
+  ) -> Result<(), TransformFeedbackError>;
+
+  /// Stop capturing vertices, and enable rasterization back.
+  ///
+  /// # Safety
+  ///
+  /// A capture started with [`TransformFeedbackCapture::begin_capture`] must be running.
+  unsafe fn end_capture(
+    &mut self,
+    feedback: &mut Self::TransformFeedbackRepr,
+    output: &mut Self::TessRepr,
+  );
+}
@@ -0,0 +1,47 @@
+//! Color masks and related types.
+//!
+//! A color mask selects which channels of the color attachments are written to when rendering. It can be set for all
+//! the color attachments of a framebuffer at once, or for a specific one; masking all the channels of a color
+//! attachment disables writing to it, which is handy to render to only some targets of a multiple render targets
+//! framebuffer without having to create another framebuffer.
+
+/// Channels of a color attachment to write to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ColorMask {
+  /// Whether the red channel is written to.
+  pub red: bool,
+
+  /// Whether the green channel is written to.
+  pub green: bool,
+
+  /// Whether the blue channel is written to.
+  pub blue: bool,
+
+  /// Whether the alpha channel is written to.
+  pub alpha: bool,
+}
+
+impl ColorMask {
+  /// Write to all channels.
+  pub const ALL: Self = ColorMask::new(true, true, true, true);
+
+  /// Write to no channel, disabling writing to the color attachment.
+  pub const NONE: Self = ColorMask::new(false, false, false, false);
+
+  /// Create a new [`ColorMask`].
+  pub const fn new(red: bool, green: bool, blue: bool, alpha: bool) -> Self {
+    ColorMask {
+      red,
+      green,
+      blue,
+      alpha,
+    }
+  }
+}
+
+impl Default for ColorMask {
+  /// [`ColorMask::ALL`].
+  fn default() -> Self {
+    ColorMask::ALL
+  }
+}
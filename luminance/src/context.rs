@@ -49,9 +49,13 @@ use crate::{
     depth_stencil_slot::DepthStencilSlot,
     framebuffer::Framebuffer as FramebufferBackend,
     query::Query as QueryBackend,
-    shader::{Shader, ShaderData as ShaderDataBackend},
+    shader::{
+      AtomicCounters as AtomicCountersBackend, Shader, ShaderData as ShaderDataBackend,
+      ShaderSeparable as ShaderSeparableBackend,
+    },
     tess::Tess as TessBackend,
     texture::Texture as TextureBackend,
+    transform_feedback::TransformFeedback as TransformFeedbackBackend,
   },
   texture::TexelUpload,
 };
@@ -60,9 +64,13 @@ use crate::{
   pipeline::PipelineGate,
   pixel::Pixel,
   query::Query,
-  shader::{ProgramBuilder, ShaderData, ShaderDataError, Stage, StageError, StageType},
+  shader::{
+    AtomicCounters, ProgramBuilder, ProgramError, ProgramPipeline, ShaderData, ShaderDataError,
+    Stage, StageError, StageType,
+  },
   tess::{Deinterleaved, Interleaved, TessBuilder, TessVertexData},
   texture::{Dimensionable, Sampler, Texture, TextureError},
+  transform_feedback::{TransformFeedback, TransformFeedbackError},
   vertex::Semantics,
 };
 
@@ -148,6 +156,41 @@ pub unsafe trait GraphicsContext: Sized {
     ShaderData::new(self, values)
   }
 
+  /// Create new atomic counters.
+  ///
+  /// See the documentation of [`AtomicCounters::new`] for further details.
+  fn new_atomic_counters(
+    &mut self,
+    values: impl IntoIterator<Item = u32>,
+  ) -> Result<AtomicCounters<Self::Backend>, ShaderDataError>
+  where
+    Self::Backend: AtomicCountersBackend,
+  {
+    AtomicCounters::new(self, values)
+  }
+
+  /// Create a new, empty program pipeline.
+  ///
+  /// See the documentation of [`ProgramPipeline::new`] for further details.
+  fn new_program_pipeline(&mut self) -> Result<ProgramPipeline<Self::Backend>, ProgramError>
+  where
+    Self::Backend: ShaderSeparableBackend,
+  {
+    ProgramPipeline::new(self)
+  }
+
+  /// Create a new transform feedback object.
+  ///
+  /// See the documentation of [`TransformFeedback::new`] for further details.
+  fn new_transform_feedback(
+    &mut self,
+  ) -> Result<TransformFeedback<Self::Backend>, TransformFeedbackError>
+  where
+    Self::Backend: TransformFeedbackBackend,
+  {
+    TransformFeedback::new(self)
+  }
+
   /// Create a [`TessBuilder`].
   ///
   /// See the documentation of [`TessBuilder::new`] for further details.
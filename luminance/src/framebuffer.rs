@@ -31,6 +31,21 @@
 //! passing a reference on your [`Framebuffer`]. Once the pipeline is done, the [`Framebuffer`]
 //! contains the result of the render.
 //!
+//! # Back buffer
+//!
+//! The _back buffer_ is the default framebuffer of the window — or canvas — the context renders
+//! to. It is a [`BackBuffer`], created with [`Framebuffer::back_buffer`] with the size of the
+//! window; it has no slots, as its color and depth buffers belong to the window. It is used in
+//! pipelines like any other framebuffer, and is typically created once and kept up to date with
+//! [`Framebuffer::resize`] when the window is resized, which doesn’t allocate anything:
+//!
+//! ```ignore
+//! let mut back_buffer = BackBuffer::back_buffer(&mut ctx, [width, height])?;
+//!
+//! // when the window is resized
+//! back_buffer.resize([new_width, new_height])?;
+//! ```
+//!
 //! # Manipulating slots
 //!
 //! Slots’ types depend entirely on the types you choose in [`Framebuffer`]. The rule is that any
@@ -45,6 +60,120 @@
 //! slot via [`Framebuffer::depth_slot`]. Once you get textures from the color slots, you can use
 //! them as regular textures as input of next renders, for instance.
 //!
+//! If the backend supports it, a slot can also be a [`Renderbuffer`] of a pixel format, such as
+//! `Renderbuffer<Depth32F>`. Renderbuffers are cheaper than textures but cannot be sampled, so they
+//! are typically used for depth buffers which are only used for depth testing. They give no
+//! texture — their associated type is `()` — and are only available for 2D framebuffers. In a
+//! tuple of color slots, a renderbuffer takes the attachment of its rank, like textures.
+//!
+//! Stencil-based techniques, such as portals or decals, require a depth slot with stencil
+//! information: either a combined depth/stencil format — [`Depth24Stencil8`] or
+//! [`Depth32FStencil8`] — or, if no depth test is needed, a `Renderbuffer<Stencil8>`.
+//!
+//! # Layered framebuffers
+//!
+//! Framebuffers of 3D, array or cubemap dimensions — [`Dim3`], [`Dim1Array`], [`Dim2Array`] and
+//! [`Cubemap`] — hold several _layers_; the layers of a cubemap are its faces. Depending on the
+//! backend, renders go either to all the layers at once — a geometry stage then selects the layer
+//! of each primitive — or to a single one. If the backend supports it,
+//! [`Framebuffer::select_layer`] allows to choose the layer to render to, for instance to fill a
+//! 3D texture slice by slice, and [`Framebuffer::select_face`] the face of a cubemap to render to.
+//!
+//! Layered rendering is selected when framebuffers are created, if the backend supports it. The
+//! geometry stage routes each primitive to a layer by writing the `gl_Layer` built-in variable,
+//! so that, for instance, the six faces of a point-light shadow map are rendered in a single pass:
+//!
+//! ```glsl
+//! layout (triangles) in;
+//! layout (triangle_strip, max_vertices = 18) out;
+//!
+//! uniform mat4 face_views[6];
+//!
+//! void main() {
+//!   for (int face = 0; face < 6; ++face) {
+//!     gl_Layer = face;
+//!
+//!     for (int i = 0; i < 3; ++i) {
+//!       gl_Position = face_views[face] * gl_in[i].gl_Position;
+//!       EmitVertex();
+//!     }
+//!
+//!     EndPrimitive();
+//!   }
+//! }
+//! ```
+//!
+//! Here, the framebuffer would be a `Framebuffer<Cubemap, (), Depth32F>`, whose depth slot is the
+//! shadow map. Framebuffers without depth slot get a layered depth buffer from the backend as well.
+//! Backends without layered rendering — such as WebGL2, which has no geometry stage — select the
+//! first layer instead.
+//!
+//! # Mipmap levels
+//!
+//! Framebuffers render to the base mipmap level of their textures by default. If the backend
+//! supports it, [`Framebuffer::select_level`] selects another level to render to; the size of the
+//! framebuffer, and thus its viewport, becomes the size of that level. A mipmap chain is then
+//! generated manually — with a custom filter, for instance to downsample a bloom texture — by
+//! rendering each level from the previous one:
+//!
+//! ```ignore
+//! for level in 1..=mipmaps {
+//!   framebuffer.select_level(level)?;
+//!   // sample the `level - 1` level of the color slot through a texture view of that level, and
+//!   // render a full-screen quad to the framebuffer
+//! }
+//! ```
+//!
+//! Mipmap levels and layers are selected independently, so that a single layer of a mipmap level
+//! of an array texture — a tile of an atlas being baked, for instance — can be rendered to.
+//!
+//! # Multisampled framebuffers
+//!
+//! A [`MultisampleFramebuffer`] stores several samples per pixel, for anti-aliased (MSAA)
+//! off-screen rendering. It is rendered to like any other framebuffer — via
+//! [`MultisampleFramebuffer::framebuffer`] — but its storage cannot be sampled: once the render
+//! is done, the samples are averaged into the color slots of a regular [`Framebuffer`] of the
+//! same size — or the back buffer — with [`MultisampleFramebuffer::resolve`].
+//!
+//! # Blitting
+//!
+//! If the backend supports it, [`Framebuffer::blit_to`] copies a rectangular region of a 2D
+//! framebuffer into a region of another one, such as the back buffer. The color, depth and stencil
+//! buffers to copy are selected with a [`BlitMask`]; if the regions have different sizes, the
+//! copy is scaled with the given filter. Blitting from the framebuffer of a
+//! [`MultisampleFramebuffer`] resolves its samples, like [`MultisampleFramebuffer::resolve`], but
+//! allows to resolve depth as well.
+//!
+//! # Reading pixels
+//!
+//! If the backend supports it, the pixels of a region of a 2D framebuffer are read back with
+//! [`Framebuffer::read_color_slot`] and [`Framebuffer::read_depth`], for GPU picking or to check
+//! the result of a render in tests. The pixel format to read must be the one of the attachment:
+//! colors of the back buffer are read as [`NormRGBA8UI`], and depth buffers allocated by the
+//! backend — for `()` depth slots — as [`Depth32F`]. Reading pixels stalls until the GPU is
+//! done rendering to the framebuffer, so it should not be done every frame.
+//!
+//! To avoid stalling, [`Framebuffer::read_color_slot_async`] and [`Framebuffer::read_depth_async`]
+//! only schedule the read, and return a [`PixelReadback`] whose pixels are usually ready a frame
+//! or two later. Reading pixels every frame — to capture a video, for instance — is then done by
+//! keeping a couple of readbacks in flight:
+//!
+//! ```ignore
+//! let mut pending = VecDeque::new();
+//!
+//! loop {
+//!   // … render to framebuffer …
+//!
+//!   pending.push_back(framebuffer.read_color_slot_async::<NormRGBA8UI>(0, region)?);
+//!
+//!   // double buffering: only wait for a readback once the next one is scheduled
+//!   if pending.len() > 2 || pending.front().map_or(false, PixelReadback::is_ready) {
+//!     let pixels = pending.pop_front().unwrap().pixels()?;
+//!     // … encode pixels …
+//!   }
+//! }
+//! ```
+//!
 //! ## Note on type generation
 //!
 //! Because framebuffers are highly subject to refinement typing, types are transformed at
@@ -59,20 +188,67 @@
 //! All this look a bit magical but the type-system ensures it’s total and not as magic as you
 //! might think.
 //!
+//! Arrays of a single pixel format work the same way: an `[RGBA32F; 4]` color slot gives an array
+//! of four textures. This is handy for _multiple render targets_, as used by deferred shading:
+//!
+//! ```ignore
+//! // albedo, normals and positions of a G-buffer
+//! let mut g_buffer = Framebuffer::<Dim2, (NormRGBA8UI, RGBA32F, RGBA32F), Depth32F>::new(
+//!   &mut ctx,
+//!   [800, 600],
+//!   0,
+//!   Sampler::default(),
+//! )?;
+//!
+//! // … render to g_buffer, the fragment shader writing its outputs 0, 1 and 2 …
+//!
+//! let (albedo, normals, positions) = g_buffer.color_slot();
+//! ```
+//!
+//! Each pixel format is attached to the color attachment of the same index, which receives the
+//! fragment shader output at the same location. Backends support a limited number of color
+//! attachments — at least 8 on OpenGL 3.3 and 4 on WebGL2 — and creating a framebuffer with more
+//! fails with [`FramebufferError::TooManyColorAttachments`].
+//!
 //! [backend::color_slot]: crate::backend::color_slot
 //! [backend::depth_slot]: crate::backend::depth_slot
 //! [`PipelineGate`]: crate::pipeline::PipelineGate
+//! [`Dim3`]: crate::texture::Dim3
+//! [`Dim1Array`]: crate::texture::Dim1Array
+//! [`Dim2Array`]: crate::texture::Dim2Array
+//! [`Cubemap`]: crate::texture::Cubemap
+//! [`Depth24Stencil8`]: crate::pixel::Depth24Stencil8
+//! [`Depth32FStencil8`]: crate::pixel::Depth32FStencil8
+//! [`NormRGBA8UI`]: crate::pixel::NormRGBA8UI
+//! [`Depth32F`]: crate::pixel::Depth32F
 
-use std::{error, fmt};
+#[cfg(feature = "image")]
+use crate::pixel::{
+  NormR8UI, NormRGB8UI, R16F, R32F, RGB16F, RGB32F, RGBA16F, RGBA32F, SRGB8UI, SRGBA8UI,
+};
+#[cfg(feature = "image")]
+use std::path::Path;
+use std::{error, fmt, marker::PhantomData, time::Duration};
 
 use crate::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      Framebuffer as FramebufferBackend, FramebufferBackBuffer,
+      FramebufferBlit as FramebufferBlitBackend, FramebufferLayer as FramebufferLayerBackend,
+      FramebufferLevel as FramebufferLevelBackend,
+      FramebufferMultisample as FramebufferMultisampleBackend,
+      FramebufferRead as FramebufferReadBackend, FramebufferReadback as FramebufferReadbackBackend,
+      FramebufferResize as FramebufferResizeBackend,
+    },
   },
   context::GraphicsContext,
-  texture::{Dim2, Dimensionable, Sampler, TextureError},
+  pixel::{
+    Depth16, Depth24, Depth24Stencil8, Depth32F, Depth32FStencil8, DepthPixel, NormRGBA8UI, Pixel,
+    PixelFormat,
+  },
+  texture::{CubeFace, Cubemap, Dim, Dim2, Dimensionable, MagFilter, Sampler, TextureError},
 };
 
 /// Typed framebuffers.
@@ -95,6 +271,7 @@ where
   pub(crate) repr: B::FramebufferRepr,
   color_slot: CS::ColorTextures,
   depth_stencil_slot: DS::DepthStencilTexture,
+  mipmaps: usize,
 }
 
 impl<B, D, CS, DS> Framebuffer<B, D, CS, DS>
@@ -143,15 +320,39 @@ where
         repr,
         color_slot,
         depth_stencil_slot: depth_slot,
+        mipmaps,
       })
     }
   }
 
   /// Get the size of the framebuffer.
+  ///
+  /// This is the size of the mipmap level rendered to — see [`Framebuffer::select_level`].
   pub fn size(&self) -> D::Size {
     unsafe { B::framebuffer_size(&self.repr) }
   }
 
+  /// Resize the framebuffer.
+  ///
+  /// The textures of the color and depth/stencil slots are resized in place — their content is lost — so that they can
+  /// keep being used, and the storage allocated by the framebuffer, such as renderbuffers, is reallocated. This is
+  /// typically done when the window is resized. `size` is the size of the base mipmap level; the selected level is
+  /// kept.
+  ///
+  /// A [`FramebufferError`] is returned if the textures cannot be resized or if the resized framebuffer is incomplete.
+  pub fn resize(&mut self, size: D::Size) -> Result<(), FramebufferError>
+  where
+    B: FramebufferResizeBackend<D>,
+  {
+    unsafe {
+      B::resize_framebuffer(&mut self.repr, size)?;
+      CS::resize_color_textures(&mut self.color_slot, size, &mut self.repr, 0)?;
+      DS::resize_depth_texture(&mut self.depth_stencil_slot, size, &mut self.repr)?;
+
+      B::validate_resized_framebuffer(&mut self.repr)
+    }
+  }
+
   /// Access the carried color slot.
   pub fn color_slot(&mut self) -> &mut CS::ColorTextures {
     &mut self.color_slot
@@ -176,13 +377,484 @@ where
   pub fn into_depth_stencil_slot(self) -> DS::DepthStencilTexture {
     self.depth_stencil_slot
   }
+
+  /// Select the layer of a layered framebuffer to render to.
+  ///
+  /// `Some(layer)` attaches only the given layer of the color and depth slots, while `None` attaches all of them, for
+  /// layered rendering. See the [module documentation](crate::framebuffer#layered-framebuffers) for further details.
+  ///
+  /// [`FramebufferError::InvalidLayer`] is returned if the layer is out of bounds, and
+  /// [`FramebufferError::UnsupportedAttachment`] if the framebuffer is not layered or the backend cannot attach layers
+  /// that way. If the framebuffer would be incomplete, the error is returned and the previous layers are kept.
+  pub fn select_layer(&mut self, layer: Option<u32>) -> Result<(), FramebufferError>
+  where
+    B: FramebufferLayerBackend<D>,
+  {
+    let layers = layer_count::<D>(self.size()).ok_or(FramebufferError::UnsupportedAttachment)?;
+
+    if let Some(layer) = layer {
+      if layer >= layers {
+        return Err(FramebufferError::invalid_layer(layer, layers));
+      }
+    }
+
+    unsafe { B::select_layer(&mut self.repr, layer) }
+  }
+
+  /// Select the mipmap level of the color and depth slots to render to.
+  ///
+  /// The framebuffer then renders to the `level` mipmap level of its textures, and its [size](Framebuffer::size)
+  /// becomes the size of that level. The storage allocated by the backend, such as the depth buffer of `()` depth slots,
+  /// stays attached. See the [module documentation](crate::framebuffer#mipmap-levels) for further details.
+  ///
+  /// [`FramebufferError::InvalidLevel`] is returned if the level is out of bounds: framebuffers have `mipmaps + 1`
+  /// levels, `mipmaps` being the argument of [`Framebuffer::new`]. If the framebuffer would be incomplete, the error is
+  /// returned and the previous level is kept.
+  pub fn select_level(&mut self, level: usize) -> Result<(), FramebufferError>
+  where
+    B: FramebufferLevelBackend<D>,
+  {
+    let levels = self.mipmaps + 1;
+
+    if level >= levels {
+      return Err(FramebufferError::invalid_level(level, levels));
+    }
+
+    unsafe { B::select_level(&mut self.repr, level) }
+  }
+}
+
+impl<B, CS, DS> Framebuffer<B, Dim2, CS, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2>,
+  CS: ColorSlot<B, Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  /// Copy the `src` region of this framebuffer into the `dst` region of `target`.
+  ///
+  /// The buffers selected by `mask` are copied; color attachments are copied by pairs of the same index, and only the
+  /// first one is copied if either framebuffer is the back buffer. If the regions have different sizes, the copy is
+  /// scaled with `filter`. See the [module documentation](crate::framebuffer#blitting) for further details.
+  ///
+  /// [`FramebufferError::CannotBlit`] is returned if a region is out of the bounds of its framebuffer, if depth or
+  /// stencil — or integral color formats — are copied with [`MagFilter::Linear`], if depth or stencil are copied
+  /// between different depth formats, or if the backend cannot perform the copy, such as scaled copies from
+  /// multisampled framebuffers.
+  pub fn blit_to<TCS, TDS>(
+    &self,
+    target: &mut Framebuffer<B, Dim2, TCS, TDS>,
+    src: Region,
+    dst: Region,
+    mask: BlitMask,
+    filter: MagFilter,
+  ) -> Result<(), FramebufferError>
+  where
+    B: FramebufferBlitBackend,
+    TCS: ColorSlot<B, Dim2>,
+    TDS: DepthStencilSlot<B, Dim2>,
+  {
+    src
+      .check_bounds(self.size())
+      .map_err(FramebufferError::cannot_blit)?;
+    dst
+      .check_bounds(target.size())
+      .map_err(FramebufferError::cannot_blit)?;
+
+    if mask.is_empty() {
+      return Ok(());
+    }
+
+    if filter == MagFilter::Linear {
+      if mask.depth || mask.stencil {
+        return Err(FramebufferError::cannot_blit(
+          "depth and stencil can only be blitted with nearest filtering",
+        ));
+      }
+
+      let integral = CS::color_formats()
+        .into_iter()
+        .chain(TCS::color_formats())
+        .any(|pf| pf.is_integral());
+
+      if integral {
+        return Err(FramebufferError::cannot_blit(
+          "integral color formats can only be blitted with nearest filtering",
+        ));
+      }
+    }
+
+    if mask.depth || mask.stencil {
+      // the formats of () depth slots are chosen by the backend, so they are not checked
+      if let (Some(source), Some(target)) = (DS::depth_format(), TDS::depth_format()) {
+        if source != target {
+          return Err(FramebufferError::cannot_blit(format!(
+            "cannot blit depth and stencil from {:?} to {:?}",
+            source, target
+          )));
+        }
+      }
+    }
+
+    unsafe { B::blit(&self.repr, &mut target.repr, src, dst, mask, filter) }
+  }
+
+  /// Read the pixels of the `region` of the color attachment at `index`.
+  ///
+  /// `P` must be the pixel format of the attachment, or [`NormRGBA8UI`] to read the color buffer of
+  /// the back buffer. Pixels are returned row by row, starting with the bottom row of the region.
+  ///
+  /// [`FramebufferError::CannotRead`] is returned if `region` is out of the bounds of the
+  /// framebuffer, if there is no color attachment at `index` or if its pixel format is not `P`,
+  /// or if the backend cannot read the attachment, such as multisampled ones.
+  pub fn read_color_slot<P>(
+    &self,
+    index: usize,
+    region: Region,
+  ) -> Result<Vec<P::Encoding>, FramebufferError>
+  where
+    B: FramebufferReadBackend,
+    P: Pixel,
+    P::Encoding: Copy + Default,
+  {
+    self.check_color_read::<P>(index, region)?;
+    unsafe { B::read_pixels::<P>(&self.repr, Some(index), region) }
+  }
+
+  /// Start reading the pixels of the `region` of the color attachment at `index`, without waiting
+  /// for the GPU to be done rendering to the framebuffer.
+  ///
+  /// See [`Framebuffer::read_color_slot`] for further details.
+  ///
+  /// The same errors as [`Framebuffer::read_color_slot`] are returned.
+  pub fn read_color_slot_async<P>(
+    &self,
+    index: usize,
+    region: Region,
+  ) -> Result<PixelReadback<B, P::Encoding>, FramebufferError>
+  where
+    B: FramebufferReadbackBackend,
+    P: Pixel,
+  {
+    self.check_color_read::<P>(index, region)?;
+    unsafe { B::read_pixels_async::<P>(&self.repr, Some(index), region) }.map(PixelReadback::new)
+  }
+
+  /// Pixel format of the color attachment at `index`.
+  fn color_format(index: usize) -> Result<PixelFormat, FramebufferError> {
+    // framebuffers without color slot, such as the back buffer, have a single RGBA color buffer
+    let formats = CS::color_formats();
+
+    if formats.is_empty() && index == 0 {
+      Ok(NormRGBA8UI::pixel_format())
+    } else {
+      formats.get(index).copied().ok_or_else(|| {
+        FramebufferError::cannot_read(format!("no color attachment at index {}", index))
+      })
+    }
+  }
+
+  /// Save the color attachment at `index` to a PNG file at `path`.
+  ///
+  /// Pixels are read with [`Framebuffer::read_color_slot`] and written top row first, as expected by image files.
+  /// Floating attachments hold linear colors: they are clamped to `[0; 1]` and encoded in sRGB, except for their alpha
+  /// channel. The other supported attachments — normalized 8-bit and sRGB ones — are written as is. The alpha channel of
+  /// the back buffer is not meaningful, so it is dropped.
+  ///
+  /// Only available with the `"image"` feature.
+  ///
+  /// The errors of [`Framebuffer::read_color_slot`] are returned, and [`FramebufferError::CannotSave`] is returned if
+  /// the pixel format of the attachment is not supported — integral and two channels ones — or if the file cannot be
+  /// written.
+  #[cfg(feature = "image")]
+  pub fn save_color_slot(
+    &self,
+    index: usize,
+    path: impl AsRef<Path>,
+  ) -> Result<(), FramebufferError>
+  where
+    B: FramebufferReadBackend,
+  {
+    use image::ColorType;
+
+    let [width, height] = self.size();
+    let region = Region::whole([width, height]);
+    let format = Self::color_format(index)?;
+
+    let (bytes, color_type): (Vec<u8>, _) = if CS::color_formats().is_empty() {
+      let pixels = self.read_color_slot::<NormRGBA8UI>(index, region)?;
+      let bytes = pixels.into_iter().flat_map(|[r, g, b, _]| [r, g, b]);
+      (bytes.collect(), ColorType::Rgb8)
+    } else if format == NormR8UI::pixel_format() {
+      (
+        self.read_color_slot::<NormR8UI>(index, region)?,
+        ColorType::L8,
+      )
+    } else if format == NormRGB8UI::pixel_format() {
+      let pixels = self.read_color_slot::<NormRGB8UI>(index, region)?;
+      (pixels.into_iter().flatten().collect(), ColorType::Rgb8)
+    } else if format == SRGB8UI::pixel_format() {
+      let pixels = self.read_color_slot::<SRGB8UI>(index, region)?;
+      (pixels.into_iter().flatten().collect(), ColorType::Rgb8)
+    } else if format == NormRGBA8UI::pixel_format() {
+      let pixels = self.read_color_slot::<NormRGBA8UI>(index, region)?;
+      (pixels.into_iter().flatten().collect(), ColorType::Rgba8)
+    } else if format == SRGBA8UI::pixel_format() {
+      let pixels = self.read_color_slot::<SRGBA8UI>(index, region)?;
+      (pixels.into_iter().flatten().collect(), ColorType::Rgba8)
+    } else if format == R16F::pixel_format() || format == R32F::pixel_format() {
+      let pixels = if format == R16F::pixel_format() {
+        self.read_color_slot::<R16F>(index, region)?
+      } else {
+        self.read_color_slot::<R32F>(index, region)?
+      };
+
+      (
+        pixels.into_iter().map(linear_to_srgb).collect(),
+        ColorType::L8,
+      )
+    } else if format == RGB16F::pixel_format() || format == RGB32F::pixel_format() {
+      let pixels = if format == RGB16F::pixel_format() {
+        self.read_color_slot::<RGB16F>(index, region)?
+      } else {
+        self.read_color_slot::<RGB32F>(index, region)?
+      };
+      let bytes = pixels.into_iter().flatten().map(linear_to_srgb);
+
+      (bytes.collect(), ColorType::Rgb8)
+    } else if format == RGBA16F::pixel_format() || format == RGBA32F::pixel_format() {
+      let pixels = if format == RGBA16F::pixel_format() {
+        self.read_color_slot::<RGBA16F>(index, region)?
+      } else {
+        self.read_color_slot::<RGBA32F>(index, region)?
+      };
+      let bytes = pixels.into_iter().flat_map(|[r, g, b, a]| {
+        [
+          linear_to_srgb(r),
+          linear_to_srgb(g),
+          linear_to_srgb(b),
+          (a.clamp(0., 1.) * 255.).round() as u8,
+        ]
+      });
+
+      (bytes.collect(), ColorType::Rgba8)
+    } else {
+      return Err(FramebufferError::cannot_save(format!(
+        "unsupported {:?} color attachment",
+        format
+      )));
+    };
+
+    // pixels are read starting with the bottom row
+    let row_len = bytes.len() / height.max(1) as usize;
+    let bytes: Vec<u8> = bytes
+      .chunks(row_len.max(1))
+      .rev()
+      .flatten()
+      .copied()
+      .collect();
+
+    image::save_buffer_with_format(
+      path,
+      &bytes,
+      width,
+      height,
+      color_type,
+      image::ImageFormat::Png,
+    )
+    .map_err(|e| FramebufferError::cannot_save(e.to_string()))
+  }
+
+  /// Check that the `region` of the color attachment at `index` can be read as `P` pixels.
+  fn check_color_read<P>(&self, index: usize, region: Region) -> Result<(), FramebufferError>
+  where
+    P: Pixel,
+  {
+    region
+      .check_bounds(self.size())
+      .map_err(FramebufferError::cannot_read)?;
+
+    let format = Self::color_format(index)?;
+
+    if format != P::pixel_format() {
+      return Err(FramebufferError::cannot_read(format!(
+        "cannot read {:?} pixels from a {:?} color attachment",
+        P::pixel_format(),
+        format
+      )));
+    }
+
+    Ok(())
+  }
+
+  /// Read the pixels of the `region` of the depth attachment.
+  ///
+  /// `P` must be the pixel format of the depth/stencil slot, or [`Depth32F`] for `()` depth/stencil
+  /// slots. Pixels are returned row by row, starting with the bottom row of the region.
+  ///
+  /// [`FramebufferError::CannotRead`] is returned if `region` is out of the bounds of the
+  /// framebuffer, if the pixel format of the depth/stencil slot is not `P`, or if the backend
+  /// cannot read the attachment, such as multisampled ones.
+  pub fn read_depth<P>(&self, region: Region) -> Result<Vec<P::Encoding>, FramebufferError>
+  where
+    B: FramebufferReadBackend,
+    P: DepthPixel,
+    P::Encoding: Copy + Default,
+  {
+    self.check_depth_read::<P>(region)?;
+    unsafe { B::read_pixels::<P>(&self.repr, None, region) }
+  }
+
+  /// Start reading the pixels of the `region` of the depth attachment, without waiting for the GPU
+  /// to be done rendering to the framebuffer.
+  ///
+  /// See [`Framebuffer::read_depth`] for further details.
+  ///
+  /// The same errors as [`Framebuffer::read_depth`] are returned.
+  pub fn read_depth_async<P>(
+    &self,
+    region: Region,
+  ) -> Result<PixelReadback<B, P::Encoding>, FramebufferError>
+  where
+    B: FramebufferReadbackBackend,
+    P: DepthPixel,
+  {
+    self.check_depth_read::<P>(region)?;
+    unsafe { B::read_pixels_async::<P>(&self.repr, None, region) }.map(PixelReadback::new)
+  }
+
+  /// Read the depth at `(x, y)`, in `[0; 1]`.
+  ///
+  /// The position is in pixels from the lower-left corner of the framebuffer, so window coordinates, such as the ones of
+  /// the mouse cursor, typically need their vertical axis flipped. This is useful to pick the nearest rendered object
+  /// under the cursor.
+  ///
+  /// See [`Framebuffer::read_depth_area`] for further details.
+  ///
+  /// The same errors as [`Framebuffer::read_depth_area`] are returned.
+  pub fn read_depth_at(&self, x: u32, y: u32) -> Result<f32, FramebufferError>
+  where
+    B: FramebufferReadBackend,
+  {
+    let depths = self.read_depth_area(Region::new(x, y, 1, 1))?;
+    Ok(depths[0])
+  }
+
+  /// Read the depths of the `region` of the depth attachment, in `[0; 1]`.
+  ///
+  /// Unlike [`Framebuffer::read_depth`], the pixel format of the depth/stencil slot doesn’t have to be known: depths
+  /// are read with it and normalized. Depths are returned row by row, starting with the bottom row of the region.
+  ///
+  /// [`FramebufferError::CannotRead`] is returned if `region` is out of the bounds of the framebuffer, if the
+  /// depth/stencil slot has no depth, such as [`Stencil8`] ones, or if the backend cannot read the attachment, such as
+  /// multisampled ones.
+  ///
+  /// [`Stencil8`]: crate::pixel::Stencil8
+  pub fn read_depth_area(&self, region: Region) -> Result<Vec<f32>, FramebufferError>
+  where
+    B: FramebufferReadBackend,
+  {
+    let format = DS::depth_format().unwrap_or_else(Depth32F::pixel_format);
+
+    if format == Depth32F::pixel_format() {
+      self.read_depth::<Depth32F>(region)
+    } else if format == Depth32FStencil8::pixel_format() {
+      self.read_depth::<Depth32FStencil8>(region)
+    } else if format == Depth16::pixel_format() {
+      let depths = self.read_depth::<Depth16>(region)?;
+      Ok(
+        depths
+          .into_iter()
+          .map(|d| normalize_depth(d.into(), 16))
+          .collect(),
+      )
+    } else if format == Depth24::pixel_format() {
+      // depths are read as 32-bit normalized integers
+      let depths = self.read_depth::<Depth24>(region)?;
+      Ok(depths.into_iter().map(|d| normalize_depth(d, 32)).collect())
+    } else if format == Depth24Stencil8::pixel_format() {
+      // depths are packed in the 24 most significant bits, with the stencil
+      let depths = self.read_depth::<Depth24Stencil8>(region)?;
+      Ok(
+        depths
+          .into_iter()
+          .map(|d| normalize_depth(d >> 8, 24))
+          .collect(),
+      )
+    } else {
+      Err(FramebufferError::cannot_read(format!(
+        "cannot read depths from a {:?} depth/stencil attachment",
+        format
+      )))
+    }
+  }
+
+  /// Check that the `region` of the depth attachment can be read as `P` pixels.
+  fn check_depth_read<P>(&self, region: Region) -> Result<(), FramebufferError>
+  where
+    P: DepthPixel,
+  {
+    region
+      .check_bounds(self.size())
+      .map_err(FramebufferError::cannot_read)?;
+
+    let format = DS::depth_format().unwrap_or_else(Depth32F::pixel_format);
+
+    if format != P::pixel_format() {
+      return Err(FramebufferError::cannot_read(format!(
+        "cannot read {:?} pixels from a {:?} depth attachment",
+        P::pixel_format(),
+        format
+      )));
+    }
+
+    Ok(())
+  }
 }
 
+impl<B, CS, DS> Framebuffer<B, Cubemap, CS, DS>
+where
+  B: ?Sized + FramebufferBackend<Cubemap>,
+  CS: ColorSlot<B, Cubemap>,
+  DS: DepthStencilSlot<B, Cubemap>,
+{
+  /// Select the face of a cubemap framebuffer to render to.
+  ///
+  /// `None` attaches all the faces, for layered rendering. See [`Framebuffer::select_layer`] for further details.
+  pub fn select_face(&mut self, face: Option<CubeFace>) -> Result<(), FramebufferError>
+  where
+    B: FramebufferLayerBackend<Cubemap>,
+  {
+    self.select_layer(face.map(CubeFace::index))
+  }
+}
+
+/// Number of layers of a layered dimension, if layered.
+fn layer_count<D>(size: D::Size) -> Option<u32>
+where
+  D: Dimensionable,
+{
+  match D::dim() {
+    Dim::Dim3 | Dim::Dim2Array => Some(D::depth(size)),
+    Dim::Dim1Array => Some(D::height(size)),
+    Dim::Cubemap => Some(6),
+    Dim::Dim1 | Dim::Dim2 => None,
+  }
+}
+
+/// The back buffer: the default framebuffer of the window the context renders to.
+///
+/// See the [module documentation](crate::framebuffer#back-buffer) for further details.
+pub type BackBuffer<B> = Framebuffer<B, Dim2, (), ()>;
+
 impl<B> Framebuffer<B, Dim2, (), ()>
 where
   B: ?Sized + FramebufferBackend<Dim2> + FramebufferBackBuffer,
 {
   /// Get the _back buffer_ from the input context and the required resolution.
+  ///
+  /// `size` is the size of the window, in pixels. The back buffer has no attachment of its own, so it doesn’t follow the
+  /// window when it is resized: call [`Framebuffer::resize`] with the new size of the window, or get the back buffer
+  /// again.
   pub fn back_buffer<C>(
     ctx: &mut C,
     size: <Dim2 as Dimensionable>::Size,
@@ -194,10 +866,290 @@ where
       repr,
       color_slot: (),
       depth_stencil_slot: (),
+      mipmaps: 0,
     })
   }
 }
 
+/// Rectangular region of a framebuffer, in pixels, used by [`Framebuffer::blit_to`] and to read pixels.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Region {
+  /// Horizontal position of the lower-left corner of the region.
+  pub x: u32,
+  /// Vertical position of the lower-left corner of the region.
+  pub y: u32,
+  /// Width of the region.
+  pub width: u32,
+  /// Height of the region.
+  pub height: u32,
+}
+
+impl Region {
+  /// Create a new [`Region`].
+  pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+    Region {
+      x,
+      y,
+      width,
+      height,
+    }
+  }
+
+  /// Region covering a whole framebuffer of size `size`.
+  pub fn whole(size: [u32; 2]) -> Self {
+    Self::new(0, 0, size[0], size[1])
+  }
+
+  /// Check that the region is in the bounds of a framebuffer of size `size`.
+  fn check_bounds(self, [width, height]: [u32; 2]) -> Result<(), String> {
+    let in_bounds = self
+      .x
+      .checked_add(self.width)
+      .zip(self.y.checked_add(self.height))
+      .map_or(false, |(right, top)| right <= width && top <= height);
+
+    if in_bounds {
+      Ok(())
+    } else {
+      Err(format!(
+        "{:?} is out of the bounds of a {}×{} framebuffer",
+        self, width, height
+      ))
+    }
+  }
+}
+
+/// Pending read of the pixels of a framebuffer.
+///
+/// Started with [`Framebuffer::read_color_slot_async`] or [`Framebuffer::read_depth_async`], the
+/// read is performed by the GPU once it is done rendering to the framebuffer; the pixels are
+/// usually ready a frame or two later. Dropping the readback discards its pixels.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `T` is the type of the pixels, which is the [`Pixel::Encoding`] of the read pixel format.
+pub struct PixelReadback<B, T>
+where
+  B: ?Sized + FramebufferReadbackBackend,
+{
+  repr: B::PixelReadbackRepr,
+  _phantom: PhantomData<*const T>,
+}
+
+impl<B, T> PixelReadback<B, T>
+where
+  B: ?Sized + FramebufferReadbackBackend,
+{
+  fn new(repr: B::PixelReadbackRepr) -> Self {
+    PixelReadback {
+      repr,
+      _phantom: PhantomData,
+    }
+  }
+
+  /// Whether the pixels are ready.
+  ///
+  /// This method never blocks.
+  pub fn is_ready(&self) -> bool {
+    unsafe { B::is_readback_ready(&self.repr) }
+  }
+
+  /// Block until the pixels are ready, for at most `timeout`.
+  ///
+  /// Return whether the pixels are ready.
+  pub fn wait(&self, timeout: Duration) -> bool {
+    unsafe { B::wait_readback(&self.repr, timeout) }
+  }
+
+  /// Get the pixels, row by row, starting with the bottom row of the read region.
+  ///
+  /// This method blocks until the pixels are ready.
+  pub fn pixels(self) -> Result<Vec<T>, FramebufferError>
+  where
+    T: Copy + Default,
+  {
+    unsafe { B::readback_pixels(self.repr) }
+  }
+}
+
+/// Buffers copied by [`Framebuffer::blit_to`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BlitMask {
+  /// Copy the color attachments.
+  pub color: bool,
+  /// Copy the depth attachment.
+  pub depth: bool,
+  /// Copy the stencil attachment.
+  pub stencil: bool,
+}
+
+impl BlitMask {
+  /// Copy the color attachments only.
+  pub const COLOR: Self = BlitMask {
+    color: true,
+    depth: false,
+    stencil: false,
+  };
+
+  /// Copy the depth attachment only.
+  pub const DEPTH: Self = BlitMask {
+    color: false,
+    depth: true,
+    stencil: false,
+  };
+
+  /// Copy the stencil attachment only.
+  pub const STENCIL: Self = BlitMask {
+    color: false,
+    depth: false,
+    stencil: true,
+  };
+
+  /// Copy all the attachments.
+  pub const ALL: Self = BlitMask {
+    color: true,
+    depth: true,
+    stencil: true,
+  };
+
+  /// Whether no attachment is copied.
+  pub fn is_empty(self) -> bool {
+    !(self.color || self.depth || self.stencil)
+  }
+}
+
+/// Renderbuffer slot.
+///
+/// `Renderbuffer<P>` can be used as a color slot — or in a tuple of color slots — if `P` is a
+/// color pixel format, and as a depth/stencil slot if `P` is a depth pixel format. The backend then
+/// allocates a renderbuffer of that format instead of a texture; it is owned by the framebuffer and
+/// cannot be accessed. See the [module documentation](crate::framebuffer#manipulating-slots) for
+/// further details.
+#[derive(Clone, Copy, Debug)]
+pub struct Renderbuffer<P> {
+  _phantom: PhantomData<P>,
+}
+
+/// Multisampled framebuffers.
+///
+/// A multisampled framebuffer is rendered to via the [`Framebuffer`] returned by
+/// [`MultisampleFramebuffer::framebuffer`], and its color samples are then resolved into a regular
+/// [`Framebuffer`] with [`MultisampleFramebuffer::resolve`]. See the
+/// [module documentation](crate::framebuffer#multisampled-framebuffers) for further details.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [backend::framebuffer::FramebufferMultisample].
+/// - `CS` is the color slot type. It must implement [`ColorSlot`]. Its formats are used for the
+///   multisampled storage only: no texture is reified.
+/// - `DS` is the depth slot type. It must implement [`DepthSlot`]. If `()`, a default depth
+///   storage is allocated.
+///
+/// [backend::framebuffer::FramebufferMultisample]: crate::backend::framebuffer::FramebufferMultisample
+pub struct MultisampleFramebuffer<B, CS, DS>
+where
+  B: ?Sized + FramebufferMultisampleBackend,
+  CS: ColorSlot<B, Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  framebuffer: Framebuffer<B, Dim2, (), ()>,
+  samples: usize,
+  _phantom: PhantomData<(*const CS, *const DS)>,
+}
+
+impl<B, CS, DS> MultisampleFramebuffer<B, CS, DS>
+where
+  B: ?Sized + FramebufferMultisampleBackend,
+  CS: ColorSlot<B, Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  /// Create a new [`MultisampleFramebuffer`] with `samples` samples per pixel.
+  ///
+  /// [`FramebufferError::UnsupportedSampleCount`] is returned if the backend doesn’t support that
+  /// number of samples.
+  pub fn new<C>(
+    ctx: &mut C,
+    size: <Dim2 as Dimensionable>::Size,
+    samples: usize,
+  ) -> Result<Self, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let repr = unsafe {
+      ctx
+        .backend()
+        .new_multisample_framebuffer::<CS, DS>(size, samples)?
+    };
+
+    Ok(MultisampleFramebuffer {
+      framebuffer: Framebuffer {
+        repr,
+        color_slot: (),
+        depth_stencil_slot: (),
+        mipmaps: 0,
+      },
+      samples,
+      _phantom: PhantomData,
+    })
+  }
+
+  /// Number of samples per pixel.
+  pub fn samples(&self) -> usize {
+    self.samples
+  }
+
+  /// Get the size of the framebuffer.
+  pub fn size(&self) -> <Dim2 as Dimensionable>::Size {
+    self.framebuffer.size()
+  }
+
+  /// Resize the multisampled framebuffer.
+  ///
+  /// The samples are lost. The framebuffers it is resolved into must be resized to the same size.
+  pub fn resize(&mut self, size: <Dim2 as Dimensionable>::Size) -> Result<(), FramebufferError>
+  where
+    B: FramebufferResizeBackend<Dim2>,
+  {
+    self.framebuffer.resize(size)
+  }
+
+  /// Framebuffer to render to.
+  ///
+  /// This is the framebuffer to pass to [`PipelineGate::pipeline`]. It has no slot, as its storage
+  /// cannot be sampled.
+  ///
+  /// [`PipelineGate::pipeline`]: crate::pipeline::PipelineGate::pipeline
+  pub fn framebuffer(&self) -> &Framebuffer<B, Dim2, (), ()> {
+    &self.framebuffer
+  }
+
+  /// Resolve the color samples into the color slots of `target`.
+  ///
+  /// The color attachments are resolved by pairs of the same index; if `target` is the back buffer,
+  /// only the first one is resolved. Depth is not resolved.
+  ///
+  /// [`FramebufferError::ResolveSizeMismatch`] is returned if `target` doesn’t have the same size
+  /// as the multisampled framebuffer.
+  pub fn resolve<TCS, TDS>(
+    &self,
+    target: &mut Framebuffer<B, Dim2, TCS, TDS>,
+  ) -> Result<(), FramebufferError>
+  where
+    TCS: ColorSlot<B, Dim2>,
+    TDS: DepthStencilSlot<B, Dim2>,
+  {
+    let source = self.size();
+    let target_size = target.size();
+
+    if source != target_size {
+      return Err(FramebufferError::resolve_size_mismatch(source, target_size));
+    }
+
+    unsafe { B::resolve(&self.framebuffer.repr, &mut target.repr) }
+  }
+}
+
 /// Framebuffer error.
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -214,6 +1166,42 @@ pub enum FramebufferError {
   Incomplete(IncompleteReason),
   /// Cannot attach something to a framebuffer.
   UnsupportedAttachment,
+  /// A layer is out of the bounds of a layered framebuffer.
+  InvalidLayer {
+    /// Selected layer.
+    layer: u32,
+    /// Number of layers of the framebuffer.
+    layers: u32,
+  },
+  /// A mipmap level is out of the bounds of a framebuffer.
+  InvalidLevel {
+    /// Selected level.
+    level: usize,
+    /// Number of mipmap levels of the framebuffer.
+    levels: usize,
+  },
+  /// The number of samples of a multisampled framebuffer is not supported.
+  UnsupportedSampleCount(usize),
+  /// A multisampled framebuffer is resolved into a framebuffer of a different size.
+  ResolveSizeMismatch {
+    /// Size of the multisampled framebuffer.
+    source: [u32; 2],
+    /// Size of the target framebuffer.
+    target: [u32; 2],
+  },
+  /// The color slot has more color attachments than supported.
+  TooManyColorAttachments {
+    /// Number of color attachments of the color slot.
+    count: usize,
+    /// Maximum number of color attachments.
+    max: usize,
+  },
+  /// Cannot blit between framebuffers.
+  CannotBlit(String),
+  /// Cannot read pixels from a framebuffer.
+  CannotRead(String),
+  /// Cannot save a color attachment to a file.
+  CannotSave(String),
 }
 
 impl FramebufferError {
@@ -236,6 +1224,46 @@ impl FramebufferError {
   pub fn unsupported_attachment() -> Self {
     FramebufferError::UnsupportedAttachment
   }
+
+  /// A layer is out of the bounds of a layered framebuffer.
+  pub fn invalid_layer(layer: u32, layers: u32) -> Self {
+    FramebufferError::InvalidLayer { layer, layers }
+  }
+
+  /// A mipmap level is out of the bounds of a framebuffer.
+  pub fn invalid_level(level: usize, levels: usize) -> Self {
+    FramebufferError::InvalidLevel { level, levels }
+  }
+
+  /// The number of samples of a multisampled framebuffer is not supported.
+  pub fn unsupported_sample_count(samples: usize) -> Self {
+    FramebufferError::UnsupportedSampleCount(samples)
+  }
+
+  /// A multisampled framebuffer is resolved into a framebuffer of a different size.
+  pub fn resolve_size_mismatch(source: [u32; 2], target: [u32; 2]) -> Self {
+    FramebufferError::ResolveSizeMismatch { source, target }
+  }
+
+  /// The color slot has more color attachments than supported.
+  pub fn too_many_color_attachments(count: usize, max: usize) -> Self {
+    FramebufferError::TooManyColorAttachments { count, max }
+  }
+
+  /// Cannot blit between framebuffers.
+  pub fn cannot_blit(reason: impl Into<String>) -> Self {
+    FramebufferError::CannotBlit(reason.into())
+  }
+
+  /// Cannot read pixels from a framebuffer.
+  pub fn cannot_read(reason: impl Into<String>) -> Self {
+    FramebufferError::CannotRead(reason.into())
+  }
+
+  /// Cannot save a color attachment to a file.
+  pub fn cannot_save(reason: impl Into<String>) -> Self {
+    FramebufferError::CannotSave(reason.into())
+  }
 }
 
 impl fmt::Display for FramebufferError {
@@ -250,6 +1278,44 @@ impl fmt::Display for FramebufferError {
       FramebufferError::Incomplete(ref e) => write!(f, "incomplete framebuffer: {}", e),
 
       FramebufferError::UnsupportedAttachment => f.write_str("unsupported framebuffer attachment"),
+
+      FramebufferError::InvalidLayer { layer, layers } => write!(
+        f,
+        "invalid framebuffer layer {} (the framebuffer has {} layers)",
+        layer, layers
+      ),
+
+      FramebufferError::InvalidLevel { level, levels } => write!(
+        f,
+        "invalid framebuffer mipmap level {} (the framebuffer has {} levels)",
+        level, levels
+      ),
+
+      FramebufferError::UnsupportedSampleCount(samples) => {
+        write!(f, "unsupported multisample count: {}", samples)
+      }
+
+      FramebufferError::ResolveSizeMismatch { source, target } => write!(
+        f,
+        "cannot resolve a {}×{} multisampled framebuffer into a {}×{} framebuffer",
+        source[0], source[1], target[0], target[1]
+      ),
+
+      FramebufferError::TooManyColorAttachments { count, max } => write!(
+        f,
+        "too many color attachments: {} (at most {} are supported)",
+        count, max
+      ),
+
+      FramebufferError::CannotBlit(ref reason) => write!(f, "cannot blit framebuffer: {}", reason),
+
+      FramebufferError::CannotRead(ref reason) => {
+        write!(f, "cannot read framebuffer pixels: {}", reason)
+      }
+
+      FramebufferError::CannotSave(ref reason) => {
+        write!(f, "cannot save color attachment: {}", reason)
+      }
     }
   }
 }
@@ -261,6 +1327,14 @@ impl std::error::Error for FramebufferError {
       FramebufferError::TextureError(e) => Some(e),
       FramebufferError::Incomplete(e) => Some(e),
       FramebufferError::UnsupportedAttachment => None,
+      FramebufferError::InvalidLayer { .. } => None,
+      FramebufferError::InvalidLevel { .. } => None,
+      FramebufferError::UnsupportedSampleCount(_) => None,
+      FramebufferError::ResolveSizeMismatch { .. } => None,
+      FramebufferError::TooManyColorAttachments { .. } => None,
+      FramebufferError::CannotBlit(_) => None,
+      FramebufferError::CannotRead(_) => None,
+      FramebufferError::CannotSave(_) => None,
     }
   }
 }
@@ -278,16 +1352,22 @@ impl From<IncompleteReason> for FramebufferError {
 }
 
 /// Reason a framebuffer is incomplete.
+#[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IncompleteReason {
   /// Incomplete framebuffer.
   Undefined,
-  /// Incomplete attachment (color / depth).
-  IncompleteAttachment,
+  /// Incomplete attachment (color / depth), for instance with an empty size or a pixel format that cannot be rendered
+  /// to.
+  ///
+  /// The offending attachment is given if the backend could find it.
+  IncompleteAttachment(Option<Attachment>),
   /// An attachment was missing.
   MissingAttachment,
-  /// Incomplete draw buffer.
-  IncompleteDrawBuffer,
+  /// Incomplete draw buffer: a color attachment drawn to has nothing attached.
+  ///
+  /// The offending attachment is given if the backend could find it.
+  IncompleteDrawBuffer(Option<Attachment>),
   /// Incomplete read buffer.
   IncompleteReadBuffer,
   /// Unsupported framebuffer.
@@ -296,21 +1376,80 @@ pub enum IncompleteReason {
   IncompleteMultisample,
   /// Incomplete layer targets.
   IncompleteLayerTargets,
+  /// The attachments don’t have the same size.
+  IncompleteDimensions,
+  /// Unknown status reported by the backend.
+  Unknown(u32),
 }
 
 impl fmt::Display for IncompleteReason {
   fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
     match *self {
-      IncompleteReason::Undefined => write!(f, "incomplete reason"),
-      IncompleteReason::IncompleteAttachment => write!(f, "incomplete attachment"),
+      IncompleteReason::Undefined => write!(f, "undefined framebuffer"),
+      IncompleteReason::IncompleteAttachment(None) => write!(f, "incomplete attachment"),
+      IncompleteReason::IncompleteAttachment(Some(attachment)) => {
+        write!(f, "incomplete {}", attachment)
+      }
       IncompleteReason::MissingAttachment => write!(f, "missing attachment"),
-      IncompleteReason::IncompleteDrawBuffer => write!(f, "incomplete draw buffer"),
+      IncompleteReason::IncompleteDrawBuffer(None) => write!(f, "incomplete draw buffer"),
+      IncompleteReason::IncompleteDrawBuffer(Some(attachment)) => {
+        write!(
+          f,
+          "incomplete draw buffer: nothing attached to {}",
+          attachment
+        )
+      }
       IncompleteReason::IncompleteReadBuffer => write!(f, "incomplete read buffer"),
       IncompleteReason::Unsupported => write!(f, "unsupported"),
       IncompleteReason::IncompleteMultisample => write!(f, "incomplete multisample"),
       IncompleteReason::IncompleteLayerTargets => write!(f, "incomplete layer targets"),
+      IncompleteReason::IncompleteDimensions => write!(f, "attachments of different sizes"),
+      IncompleteReason::Unknown(status) => write!(f, "unknown incomplete status {:#x}", status),
     }
   }
 }
 
 impl error::Error for IncompleteReason {}
+
+/// Attachment point of a framebuffer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Attachment {
+  /// Color attachment, with its index in the color slot.
+  Color(usize),
+  /// Depth attachment.
+  Depth,
+  /// Stencil attachment.
+  Stencil,
+  /// Combined depth and stencil attachment.
+  DepthStencil,
+}
+
+impl fmt::Display for Attachment {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      Attachment::Color(index) => write!(f, "color attachment {}", index),
+      Attachment::Depth => write!(f, "depth attachment"),
+      Attachment::Stencil => write!(f, "stencil attachment"),
+      Attachment::DepthStencil => write!(f, "depth/stencil attachment"),
+    }
+  }
+}
+
+/// Normalize a depth stored in the `bits` least significant bits of `depth`.
+fn normalize_depth(depth: u32, bits: u32) -> f32 {
+  let max = (1u64 << bits) - 1;
+  (depth as f64 / max as f64) as f32
+}
+
+/// Encode a linear color channel in sRGB.
+#[cfg(feature = "image")]
+fn linear_to_srgb(c: f32) -> u8 {
+  let c = c.clamp(0., 1.);
+  let encoded = if c <= 0.0031308 {
+    c * 12.92
+  } else {
+    1.055 * c.powf(1. / 2.4) - 0.055
+  };
+
+  (encoded * 255.).round() as u8
+}
@@ -0,0 +1,275 @@
+//! Indirect rendering.
+//!
+//! Instead of issuing one render per mesh, GPU-driven renderers store the parameters of the renders — which indices
+//! to pick, how many instances, etc. — as draw commands in an [`IndirectBuffer`], and render all of them at once with
+//! [`TessGate::render_indirect`]. Each [`DrawElementsIndirectCommand`] renders a part of an indexed [`Tess`], so that
+//! many meshes packed in the same [`Tess`] can be rendered with a single call.
+//!
+//! A single draw command can also be read from an [`IndirectBuffer`] at a given byte offset with
+//! [`TessGate::render_indirect_at`]. That allows a compute pass to decide how to render a [`Tess`] — for instance, how
+//! many instances survived culling — without reading its results back on the CPU. [`DrawArraysIndirectCommand`] is
+//! then used to render [`Tess`] without picking indices.
+//!
+//! [`Tess`]: crate::tess::Tess
+//! [`TessGate::render_indirect`]: crate::tess_gate::TessGate::render_indirect
+//! [`TessGate::render_indirect_at`]: crate::tess_gate::TessGate::render_indirect_at
+
+use crate::{backend::indirect::IndirectBuffer as IndirectBufferBackend, context::GraphicsContext};
+use std::{error, fmt, marker::PhantomData, mem};
+
+/// Draw commands that can be stored in an [`IndirectBuffer`].
+///
+/// This trait is implemented by [`DrawElementsIndirectCommand`] and [`DrawArraysIndirectCommand`] only.
+pub trait IndirectCommand: Copy + sealed::Sealed {
+  /// Whether the draw command picks indices, and then requires an indexed [`Tess`].
+  ///
+  /// [`Tess`]: crate::tess::Tess
+  const INDEXED: bool;
+}
+
+/// Parameters of an indexed render, read by the GPU from an [`IndirectBuffer`].
+///
+/// The layout of this type is the one expected by graphics APIs, so that it can also be written by shader stages.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DrawElementsIndirectCommand {
+  /// Number of indices to pick.
+  pub count: u32,
+  /// Number of instances to render.
+  pub instance_count: u32,
+  /// Index of the first index to pick.
+  pub first_index: u32,
+  /// Value added to every picked index before fetching vertices.
+  pub base_vertex: i32,
+  /// Index of the first instance to fetch instance data from.
+  pub base_instance: u32,
+}
+
+impl IndirectCommand for DrawElementsIndirectCommand {
+  const INDEXED: bool = true;
+}
+
+impl DrawElementsIndirectCommand {
+  /// Create a new [`DrawElementsIndirectCommand`].
+  pub const fn new(
+    count: u32,
+    instance_count: u32,
+    first_index: u32,
+    base_vertex: i32,
+    base_instance: u32,
+  ) -> Self {
+    DrawElementsIndirectCommand {
+      count,
+      instance_count,
+      first_index,
+      base_vertex,
+      base_instance,
+    }
+  }
+}
+
+/// Parameters of a non-indexed render, read by the GPU from an [`IndirectBuffer`].
+///
+/// The layout of this type is the one expected by graphics APIs, so that it can also be written by shader stages.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DrawArraysIndirectCommand {
+  /// Number of vertices to render.
+  pub count: u32,
+  /// Number of instances to render.
+  pub instance_count: u32,
+  /// Index of the first vertex to render.
+  pub first: u32,
+  /// Index of the first instance to fetch instance data from.
+  pub base_instance: u32,
+}
+
+impl IndirectCommand for DrawArraysIndirectCommand {
+  const INDEXED: bool = false;
+}
+
+impl DrawArraysIndirectCommand {
+  /// Create a new [`DrawArraysIndirectCommand`].
+  pub const fn new(count: u32, instance_count: u32, first: u32, base_instance: u32) -> Self {
+    DrawArraysIndirectCommand {
+      count,
+      instance_count,
+      first,
+      base_instance,
+    }
+  }
+}
+
+mod sealed {
+  /// Prevent [`IndirectCommand`](super::IndirectCommand) from being implemented outside of luminance, as its layout
+  /// must be the one expected by graphics APIs.
+  pub trait Sealed {}
+
+  impl Sealed for super::DrawElementsIndirectCommand {}
+  impl Sealed for super::DrawArraysIndirectCommand {}
+}
+
+/// Errors that might occur when using indirect buffers.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndirectBufferError {
+  /// Cannot create the indirect buffer.
+  CannotCreate(String),
+  /// Uploaded draw commands don’t fit in the indirect buffer.
+  OutOfBounds {
+    /// Index of the first uploaded draw command.
+    offset: usize,
+    /// Number of uploaded draw commands.
+    len: usize,
+    /// Number of draw commands of the buffer.
+    capacity: usize,
+  },
+  /// The byte offset of a draw command is not aligned on 4 bytes, or the draw command doesn’t fit in the buffer.
+  InvalidOffset {
+    /// Requested byte offset.
+    offset: usize,
+    /// Size of the buffer, in bytes.
+    bytes: usize,
+  },
+}
+
+impl IndirectBufferError {
+  /// Cannot create the indirect buffer.
+  pub fn cannot_create(reason: impl Into<String>) -> Self {
+    IndirectBufferError::CannotCreate(reason.into())
+  }
+
+  /// Uploaded draw commands don’t fit in the indirect buffer.
+  pub fn out_of_bounds(offset: usize, len: usize, capacity: usize) -> Self {
+    IndirectBufferError::OutOfBounds {
+      offset,
+      len,
+      capacity,
+    }
+  }
+
+  /// The byte offset of a draw command is not aligned on 4 bytes, or the draw command doesn’t fit in the buffer.
+  pub fn invalid_offset(offset: usize, bytes: usize) -> Self {
+    IndirectBufferError::InvalidOffset { offset, bytes }
+  }
+}
+
+impl fmt::Display for IndirectBufferError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      IndirectBufferError::CannotCreate(ref reason) => {
+        write!(f, "cannot create indirect buffer: {}", reason)
+      }
+
+      IndirectBufferError::OutOfBounds {
+        offset,
+        len,
+        capacity,
+      } => write!(
+        f,
+        "cannot upload {} draw commands at offset {} to an indirect buffer of {} draw commands",
+        len, offset, capacity
+      ),
+
+      IndirectBufferError::InvalidOffset { offset, bytes } => write!(
+        f,
+        "invalid draw command byte offset {} in an indirect buffer of {} bytes",
+        offset, bytes
+      ),
+    }
+  }
+}
+
+impl error::Error for IndirectBufferError {}
+
+/// A GPU buffer of draw commands.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `C` is the type of the draw commands, [`DrawElementsIndirectCommand`] or [`DrawArraysIndirectCommand`].
+pub struct IndirectBuffer<B, C>
+where
+  B: ?Sized + IndirectBufferBackend<C>,
+  C: IndirectCommand,
+{
+  pub(crate) repr: B::IndirectBufferRepr,
+  len: usize,
+  _phantom: PhantomData<*const C>,
+}
+
+impl<B, C> IndirectBuffer<B, C>
+where
+  B: ?Sized + IndirectBufferBackend<C>,
+  C: IndirectCommand,
+{
+  /// Create a new [`IndirectBuffer`] holding `commands`.
+  ///
+  /// [`IndirectBufferError::CannotCreate`] is returned if `commands` is empty.
+  pub fn new<G>(ctx: &mut G, commands: &[C]) -> Result<Self, IndirectBufferError>
+  where
+    G: GraphicsContext<Backend = B>,
+  {
+    if commands.is_empty() {
+      return Err(IndirectBufferError::cannot_create("empty indirect buffer"));
+    }
+
+    unsafe {
+      ctx
+        .backend()
+        .new_indirect_buffer(commands)
+        .map(|repr| IndirectBuffer {
+          repr,
+          len: commands.len(),
+          _phantom: PhantomData,
+        })
+    }
+  }
+
+  /// Number of draw commands the buffer holds.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether the buffer holds no draw command; always `false`, as indirect buffers cannot be empty.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Upload `commands` to the buffer, starting at the draw command at index `offset`.
+  ///
+  /// [`IndirectBufferError::OutOfBounds`] is returned if the draw commands don’t fit in the buffer.
+  pub fn upload(&mut self, offset: usize, commands: &[C]) -> Result<(), IndirectBufferError> {
+    if offset
+      .checked_add(commands.len())
+      .map_or(true, |end| end > self.len)
+    {
+      return Err(IndirectBufferError::out_of_bounds(
+        offset,
+        commands.len(),
+        self.len,
+      ));
+    }
+
+    if commands.is_empty() {
+      return Ok(());
+    }
+
+    unsafe { B::upload_indirect_commands(&mut self.repr, offset, commands) }
+  }
+
+  /// Check that a draw command can be read at the byte offset `offset`.
+  pub(crate) fn check_offset(&self, offset: usize) -> Result<(), IndirectBufferError> {
+    let bytes = self.len * mem::size_of::<C>();
+
+    if offset % 4 != 0
+      || offset
+        .checked_add(mem::size_of::<C>())
+        .map_or(true, |end| end > bytes)
+    {
+      return Err(IndirectBufferError::invalid_offset(offset, bytes));
+    }
+
+    Ok(())
+  }
+}
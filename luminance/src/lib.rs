@@ -238,6 +238,14 @@
 //! This attribute allows you to specify whether the fields are to be instanced or not. For more
 //! about that, have a look at [`VertexInstancing`].
 //!
+//! Instanced attributes can also advance every `n` instances with `#[vertex(divisor = "<n>")]`. Both
+//! attributes can be set on fields too, overriding the struct one, so that, for instance, a position advances every
+//! instance while a color is shared by every group of 4 instances.
+//!
+//! Integral fields are fetched as integers in shaders — `int`, `uint`, `ivec4`, etc. — which is what bone indices,
+//! for instance, need. Fields annotated with `#[vertex(normalized = "true")]` are fetched as normalized floats instead,
+//! as colors stored as bytes usually are. See [`Normalized`] for further details.
+//!
 //! ### `Semantics`
 //!
 //! The [`Semantics`] derive proc-macro.
@@ -292,6 +300,9 @@
 //! }
 //! ```
 //!
+//! The name is used as-is, so it can also refer to an item of an array or a member of a `struct` declared in the
+//! shader program, such as `#[uniform(name = "lights[0].color")]`.
+//!
 //! Finally, you can mix both attributes if you want to change the mapping and have an unbound
 //! uniform if it cannot be mapped:
 //!
@@ -314,6 +325,7 @@
 //! [`Tess`]: https://docs.rs/luminance/latest/luminance/tess/struct.Tess.html
 //! [`Vertex`]: https://docs.rs/luminance/latest/luminance/vertex/trait.Vertex.html
 //! [`VertexAttrib`]: https://docs.rs/luminance/latest/luminance/vertex/trait.VertexAttrib.html
+//! [`Normalized`]: https://docs.rs/luminance/latest/luminance/vertex/enum.Normalized.html
 //! [`VertexInstancing`]: https://docs.rs/luminance/latest/luminance/vertex/enum.VertexInstancing.html
 //! [`UniformInterface`]: https://docs.rs/luminance/latest/luminance/shader/program/trait.UniformInterface.html
 
@@ -327,12 +339,15 @@ pub use luminance_derive::*;
 
 pub mod backend;
 pub mod blending;
+pub mod color_mask;
 pub mod context;
 pub mod depth_stencil;
 pub mod face_culling;
 pub mod framebuffer;
+pub mod indirect;
 pub mod pipeline;
 pub mod pixel;
+pub mod pixel_buffer;
 pub mod query;
 pub mod render_gate;
 pub mod render_state;
@@ -342,4 +357,8 @@ pub mod shading_gate;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
+#[cfg(feature = "container")]
+pub mod texture_container;
+pub mod texture_streamer;
+pub mod transform_feedback;
 pub mod vertex;
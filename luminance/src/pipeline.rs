@@ -53,6 +53,27 @@
 //! will then do a couple of things on the backend, depending mainly on the [`PipelineState`] you pass.
 //! For instance, framebuffer clearing, sRGB conversion or scissor test is done at that level.
 //!
+//! ## Clearing
+//!
+//! By default, the color, depth and stencil buffers of the [`Framebuffer`] are cleared when entering
+//! the [`PipelineGate`]. Each of them can be left untouched by setting its clear value to `None` —
+//! for instance, [`PipelineState::set_clear_color`] with `None` clears only depth and stencil —
+//! and [`PipelineState::without_clear`] preserves the whole content of the framebuffer, to render
+//! on top of a previous pass.
+//!
+//! Color attachments can also be cleared with their own value with
+//! [`PipelineState::set_clear_color_attachment`], overriding the clear color. This is required
+//! for integral color attachments, which can only be cleared with a [`ClearColor::Int`] or
+//! [`ClearColor::UInt`] value.
+//!
+//! ## Invalidation
+//!
+//! The content of attachments only needed while rendering — typically depth and stencil — can be
+//! declared as discardable once the pipeline is done with [`PipelineState::set_invalidate_attachments`].
+//! On tiled GPUs, such as mobile ones, this saves writing them back to memory. Invalidation is
+//! only a hint, ignored by backends not supporting it, and the content of invalidated attachments
+//! is undefined afterwards.
+//!
 //! # ShadingGate
 //!
 //! A [`ShadingGate`] is the gate allowing to share a shader [`Program`].
@@ -128,13 +149,16 @@ use crate::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
     framebuffer::Framebuffer as FramebufferBackend,
-    pipeline::{Pipeline as PipelineBackend, PipelineBase, PipelineShaderData, PipelineTexture},
+    pipeline::{
+      Pipeline as PipelineBackend, PipelineAtomicCounters, PipelineBase, PipelineImage,
+      PipelineShaderData, PipelineShaderStorage, PipelineTexture,
+    },
   },
   context::GraphicsContext,
   framebuffer::Framebuffer,
-  pixel::Pixel,
+  pixel::{DepthPixel, Pixel, PixelFormat},
   scissor::ScissorRegion,
-  shader::ShaderData,
+  shader::{AtomicCounters, ShaderData},
   shading_gate::ShadingGate,
   texture::{Dimensionable, Texture},
 };
@@ -142,11 +166,25 @@ use crate::{
 /// Possible errors that might occur in a graphics [`Pipeline`].
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
-pub enum PipelineError {}
+pub enum PipelineError {
+  /// The pixel format of a texture cannot be used for images.
+  UnsupportedImageFormat(PixelFormat),
+}
+
+impl PipelineError {
+  /// The pixel format of a texture cannot be used for images.
+  pub fn unsupported_image_format(pf: PixelFormat) -> Self {
+    PipelineError::UnsupportedImageFormat(pf)
+  }
+}
 
 impl fmt::Display for PipelineError {
-  fn fmt(&self, _: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-    Ok(())
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      PipelineError::UnsupportedImageFormat(ref pf) => {
+        write!(f, "unsupported image format: {:?}", pf)
+      }
+    }
   }
 }
 
@@ -171,6 +209,88 @@ pub enum Viewport {
   },
 }
 
+/// Value a color attachment is cleared with.
+///
+/// The variant must match the pixel format of the attachment: [`ClearColor::Float`] for floating-point and normalized
+/// formats, and [`ClearColor::Int`] or [`ClearColor::UInt`] for signed and unsigned integral formats.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClearColor {
+  /// Floating-point color.
+  Float([f32; 4]),
+  /// Signed integral color.
+  Int([i32; 4]),
+  /// Unsigned integral color.
+  UInt([u32; 4]),
+}
+
+impl From<[f32; 4]> for ClearColor {
+  fn from(color: [f32; 4]) -> Self {
+    ClearColor::Float(color)
+  }
+}
+
+impl From<[i32; 4]> for ClearColor {
+  fn from(color: [i32; 4]) -> Self {
+    ClearColor::Int(color)
+  }
+}
+
+impl From<[u32; 4]> for ClearColor {
+  fn from(color: [u32; 4]) -> Self {
+    ClearColor::UInt(color)
+  }
+}
+
+/// Attachments of a [`Framebuffer`] whose content can be discarded at the end of a pipeline.
+///
+/// See [`PipelineState::set_invalidate_attachments`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct InvalidateAttachments {
+  /// Indices of the color attachments to invalidate.
+  pub colors: Vec<usize>,
+  /// Invalidate the depth attachment.
+  pub depth: bool,
+  /// Invalidate the stencil attachment.
+  pub stencil: bool,
+}
+
+impl InvalidateAttachments {
+  /// Invalidate no attachment.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Also invalidate the color attachment at `index`.
+  pub fn color(mut self, index: usize) -> Self {
+    if !self.colors.contains(&index) {
+      self.colors.push(index);
+    }
+
+    self
+  }
+
+  /// Also invalidate the depth attachment.
+  pub fn depth(self) -> Self {
+    Self {
+      depth: true,
+      ..self
+    }
+  }
+
+  /// Also invalidate the stencil attachment.
+  pub fn stencil(self) -> Self {
+    Self {
+      stencil: true,
+      ..self
+    }
+  }
+
+  /// Whether no attachment is invalidated.
+  pub fn is_empty(&self) -> bool {
+    self.colors.is_empty() && !self.depth && !self.stencil
+  }
+}
+
 /// Various customization options for pipelines.
 #[non_exhaustive]
 #[derive(Clone, Debug)]
@@ -184,6 +304,13 @@ pub struct PipelineState {
   /// for a paint-like application).
   pub clear_color: Option<[f32; 4]>,
 
+  /// Values to use when clearing specific color attachments, by attachment index.
+  ///
+  /// The listed color attachments are cleared with their value after being cleared with
+  /// [`PipelineState::clear_color`], if any. Indices out of the color attachments of the [`Framebuffer`] are ignored;
+  /// the back buffer has a single color attachment, at index `0`.
+  pub clear_color_attachments: Vec<(usize, ClearColor)>,
+
   /// Depth value to use when clearing the depth buffer.
   ///
   /// Set this to `Some(depth)` to use that depth to clear the [`Framebuffer`] depth buffer.
@@ -205,30 +332,56 @@ pub struct PipelineState {
   ///
   /// Typical examples are when you are rendering into an image that is to be displayed to on screen: the
   /// [`Framebuffer`] can use sRGB color pixel formats and the shader doesn’t have to worry about converting from linear
-  /// color space into sRGB color space, as the pipeline will do that for you.
+  /// color space into sRGB color space, as the pipeline will do that for you. Colors rendered to the back buffer are
+  /// encoded as well if it is sRGB-capable, which [`Query::is_back_buffer_srgb`] tells.
+  ///
+  /// Some backends, such as WebGL2, always encode colors written to sRGB framebuffers, whatever this setting.
+  ///
+  /// [`Query::is_back_buffer_srgb`]: crate::query::Query::is_back_buffer_srgb
   pub srgb_enabled: bool,
 
   /// Whether to use scissor test when clearing buffers.
   pub clear_scissor: Option<ScissorRegion>,
+
+  /// Whether cubemaps are filtered seamlessly.
+  ///
+  /// When this is set to `true`, sampling a [`Cubemap`] near the edge of a face takes the texels of the adjacent faces
+  /// into account, removing the visible seams between faces, especially noticeable in the lower mipmap levels. Some
+  /// backends, such as WebGL2, always filter cubemaps seamlessly.
+  ///
+  /// [`Cubemap`]: crate::texture::Cubemap
+  pub seamless_cubemap_enabled: bool,
+
+  /// Attachments whose content can be discarded at the end of the pipeline.
+  ///
+  /// Color attachments out of the color attachments of the [`Framebuffer`] are ignored; the back buffer has a single
+  /// color attachment, at index `0`.
+  pub invalidate_attachments: InvalidateAttachments,
 }
 
 impl Default for PipelineState {
   /// Default [`PipelineState`]:
   ///
   /// - Clear color is `Some([0., 0., 0., 1.])`.
+  /// - No color attachment has its own clear value.
   /// - Depth value is `Some(1.)`.
   /// - Stencil value is `Some(0)`.
   /// - The viewport uses the whole framebuffer’s.
   /// - sRGB encoding is disabled.
   /// - No scissor test is performed.
+  /// - Seamless cubemap filtering is disabled.
+  /// - No attachment is invalidated.
   fn default() -> Self {
     PipelineState {
       clear_color: Some([0., 0., 0., 1.]),
+      clear_color_attachments: Vec::new(),
       clear_depth: Some(1.),
       clear_stencil: Some(0),
       viewport: Viewport::Whole,
       srgb_enabled: false,
       clear_scissor: None,
+      seamless_cubemap_enabled: false,
+      invalidate_attachments: InvalidateAttachments::default(),
     }
   }
 }
@@ -254,6 +407,38 @@ impl PipelineState {
     }
   }
 
+  /// Get the clear values of specific color attachments.
+  pub fn clear_color_attachments(&self) -> &[(usize, ClearColor)] {
+    &self.clear_color_attachments
+  }
+
+  /// Set the clear value of the color attachment at `index`, replacing its previous one.
+  pub fn set_clear_color_attachment(mut self, index: usize, color: impl Into<ClearColor>) -> Self {
+    let color = color.into();
+
+    match self
+      .clear_color_attachments
+      .iter_mut()
+      .find(|(i, _)| *i == index)
+    {
+      Some((_, previous)) => *previous = color,
+      None => self.clear_color_attachments.push((index, color)),
+    }
+
+    self
+  }
+
+  /// Disable all clears, preserving the content of the framebuffer.
+  pub fn without_clear(self) -> Self {
+    Self {
+      clear_color: None,
+      clear_color_attachments: Vec::new(),
+      clear_depth: None,
+      clear_stencil: None,
+      ..self
+    }
+  }
+
   /// Get the clear depth, if any.
   pub fn clear_depth(&self) -> Option<f32> {
     self.clear_depth
@@ -290,12 +475,14 @@ impl PipelineState {
     Self { viewport, ..self }
   }
 
-  /// Check whether sRGB linearization is enabled.
+  /// Check whether sRGB encoding is enabled.
   pub fn is_srgb_enabled(&self) -> bool {
     self.srgb_enabled
   }
 
-  /// Enable sRGB linearization.
+  /// Enable sRGB encoding of the linear colors written to sRGB framebuffers.
+  ///
+  /// See [`PipelineState::srgb_enabled`] for further details.
   pub fn enable_srgb(self, srgb_enabled: bool) -> Self {
     Self {
       srgb_enabled,
@@ -315,6 +502,32 @@ impl PipelineState {
       ..self
     }
   }
+
+  /// Check whether seamless cubemap filtering is enabled.
+  pub fn is_seamless_cubemap_enabled(&self) -> bool {
+    self.seamless_cubemap_enabled
+  }
+
+  /// Enable seamless cubemap filtering.
+  pub fn enable_seamless_cubemap(self, seamless_cubemap_enabled: bool) -> Self {
+    Self {
+      seamless_cubemap_enabled,
+      ..self
+    }
+  }
+
+  /// Get the attachments invalidated at the end of the pipeline.
+  pub fn invalidate_attachments(&self) -> &InvalidateAttachments {
+    &self.invalidate_attachments
+  }
+
+  /// Set the attachments invalidated at the end of the pipeline.
+  pub fn set_invalidate_attachments(self, invalidate_attachments: InvalidateAttachments) -> Self {
+    Self {
+      invalidate_attachments,
+      ..self
+    }
+  }
 }
 
 /// A GPU pipeline handle.
@@ -359,6 +572,35 @@ where
     }
   }
 
+  /// Bind a level of a texture as an image.
+  ///
+  /// Images can be loaded from and stored to by shader stages, depending on the [`ImageAccess`]. With layered textures
+  /// (3D, cubemaps and arrays), `layer` selects a single layer to bind, or all of them if [`None`]; it is ignored with
+  /// other textures. Once the texture is bound, the [`BoundImage`] object has to be dropped / die in order to bind the
+  /// texture again.
+  ///
+  /// [`PipelineError::UnsupportedImageFormat`] is returned if the pixel format of the texture cannot be used for
+  /// images.
+  pub fn bind_image<D, P>(
+    &'a self,
+    texture: &'a mut Texture<B, D, P>,
+    level: usize,
+    layer: Option<usize>,
+    access: ImageAccess,
+  ) -> Result<BoundImage<'a, B, D, P>, PipelineError>
+  where
+    B: PipelineImage<D, P>,
+    D: Dimensionable,
+    P: Pixel,
+  {
+    unsafe {
+      B::bind_image(&self.repr, &texture.repr, level, layer, access).map(|repr| BoundImage {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
   /// Bind a shader data.
   ///
   /// Once the shader data is bound, the [`BoundShaderData`] object has to be dropped / die in order to bind the shader
@@ -377,6 +619,50 @@ where
       })
     }
   }
+
+  /// Bind a shader data as shader storage.
+  ///
+  /// Contrary to [`Pipeline::bind_shader_data`], the bound data is exposed to shaders as a buffer block, which can be
+  /// written to by shader stages. Once the shader storage is bound, the [`BoundShaderStorage`] object has to be dropped
+  /// / die in order to bind the shader data again.
+  pub fn bind_shader_storage<T>(
+    &'a self,
+    shader_data: &'a mut ShaderData<B, T>,
+  ) -> Result<BoundShaderStorage<'a, B, T>, PipelineError>
+  where
+    B: PipelineShaderStorage<T>,
+  {
+    unsafe {
+      B::bind_shader_storage(&self.repr, &shader_data.repr).map(|repr| BoundShaderStorage {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Bind atomic counters at the given `binding` point.
+  ///
+  /// The binding point must be the one declared by the shader stages (i.e. `layout(binding = 0) uniform atomic_uint
+  /// counter;`); see [`Program::active_atomic_counters`] to reflect it. As the binding point is not allocated by the
+  /// backend, the atomic counters stay bound to it until other atomic counters are bound to the same point; dropping
+  /// the [`BoundAtomicCounters`] object doesn’t unbind them.
+  ///
+  /// [`Program::active_atomic_counters`]: crate::shader::Program::active_atomic_counters
+  pub fn bind_atomic_counters(
+    &'a self,
+    counters: &'a mut AtomicCounters<B>,
+    binding: u32,
+  ) -> Result<BoundAtomicCounters<'a, B>, PipelineError>
+  where
+    B: PipelineAtomicCounters,
+  {
+    unsafe {
+      B::bind_atomic_counters(&self.repr, &counters.repr, binding).map(|repr| BoundAtomicCounters {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
 }
 
 /// Top-most node in a graphics pipeline.
@@ -448,7 +734,13 @@ impl<'a, B> PipelineGate<'a, B> {
         backend: self.backend,
       };
 
-      f(pipeline, shading_gate)
+      let result = f(pipeline, shading_gate);
+
+      unsafe {
+        self.backend.end_pipeline(&framebuffer.repr, pipeline_state);
+      }
+
+      result
     };
 
     Render(render())
@@ -577,6 +869,179 @@ where
   }
 }
 
+/// Opaque shader storage binding.
+///
+/// This type represents a [`ShaderData`] bound as shader storage via [`BoundShaderStorage`]. It can be used along
+/// with a [`Uniform`] to refer to a buffer block in a shader.
+///
+/// # Parametricity
+///
+/// - `T` is the type of the carried item by the [`ShaderData`].
+///
+/// # Notes
+///
+/// You shouldn’t try to do store / cache or do anything special with that value. Consider it an opaque object.
+///
+/// [`Uniform`]: crate::shader::Uniform
+#[derive(Debug)]
+pub struct ShaderStorageBinding<T> {
+  binding: u32,
+  _phantom: PhantomData<*const T>,
+}
+
+impl<T> ShaderStorageBinding<T> {
+  /// Access the underlying binding value.
+  ///
+  /// # Notes
+  ///
+  /// That value shouldn’t be read nor store, as it’s only meaningful for backend implementations.
+  pub fn binding(self) -> u32 {
+    self.binding
+  }
+}
+
+/// A [`ShaderData`] bound as shader storage.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`PipelineShaderStorage`].
+/// - `T` is the carried item type.
+///
+/// # Notes
+///
+/// This works the same way as [`BoundShaderData`]: pass the [`ShaderStorageBinding`] obtained with
+/// [`BoundShaderStorage::binding`] to your [`ProgramInterface`] to connect the buffer block to the data.
+///
+/// [`ProgramInterface`]: crate::shader::ProgramInterface
+pub struct BoundShaderStorage<'a, B, T>
+where
+  B: PipelineShaderStorage<T>,
+{
+  pub(crate) repr: B::BoundShaderStorageRepr,
+  _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, B, T> BoundShaderStorage<'a, B, T>
+where
+  B: PipelineShaderStorage<T>,
+{
+  /// Obtain a [`ShaderStorageBinding`] object that can be used to refer to this bound shader storage in shader stages.
+  pub fn binding(&self) -> ShaderStorageBinding<T> {
+    let binding = unsafe { B::shader_storage_binding(&self.repr) };
+    ShaderStorageBinding {
+      binding,
+      _phantom: PhantomData,
+    }
+  }
+}
+
+/// Access to an image by shader stages.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ImageAccess {
+  /// Shader stages can only load from the image.
+  ReadOnly,
+  /// Shader stages can only store to the image.
+  WriteOnly,
+  /// Shader stages can both load from and store to the image.
+  ReadWrite,
+}
+
+/// Opaque image binding.
+///
+/// This type represents a [`Texture`] bound as an image via [`BoundImage`]. It can be used along with a [`Uniform`] to
+/// refer to an image in a shader.
+///
+/// # Parametricity
+///
+/// - `D` is the dimension of the original texture. It must implement [`Dimensionable`] in most useful methods.
+/// - `S` is the sampler type. It must implement [`SamplerType`] in most useful methods.
+///
+/// # Notes
+///
+/// You shouldn’t try to do store / cache or do anything special with that value. Consider it an opaque object.
+///
+/// [`Uniform`]: crate::shader::Uniform
+/// [`SamplerType`]: crate::pixel::SamplerType
+#[derive(Debug)]
+pub struct ImageBinding<D, S> {
+  binding: u32,
+  _phantom: PhantomData<*const (D, S)>,
+}
+
+impl<D, S> ImageBinding<D, S> {
+  /// Access the underlying binding value.
+  ///
+  /// # Notes
+  ///
+  /// That value shouldn’t be read nor store, as it’s only meaningful for backend implementations.
+  pub fn binding(self) -> u32 {
+    self.binding
+  }
+}
+
+/// A [`Texture`] bound as an image.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`PipelineImage`].
+/// - `D` is the dimension. It must implement [`Dimensionable`].
+/// - `P` is the pixel type. It must implement [`Pixel`].
+///
+/// # Notes
+///
+/// This works the same way as [`BoundTexture`]: pass the [`ImageBinding`] obtained with [`BoundImage::binding`] to your
+/// [`ProgramInterface`] to connect the image to the texture.
+///
+/// [`ProgramInterface`]: crate::shader::ProgramInterface
+pub struct BoundImage<'a, B, D, P>
+where
+  B: PipelineImage<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  pub(crate) repr: B::BoundImageRepr,
+  _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, B, D, P> BoundImage<'a, B, D, P>
+where
+  B: PipelineImage<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Obtain an [`ImageBinding`] object that can be used to refer to this bound image in shader stages.
+  pub fn binding(&self) -> ImageBinding<D, P::SamplerType> {
+    let binding = unsafe { B::image_binding(&self.repr) };
+    ImageBinding {
+      binding,
+      _phantom: PhantomData,
+    }
+  }
+}
+
+/// [`AtomicCounters`] bound to a binding point.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`PipelineAtomicCounters`].
+pub struct BoundAtomicCounters<'a, B>
+where
+  B: PipelineAtomicCounters,
+{
+  pub(crate) repr: B::BoundAtomicCountersRepr,
+  _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, B> BoundAtomicCounters<'a, B>
+where
+  B: PipelineAtomicCounters,
+{
+  /// Binding point the atomic counters are bound to.
+  pub fn binding(&self) -> u32 {
+    unsafe { B::atomic_counters_binding(&self.repr) }
+  }
+}
+
 /// Opaque texture binding.
 ///
 /// This type represents a bound [`Texture`] via [`BoundTexture`]. It can be used along with a
@@ -661,3 +1126,61 @@ where
     }
   }
 }
+
+impl<'a, B, D, P> BoundTexture<'a, B, D, P>
+where
+  B: PipelineTexture<D, P>,
+  D: Dimensionable,
+  P: DepthPixel,
+{
+  /// Obtain a [`ShadowBinding`] object that can be used to refer to this bound depth texture in
+  /// shader stages via a shadow sampler (e.g. `sampler2DShadow`).
+  ///
+  /// # Notes
+  ///
+  /// The texture should have been created with [`Sampler::depth_comparison`] set for the shadow
+  /// sampler to perform depth comparisons.
+  ///
+  /// [`Sampler::depth_comparison`]: crate::texture::Sampler::depth_comparison
+  pub fn shadow_binding(&self) -> ShadowBinding<D> {
+    let binding = unsafe { B::texture_binding(&self.repr) };
+    ShadowBinding {
+      binding,
+      _phantom: PhantomData,
+    }
+  }
+}
+
+/// Opaque shadow texture binding.
+///
+/// This type represents a bound depth [`Texture`] via [`BoundTexture`], to be used with a shadow
+/// sampler [`Uniform`]. Contrary to [`TextureBinding`], the sampler type is not part of the type,
+/// as shadow samplers always return floating-point comparison results.
+///
+/// # Parametricity
+///
+/// - `D` is the dimension of the original texture. It must implement [`Dimensionable`] in most
+///   useful methods.
+///
+/// # Notes
+///
+/// You shouldn’t try to do store / cache or do anything special with that value. Consider it
+/// an opaque object.
+///
+/// [`Uniform`]: crate::shader::Uniform
+#[derive(Debug)]
+pub struct ShadowBinding<D> {
+  binding: u32,
+  _phantom: PhantomData<*const D>,
+}
+
+impl<D> ShadowBinding<D> {
+  /// Access the underlying binding value.
+  ///
+  /// # Notes
+  ///
+  /// That value shouldn’t be read nor store, as it’s only meaningful for backend implementations.
+  pub fn binding(self) -> u32 {
+    self.binding
+  }
+}
@@ -10,6 +10,27 @@
 //!   format on the GPU / in shaders.
 //! - [`Pixel::pixel_format`], a function returning the [`PixelFormat`], reified version of the
 //!   type at runtime.
+//!
+//! # sRGB pixel formats
+//!
+//! Color images — photos, textures painted by artists, etc. — are most of the time stored in the
+//! sRGB colorspace, which is not linear. Uploading them to a texture with a linear format, such as
+//! [`NormRGBA8UI`], makes the GPU filter and blend them in gamma space, which darkens and shifts
+//! the colors. Textures using [`SRGB8UI`] or [`SRGBA8UI`] are linearized by the hardware when
+//! sampled instead, before any filtering happens, so that shaders always work with linear colors.
+//! The alpha channel is always linear. Use [`PixelFormat::is_srgb`] to know whether a format is
+//! in the sRGB colorspace.
+//!
+//! # Compressed pixel formats
+//!
+//! Some pixel formats — implementing [`CompressedPixel`] — store pixels compressed in blocks, as
+//! described by [`CompressedFormat`]. Their encoding is the byte, and textures using them are
+//! created and uploaded to with already compressed data only, see
+//! [`Texture::new_compressed`]. Whether a family of compressed formats is supported depends on the
+//! driver, and can be queried with [`Query::supports_compression`].
+//!
+//! [`Texture::new_compressed`]: crate::texture::Texture::new_compressed
+//! [`Query::supports_compression`]: crate::query::Query::supports_compression
 
 /// Reify a static pixel format at runtime.
 pub unsafe trait Pixel {
@@ -31,12 +52,22 @@ pub unsafe trait Pixel {
 /// Constraint on [`Pixel`] for color ones.
 pub unsafe trait ColorPixel: Pixel {}
 
-/// Constraint on [`Pixel`] for depth ones.
+/// Constraint on [`Pixel`] for depth ones — including stencil ones.
 pub unsafe trait DepthPixel: Pixel {}
 
 /// Constraint on [`Pixel`] for renderable ones.
 pub unsafe trait RenderablePixel: Pixel {}
 
+/// Constraint on [`Pixel`] for compressed ones.
+///
+/// # Safety
+///
+/// Implementors must return a [`CompressedFormat`] matching the actual encoding of the pixel type.
+pub unsafe trait CompressedPixel: Pixel<Encoding = u8, RawEncoding = u8> {
+  /// Reify to [`CompressedFormat`].
+  fn compressed_format() -> CompressedFormat;
+}
+
 /// Reify a static sample type at runtime.
 ///
 /// That trait is used to allow sampling with different types than the actual encoding of the
@@ -59,16 +90,35 @@ impl PixelFormat {
   /// Does a [`PixelFormat`] represent a color?
   pub fn is_color_pixel(self) -> bool {
     match self.format {
-      Format::Depth(_) => false,
+      Format::Depth(_) | Format::DepthStencil(_, _) | Format::Stencil(_) => false,
       _ => true,
     }
   }
 
-  /// Does a [`PixelFormat`] represent depth information?
+  /// Does a [`PixelFormat`] represent depth or stencil information?
   pub fn is_depth_pixel(self) -> bool {
     !self.is_color_pixel()
   }
 
+  /// Does a [`PixelFormat`] represent unnormalized integers?
+  ///
+  /// Such pixels are accessed as integers in shaders, via `isampler*` and `usampler*` samplers, and cannot be filtered
+  /// linearly.
+  pub fn is_integral(self) -> bool {
+    matches!(self.encoding, Type::Integral | Type::Unsigned)
+  }
+
+  /// Does a [`PixelFormat`] represent colors in the sRGB colorspace?
+  ///
+  /// Such colors are converted to linear colors when sampled.
+  pub fn is_srgb(self) -> bool {
+    match self.format {
+      Format::SRGB(_, _, _) | Format::SRGBA(_, _, _, _) => true,
+      Format::Compressed(cf) => cf.is_srgb(),
+      _ => false,
+    }
+  }
+
   /// Return the number of channels.
   pub fn channels_len(self) -> usize {
     match self.format {
@@ -80,6 +130,8 @@ impl PixelFormat {
       Format::SRGBA(_, _, _, _) => 4,
       Format::Depth(_) => 1,
       Format::DepthStencil(_, _) => 2,
+      Format::Stencil(_) => 1,
+      Format::Compressed(cf) => cf.channels_len(),
     }
   }
 }
@@ -129,10 +181,17 @@ pub enum Format {
   Depth(Size),
   /// Holds a depth+stencil channel.
   DepthStencil(Size, Size),
+  /// Holds a stencil channel.
+  Stencil(Size),
+  /// Holds compressed channels.
+  Compressed(CompressedFormat),
 }
 
 impl Format {
   /// Size (in bytes) of a pixel that a format represents.
+  ///
+  /// Compressed formats don’t have a per-pixel size, so `0` is returned for them; see
+  /// [`CompressedFormat::block_bytes`] instead.
   pub fn bytes_len(self) -> usize {
     let bits = match self {
       Format::R(r) => r.bits_len(),
@@ -143,6 +202,8 @@ impl Format {
       Format::SRGBA(r, g, b, a) => r.bits_len() + g.bits_len() + b.bits_len() + a.bits_len(),
       Format::Depth(d) => d.bits_len(),
       Format::DepthStencil(d, s) => d.bits_len() + s.bits_len(),
+      Format::Stencil(s) => s.bits_len(),
+      Format::Compressed(_) => 0,
     };
 
     bits / 8
@@ -160,6 +221,8 @@ pub enum Size {
   Eleven,
   /// 16-bit.
   Sixteen,
+  /// 24-bit.
+  TwentyFour,
   /// 32-bit.
   ThirtyTwo,
 }
@@ -172,11 +235,260 @@ impl Size {
       Size::Ten => 10,
       Size::Eleven => 11,
       Size::Sixteen => 16,
+      Size::TwentyFour => 24,
       Size::ThirtyTwo => 32,
     }
   }
 }
 
+/// Compressed format of a pixel.
+///
+/// Compressed formats store pixels by blocks of [`CompressedFormat::block_size`] pixels, each of them using
+/// [`CompressedFormat::block_bytes`] bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressedFormat {
+  /// BC1 (DXT1) red, green, blue and 1-bit alpha channels.
+  BC1RGBA,
+  /// BC1 (DXT1) red, green and blue channels in sRGB colorspace, plus a 1-bit alpha channel.
+  BC1SRGBA,
+  /// BC2 (DXT3) red, green, blue and alpha channels.
+  BC2RGBA,
+  /// BC2 (DXT3) red, green and blue channels in sRGB colorspace, plus an alpha channel.
+  BC2SRGBA,
+  /// BC3 (DXT5) red, green, blue and alpha channels.
+  BC3RGBA,
+  /// BC3 (DXT5) red, green and blue channels in sRGB colorspace, plus an alpha channel.
+  BC3SRGBA,
+  /// BC4 unsigned red channel.
+  BC4R,
+  /// BC4 signed red channel.
+  BC4RSigned,
+  /// BC5 unsigned red and green channels.
+  BC5RG,
+  /// BC5 signed red and green channels.
+  BC5RGSigned,
+  /// BC6H unsigned floating red, green and blue channels.
+  BC6HRGBUnsigned,
+  /// BC6H signed floating red, green and blue channels.
+  BC6HRGBSigned,
+  /// BC7 red, green, blue and alpha channels.
+  BC7RGBA,
+  /// BC7 red, green and blue channels in sRGB colorspace, plus an alpha channel.
+  BC7SRGBA,
+  /// ETC2 red, green and blue channels.
+  ETC2RGB,
+  /// ETC2 red, green and blue channels in sRGB colorspace.
+  ETC2SRGB,
+  /// ETC2 red, green and blue channels, plus a 1-bit alpha channel.
+  ETC2RGBA1,
+  /// ETC2 red, green and blue channels in sRGB colorspace, plus a 1-bit alpha channel.
+  ETC2SRGBA1,
+  /// ETC2 red, green, blue and alpha channels.
+  ETC2RGBA,
+  /// ETC2 red, green and blue channels in sRGB colorspace, plus an alpha channel.
+  ETC2SRGBA,
+  /// EAC unsigned red channel.
+  EACR,
+  /// EAC signed red channel.
+  EACRSigned,
+  /// EAC unsigned red and green channels.
+  EACRG,
+  /// EAC signed red and green channels.
+  EACRGSigned,
+  /// ASTC red, green, blue and alpha channels.
+  ASTCRGBA(ASTCBlock),
+  /// ASTC red, green and blue channels in sRGB colorspace, plus an alpha channel.
+  ASTCSRGBA(ASTCBlock),
+}
+
+impl CompressedFormat {
+  /// Family the format belongs to.
+  pub fn family(self) -> CompressionFamily {
+    match self {
+      CompressedFormat::BC1RGBA
+      | CompressedFormat::BC1SRGBA
+      | CompressedFormat::BC2RGBA
+      | CompressedFormat::BC2SRGBA
+      | CompressedFormat::BC3RGBA
+      | CompressedFormat::BC3SRGBA => CompressionFamily::S3TC,
+
+      CompressedFormat::BC4R
+      | CompressedFormat::BC4RSigned
+      | CompressedFormat::BC5RG
+      | CompressedFormat::BC5RGSigned => CompressionFamily::RGTC,
+
+      CompressedFormat::BC6HRGBUnsigned
+      | CompressedFormat::BC6HRGBSigned
+      | CompressedFormat::BC7RGBA
+      | CompressedFormat::BC7SRGBA => CompressionFamily::BPTC,
+
+      CompressedFormat::ETC2RGB
+      | CompressedFormat::ETC2SRGB
+      | CompressedFormat::ETC2RGBA1
+      | CompressedFormat::ETC2SRGBA1
+      | CompressedFormat::ETC2RGBA
+      | CompressedFormat::ETC2SRGBA
+      | CompressedFormat::EACR
+      | CompressedFormat::EACRSigned
+      | CompressedFormat::EACRG
+      | CompressedFormat::EACRGSigned => CompressionFamily::ETC2,
+
+      CompressedFormat::ASTCRGBA(_) | CompressedFormat::ASTCSRGBA(_) => CompressionFamily::ASTC,
+    }
+  }
+
+  /// Whether the format represents colors in the sRGB colorspace.
+  pub fn is_srgb(self) -> bool {
+    matches!(
+      self,
+      CompressedFormat::BC1SRGBA
+        | CompressedFormat::BC2SRGBA
+        | CompressedFormat::BC3SRGBA
+        | CompressedFormat::BC7SRGBA
+        | CompressedFormat::ETC2SRGB
+        | CompressedFormat::ETC2SRGBA1
+        | CompressedFormat::ETC2SRGBA
+        | CompressedFormat::ASTCSRGBA(_)
+    )
+  }
+
+  /// Number of channels.
+  pub fn channels_len(self) -> usize {
+    match self {
+      CompressedFormat::BC4R
+      | CompressedFormat::BC4RSigned
+      | CompressedFormat::EACR
+      | CompressedFormat::EACRSigned => 1,
+
+      CompressedFormat::BC5RG
+      | CompressedFormat::BC5RGSigned
+      | CompressedFormat::EACRG
+      | CompressedFormat::EACRGSigned => 2,
+
+      CompressedFormat::BC6HRGBUnsigned
+      | CompressedFormat::BC6HRGBSigned
+      | CompressedFormat::ETC2RGB
+      | CompressedFormat::ETC2SRGB => 3,
+
+      _ => 4,
+    }
+  }
+
+  /// Width and height (in pixels) of a block.
+  pub fn block_size(self) -> [u32; 2] {
+    match self {
+      CompressedFormat::ASTCRGBA(block) | CompressedFormat::ASTCSRGBA(block) => block.size(),
+      _ => [4, 4],
+    }
+  }
+
+  /// Size (in bytes) of a block.
+  pub fn block_bytes(self) -> usize {
+    match self {
+      CompressedFormat::BC1RGBA
+      | CompressedFormat::BC1SRGBA
+      | CompressedFormat::BC4R
+      | CompressedFormat::BC4RSigned
+      | CompressedFormat::ETC2RGB
+      | CompressedFormat::ETC2SRGB
+      | CompressedFormat::ETC2RGBA1
+      | CompressedFormat::ETC2SRGBA1
+      | CompressedFormat::EACR
+      | CompressedFormat::EACRSigned => 8,
+
+      _ => 16,
+    }
+  }
+
+  /// Size (in bytes) of a `width` × `height` image in that format.
+  ///
+  /// Images which size is not a multiple of the block size are padded to whole blocks.
+  pub fn compressed_len(self, width: u32, height: u32) -> usize {
+    let [block_width, block_height] = self.block_size();
+    let blocks_x = div_ceil(width, block_width);
+    let blocks_y = div_ceil(height, block_height);
+
+    blocks_x as usize * blocks_y as usize * self.block_bytes()
+  }
+}
+
+/// Divide, rounding up; unlike `(a + b - 1) / b`, it cannot overflow.
+pub(crate) fn div_ceil(a: u32, b: u32) -> u32 {
+  a / b + u32::from(a % b != 0)
+}
+
+/// Block size of ASTC compressed formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ASTCBlock {
+  /// 4×4 blocks.
+  Block4x4,
+  /// 5×4 blocks.
+  Block5x4,
+  /// 5×5 blocks.
+  Block5x5,
+  /// 6×5 blocks.
+  Block6x5,
+  /// 6×6 blocks.
+  Block6x6,
+  /// 8×5 blocks.
+  Block8x5,
+  /// 8×6 blocks.
+  Block8x6,
+  /// 8×8 blocks.
+  Block8x8,
+  /// 10×5 blocks.
+  Block10x5,
+  /// 10×6 blocks.
+  Block10x6,
+  /// 10×8 blocks.
+  Block10x8,
+  /// 10×10 blocks.
+  Block10x10,
+  /// 12×10 blocks.
+  Block12x10,
+  /// 12×12 blocks.
+  Block12x12,
+}
+
+impl ASTCBlock {
+  /// Width and height (in pixels) of the block.
+  pub fn size(self) -> [u32; 2] {
+    match self {
+      ASTCBlock::Block4x4 => [4, 4],
+      ASTCBlock::Block5x4 => [5, 4],
+      ASTCBlock::Block5x5 => [5, 5],
+      ASTCBlock::Block6x5 => [6, 5],
+      ASTCBlock::Block6x6 => [6, 6],
+      ASTCBlock::Block8x5 => [8, 5],
+      ASTCBlock::Block8x6 => [8, 6],
+      ASTCBlock::Block8x8 => [8, 8],
+      ASTCBlock::Block10x5 => [10, 5],
+      ASTCBlock::Block10x6 => [10, 6],
+      ASTCBlock::Block10x8 => [10, 8],
+      ASTCBlock::Block10x10 => [10, 10],
+      ASTCBlock::Block12x10 => [12, 10],
+      ASTCBlock::Block12x12 => [12, 12],
+    }
+  }
+}
+
+/// Family of compressed formats.
+///
+/// Support for compressed formats is provided by family, depending on the driver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionFamily {
+  /// S3TC formats: BC1, BC2 and BC3.
+  S3TC,
+  /// RGTC formats: BC4 and BC5.
+  RGTC,
+  /// BPTC formats: BC6H and BC7.
+  BPTC,
+  /// ETC2 and EAC formats.
+  ETC2,
+  /// ASTC (LDR profile) formats.
+  ASTC,
+}
+
 /// The normalized (signed) integral sampler type.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct NormIntegral;
@@ -262,6 +574,19 @@ macro_rules! impl_RenderablePixel {
   };
 }
 
+macro_rules! impl_CompressedPixel {
+  ($t:ty, $encoding_ty:ident, $format:expr) => {
+    impl_Pixel!($t, u8, u8, $encoding_ty, Format::Compressed($format));
+    impl_ColorPixel!($t);
+
+    unsafe impl CompressedPixel for $t {
+      fn compressed_format() -> CompressedFormat {
+        $format
+      }
+    }
+  };
+}
+
 /// A red 8-bit signed integral pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct R8I;
@@ -364,6 +689,16 @@ impl_Pixel!(
 impl_ColorPixel!(NormR32UI);
 impl_RenderablePixel!(NormR32UI);
 
+/// A red 16-bit floating pixel format.
+///
+/// Texels are stored as half-precision floats, but uploaded and read as `f32`.
+#[derive(Clone, Copy, Debug)]
+pub struct R16F;
+
+impl_Pixel!(R16F, f32, f32, Floating, Format::R(Size::Sixteen));
+impl_ColorPixel!(R16F);
+impl_RenderablePixel!(R16F);
+
 /// A red 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct R32F;
@@ -540,6 +875,22 @@ impl_Pixel!(
 impl_ColorPixel!(NormRG32UI);
 impl_RenderablePixel!(NormRG32UI);
 
+/// A red and green 16-bit floating pixel format.
+///
+/// Texels are stored as half-precision floats, but uploaded and read as `f32`.
+#[derive(Clone, Copy, Debug)]
+pub struct RG16F;
+
+impl_Pixel!(
+  RG16F,
+  [f32; 2],
+  f32,
+  Floating,
+  Format::RG(Size::Sixteen, Size::Sixteen)
+);
+impl_ColorPixel!(RG16F);
+impl_RenderablePixel!(RG16F);
+
 /// A red and green 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct RG32F;
@@ -728,6 +1079,22 @@ impl_Pixel!(
 impl_ColorPixel!(NormRGB32UI);
 impl_RenderablePixel!(NormRGB32UI);
 
+/// A red, green and blue 16-bit floating pixel format.
+///
+/// Texels are stored as half-precision floats, but uploaded and read as `f32`.
+#[derive(Clone, Copy, Debug)]
+pub struct RGB16F;
+
+impl_Pixel!(
+  RGB16F,
+  [f32; 3],
+  f32,
+  Floating,
+  Format::RGB(Size::Sixteen, Size::Sixteen, Size::Sixteen)
+);
+impl_ColorPixel!(RGB16F);
+impl_RenderablePixel!(RGB16F);
+
 /// A red, green and blue 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct RGB32F;
@@ -936,6 +1303,23 @@ impl_Pixel!(
 impl_ColorPixel!(NormRGBA32UI);
 impl_RenderablePixel!(NormRGBA32UI);
 
+/// A red, green, blue and alpha 16-bit floating pixel format.
+///
+/// Texels are stored as half-precision floats, but uploaded and read as `f32`. This is the usual format of the color
+/// slots of HDR pipelines, rendered to before being tonemapped.
+#[derive(Clone, Copy, Debug)]
+pub struct RGBA16F;
+
+impl_Pixel!(
+  RGBA16F,
+  [f32; 4],
+  f32,
+  Floating,
+  Format::RGBA(Size::Sixteen, Size::Sixteen, Size::Sixteen, Size::Sixteen)
+);
+impl_ColorPixel!(RGBA16F);
+impl_RenderablePixel!(RGBA16F);
+
 /// A red, green, blue and alpha 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct RGBA32F;
@@ -974,6 +1358,8 @@ impl_ColorPixel!(R11G11B10F);
 impl_RenderablePixel!(R11G11B10F);
 
 /// An 8-bit unsigned integral red, green and blue pixel format in sRGB colorspace.
+///
+/// Texels are linearized when sampled, and accessed as normalized floating pixels.
 #[derive(Clone, Copy, Debug)]
 pub struct SRGB8UI;
 
@@ -988,6 +1374,8 @@ impl_ColorPixel!(SRGB8UI);
 impl_RenderablePixel!(SRGB8UI);
 
 /// An 8-bit unsigned integral red, green and blue pixel format in sRGB colorspace, with linear alpha channel.
+///
+/// Texels are linearized when sampled, and accessed as normalized floating pixels.
 #[derive(Clone, Copy, Debug)]
 pub struct SRGBA8UI;
 
@@ -1001,6 +1389,34 @@ impl_Pixel!(
 impl_ColorPixel!(SRGBA8UI);
 impl_RenderablePixel!(SRGBA8UI);
 
+/// A depth 16-bit normalized pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct Depth16;
+
+impl_Pixel!(
+  Depth16,
+  u16,
+  u16,
+  NormUnsigned,
+  Format::Depth(Size::Sixteen)
+);
+impl_DepthPixel!(Depth16);
+
+/// A depth 24-bit normalized pixel format.
+///
+/// Each depth value is stored in the 24 least significant bits of a 32-bit word.
+#[derive(Clone, Copy, Debug)]
+pub struct Depth24;
+
+impl_Pixel!(
+  Depth24,
+  u32,
+  u32,
+  NormUnsigned,
+  Format::Depth(Size::TwentyFour)
+);
+impl_DepthPixel!(Depth24);
+
 /// A depth 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct Depth32F;
@@ -1008,7 +1424,22 @@ pub struct Depth32F;
 impl_Pixel!(Depth32F, f32, f32, Floating, Format::Depth(Size::ThirtyTwo));
 impl_DepthPixel!(Depth32F);
 
-/// A depth 24-bit + stencil 8-bit pixel format.
+/// A depth 24-bit normalized + stencil 8-bit pixel format.
+///
+/// Each depth/stencil value is packed in a 32-bit word, with the depth in the 24 most significant bits.
+#[derive(Clone, Copy, Debug)]
+pub struct Depth24Stencil8;
+
+impl_Pixel!(
+  Depth24Stencil8,
+  u32,
+  u32,
+  NormUnsigned,
+  Format::DepthStencil(Size::TwentyFour, Size::Eight)
+);
+impl_DepthPixel!(Depth24Stencil8);
+
+/// A depth 32-bit floating + stencil 8-bit pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct Depth32FStencil8;
 
@@ -1020,3 +1451,202 @@ impl_Pixel!(
   Format::DepthStencil(Size::ThirtyTwo, Size::Eight)
 );
 impl_DepthPixel!(Depth32FStencil8);
+
+/// A stencil 8-bit pixel format.
+///
+/// Stencil-only storage is typically allocated as a [`Renderbuffer`], as backends might not support stencil-only
+/// textures.
+///
+/// [`Renderbuffer`]: crate::framebuffer::Renderbuffer
+#[derive(Clone, Copy, Debug)]
+pub struct Stencil8;
+
+impl_Pixel!(Stencil8, u8, u8, Unsigned, Format::Stencil(Size::Eight));
+impl_DepthPixel!(Stencil8);
+
+/// A BC1 (DXT1) compressed red, green, blue and 1-bit alpha pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC1RGBA;
+
+impl_CompressedPixel!(BC1RGBA, NormUnsigned, CompressedFormat::BC1RGBA);
+
+/// A BC1 (DXT1) compressed red, green and blue pixel format in sRGB colorspace, with 1-bit alpha channel.
+#[derive(Clone, Copy, Debug)]
+pub struct BC1SRGBA;
+
+impl_CompressedPixel!(BC1SRGBA, NormUnsigned, CompressedFormat::BC1SRGBA);
+
+/// A BC2 (DXT3) compressed red, green, blue and alpha pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC2RGBA;
+
+impl_CompressedPixel!(BC2RGBA, NormUnsigned, CompressedFormat::BC2RGBA);
+
+/// A BC2 (DXT3) compressed red, green and blue pixel format in sRGB colorspace, with linear alpha channel.
+#[derive(Clone, Copy, Debug)]
+pub struct BC2SRGBA;
+
+impl_CompressedPixel!(BC2SRGBA, NormUnsigned, CompressedFormat::BC2SRGBA);
+
+/// A BC3 (DXT5) compressed red, green, blue and alpha pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC3RGBA;
+
+impl_CompressedPixel!(BC3RGBA, NormUnsigned, CompressedFormat::BC3RGBA);
+
+/// A BC3 (DXT5) compressed red, green and blue pixel format in sRGB colorspace, with linear alpha channel.
+#[derive(Clone, Copy, Debug)]
+pub struct BC3SRGBA;
+
+impl_CompressedPixel!(BC3SRGBA, NormUnsigned, CompressedFormat::BC3SRGBA);
+
+/// A BC4 compressed unsigned red pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct BC4R;
+
+impl_CompressedPixel!(BC4R, NormUnsigned, CompressedFormat::BC4R);
+
+/// A BC4 compressed signed red pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct BC4RSigned;
+
+impl_CompressedPixel!(BC4RSigned, NormIntegral, CompressedFormat::BC4RSigned);
+
+/// A BC5 compressed unsigned red and green pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct BC5RG;
+
+impl_CompressedPixel!(BC5RG, NormUnsigned, CompressedFormat::BC5RG);
+
+/// A BC5 compressed signed red and green pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct BC5RGSigned;
+
+impl_CompressedPixel!(BC5RGSigned, NormIntegral, CompressedFormat::BC5RGSigned);
+
+/// A BC6H compressed unsigned floating red, green and blue pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC6HRGBUnsigned;
+
+impl_CompressedPixel!(BC6HRGBUnsigned, Floating, CompressedFormat::BC6HRGBUnsigned);
+
+/// A BC6H compressed signed floating red, green and blue pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC6HRGBSigned;
+
+impl_CompressedPixel!(BC6HRGBSigned, Floating, CompressedFormat::BC6HRGBSigned);
+
+/// A BC7 compressed red, green, blue and alpha pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC7RGBA;
+
+impl_CompressedPixel!(BC7RGBA, NormUnsigned, CompressedFormat::BC7RGBA);
+
+/// A BC7 compressed red, green and blue pixel format in sRGB colorspace, with linear alpha channel.
+#[derive(Clone, Copy, Debug)]
+pub struct BC7SRGBA;
+
+impl_CompressedPixel!(BC7SRGBA, NormUnsigned, CompressedFormat::BC7SRGBA);
+
+/// An ETC2 compressed red, green and blue pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2RGB;
+
+impl_CompressedPixel!(ETC2RGB, NormUnsigned, CompressedFormat::ETC2RGB);
+
+/// An ETC2 compressed red, green and blue pixel format in sRGB colorspace.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2SRGB;
+
+impl_CompressedPixel!(ETC2SRGB, NormUnsigned, CompressedFormat::ETC2SRGB);
+
+/// An ETC2 compressed red, green, blue and 1-bit alpha pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2RGBA1;
+
+impl_CompressedPixel!(ETC2RGBA1, NormUnsigned, CompressedFormat::ETC2RGBA1);
+
+/// An ETC2 compressed red, green and blue pixel format in sRGB colorspace, with 1-bit alpha channel.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2SRGBA1;
+
+impl_CompressedPixel!(ETC2SRGBA1, NormUnsigned, CompressedFormat::ETC2SRGBA1);
+
+/// An ETC2 compressed red, green, blue and alpha pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2RGBA;
+
+impl_CompressedPixel!(ETC2RGBA, NormUnsigned, CompressedFormat::ETC2RGBA);
+
+/// An ETC2 compressed red, green and blue pixel format in sRGB colorspace, with linear alpha channel.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2SRGBA;
+
+impl_CompressedPixel!(ETC2SRGBA, NormUnsigned, CompressedFormat::ETC2SRGBA);
+
+/// An EAC compressed unsigned red pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct EACR;
+
+impl_CompressedPixel!(EACR, NormUnsigned, CompressedFormat::EACR);
+
+/// An EAC compressed signed red pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct EACRSigned;
+
+impl_CompressedPixel!(EACRSigned, NormIntegral, CompressedFormat::EACRSigned);
+
+/// An EAC compressed unsigned red and green pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct EACRG;
+
+impl_CompressedPixel!(EACRG, NormUnsigned, CompressedFormat::EACRG);
+
+/// An EAC compressed signed red and green pixel format, accessed as normalized floating pixels.
+#[derive(Clone, Copy, Debug)]
+pub struct EACRGSigned;
+
+impl_CompressedPixel!(EACRGSigned, NormIntegral, CompressedFormat::EACRGSigned);
+
+macro_rules! impl_ASTCPixel {
+  ($rgba:ident, $srgba:ident, $block:ident, $size:literal) => {
+    #[doc = concat!("An ASTC compressed red, green, blue and alpha pixel format, with ", $size, " blocks.")]
+    #[derive(Clone, Copy, Debug)]
+    pub struct $rgba;
+
+    impl_CompressedPixel!(
+      $rgba,
+      NormUnsigned,
+      CompressedFormat::ASTCRGBA(ASTCBlock::$block)
+    );
+
+    #[doc = concat!(
+      "An ASTC compressed red, green and blue pixel format in sRGB colorspace, with linear alpha channel and ",
+      $size,
+      " blocks."
+    )]
+    #[derive(Clone, Copy, Debug)]
+    pub struct $srgba;
+
+    impl_CompressedPixel!(
+      $srgba,
+      NormUnsigned,
+      CompressedFormat::ASTCSRGBA(ASTCBlock::$block)
+    );
+  };
+}
+
+impl_ASTCPixel!(ASTC4x4RGBA, ASTC4x4SRGBA, Block4x4, "4×4");
+impl_ASTCPixel!(ASTC5x4RGBA, ASTC5x4SRGBA, Block5x4, "5×4");
+impl_ASTCPixel!(ASTC5x5RGBA, ASTC5x5SRGBA, Block5x5, "5×5");
+impl_ASTCPixel!(ASTC6x5RGBA, ASTC6x5SRGBA, Block6x5, "6×5");
+impl_ASTCPixel!(ASTC6x6RGBA, ASTC6x6SRGBA, Block6x6, "6×6");
+impl_ASTCPixel!(ASTC8x5RGBA, ASTC8x5SRGBA, Block8x5, "8×5");
+impl_ASTCPixel!(ASTC8x6RGBA, ASTC8x6SRGBA, Block8x6, "8×6");
+impl_ASTCPixel!(ASTC8x8RGBA, ASTC8x8SRGBA, Block8x8, "8×8");
+impl_ASTCPixel!(ASTC10x5RGBA, ASTC10x5SRGBA, Block10x5, "10×5");
+impl_ASTCPixel!(ASTC10x6RGBA, ASTC10x6SRGBA, Block10x6, "10×6");
+impl_ASTCPixel!(ASTC10x8RGBA, ASTC10x8SRGBA, Block10x8, "10×8");
+impl_ASTCPixel!(ASTC10x10RGBA, ASTC10x10SRGBA, Block10x10, "10×10");
+impl_ASTCPixel!(ASTC12x10RGBA, ASTC12x10SRGBA, Block12x10, "12×10");
+impl_ASTCPixel!(ASTC12x12RGBA, ASTC12x12SRGBA, Block12x12, "12×12");
@@ -0,0 +1,190 @@
+//! Pixel buffers.
+//!
+//! Uploading texels to a [`Texture`] with [`Texture::upload_part`] copies them right away, which stalls the frame
+//! when uploading large textures. Instead, texels can be _streamed_: they are written to a [`PixelBuffer`] — a GPU
+//! buffer mapped in memory, via [`PixelBuffer::slice_mut`] — and then uploaded to a texture from the buffer with
+//! [`Texture::upload_part_level_from_buffer`]. That upload is only scheduled: the GPU performs it asynchronously, and
+//! the returned [`UploadFence`] tells when it is complete.
+//!
+//! A pixel buffer can be written to again while an upload from it is still pending; the pending upload is not
+//! affected. Streaming a video is then typically done with a single pixel buffer, written to and uploaded from once
+//! per frame.
+//!
+//! [`Texture`]: crate::texture::Texture
+//! [`Texture::upload_part`]: crate::texture::Texture::upload_part
+//! [`Texture::upload_part_level_from_buffer`]: crate::texture::Texture::upload_part_level_from_buffer
+
+use crate::{
+  backend::pixel_buffer::{
+    PixelBuffer as PixelBufferBackend, PixelBufferSliceMut as PixelBufferSliceMutBackend,
+    UploadFence as UploadFenceBackend,
+  },
+  context::GraphicsContext,
+};
+use std::{
+  error, fmt,
+  marker::PhantomData,
+  ops::{Deref, DerefMut},
+  time::Duration,
+};
+
+/// Errors that might occur when using pixel buffers.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PixelBufferError {
+  /// Cannot create the pixel buffer.
+  CannotCreate(String),
+  /// Cannot map the pixel buffer in memory.
+  CannotMap(String),
+}
+
+impl PixelBufferError {
+  /// Cannot create the pixel buffer.
+  pub fn cannot_create(reason: impl Into<String>) -> Self {
+    PixelBufferError::CannotCreate(reason.into())
+  }
+
+  /// Cannot map the pixel buffer in memory.
+  pub fn cannot_map(reason: impl Into<String>) -> Self {
+    PixelBufferError::CannotMap(reason.into())
+  }
+}
+
+impl fmt::Display for PixelBufferError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      PixelBufferError::CannotCreate(ref reason) => {
+        write!(f, "cannot create pixel buffer: {}", reason)
+      }
+
+      PixelBufferError::CannotMap(ref reason) => write!(f, "cannot map pixel buffer: {}", reason),
+    }
+  }
+}
+
+impl error::Error for PixelBufferError {}
+
+/// A GPU buffer of texels, used to stream them to textures.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `T` is the type of the texels, which is the [`Pixel::Encoding`] of the textures to upload to.
+///
+/// [`Pixel::Encoding`]: crate::pixel::Pixel::Encoding
+pub struct PixelBuffer<B, T>
+where
+  B: ?Sized + PixelBufferBackend<T>,
+{
+  pub(crate) repr: B::PixelBufferRepr,
+  len: usize,
+  _phantom: PhantomData<*const T>,
+}
+
+impl<B, T> PixelBuffer<B, T>
+where
+  B: ?Sized + PixelBufferBackend<T>,
+{
+  /// Create a new [`PixelBuffer`] holding `len` texels.
+  ///
+  /// The content of the buffer is undefined until written to with [`PixelBuffer::slice_mut`].
+  ///
+  /// [`PixelBufferError::CannotCreate`] is returned if `len` is zero.
+  pub fn new<C>(ctx: &mut C, len: usize) -> Result<Self, PixelBufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    if len == 0 {
+      return Err(PixelBufferError::cannot_create("empty pixel buffer"));
+    }
+
+    unsafe {
+      ctx.backend().new_pixel_buffer(len).map(|repr| PixelBuffer {
+        repr,
+        len,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Number of texels the buffer holds.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether the buffer holds no texel; always `false`, as pixel buffers cannot be empty.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Map the buffer in memory to write texels to it.
+  ///
+  /// The previous content of the buffer is lost, so all the texels to upload must be written again. Uploads from the
+  /// buffer still pending are not affected.
+  pub fn slice_mut<'a>(&'a mut self) -> Result<PixelBufferSliceMut<'a, B, T>, PixelBufferError>
+  where
+    B: PixelBufferSliceMutBackend<'a, T>,
+  {
+    unsafe { B::slice_mut(&mut self.repr).map(|repr| PixelBufferSliceMut { repr }) }
+  }
+}
+
+/// Texels of a [`PixelBuffer`] mapped in memory.
+///
+/// The buffer is unmapped when this object is dropped.
+#[derive(Debug)]
+pub struct PixelBufferSliceMut<'a, B, T>
+where
+  B: ?Sized + PixelBufferSliceMutBackend<'a, T>,
+{
+  repr: B::PixelBufferSliceMutRepr,
+}
+
+impl<'a, B, T> Deref for PixelBufferSliceMut<'a, B, T>
+where
+  B: ?Sized + PixelBufferSliceMutBackend<'a, T>,
+{
+  type Target = [T];
+
+  fn deref(&self) -> &Self::Target {
+    self.repr.deref()
+  }
+}
+
+impl<'a, B, T> DerefMut for PixelBufferSliceMut<'a, B, T>
+where
+  B: ?Sized + PixelBufferSliceMutBackend<'a, T>,
+{
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    self.repr.deref_mut()
+  }
+}
+
+/// Completion handle of an upload from a [`PixelBuffer`].
+///
+/// Dropping the fence doesn’t cancel the upload.
+pub struct UploadFence<B>
+where
+  B: ?Sized + UploadFenceBackend,
+{
+  pub(crate) repr: B::UploadFenceRepr,
+}
+
+impl<B> UploadFence<B>
+where
+  B: ?Sized + UploadFenceBackend,
+{
+  /// Whether the upload is complete.
+  ///
+  /// This method never blocks.
+  pub fn is_complete(&self) -> bool {
+    unsafe { B::is_upload_complete(&self.repr) }
+  }
+
+  /// Block until the upload is complete, for at most `timeout`.
+  ///
+  /// Return whether the upload is complete.
+  pub fn wait(&self, timeout: Duration) -> bool {
+    unsafe { B::wait_upload(&self.repr, timeout) }
+  }
+}
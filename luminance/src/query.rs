@@ -5,6 +5,8 @@
 use crate::{
   backend::query::{Query as QueryBackend, QueryError},
   context::GraphicsContext,
+  pixel::CompressionFamily,
+  shader::GlslVersion,
 };
 
 /// Query object.
@@ -49,8 +51,57 @@ where
     self.backend.backend_shading_lang_version()
   }
 
+  /// The GLSL version supported by the backend.
+  ///
+  /// This is [`Query::backend_shading_lang_version`] parsed as a [`GlslVersion`].
+  pub fn glsl_version(&self) -> Result<GlslVersion, QueryError> {
+    let version = self.backend.backend_shading_lang_version()?;
+    GlslVersion::parse(&version).ok_or(QueryError::UnknownShadingLanguageVersion(version))
+  }
+
   /// Maximum number of elements a texture array can hold.
   pub fn max_texture_array_elements(&self) -> Result<usize, QueryError> {
     self.backend.max_texture_array_elements()
   }
+
+  /// Whether a family of compressed pixel formats is supported.
+  ///
+  /// Textures with [`CompressedPixel`] formats of a non-supported family cannot be created.
+  ///
+  /// [`CompressedPixel`]: crate::pixel::CompressedPixel
+  pub fn supports_compression(&self, family: CompressionFamily) -> Result<bool, QueryError> {
+    self.backend.supports_compression(family)
+  }
+
+  /// The maximum anisotropy of anisotropic filtering, or `None` if anisotropic filtering is not supported.
+  ///
+  /// [`Sampler::max_anisotropy`] is clamped to that value.
+  ///
+  /// [`Sampler::max_anisotropy`]: crate::texture::Sampler::max_anisotropy
+  pub fn max_anisotropy(&self) -> Result<Option<f32>, QueryError> {
+    self.backend.max_anisotropy()
+  }
+
+  /// Whether 32-bit floating-point color attachments — such as [`RGBA32F`] — can be blended.
+  ///
+  /// Blending 16-bit floating-point attachments — such as [`RGBA16F`] — and [`R11G11B10F`] ones is always supported,
+  /// which makes them the formats of choice for HDR color slots when blending is needed.
+  ///
+  /// [`RGBA32F`]: crate::pixel::RGBA32F
+  /// [`RGBA16F`]: crate::pixel::RGBA16F
+  /// [`R11G11B10F`]: crate::pixel::R11G11B10F
+  pub fn supports_float_blending(&self) -> Result<bool, QueryError> {
+    self.backend.supports_float_blending()
+  }
+
+  /// Whether the color buffer of the back buffer has an sRGB encoding.
+  ///
+  /// If so, linear colors rendered to the back buffer are encoded to sRGB when sRGB encoding is enabled with
+  /// [`PipelineState::enable_srgb`], and the shaders can output linear colors. Otherwise, the shaders must encode the
+  /// colors themselves — for instance when tonemapping — for them to be displayed correctly.
+  ///
+  /// [`PipelineState::enable_srgb`]: crate::pipeline::PipelineState::enable_srgb
+  pub fn is_back_buffer_srgb(&self) -> Result<bool, QueryError> {
+    self.backend.is_back_buffer_srgb()
+  }
 }
@@ -5,9 +5,12 @@
 //!
 //! [`Tess`]: crate::tess::Tess
 
-use crate::backend::render_gate::RenderGate as RenderGateBackend;
+use crate::backend::render_gate::{RenderGate as RenderGateBackend, RenderGateMesh};
+use crate::backend::transform_feedback::TransformFeedbackCapture;
 use crate::render_state::RenderState;
+use crate::tess::{Interleaved, Tess, TessIndex, TessVertexData};
 use crate::tess_gate::TessGate;
+use crate::transform_feedback::{TransformFeedback, TransformFeedbackError};
 
 /// A render gate.
 ///
@@ -40,4 +43,65 @@ where
 
     f(tess_gate)
   }
+
+  /// Draw with the mesh program in-use, launching `count` work groups with the given [`RenderState`].
+  ///
+  /// The work groups are task shader work groups if the program has a task stage, and mesh shader work groups
+  /// otherwise. The program in-use must be a mesh program — see [`ProgramBuilder::from_mesh_stages`]; otherwise, nothing
+  /// is drawn.
+  ///
+  /// [`ProgramBuilder::from_mesh_stages`]: crate::shader::ProgramBuilder::from_mesh_stages
+  pub fn draw_mesh_tasks(&mut self, rdr_st: &RenderState, count: u32)
+  where
+    B: RenderGateMesh,
+  {
+    unsafe {
+      self.backend.enter_render_state(rdr_st);
+      self.backend.draw_mesh_tasks(count);
+    }
+  }
+
+  /// Enter a [`RenderGate`] capturing the vertices into the vertex buffer of a [`Tess`] with transform feedback.
+  ///
+  /// The varyings declared with [`ProgramBuilder::capture_varyings`] are written interleaved into `output`, which
+  /// primitive mode must be either [`Mode::Point`], [`Mode::Line`] or [`Mode::Triangle`]; the rendered primitives must be
+  /// of the same kind. Rasterization is disabled while capturing, so nothing is rendered into the framebuffer.
+  ///
+  /// [`ProgramBuilder::capture_varyings`]: crate::shader::ProgramBuilder::capture_varyings
+  /// [`Mode::Point`]: crate::tess::Mode::Point
+  /// [`Mode::Line`]: crate::tess::Mode::Line
+  /// [`Mode::Triangle`]: crate::tess::Mode::Triangle
+  pub fn capture<E, V, I, W, F>(
+    &mut self,
+    feedback: &mut TransformFeedback<B>,
+    output: &mut Tess<B, V, I, W, Interleaved>,
+    f: F,
+  ) -> Result<(), E>
+  where
+    B: TransformFeedbackCapture<V, I, W>,
+    V: TessVertexData<Interleaved>,
+    I: TessIndex,
+    W: TessVertexData<Interleaved>,
+    E: From<TransformFeedbackError>,
+    F: for<'b> FnOnce(TessGate<'b, B>) -> Result<(), E>,
+  {
+    unsafe {
+      self
+        .backend
+        .begin_capture(&mut feedback.repr, &mut output.repr)?;
+    }
+
+    let tess_gate = TessGate {
+      backend: &mut *self.backend,
+    };
+    let result = f(tess_gate);
+
+    unsafe {
+      self
+        .backend
+        .end_capture(&mut feedback.repr, &mut output.repr);
+    }
+
+    result
+  }
 }
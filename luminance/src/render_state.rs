@@ -5,6 +5,7 @@
 
 use crate::{
   blending::{Blending, BlendingMode},
+  color_mask::ColorMask,
   depth_stencil::{Comparison, StencilOperations, StencilTest, Write},
   face_culling::FaceCulling,
   scissor::ScissorRegion,
@@ -30,6 +31,10 @@ pub struct RenderState {
   face_culling: Option<FaceCulling>,
   /// Scissor region configuration.
   scissor: Option<ScissorRegion>,
+  /// Color mask of all color attachments.
+  color_mask: ColorMask,
+  /// Color masks of specific color attachments.
+  color_attachment_masks: Vec<(usize, ColorMask)>,
 }
 
 impl RenderState {
@@ -146,6 +151,47 @@ impl RenderState {
   pub fn scissor(&self) -> &Option<ScissorRegion> {
     &self.scissor
   }
+
+  /// Override the color mask of all color attachments.
+  ///
+  /// Color attachments with their own color mask, set with [`RenderState::set_color_attachment_mask`], are not
+  /// affected.
+  pub fn set_color_mask(self, color_mask: ColorMask) -> Self {
+    RenderState { color_mask, ..self }
+  }
+
+  /// Color mask of all color attachments.
+  pub fn color_mask(&self) -> ColorMask {
+    self.color_mask
+  }
+
+  /// Override the color mask of the color attachment at `index`, replacing its previous one.
+  ///
+  /// Backends not supporting per-attachment color masks ignore it.
+  pub fn set_color_attachment_mask(mut self, index: usize, color_mask: ColorMask) -> Self {
+    match self
+      .color_attachment_masks
+      .iter_mut()
+      .find(|(i, _)| *i == index)
+    {
+      Some((_, previous)) => *previous = color_mask,
+      None => self.color_attachment_masks.push((index, color_mask)),
+    }
+
+    self
+  }
+
+  /// Disable writing to the color attachment at `index`.
+  ///
+  /// This is the same as setting its color mask to [`ColorMask::NONE`].
+  pub fn disable_color_attachment(self, index: usize) -> Self {
+    self.set_color_attachment_mask(index, ColorMask::NONE)
+  }
+
+  /// Color masks of specific color attachments.
+  pub fn color_attachment_masks(&self) -> &[(usize, ColorMask)] {
+    &self.color_attachment_masks
+  }
 }
 
 impl Default for RenderState {
@@ -158,6 +204,8 @@ impl Default for RenderState {
   ///   - `stencil_operations`: `StencilOperations::default()`
   ///   - `face_culling`: `None`
   ///   - 'scissor_region`: `None`
+  ///   - `color_mask`: `ColorMask::ALL`
+  ///   - `color_attachment_masks`: none
   fn default() -> Self {
     RenderState {
       blending: None,
@@ -167,6 +215,8 @@ impl Default for RenderState {
       stencil_operations: StencilOperations::default(),
       face_culling: None,
       scissor: None,
+      color_mask: ColorMask::ALL,
+      color_attachment_masks: Vec::new(),
     }
   }
 }
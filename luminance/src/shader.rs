@@ -28,6 +28,15 @@
 //! Those are not all mandatory: only the _vertex_ stage and _fragment_ stages are mandatory. If
 //! you want tessellation shaders, you have to provide both of them.
 //!
+//! Backends supporting it also allow to use a sixth stage, [`StageType::ComputeShader`], which is
+//! not part of the graphics pipeline. It is linked alone into a [`ComputeProgram`], dispatched with
+//! [`ShadingGate::compute`].
+//!
+//! Backends supporting separate shader objects also allow to link a single stage into a _separable_
+//! [`Program`], and to mix and match such programs at render time in a [`ProgramPipeline`], shaded
+//! with [`ShadingGate::shade_pipeline`]. Uniforms of separable programs are updated with
+//! [`Program::update`], outside of any shading gate.
+//!
 //! Shader stages — [`Stage`] — are compiled independently at runtime by your GPU driver, and then
 //! _linked_ into a shader program. The creation of a [`Stage`] implies using an input string,
 //! representing the _source code_ of the stage. This is an opaque [`String`] that must represent
@@ -51,6 +60,10 @@
 //! helper methods allow to create a shader [`Program`] directly from the string source for each
 //! stage, removing the need to build each stage individually.
 //!
+//! Compiling and linking can take a while. To avoid stalling the render loop, a [`DeferredProgram`] can be created
+//! instead with [`Program::new_deferred`]: the backend then builds the program in the background, and the
+//! [`DeferredProgram`] is polled until it resolves to the [`Program`].
+//!
 //! Shader programs are typed with three important piece of information:
 //!
 //! - The vertex [`Semantics`].
@@ -115,6 +128,70 @@
 //! Most implementation also allows much more data via this mechanism, allowing to pass huge amount
 //! of data to implement various techniques, such as _geometry instancing_ for instance.
 //!
+//! On the shader side, a [`ShaderData`] is exposed as a named uniform block (i.e. `uniform Positions { … };`). You
+//! refer to that block with a `Uniform<ShaderDataBinding<T>>`, asked by using the name of the block — not the name of
+//! its instance. Once in a pipeline, [`Pipeline::bind_shader_data`] binds the [`ShaderData`] to a binding point, and
+//! setting the uniform to [`BoundShaderData::binding`] connects the block to that binding point:
+//!
+//! ```ignore
+//! #[derive(UniformInterface)]
+//! struct ShaderInterface {
+//!   #[uniform(name = "Positions")]
+//!   positions: Uniform<ShaderDataBinding<Vec2<f32>>>,
+//! }
+//!
+//! // later, in a pipeline
+//! let bound_shader_data = pipeline.bind_shader_data(&mut shader_data)?;
+//!
+//! shd_gate.shade(&mut program, |mut iface, uni, mut rdr_gate| {
+//!   iface.set(&uni.positions, bound_shader_data.binding());
+//!   // …
+//! })
+//! ```
+//!
+//! Backends supporting it also allow to bind a [`ShaderData`] as _shader storage_ with
+//! [`Pipeline::bind_shader_storage`]. The data is then exposed as a buffer block (i.e. `buffer Particles { … };`), which
+//! shader stages can write to, and referred to with a `Uniform<ShaderStorageBinding<T>>`. Use [`ShaderData::read`] to
+//! get the modified data back.
+//!
+//! Atomic counters (`atomic_uint`), on the other hand, are not set via uniform interfaces: they live in
+//! [`AtomicCounters`], bound with [`Pipeline::bind_atomic_counters`] at the binding point the shader stages declare. Use
+//! [`Program::active_atomic_counters`] to find out those binding points.
+//!
+//! ## Subroutines
+//!
+//! Backends supporting it allow to select the function called by a _subroutine uniform_ without changing the shader
+//! program — for instance, to switch between lighting models. A [`SubroutineUniform`] and the [`Subroutine`]s it can
+//! call are looked up per shader stage with [`UniformBuilder::ask_subroutine_uniform`] and
+//! [`UniformBuilder::ask_subroutine`], and the selection is done with [`ProgramInterface::set_subroutine`].
+//!
+//! ## Mesh shaders
+//!
+//! Backends supporting it can build mesh programs with [`ProgramBuilder::from_mesh_stages`], in which an optional task
+//! stage and a mesh stage replace the vertex processing stages. Instead of processing vertices, they generate
+//! primitives in work groups — which is well suited to render _meshlets_ — launched with
+//! [`RenderGate::draw_mesh_tasks`].
+//!
+//! ## Includes and defines
+//!
+//! Stages can be split into several files with `#include` directives, expanded before compilation by
+//! [`Stage::new_with_includes`]. See the [`include`] module for further details.
+//!
+//! Permutations of a stage — such as variants of an _ubershader_ — can be built from the same source with
+//! [`Stage::new_with_defines`], which injects `#define` directives before compilation. Such permutations can be kept
+//! around and shared with a [`ProgramCache`], so that they are only built once.
+//!
+//! ## GLSL versions
+//!
+//! Backends prepend a preamble to the sources of the stages, declaring the GLSL version they target — e.g.
+//! `#version 330 core` for OpenGL 3.3 or `#version 300 es` for WebGL 2 — along with the required extensions and
+//! default precisions. The same shader bodies can then be shared across backends. A source that starts with its own
+//! `#version` directive keeps it; the rest of the preamble is then inserted right after it. The GLSL version supported
+//! by a context can be queried with [`Query::glsl_version`].
+//!
+//! With the `naga` feature, stages can also be written in other shading languages, such as WGSL, and translated into
+//! the GLSL version of the backend with [`Stage::from_ir`]. See the [`ir`] module for further details.
+//!
 //! ## Uniform interfaces
 //!
 //! As with vertex semantics and render targets, the uniforms that can be used with a shader program
@@ -123,20 +200,47 @@
 //! fields in it. More on the [`UniformInterface`] documentation.
 //!
 //! [`Vertex`]: crate::vertex::Vertex
+//! [`RenderGate::draw_mesh_tasks`]: crate::render_gate::RenderGate::draw_mesh_tasks
+//! [`ProgramCache`]: crate::shader::cache::ProgramCache
+//! [`Query::glsl_version`]: crate::query::Query::glsl_version
+//! [`ShadingGate::compute`]: crate::shading_gate::ShadingGate::compute
+//! [`ShadingGate::shade_pipeline`]: crate::shading_gate::ShadingGate::shade_pipeline
 //! [`Pipeline`]: crate::pipeline::Pipeline
+//! [`Pipeline::bind_shader_data`]: crate::pipeline::Pipeline::bind_shader_data
+//! [`Pipeline::bind_shader_storage`]: crate::pipeline::Pipeline::bind_shader_storage
+//! [`Pipeline::bind_atomic_counters`]: crate::pipeline::Pipeline::bind_atomic_counters
+//! [`BoundShaderData::binding`]: crate::pipeline::BoundShaderData::binding
 //! [`ShaderData`]: crate::shader::ShaderData
 
+pub mod cache;
+pub mod diagnostic;
+pub mod include;
+#[cfg(feature = "naga")]
+pub mod ir;
 pub mod types;
 
 use crate::{
-  backend::shader::{Shader, ShaderData as ShaderDataBackend, Uniformable},
+  backend::shader::{
+    AtomicCounters as AtomicCountersBackend, Shader, ShaderCompute as ShaderComputeBackend,
+    ShaderData as ShaderDataBackend, ShaderDeferred as ShaderDeferredBackend,
+    ShaderMesh as ShaderMeshBackend, ShaderReload as ShaderReloadBackend,
+    ShaderSeparable as ShaderSeparableBackend, ShaderStorage as ShaderStorageBackend,
+    ShaderSubroutine as ShaderSubroutineBackend, ShaderValidation as ShaderValidationBackend,
+    Uniformable, UniformableRange,
+  },
   context::GraphicsContext,
-  vertex::Semantics,
+  shader::{
+    diagnostic::{Diagnostic, ShaderLog},
+    include::{expand_includes, IncludeError, IncludeResolver},
+    types::Arr,
+  },
+  tess::Mode,
+  vertex::{Normalized, Semantics, VertexAttribType, VertexBufferDesc},
 };
-use std::{error, fmt, marker::PhantomData};
+use std::{error, ffi::CStr, fmt, iter, marker::PhantomData, sync::Arc, task::Poll};
 
 /// A shader stage type.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum StageType {
   /// Vertex shader.
   VertexShader,
@@ -148,6 +252,12 @@ pub enum StageType {
   GeometryShader,
   /// Fragment shader.
   FragmentShader,
+  /// Compute shader.
+  ComputeShader,
+  /// Task shader, run before the mesh shader to decide how many mesh shader work groups are launched.
+  TaskShader,
+  /// Mesh shader, generating primitives without any vertex input.
+  MeshShader,
 }
 
 impl fmt::Display for StageType {
@@ -158,6 +268,75 @@ impl fmt::Display for StageType {
       StageType::TessellationEvaluationShader => f.write_str("tessellation evaluation shader"),
       StageType::GeometryShader => f.write_str("geometry shader"),
       StageType::FragmentShader => f.write_str("fragment shader"),
+      StageType::ComputeShader => f.write_str("compute shader"),
+      StageType::TaskShader => f.write_str("task shader"),
+      StageType::MeshShader => f.write_str("mesh shader"),
+    }
+  }
+}
+
+/// A GLSL version.
+///
+/// GLSL versions are represented as in `#version` directives: `330` is GLSL 3.30 and `300` with [`GlslVersion::es`]
+/// set is GLSL ES 3.00.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GlslVersion {
+  /// Version number, such as `330` or `460`.
+  pub version: u16,
+  /// Whether the version is a GLSL ES version.
+  pub es: bool,
+}
+
+impl GlslVersion {
+  /// A (desktop) GLSL version.
+  pub fn new(version: u16) -> Self {
+    GlslVersion { version, es: false }
+  }
+
+  /// A GLSL ES version.
+  pub fn new_es(version: u16) -> Self {
+    GlslVersion { version, es: true }
+  }
+
+  /// Parse a shading language version as reported by drivers.
+  ///
+  /// Such versions start with a `major.minor` number, optionally preceded by a description and followed by vendor
+  /// information, such as `4.60 NVIDIA` or `OpenGL ES GLSL ES 3.00`. Versions mentioning `ES` are GLSL ES versions.
+  pub fn parse(s: &str) -> Option<Self> {
+    let es = s.split_whitespace().any(|word| word == "ES");
+    let number = s
+      .split_whitespace()
+      .find(|word| word.starts_with(|c: char| c.is_ascii_digit()) && word.contains('.'))?;
+    let (major, minor) = number.split_once('.')?;
+    let minor = minor.trim_end_matches(|c: char| !c.is_ascii_digit());
+
+    let major: u16 = major.parse().ok()?;
+    let minor: u16 = match minor.len() {
+      1 => minor.parse::<u16>().ok()? * 10,
+      2 => minor.parse().ok()?,
+      _ => return None,
+    };
+
+    Some(GlslVersion {
+      version: major * 100 + minor,
+      es,
+    })
+  }
+
+  /// `#version` directive declaring this version.
+  pub fn directive(&self) -> String {
+    format!("#version {}\n", self)
+  }
+}
+
+impl fmt::Display for GlslVersion {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match (self.es, self.version) {
+      // GLSL ES 1.00 and GLSL before 1.50 have no profile
+      (true, 100) => write!(f, "{}", self.version),
+      (true, _) => write!(f, "{} es", self.version),
+      (false, v) if v < 150 => write!(f, "{}", v),
+      (false, _) => write!(f, "{} core", self.version),
     }
   }
 }
@@ -167,21 +346,56 @@ impl fmt::Display for StageType {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StageError {
   /// Occurs when a shader fails to compile.
-  CompilationFailed(StageType, String),
+  ///
+  /// The compilation log is parsed into [`Diagnostic`]s.
+  CompilationFailed(StageType, ShaderLog),
   /// Occurs when you try to create a shader which type is not supported on the current hardware.
   UnsupportedType(StageType),
+  /// Occurs when the `#include` directives of a shader cannot be expanded.
+  IncludeFailed(IncludeError),
+  /// Occurs when a define injected in a shader is not a valid identifier or has a multi-line value.
+  InvalidDefine(String),
+  /// Occurs when a shader cannot be parsed, validated or translated from another shading language.
+  TranslationFailed(String),
 }
 
 impl StageError {
   /// Occurs when a shader fails to compile.
-  pub fn compilation_failed(ty: StageType, reason: impl Into<String>) -> Self {
-    StageError::CompilationFailed(ty, reason.into())
+  ///
+  /// `log` is parsed into [`Diagnostic`]s about the `ty` stage.
+  pub fn compilation_failed(ty: StageType, log: impl Into<String>) -> Self {
+    StageError::CompilationFailed(ty, ShaderLog::parse(log, Some(ty)))
   }
 
   /// Occurs when you try to create a shader which type is not supported on the current hardware.
   pub fn unsupported_type(ty: StageType) -> Self {
     StageError::UnsupportedType(ty)
   }
+
+  /// Occurs when the `#include` directives of a shader cannot be expanded.
+  pub fn include_failed(e: IncludeError) -> Self {
+    StageError::IncludeFailed(e)
+  }
+
+  /// Occurs when a define injected in a shader is not a valid identifier or has a multi-line value.
+  pub fn invalid_define(name: impl Into<String>) -> Self {
+    StageError::InvalidDefine(name.into())
+  }
+
+  /// Occurs when a shader cannot be parsed, validated or translated from another shading language.
+  pub fn translation_failed(reason: impl Into<String>) -> Self {
+    StageError::TranslationFailed(reason.into())
+  }
+
+  /// Diagnostics of the error, if any.
+  ///
+  /// Only compilation errors have diagnostics.
+  pub fn diagnostics(&self) -> &[Diagnostic] {
+    match *self {
+      StageError::CompilationFailed(_, ref log) => log.diagnostics(),
+      _ => &[],
+    }
+  }
 }
 
 impl fmt::Display for StageError {
@@ -190,11 +404,30 @@ impl fmt::Display for StageError {
       StageError::CompilationFailed(ref ty, ref r) => write!(f, "{} compilation error: {}", ty, r),
 
       StageError::UnsupportedType(ty) => write!(f, "unsupported {}", ty),
+
+      StageError::IncludeFailed(ref e) => write!(f, "include error: {}", e),
+
+      StageError::InvalidDefine(ref name) => write!(f, "invalid define: {}", name),
+
+      StageError::TranslationFailed(ref reason) => write!(f, "translation error: {}", reason),
+    }
+  }
+}
+
+impl error::Error for StageError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      StageError::IncludeFailed(e) => Some(e),
+      _ => None,
     }
   }
 }
 
-impl error::Error for StageError {}
+impl From<IncludeError> for StageError {
+  fn from(e: IncludeError) -> Self {
+    StageError::include_failed(e)
+  }
+}
 
 impl From<StageError> for ProgramError {
   fn from(e: StageError) -> Self {
@@ -232,10 +465,21 @@ pub enum ProgramError {
   CreationFailed(String),
   /// A shader stage failed to compile or validate its state.
   StageError(StageError),
-  /// Program link failed. You can inspect the reason by looking at the contained [`String`].
-  LinkFailed(String),
+  /// Program link failed. You can inspect the reason by looking at the contained [`ShaderLog`].
+  LinkFailed(ShaderLog),
   /// A program warning.
   Warning(ProgramWarning),
+  /// A mandatory stage is missing.
+  MissingStage(StageType),
+  /// A stage was provided where a stage of another type was expected.
+  StageTypeMismatch {
+    /// Expected type.
+    expected: StageType,
+    /// Type of the provided stage.
+    found: StageType,
+  },
+  /// A [`DeferredProgram`] was resolved again after it already resolved.
+  AlreadyResolved,
 }
 
 impl ProgramError {
@@ -249,15 +493,43 @@ impl ProgramError {
     ProgramError::StageError(e)
   }
 
-  /// Program link failed. You can inspect the reason by looking at the contained [`String`].
-  pub fn link_failed(reason: impl Into<String>) -> Self {
-    ProgramError::LinkFailed(reason.into())
+  /// Program link failed.
+  ///
+  /// `log` is parsed into [`Diagnostic`]s, which stages are guessed from the log.
+  pub fn link_failed(log: impl Into<String>) -> Self {
+    ProgramError::LinkFailed(ShaderLog::parse(log, None))
   }
 
   /// A program warning.
   pub fn warning(w: ProgramWarning) -> Self {
     ProgramError::Warning(w)
   }
+
+  /// A mandatory stage is missing.
+  pub fn missing_stage(ty: StageType) -> Self {
+    ProgramError::MissingStage(ty)
+  }
+
+  /// A stage was provided where a stage of another type was expected.
+  pub fn stage_type_mismatch(expected: StageType, found: StageType) -> Self {
+    ProgramError::StageTypeMismatch { expected, found }
+  }
+
+  /// A [`DeferredProgram`] was resolved again after it already resolved.
+  pub fn already_resolved() -> Self {
+    ProgramError::AlreadyResolved
+  }
+
+  /// Diagnostics of the error, if any.
+  ///
+  /// Only compilation and link errors have diagnostics.
+  pub fn diagnostics(&self) -> &[Diagnostic] {
+    match *self {
+      ProgramError::StageError(ref e) => e.diagnostics(),
+      ProgramError::LinkFailed(ref log) => log.diagnostics(),
+      _ => &[],
+    }
+  }
 }
 
 impl fmt::Display for ProgramError {
@@ -270,6 +542,16 @@ impl fmt::Display for ProgramError {
       ProgramError::LinkFailed(ref s) => write!(f, "shader program failed to link: {}", s),
 
       ProgramError::Warning(ref e) => write!(f, "shader program warning: {}", e),
+
+      ProgramError::MissingStage(ty) => write!(f, "shader program is missing a {}", ty),
+
+      ProgramError::StageTypeMismatch { expected, found } => write!(
+        f,
+        "shader program expected a {} but got a {}",
+        expected, found
+      ),
+
+      ProgramError::AlreadyResolved => f.write_str("deferred shader program already resolved"),
     }
   }
 }
@@ -291,6 +573,9 @@ pub enum ProgramWarning {
   Uniform(UniformWarning),
   /// Some vertex attribute is ill-formed.
   VertexAttrib(VertexAttribWarning),
+  /// The compiler or the linker reported diagnostics — typically warnings — although the compilation or the link
+  /// succeeded.
+  Log(ShaderLog),
 }
 
 impl fmt::Display for ProgramWarning {
@@ -298,6 +583,7 @@ impl fmt::Display for ProgramWarning {
     match *self {
       ProgramWarning::Uniform(ref e) => write!(f, "uniform warning: {}", e),
       ProgramWarning::VertexAttrib(ref e) => write!(f, "vertex attribute warning: {}", e),
+      ProgramWarning::Log(ref log) => write!(f, "driver log: {}", log),
     }
   }
 }
@@ -307,6 +593,7 @@ impl error::Error for ProgramWarning {
     match self {
       ProgramWarning::Uniform(e) => Some(e),
       ProgramWarning::VertexAttrib(e) => Some(e),
+      ProgramWarning::Log(_) => None,
     }
   }
 }
@@ -324,13 +611,24 @@ pub enum UniformWarning {
   /// Inactive uniform (not in use / no participation to the final output in shaders).
   Inactive(String),
 
+  /// Invalid uniform name, such as a name containing a NUL byte.
+  InvalidName(String),
+
   /// Type mismatch between the static requested type (i.e. the `T` in [`Uniform<T>`] for instance)
   /// and the type that got reflected from the backend in the shaders.
   ///
-  /// The [`String`] is the name of the uniform; the second one gives the type mismatch.
-  ///
   /// [`Uniform<T>`]: crate::shader::Uniform
-  TypeMismatch(String, UniformType),
+  TypeMismatch {
+    /// Name of the uniform.
+    name: String,
+
+    /// Type of the uniform (static).
+    ty: UniformType,
+
+    /// Found type of the uniform (in the shader), or [`None`] if the backend could not reflect it or doesn’t know
+    /// about it.
+    found_ty: Option<UniformType>,
+  },
 
   /// The requested type is unsupported by the backend.
   ///
@@ -341,6 +639,10 @@ pub enum UniformWarning {
   /// Size mismatch between the static requested type (i.e. the `T` in [`Uniform<T>`] for instance)
   /// and the size that got reflected from the backend in the shaders.
   ///
+  /// This happens when the requested size is larger than the declared size of the uniform array in
+  /// the shader, as updating the uniform would then write past its end. Requesting a smaller array
+  /// is accepted: only the first elements of the array will be updated.
+  ///
   /// [`Uniform<T>`]: crate::shader::Uniform
   SizeMismatch {
     /// Name of the uniform.
@@ -352,6 +654,31 @@ pub enum UniformWarning {
     /// Found size of the uniform (in the shader).
     found_size: usize,
   },
+
+  /// Offset mismatch between the static layout of a uniform block content (i.e. the `T` in [`ShaderData`] for
+  /// instance) and the offset that got reflected from the backend in the shaders.
+  OffsetMismatch {
+    /// Name of the uniform block member.
+    name: String,
+
+    /// Offset of the member in bytes (static).
+    offset: usize,
+
+    /// Found offset of the member in bytes (in the shader).
+    found_offset: usize,
+  },
+
+  /// A range of items doesn’t fit in a uniform array.
+  RangeOutOfBounds {
+    /// Index of the first item of the range.
+    offset: usize,
+
+    /// Number of items of the range.
+    len: usize,
+
+    /// Size of the uniform array.
+    size: usize,
+  },
 }
 
 impl UniformWarning {
@@ -363,12 +690,24 @@ impl UniformWarning {
     UniformWarning::Inactive(name.into())
   }
 
+  /// Create an invalid uniform name warning.
+  pub fn invalid_name<N>(name: N) -> Self
+  where
+    N: Into<String>,
+  {
+    UniformWarning::InvalidName(name.into())
+  }
+
   /// Create a type mismatch.
-  pub fn type_mismatch<N>(name: N, ty: UniformType) -> Self
+  pub fn type_mismatch<N>(name: N, ty: UniformType, found_ty: Option<UniformType>) -> Self
   where
     N: Into<String>,
   {
-    UniformWarning::TypeMismatch(name.into(), ty)
+    UniformWarning::TypeMismatch {
+      name: name.into(),
+      ty,
+      found_ty,
+    }
   }
 
   /// Create an unsupported type error.
@@ -387,6 +726,20 @@ impl UniformWarning {
       found_size,
     }
   }
+
+  /// Create an offset mismatch error.
+  pub fn offset_mismatch(name: impl Into<String>, offset: usize, found_offset: usize) -> Self {
+    UniformWarning::OffsetMismatch {
+      name: name.into(),
+      offset,
+      found_offset,
+    }
+  }
+
+  /// Create a range out of bounds error.
+  pub fn range_out_of_bounds(offset: usize, len: usize, size: usize) -> Self {
+    UniformWarning::RangeOutOfBounds { offset, len, size }
+  }
 }
 
 impl fmt::Display for UniformWarning {
@@ -394,8 +747,30 @@ impl fmt::Display for UniformWarning {
     match *self {
       UniformWarning::Inactive(ref s) => write!(f, "inactive {} uniform", s),
 
-      UniformWarning::TypeMismatch(ref n, ref t) => {
-        write!(f, "type mismatch for uniform {}: {}", n, t)
+      UniformWarning::InvalidName(ref s) => write!(f, "invalid uniform name {:?}", s),
+
+      UniformWarning::TypeMismatch {
+        ref name,
+        ty,
+        found_ty: Some(found_ty),
+      } => {
+        write!(
+          f,
+          "type mismatch for uniform {}: {} (detected type={})",
+          name, ty, found_ty
+        )
+      }
+
+      UniformWarning::TypeMismatch {
+        ref name,
+        ty,
+        found_ty: None,
+      } => {
+        write!(
+          f,
+          "type mismatch for uniform {}: {} (detected type unknown)",
+          name, ty
+        )
       }
 
       UniformWarning::UnsupportedType(ref name, ref ty) => {
@@ -409,10 +784,30 @@ impl fmt::Display for UniformWarning {
       } => {
         write!(
           f,
-          "size mismatch for uniform {}: {} (tdetected size={}",
+          "size mismatch for uniform {}: {} (detected size={})",
           name, size, found_size
         )
       }
+
+      UniformWarning::OffsetMismatch {
+        ref name,
+        offset,
+        found_offset,
+      } => {
+        write!(
+          f,
+          "offset mismatch for uniform {}: {} (detected offset={})",
+          name, offset, found_offset
+        )
+      }
+
+      UniformWarning::RangeOutOfBounds { offset, len, size } => {
+        write!(
+          f,
+          "{} items at offset {} out of bounds of uniform array of size {}",
+          len, offset, size
+        )
+      }
     }
   }
 }
@@ -456,6 +851,195 @@ impl From<VertexAttribWarning> for ProgramWarning {
 
 impl error::Error for VertexAttribWarning {}
 
+/// Errors that occur when a vertex format doesn’t satisfy the vertex attributes of a [`Program`].
+///
+/// See [`ActiveVertexAttrib::check_format`] for further details.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VertexFormatError {
+  /// An active vertex attribute is not provided by the vertex format.
+  MissingAttrib {
+    /// Name of the vertex attribute.
+    name: String,
+    /// Location of the vertex attribute.
+    location: usize,
+  },
+  /// An active vertex attribute is provided with a type that cannot be read as its GLSL type.
+  TypeMismatch {
+    /// Name of the vertex attribute.
+    name: String,
+    /// Location of the vertex attribute.
+    location: usize,
+    /// GLSL type of the vertex attribute.
+    expected: UniformType,
+    /// Type provided by the vertex format.
+    found: VertexAttribType,
+  },
+  /// The primitive mode is not accepted by the geometry stage.
+  IncompatibleMode {
+    /// Primitive mode.
+    mode: Mode,
+    /// Input primitive of the geometry stage.
+    input: GeometryInput,
+  },
+  /// The primitive mode is [`Mode::Patch`], but the program has no tessellation stages to consume patches.
+  NoTessellationStages {
+    /// Primitive mode.
+    mode: Mode,
+  },
+}
+
+impl VertexFormatError {
+  /// An active vertex attribute is not provided by the vertex format.
+  pub fn missing_attrib(name: impl Into<String>, location: usize) -> Self {
+    VertexFormatError::MissingAttrib {
+      name: name.into(),
+      location,
+    }
+  }
+
+  /// An active vertex attribute is provided with a type that cannot be read as its GLSL type.
+  pub fn type_mismatch(
+    name: impl Into<String>,
+    location: usize,
+    expected: UniformType,
+    found: VertexAttribType,
+  ) -> Self {
+    VertexFormatError::TypeMismatch {
+      name: name.into(),
+      location,
+      expected,
+      found,
+    }
+  }
+
+  /// The primitive mode is not accepted by the geometry stage.
+  pub fn incompatible_mode(mode: Mode, input: GeometryInput) -> Self {
+    VertexFormatError::IncompatibleMode { mode, input }
+  }
+
+  /// The primitive mode is [`Mode::Patch`], but the program has no tessellation stages to consume patches.
+  pub fn no_tessellation_stages(mode: Mode) -> Self {
+    VertexFormatError::NoTessellationStages { mode }
+  }
+}
+
+impl fmt::Display for VertexFormatError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      VertexFormatError::MissingAttrib { ref name, location } => write!(
+        f,
+        "vertex attribute {} (location {}) is not provided by the vertex format",
+        name, location
+      ),
+
+      VertexFormatError::TypeMismatch {
+        ref name,
+        location,
+        expected,
+        found,
+      } => write!(
+        f,
+        "vertex attribute {} (location {}) has type {}, but the vertex format provides {:?}",
+        name, location, expected, found
+      ),
+
+      VertexFormatError::IncompatibleMode { mode, input } => write!(
+        f,
+        "{} primitive mode is not accepted by the geometry stage, which expects {}",
+        mode, input
+      ),
+
+      VertexFormatError::NoTessellationStages { mode } => write!(
+        f,
+        "{} primitive mode requires tessellation stages, but the program has none",
+        mode
+      ),
+    }
+  }
+}
+
+impl error::Error for VertexFormatError {}
+
+/// Input primitive of a geometry stage.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GeometryInput {
+  /// Points.
+  Points,
+  /// Lines.
+  Lines,
+  /// Lines with adjacency.
+  LinesAdjacency,
+  /// Triangles.
+  Triangles,
+  /// Triangles with adjacency.
+  TrianglesAdjacency,
+}
+
+impl GeometryInput {
+  /// Whether primitives rendered with `mode` can be fed to the geometry stage.
+  ///
+  /// [`Mode::Patch`] is always accepted, as the primitives are then generated by the tessellation stages. No [`Mode`]
+  /// provides adjacency information, so [`GeometryInput::LinesAdjacency`] and [`GeometryInput::TrianglesAdjacency`]
+  /// accept nothing else.
+  pub fn accepts(self, mode: Mode) -> bool {
+    matches!(
+      (self, mode),
+      (_, Mode::Patch(_))
+        | (GeometryInput::Points, Mode::Point)
+        | (GeometryInput::Lines, Mode::Line | Mode::LineStrip)
+        | (
+          GeometryInput::Triangles,
+          Mode::Triangle | Mode::TriangleStrip | Mode::TriangleFan
+        )
+    )
+  }
+}
+
+impl fmt::Display for GeometryInput {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      GeometryInput::Points => f.write_str("points"),
+      GeometryInput::Lines => f.write_str("lines"),
+      GeometryInput::LinesAdjacency => f.write_str("lines with adjacency"),
+      GeometryInput::Triangles => f.write_str("triangles"),
+      GeometryInput::TrianglesAdjacency => f.write_str("triangles with adjacency"),
+    }
+  }
+}
+
+/// Output primitive of a geometry stage.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GeometryOutput {
+  /// Points.
+  Points,
+  /// Line strips.
+  LineStrip,
+  /// Triangle strips.
+  TriangleStrip,
+}
+
+impl fmt::Display for GeometryOutput {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      GeometryOutput::Points => f.write_str("points"),
+      GeometryOutput::LineStrip => f.write_str("line strip"),
+      GeometryOutput::TriangleStrip => f.write_str("triangle strip"),
+    }
+  }
+}
+
+/// Configuration of the geometry stage of a linked [`Program`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GeometryConfig {
+  /// Input primitive, declared with `layout(…) in`.
+  pub input: GeometryInput,
+  /// Output primitive, declared with `layout(…) out`.
+  pub output: GeometryOutput,
+  /// Maximum number of vertices output per invocation, declared with `layout(max_vertices = …) out`.
+  pub max_output_vertices: usize,
+}
+
 /// A GPU shader program environment variable.
 ///
 /// A uniform is a special variable that can be used to send data to a GPU. Several
@@ -511,6 +1095,111 @@ where
   }
 }
 
+/// An interned [`Uniform`] name.
+///
+/// A [`UniformName`] is validated once, when created, and can then be used to look up uniforms with
+/// [`UniformBuilder::ask_named`] as many times as needed. It is cheap to clone and hash, which allows backends to cache
+/// lookups per [`Program`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UniformName {
+  // NUL-terminated
+  name: Arc<str>,
+}
+
+impl UniformName {
+  /// Create a new [`UniformName`].
+  ///
+  /// Fail with [`UniformWarning::InvalidName`] if `name` contains a NUL byte.
+  pub fn new(name: &str) -> Result<Self, UniformWarning> {
+    if name.contains('\0') {
+      Err(UniformWarning::invalid_name(name))
+    } else {
+      Ok(UniformName {
+        name: format!("{}\0", name).into(),
+      })
+    }
+  }
+
+  /// Get the name as a string slice.
+  pub fn as_str(&self) -> &str {
+    &self.name[..self.name.len() - 1]
+  }
+
+  /// Get the name as a NUL-terminated C string.
+  pub fn as_c_str(&self) -> &CStr {
+    // the name is NUL-terminated and doesn’t contain any other NUL byte
+    unsafe { CStr::from_bytes_with_nul_unchecked(self.name.as_bytes()) }
+  }
+}
+
+impl fmt::Display for UniformName {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    f.write_str(self.as_str())
+  }
+}
+
+/// A subroutine uniform.
+///
+/// A subroutine uniform lives in a given shader stage and calls the [`Subroutine`] selected with
+/// [`ProgramInterface::set_subroutine`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubroutineUniform {
+  stage: StageType,
+  index: i32,
+}
+
+impl SubroutineUniform {
+  /// Create a new [`SubroutineUniform`].
+  ///
+  /// # Safety
+  ///
+  /// This method must be used **only** by backends.
+  pub unsafe fn new(stage: StageType, index: i32) -> Self {
+    SubroutineUniform { stage, index }
+  }
+
+  /// Shader stage the subroutine uniform lives in.
+  pub fn stage(&self) -> StageType {
+    self.stage
+  }
+
+  /// Retrieve the internal index.
+  pub fn index(&self) -> i32 {
+    self.index
+  }
+}
+
+/// A subroutine.
+///
+/// A subroutine is a function defined in a given shader stage that can be selected to be called by a
+/// [`SubroutineUniform`] of the same stage.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subroutine {
+  stage: StageType,
+  index: u32,
+}
+
+impl Subroutine {
+  /// Create a new [`Subroutine`].
+  ///
+  /// # Safety
+  ///
+  /// This method must be used **only** by backends.
+  pub unsafe fn new(stage: StageType, index: u32) -> Self {
+    Subroutine { stage, index }
+  }
+
+  /// Shader stage the subroutine is defined in.
+  pub fn stage(&self) -> StageType {
+    self.stage
+  }
+
+  /// Retrieve the internal index.
+  pub fn index(&self) -> u32 {
+    self.index
+  }
+}
+
 /// Type of a uniform.
 ///
 /// This is an exhaustive list of possible types of value you can send to a shader program.
@@ -569,12 +1258,36 @@ pub enum UniformType {
   M33,
   /// 4×4 floating-point matrix.
   M44,
+  /// 2×3 floating-point matrix.
+  M23,
+  /// 3×2 floating-point matrix.
+  M32,
+  /// 2×4 floating-point matrix.
+  M24,
+  /// 4×2 floating-point matrix.
+  M42,
+  /// 3×4 floating-point matrix.
+  M34,
+  /// 4×3 floating-point matrix.
+  M43,
   /// 2×2 floating-point (double) matrix.
   DM22,
   /// 3×3 floating-point (double) matrix.
   DM33,
   /// 4×4 floating-point (double) matrix.
   DM44,
+  /// 2×3 floating-point (double) matrix.
+  DM23,
+  /// 3×2 floating-point (double) matrix.
+  DM32,
+  /// 2×4 floating-point (double) matrix.
+  DM24,
+  /// 4×2 floating-point (double) matrix.
+  DM42,
+  /// 3×4 floating-point (double) matrix.
+  DM34,
+  /// 4×3 floating-point (double) matrix.
+  DM43,
 
   // textures
   /// Signed integral 1D texture sampler.
@@ -613,9 +1326,63 @@ pub enum UniformType {
   UICubemap,
   /// Floating-point cubemap sampler.
   Cubemap,
+  /// 1D depth texture sampler with depth comparison.
+  Sampler1DShadow,
+  /// 2D depth texture sampler with depth comparison.
+  Sampler2DShadow,
+  /// 1D array depth texture sampler with depth comparison.
+  Sampler1DArrayShadow,
+  /// 2D array depth texture sampler with depth comparison.
+  Sampler2DArrayShadow,
+  /// Cubemap depth sampler with depth comparison.
+  CubemapShadow,
+
+  // images
+  /// Signed integral 1D texture image.
+  IImage1D,
+  /// Signed integral 2D texture image.
+  IImage2D,
+  /// Signed integral 3D texture image.
+  IImage3D,
+  /// Signed integral 1D array texture image.
+  IImage1DArray,
+  /// Signed integral 2D array texture image.
+  IImage2DArray,
+  /// Unsigned integral 1D texture image.
+  UIImage1D,
+  /// Unsigned integral 2D texture image.
+  UIImage2D,
+  /// Unsigned integral 3D texture image.
+  UIImage3D,
+  /// Unsigned integral 1D array texture image.
+  UIImage1DArray,
+  /// Unsigned integral 2D array texture image.
+  UIImage2DArray,
+  /// Floating-point 1D texture image.
+  Image1D,
+  /// Floating-point 2D texture image.
+  Image2D,
+  /// Floating-point 3D texture image.
+  Image3D,
+  /// Floating-point 1D array texture image.
+  Image1DArray,
+  /// Floating-point 2D array texture image.
+  Image2DArray,
+  /// Signed cubemap image.
+  IImageCubemap,
+  /// Unsigned cubemap image.
+  UIImageCubemap,
+  /// Floating-point cubemap image.
+  ImageCubemap,
 
   /// Shader data binding.
   ShaderDataBinding,
+
+  /// Shader storage binding.
+  ShaderStorageBinding,
+
+  /// Atomic counter.
+  AtomicCounter,
 }
 
 impl fmt::Display for UniformType {
@@ -644,9 +1411,21 @@ impl fmt::Display for UniformType {
       UniformType::M22 => f.write_str("mat2"),
       UniformType::M33 => f.write_str("mat3"),
       UniformType::M44 => f.write_str("mat4"),
+      UniformType::M23 => f.write_str("mat2x3"),
+      UniformType::M32 => f.write_str("mat3x2"),
+      UniformType::M24 => f.write_str("mat2x4"),
+      UniformType::M42 => f.write_str("mat4x2"),
+      UniformType::M34 => f.write_str("mat3x4"),
+      UniformType::M43 => f.write_str("mat4x3"),
       UniformType::DM22 => f.write_str("dmat2"),
       UniformType::DM33 => f.write_str("dmat3"),
       UniformType::DM44 => f.write_str("dmat4"),
+      UniformType::DM23 => f.write_str("dmat2x3"),
+      UniformType::DM32 => f.write_str("dmat3x2"),
+      UniformType::DM24 => f.write_str("dmat2x4"),
+      UniformType::DM42 => f.write_str("dmat4x2"),
+      UniformType::DM34 => f.write_str("dmat3x4"),
+      UniformType::DM43 => f.write_str("dmat4x3"),
       UniformType::ISampler1D => f.write_str("isampler1D"),
       UniformType::ISampler2D => f.write_str("isampler2D"),
       UniformType::ISampler3D => f.write_str("isampler3D"),
@@ -665,7 +1444,32 @@ impl fmt::Display for UniformType {
       UniformType::ICubemap => f.write_str("isamplerCube"),
       UniformType::UICubemap => f.write_str("usamplerCube"),
       UniformType::Cubemap => f.write_str("samplerCube"),
+      UniformType::Sampler1DShadow => f.write_str("sampler1DShadow"),
+      UniformType::Sampler2DShadow => f.write_str("sampler2DShadow"),
+      UniformType::Sampler1DArrayShadow => f.write_str("sampler1DArrayShadow"),
+      UniformType::Sampler2DArrayShadow => f.write_str("sampler2DArrayShadow"),
+      UniformType::CubemapShadow => f.write_str("samplerCubeShadow"),
+      UniformType::IImage1D => f.write_str("iimage1D"),
+      UniformType::IImage2D => f.write_str("iimage2D"),
+      UniformType::IImage3D => f.write_str("iimage3D"),
+      UniformType::IImage1DArray => f.write_str("iimage1DArray"),
+      UniformType::IImage2DArray => f.write_str("iimage2DArray"),
+      UniformType::UIImage1D => f.write_str("uimage1D"),
+      UniformType::UIImage2D => f.write_str("uimage2D"),
+      UniformType::UIImage3D => f.write_str("uimage3D"),
+      UniformType::UIImage1DArray => f.write_str("uimage1DArray"),
+      UniformType::UIImage2DArray => f.write_str("uimage2DArray"),
+      UniformType::Image1D => f.write_str("image1D"),
+      UniformType::Image2D => f.write_str("image2D"),
+      UniformType::Image3D => f.write_str("image3D"),
+      UniformType::Image1DArray => f.write_str("image1DArray"),
+      UniformType::Image2DArray => f.write_str("image2DArray"),
+      UniformType::IImageCubemap => f.write_str("iimageCube"),
+      UniformType::UIImageCubemap => f.write_str("uimageCube"),
+      UniformType::ImageCubemap => f.write_str("imageCube"),
       UniformType::ShaderDataBinding => f.write_str("shader data binding"),
+      UniformType::ShaderStorageBinding => f.write_str("shader storage binding"),
+      UniformType::AtomicCounter => f.write_str("atomic_uint"),
     }
   }
 }
@@ -682,6 +1486,7 @@ where
   B: ?Sized + Shader,
 {
   repr: B::StageRepr,
+  ty: StageType,
 }
 
 impl<B> Stage<B>
@@ -710,18 +1515,195 @@ where
       ctx
         .backend()
         .new_stage(ty, src.as_ref())
-        .map(|repr| Stage { repr })
+        .map(|repr| Stage { repr, ty })
     }
   }
-}
 
-/// A builder of [`Uniform`].
-///
-/// A [`UniformBuilder`] is an important type as it’s the only one that allows to safely create
-/// [`Uniform`] values.
-///
-/// # Parametricity
-///
+  /// Type of the stage.
+  pub fn ty(&self) -> StageType {
+    self.ty
+  }
+
+  /// Compilation log of the stage, if the compiler reported anything.
+  ///
+  /// Many compilers report warnings even when the compilation succeeds. They are also reported as
+  /// [`ProgramWarning::Log`] when the stage is linked in a [`Program`].
+  pub fn log(&self) -> Option<ShaderLog> {
+    parse_log(unsafe { B::stage_log(&self.repr) }, Some(self.ty))
+  }
+
+  /// Create a new stage of type `ty` by compiling `src` once its `#include` directives are expanded.
+  ///
+  /// `name` is the name of the file `src` comes from. The lines of the compilation errors refer to the original files,
+  /// along with the chain of `#include` directives that led there — see [`Diagnostic`]. See the documentation of the
+  /// [`include`] module for further details.
+  pub fn new_with_includes<C, R, I>(
+    ctx: &mut C,
+    ty: StageType,
+    name: &str,
+    src: R,
+    resolver: &mut I,
+  ) -> Result<Self, StageError>
+  where
+    C: GraphicsContext<Backend = B>,
+    R: AsRef<str>,
+    I: ?Sized + IncludeResolver,
+  {
+    let expanded = expand_includes(name, src.as_ref(), resolver)?;
+    Self::new(ctx, ty, expanded.source())
+      .map_err(|e| remap_compilation_error(e, |log| expanded.remap_log(log)))
+  }
+
+  /// Create a new stage of type `ty` by compiling `src` with a list of defines.
+  ///
+  /// Each `(name, value)` pair is injected as a `#define name value` directive at the beginning of the source, which
+  /// allows to build several permutations of the same stage (e.g. `("MAX_LIGHTS", 4)` or `("USE_SHADOWS", 1)`). If the
+  /// source starts with a `#version` directive, the defines are injected right after it. The lines of the compilation
+  /// errors refer to `src`; errors in the injected defines have no line.
+  pub fn new_with_defines<C, R, D, N, V>(
+    ctx: &mut C,
+    ty: StageType,
+    src: R,
+    defines: D,
+  ) -> Result<Self, StageError>
+  where
+    C: GraphicsContext<Backend = B>,
+    R: AsRef<str>,
+    D: IntoIterator<Item = (N, V)>,
+    N: AsRef<str>,
+    V: fmt::Display,
+  {
+    let full_src = src.as_ref();
+    let (version, src) = split_version_directive(full_src);
+    let mut defined_src = String::from(version);
+    // line at which the defines are injected
+    let defines_line = full_src[..full_src.len() - src.len()].matches('\n').count() + 1;
+    let mut defines_len = 0;
+
+    if !version.is_empty() && !version.ends_with('\n') {
+      defined_src.push('\n');
+    }
+
+    for (name, value) in defines {
+      let name = name.as_ref();
+      let value = value.to_string();
+
+      if !is_glsl_identifier(name) || value.contains('\n') {
+        return Err(StageError::invalid_define(name));
+      }
+
+      defined_src.push_str(&format!("#define {} {}\n", name, value));
+      defines_len += 1;
+    }
+
+    defined_src.push_str(src);
+    Self::new(ctx, ty, defined_src).map_err(|e| {
+      remap_compilation_error(e, |log| {
+        for diagnostic in log.diagnostics_mut() {
+          diagnostic.line = match diagnostic.line {
+            Some(line) if line >= defines_line + defines_len => Some(line - defines_len),
+            Some(line) if line >= defines_line => None,
+            line => line,
+          };
+        }
+      })
+    })
+  }
+
+  /// Create a new stage of type `ty` by translating the `entry_point` function of `ir`.
+  ///
+  /// The stage is translated into the GLSL version of the backend before being compiled. See the documentation of the
+  /// [`ir`] module for further details.
+  #[cfg(feature = "naga")]
+  pub fn from_ir<C>(
+    ctx: &mut C,
+    ty: StageType,
+    ir: &ir::ShaderIr,
+    entry_point: &str,
+  ) -> Result<Self, StageError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let version = unsafe { ctx.backend().glsl_version() };
+    let src = ir.translate(ty, entry_point, version)?;
+    Self::new(ctx, ty, src)
+  }
+}
+
+/// Parse an info log, unless it is empty.
+fn parse_log(log: String, stage: Option<StageType>) -> Option<ShaderLog> {
+  if log.trim().is_empty() {
+    None
+  } else {
+    Some(ShaderLog::parse(log, stage))
+  }
+}
+
+/// Warnings for the logs of stages and of the program they are linked in.
+fn log_warnings<B>(stages: &[&Stage<B>], program: &B::ProgramRepr) -> Vec<ProgramWarning>
+where
+  B: ?Sized + Shader,
+{
+  stages
+    .iter()
+    .filter_map(|stage| stage.log())
+    .chain(parse_log(unsafe { B::program_log(program) }, None))
+    .map(ProgramWarning::Log)
+    .collect()
+}
+
+/// Remap the log of a compilation error to the sources it was generated from.
+fn remap_compilation_error(mut e: StageError, remap: impl FnOnce(&mut ShaderLog)) -> StageError {
+  if let StageError::CompilationFailed(_, ref mut log) = e {
+    remap(log);
+  }
+
+  e
+}
+
+/// Check that a stage has the expected type.
+fn check_stage_type<B>(stage: &Stage<B>, expected: StageType) -> Result<(), ProgramError>
+where
+  B: ?Sized + Shader,
+{
+  if stage.ty == expected {
+    Ok(())
+  } else {
+    Err(ProgramError::stage_type_mismatch(expected, stage.ty))
+  }
+}
+
+/// Split the leading `#version` directive of a GLSL source, if any, from the rest of the source.
+///
+/// Backends use it to insert their pragmas right after the version directive, which must come first.
+pub fn split_version_directive(src: &str) -> (&str, &str) {
+  let trimmed = src.trim_start();
+
+  if trimmed.starts_with("#version") {
+    let end = trimmed.find('\n').map_or(trimmed.len(), |i| i + 1);
+    trimmed.split_at(end)
+  } else {
+    ("", src)
+  }
+}
+
+/// Check whether a string is a valid GLSL identifier.
+fn is_glsl_identifier(name: &str) -> bool {
+  let mut chars = name.chars();
+
+  chars
+    .next()
+    .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A builder of [`Uniform`].
+///
+/// A [`UniformBuilder`] is an important type as it’s the only one that allows to safely create
+/// [`Uniform`] values.
+///
+/// # Parametricity
+///
 /// - `B` is the backend type. It must implement the [`Shader`] trait.
 pub struct UniformBuilder<'a, B>
 where
@@ -744,6 +1726,53 @@ where
     unsafe { B::ask_uniform(&mut self.repr, name) }
   }
 
+  /// Ask the creation of a [`Uniform`], identified by its interned `name`.
+  ///
+  /// This is the same as [`UniformBuilder::ask`], but backends can use the [`UniformName`] to cache the lookup in the
+  /// [`Program`], making asking the same name again cheap.
+  pub fn ask_named<T>(&mut self, name: &UniformName) -> Result<Uniform<T>, UniformWarning>
+  where
+    B: for<'u> Uniformable<'u, T>,
+  {
+    unsafe { B::ask_uniform_named(&mut self.repr, name) }
+  }
+
+  /// Ask the creation of a [`Uniform`], identified by its explicit `location` in the shader (i.e.
+  /// `layout(location = N) uniform`).
+  ///
+  /// No name lookup is performed. The backend still checks, when it can, that a uniform of the right type lives at
+  /// that location. Shader data bindings (uniform blocks, etc.) cannot be asked that way.
+  pub fn ask_at_location<T>(&mut self, location: i32) -> Result<Uniform<T>, UniformWarning>
+  where
+    B: for<'u> Uniformable<'u, T>,
+  {
+    unsafe { B::ask_uniform_at_location(&mut self.repr, location) }
+  }
+
+  /// Ask the creation of a [`SubroutineUniform`], identified by its `name` in the given shader `stage`.
+  pub fn ask_subroutine_uniform(
+    &mut self,
+    stage: StageType,
+    name: &str,
+  ) -> Result<SubroutineUniform, UniformWarning>
+  where
+    B: ShaderSubroutineBackend,
+  {
+    unsafe { B::ask_subroutine_uniform(&mut self.repr, stage, name) }
+  }
+
+  /// Ask for a [`Subroutine`], identified by its `name` in the given shader `stage`.
+  pub fn ask_subroutine(
+    &mut self,
+    stage: StageType,
+    name: &str,
+  ) -> Result<Subroutine, UniformWarning>
+  where
+    B: ShaderSubroutineBackend,
+  {
+    unsafe { B::ask_subroutine(&mut self.repr, stage, name) }
+  }
+
   /// Ask the creation of a [`Uniform`], identified by its `name`.
   ///
   /// If the name is not found, an _unbound_ [`Uniform`] is returned (i.e. a [`Uniform`]) that does
@@ -812,6 +1841,141 @@ where
   }
 }
 
+/// An active uniform of a [`Program`], as reflected by [`Program::active_uniforms`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActiveUniform {
+  /// Name of the uniform, that can be passed to [`UniformBuilder::ask`].
+  ///
+  /// Arrays are reported with the name of their first item (i.e. `lights[0]`).
+  pub name: String,
+  /// Location of the uniform, that can be passed to [`UniformBuilder::ask_at_location`].
+  ///
+  /// [`None`] with backends whose uniform locations are not integers, such as WebGL 2.
+  pub location: Option<i32>,
+  /// Type of the uniform, or [`None`] if the type is not supported.
+  pub ty: Option<UniformType>,
+  /// Number of items in the uniform; greater than `1` for arrays.
+  pub size: usize,
+}
+
+/// An active vertex attribute of a [`Program`], as reflected by [`Program::active_vertex_attribs`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActiveVertexAttrib {
+  /// Name of the vertex attribute.
+  pub name: String,
+  /// Location of the vertex attribute, which is the index of its semantics if bound to one.
+  pub location: usize,
+  /// GLSL type of the vertex attribute, or [`None`] if the type is not supported.
+  pub ty: Option<UniformType>,
+  /// Number of items in the vertex attribute; greater than `1` for arrays.
+  pub size: usize,
+}
+
+impl ActiveVertexAttrib {
+  /// Check that a vertex format provides the vertex attribute with a compatible type.
+  ///
+  /// The vertex attribute must be provided by one of the [`VertexBufferDesc`]s at its location, with a type that can be
+  /// read as its GLSL type: floating-point or normalized integral types for `float`, `vecN` and matrices, non-normalized
+  /// signed integral types for `int` and `ivecN`, and non-normalized unsigned integral or boolean types for `uint` and
+  /// `uvecN`. Double-precision vertex attributes are not supported. Dimensions are not checked, as missing components
+  /// are filled by the backend and extra ones are ignored.
+  pub fn check_format(&self, vertex_desc: &[VertexBufferDesc]) -> Result<(), VertexFormatError> {
+    let desc = vertex_desc
+      .iter()
+      .find(|desc| desc.index == self.location)
+      .ok_or_else(|| VertexFormatError::missing_attrib(&self.name, self.location))?;
+    let found = desc.attrib_desc.ty;
+
+    match self.ty {
+      Some(expected) if !is_vertex_attrib_compatible(expected, found) => Err(
+        VertexFormatError::type_mismatch(&self.name, self.location, expected, found),
+      ),
+      _ => Ok(()),
+    }
+  }
+}
+
+/// Check whether a vertex attribute of type `found` can be read as the GLSL type `expected`.
+fn is_vertex_attrib_compatible(expected: UniformType, found: VertexAttribType) -> bool {
+  match expected {
+    UniformType::Int | UniformType::IVec2 | UniformType::IVec3 | UniformType::IVec4 => {
+      found == VertexAttribType::Integral(Normalized::No)
+    }
+
+    UniformType::UInt | UniformType::UIVec2 | UniformType::UIVec3 | UniformType::UIVec4 => {
+      matches!(
+        found,
+        VertexAttribType::Unsigned(Normalized::No) | VertexAttribType::Boolean
+      )
+    }
+
+    UniformType::Float
+    | UniformType::Vec2
+    | UniformType::Vec3
+    | UniformType::Vec4
+    | UniformType::M22
+    | UniformType::M33
+    | UniformType::M44
+    | UniformType::M23
+    | UniformType::M32
+    | UniformType::M24
+    | UniformType::M42
+    | UniformType::M34
+    | UniformType::M43 => matches!(
+      found,
+      VertexAttribType::Floating
+        | VertexAttribType::Integral(Normalized::Yes)
+        | VertexAttribType::Unsigned(Normalized::Yes)
+    ),
+
+    UniformType::Double
+    | UniformType::DVec2
+    | UniformType::DVec3
+    | UniformType::DVec4
+    | UniformType::DM22
+    | UniformType::DM33
+    | UniformType::DM44
+    | UniformType::DM23
+    | UniformType::DM32
+    | UniformType::DM24
+    | UniformType::DM42
+    | UniformType::DM34
+    | UniformType::DM43 => false,
+
+    // not vertex attribute types
+    _ => true,
+  }
+}
+
+/// Result of the validation of a [`Program`], as returned by [`Program::validate`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramValidation {
+  /// Whether the program can be used with the current state.
+  pub valid: bool,
+  /// Information log of the validation, explaining why the program is not valid; it might contain performance hints
+  /// even if it is valid.
+  pub log: String,
+}
+
+/// An active atomic counter of a [`Program`], as reflected by [`Program::active_atomic_counters`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActiveAtomicCounter {
+  /// Name of the atomic counter.
+  ///
+  /// Arrays are reported with the name of their first item (i.e. `counters[0]`).
+  pub name: String,
+  /// Binding point of the buffer the counter lives in, to pass to [`Pipeline::bind_atomic_counters`].
+  ///
+  /// [`Pipeline::bind_atomic_counters`]: crate::pipeline::Pipeline::bind_atomic_counters
+  pub binding: u32,
+  /// Offset of the counter in the buffer, in bytes.
+  ///
+  /// Counters being `u32`, the index of the counter in [`AtomicCounters::read`] is `offset / 4`.
+  pub offset: usize,
+  /// Number of counters; greater than `1` for arrays.
+  pub size: usize,
+}
+
 /// A built program with potential warnings.
 ///
 /// The sole purpose of this type is to be destructured when a program is built.
@@ -908,6 +2072,52 @@ where
     unsafe { B::update(self.program, uniform, value) };
   }
 
+  /// Set the items of an array [`Uniform`] starting at item `offset`, leaving the other items untouched.
+  ///
+  /// Only backends implementing [`UniformableRange`] support it; the WebGL 2 backend doesn’t. If the items don’t fit
+  /// in the array, nothing is updated and [`UniformWarning::RangeOutOfBounds`] is returned.
+  pub fn set_range<'u, T, const N: usize>(
+    &'u mut self,
+    uniform: &'u Uniform<Arr<T, N>>,
+    offset: usize,
+    values: &'u [T],
+  ) -> Result<(), UniformWarning>
+  where
+    B: UniformableRange<'u, T, N>,
+  {
+    let in_bounds = offset
+      .checked_add(values.len())
+      .map_or(false, |end| end <= N);
+
+    if !in_bounds {
+      return Err(UniformWarning::range_out_of_bounds(offset, values.len(), N));
+    }
+
+    unsafe { B::update_range(self.program, uniform, offset, values) };
+    Ok(())
+  }
+
+  /// Select the [`Subroutine`] called by a [`SubroutineUniform`].
+  ///
+  /// Both must belong to the same shader stage; otherwise, nothing happens. The selection is kept for the next uses of
+  /// the [`Program`].
+  pub fn set_subroutine(&mut self, uniform: &SubroutineUniform, subroutine: &Subroutine)
+  where
+    B: ShaderSubroutineBackend,
+  {
+    unsafe { B::set_subroutine(self.program, uniform, subroutine) };
+  }
+
+  /// Validate the in-use program against the current state.
+  ///
+  /// See the documentation of [`Program::validate`] for further details.
+  pub fn validate(&self) -> ProgramValidation
+  where
+    B: ShaderValidationBackend,
+  {
+    unsafe { B::validate_program(self.program) }
+  }
+
   /// Get back a [`UniformBuilder`] to dynamically access [`Uniform`] objects.
   pub fn query(&mut self) -> Result<UniformBuilder<'a, B>, ProgramError> {
     unsafe {
@@ -924,11 +2134,41 @@ where
 ///
 /// This type allows to create shader programs without having to worry too much about the highly
 /// generic API.
-pub struct ProgramBuilder<'a, C, Sem, Out, Uni> {
+///
+/// By default, the warnings generated while building a [`Program`] are collected in
+/// [`BuiltProgram::warnings`]. It is possible to route them to a handler instead — see
+/// [`ProgramBuilder::on_warning`].
+///
+/// Programs can either be built at once with the `from_*` methods, or by providing their stages one by one and then
+/// calling [`ProgramBuilder::build`]:
+///
+/// ```ignore
+/// let program = Program::<Backend, Semantics, (), ShaderInterface>::builder(&mut ctx)
+///   .vertex(&vs)
+///   .tessellation(&tcs, &tes)
+///   .fragment(&fs)
+///   .build()?
+///   .ignore_warnings();
+/// ```
+pub struct ProgramBuilder<'a, C, Sem, Out, Uni>
+where
+  C: GraphicsContext,
+  C::Backend: Shader,
+{
   ctx: &'a mut C,
+  warning_handler: Option<WarningHandler<'a>>,
+  varyings: Vec<String>,
+  fragment_outputs: Vec<(String, usize)>,
+  vertex: Option<&'a Stage<C::Backend>>,
+  tess: Option<TessellationStages<'a, Stage<C::Backend>>>,
+  geometry: Option<&'a Stage<C::Backend>>,
+  fragment: Option<&'a Stage<C::Backend>>,
   _phantom: PhantomData<(Sem, Out, Uni)>,
 }
 
+/// Handler called for each [`ProgramWarning`] generated while building a [`Program`].
+type WarningHandler<'a> = Box<dyn FnMut(ProgramWarning) -> Result<(), ProgramError> + 'a>;
+
 impl<'a, C, Sem, Out, Uni> ProgramBuilder<'a, C, Sem, Out, Uni>
 where
   C: GraphicsContext,
@@ -939,10 +2179,189 @@ where
   pub fn new(ctx: &'a mut C) -> Self {
     ProgramBuilder {
       ctx,
+      warning_handler: None,
+      varyings: Vec::new(),
+      fragment_outputs: Vec::new(),
+      vertex: None,
+      tess: None,
+      geometry: None,
+      fragment: None,
       _phantom: PhantomData,
     }
   }
 
+  /// Set the vertex stage of the [`Program`] to build with [`ProgramBuilder::build`].
+  pub fn vertex(mut self, stage: &'a Stage<C::Backend>) -> Self {
+    self.vertex = Some(stage);
+    self
+  }
+
+  /// Set the tessellation control and evaluation stages of the [`Program`] to build with [`ProgramBuilder::build`].
+  pub fn tessellation(
+    mut self,
+    control: &'a Stage<C::Backend>,
+    evaluation: &'a Stage<C::Backend>,
+  ) -> Self {
+    self.tess = Some(TessellationStages {
+      control,
+      evaluation,
+    });
+    self
+  }
+
+  /// Set the geometry stage of the [`Program`] to build with [`ProgramBuilder::build`].
+  pub fn geometry(mut self, stage: &'a Stage<C::Backend>) -> Self {
+    self.geometry = Some(stage);
+    self
+  }
+
+  /// Set the fragment stage of the [`Program`] to build with [`ProgramBuilder::build`].
+  pub fn fragment(mut self, stage: &'a Stage<C::Backend>) -> Self {
+    self.fragment = Some(stage);
+    self
+  }
+
+  /// Build a [`Program`] from the stages set on the builder, accessing a mutable environment variable.
+  ///
+  /// The stage combination is validated before the backend is asked to link anything: the vertex and fragment stages
+  /// are mandatory, and each stage must have the type of the slot it was set in.
+  pub fn build_env<E>(
+    mut self,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    let vertex = self
+      .vertex
+      .ok_or_else(|| ProgramError::missing_stage(StageType::VertexShader))?;
+    let fragment = self
+      .fragment
+      .ok_or_else(|| ProgramError::missing_stage(StageType::FragmentShader))?;
+
+    check_stage_type(vertex, StageType::VertexShader)?;
+    check_stage_type(fragment, StageType::FragmentShader)?;
+
+    if let Some(ref tess) = self.tess {
+      check_stage_type(tess.control, StageType::TessellationControlShader)?;
+      check_stage_type(tess.evaluation, StageType::TessellationEvaluationShader)?;
+    }
+
+    if let Some(geometry) = self.geometry {
+      check_stage_type(geometry, StageType::GeometryShader)?;
+    }
+
+    let tess = self.tess.take();
+    let geometry = self.geometry;
+
+    self.from_stages_env(vertex, tess, geometry, fragment, env)
+  }
+
+  /// Build a [`Program`] from the stages set on the builder.
+  ///
+  /// See the documentation of [`ProgramBuilder::build_env`] for further details.
+  pub fn build(self) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend>,
+  {
+    self.build_env(&mut ())
+  }
+
+  /// Route the warnings generated while building [`Program`]s to a handler.
+  ///
+  /// The handler is called once for each warning (inactive uniforms, type mismatches, etc.), which allows to log them
+  /// instead of having to inspect [`BuiltProgram::warnings`]. If the handler returns an error, the build fails with
+  /// that error; it is then possible to turn warnings into hard errors, for instance in debug builds:
+  ///
+  /// ```ignore
+  /// let program = ctx
+  ///   .new_shader_program::<Semantics, (), ShaderInterface>()
+  ///   .on_warning(|warning| {
+  ///     if cfg!(debug_assertions) {
+  ///       Err(warning.into())
+  ///     } else {
+  ///       log::warn!("{}", warning);
+  ///       Ok(())
+  ///     }
+  ///   })
+  ///   .from_strings(VS, None, None, FS)?
+  ///   .ignore_warnings();
+  /// ```
+  ///
+  /// When a handler is set, [`BuiltProgram::warnings`] is always empty.
+  pub fn on_warning<F>(mut self, handler: F) -> Self
+  where
+    F: 'a + FnMut(ProgramWarning) -> Result<(), ProgramError>,
+  {
+    self.warning_handler = Some(Box::new(handler));
+    self
+  }
+
+  /// Capture varyings with transform feedback.
+  ///
+  /// The varyings are output by the last vertex processing stage of the [`Program`]s built with this builder, and are
+  /// written interleaved, in the order given here, when rendering in [`RenderGate::capture`]. The vertex type of the
+  /// output [`Tess`] must then have the same layout.
+  ///
+  /// [`RenderGate::capture`]: crate::render_gate::RenderGate::capture
+  /// [`Tess`]: crate::tess::Tess
+  pub fn capture_varyings<I, N>(mut self, varyings: I) -> Self
+  where
+    I: IntoIterator<Item = N>,
+    N: Into<String>,
+  {
+    self.varyings = varyings.into_iter().map(Into::into).collect();
+    self
+  }
+
+  /// Bind a fragment output to a color attachment index.
+  ///
+  /// When rendering into a framebuffer with several color slots, `name` — an `out` variable of the fragment stage — is
+  /// written to the color attachment at `index`. This method can be called several times to bind several outputs.
+  /// Outputs declared with an explicit `layout(location = N)` in the shader keep their location. The effective
+  /// bindings can be checked once the program is built with [`Program::fragment_output_location`].
+  ///
+  /// Backends that only support explicit locations, such as WebGL 2, fail to build the program if outputs are bound
+  /// this way.
+  pub fn bind_fragment_output(mut self, name: impl Into<String>, index: usize) -> Self {
+    self.fragment_outputs.push((name.into(), index));
+    self
+  }
+
+  /// Declare what must be known before linking programs.
+  unsafe fn prepare_link(
+    &self,
+    repr: &mut <C::Backend as Shader>::ProgramRepr,
+  ) -> Result<(), ProgramError> {
+    if !self.varyings.is_empty() {
+      C::Backend::set_transform_feedback_varyings(repr, &self.varyings)?;
+    }
+
+    if !self.fragment_outputs.is_empty() {
+      C::Backend::set_fragment_output_locations(repr, &self.fragment_outputs)?;
+    }
+
+    Ok(())
+  }
+
+  /// Either pass the warnings to the warning handler, if any, or collect them.
+  fn handle_warnings(
+    &mut self,
+    warnings: impl IntoIterator<Item = ProgramWarning>,
+  ) -> Result<Vec<ProgramError>, ProgramError> {
+    match self.warning_handler {
+      Some(ref mut handler) => {
+        for warning in warnings {
+          handler(warning)?;
+        }
+
+        Ok(Vec::new())
+      }
+
+      None => Ok(warnings.into_iter().map(ProgramError::Warning).collect()),
+    }
+  }
+
   /// Create a [`Program`] by linking [`Stage`]s and accessing a mutable environment variable.
   ///
   /// # Parametricity
@@ -970,6 +2389,15 @@ where
   {
     let tess = tess.into();
     let geometry = geometry.into();
+    let stages: Vec<_> = iter::once(vertex)
+      .chain(
+        tess
+          .iter()
+          .flat_map(|stages| [stages.control, stages.evaluation]),
+      )
+      .chain(geometry)
+      .chain(iter::once(fragment))
+      .collect();
 
     unsafe {
       let mut repr = self.ctx.backend().new_program(
@@ -982,10 +2410,10 @@ where
         &fragment.repr,
       )?;
 
-      let warnings = C::Backend::apply_semantics::<Sem>(&mut repr)?
-        .into_iter()
-        .map(|w| ProgramError::Warning(w.into()))
-        .collect();
+      self.prepare_link(&mut repr)?;
+
+      let attrib_warnings = C::Backend::apply_semantics::<Sem>(&mut repr)?;
+      let log_warnings = log_warnings(&stages, &repr);
 
       let mut uniform_builder =
         C::Backend::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
@@ -997,6 +2425,22 @@ where
       let uni =
         Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
 
+      let warnings = self.handle_warnings(
+        log_warnings
+          .into_iter()
+          .chain(
+            attrib_warnings
+              .into_iter()
+              .map(ProgramWarning::VertexAttrib),
+          )
+          .chain(
+            uniform_builder
+              .warnings
+              .into_iter()
+              .map(ProgramWarning::Uniform),
+          ),
+      )?;
+
       let program = Program {
         repr,
         uni,
@@ -1132,110 +2576,1063 @@ where
   {
     Self::from_strings_env(self, vertex, tess, geometry, fragment, &mut ())
   }
-}
-
-/// A shader program.
-///
-/// Shader programs are GPU binaries that execute when a draw command is issued.
-///
-/// # Parametricity
-///
-/// - `B` is the backend type.
-/// - `Sem` is the [`Semantics`] type.
-/// - `Out` is the render target type.
-/// - `Uni` is the [`UniformInterface`] type.
-pub struct Program<B, Sem, Out, Uni>
-where
-  B: Shader,
-{
-  pub(crate) repr: B::ProgramRepr,
-  pub(crate) uni: Uni,
-  _sem: PhantomData<*const Sem>,
-  _out: PhantomData<*const Out>,
-}
 
-impl<B, Sem, Out, Uni> Program<B, Sem, Out, Uni>
-where
-  B: Shader,
-  Sem: Semantics,
-{
-  /// Create a new [`UniformInterface`] but keep the [`Program`] around without rebuilding it.
+  /// Start creating a [`Program`] from [`&str`]s, without waiting for the backend to compile and link them.
+  ///
+  /// The returned [`DeferredProgram`] must be polled until the program is ready — for instance once per frame — so that
+  /// the render loop is not stalled while the backend is at work. Compilation and link errors are reported when the
+  /// program resolves. See the documentation of [`DeferredProgram`] for further details.
   ///
   /// # Parametricity
   ///
-  /// - `Q` is the new [`UniformInterface`].
-  pub fn adapt<Q>(self) -> Result<BuiltProgram<B, Sem, Out, Q>, AdaptationFailure<B, Sem, Out, Uni>>
+  /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
+  /// - `G` is an [`Option`] containing a [`&str`] inside (geometry shader).
+  ///
+  /// [`&str`]: str
+  pub fn from_strings_deferred<'b, T, G>(
+    &mut self,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+  ) -> Result<DeferredProgram<C::Backend, Sem, Out, Uni>, ProgramError>
   where
-    Q: UniformInterface<B>,
+    C::Backend: ShaderDeferredBackend,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
   {
-    self.adapt_env(&mut ())
-  }
+    let vs_stage = self.new_deferred_stage(StageType::VertexShader, vertex)?;
 
-  /// Create a new [`UniformInterface`] but keep the [`Program`] around without rebuilding it, by
-  /// using a mutable environment variable.
+    let tess_stages = match tess.into() {
+      Some(TessellationStages {
+        control,
+        evaluation,
+      }) => Some((
+        self.new_deferred_stage(StageType::TessellationControlShader, control)?,
+        self.new_deferred_stage(StageType::TessellationEvaluationShader, evaluation)?,
+      )),
+      None => None,
+    };
+
+    let gs_stage = match geometry.into() {
+      Some(geometry) => Some(self.new_deferred_stage(StageType::GeometryShader, geometry)?),
+      None => None,
+    };
+
+    let fs_stage = self.new_deferred_stage(StageType::FragmentShader, fragment)?;
+
+    unsafe {
+      let mut repr = self.ctx.backend().new_deferred_program(
+        &vs_stage.repr,
+        tess_stages
+          .as_ref()
+          .map(|(control, evaluation)| TessellationStages {
+            control: &control.repr,
+            evaluation: &evaluation.repr,
+          }),
+        gs_stage.as_ref().map(|stage| &stage.repr),
+        &fs_stage.repr,
+      )?;
+
+      self.prepare_link(&mut repr)?;
+
+      C::Backend::start_deferred_link::<Sem>(&mut repr);
+
+      let mut stages = vec![vs_stage];
+      stages.extend(
+        tess_stages
+          .into_iter()
+          .flat_map(|(control, evaluation)| [control, evaluation]),
+      );
+      stages.extend(gs_stage);
+      stages.push(fs_stage);
+
+      Ok(DeferredProgram {
+        repr: Some(repr),
+        stages,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Start compiling a stage without checking whether it succeeds.
+  fn new_deferred_stage(
+    &mut self,
+    ty: StageType,
+    src: &str,
+  ) -> Result<Stage<C::Backend>, StageError>
+  where
+    C::Backend: ShaderDeferredBackend,
+  {
+    unsafe {
+      self
+        .ctx
+        .backend()
+        .new_deferred_stage(ty, src)
+        .map(|repr| Stage { repr, ty })
+    }
+  }
+
+  /// Create a [`ComputeProgram`] from a compute [`Stage`] and accessing a mutable environment variable.
+  ///
+  /// The vertex [`Semantics`] and render target types of the [`ProgramBuilder`] are ignored.
+  pub fn from_compute_stage_env<E>(
+    &mut self,
+    compute: &Stage<C::Backend>,
+    env: &mut E,
+  ) -> Result<BuiltComputeProgram<C::Backend, Uni>, ProgramError>
+  where
+    C::Backend: ShaderComputeBackend,
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    unsafe {
+      let mut repr = self.ctx.backend().new_compute_program(&compute.repr)?;
+      let log_warnings = log_warnings(&[compute], &repr);
+
+      let mut uniform_builder =
+        C::Backend::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
+          repr,
+          warnings: Vec::new(),
+          _a: PhantomData,
+        })?;
+
+      let uni =
+        Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+
+      let warnings = self.handle_warnings(
+        log_warnings.into_iter().chain(
+          uniform_builder
+            .warnings
+            .into_iter()
+            .map(ProgramWarning::Uniform),
+        ),
+      )?;
+
+      let program = ComputeProgram { repr, uni };
+
+      Ok(BuiltComputeProgram { program, warnings })
+    }
+  }
+
+  /// Create a [`ComputeProgram`] from a compute [`Stage`].
+  ///
+  /// The vertex [`Semantics`] and render target types of the [`ProgramBuilder`] are ignored.
+  pub fn from_compute_stage(
+    &mut self,
+    compute: &Stage<C::Backend>,
+  ) -> Result<BuiltComputeProgram<C::Backend, Uni>, ProgramError>
+  where
+    C::Backend: ShaderComputeBackend,
+    Uni: UniformInterface<C::Backend>,
+  {
+    Self::from_compute_stage_env(self, compute, &mut ())
+  }
+
+  /// Create a [`ComputeProgram`] from the source of a compute stage and accessing a mutable environment variable.
+  ///
+  /// The vertex [`Semantics`] and render target types of the [`ProgramBuilder`] are ignored.
+  pub fn from_compute_string_env<E>(
+    &mut self,
+    compute: &str,
+    env: &mut E,
+  ) -> Result<BuiltComputeProgram<C::Backend, Uni>, ProgramError>
+  where
+    C::Backend: ShaderComputeBackend,
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    let cs_stage = Stage::new(self.ctx, StageType::ComputeShader, compute)?;
+    Self::from_compute_stage_env(self, &cs_stage, env)
+  }
+
+  /// Create a [`ComputeProgram`] from the source of a compute stage.
+  ///
+  /// The vertex [`Semantics`] and render target types of the [`ProgramBuilder`] are ignored.
+  pub fn from_compute_string(
+    &mut self,
+    compute: &str,
+  ) -> Result<BuiltComputeProgram<C::Backend, Uni>, ProgramError>
+  where
+    C::Backend: ShaderComputeBackend,
+    Uni: UniformInterface<C::Backend>,
+  {
+    Self::from_compute_string_env(self, compute, &mut ())
+  }
+
+  /// Create a separable [`Program`] from a single [`Stage`] and accessing a mutable environment variable.
+  ///
+  /// Separable programs are meant to be used in a [`ProgramPipeline`]. The vertex [`Semantics`] are only relevant for
+  /// vertex stages; use `()` for the other stages.
+  pub fn from_separable_stage_env<E>(
+    &mut self,
+    stage: &Stage<C::Backend>,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderSeparableBackend,
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    unsafe {
+      let mut repr = self.ctx.backend().new_separable_program(&stage.repr)?;
+
+      self.prepare_link(&mut repr)?;
+
+      let attrib_warnings = C::Backend::apply_semantics::<Sem>(&mut repr)?;
+      let log_warnings = log_warnings(&[stage], &repr);
+
+      let mut uniform_builder =
+        C::Backend::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
+          repr,
+          warnings: Vec::new(),
+          _a: PhantomData,
+        })?;
+
+      let uni =
+        Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+
+      let warnings = self.handle_warnings(
+        log_warnings
+          .into_iter()
+          .chain(
+            attrib_warnings
+              .into_iter()
+              .map(ProgramWarning::VertexAttrib),
+          )
+          .chain(
+            uniform_builder
+              .warnings
+              .into_iter()
+              .map(ProgramWarning::Uniform),
+          ),
+      )?;
+
+      let program = Program {
+        repr,
+        uni,
+        _sem: PhantomData,
+        _out: PhantomData,
+      };
+
+      Ok(BuiltProgram { program, warnings })
+    }
+  }
+
+  /// Create a separable [`Program`] from a single [`Stage`].
+  ///
+  /// See the documentation of [`ProgramBuilder::from_separable_stage_env`] for further details.
+  pub fn from_separable_stage(
+    &mut self,
+    stage: &Stage<C::Backend>,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderSeparableBackend,
+    Uni: UniformInterface<C::Backend>,
+  {
+    Self::from_separable_stage_env(self, stage, &mut ())
+  }
+
+  /// Create a separable [`Program`] from the source of a single stage and accessing a mutable environment variable.
+  ///
+  /// See the documentation of [`ProgramBuilder::from_separable_stage_env`] for further details.
+  pub fn from_separable_string_env<E>(
+    &mut self,
+    ty: StageType,
+    src: &str,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderSeparableBackend,
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    let stage = Stage::new(self.ctx, ty, src)?;
+    Self::from_separable_stage_env(self, &stage, env)
+  }
+
+  /// Create a separable [`Program`] from the source of a single stage.
+  ///
+  /// See the documentation of [`ProgramBuilder::from_separable_stage_env`] for further details.
+  pub fn from_separable_string(
+    &mut self,
+    ty: StageType,
+    src: &str,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderSeparableBackend,
+    Uni: UniformInterface<C::Backend>,
+  {
+    Self::from_separable_string_env(self, ty, src, &mut ())
+  }
+
+  /// Create a mesh [`Program`] from its [`Stage`]s and accessing a mutable environment variable.
+  ///
+  /// Mesh programs replace the vertex processing stages by an optional task stage and a mesh stage, which generate
+  /// primitives in work groups instead of processing vertices. They are drawn with [`RenderGate::draw_mesh_tasks`]. As
+  /// they have no vertex input, the vertex [`Semantics`] are irrelevant; use `()`.
+  ///
+  /// # Parametricity
+  ///
+  /// - `T` is an [`Option`] containing a [`Stage`] inside (task shader).
+  /// - `E` is the mutable environment variable.
+  ///
+  /// [`RenderGate::draw_mesh_tasks`]: crate::render_gate::RenderGate::draw_mesh_tasks
+  pub fn from_mesh_stages_env<'b, T, E>(
+    &mut self,
+    task: T,
+    mesh: &'b Stage<C::Backend>,
+    fragment: &'b Stage<C::Backend>,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderMeshBackend,
+    Uni: UniformInterface<C::Backend, E>,
+    T: Into<Option<&'b Stage<C::Backend>>>,
+  {
+    let task = task.into();
+
+    if let Some(task) = task {
+      check_stage_type(task, StageType::TaskShader)?;
+    }
+
+    check_stage_type(mesh, StageType::MeshShader)?;
+    check_stage_type(fragment, StageType::FragmentShader)?;
+
+    let stages: Vec<_> = task.into_iter().chain([mesh, fragment]).collect();
+
+    unsafe {
+      let mut repr = self.ctx.backend().new_mesh_program(
+        task.map(|stage| &stage.repr),
+        &mesh.repr,
+        &fragment.repr,
+      )?;
+
+      self.prepare_link(&mut repr)?;
+
+      let attrib_warnings = C::Backend::apply_semantics::<Sem>(&mut repr)?;
+      let log_warnings = log_warnings(&stages, &repr);
+
+      let mut uniform_builder =
+        C::Backend::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
+          repr,
+          warnings: Vec::new(),
+          _a: PhantomData,
+        })?;
+
+      let uni =
+        Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+
+      let warnings = self.handle_warnings(
+        log_warnings.into_iter().chain(
+          attrib_warnings
+            .into_iter()
+            .map(ProgramWarning::VertexAttrib)
+            .chain(
+              uniform_builder
+                .warnings
+                .into_iter()
+                .map(ProgramWarning::Uniform),
+            ),
+        ),
+      )?;
+
+      let program = Program {
+        repr,
+        uni,
+        _sem: PhantomData,
+        _out: PhantomData,
+      };
+
+      Ok(BuiltProgram { program, warnings })
+    }
+  }
+
+  /// Create a mesh [`Program`] from its [`Stage`]s.
+  ///
+  /// See the documentation of [`ProgramBuilder::from_mesh_stages_env`] for further details.
+  pub fn from_mesh_stages<'b, T>(
+    &mut self,
+    task: T,
+    mesh: &'b Stage<C::Backend>,
+    fragment: &'b Stage<C::Backend>,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderMeshBackend,
+    Uni: UniformInterface<C::Backend>,
+    T: Into<Option<&'b Stage<C::Backend>>>,
+  {
+    Self::from_mesh_stages_env(self, task, mesh, fragment, &mut ())
+  }
+
+  /// Create a mesh [`Program`] from the sources of its stages and accessing a mutable environment variable.
+  ///
+  /// See the documentation of [`ProgramBuilder::from_mesh_stages_env`] for further details.
+  ///
+  /// # Parametricity
+  ///
+  /// - `T` is an [`Option`] containing a [`&str`] inside (task shader).
+  /// - `E` is the mutable environment variable.
+  ///
+  /// [`&str`]: str
+  pub fn from_mesh_strings_env<'b, T, E>(
+    &mut self,
+    task: T,
+    mesh: &'b str,
+    fragment: &'b str,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderMeshBackend,
+    Uni: UniformInterface<C::Backend, E>,
+    T: Into<Option<&'b str>>,
+  {
+    let ts_stage = match task.into() {
+      Some(task) => Some(Stage::new(self.ctx, StageType::TaskShader, task)?),
+      None => None,
+    };
+    let ms_stage = Stage::new(self.ctx, StageType::MeshShader, mesh)?;
+    let fs_stage = Stage::new(self.ctx, StageType::FragmentShader, fragment)?;
+
+    Self::from_mesh_stages_env(self, ts_stage.as_ref(), &ms_stage, &fs_stage, env)
+  }
+
+  /// Create a mesh [`Program`] from the sources of its stages.
+  ///
+  /// See the documentation of [`ProgramBuilder::from_mesh_stages_env`] for further details.
+  pub fn from_mesh_strings<'b, T>(
+    &mut self,
+    task: T,
+    mesh: &'b str,
+    fragment: &'b str,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    C::Backend: ShaderMeshBackend,
+    Uni: UniformInterface<C::Backend>,
+    T: Into<Option<&'b str>>,
+  {
+    Self::from_mesh_strings_env(self, task, mesh, fragment, &mut ())
+  }
+}
+
+/// A shader program.
+///
+/// Shader programs are GPU binaries that execute when a draw command is issued.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `Sem` is the [`Semantics`] type.
+/// - `Out` is the render target type.
+/// - `Uni` is the [`UniformInterface`] type.
+pub struct Program<B, Sem, Out, Uni>
+where
+  B: Shader,
+{
+  pub(crate) repr: B::ProgramRepr,
+  pub(crate) uni: Uni,
+  _sem: PhantomData<*const Sem>,
+  _out: PhantomData<*const Out>,
+}
+
+impl<B, Sem, Out, Uni> Program<B, Sem, Out, Uni>
+where
+  B: Shader,
+  Sem: Semantics,
+{
+  /// Create a [`ProgramBuilder`] to build a [`Program`] stage by stage.
+  ///
+  /// See the documentation of [`ProgramBuilder`] for further details.
+  pub fn builder<C>(ctx: &mut C) -> ProgramBuilder<'_, C, Sem, Out, Uni>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    ProgramBuilder::new(ctx)
+  }
+
+  /// Start creating a [`Program`] from [`&str`]s, without waiting for the backend to compile and link them.
+  ///
+  /// See the documentation of [`ProgramBuilder::from_strings_deferred`] for further details.
+  ///
+  /// [`&str`]: str
+  pub fn new_deferred<'b, C, T, G>(
+    ctx: &mut C,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+  ) -> Result<DeferredProgram<B, Sem, Out, Uni>, ProgramError>
+  where
+    C: GraphicsContext<Backend = B>,
+    B: ShaderDeferredBackend,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
+  {
+    ProgramBuilder::new(ctx).from_strings_deferred(vertex, tess, geometry, fragment)
+  }
+
+  /// List the active uniforms of the program.
+  ///
+  /// This is useful to discover what a program exposes without knowing the names of its uniforms. Uniforms declared
+  /// in uniform blocks are not listed.
+  pub fn active_uniforms(&self) -> Vec<ActiveUniform> {
+    unsafe { B::active_uniforms(&self.repr) }
+  }
+
+  /// List the active vertex attributes of the program.
+  ///
+  /// Built-in vertex attributes, such as `gl_VertexID`, are not listed. See [`ActiveVertexAttrib::check_format`] to
+  /// check them against a vertex format.
+  pub fn active_vertex_attribs(&self) -> Vec<ActiveVertexAttrib> {
+    unsafe { B::active_vertex_attribs(&self.repr) }
+  }
+
+  /// Configuration of the geometry stage of the program, if any.
+  ///
+  /// See [`GeometryInput::accepts`] to check it against the primitive mode of a [`Tess`].
+  ///
+  /// [`Tess`]: crate::tess::Tess
+  pub fn geometry_config(&self) -> Option<GeometryConfig> {
+    unsafe { B::geometry_config(&self.repr) }
+  }
+
+  /// Link log of the program, if the linker reported anything.
+  pub fn log(&self) -> Option<ShaderLog> {
+    parse_log(unsafe { B::program_log(&self.repr) }, None)
+  }
+
+  /// Get the color attachment index a fragment output is written to.
+  ///
+  /// This reflects the effective binding of the output after linking, whether it was bound with
+  /// [`ProgramBuilder::bind_fragment_output`], declared with an explicit location or assigned by the backend. [`None`]
+  /// is returned if the output is not active.
+  pub fn fragment_output_location(&self, name: &str) -> Option<usize> {
+    unsafe { B::fragment_output_location(&self.repr, name) }
+  }
+
+  /// List the active atomic counters of the program.
+  ///
+  /// This is useful to know which binding points to bind [`AtomicCounters`] to, and where the counters live in them.
+  pub fn active_atomic_counters(&self) -> Vec<ActiveAtomicCounter>
+  where
+    B: AtomicCountersBackend,
+  {
+    unsafe { B::active_atomic_counters(&self.repr) }
+  }
+
+  /// Update the uniforms of the program while it’s not in use.
+  ///
+  /// This is mostly useful with separable programs, which are not used directly but via a [`ProgramPipeline`], but it
+  /// works with any program.
+  pub fn update<E, F>(&mut self, f: F) -> Result<(), E>
+  where
+    B: ShaderSeparableBackend,
+    F: for<'b> FnOnce(ProgramInterface<'b, B>, &'b Uni) -> Result<(), E>,
+  {
+    let program_interface = ProgramInterface {
+      program: &mut self.repr,
+    };
+
+    f(program_interface, &self.uni)
+  }
+
+  /// Validate the program against the current state.
+  ///
+  /// The state — bound textures, samplers, etc. — is checked as if the program was used for a draw command, which helps
+  /// debugging issues such as different sampler types using the same texture unit. Validation is only meaningful
+  /// inside a pipeline, once the resources are bound; use [`ProgramInterface::validate`] while shading.
+  pub fn validate(&self) -> ProgramValidation
+  where
+    B: ShaderValidationBackend,
+  {
+    unsafe { B::validate_program(&self.repr) }
+  }
+
+  /// Create a new [`UniformInterface`] but keep the [`Program`] around without rebuilding it.
+  ///
+  /// # Parametricity
+  ///
+  /// - `Q` is the new [`UniformInterface`].
+  pub fn adapt<Q>(self) -> Result<BuiltProgram<B, Sem, Out, Q>, AdaptationFailure<B, Sem, Out, Uni>>
+  where
+    Q: UniformInterface<B>,
+  {
+    self.adapt_env(&mut ())
+  }
+
+  /// Create a new [`UniformInterface`] but keep the [`Program`] around without rebuilding it, by
+  /// using a mutable environment variable.
+  ///
+  /// # Parametricity
+  ///
+  /// - `Q` is the new [`UniformInterface`].
+  /// - `E` is the mutable environment variable.
+  pub fn adapt_env<Q, E>(
+    mut self,
+    env: &mut E,
+  ) -> Result<BuiltProgram<B, Sem, Out, Q>, AdaptationFailure<B, Sem, Out, Uni>>
+  where
+    Q: UniformInterface<B, E>,
+  {
+    // first, try to create the new uniform interface
+    let mut uniform_builder: UniformBuilder<B> =
+      match unsafe { B::new_uniform_builder(&mut self.repr) } {
+        Ok(repr) => UniformBuilder {
+          repr,
+          warnings: Vec::new(),
+          _a: PhantomData,
+        },
+
+        Err(e) => return Err(AdaptationFailure::new(self, e)),
+      };
+
+    let uni = match Q::uniform_interface(&mut uniform_builder, env) {
+      Ok(uni) => uni,
+      Err(e) => {
+        return Err(AdaptationFailure::new(
+          self,
+          ProgramWarning::Uniform(e).into(),
+        ))
+      }
+    };
+
+    let warnings = uniform_builder
+      .warnings
+      .into_iter()
+      .map(|w| ProgramError::Warning(w.into()))
+      .collect();
+
+    let program = Program {
+      repr: self.repr,
+      uni,
+      _sem: PhantomData,
+      _out: PhantomData,
+    };
+
+    Ok(BuiltProgram { program, warnings })
+  }
+
+  /// Re-create the [`UniformInterface`] but keep the [`Program`] around without rebuilding it.
+  ///
+  /// # Parametricity
+  ///
+  /// - `E` is the mutable environment variable.
+  pub fn readapt_env<E>(
+    self,
+    env: &mut E,
+  ) -> Result<BuiltProgram<B, Sem, Out, Uni>, AdaptationFailure<B, Sem, Out, Uni>>
+  where
+    Uni: UniformInterface<B, E>,
+  {
+    self.adapt_env(env)
+  }
+
+  /// Reload the program in place by linking new [`Stage`]s, keeping its [`Uniform`]s valid.
+  ///
+  /// This is useful for live shader editing: the [`UniformInterface`] doesn’t have to be rebuilt, as its uniforms refer
+  /// to the uniforms with the same names in the reloaded program. The returned warnings report the uniforms that became
+  /// inactive or changed type — updating them is then a no-op — as well as vertex attributes issues and the logs of the
+  /// compiler and the linker. If the new stages fail to link, the program is left untouched.
+  ///
+  /// The values of the uniforms are reset by the reload, so they must be set again. The same goes for the selected
+  /// subroutines; [`SubroutineUniform`]s and [`Subroutine`]s must be asked again with [`Program::readapt_env`].
+  ///
+  /// # Parametricity
+  ///
+  /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`Stage`] inside.
+  /// - `G` is an [`Option`] containing a [`Stage`] inside (geometry shader).
+  pub fn reload<'b, C, T, G>(
+    &mut self,
+    ctx: &mut C,
+    vertex: &'b Stage<B>,
+    tess: T,
+    geometry: G,
+    fragment: &'b Stage<B>,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    B: ShaderReloadBackend,
+    C: GraphicsContext<Backend = B>,
+    T: Into<Option<TessellationStages<'b, Stage<B>>>>,
+    G: Into<Option<&'b Stage<B>>>,
+  {
+    let tess = tess.into();
+    let geometry = geometry.into();
+    let stages: Vec<_> = iter::once(vertex)
+      .chain(
+        tess
+          .iter()
+          .flat_map(|stages| [stages.control, stages.evaluation]),
+      )
+      .chain(geometry)
+      .chain(iter::once(fragment))
+      .collect();
+
+    let warnings = unsafe {
+      ctx.backend().reload_program::<Sem>(
+        &mut self.repr,
+        &vertex.repr,
+        tess.map(|stages| TessellationStages {
+          control: &stages.control.repr,
+          evaluation: &stages.evaluation.repr,
+        }),
+        geometry.map(|stage| &stage.repr),
+        &fragment.repr,
+      )?
+    };
+
+    Ok(
+      log_warnings(&stages, &self.repr)
+        .into_iter()
+        .chain(warnings)
+        .collect(),
+    )
+  }
+
+  /// Reload the program in place by linking new [`&str`]s, keeping its [`Uniform`]s valid.
   ///
-  /// # Parametricity
+  /// See the documentation of [`Program::reload`] for further details.
   ///
-  /// - `Q` is the new [`UniformInterface`].
-  /// - `E` is the mutable environment variable.
-  pub fn adapt_env<Q, E>(
-    mut self,
-    env: &mut E,
-  ) -> Result<BuiltProgram<B, Sem, Out, Q>, AdaptationFailure<B, Sem, Out, Uni>>
+  /// [`&str`]: str
+  pub fn reload_strings<'b, C, T, G>(
+    &mut self,
+    ctx: &mut C,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
   where
-    Q: UniformInterface<B, E>,
+    B: ShaderReloadBackend,
+    C: GraphicsContext<Backend = B>,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
   {
-    // first, try to create the new uniform interface
-    let mut uniform_builder: UniformBuilder<B> =
-      match unsafe { B::new_uniform_builder(&mut self.repr) } {
-        Ok(repr) => UniformBuilder {
-          repr,
-          warnings: Vec::new(),
-          _a: PhantomData,
-        },
-
-        Err(e) => return Err(AdaptationFailure::new(self, e)),
-      };
+    let vs_stage = Stage::new(ctx, StageType::VertexShader, vertex)?;
 
-    let uni = match Q::uniform_interface(&mut uniform_builder, env) {
-      Ok(uni) => uni,
-      Err(e) => {
-        return Err(AdaptationFailure::new(
-          self,
-          ProgramWarning::Uniform(e).into(),
-        ))
+    let tess_stages = match tess.into() {
+      Some(TessellationStages {
+        control,
+        evaluation,
+      }) => {
+        let control_stage = Stage::new(ctx, StageType::TessellationControlShader, control)?;
+        let evaluation_stage =
+          Stage::new(ctx, StageType::TessellationEvaluationShader, evaluation)?;
+        Some((control_stage, evaluation_stage))
       }
+      None => None,
+    };
+    let tess_stages =
+      tess_stages
+        .as_ref()
+        .map(|(ref control, ref evaluation)| TessellationStages {
+          control,
+          evaluation,
+        });
+
+    let gs_stage = match geometry.into() {
+      Some(geometry) => Some(Stage::new(ctx, StageType::GeometryShader, geometry)?),
+      None => None,
     };
 
-    let warnings = uniform_builder
-      .warnings
-      .into_iter()
-      .map(|w| ProgramError::Warning(w.into()))
-      .collect();
+    let fs_stage = Stage::new(ctx, StageType::FragmentShader, fragment)?;
 
-    let program = Program {
-      repr: self.repr,
-      uni,
-      _sem: PhantomData,
-      _out: PhantomData,
+    self.reload(ctx, &vs_stage, tess_stages, gs_stage.as_ref(), &fs_stage)
+  }
+
+  /// Reload the program in place by replacing its stage of type `ty` and linking it again, keeping its [`Uniform`]s
+  /// valid.
+  ///
+  /// The other stages of the program are kept, so they don’t need to be kept around — this is handy when only one
+  /// stage is edited. If the program has no stage of type `ty`, `stage` is added to it. `stage` must be of type `ty`.
+  ///
+  /// See the documentation of [`Program::reload`] for further details about uniforms and link failures.
+  pub fn replace_stage<C>(
+    &mut self,
+    ctx: &mut C,
+    ty: StageType,
+    stage: &Stage<B>,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    B: ShaderReloadBackend,
+    C: GraphicsContext<Backend = B>,
+  {
+    check_stage_type(stage, ty)?;
+    let warnings = unsafe {
+      ctx
+        .backend()
+        .replace_stage::<Sem>(&mut self.repr, &stage.repr)?
     };
 
-    Ok(BuiltProgram { program, warnings })
+    Ok(
+      log_warnings(&[stage], &self.repr)
+        .into_iter()
+        .chain(warnings)
+        .collect(),
+    )
   }
 
-  /// Re-create the [`UniformInterface`] but keep the [`Program`] around without rebuilding it.
+  /// Reload the program in place by replacing its stage of type `ty` by a [`&str`], keeping its [`Uniform`]s valid.
   ///
-  /// # Parametricity
+  /// See the documentation of [`Program::replace_stage`] for further details.
   ///
-  /// - `E` is the mutable environment variable.
-  pub fn readapt_env<E>(
-    self,
+  /// [`&str`]: str
+  pub fn replace_stage_string<C>(
+    &mut self,
+    ctx: &mut C,
+    ty: StageType,
+    src: &str,
+  ) -> Result<Vec<ProgramWarning>, ProgramError>
+  where
+    B: ShaderReloadBackend,
+    C: GraphicsContext<Backend = B>,
+  {
+    let stage = Stage::new(ctx, ty, src)?;
+    self.replace_stage(ctx, ty, &stage)
+  }
+}
+
+/// A [`Program`] which stages are compiled and linked in the background.
+///
+/// Compiling and linking shaders can take a long time, during which the backend is blocked when creating programs with
+/// [`ProgramBuilder::from_strings`] and friends. Deferred programs — created with [`Program::new_deferred`] or
+/// [`ProgramBuilder::from_strings_deferred`] — are handles to programs that the backend builds in the background
+/// instead (with OpenGL, this requires the `GL_KHR_parallel_shader_compile` extension).
+///
+/// [`DeferredProgram::poll`] never blocks: it returns [`Poll::Pending`] as long as the backend is at work, and resolves
+/// to the [`BuiltProgram`] — or to the compilation or link error — once done. It can then be called once per frame
+/// until the program is ready. [`DeferredProgram::wait`] blocks until the program is ready instead.
+///
+/// The warning handler of the [`ProgramBuilder`], if any, is not called for deferred programs: warnings are always
+/// collected in [`BuiltProgram::warnings`].
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `Sem` is the [`Semantics`] type.
+/// - `Out` is the render target type.
+/// - `Uni` is the [`UniformInterface`] type.
+pub struct DeferredProgram<B, Sem, Out, Uni>
+where
+  B: ShaderDeferredBackend,
+{
+  // None once resolved
+  repr: Option<B::ProgramRepr>,
+  // kept around to report compilation errors once resolved
+  stages: Vec<Stage<B>>,
+  _phantom: PhantomData<(*const Sem, *const Out, *const Uni)>,
+}
+
+impl<B, Sem, Out, Uni> DeferredProgram<B, Sem, Out, Uni>
+where
+  B: ShaderDeferredBackend,
+  Sem: Semantics,
+{
+  /// Check whether the program is ready to resolve, without blocking.
+  pub fn is_complete(&self) -> bool {
+    self
+      .repr
+      .as_ref()
+      .map_or(true, |repr| unsafe { B::is_deferred_link_complete(repr) })
+  }
+
+  /// Poll the program and build its [`UniformInterface`] by accessing a mutable environment variable once ready.
+  ///
+  /// Once the program has resolved, polling it again resolves to [`ProgramError::AlreadyResolved`].
+  pub fn poll_env<E>(
+    &mut self,
     env: &mut E,
-  ) -> Result<BuiltProgram<B, Sem, Out, Uni>, AdaptationFailure<B, Sem, Out, Uni>>
+  ) -> Poll<Result<BuiltProgram<B, Sem, Out, Uni>, ProgramError>>
   where
     Uni: UniformInterface<B, E>,
   {
-    self.adapt_env(env)
+    if self.is_complete() {
+      Poll::Ready(self.resolve(env))
+    } else {
+      Poll::Pending
+    }
+  }
+
+  /// Poll the program.
+  ///
+  /// See the documentation of [`DeferredProgram::poll_env`] for further details.
+  pub fn poll(&mut self) -> Poll<Result<BuiltProgram<B, Sem, Out, Uni>, ProgramError>>
+  where
+    Uni: UniformInterface<B>,
+  {
+    self.poll_env(&mut ())
+  }
+
+  /// Wait for the program to be ready and build its [`UniformInterface`] by accessing a mutable environment variable.
+  ///
+  /// [`ProgramError::AlreadyResolved`] is returned if the program has already resolved when polled.
+  pub fn wait_env<E>(mut self, env: &mut E) -> Result<BuiltProgram<B, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<B, E>,
+  {
+    self.resolve(env)
+  }
+
+  /// Wait for the program to be ready.
+  ///
+  /// See the documentation of [`DeferredProgram::wait_env`] for further details.
+  pub fn wait(self) -> Result<BuiltProgram<B, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<B>,
+  {
+    self.wait_env(&mut ())
+  }
+
+  fn resolve<E>(&mut self, env: &mut E) -> Result<BuiltProgram<B, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<B, E>,
+  {
+    let mut repr = self
+      .repr
+      .take()
+      .ok_or_else(ProgramError::already_resolved)?;
+    let stages = std::mem::take(&mut self.stages);
+
+    unsafe {
+      let stage_reprs: Vec<_> = stages.iter().map(|stage| &stage.repr).collect();
+      let attrib_warnings = B::finish_deferred_link::<Sem>(&mut repr, &stage_reprs)?;
+      let log_warnings = log_warnings(&stages.iter().collect::<Vec<_>>(), &repr);
+
+      let mut uniform_builder = B::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
+        repr,
+        warnings: Vec::new(),
+        _a: PhantomData,
+      })?;
+
+      let uni =
+        Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+
+      let warnings = log_warnings
+        .into_iter()
+        .chain(
+          attrib_warnings
+            .into_iter()
+            .map(ProgramWarning::VertexAttrib),
+        )
+        .chain(
+          uniform_builder
+            .warnings
+            .into_iter()
+            .map(ProgramWarning::Uniform),
+        )
+        .map(ProgramError::Warning)
+        .collect();
+
+      let program = Program {
+        repr,
+        uni,
+        _sem: PhantomData,
+        _out: PhantomData,
+      };
+
+      Ok(BuiltProgram { program, warnings })
+    }
+  }
+}
+
+/// A compute shader program.
+///
+/// Compute programs are made of a single compute stage and are not part of the graphics pipeline: instead of being
+/// invoked by draw commands, they are dispatched over a grid of work groups with [`ShadingGate::compute`]. They
+/// typically read from and write to shader storage and images.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `Uni` is the [`UniformInterface`] type.
+///
+/// [`ShadingGate::compute`]: crate::shading_gate::ShadingGate::compute
+pub struct ComputeProgram<B, Uni>
+where
+  B: Shader,
+{
+  pub(crate) repr: B::ProgramRepr,
+  pub(crate) uni: Uni,
+}
+
+impl<B, Uni> ComputeProgram<B, Uni>
+where
+  B: Shader,
+{
+  /// List the active uniforms of the program.
+  ///
+  /// Uniforms declared in uniform blocks are not listed.
+  pub fn active_uniforms(&self) -> Vec<ActiveUniform> {
+    unsafe { B::active_uniforms(&self.repr) }
+  }
+
+  /// Link log of the program, if the linker reported anything.
+  pub fn log(&self) -> Option<ShaderLog> {
+    parse_log(unsafe { B::program_log(&self.repr) }, None)
+  }
+}
+
+/// A program pipeline.
+///
+/// Program pipelines combine separable [`Program`]s — built with [`ProgramBuilder::from_separable_stage`] and friends —
+/// at render time: each stage of the pipeline uses the corresponding stage of a separable program. This allows to mix
+/// and match stages without linking a new [`Program`] for every combination.
+///
+/// A program pipeline only refers to the programs it uses: they must be kept alive as long as the pipeline is used.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+pub struct ProgramPipeline<B>
+where
+  B: ShaderSeparableBackend,
+{
+  pub(crate) repr: B::ProgramPipelineRepr,
+}
+
+impl<B> ProgramPipeline<B>
+where
+  B: ShaderSeparableBackend,
+{
+  /// Create a new, empty [`ProgramPipeline`].
+  pub fn new<C>(ctx: &mut C) -> Result<Self, ProgramError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    unsafe {
+      ctx
+        .backend()
+        .new_program_pipeline()
+        .map(|repr| ProgramPipeline { repr })
+    }
+  }
+
+  /// Use the stage of a separable [`Program`], replacing the program previously used for that stage, if any.
+  pub fn use_stage<Sem, Out, Uni>(&mut self, program: &Program<B, Sem, Out, Uni>) {
+    unsafe { B::use_program_stage(&mut self.repr, &program.repr) }
+  }
+}
+
+/// A built compute program with potential warnings.
+///
+/// The sole purpose of this type is to be destructured when a compute program is built.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `Uni` is the [`UniformInterface`] type.
+pub struct BuiltComputeProgram<B, Uni>
+where
+  B: Shader,
+{
+  /// Built compute program.
+  pub program: ComputeProgram<B, Uni>,
+  /// Potential warnings.
+  pub warnings: Vec<ProgramError>,
+}
+
+impl<B, Uni> BuiltComputeProgram<B, Uni>
+where
+  B: Shader,
+{
+  /// Get the compute program and ignore the warnings.
+  pub fn ignore_warnings(self) -> ComputeProgram<B, Uni> {
+    self.program
   }
 }
 
@@ -1281,6 +3678,65 @@ where
   pub fn replace(&mut self, values: impl IntoIterator<Item = T>) -> Result<(), ShaderDataError> {
     unsafe { B::set_shader_data_values(&mut self.repr, values.into_iter()) }
   }
+
+  /// Read all the items back from the GPU.
+  ///
+  /// This is mostly useful after the data was bound with [`Pipeline::bind_shader_storage`] and modified by shader
+  /// stages, as [`ShaderData::at`] might not see such modifications.
+  ///
+  /// [`Pipeline::bind_shader_storage`]: crate::pipeline::Pipeline::bind_shader_storage
+  pub fn read(&self) -> Result<Vec<T>, ShaderDataError>
+  where
+    B: ShaderStorageBackend<T>,
+  {
+    unsafe { B::read_shader_storage(&self.repr) }
+  }
+}
+
+/// Atomic counters.
+///
+/// Atomic counters are `u32` values living in GPU memory that shader stages can atomically increment and decrement
+/// (`atomic_uint`). They are typically used to allocate slots in shader storage, such as fragment lists for
+/// order-independent transparency.
+///
+/// Atomic counters are bound with [`Pipeline::bind_atomic_counters`] at the binding point declared in the shader
+/// stages. Use [`AtomicCounters::read`] to get the counted values back and [`AtomicCounters::replace`] to reset them.
+///
+/// # Parametricity
+///
+/// - `B`: the backend type.
+///
+/// [`Pipeline::bind_atomic_counters`]: crate::pipeline::Pipeline::bind_atomic_counters
+pub struct AtomicCounters<B>
+where
+  B: ?Sized + AtomicCountersBackend,
+{
+  pub(crate) repr: B::AtomicCountersRepr,
+}
+
+impl<B> AtomicCounters<B>
+where
+  B: ?Sized + AtomicCountersBackend,
+{
+  /// Create [`AtomicCounters`] via an iterator of initial values.
+  pub fn new(
+    ctx: &mut impl GraphicsContext<Backend = B>,
+    values: impl IntoIterator<Item = u32>,
+  ) -> Result<Self, ShaderDataError> {
+    let repr = unsafe { ctx.backend().new_atomic_counters(values.into_iter())? };
+    Ok(Self { repr })
+  }
+
+  /// Read all the counters back from the GPU.
+  pub fn read(&self) -> Result<Vec<u32>, ShaderDataError> {
+    unsafe { B::read_atomic_counters(&self.repr) }
+  }
+
+  /// Replace all the counters with the values provided by the iterator, which must provide exactly one value per
+  /// counter; otherwise, [`ShaderDataError::CannotReplaceData`] is returned and the counters are left untouched.
+  pub fn replace(&mut self, values: impl IntoIterator<Item = u32>) -> Result<(), ShaderDataError> {
+    unsafe { B::set_atomic_counters(&mut self.repr, values.into_iter()) }
+  }
 }
 
 /// Possible errors that can occur with shader data.
@@ -1304,6 +3760,9 @@ pub enum ShaderDataError {
 
   /// Cannot replace data.
   CannotReplaceData,
+
+  /// Cannot read data back.
+  CannotReadData,
 }
 
 impl fmt::Display for ShaderDataError {
@@ -1320,6 +3779,8 @@ impl fmt::Display for ShaderDataError {
       }
 
       ShaderDataError::CannotReplaceData => f.write_str("cannot replace shader data"),
+
+      ShaderDataError::CannotReadData => f.write_str("cannot read shader data back"),
     }
   }
 }
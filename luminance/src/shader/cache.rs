@@ -0,0 +1,211 @@
+//! In-process program cache.
+//!
+//! Applications often build the same [`Program`]s — or the same permutations of an _ubershader_ — several times, for
+//! instance when switching from a scene to another. A [`ProgramCache`] keeps the programs it builds around, keyed by
+//! the sources and defines of their stages, and hands out shared handles to the programs already built instead of
+//! compiling and linking them again.
+//!
+//! The programs are shared as [`SharedProgram`]s, which must be borrowed mutably to shade with them.
+
+use crate::{
+  context::GraphicsContext,
+  shader::{
+    Program, ProgramBuilder, ProgramError, Shader, Stage, StageType, TessellationStages,
+    UniformInterface,
+  },
+  vertex::Semantics,
+};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+/// A [`Program`] shared by a [`ProgramCache`].
+pub type SharedProgram<B, Sem, Out, Uni> = Rc<RefCell<Program<B, Sem, Out, Uni>>>;
+
+/// Everything a cached program is built from.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct ProgramKey {
+  stages: Vec<(StageType, String)>,
+  defines: Vec<(String, String)>,
+  varyings: Vec<String>,
+  fragment_outputs: Vec<(String, usize)>,
+}
+
+/// A cache of [`Program`]s, keyed by the sources and defines of their stages.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `Sem` is the [`Semantics`] type.
+/// - `Out` is the render target type.
+/// - `Uni` is the [`UniformInterface`] type.
+pub struct ProgramCache<B, Sem, Out, Uni>
+where
+  B: Shader,
+{
+  programs: HashMap<ProgramKey, SharedProgram<B, Sem, Out, Uni>>,
+}
+
+impl<B, Sem, Out, Uni> Default for ProgramCache<B, Sem, Out, Uni>
+where
+  B: Shader,
+{
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<B, Sem, Out, Uni> ProgramCache<B, Sem, Out, Uni>
+where
+  B: Shader,
+{
+  /// Create an empty cache.
+  pub fn new() -> Self {
+    ProgramCache {
+      programs: HashMap::new(),
+    }
+  }
+
+  /// Get the [`Program`] built from the given sources and defines, building it with `builder` and accessing a mutable
+  /// environment variable if it is not in the cache yet.
+  ///
+  /// The defines are injected in every stage, as with [`Stage::new_with_defines`]. The transform feedback varyings and
+  /// fragment outputs configured on `builder` are part of the key, too.
+  ///
+  /// Warnings are only generated when the program gets built. They are passed to the warning handler of `builder`, if
+  /// any — see [`ProgramBuilder::on_warning`] — and ignored otherwise.
+  ///
+  /// # Parametricity
+  ///
+  /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
+  /// - `G` is an [`Option`] containing a [`&str`] inside (geometry shader).
+  /// - `E` is the mutable environment variable.
+  ///
+  /// [`&str`]: str
+  #[allow(clippy::too_many_arguments)]
+  pub fn get_or_build_env<'b, C, T, G, E>(
+    &mut self,
+    builder: &mut ProgramBuilder<C, Sem, Out, Uni>,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+    defines: &[(&str, &str)],
+    env: &mut E,
+  ) -> Result<SharedProgram<B, Sem, Out, Uni>, ProgramError>
+  where
+    C: GraphicsContext<Backend = B>,
+    Sem: Semantics,
+    Uni: UniformInterface<B, E>,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
+  {
+    let mut sources = vec![(StageType::VertexShader, vertex)];
+
+    if let Some(TessellationStages {
+      control,
+      evaluation,
+    }) = tess.into()
+    {
+      sources.push((StageType::TessellationControlShader, control));
+      sources.push((StageType::TessellationEvaluationShader, evaluation));
+    }
+
+    if let Some(geometry) = geometry.into() {
+      sources.push((StageType::GeometryShader, geometry));
+    }
+
+    sources.push((StageType::FragmentShader, fragment));
+
+    let key = ProgramKey {
+      stages: sources
+        .iter()
+        .map(|&(ty, src)| (ty, src.to_owned()))
+        .collect(),
+      defines: defines
+        .iter()
+        .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+        .collect(),
+      varyings: builder.varyings.clone(),
+      fragment_outputs: builder.fragment_outputs.clone(),
+    };
+
+    if let Some(program) = self.programs.get(&key) {
+      return Ok(program.clone());
+    }
+
+    let stages = sources
+      .iter()
+      .map(|&(ty, src)| Stage::new_with_defines(builder.ctx, ty, src, defines.iter().copied()))
+      .collect::<Result<Vec<_>, _>>()?;
+
+    // stages are in the same order as the sources: vertex, then optional tessellation and geometry, then fragment
+    let (vertex, rest) = stages.split_first().unwrap();
+    let (fragment, rest) = rest.split_last().unwrap();
+    let (tess, geometry) = match rest {
+      [control, evaluation, geometry @ ..] => (
+        Some(TessellationStages {
+          control,
+          evaluation,
+        }),
+        geometry.first(),
+      ),
+      geometry => (None, geometry.first()),
+    };
+
+    let program = builder
+      .from_stages_env(vertex, tess, geometry, fragment, env)?
+      .ignore_warnings();
+    let program = Rc::new(RefCell::new(program));
+
+    self.programs.insert(key, program.clone());
+
+    Ok(program)
+  }
+
+  /// Get the [`Program`] built from the given sources and defines, building it with `builder` if it is not in the
+  /// cache yet.
+  ///
+  /// See the documentation of [`ProgramCache::get_or_build_env`] for further details.
+  #[allow(clippy::too_many_arguments)]
+  pub fn get_or_build<'b, C, T, G>(
+    &mut self,
+    builder: &mut ProgramBuilder<C, Sem, Out, Uni>,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+    defines: &[(&str, &str)],
+  ) -> Result<SharedProgram<B, Sem, Out, Uni>, ProgramError>
+  where
+    C: GraphicsContext<Backend = B>,
+    Sem: Semantics,
+    Uni: UniformInterface<B>,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
+  {
+    self.get_or_build_env(builder, vertex, tess, geometry, fragment, defines, &mut ())
+  }
+
+  /// Number of programs in the cache.
+  pub fn len(&self) -> usize {
+    self.programs.len()
+  }
+
+  /// Whether the cache is empty.
+  pub fn is_empty(&self) -> bool {
+    self.programs.is_empty()
+  }
+
+  /// Remove the programs that are only referenced by the cache, dropping them.
+  pub fn purge(&mut self) {
+    self
+      .programs
+      .retain(|_, program| Rc::strong_count(program) > 1);
+  }
+
+  /// Remove all the programs from the cache.
+  ///
+  /// The programs still referenced elsewhere are kept alive until their last handle is dropped.
+  pub fn clear(&mut self) {
+    self.programs.clear();
+  }
+}
@@ -0,0 +1,281 @@
+//! Shader compilation and link diagnostics.
+//!
+//! Drivers report compilation and link errors as free-form logs, which format is not specified and differs from one
+//! vendor to another. This module parses such logs into [`Diagnostic`]s — stage, line, column and message — so that
+//! tools such as editors or tests can act upon them. The formats of the most common drivers are recognized (Mesa,
+//! NVIDIA, AMD and ANGLE); lines that cannot be understood are skipped, but the raw log is always retained in
+//! [`ShaderLog`] as a fallback.
+
+use crate::shader::StageType;
+use std::fmt;
+
+/// Severity of a [`Diagnostic`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Severity {
+  /// An error, preventing the compilation or link from succeeding.
+  Error,
+  /// A warning.
+  Warning,
+}
+
+impl fmt::Display for Severity {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      Severity::Error => f.write_str("error"),
+      Severity::Warning => f.write_str("warning"),
+    }
+  }
+}
+
+/// A single diagnostic extracted from a compilation or link log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+  /// Severity of the diagnostic.
+  pub severity: Severity,
+  /// Stage the diagnostic is about, if known.
+  pub stage: Option<StageType>,
+  /// File the diagnostic is about, if known.
+  ///
+  /// This is only known for sources expanded from several files — see the [`include`] module.
+  ///
+  /// [`include`]: crate::shader::include
+  pub file: Option<String>,
+  /// Chain of `#include` directives leading to [`Diagnostic::file`], as the file and line of each directive, starting
+  /// with the one in the root file.
+  pub include_stack: Vec<(String, usize)>,
+  /// Line (starting at 1) in [`Diagnostic::file`] if known, or in the source of the stage otherwise, if known.
+  pub line: Option<usize>,
+  /// Column (starting at 1) in the line, if known.
+  pub column: Option<usize>,
+  /// Message of the diagnostic.
+  pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    if let Some(stage) = self.stage {
+      write!(f, "{}:", stage)?;
+    }
+
+    if let Some(ref file) = self.file {
+      write!(f, "{}:", file)?;
+    }
+
+    if let Some(line) = self.line {
+      write!(f, "{}:", line)?;
+
+      if let Some(column) = self.column {
+        write!(f, "{}:", column)?;
+      }
+    }
+
+    if self.stage.is_some() || self.file.is_some() || self.line.is_some() {
+      f.write_str(" ")?;
+    }
+
+    write!(f, "{}: {}", self.severity, self.message)
+  }
+}
+
+/// A compilation or link log, along with the [`Diagnostic`]s parsed from it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShaderLog {
+  raw: String,
+  diagnostics: Vec<Diagnostic>,
+}
+
+impl ShaderLog {
+  /// Parse a log.
+  ///
+  /// `stage` is the stage the log is about, if known — e.g. for compilation logs. Otherwise, the stage of each
+  /// diagnostic is guessed from the log, if possible.
+  pub fn parse(raw: impl Into<String>, stage: Option<StageType>) -> Self {
+    let raw = raw.into();
+    let diagnostics = parse_diagnostics(&raw, stage);
+
+    ShaderLog { raw, diagnostics }
+  }
+
+  /// Raw log, as reported by the driver.
+  pub fn raw(&self) -> &str {
+    &self.raw
+  }
+
+  /// Get the raw log back.
+  pub fn into_raw(self) -> String {
+    self.raw
+  }
+
+  /// Diagnostics that could be parsed from the log.
+  pub fn diagnostics(&self) -> &[Diagnostic] {
+    &self.diagnostics
+  }
+
+  pub(crate) fn diagnostics_mut(&mut self) -> &mut [Diagnostic] {
+    &mut self.diagnostics
+  }
+}
+
+impl fmt::Display for ShaderLog {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    f.write_str(&self.raw)
+  }
+}
+
+fn parse_diagnostics(raw: &str, stage: Option<StageType>) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+  let mut current_stage = stage;
+
+  for line in raw.lines().map(str::trim) {
+    if line.is_empty() || line.chars().all(|c| c == '-') {
+      continue;
+    }
+
+    // some drivers (NVIDIA) split link logs per stage with headers such as “Vertex info”
+    if stage.is_none() {
+      if let Some(header_stage) = line.strip_suffix(" info").and_then(parse_stage_prefix) {
+        current_stage = Some(header_stage);
+        continue;
+      }
+    }
+
+    if let Some(mut diagnostic) = parse_diagnostic(line) {
+      if diagnostic.stage.is_none() || stage.is_some() {
+        diagnostic.stage = current_stage;
+      }
+
+      diagnostics.push(diagnostic);
+    }
+  }
+
+  diagnostics
+}
+
+/// Parse a single line of a log.
+///
+/// The recognized formats are:
+///
+/// - `0:12(5): error: message` (Mesa).
+/// - `0(12) : error C0000: message` (NVIDIA).
+/// - `ERROR: 0:12: message` (AMD, ANGLE).
+/// - `error: message`, without location.
+fn parse_diagnostic(line: &str) -> Option<Diagnostic> {
+  // severity first, then optional location
+  if let Some((severity, rest)) = parse_severity(line) {
+    let (line_nb, column, message) = match parse_location(rest) {
+      Some((line_nb, column, message)) => (Some(line_nb), column, message),
+      None => (None, None, rest),
+    };
+
+    // summaries such as “1 compilation errors. No code generated.”
+    if line_nb.is_none() && message.contains("compilation error") {
+      return None;
+    }
+
+    return Some(new_diagnostic(severity, line_nb, column, message));
+  }
+
+  // location first, then severity
+  let (line_nb, column, rest) = parse_location(line)?;
+  let (severity, message) = parse_severity(rest).unwrap_or((Severity::Error, rest));
+
+  Some(new_diagnostic(severity, Some(line_nb), column, message))
+}
+
+fn new_diagnostic(
+  severity: Severity,
+  line: Option<usize>,
+  column: Option<usize>,
+  message: &str,
+) -> Diagnostic {
+  // link messages often start with the stage they are about (e.g. “fragment shader output …”)
+  let stage = parse_stage_prefix(message);
+
+  Diagnostic {
+    severity,
+    stage,
+    file: None,
+    include_stack: Vec::new(),
+    line,
+    column,
+    message: message.to_owned(),
+  }
+}
+
+/// Parse a severity, optionally followed by an error code, and a colon.
+fn parse_severity(s: &str) -> Option<(Severity, &str)> {
+  let word_len = s.find(|c: char| !c.is_ascii_alphabetic())?;
+  let severity = match s[..word_len].to_ascii_lowercase().as_str() {
+    "error" => Severity::Error,
+    "warning" => Severity::Warning,
+    _ => return None,
+  };
+
+  // skip an optional error code, such as C1008
+  let rest = s[word_len..].trim_start();
+  let rest = match rest.find(':') {
+    Some(colon) if rest[..colon].chars().all(|c| c.is_ascii_alphanumeric()) => &rest[colon + 1..],
+    _ => return None,
+  };
+
+  Some((severity, rest.trim()))
+}
+
+/// Parse a location — `file:line(column):`, `file(line) :` or `file:line:` — followed by the rest of the line.
+fn parse_location(s: &str) -> Option<(usize, Option<usize>, &str)> {
+  let (_, rest) = parse_number(s)?;
+
+  let (line, column, rest) = if let Some(rest) = rest.strip_prefix(':') {
+    let (line, rest) = parse_number(rest)?;
+
+    match rest.strip_prefix('(') {
+      Some(rest) => {
+        let (column, rest) = parse_number(rest)?;
+        (line, Some(column), rest.strip_prefix(')')?)
+      }
+
+      None => (line, None, rest),
+    }
+  } else {
+    let (line, rest) = parse_number(rest.strip_prefix('(')?)?;
+    (line, None, rest.strip_prefix(')')?)
+  };
+
+  let rest = rest.trim_start().strip_prefix(':')?;
+  Some((line, column, rest.trim()))
+}
+
+fn parse_number(s: &str) -> Option<(usize, &str)> {
+  let len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+  let number = s[..len].parse().ok()?;
+
+  Some((number, &s[len..]))
+}
+
+/// Parse a stage name at the beginning of a string, such as `vertex` or `Fragment shader`.
+fn parse_stage_prefix(s: &str) -> Option<StageType> {
+  let s = s.to_ascii_lowercase();
+  let stages = [
+    ("vertex", StageType::VertexShader),
+    ("tessellation control", StageType::TessellationControlShader),
+    (
+      "tessellation evaluation",
+      StageType::TessellationEvaluationShader,
+    ),
+    ("geometry", StageType::GeometryShader),
+    ("fragment", StageType::FragmentShader),
+    ("compute", StageType::ComputeShader),
+    ("task", StageType::TaskShader),
+    ("mesh", StageType::MeshShader),
+  ];
+
+  stages.iter().find_map(|&(name, stage)| {
+    let rest = s.strip_prefix(name)?;
+
+    if rest.is_empty() || rest.starts_with(" shader") {
+      Some(stage)
+    } else {
+      None
+    }
+  })
+}
@@ -0,0 +1,318 @@
+//! Shader `#include` resolution.
+//!
+//! GLSL has no notion of files, so sharing code between shader stages requires to paste it in each of them. This
+//! module expands `#include "path"` (or `#include <path>`) directives before compilation instead, by asking an
+//! [`IncludeResolver`] for the source of the included files. Included files can include other files; cyclic inclusions
+//! are reported as errors.
+//!
+//! Directives are expanded regardless of conditional compilation (`#if`, `#ifdef`, etc.), but including the same file
+//! several times is allowed, so regular include guards can be used to prevent multiple definitions.
+//!
+//! Because the expanded source doesn’t have the same lines as the original files anymore, [`ExpandedSource::origin`]
+//! maps lines of the expanded source back to the file and line they come from, and [`ExpandedSource::include_stack`]
+//! to the `#include` directives that led there. [`Stage::new_with_includes`] uses them to report compilation errors in
+//! terms of the original files.
+//!
+//! [`Stage::new_with_includes`]: crate::shader::Stage::new_with_includes
+
+use crate::shader::diagnostic::{Diagnostic, ShaderLog};
+use std::{collections::HashMap, error, fmt, hash::BuildHasher};
+
+/// Resolve the path of included files to their source.
+///
+/// Paths are passed verbatim, as written in the `#include` directives. A [`HashMap`] from paths to sources can be used as
+/// a virtual filesystem, and closures can be used to look up files in any other way (e.g. on disk).
+pub trait IncludeResolver {
+  /// Get the source of the file at `path`, if any.
+  fn resolve(&mut self, path: &str) -> Option<String>;
+}
+
+impl<S, H> IncludeResolver for HashMap<String, S, H>
+where
+  S: AsRef<str>,
+  H: BuildHasher,
+{
+  fn resolve(&mut self, path: &str) -> Option<String> {
+    self.get(path).map(|src| src.as_ref().to_owned())
+  }
+}
+
+impl<F> IncludeResolver for F
+where
+  F: FnMut(&str) -> Option<String>,
+{
+  fn resolve(&mut self, path: &str) -> Option<String> {
+    self(path)
+  }
+}
+
+/// Errors that can occur while expanding `#include` directives.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IncludeError {
+  /// An `#include` directive is malformed.
+  Malformed {
+    /// File containing the directive.
+    file: String,
+    /// Line of the directive in that file.
+    line: usize,
+  },
+  /// An included file cannot be resolved.
+  NotFound {
+    /// Path of the included file.
+    path: String,
+    /// File containing the directive.
+    file: String,
+    /// Line of the directive in that file.
+    line: usize,
+  },
+  /// A file includes itself, directly or not.
+  ///
+  /// The chain of inclusions is provided, starting with the file included first and ending with the file included
+  /// again.
+  Cycle(Vec<String>),
+}
+
+impl IncludeError {
+  /// An `#include` directive is malformed.
+  pub fn malformed(file: impl Into<String>, line: usize) -> Self {
+    IncludeError::Malformed {
+      file: file.into(),
+      line,
+    }
+  }
+
+  /// An included file cannot be resolved.
+  pub fn not_found(path: impl Into<String>, file: impl Into<String>, line: usize) -> Self {
+    IncludeError::NotFound {
+      path: path.into(),
+      file: file.into(),
+      line,
+    }
+  }
+
+  /// A file includes itself, directly or not.
+  pub fn cycle(chain: impl Into<Vec<String>>) -> Self {
+    IncludeError::Cycle(chain.into())
+  }
+}
+
+impl fmt::Display for IncludeError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      IncludeError::Malformed { ref file, line } => {
+        write!(f, "malformed #include directive at {}:{}", file, line)
+      }
+
+      IncludeError::NotFound {
+        ref path,
+        ref file,
+        line,
+      } => write!(f, "cannot find included file {} at {}:{}", path, file, line),
+
+      IncludeError::Cycle(ref chain) => write!(f, "cyclic #include: {}", chain.join(" -> ")),
+    }
+  }
+}
+
+impl error::Error for IncludeError {}
+
+/// A shader source which `#include` directives were expanded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpandedSource {
+  source: String,
+  // names of the files the source is made of; the first one is the root file
+  files: Vec<String>,
+  // file index of each inclusion, along with the inclusion and line of the #include directive it comes from; the first
+  // inclusion is the root file
+  inclusions: Vec<(usize, Option<(usize, usize)>)>,
+  // inclusion and line (starting at 1) each line of the expanded source comes from
+  lines: Vec<(usize, usize)>,
+}
+
+impl ExpandedSource {
+  /// Expanded source.
+  pub fn source(&self) -> &str {
+    &self.source
+  }
+
+  /// Get the expanded source back.
+  pub fn into_source(self) -> String {
+    self.source
+  }
+
+  /// Find the file and line a line of the expanded source comes from.
+  ///
+  /// Lines start at 1. If `line` is out of the expanded source, [`None`] is returned.
+  pub fn origin(&self, line: usize) -> Option<(&str, usize)> {
+    let (inclusion, file_line) = *self.lines.get(line.checked_sub(1)?)?;
+    Some((&self.files[self.inclusions[inclusion].0], file_line))
+  }
+
+  /// Find the chain of `#include` directives a line of the expanded source comes from.
+  ///
+  /// Each directive is given as the file and line it is at, starting with the one in the root file. The chain is empty
+  /// for lines of the root file. If `line` is out of the expanded source, [`None`] is returned.
+  pub fn include_stack(&self, line: usize) -> Option<Vec<(&str, usize)>> {
+    let (mut inclusion, _) = *self.lines.get(line.checked_sub(1)?)?;
+    let mut stack = Vec::new();
+
+    while let Some((parent, directive_line)) = self.inclusions[inclusion].1 {
+      stack.push((
+        self.files[self.inclusions[parent].0].as_str(),
+        directive_line,
+      ));
+      inclusion = parent;
+    }
+
+    stack.reverse();
+    Some(stack)
+  }
+
+  /// Remap a [`Diagnostic`] about the expanded source to the file and line it comes from.
+  ///
+  /// Diagnostics without line, or which line is out of the expanded source, are left untouched.
+  pub fn remap(&self, diagnostic: &mut Diagnostic) {
+    let line = match diagnostic.line {
+      Some(line) => line,
+      None => return,
+    };
+
+    if let (Some((file, file_line)), Some(stack)) = (self.origin(line), self.include_stack(line)) {
+      diagnostic.file = Some(file.to_owned());
+      diagnostic.line = Some(file_line);
+      diagnostic.include_stack = stack
+        .into_iter()
+        .map(|(file, line)| (file.to_owned(), line))
+        .collect();
+    }
+  }
+
+  /// Remap all the [`Diagnostic`]s of a [`ShaderLog`] about the expanded source.
+  ///
+  /// See the documentation of [`ExpandedSource::remap`] for further details.
+  pub fn remap_log(&self, log: &mut ShaderLog) {
+    for diagnostic in log.diagnostics_mut() {
+      self.remap(diagnostic);
+    }
+  }
+
+  fn file_index(&mut self, name: &str) -> usize {
+    match self.files.iter().position(|file| file == name) {
+      Some(index) => index,
+
+      None => {
+        self.files.push(name.to_owned());
+        self.files.len() - 1
+      }
+    }
+  }
+
+  fn expand<R>(
+    &mut self,
+    name: &str,
+    src: &str,
+    resolver: &mut R,
+    stack: &mut Vec<String>,
+    directive: Option<(usize, usize)>,
+  ) -> Result<(), IncludeError>
+  where
+    R: ?Sized + IncludeResolver,
+  {
+    let file = self.file_index(name);
+    let inclusion = self.inclusions.len();
+    self.inclusions.push((file, directive));
+    stack.push(name.to_owned());
+
+    for (i, line) in src.lines().enumerate() {
+      let path = match parse_include(line) {
+        Some(Ok(path)) => path,
+        Some(Err(())) => return Err(IncludeError::malformed(name, i + 1)),
+
+        None => {
+          self.source.push_str(line);
+          self.source.push('\n');
+          self.lines.push((inclusion, i + 1));
+          continue;
+        }
+      };
+
+      if stack.iter().any(|included| included == path) {
+        let mut chain = stack.clone();
+        chain.push(path.to_owned());
+        return Err(IncludeError::cycle(chain));
+      }
+
+      let included_src = resolver
+        .resolve(path)
+        .ok_or_else(|| IncludeError::not_found(path, name, i + 1))?;
+
+      self.expand(
+        path,
+        &included_src,
+        resolver,
+        stack,
+        Some((inclusion, i + 1)),
+      )?;
+    }
+
+    stack.pop();
+    Ok(())
+  }
+}
+
+/// Expand the `#include` directives of a shader source.
+///
+/// `name` is the name of the file `src` comes from; it’s used to report errors and lines origins.
+pub fn expand_includes<R>(
+  name: &str,
+  src: &str,
+  resolver: &mut R,
+) -> Result<ExpandedSource, IncludeError>
+where
+  R: ?Sized + IncludeResolver,
+{
+  let mut expanded = ExpandedSource {
+    source: String::with_capacity(src.len()),
+    files: Vec::new(),
+    inclusions: Vec::new(),
+    lines: Vec::new(),
+  };
+
+  expanded.expand(name, src, resolver, &mut Vec::new(), None)?;
+  Ok(expanded)
+}
+
+/// Parse a line as an `#include` directive.
+///
+/// Return [`None`] if the line is not an `#include` directive, and the included path otherwise, if well-formed.
+fn parse_include(line: &str) -> Option<Result<&str, ()>> {
+  let directive = line.trim_start().strip_prefix('#')?.trim_start();
+  let rest = directive.strip_prefix("include")?;
+
+  // reject identifiers starting with include, such as #includes
+  if rest.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+    return None;
+  }
+
+  let rest = rest.trim_start();
+  let close = match rest.chars().next() {
+    Some('"') => '"',
+    Some('<') => '>',
+    _ => return Some(Err(())),
+  };
+
+  let rest = &rest[1..];
+  let path_len = match rest.find(close) {
+    Some(len) if len > 0 => len,
+    _ => return Some(Err(())),
+  };
+
+  // only a comment can follow the path
+  let trailing = rest[path_len + 1..].trim();
+  if !trailing.is_empty() && !trailing.starts_with("//") {
+    return Some(Err(()));
+  }
+
+  Some(Ok(&rest[..path_len]))
+}
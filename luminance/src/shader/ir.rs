@@ -0,0 +1,134 @@
+//! Shader translation from an intermediate representation.
+//!
+//! This module is available with the `naga` feature. It allows to write shader stages in another shading language —
+//! such as WGSL, or GLSL 4.50 as used with Vulkan — and to translate them at stage creation into the GLSL dialect the
+//! backend expects. Sources are parsed and validated once into a [`ShaderIr`], which can then be translated to any
+//! GLSL version with [`ShaderIr::translate`], or directly turned into a [`Stage`] with [`Stage::from_ir`].
+//!
+//! Translation has some limitations:
+//!
+//! - Only vertex, fragment and compute stages can be translated.
+//! - The translated stages might use different names for their inputs, outputs and uniforms than the original
+//!   sources; [naga] only preserves the names of plain (non-block) uniforms and of the entry point structure members
+//!   when it can.
+//!
+//! [`Stage`]: crate::shader::Stage
+//! [`Stage::from_ir`]: crate::shader::Stage::from_ir
+//! [naga]: https://crates.io/crates/naga
+
+use crate::shader::{GlslVersion, StageError, StageType};
+use naga::{
+  back::glsl,
+  front::{glsl as glsl_in, wgsl},
+  proc::BoundsCheckPolicies,
+  valid::{Capabilities, ModuleInfo, ValidationFlags, Validator},
+  Module, ShaderStage,
+};
+
+/// A validated shader module, ready to be translated.
+///
+/// A single module can contain several entry points — e.g. a vertex and a fragment one in a WGSL source.
+#[derive(Debug)]
+pub struct ShaderIr {
+  module: Module,
+  info: ModuleInfo,
+}
+
+impl ShaderIr {
+  /// Validate a [naga] module.
+  ///
+  /// [naga]: https://crates.io/crates/naga
+  pub fn from_module(module: Module) -> Result<Self, StageError> {
+    let info = Validator::new(ValidationFlags::all(), Capabilities::all())
+      .validate(&module)
+      .map_err(|e| StageError::translation_failed(e.into_inner().to_string()))?;
+
+    Ok(ShaderIr { module, info })
+  }
+
+  /// Parse and validate a WGSL source.
+  pub fn from_wgsl(src: &str) -> Result<Self, StageError> {
+    let module =
+      wgsl::parse_str(src).map_err(|e| StageError::translation_failed(e.emit_to_string(src)))?;
+
+    Self::from_module(module)
+  }
+
+  /// Parse and validate a GLSL source of type `ty`.
+  ///
+  /// The source must start with a `#version` directive, and is expected to be written in the GLSL dialect used with
+  /// Vulkan (version 4.40 or higher).
+  pub fn from_glsl(ty: StageType, src: &str) -> Result<Self, StageError> {
+    let stage = naga_stage(ty)?;
+    let module = glsl_in::Frontend::default()
+      .parse(&glsl_in::Options::from(stage), src)
+      .map_err(|errors| {
+        let messages = errors
+          .iter()
+          .map(|e| e.to_string())
+          .collect::<Vec<_>>()
+          .join("\n");
+
+        StageError::translation_failed(messages)
+      })?;
+
+    Self::from_module(module)
+  }
+
+  /// Underlying [naga] module.
+  ///
+  /// [naga]: https://crates.io/crates/naga
+  pub fn module(&self) -> &Module {
+    &self.module
+  }
+
+  /// Translate the `entry_point` function of type `ty` into GLSL.
+  ///
+  /// The translated source starts with a `#version` directive declaring `version`.
+  pub fn translate(
+    &self,
+    ty: StageType,
+    entry_point: &str,
+    version: GlslVersion,
+  ) -> Result<String, StageError> {
+    let options = glsl::Options {
+      version: if version.es {
+        glsl::Version::new_gles(version.version)
+      } else {
+        glsl::Version::Desktop(version.version)
+      },
+      ..Default::default()
+    };
+    let pipeline_options = glsl::PipelineOptions {
+      shader_stage: naga_stage(ty)?,
+      entry_point: entry_point.to_owned(),
+      multiview: None,
+    };
+
+    let mut src = String::new();
+    glsl::Writer::new(
+      &mut src,
+      &self.module,
+      &self.info,
+      &options,
+      &pipeline_options,
+      BoundsCheckPolicies::default(),
+    )
+    .and_then(|mut writer| writer.write())
+    .map_err(|e| StageError::translation_failed(e.to_string()))?;
+
+    Ok(src)
+  }
+}
+
+fn naga_stage(ty: StageType) -> Result<ShaderStage, StageError> {
+  match ty {
+    StageType::VertexShader => Ok(ShaderStage::Vertex),
+    StageType::FragmentShader => Ok(ShaderStage::Fragment),
+    StageType::ComputeShader => Ok(ShaderStage::Compute),
+    _ => Err(StageError::translation_failed(format!(
+      "{} cannot be translated",
+      ty
+    ))),
+  }
+}
@@ -236,3 +236,40 @@ macro_rules! matrix {
 matrix!(Mat22, 2, 2);
 matrix!(Mat33, 3, 3);
 matrix!(Mat44, 4, 4);
+
+// non-square matrices follow the GLSL matCxR naming: the outer array holds the C columns, each of R rows
+matrix!(Mat23, 2, 3);
+matrix!(Mat32, 3, 2);
+matrix!(Mat24, 2, 4);
+matrix!(Mat42, 4, 2);
+matrix!(Mat34, 3, 4);
+matrix!(Mat43, 4, 3);
+
+/// A matrix uploaded as its transpose.
+///
+/// Matrices are expected to be column-major (i.e. `Mat44::new([col0, col1, col2, col3])`). Wrapping a row-major
+/// matrix into [`Transposed`] allows to pass it as-is: the backend transposes it while uploading it. Because the
+/// transpose of a `C×R` matrix is a `R×C` matrix, `Transposed<Mat32<f32>>` maps to a `mat2x3` in shaders.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Transposed<M>(pub M);
+
+impl<M> From<M> for Transposed<M> {
+  fn from(m: M) -> Self {
+    Transposed(m)
+  }
+}
+
+impl<M> Deref for Transposed<M> {
+  type Target = M;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<M> DerefMut for Transposed<M> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
@@ -5,9 +5,11 @@
 //! [`Program`]: crate::shader::Program
 
 use crate::{
-  backend::shading_gate::ShadingGate as ShadingGateBackend,
+  backend::shading_gate::{
+    ShadingGate as ShadingGateBackend, ShadingGateCompute, ShadingGateSeparable,
+  },
   render_gate::RenderGate,
-  shader::{Program, ProgramInterface, UniformInterface},
+  shader::{ComputeProgram, Program, ProgramInterface, ProgramPipeline, UniformInterface},
   vertex::Semantics,
 };
 
@@ -58,4 +60,75 @@ where
 
     f(program_interface, &program.uni, render_gate)
   }
+
+  /// Enter a [`ShadingGate`] by using a [`ProgramPipeline`].
+  ///
+  /// Contrary to [`ShadingGate::shade`], no [`ProgramInterface`] is given to the argument closure, because a
+  /// [`ProgramPipeline`] has no uniforms on its own: the uniforms of its programs are updated with [`Program::update`].
+  pub fn shade_pipeline<E, F>(&mut self, pipeline: &ProgramPipeline<B>, f: F) -> Result<(), E>
+  where
+    B: ShadingGateSeparable,
+    F: for<'b> FnOnce(RenderGate<'b, B>) -> Result<(), E>,
+  {
+    unsafe {
+      self.backend.apply_program_pipeline(&pipeline.repr);
+    }
+
+    let render_gate = RenderGate {
+      backend: self.backend,
+    };
+
+    f(render_gate)
+  }
+
+  /// Enter a [`ComputeGate`] by using a [`ComputeProgram`].
+  ///
+  /// The argument closure is given two arguments:
+  ///
+  /// - A [`ProgramInterface`], that allows to pass values (via [`ProgramInterface::set`]) to the
+  ///   in-use [`ComputeProgram`] and/or perform dynamic lookup of uniforms.
+  /// - A [`ComputeGate`], allowing to dispatch the [`ComputeProgram`].
+  pub fn compute<E, Uni, F>(&mut self, program: &mut ComputeProgram<B, Uni>, f: F) -> Result<(), E>
+  where
+    B: ShadingGateCompute,
+    Uni: UniformInterface<B>,
+    F: for<'b> FnOnce(ProgramInterface<'b, B>, &'b Uni, ComputeGate<'b, B>) -> Result<(), E>,
+  {
+    unsafe {
+      self.backend.apply_shader_program(&program.repr);
+    }
+
+    let compute_gate = ComputeGate {
+      backend: self.backend,
+    };
+    let program_interface = ProgramInterface {
+      program: &mut program.repr,
+    };
+
+    f(program_interface, &program.uni, compute_gate)
+  }
+}
+
+/// A compute gate.
+///
+/// This is obtained after entering a [`ShadingGate`] with a [`ComputeProgram`].
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+pub struct ComputeGate<'a, B> {
+  pub(crate) backend: &'a mut B,
+}
+
+impl<'a, B> ComputeGate<'a, B>
+where
+  B: ShadingGateCompute,
+{
+  /// Dispatch the [`ComputeProgram`] over a grid of `x * y * z` work groups.
+  ///
+  /// The size of a work group is declared in the compute stage (i.e. `layout(local_size_x = 64) in;`). Writes
+  /// performed by the [`ComputeProgram`] are visible to the next dispatches, renders and reads.
+  pub fn dispatch(&mut self, x: u32, y: u32, z: u32) {
+    unsafe { self.backend.dispatch(x, y, z) }
+  }
 }
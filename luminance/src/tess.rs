@@ -27,12 +27,39 @@
 //!
 //! - _Attributeless_: when you only specify the [`Mode`] and number of vertices to render (and
 //!   optionally the number of instances). That will create a vertex set with no vertex data. Your
-//!   vertex shader will be responsible for creating the vertex attributes on the fly.
+//!   vertex shader will be responsible for creating the vertex attributes on the fly. The
+//!   [`Tess::attributeless`] shortcut creates such a [`Tess`] without a [`TessBuilder`].
 //! - _Direct geometry_: when you pass vertices directly.
-//! - _Indexed geometry_: when you pass vertices and reference from with indices.
+//! - _Indexed geometry_: when you pass vertices and reference from with indices. Indices are [`u8`], [`u16`] or
+//!   [`u32`] — see [`TessIndex`].
 //! - _Instanced geometry_: when you ask to use instances, making the graphics pipeline create
 //!   several instances of your vertex set on the GPU.
 //!
+//! # Memory layouts
+//!
+//! The vertices of a [`Tess`] are stored either _interleaved_ — [`Interleaved`], the default — or _deinterleaved_ —
+//! [`Deinterleaved`]. Interleaved vertices are stored in a single GPU buffer, with all the attributes of a vertex next
+//! to each other. Deinterleaved vertices use a GPU buffer per attribute instead: such a [`Tess`] is created with
+//! [`GraphicsContext::new_deinterleaved_tess`], and each attribute is set with [`TessBuilder::set_attributes`].
+//!
+//! Each attribute of a deinterleaved [`Tess`] is then mapped on its own with [`Tess::vertices_mut`], which leaves
+//! the buffers of the other attributes untouched. That makes deinterleaved memory a good fit when only some of the
+//! attributes change, such as positions streamed every frame with static normals and UVs:
+//!
+//! ```ignore
+//! let mut tess = ctx
+//!   .new_deinterleaved_tess::<Vertex, ()>()
+//!   .set_attributes(positions)
+//!   .set_attributes(normals)
+//!   .set_mode(Mode::Triangle)
+//!   .build()?;
+//!
+//! // every frame, only upload the new positions
+//! tess
+//!   .vertices_mut::<VertexPosition>()?
+//!   .copy_from_slice(&new_positions);
+//! ```
+//!
 //! # Tessellation views
 //!
 //! Once you have a [`Tess`] — created from [`TessBuilder::build`], you can now render it in a
@@ -49,6 +76,35 @@
 //! [`View::view`] and [`View::inst_view`] (for instanced rendering) methods, which accept Rust’s
 //! range operators to create the [`TessView`]s in a more comfortable way.
 //!
+//! Views are also how several meshes share a single [`Tess`]: their vertices (and indices) are packed in the same
+//! buffers, and each mesh is rendered with a view of its own range, without creating a GPU object per mesh:
+//!
+//! ```ignore
+//! // the first mesh uses the 36 first vertices (or indices), the second one the 24 next ones
+//! tess_gate.render(tess.view(0..36)?)?;
+//! tess_gate.render(tess.view(36..60)?)?;
+//! ```
+//!
+//! With indexed tessellations, ranges select indices; if each mesh’s indices start at zero,
+//! [`TessGate::render_base_vertex`] adds the offset of the mesh’s first vertex to them.
+//!
+//! # Instanced rendering
+//!
+//! Instanced rendering draws the vertices of a [`Tess`] several times in a single draw call. Per-instance data, such as
+//! transforms, are passed with [`TessBuilder::set_instances`] (or [`TessBuilder::set_instance_attributes`] with
+//! deinterleaved memory), as a type implementing [`Vertex`] whose attributes use [`VertexInstancing::On`] — with the
+//! derive proc-macro, `#[vertex(instanced = "true")]`. Each instance then reads its own value of these attributes. With
+//! [`VertexInstancing::Divisor`] — `#[vertex(divisor = "<n>")]` — a value is shared by `n` consecutive instances.
+//!
+//! By default, a [`Tess`] renders as many instances as there are instance values, multiplied by their divisor, or the
+//! number set with [`TessBuilder::set_render_instance_nb`]. Another number of instances is rendered with a
+//! [`TessView`] created with [`View::inst_view`] or [`TessView::inst_whole`]:
+//!
+//! ```ignore
+//! // render 100 instances of the whole tessellation
+//! tess_gate.render(tess.inst_view(.., 100)?)?;
+//! ```
+//!
 //! # Tessellation mapping
 //!
 //! Sometimes, you will want to edit tessellations in a dynamic way instead of re-creating new
@@ -60,16 +116,28 @@
 //! - [`Tess::indices`] [`Tess::indices_mut`] to map tessellations’ indices.
 //! - [`Tess::instances`] [`Tess::instances_mut`] to map tessellations’ instances.
 //!
+//! The returned objects dereference to slices of the mapped storage, so that the [`Tess`] is read
+//! back or edited in place, without re-creating it. The storage is unmapped when they are dropped;
+//! as they borrow the [`Tess`], it cannot be rendered while mapped.
+//!
+//! If you only need to overwrite a range of vertices, [`Tess::upload_vertices`] copies them to the
+//! GPU directly, without mapping the whole buffer.
+//!
 //! > Note: because of their slice nature, mapping a tessellation (vertices, indices or instances)
 //! > will not help you with resizing a [`Tess`], as this is not currently supported. Creating a large
 //! > enough [`Tess`] is preferable for now.
 //!
 //! [`TessGate`]: crate::tess_gate::TessGate
+//! [`TessGate::render_base_vertex`]: crate::tess_gate::TessGate::render_base_vertex
+//! [`GraphicsContext::new_deinterleaved_tess`]: crate::context::GraphicsContext::new_deinterleaved_tess
+//! [`VertexInstancing::On`]: crate::vertex::VertexInstancing::On
+//! [`VertexInstancing::Divisor`]: crate::vertex::VertexInstancing::Divisor
 
 use crate::{
   backend::tess::{
     IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-    VertexSlice as VertexSliceBackend,
+    TessPatchLevels as TessPatchLevelsBackend, VertexSlice as VertexSliceBackend,
+    VertexUpload as VertexUploadBackend,
   },
   context::GraphicsContext,
   vertex::{Deinterleave, Vertex, VertexDesc},
@@ -100,6 +168,9 @@ use std::{
 /// restart index_ with [`TessBuilder::set_primitive_restart_index`]. Whenever a vertex index is set
 /// to the same value as the _primitive restart index_, the value is not interpreted as a vertex
 /// index but just a marker / hint to start a new primitive.
+///
+/// Prefer [`TessBuilder::enable_fixed_primitive_restart`], which uses the maximum value of the index
+/// type as primitive restart index: it is portable across backends and will remain supported.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Mode {
   /// A single point.
@@ -148,7 +219,11 @@ pub enum Mode {
   /// For example, `Mode::Patch(3)` represents triangle patches, so every three vertices in the
   /// buffer form a patch.
   ///
-  /// If you want to employ tessellation shaders, this is the only primitive mode you can use.
+  /// If you want to employ tessellation shaders, this is the only primitive mode you can use. The number of vertices
+  /// per patch is set right before rendering. Conversely, patches can only be consumed by programs with tessellation
+  /// stages, which [`TessGate::render_validated`] checks.
+  ///
+  /// [`TessGate::render_validated`]: crate::tess_gate::TessGate::render_validated
   Patch(usize),
 }
 
@@ -166,6 +241,35 @@ impl fmt::Display for Mode {
   }
 }
 
+/// Default tessellation levels of patches.
+///
+/// When a [`Program`] has a tessellation evaluation stage but no tessellation control stage, patches are tessellated
+/// with those levels, which are otherwise computed by the tessellation control stage (`gl_TessLevelOuter` and
+/// `gl_TessLevelInner`). They are only relevant with [`Mode::Patch`].
+///
+/// [`Program`]: crate::shader::Program
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TessLevels {
+  /// Outer tessellation levels.
+  pub outer: [f32; 4],
+  /// Inner tessellation levels.
+  pub inner: [f32; 2],
+}
+
+impl TessLevels {
+  /// Create new [`TessLevels`].
+  pub fn new(outer: [f32; 4], inner: [f32; 2]) -> Self {
+    TessLevels { outer, inner }
+  }
+}
+
+impl Default for TessLevels {
+  /// All levels are set to `1.`, which doesn’t subdivide patches.
+  fn default() -> Self {
+    TessLevels::new([1.; 4], [1.; 2])
+  }
+}
+
 /// Error that can occur while trying to map GPU tessellations to host code.
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
@@ -257,6 +361,31 @@ pub enum TessError {
   ForbiddenPrimitiveMode(Mode),
   /// No data provided and empty tessellation.
   NoData,
+  /// Uploaded data doesn’t fit in a buffer.
+  OutOfBounds {
+    /// Index of the first uploaded item.
+    offset: usize,
+    /// Number of uploaded items.
+    len: usize,
+    /// Number of items of the buffer.
+    capacity: usize,
+  },
+  /// The tessellation is not indexed, while the render requires indices.
+  NotIndexed,
+  /// An index refers to a vertex that doesn’t exist.
+  IndexOutOfBounds {
+    /// Out of bounds index.
+    index: usize,
+    /// Number of vertices.
+    vert_nb: usize,
+  },
+  /// The number of vertices per patch is zero, or doesn’t divide the number of vertices to render.
+  InvalidPatchSize {
+    /// Number of vertices per patch.
+    patch_size: usize,
+    /// Number of vertices to render.
+    vert_nb: usize,
+  },
 }
 
 impl TessError {
@@ -284,6 +413,33 @@ impl TessError {
   pub fn no_data() -> Self {
     TessError::NoData
   }
+
+  /// Uploaded data doesn’t fit in a buffer.
+  pub fn out_of_bounds(offset: usize, len: usize, capacity: usize) -> Self {
+    TessError::OutOfBounds {
+      offset,
+      len,
+      capacity,
+    }
+  }
+
+  /// The tessellation is not indexed, while the render requires indices.
+  pub fn not_indexed() -> Self {
+    TessError::NotIndexed
+  }
+
+  /// An index refers to a vertex that doesn’t exist.
+  pub fn index_out_of_bounds(index: usize, vert_nb: usize) -> Self {
+    TessError::IndexOutOfBounds { index, vert_nb }
+  }
+
+  /// The number of vertices per patch is zero, or doesn’t divide the number of vertices to render.
+  pub fn invalid_patch_size(patch_size: usize, vert_nb: usize) -> Self {
+    TessError::InvalidPatchSize {
+      patch_size,
+      vert_nb,
+    }
+  }
 }
 
 impl fmt::Display for TessError {
@@ -296,6 +452,31 @@ impl fmt::Display for TessError {
       }
       TessError::ForbiddenPrimitiveMode(ref e) => write!(f, "forbidden primitive mode: {}", e),
       TessError::NoData => f.write_str("no data or empty tessellation"),
+      TessError::OutOfBounds {
+        offset,
+        len,
+        capacity,
+      } => write!(
+        f,
+        "cannot upload {} items at offset {} to a buffer of {} items",
+        len, offset, capacity
+      ),
+      TessError::NotIndexed => f.write_str("tessellation is not indexed"),
+      TessError::IndexOutOfBounds { index, vert_nb } => {
+        write!(
+          f,
+          "index {} is out of bounds of {} vertices",
+          index, vert_nb
+        )
+      }
+      TessError::InvalidPatchSize {
+        patch_size,
+        vert_nb,
+      } => write!(
+        f,
+        "cannot render {} vertices as patches of {} vertices",
+        vert_nb, patch_size
+      ),
     }
   }
 }
@@ -329,11 +510,11 @@ impl TessIndexType {
 /// Values which types implement this trait are allowed to be used to index tessellation in *indexed
 /// draw commands*.
 ///
-/// You shouldn’t have to worry too much about that trait. Have a look at the current implementors
-/// for an exhaustive list of types you can use.
-///
-/// > Implementing this trait is `unsafe`.
-pub unsafe trait TessIndex: Copy {
+/// The implementors are [`u8`], [`u16`] and [`u32`], matching the variants of [`TessIndexType`], and `()` to disable
+/// indexing. Smaller index types use less memory and bandwidth: a [`Tess`] with at most 256 vertices can use [`u8`]
+/// indices, and one with at most 65536 vertices [`u16`] indices. This trait is sealed, so it cannot be implemented
+/// outside of luminance.
+pub unsafe trait TessIndex: Copy + sealed::Sealed {
   /// Type of the underlying index.
   ///
   /// You are limited in which types you can use as indexes. Feel free to have a look at the
@@ -342,45 +523,65 @@ pub unsafe trait TessIndex: Copy {
   /// `None` means that you disable indexing.
   const INDEX_TYPE: Option<TessIndexType>;
 
+  /// Primitive restart index of the fixed-index variant of _primitive restart_.
+  ///
+  /// This is the maximum value of the index type. See [`TessBuilder::enable_fixed_primitive_restart`].
+  const FIXED_RESTART_INDEX: Self;
+
   /// Get and convert the index to [`u32`], if possible.
   fn try_into_u32(self) -> Option<u32>;
 }
 
 unsafe impl TessIndex for () {
   const INDEX_TYPE: Option<TessIndexType> = None;
+  const FIXED_RESTART_INDEX: Self = ();
 
   fn try_into_u32(self) -> Option<u32> {
     None
   }
 }
 
-/// Boop.
+/// 8-bit indices.
 unsafe impl TessIndex for u8 {
   const INDEX_TYPE: Option<TessIndexType> = Some(TessIndexType::U8);
+  const FIXED_RESTART_INDEX: Self = u8::MAX;
 
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
   }
 }
 
-/// Boop.
+/// 16-bit indices.
 unsafe impl TessIndex for u16 {
   const INDEX_TYPE: Option<TessIndexType> = Some(TessIndexType::U16);
+  const FIXED_RESTART_INDEX: Self = u16::MAX;
 
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
   }
 }
 
-/// Wuuuuuuha.
+/// 32-bit indices.
 unsafe impl TessIndex for u32 {
   const INDEX_TYPE: Option<TessIndexType> = Some(TessIndexType::U32);
+  const FIXED_RESTART_INDEX: Self = u32::MAX;
 
   fn try_into_u32(self) -> Option<u32> {
     Some(self.into())
   }
 }
 
+mod sealed {
+  /// Prevent [`TessIndex`](super::TessIndex) from being implemented outside of luminance, as backends only support
+  /// the [`TessIndexType`](super::TessIndexType) variants.
+  pub trait Sealed {}
+
+  impl Sealed for () {}
+  impl Sealed for u8 {}
+  impl Sealed for u16 {}
+  impl Sealed for u32 {}
+}
+
 /// Interleaved memory marker.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Interleaved {}
@@ -601,6 +802,16 @@ where
     self.restart_index = Some(restart_index);
     self
   }
+
+  /// Enable the fixed-index variant of _primitive restart_, using the maximum value of the index type
+  /// ([`TessIndex::FIXED_RESTART_INDEX`]) as primitive restart index.
+  ///
+  /// This is the only variant supported by some backends, such as WebGL2, and backends supporting it natively don’t
+  /// have to set the primitive restart index on every render. It replaces any index previously set with
+  /// [`TessBuilder::set_primitive_restart_index`].
+  pub fn enable_fixed_primitive_restart(self) -> Self {
+    self.set_primitive_restart_index(I::FIXED_RESTART_INDEX)
+  }
 }
 
 impl<'a, B, V, I, W, S> TessBuilder<'a, B, V, I, W, S>
@@ -730,9 +941,10 @@ where
   I: TessIndex,
   W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
 {
-  /// Add vertices to be bundled in the [`Tess`].
+  /// Add the values of the attribute `A` of the vertices to be bundled in the [`Tess`].
   ///
-  /// Every time you call that function, the set of vertices is replaced by the one you provided.
+  /// Each attribute is stored in its own GPU buffer. Every time you call that function for a given attribute, its
+  /// values are replaced by the ones you provided.
   pub fn set_attributes<A, X>(mut self, attributes: X) -> Self
   where
     X: Into<Vec<A>>,
@@ -820,10 +1032,22 @@ where
   ///   and/or [`TessBuilder::set_instances`], do not forget that you must submit sets with the
   ///   same size. Otherwise, the GPU will not know what values use for missing attributes in
   ///   vertices.
+  ///
+  /// Besides incoherent lengths, [`TessError::IndexOutOfBounds`] is returned if an index — other
+  /// than the primitive restart index — refers to a vertex that doesn’t exist, and
+  /// [`TessError::InvalidPatchSize`] if the mode is [`Mode::Patch`] with a number of vertices per
+  /// patch that is zero or doesn’t divide the number of vertices to render.
   pub fn build(self) -> Result<Tess<B, V, I, W, S>, TessError> {
     // validate input data before giving it to the backend
     let render_vert_nb = self.guess_render_vertex_len()?;
     let render_inst_nb = self.guess_render_instance_len()?;
+    self.validate_indices()?;
+
+    if let Mode::Patch(patch_size) = self.mode {
+      if patch_size == 0 || render_vert_nb % patch_size != 0 {
+        return Err(TessError::invalid_patch_size(patch_size, render_vert_nb));
+      }
+    }
 
     unsafe {
       self
@@ -839,6 +1063,7 @@ where
           repr,
           render_vert_nb,
           render_inst_nb,
+          mode: self.mode,
           _phantom: PhantomData,
         })
     }
@@ -886,11 +1111,41 @@ where
     }
   }
 
+  /// Check that indices refer to existing vertices.
+  fn validate_indices(&self) -> Result<(), TessError> {
+    // attributeless indexed renders have no vertices to check against
+    let vert_nb = match self.vertex_data {
+      Some(ref data) => V::coherent_len(data)?,
+      None => return Ok(()),
+    };
+    let restart_index = self.restart_index.and_then(I::try_into_u32);
+
+    let out_of_bounds = self
+      .index_data
+      .iter()
+      .filter_map(|index| index.try_into_u32())
+      .filter(|&index| Some(index) != restart_index)
+      .find(|&index| index as usize >= vert_nb);
+
+    match out_of_bounds {
+      Some(index) => Err(TessError::index_out_of_bounds(index as usize, vert_nb)),
+      None => Ok(()),
+    }
+  }
+
   fn guess_render_instance_len(&self) -> Result<usize, TessError> {
+    // each instance attribute value is shared by as many instances as its divisor
+    let divisor = W::vertex_desc()
+      .iter()
+      .map(|desc| desc.instancing.divisor() as usize)
+      .filter(|&divisor| divisor > 0)
+      .min()
+      .unwrap_or(1);
+
     // as with vertex length, we first check for an explicit number, and if none, we deduce it
     if self.render_inst_nb == 0 {
       match self.instance_data {
-        Some(ref data) => W::coherent_len(data),
+        Some(ref data) => W::coherent_len(data).map(|len| len * divisor),
         None => Ok(0),
       }
     } else {
@@ -898,7 +1153,8 @@ where
         .instance_data
         .as_ref()
         .ok_or_else(|| TessError::attributeless_error("missing number of instances"))
-        .and_then(W::coherent_len)?;
+        .and_then(W::coherent_len)?
+        * divisor;
 
       if self.render_inst_nb <= coherent_len {
         Ok(self.render_inst_nb)
@@ -940,9 +1196,34 @@ where
   // default number of instances to render
   render_inst_nb: usize,
 
+  // primitive mode
+  pub(crate) mode: Mode,
+
   _phantom: PhantomData<*const S>,
 }
 
+impl<B> Tess<B, ()>
+where
+  B: ?Sized + TessBackend<(), (), (), Interleaved>,
+{
+  /// Create an attributeless [`Tess`], rendering `vert_nb` vertices connected with `mode`.
+  ///
+  /// No vertex data is sent to the GPU: the vertex shader is responsible for generating the vertices, typically from
+  /// `gl_VertexID`. That is useful for full-screen triangles or procedural geometry. This is a shortcut for creating
+  /// the [`Tess`] with a [`TessBuilder`], setting only its [`Mode`] and number of vertices to render.
+  ///
+  /// [`TessError::NoData`] is returned if `vert_nb` is zero.
+  pub fn attributeless<C>(ctx: &mut C, mode: Mode, vert_nb: usize) -> Result<Self, TessError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    TessBuilder::new(ctx)
+      .set_mode(mode)
+      .set_render_vertex_nb(vert_nb)
+      .build()
+  }
+}
+
 impl<B, V, I, W, S> Tess<B, V, I, W, S>
 where
   B: ?Sized + TessBackend<V, I, W, S>,
@@ -966,6 +1247,11 @@ where
     unsafe { B::tess_instances_nb(&self.repr) }
   }
 
+  /// Primitive mode.
+  pub fn mode(&self) -> Mode {
+    self.mode
+  }
+
   /// Default number of vertices to render.
   ///
   /// This number represents the number of vertices that will be rendered when not explicitly asked to render a given
@@ -982,6 +1268,29 @@ where
     self.render_inst_nb
   }
 
+  /// Default tessellation levels of the patches.
+  ///
+  /// See the documentation of [`TessLevels`] for further details.
+  pub fn default_tess_levels(&self) -> TessLevels
+  where
+    B: TessPatchLevelsBackend<V, I, W, S>,
+  {
+    unsafe { B::default_tess_levels(&self.repr) }
+  }
+
+  /// Set the default tessellation levels of the patches.
+  ///
+  /// Those levels are used when rendering the [`Tess`] with a [`Program`] without tessellation control stage. They are
+  /// ignored if the primitive mode is not [`Mode::Patch`]; the number of vertices per patch is set with the mode itself.
+  ///
+  /// [`Program`]: crate::shader::Program
+  pub fn set_default_tess_levels(&mut self, levels: TessLevels)
+  where
+    B: TessPatchLevelsBackend<V, I, W, S>,
+  {
+    unsafe { B::set_default_tess_levels(&mut self.repr, levels) }
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _index storage_.
@@ -992,9 +1301,12 @@ where
     unsafe { B::indices(&mut self.repr).map(|repr| Indices { repr }) }
   }
 
-  /// Slice the [`Tess`] in order to read its content via usual slices.
+  /// Slice the [`Tess`] in order to write its content via usual slices.
   ///
-  /// This method gives access to the underlying _index storage_.
+  /// This method gives access to the underlying _index storage_, mapped in memory. The written indices are visible
+  /// to the GPU once the returned [`IndicesMut`] is dropped, which unmaps the storage.
+  ///
+  /// [`TessMapError::CannotMap`] is returned if the backend cannot map the storage.
   pub fn indices_mut<'a>(&'a mut self) -> Result<IndicesMut<'a, B, V, I, W, S>, TessMapError>
   where
     B: IndexSliceBackend<'a, V, I, W, S>,
@@ -1022,9 +1334,14 @@ where
     unsafe { B::vertices(&mut self.repr).map(|repr| Vertices { repr }) }
   }
 
-  /// Slice the [`Tess`] in order to read its content via usual slices.
+  /// Slice the [`Tess`] in order to write its content via usual slices.
   ///
-  /// This method gives access to the underlying _vertex storage_.
+  /// This method gives access to the underlying _vertex storage_, mapped in memory. The written vertices are
+  /// visible to the GPU once the returned [`VerticesMut`] is dropped, which unmaps the storage. To update only a few
+  /// vertices, [`Tess::upload_vertices`] is usually cheaper.
+  ///
+  /// [`TessMapError::ForbiddenAttributelessMapping`] is returned if the [`Tess`] is attributeless, and
+  /// [`TessMapError::CannotMap`] if the backend cannot map the storage.
   pub fn vertices_mut<'a>(
     &'a mut self,
   ) -> Result<VerticesMut<'a, B, V, I, W, Interleaved, V>, TessMapError>
@@ -1034,6 +1351,25 @@ where
     unsafe { B::vertices_mut(&mut self.repr).map(|repr| VerticesMut { repr }) }
   }
 
+  /// Upload `vertices` to the vertex storage, starting at the vertex at index `offset`.
+  ///
+  /// Only the uploaded vertices are sent to the GPU, and the other vertices are left untouched. This is the cheapest
+  /// way to update dynamic geometry, such as UI, particles or debug lines, every frame without re-creating the
+  /// [`Tess`].
+  ///
+  /// [`TessError::OutOfBounds`] is returned if the vertices don’t fit in the vertex storage, and
+  /// [`TessError::AttributelessError`] if the [`Tess`] is attributeless.
+  pub fn upload_vertices(&mut self, offset: usize, vertices: &[V]) -> Result<(), TessError>
+  where
+    B: VertexUploadBackend<V, I, W, Interleaved, V>,
+  {
+    if vertices.is_empty() {
+      return Ok(());
+    }
+
+    unsafe { B::upload_vertices(&mut self.repr, offset, vertices) }
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _instance storage_.
@@ -1046,9 +1382,10 @@ where
     unsafe { B::instances(&mut self.repr).map(|repr| Instances { repr }) }
   }
 
-  /// Slice the [`Tess`] in order to read its content via usual slices.
+  /// Slice the [`Tess`] in order to write its content via usual slices.
   ///
-  /// This method gives access to the underlying _instance storage_.
+  /// This method gives access to the underlying _instance storage_, mapped in memory. The written instances are
+  /// visible to the GPU once the returned [`InstancesMut`] is dropped, which unmaps the storage.
   pub fn instances_mut<'a>(
     &'a mut self,
   ) -> Result<InstancesMut<'a, B, V, I, W, Interleaved, W>, TessMapError>
@@ -1066,9 +1403,9 @@ where
   I: TessIndex,
   W: TessVertexData<Deinterleaved>,
 {
-  /// Slice the [`Tess`] in order to read its content via usual slices.
+  /// Slice the [`Tess`] in order to read the values of the attribute `T` via usual slices.
   ///
-  /// This method gives access to the underlying _vertex storage_.
+  /// This method gives access to the underlying _vertex storage_ of the attribute.
   pub fn vertices<'a, T>(
     &'a mut self,
   ) -> Result<Vertices<'a, B, V, I, W, Deinterleaved, T>, TessMapError>
@@ -1079,9 +1416,10 @@ where
     unsafe { B::vertices(&mut self.repr).map(|repr| Vertices { repr }) }
   }
 
-  /// Slice the [`Tess`] in order to read its content via usual slices.
+  /// Slice the [`Tess`] in order to write the values of the attribute `T` via usual slices.
   ///
-  /// This method gives access to the underlying _vertex storage_.
+  /// This method gives access to the underlying _vertex storage_ of the attribute; the other attributes are not
+  /// affected.
   pub fn vertices_mut<'a, T>(
     &'a mut self,
   ) -> Result<VerticesMut<'a, B, V, I, W, Deinterleaved, T>, TessMapError>
@@ -1092,6 +1430,26 @@ where
     unsafe { B::vertices_mut(&mut self.repr).map(|repr| VerticesMut { repr }) }
   }
 
+  /// Upload the values of the attribute `T` of `vertices` to the vertex storage, starting at the vertex at index
+  /// `offset`.
+  ///
+  /// Only the uploaded values are sent to the GPU, and the other attributes are left untouched.
+  ///
+  /// [`TessError::OutOfBounds`] is returned if the values don’t fit in the vertex storage, and
+  /// [`TessError::AttributelessError`] if the [`Tess`] is attributeless.
+  pub fn upload_vertices<T>(&mut self, offset: usize, vertices: &[T]) -> Result<(), TessError>
+  where
+    B: VertexUploadBackend<V, I, W, Deinterleaved, T>,
+    V: Deinterleave<T>,
+    T: Copy,
+  {
+    if vertices.is_empty() {
+      return Ok(());
+    }
+
+    unsafe { B::upload_vertices(&mut self.repr, offset, vertices) }
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _instance storage_.
@@ -1105,9 +1463,10 @@ where
     unsafe { B::instances(&mut self.repr).map(|repr| Instances { repr }) }
   }
 
-  /// Slice the [`Tess`] in order to read its content via usual slices.
+  /// Slice the [`Tess`] in order to write the values of the attribute `T` via usual slices.
   ///
-  /// This method gives access to the underlying _instance storage_.
+  /// This method gives access to the underlying _instance storage_ of the attribute, mapped in memory. The
+  /// written values are visible to the GPU once the returned [`InstancesMut`] is dropped, which unmaps the storage.
   pub fn instances_mut<'a, T>(
     &'a mut self,
   ) -> Result<InstancesMut<'a, B, V, I, W, Deinterleaved, T>, TessMapError>
@@ -1119,7 +1478,9 @@ where
   }
 }
 
-/// TODO
+/// Vertices of a [`Tess`] mapped in memory for reading.
+///
+/// With [`Deinterleaved`] memory, only the values of the attribute `T` are mapped.
 #[derive(Debug)]
 pub struct Vertices<'a, B, V, I, W, S, T>
 where
@@ -1147,7 +1508,10 @@ where
   }
 }
 
-/// TODO
+/// Vertices of a [`Tess`] mapped in memory for writing.
+///
+/// With [`Deinterleaved`] memory, only the values of the attribute `T` are mapped. The written vertices are uploaded
+/// when this object is dropped.
 #[derive(Debug)]
 pub struct VerticesMut<'a, B, V, I, W, S, T>
 where
@@ -1188,7 +1552,7 @@ where
   }
 }
 
-/// TODO
+/// Indices of a [`Tess`] mapped in memory for reading.
 #[derive(Debug)]
 pub struct Indices<'a, B, V, I, W, S>
 where
@@ -1216,7 +1580,9 @@ where
   }
 }
 
-/// TODO
+/// Indices of a [`Tess`] mapped in memory for writing.
+///
+/// The written indices are uploaded when this object is dropped.
 #[derive(Debug)]
 pub struct IndicesMut<'a, B, V, I, W, S>
 where
@@ -1257,7 +1623,9 @@ where
   }
 }
 
-/// TODO
+/// Instances of a [`Tess`] mapped in memory for reading.
+///
+/// With [`Deinterleaved`] memory, only the values of the attribute `T` are mapped.
 #[derive(Debug)]
 pub struct Instances<'a, B, V, I, W, S, T>
 where
@@ -1285,7 +1653,10 @@ where
   }
 }
 
-/// TODO
+/// Instances of a [`Tess`] mapped in memory for writing.
+///
+/// With [`Deinterleaved`] memory, only the values of the attribute `T` are mapped. The written instances are
+/// uploaded when this object is dropped.
 #[derive(Debug)]
 pub struct InstancesMut<'a, B, V, I, W, S, T>
 where
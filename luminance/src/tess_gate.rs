@@ -4,8 +4,16 @@
 //!
 //! [`Tess`]: crate::tess::Tess
 
-use crate::backend::tess_gate::TessGate as TessGateBackend;
-use crate::tess::{TessIndex, TessVertexData, TessView};
+use crate::backend::tess_gate::{
+  TessGate as TessGateBackend, TessGateBaseInstance as TessGateBaseInstanceBackend,
+  TessGateBaseVertex as TessGateBaseVertexBackend, TessGateIndirect as TessGateIndirectBackend,
+  TessGateIndirectAt as TessGateIndirectAtBackend, TessGateValidation as TessGateValidationBackend,
+};
+use crate::indirect::{
+  DrawElementsIndirectCommand, IndirectBuffer, IndirectBufferError, IndirectCommand,
+};
+use crate::shader::VertexFormatError;
+use crate::tess::{Mode, Tess, TessError, TessIndex, TessVertexData, TessView};
 
 /// Tessellation gate.
 pub struct TessGate<'a, B>
@@ -42,4 +50,201 @@ where
       Ok(())
     }
   }
+
+  /// Enter the [`TessGate`] by sharing a [`TessView`], adding `base_vertex` to every index it picks.
+  ///
+  /// This allows to pack several meshes in the same [`Tess`] while keeping their indices relative to their first
+  /// vertex: each mesh is then rendered with a [`TessView`] selecting its indices and its first vertex as
+  /// `base_vertex`. For non-indexed [`Tess`], `base_vertex` simply offsets the start of the [`TessView`].
+  ///
+  /// [`Tess`]: crate::tess::Tess
+  pub fn render_base_vertex<'b, E, T, V, I, W, S>(
+    &'b mut self,
+    tess_view: T,
+    base_vertex: isize,
+  ) -> Result<(), E>
+  where
+    B: TessGateBaseVertexBackend<V, I, W, S>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+  {
+    let tess_view = tess_view.into();
+
+    unsafe {
+      self.backend.render_base_vertex(
+        &tess_view.tess.repr,
+        tess_view.start_index,
+        tess_view.vert_nb,
+        tess_view.inst_nb,
+        base_vertex,
+      );
+
+      Ok(())
+    }
+  }
+
+  /// Enter the [`TessGate`] by sharing a [`TessView`], adding `base_vertex` to every index it picks and fetching
+  /// instance data starting at the instance `base_instance`.
+  ///
+  /// This is the same as [`TessGate::render_base_vertex`], also allowing to pack the instance data of several meshes
+  /// in the same [`Tess`]. `base_instance` doesn’t change the instance index seen by shader stages.
+  pub fn render_base_instance<'b, E, T, V, I, W, S>(
+    &'b mut self,
+    tess_view: T,
+    base_vertex: isize,
+    base_instance: usize,
+  ) -> Result<(), E>
+  where
+    B: TessGateBaseInstanceBackend<V, I, W, S>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+  {
+    let tess_view = tess_view.into();
+
+    unsafe {
+      self.backend.render_base_instance(
+        &tess_view.tess.repr,
+        tess_view.start_index,
+        tess_view.vert_nb,
+        tess_view.inst_nb,
+        base_vertex,
+        base_instance,
+      );
+
+      Ok(())
+    }
+  }
+
+  /// Enter the [`TessGate`] by sharing an indexed [`Tess`], rendering it once per draw command of `commands`.
+  ///
+  /// Each [`DrawElementsIndirectCommand`] picks its own indices, instances, base vertex and base instance in the
+  /// [`Tess`]; the default number of vertices and instances to render of the [`Tess`] are ignored. All the draw
+  /// commands are issued at once, and can be written by the GPU itself.
+  ///
+  /// [`TessError::NotIndexed`] is returned if the [`Tess`] is not indexed; nothing is rendered then.
+  pub fn render_indirect<'b, E, V, I, W, S>(
+    &'b mut self,
+    tess: &'b Tess<B, V, I, W, S>,
+    commands: &IndirectBuffer<B, DrawElementsIndirectCommand>,
+  ) -> Result<(), E>
+  where
+    B: TessGateIndirectBackend<V, I, W, S>,
+    E: From<TessError>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+  {
+    if I::INDEX_TYPE.is_none() {
+      return Err(TessError::not_indexed().into());
+    }
+
+    unsafe {
+      self
+        .backend
+        .render_indirect(&tess.repr, &commands.repr, commands.len());
+    }
+
+    Ok(())
+  }
+
+  /// Enter the [`TessGate`] by sharing a [`Tess`], rendering it as described by the draw command at the byte offset
+  /// `offset` in `commands`.
+  ///
+  /// The draw command is read by the GPU when rendering, so it can be written by a previous pass, such as a culling
+  /// compute pass, without reading it back on the CPU. With [`DrawElementsIndirectCommand`], the [`Tess`] must be
+  /// indexed; with [`DrawArraysIndirectCommand`], its indices, if any, are ignored.
+  ///
+  /// [`IndirectBufferError::InvalidOffset`] is returned if `offset` is not aligned on 4 bytes or if the draw command
+  /// doesn’t fit in `commands`, and [`TessError::NotIndexed`] if the draw command requires an indexed [`Tess`] while
+  /// it is not. Nothing is rendered then.
+  ///
+  /// [`DrawArraysIndirectCommand`]: crate::indirect::DrawArraysIndirectCommand
+  pub fn render_indirect_at<'b, E, V, I, W, S, C>(
+    &'b mut self,
+    tess: &'b Tess<B, V, I, W, S>,
+    commands: &IndirectBuffer<B, C>,
+    offset: usize,
+  ) -> Result<(), E>
+  where
+    B: TessGateIndirectAtBackend<V, I, W, S, C>,
+    E: From<IndirectBufferError> + From<TessError>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+    C: IndirectCommand,
+  {
+    commands.check_offset(offset)?;
+
+    if C::INDEXED && I::INDEX_TYPE.is_none() {
+      return Err(TessError::not_indexed().into());
+    }
+
+    unsafe {
+      self
+        .backend
+        .render_indirect_at(&tess.repr, &commands.repr, offset);
+    }
+
+    Ok(())
+  }
+
+  /// Enter the [`TessGate`] by sharing a [`TessView`], once checked that its vertex format satisfies the vertex
+  /// attributes of the program in use.
+  ///
+  /// Each active vertex attribute of the program must be provided, with a compatible type, by either the vertex or the
+  /// instance vertex format of the [`Tess`]; see [`ActiveVertexAttrib::check_format`] for further details. If the
+  /// program has a geometry stage, its input primitive must also accept the primitive mode of the [`Tess`]; see
+  /// [`GeometryInput::accepts`]. If the primitive mode is [`Mode::Patch`], the program must have tessellation stages
+  /// to consume the patches. Otherwise, nothing is rendered and the mismatch is returned as a
+  /// [`VertexFormatError`], instead of rendering garbage.
+  ///
+  /// The vertex attributes are reflected from the program at each call, so this is mostly useful in debug builds.
+  /// Programs used in a program pipeline are not checked.
+  ///
+  /// [`Tess`]: crate::tess::Tess
+  /// [`ActiveVertexAttrib::check_format`]: crate::shader::ActiveVertexAttrib::check_format
+  /// [`GeometryInput::accepts`]: crate::shader::GeometryInput::accepts
+  /// [`Mode::Patch`]: crate::tess::Mode::Patch
+  pub fn render_validated<'b, E, T, V, I, W, S>(&'b mut self, tess_view: T) -> Result<(), E>
+  where
+    B: TessGateBackend<V, I, W, S> + TessGateValidationBackend,
+    E: From<VertexFormatError>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+  {
+    let tess_view = tess_view.into();
+    let mut vertex_desc = V::vertex_desc();
+    vertex_desc.extend(W::vertex_desc());
+
+    for attrib in unsafe { self.backend.current_vertex_attribs() } {
+      attrib.check_format(&vertex_desc)?;
+    }
+
+    let mode = tess_view.tess.mode;
+
+    if let Some(config) = unsafe { self.backend.current_geometry_config() } {
+      if !config.input.accepts(mode) {
+        return Err(VertexFormatError::incompatible_mode(mode, config.input).into());
+      }
+    }
+
+    if let Mode::Patch(_) = mode {
+      if let Some(false) = unsafe { self.backend.current_has_tessellation_stages() } {
+        return Err(VertexFormatError::no_tessellation_stages(mode).into());
+      }
+    }
+
+    self.render(tess_view)
+  }
 }
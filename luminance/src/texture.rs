@@ -29,14 +29,79 @@
 //!   _nearest_ pixel to where you sample, or you can ask the GPU to perform a linear
 //!   interpolation between all neighboring pixels, etc. [`Sampler`] allows way more than that, so
 //!   feel free to read their documentation.
+//!
+//! # 1D textures
+//!
+//! [`Dim1`] textures are a single row of texels, sampled with a `sampler1D` and a single texture coordinate. They are a
+//! good fit for lookup tables indexed by a scalar, such as transfer functions or color gradients, and can be attached to
+//! framebuffers to be rendered to like any other texture. WebGL doesn’t support them: use a [`Dim2`] texture of height
+//! `1` instead.
+//!
+//! # Texture arrays
+//!
+//! Array dimensions — [`Dim1Array`] and [`Dim2Array`] — are made of several _layers_ of the same
+//! size, which makes them a good alternative to texture atlases: each image gets its own layer,
+//! with no bleeding between neighbors. They implement [`Layerable`], which allows to create an
+//! array from a list of images with [`Texture::from_layers`] and to upload a single layer with
+//! [`Texture::upload_layer`]. Shaders pick a layer by passing its index as the last texture
+//! coordinate, e.g. `texture(tex, vec3(uv, layer))` with a `sampler2DArray`.
+//!
+//! # Mipmaps
+//!
+//! Textures can hold _mipmap levels_, each half the size of the previous one — see
+//! [`Dimensionable::level_size`]. They can be generated from the base level — at creation with
+//! [`TexelUpload::BaseLevel`], or later with [`Texture::generate_mipmaps`] — or provided one by
+//! one, for instance when loading a precomputed mipmap chain, with [`Texture::upload_level`].
+//! [`Texture::upload_part_level`] updates a region of a single level, such as a glyph in an atlas,
+//! without regenerating the other levels.
+//!
+//! # Texture views
+//!
+//! A texture view reinterprets the storage of a texture — a range of its mipmap levels and layers — as another
+//! texture, without copying anything: a [`NormRGBA8UI`] texture can be viewed as [`SRGBA8UI`], one layer of a
+//! [`Dim2Array`] texture can be viewed as a [`Dim2`] texture, etc. Views are created with [`Texture::view`]; the
+//! dimensions a texture can be viewed as are given by the [`ViewableAs`] trait, and the pixel format of the view must
+//! have the same size as the one of the texture. Writing to a view writes to the viewed texture, and conversely.
+//!
+//! Views are an optional feature of backends, which might also require the viewed texture to have immutable storage.
+//!
+//! [`NormRGBA8UI`]: crate::pixel::NormRGBA8UI
+//! [`SRGBA8UI`]: crate::pixel::SRGBA8UI
+//!
+//! # Compressed textures
+//!
+//! 2D textures can use [`CompressedPixel`] formats — BCn, ETC2 or ASTC. Such textures are created from already
+//! compressed data with [`Texture::new_compressed`], one slice of bytes per mipmap level, as mipmaps cannot be
+//! generated for them. The length of each slice is checked against the block size of the format before anything is
+//! sent to the GPU. Support for each family of formats depends on the driver and can be checked beforehand with
+//! [`Query::supports_compression`].
+//!
+//! [`Query::supports_compression`]: crate::query::Query::supports_compression
+//!
+//! # Integral textures
+//!
+//! Pixel formats storing unnormalized integers — such as [`R32UI`] or [`RGBA8I`] — are accessed as integers in shaders,
+//! via `usampler*` and `isampler*` samplers, which makes them suitable to store entity IDs for GPU picking, for
+//! instance. Integers cannot be interpolated, so such textures must be sampled with [`MinFilter::Nearest`] (or
+//! [`MinFilter::NearestMipmapNearest`]) and [`MagFilter::Nearest`]; creating one with any other filter fails with
+//! [`TextureError::LinearFilteringOnIntegral`].
+//!
+//! [`R32UI`]: crate::pixel::R32UI
+//! [`RGBA8I`]: crate::pixel::RGBA8I
 
 use crate::{
-  backend::texture::Texture as TextureBackend,
+  backend::pixel_buffer::TextureStreaming as TextureStreamingBackend,
+  backend::texture::{
+    Texture as TextureBackend, TextureBindless as TextureBindlessBackend,
+    TextureCompressed as TextureCompressedBackend, TextureCopy as TextureCopyBackend,
+    TextureView as TextureViewBackend,
+  },
   context::GraphicsContext,
   depth_stencil::Comparison,
-  pixel::{Pixel, PixelFormat},
+  pixel::{CompressedPixel, Format, Pixel, PixelFormat, Size, Type},
+  pixel_buffer::{PixelBuffer, UploadFence},
 };
-use std::{error, fmt, marker::PhantomData};
+use std::{error, fmt, marker::PhantomData, mem, ops::Range, ptr, slice};
 
 /// How to wrap texture coordinates while sampling textures?
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -53,6 +118,11 @@ pub enum Wrap {
   Repeat,
   /// Same as `Repeat` but it will alternatively repeat between `[0;1]` and `[1;0]`.
   MirroredRepeat,
+  /// If textures coordinates lay outside of `[0;1]`, the border color of the sampler is returned — see
+  /// [`Sampler::border_color`].
+  ///
+  /// Backends not supporting border colors, such as WebGL, fall back to [`Wrap::ClampToEdge`].
+  ClampToBorder,
 }
 
 /// Minification filter.
@@ -85,6 +155,29 @@ pub enum MagFilter {
   Linear,
 }
 
+/// Source of a channel of sampled texels.
+///
+/// Swizzling remaps the channels of a texture when it is sampled, without changing shaders: for instance, a
+/// [`NormR8UI`] glyph mask sampled with `[Swizzle::One, Swizzle::One, Swizzle::One, Swizzle::Red]` reads as
+/// `(1, 1, 1, r)`. See [`Sampler::swizzle`].
+///
+/// [`NormR8UI`]: crate::pixel::NormR8UI
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Swizzle {
+  /// The red channel of the texel.
+  Red,
+  /// The green channel of the texel.
+  Green,
+  /// The blue channel of the texel.
+  Blue,
+  /// The alpha channel of the texel.
+  Alpha,
+  /// Always `0`.
+  Zero,
+  /// Always `1`.
+  One,
+}
+
 /// Class of [`Texture`] dimensions.
 ///
 /// This trait provides a simple mapping between the implementor and the [`Dim`] type, which represents a [`Texture`]
@@ -125,12 +218,12 @@ pub trait Dimensionable {
 
   /// Y offset. If it doesn’t have one, set it to 0.
   fn y_offset(_: Self::Offset) -> u32 {
-    1
+    0
   }
 
   /// Z offset. If it doesn’t have one, set it to 0.
   fn z_offset(_: Self::Offset) -> u32 {
-    1
+    0
   }
 
   /// Amount of pixels this size represents.
@@ -138,6 +231,16 @@ pub trait Dimensionable {
   /// For 2D sizes, it represents the area; for 3D sizes, the volume; etc.
   /// For cubemaps, it represents the side length of the cube.
   fn count(size: Self::Size) -> usize;
+
+  /// Size of the `level` mipmap level of a texture of size `size`.
+  ///
+  /// Each level is half the size of the previous one, down to `1`. Layers are not affected by mipmapping.
+  fn level_size(size: Self::Size, level: usize) -> Self::Size;
+}
+
+/// Length of the `level` mipmap level of a dimension of length `len`.
+fn level_len(len: u32, level: usize) -> u32 {
+  len.checked_shr(level as u32).unwrap_or(0).max(1)
 }
 
 /// Dimension of a texture.
@@ -201,6 +304,10 @@ impl Dimensionable for Dim1 {
   fn count(size: Self::Size) -> usize {
     size as usize
   }
+
+  fn level_size(size: Self::Size, level: usize) -> Self::Size {
+    level_len(size, level)
+  }
 }
 
 /// 2D dimension.
@@ -236,6 +343,10 @@ impl Dimensionable for Dim2 {
   fn count([width, height]: Self::Size) -> usize {
     width as usize * height as usize
   }
+
+  fn level_size([width, height]: Self::Size, level: usize) -> Self::Size {
+    [level_len(width, level), level_len(height, level)]
+  }
 }
 
 /// 3D dimension.
@@ -279,6 +390,14 @@ impl Dimensionable for Dim3 {
   fn count([width, height, depth]: Self::Size) -> usize {
     width as usize * height as usize * depth as usize
   }
+
+  fn level_size([width, height, depth]: Self::Size, level: usize) -> Self::Size {
+    [
+      level_len(width, level),
+      level_len(height, level),
+      level_len(depth, level),
+    ]
+  }
 }
 
 /// Cubemap dimension.
@@ -316,20 +435,17 @@ impl Dimensionable for Cubemap {
   }
 
   fn z_offset(off: Self::Offset) -> u32 {
-    match off.1 {
-      CubeFace::PositiveX => 0,
-      CubeFace::NegativeX => 1,
-      CubeFace::PositiveY => 2,
-      CubeFace::NegativeY => 3,
-      CubeFace::PositiveZ => 4,
-      CubeFace::NegativeZ => 5,
-    }
+    off.1.index()
   }
 
   fn count(size: Self::Size) -> usize {
     let size = size as usize;
     size * size
   }
+
+  fn level_size(size: Self::Size, level: usize) -> Self::Size {
+    level_len(size, level)
+  }
 }
 
 /// Faces of a cubemap.
@@ -349,6 +465,32 @@ pub enum CubeFace {
   NegativeZ,
 }
 
+impl CubeFace {
+  /// All the faces of a cube, in [`CubeFace::index`] order.
+  pub const ALL: [CubeFace; 6] = [
+    CubeFace::PositiveX,
+    CubeFace::NegativeX,
+    CubeFace::PositiveY,
+    CubeFace::NegativeY,
+    CubeFace::PositiveZ,
+    CubeFace::NegativeZ,
+  ];
+
+  /// Index of the face, from `0` for [`CubeFace::PositiveX`] to `5` for [`CubeFace::NegativeZ`].
+  ///
+  /// This is the layer of the face when a cubemap is seen as a layered texture.
+  pub fn index(self) -> u32 {
+    match self {
+      CubeFace::PositiveX => 0,
+      CubeFace::NegativeX => 1,
+      CubeFace::PositiveY => 2,
+      CubeFace::NegativeY => 3,
+      CubeFace::PositiveZ => 4,
+      CubeFace::NegativeZ => 5,
+    }
+  }
+}
+
 /// 1D array dimension.
 #[derive(Clone, Copy, Debug)]
 pub struct Dim1Array;
@@ -382,6 +524,10 @@ impl Dimensionable for Dim1Array {
   fn count((width, layer): Self::Size) -> usize {
     width as usize * layer as usize
   }
+
+  fn level_size((width, layer): Self::Size, level: usize) -> Self::Size {
+    (level_len(width, level), layer)
+  }
 }
 
 /// 2D dimension.
@@ -425,8 +571,108 @@ impl Dimensionable for Dim2Array {
   fn count(([width, height], layer): Self::Size) -> usize {
     width as usize * height as usize * layer as usize
   }
+
+  fn level_size(([width, height], layer): Self::Size, level: usize) -> Self::Size {
+    ([level_len(width, level), level_len(height, level)], layer)
+  }
+}
+
+/// Class of layered [`Texture`] dimensions, i.e. texture arrays.
+///
+/// A layered dimension is made of several layers of the same size, each of them having the [`Layerable::Layer`]
+/// dimension.
+pub trait Layerable: Dimensionable {
+  /// Dimension of a single layer.
+  type Layer: Dimensionable;
+
+  /// Number of layers of the associated [`Dimensionable::Size`].
+  fn layers(size: Self::Size) -> u32;
+
+  /// Size of a single layer of the associated [`Dimensionable::Size`].
+  fn layer_size(size: Self::Size) -> <Self::Layer as Dimensionable>::Size;
+
+  /// Size made of `layers` layers of size `layer_size`.
+  fn layered_size(layer_size: <Self::Layer as Dimensionable>::Size, layers: u32) -> Self::Size;
+
+  /// Offset of the origin of a layer.
+  fn layer_offset(layer: u32) -> Self::Offset;
+}
+
+impl Layerable for Dim1Array {
+  type Layer = Dim1;
+
+  fn layers((_, layers): Self::Size) -> u32 {
+    layers
+  }
+
+  fn layer_size((width, _): Self::Size) -> u32 {
+    width
+  }
+
+  fn layered_size(width: u32, layers: u32) -> Self::Size {
+    (width, layers)
+  }
+
+  fn layer_offset(layer: u32) -> Self::Offset {
+    (0, layer)
+  }
+}
+
+impl Layerable for Dim2Array {
+  type Layer = Dim2;
+
+  fn layers((_, layers): Self::Size) -> u32 {
+    layers
+  }
+
+  fn layer_size((size, _): Self::Size) -> [u32; 2] {
+    size
+  }
+
+  fn layered_size(size: [u32; 2], layers: u32) -> Self::Size {
+    (size, layers)
+  }
+
+  fn layer_offset(layer: u32) -> Self::Offset {
+    ([0, 0], layer)
+  }
 }
 
+/// Dimensions a texture can be viewed as.
+///
+/// See [`Texture::view`].
+pub trait ViewableAs<VD>: Dimensionable
+where
+  VD: Dimensionable,
+{
+  /// Size of a view made of `layers` layers of the mipmap level of size `level_size`.
+  fn view_size(level_size: Self::Size, layers: u32) -> VD::Size;
+}
+
+macro_rules! impl_ViewableAs {
+  ($src:ty => $view:ty, |$size:pat_param, $layers:pat_param| $view_size:expr) => {
+    impl ViewableAs<$view> for $src {
+      fn view_size($size: Self::Size, $layers: u32) -> <$view as Dimensionable>::Size {
+        $view_size
+      }
+    }
+  };
+}
+
+impl_ViewableAs!(Dim1 => Dim1, |width, _| width);
+impl_ViewableAs!(Dim1 => Dim1Array, |width, layers| (width, layers));
+impl_ViewableAs!(Dim1Array => Dim1, |(width, _), _| width);
+impl_ViewableAs!(Dim1Array => Dim1Array, |(width, _), layers| (width, layers));
+impl_ViewableAs!(Dim2 => Dim2, |size, _| size);
+impl_ViewableAs!(Dim2 => Dim2Array, |size, layers| (size, layers));
+impl_ViewableAs!(Dim2Array => Dim2, |(size, _), _| size);
+impl_ViewableAs!(Dim2Array => Dim2Array, |(size, _), layers| (size, layers));
+impl_ViewableAs!(Dim2Array => Cubemap, |([width, _], _), _| width);
+impl_ViewableAs!(Dim3 => Dim3, |size, _| size);
+impl_ViewableAs!(Cubemap => Cubemap, |size, _| size);
+impl_ViewableAs!(Cubemap => Dim2, |size, _| [size, size]);
+impl_ViewableAs!(Cubemap => Dim2Array, |size, layers| ([size, size], layers));
+
 /// A `Sampler` object gives hint on how a `Texture` should be sampled.
 #[derive(Clone, Copy, Debug)]
 pub struct Sampler {
@@ -442,6 +688,23 @@ pub struct Sampler {
   pub mag_filter: MagFilter,
   /// For depth textures, should we perform depth comparison and if so, how?
   pub depth_comparison: Option<Comparison>,
+  /// Maximum anisotropy of anisotropic filtering, or `None` to disable it.
+  ///
+  /// Anisotropic filtering reduces the blurring and shimmering of textures seen at grazing angles, such as terrains.
+  /// The value is clamped to the maximum supported by the backend — see [`Query::max_anisotropy`] —, and ignored if
+  /// the backend doesn’t support anisotropic filtering.
+  ///
+  /// [`Query::max_anisotropy`]: crate::query::Query::max_anisotropy
+  pub max_anisotropy: Option<f32>,
+  /// Color returned when sampling outside of the texture with [`Wrap::ClampToBorder`], as RGBA.
+  ///
+  /// For depth textures, the first component is the depth: a shadow map sampled with `[1., 1., 1., 1.]` is lit outside
+  /// of the light frustum.
+  pub border_color: [f32; 4],
+  /// Sources of the red, green, blue and alpha channels of sampled texels.
+  ///
+  /// Backends not supporting swizzling, such as WebGL, ignore it.
+  pub swizzle: [Swizzle; 4],
 }
 
 /// Default value is as following:
@@ -454,6 +717,9 @@ impl Default for Sampler {
       min_filter: MinFilter::NearestMipmapLinear,
       mag_filter: MagFilter::Linear,
       depth_comparison: None,
+      max_anisotropy: None,
+      border_color: [0., 0., 0., 0.],
+      swizzle: [Swizzle::Red, Swizzle::Green, Swizzle::Blue, Swizzle::Alpha],
     }
   }
 }
@@ -572,6 +838,96 @@ pub enum TextureError {
 
   /// Failed to upload texels.
   CannotUploadTexels(String),
+
+  /// Cannot make a texture resident.
+  ///
+  /// That error might happen if bindless textures are not supported by the hardware.
+  CannotMakeResident(String),
+
+  /// A layer is out of the bounds of a texture array.
+  InvalidLayer {
+    /// Selected layer.
+    layer: u32,
+    /// Number of layers of the texture.
+    layers: u32,
+  },
+
+  /// A layer doesn’t have the expected number of texels.
+  ///
+  /// All the layers of a texture array have the same size, so the images used to create one must have the same
+  /// number of texels.
+  LayerSizeMismatch {
+    /// Index of the layer.
+    layer: usize,
+    /// Expected number of texels.
+    expected_texels: usize,
+    /// Provided number of texels.
+    provided_texels: usize,
+  },
+
+  /// A texture with an integral pixel format is created with a sampler filtering linearly.
+  ///
+  /// Integral textures can only be sampled with nearest filters.
+  LinearFilteringOnIntegral(PixelFormat),
+
+  /// Compressed data doesn’t have the size expected by its format.
+  CompressedSizeMismatch {
+    /// Mipmap level the data is for.
+    level: usize,
+    /// Expected number of bytes.
+    expected_bytes: usize,
+    /// Provided number of bytes.
+    provided_bytes: usize,
+  },
+
+  /// A mipmap level doesn’t exist.
+  InvalidLevel {
+    /// Selected level.
+    level: usize,
+    /// Number of levels of the texture, base level included.
+    levels: usize,
+  },
+
+  /// A region is out of the bounds of a mipmap level.
+  RegionOutOfBounds {
+    /// Mipmap level of the region.
+    level: usize,
+  },
+
+  /// A texture cannot be viewed with a pixel format.
+  ///
+  /// Only pixel formats of the same size can be used for views, and depth and compressed formats can only be viewed as
+  /// themselves.
+  IncompatibleViewFormat {
+    /// Pixel format of the texture.
+    texture: PixelFormat,
+    /// Pixel format of the view.
+    view: PixelFormat,
+  },
+
+  /// A view doesn’t have the number of layers required by its dimension.
+  ViewLayerCountMismatch {
+    /// Required number of layers.
+    expected: u32,
+    /// Number of layers of the view.
+    provided: u32,
+  },
+
+  /// Cannot create a texture view.
+  ///
+  /// That error might happen if texture views are not supported by the hardware.
+  CannotCreateView(String),
+
+  /// Cannot copy texels between textures.
+  CannotCopyTexels(String),
+
+  /// Texels cannot be converted between two pixel formats.
+  UnsupportedConversion {
+    /// Pixel format of the texels.
+    from: PixelFormat,
+    /// Pixel format the texels were to be converted to.
+    to: PixelFormat,
+  },
 }
 
 impl TextureError {
@@ -602,6 +958,78 @@ impl TextureError {
   pub fn cannot_upload_texels(reason: impl Into<String>) -> Self {
     TextureError::CannotUploadTexels(reason.into())
   }
+
+  /// Cannot make a texture resident.
+  pub fn cannot_make_resident(reason: impl Into<String>) -> Self {
+    TextureError::CannotMakeResident(reason.into())
+  }
+
+  /// A layer is out of the bounds of a texture array.
+  pub fn invalid_layer(layer: u32, layers: u32) -> Self {
+    TextureError::InvalidLayer { layer, layers }
+  }
+
+  /// A layer doesn’t have the expected number of texels.
+  pub fn layer_size_mismatch(layer: usize, expected_texels: usize, provided_texels: usize) -> Self {
+    TextureError::LayerSizeMismatch {
+      layer,
+      expected_texels,
+      provided_texels,
+    }
+  }
+
+  /// A texture with an integral pixel format is created with a sampler filtering linearly.
+  pub fn linear_filtering_on_integral(pf: PixelFormat) -> Self {
+    TextureError::LinearFilteringOnIntegral(pf)
+  }
+
+  /// Compressed data doesn’t have the size expected by its format.
+  pub fn compressed_size_mismatch(
+    level: usize,
+    expected_bytes: usize,
+    provided_bytes: usize,
+  ) -> Self {
+    TextureError::CompressedSizeMismatch {
+      level,
+      expected_bytes,
+      provided_bytes,
+    }
+  }
+
+  /// A mipmap level doesn’t exist.
+  pub fn invalid_level(level: usize, levels: usize) -> Self {
+    TextureError::InvalidLevel { level, levels }
+  }
+
+  /// A region is out of the bounds of a mipmap level.
+  pub fn region_out_of_bounds(level: usize) -> Self {
+    TextureError::RegionOutOfBounds { level }
+  }
+
+  /// A texture cannot be viewed with a pixel format.
+  pub fn incompatible_view_format(texture: PixelFormat, view: PixelFormat) -> Self {
+    TextureError::IncompatibleViewFormat { texture, view }
+  }
+
+  /// A view doesn’t have the number of layers required by its dimension.
+  pub fn view_layer_count_mismatch(expected: u32, provided: u32) -> Self {
+    TextureError::ViewLayerCountMismatch { expected, provided }
+  }
+
+  /// Cannot create a texture view.
+  pub fn cannot_create_view(reason: impl Into<String>) -> Self {
+    TextureError::CannotCreateView(reason.into())
+  }
+
+  /// Cannot copy texels between textures.
+  pub fn cannot_copy_texels(reason: impl Into<String>) -> Self {
+    TextureError::CannotCopyTexels(reason.into())
+  }
+
+  /// Texels cannot be converted between two pixel formats.
+  pub fn unsupported_conversion(from: PixelFormat, to: PixelFormat) -> Self {
+    TextureError::UnsupportedConversion { from, to }
+  }
 }
 
 impl fmt::Display for TextureError {
@@ -631,12 +1059,129 @@ impl fmt::Display for TextureError {
       TextureError::CannotUploadTexels(ref e) => {
         write!(f, "cannot upload texels to texture: {}", e)
       }
+
+      TextureError::CannotMakeResident(ref e) => {
+        write!(f, "cannot make texture resident: {}", e)
+      }
+
+      TextureError::InvalidLayer { layer, layers } => write!(
+        f,
+        "invalid texture layer {} (the texture has {} layers)",
+        layer, layers
+      ),
+
+      TextureError::LayerSizeMismatch {
+        layer,
+        expected_texels,
+        provided_texels,
+      } => write!(
+        f,
+        "layer {} has {} texels, but {} texels were expected",
+        layer, provided_texels, expected_texels
+      ),
+
+      TextureError::LinearFilteringOnIntegral(ref pf) => write!(
+        f,
+        "integral pixel format {:?} cannot be filtered linearly; use nearest filters",
+        pf
+      ),
+
+      TextureError::CompressedSizeMismatch {
+        level,
+        expected_bytes,
+        provided_bytes,
+      } => write!(
+        f,
+        "compressed level {} has {} bytes, but {} bytes were expected",
+        level, provided_bytes, expected_bytes
+      ),
+
+      TextureError::InvalidLevel { level, levels } => write!(
+        f,
+        "invalid mipmap level {} (the texture has {} levels)",
+        level, levels
+      ),
+
+      TextureError::RegionOutOfBounds { level } => {
+        write!(f, "region out of the bounds of mipmap level {}", level)
+      }
+
+      TextureError::IncompatibleViewFormat { texture, view } => write!(
+        f,
+        "texture of pixel format {:?} cannot be viewed as {:?}",
+        texture, view
+      ),
+
+      TextureError::ViewLayerCountMismatch { expected, provided } => write!(
+        f,
+        "view has {} layers, but {} layers were expected",
+        provided, expected
+      ),
+
+      TextureError::CannotCreateView(ref e) => write!(f, "cannot create texture view: {}", e),
+
+      TextureError::CannotCopyTexels(ref e) => {
+        write!(f, "cannot copy texels between textures: {}", e)
+      }
+
+      TextureError::UnsupportedConversion { from, to } => {
+        write!(f, "cannot convert texels from {:?} to {:?}", from, to)
+      }
     }
   }
 }
 
 impl error::Error for TextureError {}
 
+/// Bindless texture handle.
+///
+/// Such a handle is obtained with [`Texture::make_resident`] and refers to a resident texture in shaders. It can be
+/// passed to a [`Uniform`] or stored inside a [`ShaderData`].
+///
+/// # Parametricity
+///
+/// - `D` is the dimension of the texture.
+/// - `S` is the sampler type.
+///
+/// [`Uniform`]: crate::shader::Uniform
+/// [`ShaderData`]: crate::shader::ShaderData
+#[derive(Debug)]
+pub struct TextureHandle<D, S> {
+  handle: u64,
+  _phantom: PhantomData<*const (D, S)>,
+}
+
+impl<D, S> Clone for TextureHandle<D, S> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<D, S> Copy for TextureHandle<D, S> {}
+
+impl<D, S> TextureHandle<D, S> {
+  /// Create a new [`TextureHandle`].
+  ///
+  /// # Safety
+  ///
+  /// This method must be used **only** by backends.
+  pub unsafe fn new(handle: u64) -> Self {
+    TextureHandle {
+      handle,
+      _phantom: PhantomData,
+    }
+  }
+
+  /// Access the underlying handle value.
+  ///
+  /// # Notes
+  ///
+  /// That value shouldn’t be read nor store, as it’s only meaningful for backend implementations.
+  pub fn handle(self) -> u64 {
+    self.handle
+  }
+}
+
 /// Textures.
 ///
 /// Textures allow mainly two use cases:
@@ -724,6 +1269,8 @@ where
   where
     C: GraphicsContext<Backend = B>,
   {
+    check_sampler::<P>(&sampler)?;
+
     unsafe {
       ctx
         .backend()
@@ -758,6 +1305,8 @@ where
   where
     C: GraphicsContext<Backend = B>,
   {
+    check_sampler::<P>(&sampler)?;
+
     unsafe {
       ctx
         .backend()
@@ -780,6 +1329,11 @@ where
     self.size
   }
 
+  /// Return the size of the `level` mipmap level of the texture.
+  pub fn level_size(&self, level: usize) -> D::Size {
+    D::level_size(self.size, level)
+  }
+
   /// Resize the texture by providing a new size and texels by reusing its GPU resources.
   ///
   /// This function works similarly to [`Texture::new`] but instead of creating a brand new texture, reuses the texture
@@ -806,14 +1360,40 @@ where
     unsafe { B::resize_raw(&mut self.repr, size, texels) }
   }
 
+  /// Make the texture resident and get its bindless [`TextureHandle`].
+  ///
+  /// A resident texture can be accessed in shaders via its handle, passed as a uniform or inside a [`ShaderData`],
+  /// without being bound in a pipeline. Once a handle is obtained, the texture cannot be resized anymore.
+  ///
+  /// [`ShaderData`]: crate::shader::ShaderData
+  pub fn make_resident(&mut self) -> Result<TextureHandle<D, P::SamplerType>, TextureError>
+  where
+    B: TextureBindlessBackend<D, P>,
+  {
+    unsafe { B::make_resident(&mut self.repr).map(|handle| TextureHandle::new(handle)) }
+  }
+
+  /// Make the texture non-resident.
+  ///
+  /// The handles previously obtained with [`Texture::make_resident`] must not be used by shaders anymore.
+  pub fn make_non_resident(&mut self)
+  where
+    B: TextureBindlessBackend<D, P>,
+  {
+    unsafe { B::make_non_resident(&mut self.repr) }
+  }
+
   /// Upload pixels to a region of the texture described by the rectangle made with `size` and
   /// `offset`.
+  ///
+  /// [`TextureError::RegionOutOfBounds`] is returned if the region is not in the bounds of the texture.
   pub fn upload_part(
     &mut self,
     offset: D::Offset,
     size: D::Size,
     texels: TexelUpload<[P::Encoding]>,
   ) -> Result<(), TextureError> {
+    self.check_region(0, offset, size)?;
     unsafe { B::upload_part(&mut self.repr, offset, size, texels) }
   }
 
@@ -822,14 +1402,48 @@ where
     unsafe { B::upload(&mut self.repr, self.size, texels) }
   }
 
+  /// Upload pixels of another pixel format `Q` to the whole texture, converting them on the CPU.
+  ///
+  /// See [`convert_texels`] for the supported conversions.
+  ///
+  /// [`TextureError::UnsupportedConversion`] is returned if the texels cannot be converted.
+  pub fn upload_converted<Q>(
+    &mut self,
+    texels: TexelUpload<[Q::Encoding]>,
+  ) -> Result<(), TextureError>
+  where
+    Q: Pixel,
+  {
+    match texels {
+      TexelUpload::BaseLevel { texels, mipmaps } => {
+        let texels = convert_texels::<Q, P>(texels)?;
+        self.upload_raw(TexelUpload::base_level(&texels, mipmaps))
+      }
+
+      TexelUpload::Levels(levels) => {
+        let levels = levels
+          .iter()
+          .map(|texels| convert_texels::<Q, P>(texels))
+          .collect::<Result<Vec<_>, _>>()?;
+        let levels: Vec<_> = levels.iter().map(Vec::as_slice).collect();
+        self.upload_raw(TexelUpload::levels(&levels))
+      }
+
+      TexelUpload::Reserve { mipmaps } => self.upload_raw(TexelUpload::reserve(mipmaps)),
+    }
+  }
+
   /// Upload raw data to a region of the texture described by the rectangle made with `size` and
   /// `offset`.
+  ///
+  /// [`TextureError::RegionOutOfBounds`] is returned if the region is not in the bounds of the texture.
   pub fn upload_part_raw(
     &mut self,
     offset: D::Offset,
     size: D::Size,
     texels: TexelUpload<[P::RawEncoding]>,
   ) -> Result<(), TextureError> {
+    self.check_region(0, offset, size)?;
     unsafe { B::upload_part_raw(&mut self.repr, offset, size, texels) }
   }
 
@@ -838,6 +1452,140 @@ where
     unsafe { B::upload_raw(&mut self.repr, self.size, texels) }
   }
 
+  /// Upload pixels to a region of a single mipmap level of the texture, described by the rectangle made with `size` and
+  /// `offset`.
+  ///
+  /// `level` is `0` for the base level; the size of each following level is half the size of the previous one. This is
+  /// typically used to update a part of a texture — e.g. a glyph atlas — without uploading it whole. Mipmaps are not
+  /// regenerated.
+  ///
+  /// [`TextureError::InvalidLevel`] is returned if the texture doesn’t have the mipmap level, and
+  /// [`TextureError::RegionOutOfBounds`] if the region is not in the bounds of the level.
+  pub fn upload_part_level(
+    &mut self,
+    offset: D::Offset,
+    size: D::Size,
+    level: usize,
+    texels: &[P::Encoding],
+  ) -> Result<(), TextureError> {
+    self.check_region(level, offset, size)?;
+    unsafe { B::upload_part_level(&mut self.repr, level, offset, size, texels) }
+  }
+
+  /// Schedule the upload of the texels of a [`PixelBuffer`] to a region of a single mipmap level of the texture.
+  ///
+  /// The region is filled with the first texels of `buffer`. Unlike [`Texture::upload_part_level`], this method
+  /// doesn’t wait for the texels to be copied: the upload is performed asynchronously by the GPU, and the returned
+  /// [`UploadFence`] tells when it is complete. See the [`pixel_buffer`] module for further details.
+  ///
+  /// [`TextureError::InvalidLevel`] and [`TextureError::RegionOutOfBounds`] are returned if the region is not in the
+  /// bounds of the texture, and [`TextureError::NotEnoughPixels`] if `buffer` holds fewer texels than the region.
+  ///
+  /// [`pixel_buffer`]: crate::pixel_buffer
+  pub fn upload_part_level_from_buffer(
+    &mut self,
+    offset: D::Offset,
+    size: D::Size,
+    level: usize,
+    buffer: &PixelBuffer<B, P::Encoding>,
+  ) -> Result<UploadFence<B>, TextureError>
+  where
+    B: TextureStreamingBackend<D, P>,
+  {
+    self.check_region(level, offset, size)?;
+
+    let texel_bytes = mem::size_of::<P::Encoding>();
+    let expected_bytes = D::count(size) * texel_bytes;
+    let provided_bytes = buffer.len() * texel_bytes;
+
+    if provided_bytes < expected_bytes {
+      return Err(TextureError::not_enough_pixels(
+        expected_bytes,
+        provided_bytes,
+      ));
+    }
+
+    unsafe {
+      B::upload_part_level_from_buffer(&mut self.repr, level, offset, size, &buffer.repr)
+        .map(|repr| UploadFence { repr })
+    }
+  }
+
+  /// Upload raw data to a region of a single mipmap level of the texture.
+  ///
+  /// See [`Texture::upload_part_level`] for further details.
+  pub fn upload_part_level_raw(
+    &mut self,
+    offset: D::Offset,
+    size: D::Size,
+    level: usize,
+    texels: &[P::RawEncoding],
+  ) -> Result<(), TextureError> {
+    self.check_region(level, offset, size)?;
+    unsafe { B::upload_part_level_raw(&mut self.repr, level, offset, size, texels) }
+  }
+
+  /// Upload pixels to a whole mipmap level of the texture.
+  ///
+  /// This allows to load precomputed mipmap chains — e.g. from KTX files — level by level; the size of each level is
+  /// given by [`Texture::level_size`]. For cubemaps, only the [`CubeFace::PositiveX`] face is uploaded: use
+  /// [`Texture::upload_part_level`] for the other faces.
+  ///
+  /// [`TextureError::InvalidLevel`] is returned if the texture doesn’t have the mipmap level.
+  pub fn upload_level(&mut self, level: usize, texels: &[P::Encoding]) -> Result<(), TextureError> {
+    self.upload_part_level(D::ZERO_OFFSET, self.level_size(level), level, texels)
+  }
+
+  /// Upload raw data to a whole mipmap level of the texture.
+  ///
+  /// See [`Texture::upload_level`] for further details.
+  pub fn upload_level_raw(
+    &mut self,
+    level: usize,
+    texels: &[P::RawEncoding],
+  ) -> Result<(), TextureError> {
+    self.upload_part_level_raw(D::ZERO_OFFSET, self.level_size(level), level, texels)
+  }
+
+  /// Generate the mipmap levels of the texture out of its base level.
+  ///
+  /// This is typically used after uploading the base level with [`Texture::upload_part_level`], which doesn’t
+  /// regenerate mipmaps. Textures without mipmaps are left untouched.
+  pub fn generate_mipmaps(&mut self) -> Result<(), TextureError> {
+    unsafe { B::generate_mipmaps(&mut self.repr) }
+  }
+
+  /// Get a copy of all the pixels from the base level of the texture.
+  ///
+  /// The pixels are tightly packed, row after row, starting with the first row of the texture. Layers — and faces of
+  /// cubemaps, in [`CubeFace::index`] order — follow each other.
+  pub fn get_texels(&self) -> Result<Vec<P::Encoding>, TextureError>
+  where
+    P::Encoding: Copy + Default,
+  {
+    unsafe { B::get_texels(&self.repr, self.size) }
+  }
+
+  /// Get a copy of the pixels of a region of the base level of the texture, described by the rectangle made with `size`
+  /// and `offset`.
+  ///
+  /// The pixels are laid out as with [`Texture::get_texels`], for a texture of size `size`.
+  ///
+  /// [`TextureError::RegionOutOfBounds`] is returned if the region is not in the bounds of the texture.
+  pub fn get_texels_part(
+    &self,
+    offset: D::Offset,
+    size: D::Size,
+  ) -> Result<Vec<P::Encoding>, TextureError>
+  where
+    P::Encoding: Copy + Default,
+  {
+    self.check_region(0, offset, size)?;
+
+    let texels = self.get_texels()?;
+    Ok(crop_texels::<D, _>(&texels, self.size, offset, size))
+  }
+
   /// Get a copy of all the pixels from the texture.
   pub fn get_raw_texels(&self) -> Result<Vec<P::RawEncoding>, TextureError>
   where
@@ -845,4 +1593,608 @@ where
   {
     unsafe { B::get_raw_texels(&self.repr, self.size) }
   }
+
+  /// Copy a region of the base level of the texture to another texture, without going through the CPU.
+  ///
+  /// The region made with `src_offset` and `size` is copied to the base level of `dst`, at `dst_offset`. This allows
+  /// post-processing chains to duplicate the content of a texture — e.g. the color attachment of a framebuffer —
+  /// before rendering to it again.
+  ///
+  /// [`TextureError::RegionOutOfBounds`] is returned if the region is not in the bounds of both textures.
+  pub fn copy_to(
+    &self,
+    dst: &mut Texture<B, D, P>,
+    src_offset: D::Offset,
+    size: D::Size,
+    dst_offset: D::Offset,
+  ) -> Result<(), TextureError>
+  where
+    B: TextureCopyBackend<D, P>,
+  {
+    self.check_region(0, src_offset, size)?;
+    dst.check_region(0, dst_offset, size)?;
+
+    unsafe { B::copy_texture(&self.repr, &mut dst.repr, src_offset, size, dst_offset) }
+  }
+
+  /// Create a view of a range of the mipmap levels and layers of the texture.
+  ///
+  /// The view shares the storage of the texture: nothing is copied, and writing to either of them is visible in the
+  /// other one. Its dimension `VD` must be one the texture is [`ViewableAs`], and its pixel format `VP` must have the
+  /// same size as `P`, as when viewing a `NormRGBA8UI` texture as `SRGBA8UI` to sample it in the sRGB colorspace.
+  /// The base level of the view is the `levels.start` level of the texture.
+  ///
+  /// `layers` selects layers of arrays and faces of cubemaps; it must be `0..1` for other dimensions. Views of
+  /// dimensions without layers must have a single layer, and cubemap views must have six.
+  ///
+  /// [`TextureError::InvalidLevel`] and [`TextureError::InvalidLayer`] are returned if `levels` or `layers` is empty
+  /// or out of the bounds of the texture. [`TextureError::IncompatibleViewFormat`] is returned if `VP` cannot be used
+  /// to view the texture, and [`TextureError::ViewLayerCountMismatch`] if `layers` doesn’t fit `VD`.
+  pub fn view<VD, VP>(
+    &self,
+    levels: Range<usize>,
+    layers: Range<u32>,
+  ) -> Result<Texture<B, VD, VP>, TextureError>
+  where
+    B: TextureViewBackend<VD, VP>,
+    D: ViewableAs<VD>,
+    VD: Dimensionable,
+    VP: Pixel,
+  {
+    let level_count = self.mipmaps() + 1;
+    if levels.is_empty() || levels.end > level_count {
+      let level = levels.end.max(levels.start + 1) - 1;
+      return Err(TextureError::invalid_level(level, level_count));
+    }
+
+    let layer_count = view_layers::<D>(self.size);
+    if layers.is_empty() || layers.end > layer_count {
+      let layer = layers.end.max(layers.start + 1) - 1;
+      return Err(TextureError::invalid_layer(layer, layer_count));
+    }
+
+    let (pf, view_pf) = (P::pixel_format(), VP::pixel_format());
+    if !view_compatible(pf, view_pf) {
+      return Err(TextureError::incompatible_view_format(pf, view_pf));
+    }
+
+    let view_layer_count = layers.end - layers.start;
+    let expected_layers = match VD::dim() {
+      Dim::Dim1Array | Dim::Dim2Array => view_layer_count,
+      Dim::Cubemap => 6,
+      Dim::Dim1 | Dim::Dim2 | Dim::Dim3 => 1,
+    };
+    if view_layer_count != expected_layers {
+      return Err(TextureError::view_layer_count_mismatch(
+        expected_layers,
+        view_layer_count,
+      ));
+    }
+
+    let size = D::view_size(D::level_size(self.size, levels.start), view_layer_count);
+
+    unsafe {
+      B::new_texture_view(&self.repr, levels, layers).map(|repr| Texture {
+        repr,
+        size,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Check that a region is in the bounds of a mipmap level.
+  fn check_region(
+    &self,
+    level: usize,
+    offset: D::Offset,
+    size: D::Size,
+  ) -> Result<(), TextureError> {
+    let levels = self.mipmaps() + 1;
+
+    if level >= levels {
+      return Err(TextureError::invalid_level(level, levels));
+    }
+
+    if !region_in_bounds::<D>(self.size, level, offset, size) {
+      return Err(TextureError::region_out_of_bounds(level));
+    }
+
+    Ok(())
+  }
+}
+
+/// Convert texels of the pixel format `Q` to raw texels of the pixel format `P`.
+///
+/// Channels are converted one by one — normalized integers to floating-point values and back, integers to integers of
+/// another size, etc. — and saturated if out of range. Channels missing from `Q` are set to `0`, except for alpha, set
+/// to `1`; channels missing from `P` are dropped. For instance, [`NormRGB8UI`] texels can be converted to
+/// [`NormRGBA8UI`] texels, or to [`RGBA32F`] texels.
+///
+/// Conversions between color and depth formats, between integral and non-integral formats and between sRGB and linear
+/// formats are not supported, nor are conversions from or to compressed and packed formats, such as [`R11G11B10F`].
+///
+/// [`TextureError::UnsupportedConversion`] is returned if the texels cannot be converted.
+///
+/// [`NormRGB8UI`]: crate::pixel::NormRGB8UI
+/// [`NormRGBA8UI`]: crate::pixel::NormRGBA8UI
+/// [`RGBA32F`]: crate::pixel::RGBA32F
+/// [`R11G11B10F`]: crate::pixel::R11G11B10F
+pub fn convert_texels<Q, P>(texels: &[Q::Encoding]) -> Result<Vec<P::RawEncoding>, TextureError>
+where
+  Q: Pixel,
+  P: Pixel,
+{
+  let (from, to) = (Q::pixel_format(), P::pixel_format());
+  let unsupported = || TextureError::unsupported_conversion(from, to);
+
+  let (from_channel, from_channels) = conversion_layout(from).ok_or_else(unsupported)?;
+  let (to_channel, to_channels) = conversion_layout(to).ok_or_else(unsupported)?;
+
+  if from.is_depth_pixel() != to.is_depth_pixel()
+    || from.is_integral() != to.is_integral()
+    || from.is_srgb() != to.is_srgb()
+  {
+    return Err(unsupported());
+  }
+
+  // the encoding of pixels is made of their channels
+  let bytes =
+    unsafe { slice::from_raw_parts(texels.as_ptr() as *const u8, mem::size_of_val(texels)) };
+  let from_texel_len = from_channel.bytes_len() * from_channels;
+  let mut converted =
+    Vec::with_capacity(bytes.len() / from_texel_len * to_channel.bytes_len() * to_channels);
+
+  for texel in bytes.chunks_exact(from_texel_len) {
+    for channel in 0..to_channels {
+      let value = if channel < from_channels {
+        let bytes = &texel[channel * from_channel.bytes_len()..];
+        from_channel.decode(bytes)
+      } else if channel == 3 {
+        1.
+      } else {
+        0.
+      };
+
+      to_channel.encode(value, &mut converted);
+    }
+  }
+
+  let ptr = converted.as_ptr() as *const P::RawEncoding;
+  let len = converted.len() / mem::size_of::<P::RawEncoding>();
+
+  // the converted bytes are not necessarily aligned for the raw encoding
+  Ok(
+    (0..len)
+      .map(|i| unsafe { ptr::read_unaligned(ptr.add(i)) })
+      .collect(),
+  )
+}
+
+/// Channel type of a pixel format, for conversions.
+#[derive(Clone, Copy, Debug)]
+struct ConversionChannel {
+  encoding: Type,
+  size: Size,
+}
+
+impl ConversionChannel {
+  fn bytes_len(self) -> usize {
+    self.size.bits_len() / 8
+  }
+
+  /// Largest value of normalized channels.
+  fn norm_max(self) -> f64 {
+    match (self.encoding, self.size) {
+      (Type::NormUnsigned, Size::Eight) => u8::MAX as f64,
+      (Type::NormUnsigned, Size::Sixteen) => u16::MAX as f64,
+      (Type::NormUnsigned, _) => u32::MAX as f64,
+      (_, Size::Eight) => i8::MAX as f64,
+      (_, Size::Sixteen) => i16::MAX as f64,
+      _ => i32::MAX as f64,
+    }
+  }
+
+  /// Decode a channel — normalized, if normalized — out of the first bytes of `bytes`.
+  fn decode(self, bytes: &[u8]) -> f64 {
+    let value = match (self.encoding, self.size) {
+      (Type::Floating, _) => f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+      (Type::NormUnsigned | Type::Unsigned, Size::Eight) => bytes[0] as f64,
+      (Type::NormUnsigned | Type::Unsigned, Size::Sixteen) => {
+        u16::from_ne_bytes([bytes[0], bytes[1]]) as f64
+      }
+      (Type::NormUnsigned | Type::Unsigned, _) => {
+        u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64
+      }
+      (_, Size::Eight) => bytes[0] as i8 as f64,
+      (_, Size::Sixteen) => i16::from_ne_bytes([bytes[0], bytes[1]]) as f64,
+      _ => i32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+    };
+
+    match self.encoding {
+      Type::NormUnsigned | Type::NormIntegral => (value / self.norm_max()).max(-1.),
+      _ => value,
+    }
+  }
+
+  /// Encode a channel — normalized, if normalized — at the end of `bytes`, saturating it.
+  fn encode(self, value: f64, bytes: &mut Vec<u8>) {
+    let value = match self.encoding {
+      Type::NormUnsigned => (value.clamp(0., 1.) * self.norm_max()).round(),
+      Type::NormIntegral => (value.clamp(-1., 1.) * self.norm_max()).round(),
+      _ => value,
+    };
+
+    // float to integer casts saturate
+    match (self.encoding, self.size) {
+      (Type::Floating, _) => bytes.extend_from_slice(&(value as f32).to_ne_bytes()),
+      (Type::NormUnsigned | Type::Unsigned, Size::Eight) => bytes.push(value as u8),
+      (Type::NormUnsigned | Type::Unsigned, Size::Sixteen) => {
+        bytes.extend_from_slice(&(value as u16).to_ne_bytes())
+      }
+      (Type::NormUnsigned | Type::Unsigned, _) => {
+        bytes.extend_from_slice(&(value as u32).to_ne_bytes())
+      }
+      (_, Size::Eight) => bytes.extend_from_slice(&(value as i8).to_ne_bytes()),
+      (_, Size::Sixteen) => bytes.extend_from_slice(&(value as i16).to_ne_bytes()),
+      _ => bytes.extend_from_slice(&(value as i32).to_ne_bytes()),
+    }
+  }
+}
+
+/// Channel type and number of channels of a pixel format, if its texels can be converted.
+///
+/// Only formats which channels all have the same 8-bit, 16-bit or 32-bit type can be converted.
+fn conversion_layout(pf: PixelFormat) -> Option<(ConversionChannel, usize)> {
+  let sizes = match pf.format {
+    Format::R(r) | Format::Depth(r) => vec![r],
+    Format::RG(r, g) => vec![r, g],
+    Format::RGB(r, g, b) | Format::SRGB(r, g, b) => vec![r, g, b],
+    Format::RGBA(r, g, b, a) | Format::SRGBA(r, g, b, a) => vec![r, g, b, a],
+    Format::DepthStencil(..) | Format::Stencil(_) | Format::Compressed(_) => return None,
+  };
+
+  let size = sizes[0];
+  let supported = match pf.encoding {
+    Type::Floating => matches!(size, Size::Sixteen | Size::ThirtyTwo),
+    _ => matches!(size, Size::Eight | Size::Sixteen | Size::ThirtyTwo),
+  };
+
+  if !supported || sizes.iter().any(|&s| s != size) {
+    return None;
+  }
+
+  let channel = ConversionChannel {
+    encoding: pf.encoding,
+    // floating-point channels are encoded as f32, whatever their size
+    size: match pf.encoding {
+      Type::Floating => Size::ThirtyTwo,
+      _ => size,
+    },
+  };
+
+  Some((channel, sizes.len()))
+}
+
+/// Copy the texels of the region made with `offset` and `size` out of the texels of a whole texture of size
+/// `texture_size`.
+///
+/// The region must be in bounds.
+fn crop_texels<D, T>(
+  texels: &[T],
+  texture_size: D::Size,
+  offset: D::Offset,
+  size: D::Size,
+) -> Vec<T>
+where
+  D: Dimensionable,
+  T: Copy,
+{
+  let (tw, th) = (
+    D::width(texture_size) as usize,
+    D::height(texture_size) as usize,
+  );
+  let x = D::x_offset(offset) as usize;
+  let w = D::width(size) as usize;
+
+  // y and z ranges of the region; cubemap regions cover a single face
+  let (y, h, z, d) = match D::dim() {
+    Dim::Dim1 => (0, 1, 0, 1),
+    Dim::Dim2 | Dim::Dim1Array => (D::y_offset(offset), D::height(size), 0, 1),
+    Dim::Cubemap => (D::y_offset(offset), D::height(size), D::z_offset(offset), 1),
+    Dim::Dim3 | Dim::Dim2Array => (
+      D::y_offset(offset),
+      D::height(size),
+      D::z_offset(offset),
+      D::depth(size),
+    ),
+  };
+  let (y, h, z, d) = (y as usize, h as usize, z as usize, d as usize);
+
+  let mut region = Vec::with_capacity(w * h * d);
+
+  for layer in z..z + d {
+    for row in y..y + h {
+      let start = (layer * th + row) * tw + x;
+      region.extend_from_slice(&texels[start..start + w]);
+    }
+  }
+
+  region
+}
+
+/// Whether the region made with `offset` and `size` is in the bounds of the `level` mipmap level of a texture of size
+/// `texture_size`.
+fn region_in_bounds<D>(
+  texture_size: D::Size,
+  level: usize,
+  offset: D::Offset,
+  size: D::Size,
+) -> bool
+where
+  D: Dimensionable,
+{
+  let level_size = D::level_size(texture_size, level);
+  let fits = |off: u32, len: u32, max: u32| off.checked_add(len).map_or(false, |end| end <= max);
+
+  let x = fits(D::x_offset(offset), D::width(size), D::width(level_size));
+  let y = || fits(D::y_offset(offset), D::height(size), D::height(level_size));
+  let z = || fits(D::z_offset(offset), D::depth(size), D::depth(level_size));
+
+  match D::dim() {
+    Dim::Dim1 => x,
+    // regions of cubemaps cover a single face
+    Dim::Dim2 | Dim::Dim1Array | Dim::Cubemap => x && y(),
+    Dim::Dim3 | Dim::Dim2Array => x && y() && z(),
+  }
+}
+
+/// Number of layers a view can select in a texture of size `size`; cubemaps have one layer per face.
+fn view_layers<D>(size: D::Size) -> u32
+where
+  D: Dimensionable,
+{
+  match D::dim() {
+    Dim::Dim1Array => D::height(size),
+    Dim::Dim2Array | Dim::Cubemap => D::depth(size),
+    Dim::Dim1 | Dim::Dim2 | Dim::Dim3 => 1,
+  }
+}
+
+/// Whether a texture of pixel format `texture` can be viewed with the pixel format `view`.
+fn view_compatible(texture: PixelFormat, view: PixelFormat) -> bool {
+  let sized_color = |pf: PixelFormat| pf.is_color_pixel() && pf.format.bytes_len() != 0;
+
+  texture == view
+    || (sized_color(texture)
+      && sized_color(view)
+      && texture.format.bytes_len() == view.format.bytes_len())
+}
+
+/// Check that a sampler can be used with textures of pixel format `P`.
+fn check_sampler<P>(sampler: &Sampler) -> Result<(), TextureError>
+where
+  P: Pixel,
+{
+  let pf = P::pixel_format();
+  let nearest = matches!(
+    sampler.min_filter,
+    MinFilter::Nearest | MinFilter::NearestMipmapNearest
+  ) && sampler.mag_filter == MagFilter::Nearest;
+
+  if pf.is_integral() && !nearest {
+    return Err(TextureError::linear_filtering_on_integral(pf));
+  }
+
+  Ok(())
+}
+
+impl<B, P> Texture<B, Cubemap, P>
+where
+  B: ?Sized + TextureBackend<Cubemap, P>,
+  P: Pixel,
+{
+  /// Upload pixels to a single face of the cubemap.
+  pub fn upload_face(
+    &mut self,
+    face: CubeFace,
+    texels: TexelUpload<[P::Encoding]>,
+  ) -> Result<(), TextureError> {
+    self.upload_part(([0, 0], face), self.size, texels)
+  }
+
+  /// Upload raw data to a single face of the cubemap.
+  pub fn upload_face_raw(
+    &mut self,
+    face: CubeFace,
+    texels: TexelUpload<[P::RawEncoding]>,
+  ) -> Result<(), TextureError> {
+    self.upload_part_raw(([0, 0], face), self.size, texels)
+  }
+}
+
+impl<B, D, P> Texture<B, D, P>
+where
+  B: ?Sized + TextureBackend<D, P>,
+  D: Layerable,
+  P: Pixel,
+{
+  /// Create a new texture array from a list of images of the same size.
+  ///
+  /// `layer_size` is the size of each image; the array has as many layers as `layers` has images. `mipmaps` is the
+  /// number of mipmap levels to generate, as with [`TexelUpload::BaseLevel`].
+  ///
+  /// [`TextureError::LayerSizeMismatch`] is returned if an image doesn’t have exactly the number of texels of
+  /// `layer_size`.
+  pub fn from_layers<C>(
+    ctx: &mut C,
+    layer_size: <D::Layer as Dimensionable>::Size,
+    sampler: Sampler,
+    layers: &[&[P::Encoding]],
+    mipmaps: usize,
+  ) -> Result<Self, TextureError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let expected_texels = D::Layer::count(layer_size);
+    let mut texels = Vec::with_capacity(expected_texels * layers.len());
+
+    for (layer, &layer_texels) in layers.iter().enumerate() {
+      if layer_texels.len() != expected_texels {
+        return Err(TextureError::layer_size_mismatch(
+          layer,
+          expected_texels,
+          layer_texels.len(),
+        ));
+      }
+
+      texels.extend_from_slice(layer_texels);
+    }
+
+    let size = D::layered_size(layer_size, layers.len() as u32);
+    Self::new(
+      ctx,
+      size,
+      sampler,
+      TexelUpload::base_level(&texels, mipmaps),
+    )
+  }
+
+  /// Number of layers of the texture.
+  pub fn layers(&self) -> u32 {
+    D::layers(self.size)
+  }
+
+  /// Upload pixels to a single layer of the texture.
+  pub fn upload_layer(
+    &mut self,
+    layer: u32,
+    texels: TexelUpload<[P::Encoding]>,
+  ) -> Result<(), TextureError> {
+    let (offset, size) = self.layer_region(layer)?;
+    self.upload_part(offset, size, texels)
+  }
+
+  /// Upload raw data to a single layer of the texture.
+  pub fn upload_layer_raw(
+    &mut self,
+    layer: u32,
+    texels: TexelUpload<[P::RawEncoding]>,
+  ) -> Result<(), TextureError> {
+    let (offset, size) = self.layer_region(layer)?;
+    self.upload_part_raw(offset, size, texels)
+  }
+
+  /// Offset and size of a layer, if in bounds.
+  fn layer_region(&self, layer: u32) -> Result<(D::Offset, D::Size), TextureError> {
+    let layers = self.layers();
+
+    if layer >= layers {
+      return Err(TextureError::invalid_layer(layer, layers));
+    }
+
+    let size = D::layered_size(D::layer_size(self.size), 1);
+    Ok((D::layer_offset(layer), size))
+  }
+}
+
+impl<B, P> Texture<B, Dim2, P>
+where
+  B: ?Sized + TextureCompressedBackend<P>,
+  P: CompressedPixel,
+{
+  /// Create a new compressed texture.
+  ///
+  /// `levels` contains the compressed data of each mipmap level, starting with the base level of size `size`; each
+  /// following level is half the size of the previous one. Mipmaps are never generated for compressed textures.
+  ///
+  /// [`TextureError::CompressedSizeMismatch`] is returned if a level doesn’t have exactly the number of bytes
+  /// required by its size — see [`CompressedFormat::compressed_len`].
+  ///
+  /// [`CompressedFormat::compressed_len`]: crate::pixel::CompressedFormat::compressed_len
+  pub fn new_compressed<C>(
+    ctx: &mut C,
+    size: [u32; 2],
+    sampler: Sampler,
+    levels: &[&[u8]],
+  ) -> Result<Self, TextureError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    if levels.is_empty() {
+      return Err(TextureError::texture_storage_creation_failed(
+        "no compressed level provided",
+      ));
+    }
+
+    let format = P::compressed_format();
+
+    for (level, data) in levels.iter().enumerate() {
+      let width = (size[0] >> level).max(1);
+      let height = (size[1] >> level).max(1);
+      let expected_bytes = format.compressed_len(width, height);
+
+      if data.len() != expected_bytes {
+        return Err(TextureError::compressed_size_mismatch(
+          level,
+          expected_bytes,
+          data.len(),
+        ));
+      }
+    }
+
+    unsafe {
+      ctx
+        .backend()
+        .new_compressed_texture(size, sampler, levels)
+        .map(|repr| Texture {
+          repr,
+          size,
+          _phantom: PhantomData,
+        })
+    }
+  }
+
+  /// Upload compressed data to the whole base level of the texture.
+  pub fn upload_compressed(&mut self, data: &[u8]) -> Result<(), TextureError> {
+    self.upload_compressed_part([0, 0], self.size, data)
+  }
+
+  /// Upload compressed data to a region of the base level of the texture described by the rectangle made with `size`
+  /// and `offset`.
+  ///
+  /// The region must be aligned on the blocks of the format: `offset` must be a multiple of the block size, and so
+  /// must be `size`, unless the region reaches the edge of the texture.
+  pub fn upload_compressed_part(
+    &mut self,
+    offset: [u32; 2],
+    size: [u32; 2],
+    data: &[u8],
+  ) -> Result<(), TextureError> {
+    let format = P::compressed_format();
+    let block_size = format.block_size();
+
+    for i in 0..2 {
+      let aligned_offset = offset[i] % block_size[i] == 0;
+      let aligned_size = size[i] % block_size[i] == 0 || offset[i] + size[i] == self.size[i];
+
+      if !aligned_offset || !aligned_size || offset[i] + size[i] > self.size[i] {
+        return Err(TextureError::cannot_upload_texels(format!(
+          "region at {:?} of size {:?} is not aligned on {:?} blocks within the texture",
+          offset, size, block_size
+        )));
+      }
+    }
+
+    let expected_bytes = format.compressed_len(size[0], size[1]);
+
+    if data.len() != expected_bytes {
+      return Err(TextureError::compressed_size_mismatch(
+        0,
+        expected_bytes,
+        data.len(),
+      ));
+    }
+
+    unsafe { B::upload_compressed_part(&mut self.repr, offset, size, data) }
+  }
 }
@@ -0,0 +1,1106 @@
+//! Texture containers.
+//!
+//! Textures are usually shipped in _containers_, which store their texels in a GPU-ready pixel format — compressed or
+//! not — along with their whole mipmap chain. This module parses the most common ones — [KTX], [KTX2] and [DDS] — into
+//! a [`TextureContainer`] with [`TextureContainer::parse`], and uploads them directly to textures with
+//! [`Texture::from_container`] and, for compressed formats, [`Texture::from_compressed_container`].
+//!
+//! The pixel format of a container is mapped to one of the [`PixelFormat`]s of luminance; the [`Pixel`] type of the
+//! texture to create must have that very format. Containers using other formats — such as 16-bit floating-point ones,
+//! BGRA orderings or supercompressed KTX2 files — cannot be parsed, and neither can cubemap arrays nor 3D arrays.
+//!
+//! This module requires the `container` feature.
+//!
+//! [KTX]: https://registry.khronos.org/KTX/specs/1.0/ktxspec.v1.html
+//! [KTX2]: https://registry.khronos.org/KTX/specs/2.0/ktxspec.v2.html
+//! [DDS]: https://learn.microsoft.com/en-us/windows/win32/direct3ddds/dx-graphics-dds-pguide
+
+use crate::{
+  backend::texture::{Texture as TextureBackend, TextureCompressed as TextureCompressedBackend},
+  context::GraphicsContext,
+  pixel::{
+    div_ceil, ASTCBlock, CompressedFormat, CompressedPixel, Format, NormR8I, NormR8UI, NormRG8I,
+    NormRG8UI, NormRGB8UI, NormRGBA8I, NormRGBA8UI, Pixel, PixelFormat, Type, R11G11B10F, R16I,
+    R16UI, R32F, R32I, R32UI, R8I, R8UI, RG32F, RGB32F, RGBA32F, RGBA32I, RGBA32UI, RGBA8I,
+    RGBA8UI, SRGB8UI, SRGBA8UI,
+  },
+  texture::{
+    CubeFace, Cubemap, Dim, Dim1, Dim1Array, Dim2, Dim2Array, Dim3, Dimensionable, Sampler,
+    TexelUpload, Texture, TextureError,
+  },
+};
+use std::{convert::TryInto, error, fmt, mem, ptr};
+
+/// Errors that might occur when parsing or loading texture containers.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContainerError {
+  /// The data is not a KTX, KTX2 nor DDS container.
+  UnknownContainer,
+  /// The container is malformed or truncated.
+  InvalidContainer(String),
+  /// The pixel format of the container is not supported.
+  UnsupportedFormat(String),
+  /// The layout of the container — its dimension, number of layers, etc. — is not supported.
+  UnsupportedLayout(String),
+  /// The dimension of the container is not the one of the texture.
+  DimMismatch {
+    /// Dimension of the container.
+    container: Dim,
+    /// Dimension of the texture.
+    texture: Dim,
+  },
+  /// The pixel format of the container is not the one of the texture.
+  PixelFormatMismatch {
+    /// Pixel format of the container.
+    container: PixelFormat,
+    /// Pixel format of the texture.
+    texture: PixelFormat,
+  },
+  /// Error while creating or uploading to the texture.
+  TextureError(TextureError),
+}
+
+impl ContainerError {
+  /// The container is malformed or truncated.
+  pub fn invalid_container(reason: impl Into<String>) -> Self {
+    ContainerError::InvalidContainer(reason.into())
+  }
+
+  /// The pixel format of the container is not supported.
+  pub fn unsupported_format(reason: impl Into<String>) -> Self {
+    ContainerError::UnsupportedFormat(reason.into())
+  }
+
+  /// The layout of the container is not supported.
+  pub fn unsupported_layout(reason: impl Into<String>) -> Self {
+    ContainerError::UnsupportedLayout(reason.into())
+  }
+
+  /// The dimension of the container is not the one of the texture.
+  pub fn dim_mismatch(container: Dim, texture: Dim) -> Self {
+    ContainerError::DimMismatch { container, texture }
+  }
+
+  /// The pixel format of the container is not the one of the texture.
+  pub fn pixel_format_mismatch(container: PixelFormat, texture: PixelFormat) -> Self {
+    ContainerError::PixelFormatMismatch { container, texture }
+  }
+
+  /// Error while creating or uploading to the texture.
+  pub fn texture_error(e: TextureError) -> Self {
+    ContainerError::TextureError(e)
+  }
+}
+
+impl fmt::Display for ContainerError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      ContainerError::UnknownContainer => f.write_str("unknown texture container"),
+
+      ContainerError::InvalidContainer(ref reason) => {
+        write!(f, "invalid texture container: {}", reason)
+      }
+
+      ContainerError::UnsupportedFormat(ref reason) => {
+        write!(f, "unsupported texture container pixel format: {}", reason)
+      }
+
+      ContainerError::UnsupportedLayout(ref reason) => {
+        write!(f, "unsupported texture container layout: {}", reason)
+      }
+
+      ContainerError::DimMismatch { container, texture } => write!(
+        f,
+        "texture container dimension mismatch: container is {}, texture is {}",
+        container, texture
+      ),
+
+      ContainerError::PixelFormatMismatch { container, texture } => write!(
+        f,
+        "texture container pixel format mismatch: container is {:?}, texture is {:?}",
+        container, texture
+      ),
+
+      ContainerError::TextureError(ref e) => write!(f, "texture container error: {}", e),
+    }
+  }
+}
+
+impl error::Error for ContainerError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      ContainerError::TextureError(e) => Some(e),
+      _ => None,
+    }
+  }
+}
+
+impl From<TextureError> for ContainerError {
+  fn from(e: TextureError) -> Self {
+    ContainerError::TextureError(e)
+  }
+}
+
+/// A parsed KTX, KTX2 or DDS texture container.
+///
+/// Each mipmap level holds the tightly packed texels of all the images of the level, layer by layer and, for cubemaps,
+/// face by face, in the [`CubeFace`] order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TextureContainer {
+  pixel_format: PixelFormat,
+  dim: Dim,
+  width: u32,
+  height: u32,
+  depth: u32,
+  layers: u32,
+  levels: Vec<Vec<u8>>,
+}
+
+impl TextureContainer {
+  /// Parse a KTX, KTX2 or DDS container, detected by its magic number.
+  pub fn parse(bytes: &[u8]) -> Result<Self, ContainerError> {
+    if bytes.starts_with(&KTX_IDENTIFIER) {
+      parse_ktx(bytes)
+    } else if bytes.starts_with(&KTX2_IDENTIFIER) {
+      parse_ktx2(bytes)
+    } else if bytes.starts_with(DDS_MAGIC) {
+      parse_dds(bytes)
+    } else {
+      Err(ContainerError::UnknownContainer)
+    }
+  }
+
+  /// Pixel format of the texels.
+  pub fn pixel_format(&self) -> PixelFormat {
+    self.pixel_format
+  }
+
+  /// Dimension of the texture.
+  pub fn dim(&self) -> Dim {
+    self.dim
+  }
+
+  /// Width of the base level.
+  pub fn width(&self) -> u32 {
+    self.width
+  }
+
+  /// Height of the base level; `1` for 1D textures.
+  pub fn height(&self) -> u32 {
+    self.height
+  }
+
+  /// Depth of the base level; `1` for non-3D textures.
+  pub fn depth(&self) -> u32 {
+    self.depth
+  }
+
+  /// Number of layers; `1` for non-array textures.
+  pub fn layers(&self) -> u32 {
+    self.layers
+  }
+
+  /// Number of mipmap levels, including the base level.
+  pub fn levels(&self) -> usize {
+    self.levels.len()
+  }
+
+  /// Texels of the `level` mipmap level, if any.
+  pub fn level(&self, level: usize) -> Option<&[u8]> {
+    self.levels.get(level).map(Vec::as_slice)
+  }
+}
+
+/// Dimensions textures can be loaded from containers with.
+pub trait ContainerDimensionable: Dimensionable {
+  /// Number of faces of each image; `6` for cubemaps, `1` otherwise.
+  const FACES: usize = 1;
+
+  /// Size of a texture of this dimension holding the base level of `container`.
+  fn container_size(container: &TextureContainer) -> Self::Size;
+
+  /// Offset of the `face` face of a mipmap level.
+  fn face_offset(_face: usize) -> Self::Offset {
+    Self::ZERO_OFFSET
+  }
+}
+
+impl ContainerDimensionable for Dim1 {
+  fn container_size(container: &TextureContainer) -> Self::Size {
+    container.width
+  }
+}
+
+impl ContainerDimensionable for Dim2 {
+  fn container_size(container: &TextureContainer) -> Self::Size {
+    [container.width, container.height]
+  }
+}
+
+impl ContainerDimensionable for Dim3 {
+  fn container_size(container: &TextureContainer) -> Self::Size {
+    [container.width, container.height, container.depth]
+  }
+}
+
+impl ContainerDimensionable for Cubemap {
+  const FACES: usize = 6;
+
+  fn container_size(container: &TextureContainer) -> Self::Size {
+    container.width
+  }
+
+  fn face_offset(face: usize) -> Self::Offset {
+    const FACES: [CubeFace; 6] = [
+      CubeFace::PositiveX,
+      CubeFace::NegativeX,
+      CubeFace::PositiveY,
+      CubeFace::NegativeY,
+      CubeFace::PositiveZ,
+      CubeFace::NegativeZ,
+    ];
+
+    ([0, 0], FACES[face])
+  }
+}
+
+impl ContainerDimensionable for Dim1Array {
+  fn container_size(container: &TextureContainer) -> Self::Size {
+    (container.width, container.layers)
+  }
+}
+
+impl ContainerDimensionable for Dim2Array {
+  fn container_size(container: &TextureContainer) -> Self::Size {
+    ([container.width, container.height], container.layers)
+  }
+}
+
+impl<B, D, P> Texture<B, D, P>
+where
+  B: ?Sized + TextureBackend<D, P>,
+  D: ContainerDimensionable,
+  P: Pixel,
+{
+  /// Create a new [`Texture`] out of an uncompressed [`TextureContainer`], with all its mipmap levels.
+  ///
+  /// [`ContainerError::DimMismatch`] and [`ContainerError::PixelFormatMismatch`] are returned if the container doesn’t
+  /// have the dimension or the pixel format of the texture. Compressed containers must be loaded with
+  /// [`Texture::from_compressed_container`] instead.
+  pub fn from_container<C>(
+    ctx: &mut C,
+    container: &TextureContainer,
+    sampler: Sampler,
+  ) -> Result<Self, ContainerError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    if let Format::Compressed(format) = container.pixel_format.format {
+      return Err(ContainerError::unsupported_format(format!(
+        "{:?} is compressed and must be loaded as a compressed texture",
+        format
+      )));
+    }
+
+    check_container::<D, P>(container)?;
+
+    let size = D::container_size(container);
+    let mut texture = Self::new_raw(
+      ctx,
+      size,
+      sampler,
+      TexelUpload::reserve(container.levels() - 1),
+    )?;
+
+    for (level, data) in container.levels.iter().enumerate() {
+      let level_size = D::level_size(size, level);
+      let face_len = data.len() / D::FACES;
+
+      for (face, face_data) in data.chunks(face_len).enumerate() {
+        let texels = raw_texels::<P::RawEncoding>(face_data);
+        texture.upload_part_level_raw(D::face_offset(face), level_size, level, &texels)?;
+      }
+    }
+
+    Ok(texture)
+  }
+}
+
+impl<B, P> Texture<B, Dim2, P>
+where
+  B: ?Sized + TextureCompressedBackend<P>,
+  P: CompressedPixel,
+{
+  /// Create a new compressed [`Texture`] out of a 2D [`TextureContainer`], with all its mipmap levels.
+  ///
+  /// [`ContainerError::DimMismatch`] and [`ContainerError::PixelFormatMismatch`] are returned if the container doesn’t
+  /// have the dimension or the pixel format of the texture.
+  pub fn from_compressed_container<C>(
+    ctx: &mut C,
+    container: &TextureContainer,
+    sampler: Sampler,
+  ) -> Result<Self, ContainerError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    check_container::<Dim2, P>(container)?;
+
+    let levels: Vec<&[u8]> = container.levels.iter().map(Vec::as_slice).collect();
+    let texture = Self::new_compressed(ctx, [container.width, container.height], sampler, &levels)?;
+
+    Ok(texture)
+  }
+}
+
+/// Check that a container can be loaded in a texture of dimension `D` and pixel format `P`.
+fn check_container<D, P>(container: &TextureContainer) -> Result<(), ContainerError>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  if container.dim != D::dim() {
+    return Err(ContainerError::dim_mismatch(container.dim, D::dim()));
+  }
+
+  if container.pixel_format != P::pixel_format() {
+    return Err(ContainerError::pixel_format_mismatch(
+      container.pixel_format,
+      P::pixel_format(),
+    ));
+  }
+
+  Ok(())
+}
+
+/// Reinterpret little-endian bytes as raw texels.
+fn raw_texels<T>(bytes: &[u8]) -> Vec<T>
+where
+  T: Copy,
+{
+  let len = bytes.len() / mem::size_of::<T>();
+  let ptr = bytes.as_ptr() as *const T;
+
+  // the bytes are not necessarily aligned for T
+  (0..len)
+    .map(|i| unsafe { ptr::read_unaligned(ptr.add(i)) })
+    .collect()
+}
+
+const KTX_IDENTIFIER: [u8; 12] = [
+  0xAB, 0x4B, 0x54, 0x58, 0x20, 0x31, 0x31, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+const KTX2_IDENTIFIER: [u8; 12] = [
+  0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+const DDS_MAGIC: &[u8] = b"DDS ";
+
+const ASTC_BLOCKS: [ASTCBlock; 14] = [
+  ASTCBlock::Block4x4,
+  ASTCBlock::Block5x4,
+  ASTCBlock::Block5x5,
+  ASTCBlock::Block6x5,
+  ASTCBlock::Block6x6,
+  ASTCBlock::Block8x5,
+  ASTCBlock::Block8x6,
+  ASTCBlock::Block8x8,
+  ASTCBlock::Block10x5,
+  ASTCBlock::Block10x6,
+  ASTCBlock::Block10x8,
+  ASTCBlock::Block10x10,
+  ASTCBlock::Block12x10,
+  ASTCBlock::Block12x12,
+];
+
+/// Layout of the images of a container.
+struct Layout {
+  pixel_format: PixelFormat,
+  dim: Dim,
+  width: u32,
+  height: u32,
+  depth: u32,
+  layers: u32,
+}
+
+impl Layout {
+  /// Validate a layout; `height`, `depth` and `layers` must be `1` when the dimension doesn’t have them.
+  fn new(
+    pixel_format: PixelFormat,
+    dim: Dim,
+    [width, height, depth]: [u32; 3],
+    layers: u32,
+    levels: usize,
+  ) -> Result<Self, ContainerError> {
+    if width == 0 || height == 0 || depth == 0 || layers == 0 {
+      return Err(ContainerError::invalid_container("empty image"));
+    }
+
+    if dim == Dim::Cubemap && width != height {
+      return Err(ContainerError::invalid_container(format!(
+        "cubemap faces are not square: {}×{}",
+        width, height
+      )));
+    }
+
+    let max_levels = 32 - width.max(height).max(depth).leading_zeros() as usize;
+    if levels > max_levels {
+      return Err(ContainerError::invalid_container(format!(
+        "{} mipmap levels for at most {}",
+        levels, max_levels
+      )));
+    }
+
+    Ok(Layout {
+      pixel_format,
+      dim,
+      width,
+      height,
+      depth,
+      layers,
+    })
+  }
+
+  /// Number of faces of each image.
+  fn faces(&self) -> usize {
+    if self.dim == Dim::Cubemap {
+      6
+    } else {
+      1
+    }
+  }
+
+  /// Number of images — faces of layers — of each mipmap level.
+  fn images(&self) -> usize {
+    self.layers as usize * self.faces()
+  }
+
+  /// Width, height and depth of the `level` mipmap level.
+  fn level_size(&self, level: usize) -> [u32; 3] {
+    let len = |len: u32| len.checked_shr(level as u32).unwrap_or(0).max(1);
+    [len(self.width), len(self.height), len(self.depth)]
+  }
+
+  /// Number of bytes of a row of the `level` mipmap level; a row of blocks for compressed formats.
+  fn row_len(&self, level: usize) -> Result<usize, ContainerError> {
+    let [width, _, _] = self.level_size(level);
+
+    let (items, item_len) = match self.pixel_format.format {
+      Format::Compressed(format) => (
+        div_ceil(width, format.block_size()[0]),
+        format.block_bytes(),
+      ),
+      format => (width, format.bytes_len()),
+    };
+
+    (items as usize)
+      .checked_mul(item_len)
+      .ok_or_else(|| ContainerError::invalid_container("image too large"))
+  }
+
+  /// Number of rows of an image of the `level` mipmap level.
+  fn rows(&self, level: usize) -> Result<usize, ContainerError> {
+    let [_, height, depth] = self.level_size(level);
+
+    let rows = match self.pixel_format.format {
+      Format::Compressed(format) => div_ceil(height, format.block_size()[1]),
+      _ => height,
+    };
+
+    (rows as usize)
+      .checked_mul(depth as usize)
+      .ok_or_else(|| ContainerError::invalid_container("image too large"))
+  }
+
+  /// Number of bytes of an image of the `level` mipmap level.
+  fn image_len(&self, level: usize) -> Result<usize, ContainerError> {
+    self
+      .row_len(level)?
+      .checked_mul(self.rows(level)?)
+      .ok_or_else(|| ContainerError::invalid_container("image too large"))
+  }
+
+  /// Number of bytes of the `level` mipmap level.
+  fn level_len(&self, level: usize) -> Result<usize, ContainerError> {
+    self
+      .image_len(level)?
+      .checked_mul(self.images())
+      .ok_or_else(|| ContainerError::invalid_container("image too large"))
+  }
+
+  fn into_container(self, levels: Vec<Vec<u8>>) -> TextureContainer {
+    TextureContainer {
+      pixel_format: self.pixel_format,
+      dim: self.dim,
+      width: self.width,
+      height: self.height,
+      depth: self.depth,
+      layers: self.layers,
+      levels,
+    }
+  }
+}
+
+/// Dimension of a container out of the features of its images.
+fn container_dim(
+  height: bool,
+  depth: bool,
+  cubemap: bool,
+  array: bool,
+) -> Result<Dim, ContainerError> {
+  match (height, depth, cubemap, array) {
+    (false, false, false, false) => Ok(Dim::Dim1),
+    (false, false, false, true) => Ok(Dim::Dim1Array),
+    (true, false, false, false) => Ok(Dim::Dim2),
+    (true, false, false, true) => Ok(Dim::Dim2Array),
+    (true, true, false, false) => Ok(Dim::Dim3),
+    (true, false, true, false) => Ok(Dim::Cubemap),
+    (true, false, true, true) => Err(ContainerError::unsupported_layout("cubemap array")),
+    (true, true, _, true) => Err(ContainerError::unsupported_layout("3D array")),
+    _ => Err(ContainerError::invalid_container("inconsistent dimension")),
+  }
+}
+
+fn read_bytes(bytes: &[u8], offset: usize, len: usize) -> Result<&[u8], ContainerError> {
+  offset
+    .checked_add(len)
+    .and_then(|end| bytes.get(offset..end))
+    .ok_or_else(|| ContainerError::invalid_container("truncated data"))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, ContainerError> {
+  let bytes = read_bytes(bytes, offset, 4)?;
+  Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Result<usize, ContainerError> {
+  let bytes = read_bytes(bytes, offset, 8)?;
+  u64::from_le_bytes(bytes.try_into().unwrap())
+    .try_into()
+    .map_err(|_| ContainerError::invalid_container("offset too large"))
+}
+
+/// Parse a KTX container.
+fn parse_ktx(bytes: &[u8]) -> Result<TextureContainer, ContainerError> {
+  if read_u32(bytes, 12)? != 0x04030201 {
+    return Err(ContainerError::unsupported_layout(
+      "big-endian KTX container",
+    ));
+  }
+
+  let internal_format = read_u32(bytes, 28)?;
+  let pixel_format = gl_pixel_format(internal_format).ok_or_else(|| {
+    ContainerError::unsupported_format(format!("GL internal format {:#x}", internal_format))
+  })?;
+
+  let width = read_u32(bytes, 36)?;
+  let height = read_u32(bytes, 40)?;
+  let depth = read_u32(bytes, 44)?;
+  let layers = read_u32(bytes, 48)?;
+  let faces = read_u32(bytes, 52)?;
+  let levels = read_u32(bytes, 56)?.max(1) as usize;
+  let key_values_len = read_u32(bytes, 60)? as usize;
+
+  if faces != 1 && faces != 6 {
+    return Err(ContainerError::invalid_container(format!(
+      "{} faces",
+      faces
+    )));
+  }
+
+  let dim = container_dim(height != 0, depth != 0, faces == 6, layers != 0)?;
+  let layout = Layout::new(
+    pixel_format,
+    dim,
+    [width, height.max(1), depth.max(1)],
+    layers.max(1),
+    levels,
+  )?;
+
+  // non-array cubemaps store the size of a single face and pad each face; everything else stores the size of the whole
+  // level
+  let chunks = if dim == Dim::Cubemap { 6 } else { 1 };
+  let mut offset = 64usize
+    .checked_add(key_values_len)
+    .ok_or_else(|| ContainerError::invalid_container("truncated data"))?;
+  let mut level_data = Vec::with_capacity(levels);
+
+  for level in 0..levels {
+    let chunk_len = read_u32(bytes, offset)? as usize;
+    offset += 4;
+
+    // check the length against the input before allocating, as it comes from the header
+    let len = chunk_len
+      .checked_mul(chunks)
+      .filter(|&len| len <= bytes.len().saturating_sub(offset))
+      .ok_or_else(|| ContainerError::invalid_container("truncated data"))?;
+
+    let mut padded = Vec::with_capacity(len);
+    for _ in 0..chunks {
+      padded.extend_from_slice(read_bytes(bytes, offset, chunk_len)?);
+      offset = align4(offset + chunk_len);
+    }
+
+    level_data.push(unpad_rows(&layout, level, &padded)?);
+  }
+
+  Ok(layout.into_container(level_data))
+}
+
+/// Round up to a multiple of 4.
+fn align4(len: usize) -> usize {
+  (len + 3) & !3
+}
+
+/// Remove the padding of the rows of uncompressed KTX images, which are aligned on 4 bytes.
+fn unpad_rows(layout: &Layout, level: usize, padded: &[u8]) -> Result<Vec<u8>, ContainerError> {
+  let row_len = layout.row_len(level)?;
+  let padded_row_len = match layout.pixel_format.format {
+    Format::Compressed(_) => row_len,
+    _ => align4(row_len),
+  };
+  let rows = layout.rows(level)?.checked_mul(layout.images());
+
+  if rows.and_then(|rows| padded_row_len.checked_mul(rows)) != Some(padded.len()) {
+    return Err(ContainerError::invalid_container(format!(
+      "mipmap level {} has {} bytes",
+      level,
+      padded.len()
+    )));
+  }
+
+  if padded_row_len == row_len {
+    return Ok(padded.to_owned());
+  }
+
+  Ok(
+    padded
+      .chunks(padded_row_len)
+      .flat_map(|row| &row[..row_len])
+      .copied()
+      .collect(),
+  )
+}
+
+/// Parse a KTX2 container.
+fn parse_ktx2(bytes: &[u8]) -> Result<TextureContainer, ContainerError> {
+  let vk_format = read_u32(bytes, 12)?;
+  let width = read_u32(bytes, 20)?;
+  let height = read_u32(bytes, 24)?;
+  let depth = read_u32(bytes, 28)?;
+  let layers = read_u32(bytes, 32)?;
+  let faces = read_u32(bytes, 36)?;
+  let levels = read_u32(bytes, 40)?.max(1) as usize;
+  let supercompression = read_u32(bytes, 44)?;
+
+  if supercompression != 0 {
+    return Err(ContainerError::unsupported_layout(format!(
+      "supercompression scheme {}",
+      supercompression
+    )));
+  }
+
+  let pixel_format = vk_pixel_format(vk_format)
+    .ok_or_else(|| ContainerError::unsupported_format(format!("Vulkan format {}", vk_format)))?;
+
+  if faces != 1 && faces != 6 {
+    return Err(ContainerError::invalid_container(format!(
+      "{} faces",
+      faces
+    )));
+  }
+
+  let dim = container_dim(height != 0, depth != 0, faces == 6, layers != 0)?;
+  let layout = Layout::new(
+    pixel_format,
+    dim,
+    [width, height.max(1), depth.max(1)],
+    layers.max(1),
+    levels,
+  )?;
+
+  let level_data = (0..levels)
+    .map(|level| {
+      let index = 80 + level * 24;
+      let offset = read_u64(bytes, index)?;
+      let len = read_u64(bytes, index + 8)?;
+
+      if len != layout.level_len(level)? {
+        return Err(ContainerError::invalid_container(format!(
+          "mipmap level {} has {} bytes",
+          level, len
+        )));
+      }
+
+      read_bytes(bytes, offset, len).map(<[u8]>::to_owned)
+    })
+    .collect::<Result<_, _>>()?;
+
+  Ok(layout.into_container(level_data))
+}
+
+const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+const DDPF_ALPHAPIXELS: u32 = 0x1;
+const DDPF_FOURCC: u32 = 0x4;
+const DDPF_RGB: u32 = 0x40;
+const DDPF_LUMINANCE: u32 = 0x20000;
+const DDSCAPS2_CUBEMAP: u32 = 0x200;
+const DDSCAPS2_CUBEMAP_ALL_FACES: u32 = 0xFC00;
+const DDSCAPS2_VOLUME: u32 = 0x200000;
+const D3D10_RESOURCE_DIMENSION_TEXTURE1D: u32 = 2;
+const D3D10_RESOURCE_DIMENSION_TEXTURE2D: u32 = 3;
+const D3D10_RESOURCE_DIMENSION_TEXTURE3D: u32 = 4;
+const D3D10_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+/// Parse a DDS container.
+fn parse_dds(bytes: &[u8]) -> Result<TextureContainer, ContainerError> {
+  if read_u32(bytes, 4)? != 124 {
+    return Err(ContainerError::invalid_container("invalid DDS header size"));
+  }
+
+  let flags = read_u32(bytes, 8)?;
+  let height = read_u32(bytes, 12)?;
+  let width = read_u32(bytes, 16)?;
+  let depth = read_u32(bytes, 24)?;
+  let levels = if flags & DDSD_MIPMAPCOUNT != 0 {
+    read_u32(bytes, 28)?.max(1) as usize
+  } else {
+    1
+  };
+  let pf_flags = read_u32(bytes, 80)?;
+  let four_cc = read_bytes(bytes, 84, 4)?;
+  let caps2 = read_u32(bytes, 112)?;
+
+  let (layout, data_offset) = if pf_flags & DDPF_FOURCC != 0 && four_cc == b"DX10" {
+    let dxgi_format = read_u32(bytes, 128)?;
+    let resource_dim = read_u32(bytes, 132)?;
+    let misc_flags = read_u32(bytes, 136)?;
+    let array_size = read_u32(bytes, 140)?.max(1);
+
+    let pixel_format = dxgi_pixel_format(dxgi_format)
+      .ok_or_else(|| ContainerError::unsupported_format(format!("DXGI format {}", dxgi_format)))?;
+
+    let cubemap = misc_flags & D3D10_RESOURCE_MISC_TEXTURECUBE != 0;
+    let dim = match resource_dim {
+      D3D10_RESOURCE_DIMENSION_TEXTURE1D => container_dim(false, false, false, array_size > 1)?,
+      D3D10_RESOURCE_DIMENSION_TEXTURE2D => container_dim(true, false, cubemap, array_size > 1)?,
+      D3D10_RESOURCE_DIMENSION_TEXTURE3D => container_dim(true, true, false, array_size > 1)?,
+      _ => {
+        return Err(ContainerError::invalid_container(format!(
+          "resource dimension {}",
+          resource_dim
+        )))
+      }
+    };
+    let height = if dim == Dim::Dim1 || dim == Dim::Dim1Array {
+      1
+    } else {
+      height
+    };
+    let depth = if dim == Dim::Dim3 { depth } else { 1 };
+
+    let layout = Layout::new(
+      pixel_format,
+      dim,
+      [width, height, depth],
+      array_size,
+      levels,
+    )?;
+    (layout, 148)
+  } else {
+    let pixel_format = legacy_dds_pixel_format(bytes, pf_flags, four_cc)?;
+
+    let dim = if caps2 & DDSCAPS2_VOLUME != 0 {
+      Dim::Dim3
+    } else if caps2 & DDSCAPS2_CUBEMAP != 0 {
+      if caps2 & DDSCAPS2_CUBEMAP_ALL_FACES != DDSCAPS2_CUBEMAP_ALL_FACES {
+        return Err(ContainerError::unsupported_layout("partial cubemap"));
+      }
+
+      Dim::Cubemap
+    } else {
+      Dim::Dim2
+    };
+    let depth = if dim == Dim::Dim3 { depth } else { 1 };
+
+    let layout = Layout::new(pixel_format, dim, [width, height, depth], 1, levels)?;
+    (layout, 128)
+  };
+
+  // check the length of the images against the input before allocating, as it comes from the header
+  let data_len = (0..levels).try_fold(0usize, |len, level| {
+    layout
+      .level_len(level)?
+      .checked_add(len)
+      .ok_or_else(|| ContainerError::invalid_container("image too large"))
+  })?;
+
+  if data_len > bytes.len().saturating_sub(data_offset) {
+    return Err(ContainerError::invalid_container("truncated data"));
+  }
+
+  // DDS containers store the whole mipmap chain of an image before the next image
+  let mut level_data = (0..levels)
+    .map(|level| layout.level_len(level).map(Vec::with_capacity))
+    .collect::<Result<Vec<_>, _>>()?;
+  let mut offset = data_offset;
+
+  for _ in 0..layout.images() {
+    for (level, data) in level_data.iter_mut().enumerate() {
+      let image_len = layout.image_len(level)?;
+      data.extend_from_slice(read_bytes(bytes, offset, image_len)?);
+      offset += image_len;
+    }
+  }
+
+  Ok(layout.into_container(level_data))
+}
+
+/// Pixel format of a DDS container without the DX10 header.
+fn legacy_dds_pixel_format(
+  bytes: &[u8],
+  pf_flags: u32,
+  four_cc: &[u8],
+) -> Result<PixelFormat, ContainerError> {
+  if pf_flags & DDPF_FOURCC != 0 {
+    let pixel_format = match four_cc {
+      b"DXT1" => Some(compressed(CompressedFormat::BC1RGBA)),
+      b"DXT2" | b"DXT3" => Some(compressed(CompressedFormat::BC2RGBA)),
+      b"DXT4" | b"DXT5" => Some(compressed(CompressedFormat::BC3RGBA)),
+      b"ATI1" | b"BC4U" => Some(compressed(CompressedFormat::BC4R)),
+      b"BC4S" => Some(compressed(CompressedFormat::BC4RSigned)),
+      b"ATI2" | b"BC5U" => Some(compressed(CompressedFormat::BC5RG)),
+      b"BC5S" => Some(compressed(CompressedFormat::BC5RGSigned)),
+      // D3DFMT_R32F, D3DFMT_G32R32F and D3DFMT_A32B32G32R32F
+      [114, 0, 0, 0] => Some(R32F::pixel_format()),
+      [115, 0, 0, 0] => Some(RG32F::pixel_format()),
+      [116, 0, 0, 0] => Some(RGBA32F::pixel_format()),
+      _ => None,
+    };
+
+    return pixel_format
+      .ok_or_else(|| ContainerError::unsupported_format(format!("DDS FourCC {:?}", four_cc)));
+  }
+
+  let bits = read_u32(bytes, 88)?;
+  let masks = [
+    read_u32(bytes, 92)?,
+    read_u32(bytes, 96)?,
+    read_u32(bytes, 100)?,
+    read_u32(bytes, 104)?,
+  ];
+  let alpha = pf_flags & DDPF_ALPHAPIXELS != 0;
+
+  let pixel_format = if pf_flags & DDPF_RGB != 0 {
+    match (bits, masks, alpha) {
+      (32, [0xFF, 0xFF00, 0xFF0000, 0xFF000000], true) => Some(NormRGBA8UI::pixel_format()),
+      (24, [0xFF, 0xFF00, 0xFF0000, _], false) => Some(NormRGB8UI::pixel_format()),
+      _ => None,
+    }
+  } else if pf_flags & DDPF_LUMINANCE != 0 {
+    match (bits, masks[0], alpha) {
+      (8, 0xFF, false) => Some(NormR8UI::pixel_format()),
+      _ => None,
+    }
+  } else {
+    None
+  };
+
+  pixel_format.ok_or_else(|| {
+    ContainerError::unsupported_format(format!(
+      "DDS pixel format of {} bits with masks {:#x?}",
+      bits, masks
+    ))
+  })
+}
+
+/// Pixel format of a compressed format.
+fn compressed(format: CompressedFormat) -> PixelFormat {
+  let encoding = match format {
+    CompressedFormat::BC4RSigned
+    | CompressedFormat::BC5RGSigned
+    | CompressedFormat::EACRSigned
+    | CompressedFormat::EACRGSigned => Type::NormIntegral,
+    CompressedFormat::BC6HRGBUnsigned | CompressedFormat::BC6HRGBSigned => Type::Floating,
+    _ => Type::NormUnsigned,
+  };
+
+  PixelFormat {
+    encoding,
+    format: Format::Compressed(format),
+  }
+}
+
+/// Pixel format of a GL internal format, as used by KTX containers.
+fn gl_pixel_format(internal_format: u32) -> Option<PixelFormat> {
+  let pf = match internal_format {
+    0x8229 => NormR8UI::pixel_format(),    // GL_R8
+    0x8F94 => NormR8I::pixel_format(),     // GL_R8_SNORM
+    0x8232 => R8UI::pixel_format(),        // GL_R8UI
+    0x8231 => R8I::pixel_format(),         // GL_R8I
+    0x822B => NormRG8UI::pixel_format(),   // GL_RG8
+    0x8F95 => NormRG8I::pixel_format(),    // GL_RG8_SNORM
+    0x8051 => NormRGB8UI::pixel_format(),  // GL_RGB8
+    0x8C41 => SRGB8UI::pixel_format(),     // GL_SRGB8
+    0x8058 => NormRGBA8UI::pixel_format(), // GL_RGBA8
+    0x8F97 => NormRGBA8I::pixel_format(),  // GL_RGBA8_SNORM
+    0x8C43 => SRGBA8UI::pixel_format(),    // GL_SRGB8_ALPHA8
+    0x8D7C => RGBA8UI::pixel_format(),     // GL_RGBA8UI
+    0x8D8E => RGBA8I::pixel_format(),      // GL_RGBA8I
+    0x8234 => R16UI::pixel_format(),       // GL_R16UI
+    0x8233 => R16I::pixel_format(),        // GL_R16I
+    0x8236 => R32UI::pixel_format(),       // GL_R32UI
+    0x8235 => R32I::pixel_format(),        // GL_R32I
+    0x822E => R32F::pixel_format(),        // GL_R32F
+    0x8230 => RG32F::pixel_format(),       // GL_RG32F
+    0x8815 => RGB32F::pixel_format(),      // GL_RGB32F
+    0x8814 => RGBA32F::pixel_format(),     // GL_RGBA32F
+    0x8D70 => RGBA32UI::pixel_format(),    // GL_RGBA32UI
+    0x8D82 => RGBA32I::pixel_format(),     // GL_RGBA32I
+    0x8C3A => R11G11B10F::pixel_format(),  // GL_R11F_G11F_B10F
+    0x83F1 => compressed(CompressedFormat::BC1RGBA),
+    0x8C4D => compressed(CompressedFormat::BC1SRGBA),
+    0x83F2 => compressed(CompressedFormat::BC2RGBA),
+    0x8C4E => compressed(CompressedFormat::BC2SRGBA),
+    0x83F3 => compressed(CompressedFormat::BC3RGBA),
+    0x8C4F => compressed(CompressedFormat::BC3SRGBA),
+    0x8DBB => compressed(CompressedFormat::BC4R),
+    0x8DBC => compressed(CompressedFormat::BC4RSigned),
+    0x8DBD => compressed(CompressedFormat::BC5RG),
+    0x8DBE => compressed(CompressedFormat::BC5RGSigned),
+    0x8E8F => compressed(CompressedFormat::BC6HRGBUnsigned),
+    0x8E8E => compressed(CompressedFormat::BC6HRGBSigned),
+    0x8E8C => compressed(CompressedFormat::BC7RGBA),
+    0x8E8D => compressed(CompressedFormat::BC7SRGBA),
+    0x9274 => compressed(CompressedFormat::ETC2RGB),
+    0x9275 => compressed(CompressedFormat::ETC2SRGB),
+    0x9276 => compressed(CompressedFormat::ETC2RGBA1),
+    0x9277 => compressed(CompressedFormat::ETC2SRGBA1),
+    0x9278 => compressed(CompressedFormat::ETC2RGBA),
+    0x9279 => compressed(CompressedFormat::ETC2SRGBA),
+    0x9270 => compressed(CompressedFormat::EACR),
+    0x9271 => compressed(CompressedFormat::EACRSigned),
+    0x9272 => compressed(CompressedFormat::EACRG),
+    0x9273 => compressed(CompressedFormat::EACRGSigned),
+    0x93B0..=0x93BD => compressed(CompressedFormat::ASTCRGBA(
+      ASTC_BLOCKS[(internal_format - 0x93B0) as usize],
+    )),
+    0x93D0..=0x93DD => compressed(CompressedFormat::ASTCSRGBA(
+      ASTC_BLOCKS[(internal_format - 0x93D0) as usize],
+    )),
+    _ => return None,
+  };
+
+  Some(pf)
+}
+
+/// Pixel format of a Vulkan format, as used by KTX2 containers.
+fn vk_pixel_format(vk_format: u32) -> Option<PixelFormat> {
+  let pf = match vk_format {
+    9 => NormR8UI::pixel_format(),     // VK_FORMAT_R8_UNORM
+    10 => NormR8I::pixel_format(),     // VK_FORMAT_R8_SNORM
+    13 => R8UI::pixel_format(),        // VK_FORMAT_R8_UINT
+    14 => R8I::pixel_format(),         // VK_FORMAT_R8_SINT
+    16 => NormRG8UI::pixel_format(),   // VK_FORMAT_R8G8_UNORM
+    17 => NormRG8I::pixel_format(),    // VK_FORMAT_R8G8_SNORM
+    23 => NormRGB8UI::pixel_format(),  // VK_FORMAT_R8G8B8_UNORM
+    29 => SRGB8UI::pixel_format(),     // VK_FORMAT_R8G8B8_SRGB
+    37 => NormRGBA8UI::pixel_format(), // VK_FORMAT_R8G8B8A8_UNORM
+    38 => NormRGBA8I::pixel_format(),  // VK_FORMAT_R8G8B8A8_SNORM
+    41 => RGBA8UI::pixel_format(),     // VK_FORMAT_R8G8B8A8_UINT
+    42 => RGBA8I::pixel_format(),      // VK_FORMAT_R8G8B8A8_SINT
+    43 => SRGBA8UI::pixel_format(),    // VK_FORMAT_R8G8B8A8_SRGB
+    74 => R16UI::pixel_format(),       // VK_FORMAT_R16_UINT
+    75 => R16I::pixel_format(),        // VK_FORMAT_R16_SINT
+    98 => R32UI::pixel_format(),       // VK_FORMAT_R32_UINT
+    99 => R32I::pixel_format(),        // VK_FORMAT_R32_SINT
+    100 => R32F::pixel_format(),       // VK_FORMAT_R32_SFLOAT
+    103 => RG32F::pixel_format(),      // VK_FORMAT_R32G32_SFLOAT
+    106 => RGB32F::pixel_format(),     // VK_FORMAT_R32G32B32_SFLOAT
+    107 => RGBA32UI::pixel_format(),   // VK_FORMAT_R32G32B32A32_UINT
+    108 => RGBA32I::pixel_format(),    // VK_FORMAT_R32G32B32A32_SINT
+    109 => RGBA32F::pixel_format(),    // VK_FORMAT_R32G32B32A32_SFLOAT
+    122 => R11G11B10F::pixel_format(), // VK_FORMAT_B10G11R11_UFLOAT_PACK32
+    131 | 133 => compressed(CompressedFormat::BC1RGBA),
+    132 | 134 => compressed(CompressedFormat::BC1SRGBA),
+    135 => compressed(CompressedFormat::BC2RGBA),
+    136 => compressed(CompressedFormat::BC2SRGBA),
+    137 => compressed(CompressedFormat::BC3RGBA),
+    138 => compressed(CompressedFormat::BC3SRGBA),
+    139 => compressed(CompressedFormat::BC4R),
+    140 => compressed(CompressedFormat::BC4RSigned),
+    141 => compressed(CompressedFormat::BC5RG),
+    142 => compressed(CompressedFormat::BC5RGSigned),
+    143 => compressed(CompressedFormat::BC6HRGBUnsigned),
+    144 => compressed(CompressedFormat::BC6HRGBSigned),
+    145 => compressed(CompressedFormat::BC7RGBA),
+    146 => compressed(CompressedFormat::BC7SRGBA),
+    147 => compressed(CompressedFormat::ETC2RGB),
+    148 => compressed(CompressedFormat::ETC2SRGB),
+    149 => compressed(CompressedFormat::ETC2RGBA1),
+    150 => compressed(CompressedFormat::ETC2SRGBA1),
+    151 => compressed(CompressedFormat::ETC2RGBA),
+    152 => compressed(CompressedFormat::ETC2SRGBA),
+    153 => compressed(CompressedFormat::EACR),
+    154 => compressed(CompressedFormat::EACRSigned),
+    155 => compressed(CompressedFormat::EACRG),
+    156 => compressed(CompressedFormat::EACRGSigned),
+    // ASTC formats alternate between UNORM and SRGB variants
+    157..=184 => {
+      let block = ASTC_BLOCKS[(vk_format - 157) as usize / 2];
+
+      if vk_format % 2 == 1 {
+        compressed(CompressedFormat::ASTCRGBA(block))
+      } else {
+        compressed(CompressedFormat::ASTCSRGBA(block))
+      }
+    }
+    _ => return None,
+  };
+
+  Some(pf)
+}
+
+/// Pixel format of a DXGI format, as used by DDS containers with the DX10 header.
+fn dxgi_pixel_format(dxgi_format: u32) -> Option<PixelFormat> {
+  let pf = match dxgi_format {
+    2 => RGBA32F::pixel_format(),      // DXGI_FORMAT_R32G32B32A32_FLOAT
+    3 => RGBA32UI::pixel_format(),     // DXGI_FORMAT_R32G32B32A32_UINT
+    4 => RGBA32I::pixel_format(),      // DXGI_FORMAT_R32G32B32A32_SINT
+    6 => RGB32F::pixel_format(),       // DXGI_FORMAT_R32G32B32_FLOAT
+    16 => RG32F::pixel_format(),       // DXGI_FORMAT_R32G32_FLOAT
+    26 => R11G11B10F::pixel_format(),  // DXGI_FORMAT_R11G11B10_FLOAT
+    28 => NormRGBA8UI::pixel_format(), // DXGI_FORMAT_R8G8B8A8_UNORM
+    29 => SRGBA8UI::pixel_format(),    // DXGI_FORMAT_R8G8B8A8_UNORM_SRGB
+    30 => RGBA8UI::pixel_format(),     // DXGI_FORMAT_R8G8B8A8_UINT
+    31 => NormRGBA8I::pixel_format(),  // DXGI_FORMAT_R8G8B8A8_SNORM
+    32 => RGBA8I::pixel_format(),      // DXGI_FORMAT_R8G8B8A8_SINT
+    41 => R32F::pixel_format(),        // DXGI_FORMAT_R32_FLOAT
+    42 => R32UI::pixel_format(),       // DXGI_FORMAT_R32_UINT
+    43 => R32I::pixel_format(),        // DXGI_FORMAT_R32_SINT
+    49 => NormRG8UI::pixel_format(),   // DXGI_FORMAT_R8G8_UNORM
+    51 => NormRG8I::pixel_format(),    // DXGI_FORMAT_R8G8_SNORM
+    57 => R16UI::pixel_format(),       // DXGI_FORMAT_R16_UINT
+    59 => R16I::pixel_format(),        // DXGI_FORMAT_R16_SINT
+    61 => NormR8UI::pixel_format(),    // DXGI_FORMAT_R8_UNORM
+    62 => R8UI::pixel_format(),        // DXGI_FORMAT_R8_UINT
+    63 => NormR8I::pixel_format(),     // DXGI_FORMAT_R8_SNORM
+    64 => R8I::pixel_format(),         // DXGI_FORMAT_R8_SINT
+    71 => compressed(CompressedFormat::BC1RGBA),
+    72 => compressed(CompressedFormat::BC1SRGBA),
+    74 => compressed(CompressedFormat::BC2RGBA),
+    75 => compressed(CompressedFormat::BC2SRGBA),
+    77 => compressed(CompressedFormat::BC3RGBA),
+    78 => compressed(CompressedFormat::BC3SRGBA),
+    80 => compressed(CompressedFormat::BC4R),
+    81 => compressed(CompressedFormat::BC4RSigned),
+    83 => compressed(CompressedFormat::BC5RG),
+    84 => compressed(CompressedFormat::BC5RGSigned),
+    95 => compressed(CompressedFormat::BC6HRGBUnsigned),
+    96 => compressed(CompressedFormat::BC6HRGBSigned),
+    98 => compressed(CompressedFormat::BC7RGBA),
+    99 => compressed(CompressedFormat::BC7SRGBA),
+    _ => return None,
+  };
+
+  Some(pf)
+}
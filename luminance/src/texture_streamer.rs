@@ -0,0 +1,437 @@
+//! Texture streaming with a GPU memory budget.
+//!
+//! Large worlds often have more textures than can fit in GPU memory at once, and most of them are only seen from far
+//! away, where their finest mipmap levels are not needed. A [`TextureStreamer`] manages the residency of such
+//! textures: each texture is registered with [`TextureStreamer::insert`], but its texels are only loaded — level by
+//! level, from the coarsest one — once it is requested with [`TextureStreamer::request`].
+//!
+//! Requests are served once per frame by [`TextureStreamer::update`], which loads the requested levels with a
+//! user-provided function and streams them to the GPU through a [`PixelBuffer`], without stalling. The memory used by
+//! the resident levels is kept under a budget: textures that were not requested for the longest time are evicted to
+//! make room, and requests that still don’t fit are served with coarser levels. Each update returns
+//! [`TextureStreamerStats`] about the frame.
+//!
+//! A texture only has its resident levels allocated: its base level is its finest resident level, so it must be
+//! sampled with normalized texture coordinates.
+
+use crate::{
+  backend::pixel_buffer::{
+    PixelBufferSliceMut as PixelBufferSliceMutBackend, TextureStreaming as TextureStreamingBackend,
+  },
+  context::GraphicsContext,
+  pixel::Pixel,
+  pixel_buffer::{PixelBuffer, PixelBufferError, UploadFence},
+  texture::{Dimensionable, Sampler, TexelUpload, Texture, TextureError},
+};
+use std::{cmp::Reverse, collections::HashMap, error, fmt, hash::Hash};
+
+/// Errors that might occur while streaming textures.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TextureStreamerError {
+  /// Error while creating or uploading to a texture.
+  TextureError(TextureError),
+  /// Error while creating or writing to the pixel buffer.
+  PixelBufferError(PixelBufferError),
+}
+
+impl TextureStreamerError {
+  /// Error while creating or uploading to a texture.
+  pub fn texture_error(e: TextureError) -> Self {
+    TextureStreamerError::TextureError(e)
+  }
+
+  /// Error while creating or writing to the pixel buffer.
+  pub fn pixel_buffer_error(e: PixelBufferError) -> Self {
+    TextureStreamerError::PixelBufferError(e)
+  }
+}
+
+impl fmt::Display for TextureStreamerError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      TextureStreamerError::TextureError(ref e) => write!(f, "texture streaming error: {}", e),
+      TextureStreamerError::PixelBufferError(ref e) => write!(f, "texture streaming error: {}", e),
+    }
+  }
+}
+
+impl error::Error for TextureStreamerError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      TextureStreamerError::TextureError(e) => Some(e),
+      TextureStreamerError::PixelBufferError(e) => Some(e),
+    }
+  }
+}
+
+impl From<TextureError> for TextureStreamerError {
+  fn from(e: TextureError) -> Self {
+    TextureStreamerError::TextureError(e)
+  }
+}
+
+impl From<PixelBufferError> for TextureStreamerError {
+  fn from(e: PixelBufferError) -> Self {
+    TextureStreamerError::PixelBufferError(e)
+  }
+}
+
+/// Statistics of a frame of a [`TextureStreamer`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TextureStreamerStats {
+  /// Memory budget, in bytes.
+  pub budget: usize,
+  /// Memory used by the resident levels at the end of the frame, in bytes.
+  pub resident_bytes: usize,
+  /// Number of levels uploaded during the frame.
+  pub uploaded_levels: usize,
+  /// Number of bytes uploaded during the frame.
+  pub uploaded_bytes: usize,
+  /// Number of textures evicted during the frame.
+  pub evicted_textures: usize,
+  /// Number of requests served with coarser levels than requested, or not served at all, because of the budget.
+  pub deferred_requests: usize,
+  /// Number of uploads still performed by the GPU at the end of the frame.
+  pub pending_uploads: usize,
+}
+
+/// A texture managed by a [`TextureStreamer`].
+struct StreamedTexture<B, D, P>
+where
+  B: ?Sized + TextureStreamingBackend<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Size of the base level of the full texture.
+  size: D::Size,
+  sampler: Sampler,
+  /// Number of levels of the full texture.
+  levels: usize,
+  /// GPU texture holding the resident levels, if any.
+  texture: Option<Texture<B, D, P>>,
+  /// Finest resident level; the base level of `texture`.
+  resident_level: usize,
+  /// Finest level requested during the current frame.
+  requested_level: Option<usize>,
+  /// Frame the texture was requested last.
+  last_used: u64,
+}
+
+impl<B, D, P> StreamedTexture<B, D, P>
+where
+  B: ?Sized + TextureStreamingBackend<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Memory used by the levels from `level` to the coarsest one.
+  fn bytes_from(&self, level: usize) -> usize {
+    let texels: usize = (level..self.levels)
+      .map(|level| D::count(D::level_size(self.size, level)))
+      .sum();
+
+    texels * P::pixel_format().format.bytes_len()
+  }
+
+  /// Memory used by the resident levels.
+  fn resident_bytes(&self) -> usize {
+    if self.texture.is_some() {
+      self.bytes_from(self.resident_level)
+    } else {
+      0
+    }
+  }
+}
+
+/// Streamer of textures keyed by `K`, keeping their resident levels under a memory budget.
+///
+/// See the [module documentation](crate::texture_streamer) for further details.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `K` is the type of the keys identifying textures, such as asset paths.
+/// - `D` is the dimension of the textures.
+/// - `P` is the pixel format of the textures.
+pub struct TextureStreamer<B, K, D, P>
+where
+  B: ?Sized + TextureStreamingBackend<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  budget: usize,
+  resident_bytes: usize,
+  frame: u64,
+  textures: HashMap<K, StreamedTexture<B, D, P>>,
+  buffer: Option<PixelBuffer<B, P::Encoding>>,
+  fences: Vec<UploadFence<B>>,
+  stats: TextureStreamerStats,
+}
+
+impl<B, K, D, P> TextureStreamer<B, K, D, P>
+where
+  B: ?Sized + TextureStreamingBackend<D, P>,
+  K: Clone + Eq + Hash,
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Create a new [`TextureStreamer`] with a memory budget of `budget` bytes.
+  pub fn new(budget: usize) -> Self {
+    TextureStreamer {
+      budget,
+      resident_bytes: 0,
+      frame: 0,
+      textures: HashMap::new(),
+      buffer: None,
+      fences: Vec::new(),
+      stats: TextureStreamerStats {
+        budget,
+        ..TextureStreamerStats::default()
+      },
+    }
+  }
+
+  /// Memory budget, in bytes.
+  pub fn budget(&self) -> usize {
+    self.budget
+  }
+
+  /// Change the memory budget.
+  ///
+  /// Resident textures are only evicted to respect a lower budget when other textures need room.
+  pub fn set_budget(&mut self, budget: usize) {
+    self.budget = budget;
+  }
+
+  /// Memory used by the resident levels, in bytes.
+  pub fn resident_bytes(&self) -> usize {
+    self.resident_bytes
+  }
+
+  /// Statistics of the last frame.
+  pub fn stats(&self) -> TextureStreamerStats {
+    self.stats
+  }
+
+  /// Register a texture of base level of size `size`, with `levels` mipmap levels, base level included.
+  ///
+  /// Nothing is loaded until the texture is requested. Registering a texture with the key of another one replaces it.
+  pub fn insert(&mut self, key: K, size: D::Size, sampler: Sampler, levels: usize) {
+    let texture = StreamedTexture {
+      size,
+      sampler,
+      levels: levels.max(1),
+      texture: None,
+      resident_level: 0,
+      requested_level: None,
+      last_used: 0,
+    };
+
+    if let Some(previous) = self.textures.insert(key, texture) {
+      self.resident_bytes -= previous.resident_bytes();
+    }
+  }
+
+  /// Unregister a texture, freeing its resident levels.
+  ///
+  /// Return whether the texture was registered.
+  pub fn remove(&mut self, key: &K) -> bool {
+    match self.textures.remove(key) {
+      Some(texture) => {
+        self.resident_bytes -= texture.resident_bytes();
+        true
+      }
+
+      None => false,
+    }
+  }
+
+  /// Request the levels of a texture from `level` to the coarsest one, and get the texture if it has resident levels.
+  ///
+  /// The request is served by the next [`TextureStreamer::update`]; until then, the texture might not be resident or
+  /// only have coarser levels. Requesting a texture also marks it as used during the current frame, which prevents it
+  /// from being evicted.
+  pub fn request(&mut self, key: &K, level: usize) -> Option<&mut Texture<B, D, P>> {
+    let frame = self.frame;
+    let texture = self.textures.get_mut(key)?;
+    let level = level.min(texture.levels - 1);
+
+    texture.last_used = frame;
+    texture.requested_level = Some(texture.requested_level.map_or(level, |l| l.min(level)));
+    texture.texture.as_mut()
+  }
+
+  /// Get a texture if it has resident levels, without requesting it.
+  pub fn texture(&mut self, key: &K) -> Option<&mut Texture<B, D, P>> {
+    self.textures.get_mut(key)?.texture.as_mut()
+  }
+
+  /// Finest resident level of a texture, if any.
+  pub fn resident_level(&self, key: &K) -> Option<usize> {
+    let texture = self.textures.get(key)?;
+    texture.texture.as_ref().map(|_| texture.resident_level)
+  }
+
+  /// Serve the requests of the current frame, and start a new frame.
+  ///
+  /// `load` is called with the key of a texture, a mipmap level, and the texels of that level to fill. Requests are
+  /// served from the coarsest requested level, so that as many textures as possible get resident levels.
+  pub fn update<C, F>(
+    &mut self,
+    ctx: &mut C,
+    mut load: F,
+  ) -> Result<TextureStreamerStats, TextureStreamerError>
+  where
+    C: GraphicsContext<Backend = B>,
+    B: for<'a> PixelBufferSliceMutBackend<'a, P::Encoding>,
+    F: FnMut(&K, usize, &mut [P::Encoding]),
+  {
+    self.fences.retain(|fence| !fence.is_complete());
+
+    let mut stats = TextureStreamerStats {
+      budget: self.budget,
+      ..TextureStreamerStats::default()
+    };
+
+    let mut requests: Vec<(K, usize)> = self
+      .textures
+      .iter()
+      .filter_map(|(key, texture)| {
+        let level = texture.requested_level?;
+        let missing = texture.texture.is_none() || level < texture.resident_level;
+        missing.then(|| (key.clone(), level))
+      })
+      .collect();
+    requests.sort_by_key(|&(_, level)| Reverse(level));
+
+    for (key, level) in requests {
+      let current_bytes = self.textures[&key].resident_bytes();
+      let wanted_bytes = self.textures[&key].bytes_from(level);
+
+      while self.resident_bytes - current_bytes + wanted_bytes > self.budget && self.evict_lru(&key)
+      {
+        stats.evicted_textures += 1;
+      }
+
+      let available = self
+        .budget
+        .saturating_sub(self.resident_bytes - current_bytes);
+      let texture = self.textures.get_mut(&key).unwrap();
+      let fitting = (level..texture.levels).find(|&l| texture.bytes_from(l) <= available);
+
+      if fitting != Some(level) {
+        stats.deferred_requests += 1;
+      }
+
+      let fitting = match fitting {
+        Some(fitting) if texture.texture.is_none() || fitting < texture.resident_level => fitting,
+        _ => continue,
+      };
+
+      stream_levels(
+        ctx,
+        &mut self.buffer,
+        &mut self.fences,
+        &key,
+        texture,
+        fitting,
+        &mut load,
+        &mut stats,
+      )?;
+
+      self.resident_bytes = self.resident_bytes - current_bytes + texture.resident_bytes();
+    }
+
+    for texture in self.textures.values_mut() {
+      texture.requested_level = None;
+    }
+
+    self.frame += 1;
+
+    stats.resident_bytes = self.resident_bytes;
+    stats.pending_uploads = self.fences.len();
+    self.stats = stats;
+
+    Ok(stats)
+  }
+
+  /// Evict the least recently used resident texture that was not used during the current frame, other than `key`.
+  ///
+  /// Return whether a texture was evicted.
+  fn evict_lru(&mut self, key: &K) -> bool {
+    let frame = self.frame;
+    let lru = self
+      .textures
+      .iter_mut()
+      .filter(|(k, texture)| *k != key && texture.texture.is_some() && texture.last_used < frame)
+      .min_by_key(|(_, texture)| texture.last_used);
+
+    match lru {
+      Some((_, texture)) => {
+        self.resident_bytes -= texture.resident_bytes();
+        texture.texture = None;
+        true
+      }
+
+      None => false,
+    }
+  }
+}
+
+/// Reallocate a texture so that its base level is `level`, and stream all its levels.
+#[allow(clippy::too_many_arguments)]
+fn stream_levels<B, C, K, D, P, F>(
+  ctx: &mut C,
+  buffer: &mut Option<PixelBuffer<B, P::Encoding>>,
+  fences: &mut Vec<UploadFence<B>>,
+  key: &K,
+  texture: &mut StreamedTexture<B, D, P>,
+  level: usize,
+  load: &mut F,
+  stats: &mut TextureStreamerStats,
+) -> Result<(), TextureStreamerError>
+where
+  B: ?Sized + TextureStreamingBackend<D, P> + for<'a> PixelBufferSliceMutBackend<'a, P::Encoding>,
+  C: GraphicsContext<Backend = B>,
+  D: Dimensionable,
+  P: Pixel,
+  F: FnMut(&K, usize, &mut [P::Encoding]),
+{
+  let size = D::level_size(texture.size, level);
+  let texels = TexelUpload::reserve(texture.levels - 1 - level);
+
+  let gpu_texture = match texture.texture {
+    Some(ref mut gpu_texture) => {
+      gpu_texture.resize(size, texels)?;
+      gpu_texture
+    }
+
+    None => texture
+      .texture
+      .insert(Texture::new(ctx, size, texture.sampler, texels)?),
+  };
+
+  texture.resident_level = level;
+
+  for (mip, full_level) in (level..texture.levels).enumerate() {
+    let mip_size = gpu_texture.level_size(mip);
+    let len = D::count(mip_size);
+
+    // levels are streamed from the finest one, so the buffer only grows for the first one
+    let pixel_buffer = match buffer {
+      Some(pixel_buffer) if pixel_buffer.len() >= len => pixel_buffer,
+      _ => buffer.insert(PixelBuffer::new(ctx, len)?),
+    };
+
+    load(key, full_level, &mut pixel_buffer.slice_mut()?[..len]);
+
+    let fence =
+      gpu_texture.upload_part_level_from_buffer(D::ZERO_OFFSET, mip_size, mip, pixel_buffer)?;
+    fences.push(fence);
+
+    stats.uploaded_levels += 1;
+    stats.uploaded_bytes += len * P::pixel_format().format.bytes_len();
+  }
+
+  Ok(())
+}
@@ -0,0 +1,102 @@
+//! Transform feedback.
+//!
+//! Transform feedback allows to capture the vertices output by the vertex processing stages — vertex, tessellation and
+//! geometry shaders — into the vertex buffer of a [`Tess`], instead of (or before) rasterizing them. This is typically
+//! used to run simulations on the GPU, such as particles systems: the particles are updated by a vertex shader, captured
+//! into a [`Tess`] that is then both rendered and used as input of the next update.
+//!
+//! The varyings to capture are declared when building the shader program, with
+//! [`ProgramBuilder::capture_varyings`]. They are written interleaved, in order, so the vertex type of the output
+//! [`Tess`] must have the same layout. Capturing is done in a pipeline with [`RenderGate::capture`], by providing a
+//! [`TransformFeedback`] object and the output [`Tess`].
+//!
+//! [`Tess`]: crate::tess::Tess
+//! [`ProgramBuilder::capture_varyings`]: crate::shader::ProgramBuilder::capture_varyings
+//! [`RenderGate::capture`]: crate::render_gate::RenderGate::capture
+
+use crate::{
+  backend::transform_feedback::TransformFeedback as TransformFeedbackBackend,
+  context::GraphicsContext,
+};
+use std::{error, fmt};
+
+/// Errors that might occur when using transform feedback.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TransformFeedbackError {
+  /// Cannot create the transform feedback object.
+  CannotCreate(String),
+  /// The primitive mode of the output tessellation cannot be captured; only points, lines and triangles can.
+  UnsupportedPrimitiveMode,
+  /// The output tessellation has no vertex buffer to capture into.
+  NoVertexBuffer,
+}
+
+impl TransformFeedbackError {
+  /// Cannot create the transform feedback object.
+  pub fn cannot_create(reason: impl Into<String>) -> Self {
+    TransformFeedbackError::CannotCreate(reason.into())
+  }
+
+  /// The primitive mode of the output tessellation cannot be captured.
+  pub fn unsupported_primitive_mode() -> Self {
+    TransformFeedbackError::UnsupportedPrimitiveMode
+  }
+
+  /// The output tessellation has no vertex buffer to capture into.
+  pub fn no_vertex_buffer() -> Self {
+    TransformFeedbackError::NoVertexBuffer
+  }
+}
+
+impl fmt::Display for TransformFeedbackError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      TransformFeedbackError::CannotCreate(ref reason) => {
+        write!(f, "cannot create transform feedback: {}", reason)
+      }
+
+      TransformFeedbackError::UnsupportedPrimitiveMode => {
+        f.write_str("unsupported primitive mode for transform feedback")
+      }
+
+      TransformFeedbackError::NoVertexBuffer => {
+        f.write_str("no vertex buffer to capture transform feedback into")
+      }
+    }
+  }
+}
+
+impl error::Error for TransformFeedbackError {}
+
+/// A transform feedback object.
+///
+/// Such an object holds the state of a capture. It can be reused for as many captures as needed.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+pub struct TransformFeedback<B>
+where
+  B: ?Sized + TransformFeedbackBackend,
+{
+  pub(crate) repr: B::TransformFeedbackRepr,
+}
+
+impl<B> TransformFeedback<B>
+where
+  B: ?Sized + TransformFeedbackBackend,
+{
+  /// Create a new [`TransformFeedback`] object.
+  pub fn new<C>(ctx: &mut C) -> Result<Self, TransformFeedbackError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    unsafe {
+      ctx
+        .backend()
+        .new_transform_feedback()
+        .map(|repr| TransformFeedback { repr })
+    }
+  }
+}
@@ -9,6 +9,7 @@
 //! [`VertexAttribType`]: crate::vertex::VertexAttribType
 
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 
 /// A type that can be used as a [`Vertex`] has to implement that trait – it must provide an
 /// associated [`VertexDesc`] value via a function call. This associated value gives enough
@@ -84,13 +85,50 @@ impl VertexBufferDesc {
 /// Should vertex instancing be used for a vertex attribute?
 ///
 /// Enabling this is done per attribute but if you enable it for a single attribute of a struct, it
-/// should be enabled for all others (interleaved vertex instancing is not supported).
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+/// should be enabled for all others (interleaved vertex instancing is not supported). Instanced
+/// attributes of a struct can use different divisors, though.
+///
+/// Instancings are compared by [`VertexInstancing::divisor`], so that `Divisor(1)` equals `On`, and `Divisor(0)`
+/// equals `Off`.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum VertexInstancing {
   /// Use vertex instancing.
   On,
   /// Disable vertex instancing.
   Off,
+  /// Use vertex instancing, advancing to the next attribute value every given number of instances.
+  ///
+  /// `Divisor(1)` is the same as [`VertexInstancing::On`], and `Divisor(0)` as [`VertexInstancing::Off`].
+  Divisor(u32),
+}
+
+impl VertexInstancing {
+  /// Number of instances sharing the same attribute value, or `0` if the attribute is per-vertex.
+  pub fn divisor(self) -> u32 {
+    match self {
+      VertexInstancing::On => 1,
+      VertexInstancing::Off => 0,
+      VertexInstancing::Divisor(divisor) => divisor,
+    }
+  }
+}
+
+impl PartialEq for VertexInstancing {
+  fn eq(&self, other: &Self) -> bool {
+    self.divisor() == other.divisor()
+  }
+}
+
+impl Eq for VertexInstancing {}
+
+impl Hash for VertexInstancing {
+  fn hash<H>(&self, state: &mut H)
+  where
+    H: Hasher,
+  {
+    self.divisor().hash(state);
+  }
 }
 
 /// Vertex attribute format.
@@ -118,6 +156,10 @@ pub struct VertexAttribDesc {
 
 impl VertexAttribDesc {
   /// Normalize a vertex attribute format’s type.
+  ///
+  /// Only integral types are affected; their size is kept, so that, for instance, colors stored as
+  /// `[u8; 4]` or normals stored as `[i16; 3]` take a fraction of the memory of `f32` components
+  /// while still being fetched as floats in `[0, 1]` (unsigned) or `[-1, 1]` (signed).
   pub fn normalize(self) -> Self {
     VertexAttribDesc {
       ty: self.ty.normalize(),
@@ -161,6 +203,9 @@ impl VertexAttribType {
 ///
 /// The default implementation is not to normalize anything. You have to explicitly ask for
 /// normalized integers (that will, then, be accessed as floating vertex attributes).
+///
+/// Non-normalized integers are accessed as integral vertex attributes (`int`, `uvec4`, etc.), with
+/// their values left untouched, which is required for indices, such as bone indices.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Normalized {
   /// Normalize integral values and expose them as floating-point values.
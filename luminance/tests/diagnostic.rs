@@ -0,0 +1,127 @@
+use luminance::shader::{
+  diagnostic::{Diagnostic, Severity, ShaderLog},
+  ProgramError, ProgramWarning, StageError, StageType,
+};
+
+fn diagnostic(
+  severity: Severity,
+  stage: Option<StageType>,
+  line: Option<usize>,
+  column: Option<usize>,
+  message: &str,
+) -> Diagnostic {
+  Diagnostic {
+    severity,
+    stage,
+    file: None,
+    include_stack: Vec::new(),
+    line,
+    column,
+    message: message.to_owned(),
+  }
+}
+
+#[test]
+fn parse_mesa_compilation_log() {
+  let raw = "0:12(5): error: `foo' undeclared\n0:13(1): warning: unused variable `bar'\n";
+  let e = StageError::compilation_failed(StageType::FragmentShader, raw);
+
+  assert_eq!(
+    e.diagnostics(),
+    &[
+      diagnostic(
+        Severity::Error,
+        Some(StageType::FragmentShader),
+        Some(12),
+        Some(5),
+        "`foo' undeclared"
+      ),
+      diagnostic(
+        Severity::Warning,
+        Some(StageType::FragmentShader),
+        Some(13),
+        Some(1),
+        "unused variable `bar'"
+      ),
+    ]
+  );
+}
+
+#[test]
+fn parse_nvidia_link_log() {
+  let raw = "Vertex info\n-----------\n0(7) : error C1008: undefined variable \"x\"\n\nFragment info\n-------------\n0(3) : warning C7050: \"y\" might be used before being initialized\n";
+  let log = ShaderLog::parse(raw, None);
+
+  assert_eq!(log.raw(), raw);
+  assert_eq!(
+    log.diagnostics(),
+    &[
+      diagnostic(
+        Severity::Error,
+        Some(StageType::VertexShader),
+        Some(7),
+        None,
+        "undefined variable \"x\""
+      ),
+      diagnostic(
+        Severity::Warning,
+        Some(StageType::FragmentShader),
+        Some(3),
+        None,
+        "\"y\" might be used before being initialized"
+      ),
+    ]
+  );
+}
+
+#[test]
+fn parse_angle_log() {
+  let raw = "ERROR: 0:4: 'color' : undeclared identifier\nERROR: 1 compilation errors.  No code generated.\n";
+  let log = ShaderLog::parse(raw, Some(StageType::VertexShader));
+
+  assert_eq!(
+    log.diagnostics(),
+    &[diagnostic(
+      Severity::Error,
+      Some(StageType::VertexShader),
+      Some(4),
+      None,
+      "'color' : undeclared identifier"
+    )]
+  );
+}
+
+#[test]
+fn link_errors_attributed_to_stages() {
+  let e =
+    ProgramError::link_failed("error: fragment shader lacks `main'\nsome unknown driver output\n");
+
+  assert_eq!(
+    e.diagnostics(),
+    &[diagnostic(
+      Severity::Error,
+      Some(StageType::FragmentShader),
+      None,
+      None,
+      "fragment shader lacks `main'"
+    )]
+  );
+  assert_eq!(
+    e.to_string(),
+    "shader program failed to link: error: fragment shader lacks `main'\nsome unknown driver output\n"
+  );
+}
+
+#[test]
+fn log_warning() {
+  let log = ShaderLog::parse(
+    "0:3(10): warning: `x' is unused\n",
+    Some(StageType::VertexShader),
+  );
+  let warning = ProgramWarning::Log(log);
+
+  assert_eq!(
+    warning.to_string(),
+    "driver log: 0:3(10): warning: `x' is unused\n"
+  );
+}
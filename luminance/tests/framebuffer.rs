@@ -0,0 +1,111 @@
+use luminance::framebuffer::{Attachment, BlitMask, FramebufferError, IncompleteReason, Region};
+
+#[test]
+fn invalid_layer() {
+  let e = FramebufferError::invalid_layer(4, 4);
+
+  assert_eq!(
+    e,
+    FramebufferError::InvalidLayer {
+      layer: 4,
+      layers: 4
+    }
+  );
+  assert_eq!(
+    e.to_string(),
+    "invalid framebuffer layer 4 (the framebuffer has 4 layers)"
+  );
+}
+
+#[test]
+fn invalid_level() {
+  let e = FramebufferError::invalid_level(3, 3);
+
+  assert_eq!(
+    e,
+    FramebufferError::InvalidLevel {
+      level: 3,
+      levels: 3
+    }
+  );
+  assert_eq!(
+    e.to_string(),
+    "invalid framebuffer mipmap level 3 (the framebuffer has 3 levels)"
+  );
+}
+
+#[test]
+fn multisample_errors() {
+  assert_eq!(
+    FramebufferError::unsupported_sample_count(64).to_string(),
+    "unsupported multisample count: 64"
+  );
+  assert_eq!(
+    FramebufferError::resolve_size_mismatch([800, 600], [400, 300]).to_string(),
+    "cannot resolve a 800×600 multisampled framebuffer into a 400×300 framebuffer"
+  );
+}
+
+#[test]
+fn too_many_color_attachments() {
+  assert_eq!(
+    FramebufferError::too_many_color_attachments(9, 8).to_string(),
+    "too many color attachments: 9 (at most 8 are supported)"
+  );
+}
+
+#[test]
+fn blit_regions_and_masks() {
+  assert_eq!(Region::whole([800, 600]), Region::new(0, 0, 800, 600));
+
+  assert!(BlitMask::default().is_empty());
+  assert!(!BlitMask::DEPTH.is_empty());
+  assert_eq!(
+    BlitMask {
+      color: true,
+      ..BlitMask::STENCIL
+    },
+    BlitMask {
+      color: true,
+      depth: false,
+      stencil: true
+    }
+  );
+
+  assert_eq!(
+    FramebufferError::cannot_blit("depth and stencil can only be blitted with nearest filtering")
+      .to_string(),
+    "cannot blit framebuffer: depth and stencil can only be blitted with nearest filtering"
+  );
+}
+
+#[test]
+fn read_errors() {
+  assert_eq!(
+    FramebufferError::cannot_read("no color attachment at index 2").to_string(),
+    "cannot read framebuffer pixels: no color attachment at index 2"
+  );
+}
+
+#[test]
+fn incomplete_reasons() {
+  assert_eq!(
+    IncompleteReason::IncompleteAttachment(Some(Attachment::Color(1))).to_string(),
+    "incomplete color attachment 1"
+  );
+  assert_eq!(
+    IncompleteReason::IncompleteDrawBuffer(Some(Attachment::Color(2))).to_string(),
+    "incomplete draw buffer: nothing attached to color attachment 2"
+  );
+  assert_eq!(
+    FramebufferError::from(IncompleteReason::IncompleteAttachment(Some(
+      Attachment::DepthStencil
+    )))
+    .to_string(),
+    "incomplete framebuffer: incomplete depth/stencil attachment"
+  );
+  assert_eq!(
+    IncompleteReason::Unknown(0x8cd9).to_string(),
+    "unknown incomplete status 0x8cd9"
+  );
+}
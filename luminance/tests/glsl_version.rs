@@ -0,0 +1,30 @@
+use luminance::shader::GlslVersion;
+
+#[test]
+fn parse_driver_versions() {
+  assert_eq!(
+    GlslVersion::parse("4.60 NVIDIA"),
+    Some(GlslVersion::new(460))
+  );
+  assert_eq!(
+    GlslVersion::parse("3.30 - Build 27.20.100.8681"),
+    Some(GlslVersion::new(330))
+  );
+  assert_eq!(
+    GlslVersion::parse("OpenGL ES GLSL ES 3.20"),
+    Some(GlslVersion::new_es(320))
+  );
+  assert_eq!(
+    GlslVersion::parse("WebGL GLSL ES 3.00 (OpenGL ES GLSL ES 3.0 Chromium)"),
+    Some(GlslVersion::new_es(300))
+  );
+  assert_eq!(GlslVersion::parse("unknown"), None);
+}
+
+#[test]
+fn version_directives() {
+  assert_eq!(GlslVersion::new(330).directive(), "#version 330 core\n");
+  assert_eq!(GlslVersion::new(120).directive(), "#version 120\n");
+  assert_eq!(GlslVersion::new_es(300).directive(), "#version 300 es\n");
+  assert_eq!(GlslVersion::new_es(100).directive(), "#version 100\n");
+}
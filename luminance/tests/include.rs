@@ -0,0 +1,118 @@
+use luminance::shader::{
+  diagnostic::ShaderLog,
+  include::{expand_includes, IncludeError},
+  StageType,
+};
+use std::collections::HashMap;
+
+fn files(files: &[(&str, &str)]) -> HashMap<String, String> {
+  files
+    .iter()
+    .map(|&(path, src)| (path.to_owned(), src.to_owned()))
+    .collect()
+}
+
+#[test]
+fn expand_nested_includes() {
+  let mut fs = files(&[
+    ("common.glsl", "#include \"math.glsl\"\nfloat common;"),
+    ("math.glsl", "const float PI = 3.14;"),
+  ]);
+  let src = "#include <common.glsl>\nvoid main() {}\n  #  include \"math.glsl\" // again";
+
+  let expanded = expand_includes("main.glsl", src, &mut fs).unwrap();
+
+  assert_eq!(
+    expanded.source(),
+    "const float PI = 3.14;\nfloat common;\nvoid main() {}\nconst float PI = 3.14;\n"
+  );
+  assert_eq!(expanded.origin(1), Some(("math.glsl", 1)));
+  assert_eq!(expanded.origin(2), Some(("common.glsl", 2)));
+  assert_eq!(expanded.origin(3), Some(("main.glsl", 2)));
+  assert_eq!(expanded.origin(4), Some(("math.glsl", 1)));
+  assert_eq!(expanded.origin(0), None);
+  assert_eq!(expanded.origin(5), None);
+
+  assert_eq!(
+    expanded.include_stack(1),
+    Some(vec![("main.glsl", 1), ("common.glsl", 1)])
+  );
+  assert_eq!(expanded.include_stack(3), Some(vec![]));
+  assert_eq!(expanded.include_stack(4), Some(vec![("main.glsl", 3)]));
+  assert_eq!(expanded.include_stack(5), None);
+}
+
+#[test]
+fn remap_diagnostics() {
+  let mut fs = files(&[("lighting.glsl", "float light() {\n  return x;\n}")]);
+  let src = "#include \"lighting.glsl\"\nvoid main() {}";
+
+  let expanded = expand_includes("main.glsl", src, &mut fs).unwrap();
+  let mut log = ShaderLog::parse(
+    "0:2(10): error: `x' undeclared\n",
+    Some(StageType::FragmentShader),
+  );
+  expanded.remap_log(&mut log);
+
+  let diagnostic = &log.diagnostics()[0];
+  assert_eq!(diagnostic.file.as_deref(), Some("lighting.glsl"));
+  assert_eq!(diagnostic.line, Some(2));
+  assert_eq!(diagnostic.include_stack, vec![("main.glsl".to_owned(), 1)]);
+  assert_eq!(
+    diagnostic.to_string(),
+    "fragment shader:lighting.glsl:2:10: error: `x' undeclared"
+  );
+}
+
+#[test]
+fn expand_includes_with_closure() {
+  let mut resolver = |path: &str| (path == "a.glsl").then(|| "float a;".to_owned());
+
+  let expanded = expand_includes("main.glsl", "#include \"a.glsl\"", &mut resolver).unwrap();
+  assert_eq!(expanded.source(), "float a;\n");
+
+  assert_eq!(
+    expand_includes("main.glsl", "\n#include \"b.glsl\"", &mut resolver),
+    Err(IncludeError::not_found("b.glsl", "main.glsl", 2))
+  );
+}
+
+#[test]
+fn detect_include_cycles() {
+  let mut fs = files(&[
+    ("a.glsl", "#include \"b.glsl\""),
+    ("b.glsl", "#include \"a.glsl\""),
+  ]);
+
+  assert_eq!(
+    expand_includes("main.glsl", "#include \"a.glsl\"", &mut fs),
+    Err(IncludeError::cycle(vec![
+      "main.glsl".to_owned(),
+      "a.glsl".to_owned(),
+      "b.glsl".to_owned(),
+      "a.glsl".to_owned()
+    ]))
+  );
+}
+
+#[test]
+fn reject_malformed_includes() {
+  let mut fs = files(&[]);
+
+  for src in [
+    "#include",
+    "#include a.glsl",
+    "#include \"a.glsl",
+    "#include \"\"",
+    "#include \"a\" b",
+  ] {
+    assert_eq!(
+      expand_includes("main.glsl", src, &mut fs),
+      Err(IncludeError::malformed("main.glsl", 1))
+    );
+  }
+
+  // not include directives
+  let expanded = expand_includes("main.glsl", "#includes\n#define include", &mut fs).unwrap();
+  assert_eq!(expanded.source(), "#includes\n#define include\n");
+}
@@ -0,0 +1,54 @@
+use luminance::{
+  indirect::{
+    DrawArraysIndirectCommand, DrawElementsIndirectCommand, IndirectBufferError, IndirectCommand,
+  },
+  tess::TessError,
+};
+use std::mem;
+
+#[test]
+fn draw_elements_indirect_command_layout() {
+  // layout expected by glMultiDrawElementsIndirect
+  assert_eq!(mem::size_of::<DrawElementsIndirectCommand>(), 20);
+
+  let command = DrawElementsIndirectCommand::new(36, 2, 12, -4, 1);
+  let words: [u32; 5] = unsafe { mem::transmute(command) };
+  assert_eq!(words, [36, 2, 12, -4i32 as u32, 1]);
+
+  assert_eq!(mem::size_of::<DrawArraysIndirectCommand>(), 16);
+
+  let command = DrawArraysIndirectCommand::new(3, 1, 6, 0);
+  let words: [u32; 4] = unsafe { mem::transmute(command) };
+  assert_eq!(words, [3, 1, 6, 0]);
+
+  assert!(DrawElementsIndirectCommand::INDEXED);
+  assert!(!DrawArraysIndirectCommand::INDEXED);
+}
+
+#[test]
+fn errors() {
+  assert_eq!(
+    IndirectBufferError::cannot_create("empty indirect buffer").to_string(),
+    "cannot create indirect buffer: empty indirect buffer"
+  );
+  assert_eq!(
+    IndirectBufferError::out_of_bounds(3, 2, 4),
+    IndirectBufferError::OutOfBounds {
+      offset: 3,
+      len: 2,
+      capacity: 4
+    }
+  );
+  assert_eq!(
+    IndirectBufferError::out_of_bounds(3, 2, 4).to_string(),
+    "cannot upload 2 draw commands at offset 3 to an indirect buffer of 4 draw commands"
+  );
+  assert_eq!(
+    IndirectBufferError::invalid_offset(6, 40).to_string(),
+    "invalid draw command byte offset 6 in an indirect buffer of 40 bytes"
+  );
+  assert_eq!(
+    TessError::not_indexed().to_string(),
+    "tessellation is not indexed"
+  );
+}
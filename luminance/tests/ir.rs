@@ -0,0 +1,52 @@
+#![cfg(feature = "naga")]
+
+use luminance::shader::{ir::ShaderIr, GlslVersion, StageError, StageType};
+
+const WGSL: &str = r#"
+@vertex
+fn vs_main(@location(0) position: vec2<f32>) -> @builtin(position) vec4<f32> {
+  return vec4<f32>(position, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+  return vec4<f32>(1.0, 0.5, 0.25, 1.0);
+}
+"#;
+
+#[test]
+fn translate_wgsl() {
+  let ir = ShaderIr::from_wgsl(WGSL).unwrap();
+
+  let vs = ir
+    .translate(StageType::VertexShader, "vs_main", GlslVersion::new(330))
+    .unwrap();
+  assert!(vs.starts_with("#version 330 core\n"));
+
+  let fs = ir
+    .translate(
+      StageType::FragmentShader,
+      "fs_main",
+      GlslVersion::new_es(300),
+    )
+    .unwrap();
+  assert!(fs.starts_with("#version 300 es\n"));
+}
+
+#[test]
+fn translation_errors() {
+  assert!(matches!(
+    ShaderIr::from_wgsl("fn main( {"),
+    Err(StageError::TranslationFailed(_))
+  ));
+
+  let ir = ShaderIr::from_wgsl(WGSL).unwrap();
+  assert!(matches!(
+    ir.translate(StageType::GeometryShader, "vs_main", GlslVersion::new(330)),
+    Err(StageError::TranslationFailed(_))
+  ));
+  assert!(matches!(
+    ir.translate(StageType::VertexShader, "missing", GlslVersion::new(330)),
+    Err(StageError::TranslationFailed(_))
+  ));
+}
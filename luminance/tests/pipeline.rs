@@ -0,0 +1,54 @@
+use luminance::pipeline::{ClearColor, InvalidateAttachments, PipelineState};
+
+#[test]
+fn clear_color_attachments() {
+  let state = PipelineState::new()
+    .set_clear_color(None)
+    .set_clear_color_attachment(1, [1, 2, 3, 4])
+    .set_clear_color_attachment(0, [0.5, 0.5, 0.5, 1.])
+    .set_clear_color_attachment(1, [5u32, 6, 7, 8]);
+
+  assert_eq!(state.clear_color(), None);
+  assert_eq!(
+    state.clear_color_attachments(),
+    &[
+      (1, ClearColor::UInt([5, 6, 7, 8])),
+      (0, ClearColor::Float([0.5, 0.5, 0.5, 1.]))
+    ]
+  );
+}
+
+#[test]
+fn without_clear() {
+  let state = PipelineState::new()
+    .set_clear_color_attachment(0, [1, 2, 3, 4])
+    .without_clear();
+
+  assert_eq!(state.clear_color(), None);
+  assert!(state.clear_color_attachments().is_empty());
+  assert_eq!(state.clear_depth(), None);
+  assert_eq!(state.clear_stencil(), None);
+}
+
+#[test]
+fn invalidate_attachments() {
+  assert!(InvalidateAttachments::new().is_empty());
+  assert!(PipelineState::new().invalidate_attachments().is_empty());
+
+  let invalidate = InvalidateAttachments::new()
+    .color(2)
+    .depth()
+    .color(2)
+    .stencil();
+  assert_eq!(
+    invalidate,
+    InvalidateAttachments {
+      colors: vec![2],
+      depth: true,
+      stencil: true
+    }
+  );
+
+  let state = PipelineState::new().set_invalidate_attachments(invalidate.clone());
+  assert_eq!(state.invalidate_attachments(), &invalidate);
+}
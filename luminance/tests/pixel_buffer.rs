@@ -0,0 +1,17 @@
+use luminance::pixel_buffer::PixelBufferError;
+
+#[test]
+fn errors() {
+  assert_eq!(
+    PixelBufferError::cannot_create("empty pixel buffer"),
+    PixelBufferError::CannotCreate("empty pixel buffer".to_owned())
+  );
+  assert_eq!(
+    PixelBufferError::cannot_create("empty pixel buffer").to_string(),
+    "cannot create pixel buffer: empty pixel buffer"
+  );
+  assert_eq!(
+    PixelBufferError::cannot_map("glMapBufferRange failed").to_string(),
+    "cannot map pixel buffer: glMapBufferRange failed"
+  );
+}
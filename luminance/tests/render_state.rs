@@ -0,0 +1,22 @@
+use luminance::{color_mask::ColorMask, render_state::RenderState};
+
+#[test]
+fn color_masks() {
+  let state = RenderState::default();
+
+  assert_eq!(state.color_mask(), ColorMask::ALL);
+  assert!(state.color_attachment_masks().is_empty());
+
+  let red = ColorMask::new(true, false, false, false);
+  let state = state
+    .set_color_mask(red)
+    .disable_color_attachment(2)
+    .set_color_attachment_mask(0, red)
+    .set_color_attachment_mask(2, ColorMask::ALL);
+
+  assert_eq!(state.color_mask(), red);
+  assert_eq!(
+    state.color_attachment_masks(),
+    &[(2, ColorMask::ALL), (0, red)]
+  );
+}
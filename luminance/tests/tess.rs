@@ -0,0 +1,57 @@
+use luminance::tess::{TessError, TessIndex, TessIndexType};
+
+#[test]
+fn index_types() {
+  assert_eq!(<() as TessIndex>::INDEX_TYPE, None);
+  assert_eq!(u8::INDEX_TYPE, Some(TessIndexType::U8));
+  assert_eq!(u16::INDEX_TYPE, Some(TessIndexType::U16));
+  assert_eq!(u32::INDEX_TYPE, Some(TessIndexType::U32));
+
+  assert_eq!(u8::FIXED_RESTART_INDEX, 0xFF);
+  assert_eq!(u16::FIXED_RESTART_INDEX, 0xFFFF);
+  assert_eq!(u32::FIXED_RESTART_INDEX, 0xFFFF_FFFF);
+
+  assert_eq!(TessIndexType::U8.bytes(), 1);
+  assert_eq!(TessIndexType::U16.bytes(), 2);
+  assert_eq!(TessIndexType::U32.bytes(), 4);
+
+  assert_eq!(255u8.try_into_u32(), Some(255));
+  assert_eq!(().try_into_u32(), None);
+}
+
+#[test]
+fn out_of_bounds_error() {
+  let err = TessError::out_of_bounds(3, 2, 4);
+
+  assert_eq!(
+    err,
+    TessError::OutOfBounds {
+      offset: 3,
+      len: 2,
+      capacity: 4
+    }
+  );
+  assert_eq!(
+    err.to_string(),
+    "cannot upload 2 items at offset 3 to a buffer of 4 items"
+  );
+}
+
+#[test]
+fn builder_validation_errors() {
+  assert_eq!(
+    TessError::index_out_of_bounds(4, 3).to_string(),
+    "index 4 is out of bounds of 3 vertices"
+  );
+  assert_eq!(
+    TessError::invalid_patch_size(3, 4),
+    TessError::InvalidPatchSize {
+      patch_size: 3,
+      vert_nb: 4
+    }
+  );
+  assert_eq!(
+    TessError::invalid_patch_size(0, 4).to_string(),
+    "cannot render 4 vertices as patches of 0 vertices"
+  );
+}
@@ -0,0 +1,262 @@
+use luminance::{
+  pixel::{
+    ASTC10x8RGBA, ASTCBlock, CompressedFormat, CompressedPixel, CompressionFamily, Depth16,
+    Depth24, Depth24Stencil8, Depth32FStencil8, Format, NormR8UI, NormRGB8UI, NormRGBA8I,
+    NormRGBA8UI, Pixel, Stencil8, BC1RGBA, BC7SRGBA, EACRG, R11G11B10F, R32F, R32UI, RG16F, RG16UI,
+    RGBA32F, RGBA8I, SRGB8UI, SRGBA8UI,
+  },
+  texture::{
+    convert_texels, CubeFace, Cubemap, Dim1, Dim1Array, Dim2, Dim2Array, Dim3, Dimensionable,
+    Layerable, TextureError, ViewableAs,
+  },
+};
+
+#[test]
+fn layered_sizes() {
+  let size = Dim2Array::layered_size([4, 2], 3);
+
+  assert_eq!(size, ([4, 2], 3));
+  assert_eq!(Dim2Array::layers(size), 3);
+  assert_eq!(Dim2Array::layer_size(size), [4, 2]);
+  assert_eq!(Dim2Array::count(size), 24);
+  assert_eq!(Dim2Array::z_offset(Dim2Array::layer_offset(2)), 2);
+
+  let size = Dim1Array::layered_size(8, 5);
+
+  assert_eq!(Dim1Array::layers(size), 5);
+  assert_eq!(Dim1Array::layer_size(size), 8);
+  assert_eq!(Dim1Array::y_offset(Dim1Array::layer_offset(4)), 4);
+}
+
+#[test]
+fn dim1_sizes() {
+  assert_eq!(Dim1::width(8), 8);
+  assert_eq!(Dim1::height(8), 1);
+  assert_eq!(Dim1::depth(8), 1);
+  assert_eq!(Dim1::count(8), 8);
+  assert_eq!(Dim1::level_size(8, 2), 2);
+  assert_eq!(Dim1::x_offset(3), 3);
+  assert_eq!(Dim1::y_offset(3), 0);
+  assert_eq!(Dim1::z_offset(3), 0);
+}
+
+#[test]
+fn layer_errors() {
+  assert_eq!(
+    TextureError::invalid_layer(3, 3).to_string(),
+    "invalid texture layer 3 (the texture has 3 layers)"
+  );
+  assert_eq!(
+    TextureError::layer_size_mismatch(1, 16, 12).to_string(),
+    "layer 1 has 12 texels, but 16 texels were expected"
+  );
+}
+
+#[test]
+fn cube_faces() {
+  for (i, face) in CubeFace::ALL.iter().enumerate() {
+    assert_eq!(face.index(), i as u32);
+    assert_eq!(Cubemap::z_offset(([0, 0], *face)), i as u32);
+  }
+}
+
+#[test]
+fn compressed_sizes() {
+  let bc1 = BC1RGBA::compressed_format();
+
+  assert_eq!(bc1.family(), CompressionFamily::S3TC);
+  assert_eq!(bc1.block_size(), [4, 4]);
+  assert_eq!(bc1.compressed_len(256, 256), 64 * 64 * 8);
+  // partial blocks are padded
+  assert_eq!(bc1.compressed_len(5, 1), 2 * 8);
+  assert_eq!(bc1.compressed_len(1, 1), 8);
+
+  let bc7 = BC7SRGBA::compressed_format();
+
+  assert_eq!(bc7.family(), CompressionFamily::BPTC);
+  assert_eq!(bc7.compressed_len(16, 8), 4 * 2 * 16);
+
+  assert_eq!(EACRG::compressed_format().channels_len(), 2);
+  assert_eq!(EACRG::compressed_format().block_bytes(), 16);
+
+  let astc = ASTC10x8RGBA::compressed_format();
+
+  assert_eq!(astc, CompressedFormat::ASTCRGBA(ASTCBlock::Block10x8));
+  assert_eq!(astc.family(), CompressionFamily::ASTC);
+  assert_eq!(astc.block_size(), [10, 8]);
+  assert_eq!(astc.compressed_len(20, 20), 2 * 3 * 16);
+
+  let pf = ASTC10x8RGBA::pixel_format();
+
+  assert_eq!(pf.format, Format::Compressed(astc));
+  assert_eq!(pf.channels_len(), 4);
+  assert_eq!(pf.format.bytes_len(), 0);
+}
+
+#[test]
+fn srgb_formats() {
+  assert!(SRGB8UI::pixel_format().is_srgb());
+  assert!(SRGBA8UI::pixel_format().is_srgb());
+  assert!(BC7SRGBA::pixel_format().is_srgb());
+  assert!(!NormRGBA8UI::pixel_format().is_srgb());
+  assert!(!BC1RGBA::pixel_format().is_srgb());
+}
+
+#[test]
+fn compressed_errors() {
+  assert_eq!(
+    TextureError::compressed_size_mismatch(2, 32, 16).to_string(),
+    "compressed level 2 has 16 bytes, but 32 bytes were expected"
+  );
+}
+
+#[test]
+fn depth_formats() {
+  assert!(Depth16::pixel_format().is_depth_pixel());
+  assert!(Depth24::pixel_format().is_depth_pixel());
+  assert!(Depth24Stencil8::pixel_format().is_depth_pixel());
+  assert!(Depth32FStencil8::pixel_format().is_depth_pixel());
+  assert!(Stencil8::pixel_format().is_depth_pixel());
+  assert!(!NormRGBA8UI::pixel_format().is_depth_pixel());
+
+  assert_eq!(Depth16::pixel_format().format.bytes_len(), 2);
+  assert_eq!(Depth24::pixel_format().format.bytes_len(), 3);
+  assert_eq!(Depth24Stencil8::pixel_format().format.bytes_len(), 4);
+  assert_eq!(Stencil8::pixel_format().format.bytes_len(), 1);
+}
+
+#[test]
+fn integral_formats() {
+  assert!(R32UI::pixel_format().is_integral());
+  assert!(RGBA8I::pixel_format().is_integral());
+  assert!(!R32F::pixel_format().is_integral());
+  assert!(!NormRGBA8UI::pixel_format().is_integral());
+
+  assert_eq!(
+    TextureError::linear_filtering_on_integral(R32UI::pixel_format()).to_string(),
+    format!(
+      "integral pixel format {:?} cannot be filtered linearly; use nearest filters",
+      R32UI::pixel_format()
+    )
+  );
+}
+
+#[test]
+fn level_errors() {
+  assert_eq!(
+    TextureError::invalid_level(3, 3),
+    TextureError::InvalidLevel {
+      level: 3,
+      levels: 3
+    }
+  );
+  assert_eq!(
+    TextureError::invalid_level(3, 3).to_string(),
+    "invalid mipmap level 3 (the texture has 3 levels)"
+  );
+  assert_eq!(
+    TextureError::region_out_of_bounds(1).to_string(),
+    "region out of the bounds of mipmap level 1"
+  );
+}
+
+#[test]
+fn level_sizes() {
+  assert_eq!(Dim2::level_size([16, 4], 0), [16, 4]);
+  assert_eq!(Dim2::level_size([16, 4], 1), [8, 2]);
+  assert_eq!(Dim2::level_size([16, 4], 3), [2, 1]);
+  assert_eq!(Dim2::level_size([16, 4], 64), [1, 1]);
+  assert_eq!(Dim3::level_size([8, 8, 2], 2), [2, 2, 1]);
+  assert_eq!(Cubemap::level_size(32, 2), 8);
+  assert_eq!(Dim1Array::level_size((8, 3), 1), (4, 3));
+  assert_eq!(Dim2Array::level_size(([8, 4], 3), 2), ([2, 1], 3));
+}
+
+#[test]
+fn view_sizes() {
+  assert_eq!(
+    <Dim2Array as ViewableAs<Dim2>>::view_size(([8, 4], 3), 1),
+    [8, 4]
+  );
+  assert_eq!(
+    <Dim2 as ViewableAs<Dim2Array>>::view_size([8, 4], 1),
+    ([8, 4], 1)
+  );
+  assert_eq!(
+    <Dim2Array as ViewableAs<Cubemap>>::view_size(([8, 8], 12), 6),
+    8
+  );
+  assert_eq!(
+    <Cubemap as ViewableAs<Dim2Array>>::view_size(16, 2),
+    ([16, 16], 2)
+  );
+  assert_eq!(<Dim1 as ViewableAs<Dim1Array>>::view_size(8, 1), (8, 1));
+}
+
+#[test]
+fn view_errors() {
+  let (rgba, srgba) = (NormRGBA8UI::pixel_format(), SRGBA8UI::pixel_format());
+
+  assert_eq!(
+    TextureError::incompatible_view_format(rgba, srgba),
+    TextureError::IncompatibleViewFormat {
+      texture: rgba,
+      view: srgba
+    }
+  );
+  assert_eq!(
+    TextureError::view_layer_count_mismatch(6, 2).to_string(),
+    "view has 2 layers, but 6 layers were expected"
+  );
+  assert_eq!(
+    TextureError::cannot_create_view("texture views are not supported").to_string(),
+    "cannot create texture view: texture views are not supported"
+  );
+}
+
+#[test]
+fn copy_errors() {
+  assert_eq!(
+    TextureError::cannot_copy_texels("BC1RGBA textures cannot be attached to framebuffers")
+      .to_string(),
+    "cannot copy texels between textures: BC1RGBA textures cannot be attached to framebuffers"
+  );
+}
+
+#[test]
+fn texel_conversions() {
+  assert_eq!(
+    convert_texels::<NormRGB8UI, NormRGBA8UI>(&[[1, 2, 3], [4, 5, 6]]),
+    Ok(vec![1, 2, 3, 255, 4, 5, 6, 255])
+  );
+  assert_eq!(
+    convert_texels::<NormR8UI, R32F>(&[255, 0]),
+    Ok(vec![1., 0.])
+  );
+  assert_eq!(
+    convert_texels::<RGBA32F, NormRGBA8I>(&[[1., -1., 2., 0.5]]),
+    Ok(vec![127, -127, 127, 64])
+  );
+  assert_eq!(
+    convert_texels::<R32UI, RG16UI>(&[70000, 12]),
+    Ok(vec![65535, 0, 12, 0])
+  );
+  assert_eq!(
+    convert_texels::<RG16F, RGBA32F>(&[[0.5, 2.]]),
+    Ok(vec![0.5, 2., 0., 1.])
+  );
+}
+
+#[test]
+fn texel_conversion_errors() {
+  assert_eq!(
+    convert_texels::<R32UI, R32F>(&[1]),
+    Err(TextureError::unsupported_conversion(
+      R32UI::pixel_format(),
+      R32F::pixel_format()
+    ))
+  );
+  assert!(convert_texels::<SRGB8UI, NormRGBA8UI>(&[[0, 0, 0]]).is_err());
+  assert!(convert_texels::<R11G11B10F, RGBA32F>(&[[0.; 4]]).is_err());
+  assert!(convert_texels::<Depth16, R32F>(&[0]).is_err());
+}
@@ -0,0 +1,256 @@
+#![cfg(feature = "container")]
+
+use luminance::{
+  pixel::{CompressedFormat, Format, NormRGB8UI, NormRGBA8UI, Pixel, R32F},
+  texture::Dim,
+  texture_container::{ContainerError, TextureContainer},
+};
+
+const KTX_IDENTIFIER: [u8; 12] = [
+  0xAB, 0x4B, 0x54, 0x58, 0x20, 0x31, 0x31, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+const KTX2_IDENTIFIER: [u8; 12] = [
+  0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+fn push_u32(bytes: &mut Vec<u8>, values: &[u32]) {
+  for value in values {
+    bytes.extend_from_slice(&value.to_le_bytes());
+  }
+}
+
+/// KTX container; `header` starts with glType and ends with numberOfMipmapLevels.
+fn ktx(header: [u32; 11], levels: &[&[u8]]) -> Vec<u8> {
+  let mut bytes = KTX_IDENTIFIER.to_vec();
+  push_u32(&mut bytes, &[0x04030201]);
+  push_u32(&mut bytes, &header);
+  push_u32(&mut bytes, &[0]);
+
+  for level in levels {
+    push_u32(&mut bytes, &[level.len() as u32]);
+    bytes.extend_from_slice(level);
+  }
+
+  bytes
+}
+
+/// KTX2 container; `header` starts with vkFormat and ends with supercompressionScheme.
+fn ktx2(header: [u32; 9], levels: &[&[u8]]) -> Vec<u8> {
+  let mut bytes = KTX2_IDENTIFIER.to_vec();
+  push_u32(&mut bytes, &header);
+  bytes.extend_from_slice(&[0; 32]);
+
+  let mut offset = 80 + 24 * levels.len();
+  for level in levels {
+    for value in [offset, level.len(), level.len()] {
+      bytes.extend_from_slice(&(value as u64).to_le_bytes());
+    }
+
+    offset += level.len();
+  }
+
+  for level in levels {
+    bytes.extend_from_slice(level);
+  }
+
+  bytes
+}
+
+/// DDS container; `dx10` is the DX10 header, if any.
+fn dds(
+  [height, width, depth, levels]: [u32; 4],
+  pixel_format: [u32; 8],
+  caps2: u32,
+  dx10: Option<[u32; 5]>,
+  data: &[u8],
+) -> Vec<u8> {
+  let mut bytes = b"DDS ".to_vec();
+  push_u32(&mut bytes, &[124, 0x20000, height, width, 0, depth, levels]);
+  push_u32(&mut bytes, &[0; 11]);
+  push_u32(&mut bytes, &pixel_format);
+  push_u32(&mut bytes, &[0x1000, caps2, 0, 0, 0]);
+
+  if let Some(dx10) = dx10 {
+    push_u32(&mut bytes, &dx10);
+  }
+
+  bytes.extend_from_slice(data);
+  bytes
+}
+
+#[test]
+fn ktx_mipmaps() {
+  let base: Vec<u8> = (0..16).collect();
+  let container = TextureContainer::parse(&ktx(
+    [0x1401, 1, 0x1908, 0x8058, 0x1908, 2, 2, 0, 0, 1, 2],
+    &[&base, &[16, 17, 18, 19]],
+  ))
+  .unwrap();
+
+  assert_eq!(container.pixel_format(), NormRGBA8UI::pixel_format());
+  assert_eq!(container.dim(), Dim::Dim2);
+  assert_eq!((container.width(), container.height()), (2, 2));
+  assert_eq!(container.levels(), 2);
+  assert_eq!(container.level(0), Some(&base[..]));
+  assert_eq!(container.level(1), Some(&[16, 17, 18, 19][..]));
+  assert_eq!(container.level(2), None);
+}
+
+#[test]
+fn ktx_unpads_rows() {
+  // 1×2 RGB8 rows are padded from 3 to 4 bytes
+  let container = TextureContainer::parse(&ktx(
+    [0x1401, 1, 0x1907, 0x8051, 0x1907, 1, 2, 0, 0, 1, 1],
+    &[&[1, 2, 3, 0, 4, 5, 6, 0]],
+  ))
+  .unwrap();
+
+  assert_eq!(container.pixel_format(), NormRGB8UI::pixel_format());
+  assert_eq!(container.level(0), Some(&[1, 2, 3, 4, 5, 6][..]));
+}
+
+#[test]
+fn ktx_cubemap() {
+  let faces: Vec<u8> = (0..6).flat_map(|face| [face; 4]).collect();
+  let mut bytes = ktx([0x1401, 1, 0x1908, 0x8058, 0x1908, 1, 1, 0, 0, 6, 1], &[]);
+  push_u32(&mut bytes, &[4]);
+  bytes.extend_from_slice(&faces);
+
+  let container = TextureContainer::parse(&bytes).unwrap();
+
+  assert_eq!(container.dim(), Dim::Cubemap);
+  assert_eq!(container.level(0), Some(&faces[..]));
+}
+
+#[test]
+fn ktx2_compressed() {
+  let container =
+    TextureContainer::parse(&ktx2([131, 1, 8, 8, 0, 0, 1, 2, 0], &[&[0; 32], &[1; 8]])).unwrap();
+
+  assert_eq!(
+    container.pixel_format().format,
+    Format::Compressed(CompressedFormat::BC1RGBA)
+  );
+  assert_eq!(container.levels(), 2);
+  assert_eq!(container.level(1), Some(&[1; 8][..]));
+}
+
+#[test]
+fn ktx2_array() {
+  let container =
+    TextureContainer::parse(&ktx2([100, 4, 1, 1, 0, 3, 1, 1, 0], &[&[0; 12]])).unwrap();
+
+  assert_eq!(container.pixel_format(), R32F::pixel_format());
+  assert_eq!(container.dim(), Dim::Dim2Array);
+  assert_eq!(container.layers(), 3);
+}
+
+#[test]
+fn dds_legacy_dxt5() {
+  let four_cc = u32::from_le_bytes(*b"DXT5");
+  let data: Vec<u8> = (0..96).collect();
+  let container = TextureContainer::parse(&dds(
+    [8, 8, 0, 3],
+    [32, 0x4, four_cc, 0, 0, 0, 0, 0],
+    0,
+    None,
+    &data,
+  ))
+  .unwrap();
+
+  assert_eq!(
+    container.pixel_format().format,
+    Format::Compressed(CompressedFormat::BC3RGBA)
+  );
+  assert_eq!(container.levels(), 3);
+  assert_eq!(container.level(0), Some(&data[..64]));
+  assert_eq!(container.level(1), Some(&data[64..80]));
+  assert_eq!(container.level(2), Some(&data[80..]));
+}
+
+#[test]
+fn dds_dx10_array_is_reordered_by_level() {
+  let four_cc = u32::from_le_bytes(*b"DX10");
+  // two layers of 2×1 R8 texels with 2 levels, stored layer by layer
+  let data = [1, 2, 3, 4, 5, 6];
+  let container = TextureContainer::parse(&dds(
+    [1, 2, 0, 2],
+    [32, 0x4, four_cc, 0, 0, 0, 0, 0],
+    0,
+    Some([61, 3, 0, 2, 0]),
+    &data,
+  ))
+  .unwrap();
+
+  assert_eq!(container.dim(), Dim::Dim2Array);
+  assert_eq!(container.level(0), Some(&[1, 2, 4, 5][..]));
+  assert_eq!(container.level(1), Some(&[3, 6][..]));
+}
+
+#[test]
+fn errors() {
+  assert_eq!(
+    TextureContainer::parse(b"not a texture"),
+    Err(ContainerError::UnknownContainer)
+  );
+
+  let mut truncated = ktx(
+    [0x1401, 1, 0x1908, 0x8058, 0x1908, 2, 2, 0, 0, 1, 1],
+    &[&[0; 16]],
+  );
+  truncated.truncate(truncated.len() - 1);
+  assert!(matches!(
+    TextureContainer::parse(&truncated),
+    Err(ContainerError::InvalidContainer(_))
+  ));
+
+  assert!(matches!(
+    TextureContainer::parse(&ktx2([97, 2, 1, 1, 0, 0, 1, 1, 0], &[&[0; 8]])),
+    Err(ContainerError::UnsupportedFormat(_))
+  ));
+
+  assert!(matches!(
+    TextureContainer::parse(&ktx2([37, 1, 1, 1, 0, 0, 1, 1, 2], &[&[0; 4]])),
+    Err(ContainerError::UnsupportedLayout(_))
+  ));
+
+  assert!(matches!(
+    TextureContainer::parse(&ktx2([37, 1, 1, 1, 0, 0, 1, 2, 0], &[&[0; 4], &[0; 4]])),
+    Err(ContainerError::InvalidContainer(_))
+  ));
+}
+
+#[test]
+fn oversized_headers_are_rejected_before_allocating() {
+  // height overflowing when rounded up to whole blocks
+  let dxt5 = u32::from_le_bytes(*b"DXT5");
+  assert!(matches!(
+    TextureContainer::parse(&dds(
+      [u32::MAX, 4, 0, 1],
+      [32, 0x4, dxt5, 0, 0, 0, 0, 0],
+      0,
+      None,
+      &[0; 16],
+    )),
+    Err(ContainerError::InvalidContainer(_))
+  ));
+
+  // 16 TiB of D3DFMT_A32B32G32R32F texels
+  assert!(matches!(
+    TextureContainer::parse(&dds(
+      [1 << 20, 1 << 20, 0, 1],
+      [32, 0x4, 116, 0, 0, 0, 0, 0],
+      0,
+      None,
+      &[0; 16],
+    )),
+    Err(ContainerError::InvalidContainer(_))
+  ));
+
+  let mut truncated = ktx([0x1401, 1, 0x1908, 0x8058, 0x1908, 2, 2, 0, 0, 1, 1], &[]);
+  push_u32(&mut truncated, &[u32::MAX]);
+  assert!(matches!(
+    TextureContainer::parse(&truncated),
+    Err(ContainerError::InvalidContainer(_))
+  ));
+}
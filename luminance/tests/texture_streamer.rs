@@ -0,0 +1,37 @@
+use luminance::{
+  pixel_buffer::PixelBufferError,
+  texture::TextureError,
+  texture_streamer::{TextureStreamerError, TextureStreamerStats},
+};
+use std::error::Error;
+
+#[test]
+fn errors() {
+  let e = TextureStreamerError::from(TextureError::invalid_level(4, 4));
+
+  assert_eq!(
+    e,
+    TextureStreamerError::texture_error(TextureError::invalid_level(4, 4))
+  );
+  assert_eq!(
+    e.to_string(),
+    "texture streaming error: invalid mipmap level 4 (the texture has 4 levels)"
+  );
+  assert!(e.source().is_some());
+
+  let e = TextureStreamerError::from(PixelBufferError::cannot_map("glMapBufferRange failed"));
+
+  assert_eq!(
+    e.to_string(),
+    "texture streaming error: cannot map pixel buffer: glMapBufferRange failed"
+  );
+}
+
+#[test]
+fn default_stats() {
+  let stats = TextureStreamerStats::default();
+
+  assert_eq!(stats.resident_bytes, 0);
+  assert_eq!(stats.uploaded_levels, 0);
+  assert_eq!(stats.pending_uploads, 0);
+}
@@ -26,6 +26,8 @@ fn derive_renamed_uniform_interface() {
   struct SimpleUniformInterface {
     #[uniform(name = "time")]
     _t: Uniform<f32>,
+    #[uniform(name = "lights[0].color")]
+    _color: Uniform<f32>,
   }
 }
 
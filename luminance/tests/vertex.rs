@@ -1,7 +1,8 @@
 #![cfg(feature = "derive")]
 
 use luminance::vertex::{
-  HasSemantics, Semantics, Vertex, VertexAttrib, VertexBufferDesc, VertexInstancing,
+  HasSemantics, Normalized, Semantics, Vertex, VertexAttrib, VertexAttribDim, VertexAttribType,
+  VertexBufferDesc, VertexInstancing,
 };
 use luminance::{Semantics, Vertex};
 
@@ -80,3 +81,107 @@ fn derive_struct_tuple_vertex() {
     #[vertex(normalized = "true")] VertexColor,
   );
 }
+
+#[test]
+fn derive_instance_divisor() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "offset", repr = "[f32; 2]", wrapper = "InstanceOffset")]
+    Offset,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics", divisor = "4")]
+  struct Instance {
+    offset: InstanceOffset,
+  }
+
+  let expected_desc = vec![VertexBufferDesc::new(
+    Semantics::Offset,
+    VertexInstancing::Divisor(4),
+    <[f32; 2] as VertexAttrib>::VERTEX_ATTRIB_DESC,
+  )];
+
+  assert_eq!(Instance::vertex_desc(), expected_desc);
+  assert_eq!(VertexInstancing::On.divisor(), 1);
+  assert_eq!(VertexInstancing::Off.divisor(), 0);
+  assert_eq!(VertexInstancing::Divisor(4).divisor(), 4);
+  assert_eq!(VertexInstancing::Divisor(1), VertexInstancing::On);
+  assert_eq!(VertexInstancing::Divisor(0), VertexInstancing::Off);
+  assert_ne!(VertexInstancing::Divisor(4), VertexInstancing::On);
+}
+
+#[test]
+fn derive_integral_attributes() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "bones", repr = "[u8; 4]", wrapper = "VertexBones")]
+    Bones,
+    #[sem(name = "color", repr = "[u8; 4]", wrapper = "VertexColor")]
+    Color,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics")]
+  struct Vertex {
+    bones: VertexBones,
+    #[vertex(normalized = "true")]
+    color: VertexColor,
+  }
+
+  let types: Vec<_> = Vertex::vertex_desc()
+    .into_iter()
+    .map(|desc| desc.attrib_desc.ty)
+    .collect();
+
+  assert_eq!(
+    types,
+    vec![
+      VertexAttribType::Unsigned(Normalized::No),
+      VertexAttribType::Unsigned(Normalized::Yes),
+    ]
+  );
+}
+
+#[test]
+fn normalized_attrib_desc() {
+  let normal = <[i16; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC.normalize();
+  assert_eq!(normal.ty, VertexAttribType::Integral(Normalized::Yes));
+  assert_eq!(normal.dim, VertexAttribDim::Dim3);
+  assert_eq!(normal.unit_size, 2);
+
+  let position = <[f32; 3] as VertexAttrib>::VERTEX_ATTRIB_DESC;
+  assert_eq!(position.normalize(), position);
+}
+
+#[test]
+fn derive_field_divisor() {
+  #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+  pub enum Semantics {
+    #[sem(name = "offset", repr = "[f32; 2]", wrapper = "InstanceOffset")]
+    Offset,
+    #[sem(name = "color", repr = "[u8; 4]", wrapper = "InstanceColor")]
+    Color,
+  }
+
+  #[derive(Clone, Copy, Debug, Vertex)]
+  #[repr(C)]
+  #[vertex(sem = "Semantics", instanced = "true")]
+  struct Instance {
+    offset: InstanceOffset,
+    #[vertex(divisor = "4", normalized = "true")]
+    color: InstanceColor,
+  }
+
+  let instancings: Vec<_> = Instance::vertex_desc()
+    .into_iter()
+    .map(|desc| desc.instancing)
+    .collect();
+
+  assert_eq!(
+    instancings,
+    vec![VertexInstancing::On, VertexInstancing::Divisor(4)]
+  );
+}
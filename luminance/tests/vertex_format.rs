@@ -0,0 +1,94 @@
+#![cfg(feature = "derive")]
+
+use luminance::shader::{ActiveVertexAttrib, GeometryInput, UniformType, VertexFormatError};
+use luminance::tess::Mode;
+use luminance::vertex::{Normalized, Vertex as _, VertexAttribType};
+use luminance::{Semantics, Vertex};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+pub enum Semantics {
+  #[sem(name = "position", repr = "[f32; 3]", wrapper = "VertexPosition")]
+  Position,
+  #[sem(name = "id", repr = "i32", wrapper = "VertexId")]
+  Id,
+  #[sem(name = "color", repr = "[f32; 4]", wrapper = "VertexColor")]
+  Color,
+}
+
+#[derive(Clone, Copy, Debug, Vertex)]
+#[repr(C)]
+#[vertex(sem = "Semantics")]
+struct Vertex {
+  pos: VertexPosition,
+  id: VertexId,
+}
+
+fn attrib(name: &str, location: usize, ty: UniformType) -> ActiveVertexAttrib {
+  ActiveVertexAttrib {
+    name: name.to_owned(),
+    location,
+    ty: Some(ty),
+    size: 1,
+  }
+}
+
+#[test]
+fn compatible_vertex_format() {
+  let desc = Vertex::vertex_desc();
+
+  // missing components are filled by the backend
+  assert_eq!(
+    attrib("position", 0, UniformType::Vec4).check_format(&desc),
+    Ok(())
+  );
+  assert_eq!(
+    attrib("id", 1, UniformType::Int).check_format(&desc),
+    Ok(())
+  );
+}
+
+#[test]
+fn vertex_format_mismatches() {
+  let desc = Vertex::vertex_desc();
+
+  assert_eq!(
+    attrib("color", 2, UniformType::Vec4).check_format(&desc),
+    Err(VertexFormatError::missing_attrib("color", 2))
+  );
+
+  let e = attrib("id", 1, UniformType::Float)
+    .check_format(&desc)
+    .unwrap_err();
+  assert_eq!(
+    e,
+    VertexFormatError::type_mismatch(
+      "id",
+      1,
+      UniformType::Float,
+      VertexAttribType::Integral(Normalized::No)
+    )
+  );
+  assert_eq!(
+    e.to_string(),
+    "vertex attribute id (location 1) has type float, but the vertex format provides Integral(No)"
+  );
+}
+
+#[test]
+fn geometry_input_modes() {
+  assert!(GeometryInput::Points.accepts(Mode::Point));
+  assert!(GeometryInput::Lines.accepts(Mode::LineStrip));
+  assert!(GeometryInput::Triangles.accepts(Mode::TriangleFan));
+  assert!(GeometryInput::Triangles.accepts(Mode::Patch(3)));
+  assert!(!GeometryInput::Triangles.accepts(Mode::Line));
+  assert!(!GeometryInput::LinesAdjacency.accepts(Mode::Line));
+
+  assert_eq!(
+    VertexFormatError::incompatible_mode(Mode::Line, GeometryInput::Triangles).to_string(),
+    "line primitive mode is not accepted by the geometry stage, which expects triangles"
+  );
+  assert_eq!(
+    VertexFormatError::no_tessellation_stages(Mode::Patch(3)).to_string(),
+    "patch (3) primitive mode requires tessellation stages, but the program has none"
+  );
+}